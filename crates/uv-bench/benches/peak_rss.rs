@@ -0,0 +1,108 @@
+//! Measure the peak resident set size (RSS) of warm resolutions.
+//!
+//! Criterion measures wall time alone, so memory usage is measured separately. Since the peak RSS
+//! is a property of the process as a whole, each resolution runs once, in a dedicated process:
+//!
+//! ```shell
+//! cargo bench --bench peak_rss
+//! cargo bench --bench peak_rss -- resolve_warm_airflow
+//! ```
+//!
+//! As with the wall-time benchmarks, the cache is assumed to be warm. The peak RSS is read from
+//! `/proc/self/status`, and so is only reported on Linux.
+
+use std::process::Command;
+use std::str::FromStr;
+
+use uv_distribution_types::Requirement;
+use uv_resolver::Manifest;
+
+use crate::common::setup;
+
+mod common;
+
+/// A resolution to measure.
+struct Benchmark {
+    name: &'static str,
+    requirements: &'static [&'static str],
+    universal: bool,
+}
+
+const BENCHMARKS: &[Benchmark] = &[
+    Benchmark {
+        name: "resolve_warm_jupyter",
+        requirements: &["jupyter==1.0.0"],
+        universal: false,
+    },
+    Benchmark {
+        name: "resolve_warm_jupyter_universal",
+        requirements: &["jupyter==1.0.0"],
+        universal: true,
+    },
+    Benchmark {
+        name: "resolve_warm_airflow",
+        requirements: &[
+            "apache-airflow[all]==2.9.3",
+            "apache-airflow-providers-apache-beam>3.0.0",
+        ],
+        universal: false,
+    },
+];
+
+fn main() {
+    // Cargo passes flags like `--bench` to benchmarks without a harness; any other argument
+    // selects the benchmark to run in the current process.
+    if let Some(name) = std::env::args().skip(1).find(|arg| !arg.starts_with('-')) {
+        run(&name);
+        return;
+    }
+
+    let exe = std::env::current_exe().unwrap();
+    for benchmark in BENCHMARKS {
+        let status = Command::new(&exe).arg(benchmark.name).status().unwrap();
+        assert!(status.success(), "`{}` failed", benchmark.name);
+    }
+}
+
+/// Run the named benchmark, and report the peak RSS of the current process.
+#[allow(clippy::print_stdout)]
+fn run(name: &str) {
+    let benchmark = BENCHMARKS
+        .iter()
+        .find(|benchmark| benchmark.name == name)
+        .unwrap_or_else(|| panic!("Unknown benchmark: `{name}`"));
+
+    let manifest = Manifest::simple(
+        benchmark
+            .requirements
+            .iter()
+            .map(|requirement| {
+                Requirement::from(uv_pep508::Requirement::from_str(requirement).unwrap())
+            })
+            .collect(),
+    );
+    setup(manifest)(benchmark.universal);
+
+    match peak_rss() {
+        Some(bytes) => {
+            #[allow(clippy::cast_precision_loss)]
+            let mib = bytes as f64 / (1024.0 * 1024.0);
+            println!("{name}: {mib:.1} MiB peak RSS");
+        }
+        None => println!("{name}: peak RSS is not available on this platform"),
+    }
+}
+
+/// Return the peak RSS of the current process, in bytes.
+fn peak_rss() -> Option<u64> {
+    let status = fs_err::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kib * 1024)
+}
@@ -347,8 +347,8 @@ async fn download_and_unpack(
         .and_then(|val| val.parse::<u64>().ok());
 
     // Stream download directly to extraction
-    let reader = response
-        .bytes_stream()
+    let reader = client
+        .throttle(response.bytes_stream())
         .map_err(std::io::Error::other)
         .into_async_read()
         .compat();
@@ -21,6 +21,8 @@ use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
 use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -78,6 +80,22 @@ pub enum Error {
     TarWrite(PathBuf, #[source] io::Error),
 }
 
+/// Read the timestamp to use for archive entries from `SOURCE_DATE_EPOCH`, if set.
+///
+/// Without `SOURCE_DATE_EPOCH`, archive entries use a fixed timestamp, such that builds are
+/// reproducible either way.
+///
+/// See: <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Option<u64> {
+    let value = std::env::var(EnvVars::SOURCE_DATE_EPOCH).ok()?;
+    if let Ok(epoch) = value.trim().parse::<u64>() {
+        Some(epoch)
+    } else {
+        warn_user_once!("Ignoring invalid `SOURCE_DATE_EPOCH` value: `{value}`");
+        None
+    }
+}
+
 /// Dispatcher between writing to a directory, writing to a zip, writing to a `.tar.gz` and
 /// listing files.
 ///
@@ -1414,4 +1432,31 @@ mod tests {
         simple_namespace_part-1.0.0.dist-info/WHEEL
         ");
     }
+
+    /// Check the conversion of `SOURCE_DATE_EPOCH` timestamps to ZIP timestamps.
+    #[test]
+    fn zip_date_time_from_source_date_epoch() {
+        let date_time = wheel::zip_date_time(1_700_000_000);
+        assert_eq!(
+            (
+                date_time.year(),
+                date_time.month(),
+                date_time.day(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second()
+            ),
+            (2023, 11, 14, 22, 13, 20)
+        );
+
+        // Leap day.
+        let date_time = wheel::zip_date_time(951_782_400);
+        assert_eq!(
+            (date_time.year(), date_time.month(), date_time.day()),
+            (2000, 2, 29)
+        );
+
+        // Timestamps before 1980 can't be represented in ZIP files.
+        assert_eq!(wheel::zip_date_time(0), zip::DateTime::default());
+    }
 }
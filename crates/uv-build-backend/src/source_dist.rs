@@ -2,7 +2,7 @@ use crate::metadata::DEFAULT_EXCLUDES;
 use crate::wheel::build_exclude_matcher;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots,
+    error_on_venv, find_roots, source_date_epoch,
 };
 use flate2::Compression;
 use flate2::write::GzEncoder;
@@ -282,6 +282,8 @@ fn write_source_dist(
 struct TarGzWriter {
     path: PathBuf,
     tar: tar::Builder<GzEncoder<File>>,
+    /// The modification time for all entries, as a Unix timestamp.
+    mtime: u64,
 }
 
 impl TarGzWriter {
@@ -290,7 +292,8 @@ impl TarGzWriter {
         let file = File::create(&path)?;
         let enc = GzEncoder::new(file, Compression::default());
         let tar = tar::Builder::new(enc);
-        Ok(Self { path, tar })
+        let mtime = source_date_epoch().unwrap_or_default();
+        Ok(Self { path, tar, mtime })
     }
 }
 
@@ -298,6 +301,7 @@ impl DirectoryWriter for TarGzWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         let mut header = Header::new_gnu();
         header.set_size(bytes.len() as u64);
+        header.set_mtime(self.mtime);
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
@@ -329,6 +333,7 @@ impl DirectoryWriter for TarGzWriter {
             header.set_mode(0o644);
         }
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         self.tar
             .append_data(&mut header, path, reader)
@@ -342,6 +347,7 @@ impl DirectoryWriter for TarGzWriter {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         self.tar
             .append_data(&mut header, directory, io::empty())
             .map_err(|err| Error::TarWrite(self.path.clone(), err))?;
@@ -9,7 +9,7 @@ use std::path::{Component, Path, PathBuf};
 use std::{io, mem};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
-use zip::{CompressionMethod, ZipWriter};
+use zip::{CompressionMethod, DateTime, ZipWriter};
 
 use uv_distribution_filename::WheelFilename;
 use uv_fs::Simplified;
@@ -20,7 +20,7 @@ use uv_warnings::warn_user_once;
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots,
+    error_on_venv, find_roots, source_date_epoch,
 };
 
 /// Build a wheel from the source tree and place it in the output directory.
@@ -608,6 +608,8 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
 struct ZipDirectoryWriter {
     writer: ZipWriter<File>,
     compression: CompressionMethod,
+    /// The modification time for all entries.
+    last_modified: DateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
@@ -618,6 +620,7 @@ impl ZipDirectoryWriter {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Deflated,
+            last_modified: source_date_epoch().map(zip_date_time).unwrap_or_default(),
             record: Vec::new(),
         }
     }
@@ -630,6 +633,7 @@ impl ZipDirectoryWriter {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Stored,
+            last_modified: DateTime::default(),
             record: Vec::new(),
         }
     }
@@ -644,6 +648,7 @@ impl ZipDirectoryWriter {
         let permissions = if executable_bit { 0o755 } else { 0o644 };
         let options = zip::write::SimpleFileOptions::default()
             .unix_permissions(permissions)
+            .last_modified_time(self.last_modified)
             .compression_method(self.compression);
         self.writer.start_file(path, options)?;
         Ok(Box::new(&mut self.writer))
@@ -656,6 +661,7 @@ impl DirectoryWriter for ZipDirectoryWriter {
         // Set appropriate permissions for metadata files (644 = rw-r--r--)
         let options = zip::write::SimpleFileOptions::default()
             .unix_permissions(0o644)
+            .last_modified_time(self.last_modified)
             .compression_method(self.compression);
         self.writer.start_file(path, options)?;
         self.writer.write_all(bytes)?;
@@ -691,7 +697,9 @@ impl DirectoryWriter for ZipDirectoryWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         trace!("Adding directory {}", directory);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = zip::write::SimpleFileOptions::default()
+            .last_modified_time(self.last_modified)
+            .compression_method(self.compression);
         Ok(self.writer.add_directory(directory, options)?)
     }
 
@@ -712,6 +720,36 @@ impl DirectoryWriter for ZipDirectoryWriter {
     }
 }
 
+/// Convert a Unix timestamp to a [`DateTime`], falling back to the earliest timestamp supported
+/// by ZIP (1980-01-01) for values outside its range.
+pub(crate) fn zip_date_time(timestamp: u64) -> DateTime {
+    // Convert the days since the Unix epoch to a civil date.
+    // See: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    let (Ok(year), Ok(month), Ok(day), Ok(hour), Ok(minute), Ok(second)) = (
+        u16::try_from(year),
+        u8::try_from(month),
+        u8::try_from(day),
+        u8::try_from(seconds / 3600),
+        u8::try_from(seconds % 3600 / 60),
+        u8::try_from(seconds % 60),
+    ) else {
+        return DateTime::default();
+    };
+    DateTime::from_date_and_time(year, month, day, hour, minute, second).unwrap_or_default()
+}
+
 struct FilesystemWriter {
     /// The virtualenv or metadata directory that add file paths are relative to.
     root: PathBuf,
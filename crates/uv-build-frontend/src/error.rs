@@ -55,6 +55,9 @@ static MODULE_NOT_FOUND: LazyLock<Regex> = LazyLock::new(|| {
 static DISTUTILS_NOT_FOUND_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"ModuleNotFoundError: No module named 'distutils'").unwrap());
 
+/// The number of trailing lines of build backend output to retain for diagnostics.
+const OUTPUT_TAIL_LINES: usize = 20;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -301,6 +304,9 @@ pub struct BuildBackendError {
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    /// The trailing lines of the build backend's output, retained for diagnostics regardless of
+    /// the output level.
+    tail: Vec<String>,
 }
 
 impl Display for BuildBackendError {
@@ -334,12 +340,25 @@ impl Display for BuildBackendError {
     }
 }
 
+impl BuildBackendError {
+    /// Return the trailing lines of the build backend's output.
+    ///
+    /// Unlike the [`Display`] implementation, the output is available regardless of the
+    /// [`BuildOutput`] level, such that callers can inspect it for known failure modes.
+    pub fn output_tail(&self) -> &[String] {
+        &self.tail
+    }
+}
+
 #[derive(Debug, Error)]
 pub struct MissingHeaderError {
     message: String,
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    /// The trailing lines of the build backend's output, retained for diagnostics regardless of
+    /// the output level.
+    tail: Vec<String>,
     cause: MissingHeaderCause,
 }
 
@@ -367,6 +386,15 @@ impl Display for MissingHeaderError {
     }
 }
 
+impl MissingHeaderError {
+    /// Return the trailing lines of the build backend's output.
+    ///
+    /// See [`BuildBackendError::output_tail`].
+    pub fn output_tail(&self) -> &[String] {
+        &self.tail
+    }
+}
+
 impl Error {
     /// Construct an [`Error`] from the output of a failed command.
     pub(crate) fn from_command_output(
@@ -413,6 +441,16 @@ impl Error {
             }
         });
 
+        let tail = output
+            .stdout
+            .iter()
+            .rev()
+            .take(OUTPUT_TAIL_LINES)
+            .rev()
+            .chain(output.stderr.iter().rev().take(OUTPUT_TAIL_LINES).rev())
+            .cloned()
+            .collect::<Vec<_>>();
+
         if let Some(missing_library) = missing_library {
             return match level {
                 BuildOutput::Stderr | BuildOutput::Quiet => {
@@ -421,6 +459,7 @@ impl Error {
                         exit_code: output.status,
                         stdout: vec![],
                         stderr: vec![],
+                        tail,
                         cause: MissingHeaderCause {
                             missing_library,
                             package_name: name.cloned(),
@@ -434,6 +473,7 @@ impl Error {
                     exit_code: output.status,
                     stdout: output.stdout.clone(),
                     stderr: output.stderr.clone(),
+                    tail,
                     cause: MissingHeaderCause {
                         missing_library,
                         package_name: name.cloned(),
@@ -450,12 +490,14 @@ impl Error {
                 exit_code: output.status,
                 stdout: vec![],
                 stderr: vec![],
+                tail,
             }),
             BuildOutput::Debug => Self::BuildBackend(BuildBackendError {
                 message,
                 exit_code: output.status,
                 stdout: output.stdout.clone(),
                 stderr: output.stderr.clone(),
+                tail,
             }),
         }
     }
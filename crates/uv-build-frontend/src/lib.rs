@@ -48,7 +48,7 @@ use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBu
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
-pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::error::{BuildBackendError, Error, MissingHeaderCause, MissingHeaderError};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
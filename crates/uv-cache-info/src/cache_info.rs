@@ -287,6 +287,24 @@ impl CacheInfo {
         })
     }
 
+    /// Return the Git commit for the source tree at the given directory, along with the path of
+    /// the directory relative to the root of the repository, if the cache info depends on the
+    /// commit but not on the location of the source tree.
+    ///
+    /// That is, the cache info must include the commit, and must not include any file or directory
+    /// timestamps, which vary across checkouts of the same commit.
+    pub fn git_revision(&self, directory: &Path) -> Option<(&str, PathBuf)> {
+        if self.timestamp.is_some() || !self.directories.is_empty() {
+            return None;
+        }
+        let commit = self.commit.as_ref()?;
+        let root = directory
+            .ancestors()
+            .find(|ancestor| ancestor.join(".git").exists())?;
+        let subdirectory = directory.strip_prefix(root).ok()?;
+        Some((commit.as_str(), subdirectory.to_path_buf()))
+    }
+
     /// Returns `true` if the cache info is empty.
     pub fn is_empty(&self) -> bool {
         self.timestamp.is_none()
@@ -67,6 +67,11 @@ impl Commit {
 
         Ok(Self(commit))
     }
+
+    /// Return the commit as a string.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 /// The set of tags visible in a repository.
@@ -0,0 +1,187 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, warn};
+
+use uv_fs::{LockedFile, Simplified};
+
+use crate::removal::{Removal, rm_rf};
+
+/// The prefix for ephemeral directories (and their lock files) in the builds bucket.
+const EPHEMERAL_PREFIX: &str = "ephemeral-";
+
+/// A temporary directory in the cache for an ephemeral virtual environment, which is removed when
+/// dropped.
+///
+/// Each directory is paired with a lock file that's held for the lifetime of the directory. If the
+/// owning process exits without cleaning up (e.g., it's killed while installing packages), the
+/// lock is released, allowing the directory to be identified and removed by a subsequent process
+/// via [`remove_stale`].
+#[derive(Debug)]
+pub struct EphemeralDir {
+    path: PathBuf,
+    lock_path: PathBuf,
+    lock: Option<LockedFile>,
+    keep: bool,
+}
+
+impl EphemeralDir {
+    /// Create an ephemeral directory in the given root.
+    pub(crate) fn new_in(root: &Path) -> io::Result<Self> {
+        // Create and lock the lock file _before_ the directory, such that the directory is never
+        // observable without a held lock.
+        let (_, lock_path) = tempfile::Builder::new()
+            .prefix(EPHEMERAL_PREFIX)
+            .suffix(".lock")
+            .tempfile_in(root)?
+            .keep()
+            .map_err(|err| err.error)?;
+        let lock = LockedFile::acquire_blocking(&lock_path, lock_path.simplified_display())?;
+
+        let path = lock_path.with_extension("");
+        fs_err::create_dir(&path)?;
+
+        Ok(Self {
+            path,
+            lock_path,
+            lock: Some(lock),
+            keep: false,
+        })
+    }
+
+    /// Return the path to the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Retain the directory when dropped (e.g., for debugging).
+    ///
+    /// The lock file is removed, such that the directory is never considered stale by
+    /// [`remove_stale`]. Removing the directory is left to the user.
+    pub fn keep(&mut self) {
+        self.keep = true;
+
+        // Release the lock before removing the lock file, which is required on Windows.
+        drop(self.lock.take());
+        if let Err(err) = rm_rf(&self.lock_path) {
+            debug!(
+                "Failed to remove lock file `{}`: {err}",
+                self.lock_path.simplified_display()
+            );
+        }
+    }
+}
+
+impl Drop for EphemeralDir {
+    fn drop(&mut self) {
+        if self.keep {
+            return;
+        }
+
+        if let Err(err) = rm_rf(&self.path) {
+            warn!(
+                "Failed to remove ephemeral directory `{}`: {err}",
+                self.path.simplified_display()
+            );
+            return;
+        }
+
+        // Release the lock before removing the lock file, which is required on Windows.
+        drop(self.lock.take());
+        if let Err(err) = rm_rf(&self.lock_path) {
+            debug!(
+                "Failed to remove lock file `{}`: {err}",
+                self.lock_path.simplified_display()
+            );
+        }
+    }
+}
+
+/// Remove any ephemeral directories in the given root that were left behind by a process that
+/// exited without cleaning up.
+pub(crate) fn remove_stale(root: &Path) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+
+    let entries = match fs_err::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let lock_path = entry?.path();
+        if !lock_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(EPHEMERAL_PREFIX) && name.ends_with(".lock"))
+        {
+            continue;
+        }
+
+        // If the lock is held, the directory is still in use.
+        let Some(lock) = LockedFile::try_acquire(&lock_path, lock_path.simplified_display())?
+        else {
+            continue;
+        };
+
+        let path = lock_path.with_extension("");
+        debug!(
+            "Removing stale ephemeral environment: {}",
+            path.simplified_display()
+        );
+        summary += rm_rf(&path)?;
+
+        drop(lock);
+        summary += rm_rf(&lock_path)?;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EphemeralDir, remove_stale};
+
+    #[test]
+    fn cleanup() {
+        let root = tempfile::tempdir().unwrap();
+
+        // An ephemeral directory is removed when dropped.
+        let dir = EphemeralDir::new_in(root.path()).unwrap();
+        let path = dir.path().to_path_buf();
+        assert!(path.is_dir());
+        drop(dir);
+        assert!(!path.exists());
+        assert_eq!(fs_err::read_dir(root.path()).unwrap().count(), 0);
+
+        // A directory that's in use is retained...
+        let mut dir = EphemeralDir::new_in(root.path()).unwrap();
+        remove_stale(root.path()).unwrap();
+        assert!(dir.path().is_dir());
+
+        // ...but a directory whose owner exited without cleaning up is removed.
+        let path = dir.path().to_path_buf();
+        drop(dir.lock.take());
+        std::mem::forget(dir);
+        remove_stale(root.path()).unwrap();
+        assert!(!path.exists());
+        assert_eq!(fs_err::read_dir(root.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn keep() {
+        let root = tempfile::tempdir().unwrap();
+
+        // A kept directory is retained when dropped...
+        let mut dir = EphemeralDir::new_in(root.path()).unwrap();
+        dir.keep();
+        let path = dir.path().to_path_buf();
+        drop(dir);
+        assert!(path.is_dir());
+
+        // ...and isn't considered stale, since its lock file was removed.
+        remove_stale(root.path()).unwrap();
+        assert!(path.is_dir());
+        assert_eq!(fs_err::read_dir(root.path()).unwrap().count(), 1);
+    }
+}
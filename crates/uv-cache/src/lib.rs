@@ -5,20 +5,25 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use rustc_hash::FxHashMap;
 use tracing::{debug, warn};
 
 use uv_cache_info::Timestamp;
+use uv_cache_key::CanonicalUrl;
 use uv_fs::{LockedFile, Simplified, cachedir, directories};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
+use uv_redacted::DisplaySafeUrl;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::ephemeral::EphemeralDir;
+pub use crate::migrate::MigratedBucket;
 use crate::removal::Remover;
-pub use crate::removal::{Removal, rm_rf};
+pub use crate::removal::{Removal, measure, rm_rf};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -27,6 +32,8 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod ephemeral;
+mod migrate;
 mod removal;
 mod wheel;
 
@@ -143,6 +150,8 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The freshness policy for cached index responses.
+    index_policy: IndexCachePolicy,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -159,6 +168,7 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            index_policy: IndexCachePolicy::default(),
             temp_dir: None,
             lock_file: None,
         }
@@ -166,13 +176,25 @@ impl Cache {
 
     /// Create a temporary cache directory.
     pub fn temp() -> Result<Self, io::Error> {
-        let temp_dir = tempfile::tempdir()?;
-        Ok(Self {
+        Ok(Self::from_temp_dir(tempfile::tempdir()?))
+    }
+
+    /// Create a temporary cache directory within the given directory.
+    ///
+    /// Useful for placing the cache on the same filesystem as an environment, such that entries
+    /// can be linked into it.
+    pub fn temp_in(dir: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Ok(Self::from_temp_dir(tempfile::tempdir_in(dir)?))
+    }
+
+    fn from_temp_dir(temp_dir: tempfile::TempDir) -> Self {
+        Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            index_policy: IndexCachePolicy::default(),
             temp_dir: Some(Arc::new(temp_dir)),
             lock_file: None,
-        })
+        }
     }
 
     /// Set the [`Refresh`] policy for the cache.
@@ -181,11 +203,21 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`IndexCachePolicy`] for the cache.
+    #[must_use]
+    pub fn with_index_policy(self, index_policy: IndexCachePolicy) -> Self {
+        Self {
+            index_policy,
+            ..self
+        }
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub fn with_exclusive_lock(self) -> Result<Self, io::Error> {
         let Self {
             root,
             refresh,
+            index_policy,
             temp_dir,
             lock_file,
         } = self;
@@ -204,6 +236,7 @@ impl Cache {
         Ok(Self {
             root,
             refresh,
+            index_policy,
             temp_dir,
             lock_file: Some(Arc::new(lock_file)),
         })
@@ -219,6 +252,11 @@ impl Cache {
         &self.refresh
     }
 
+    /// Return the [`IndexCachePolicy`] for the cache.
+    pub fn index_policy(&self) -> IndexCachePolicy {
+        self.index_policy
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
@@ -250,6 +288,23 @@ impl Cache {
         tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
     }
 
+    /// Create an ephemeral directory to be used as a Python virtual environment for the duration
+    /// of a command (e.g., `uv run --isolated`).
+    ///
+    /// Unlike [`Cache::venv_dir`], the directory is removed by a subsequent process if the current
+    /// process exits without cleaning up.
+    pub fn ephemeral_dir(&self) -> io::Result<EphemeralDir> {
+        let root = self.bucket(CacheBucket::Builds);
+        fs_err::create_dir_all(&root)?;
+
+        // Opportunistically remove any directories left behind by previous processes.
+        if let Err(err) = ephemeral::remove_stale(&root) {
+            debug!("Failed to remove stale ephemeral environments: {err}");
+        }
+
+        EphemeralDir::new_in(&root)
+    }
+
     /// Create a temporary directory to be used for executing PEP 517 source distribution builds.
     pub fn build_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -261,7 +316,7 @@ impl Cache {
         match &self.refresh {
             Refresh::None(_) => false,
             Refresh::All(_) => true,
-            Refresh::Packages(packages, _, _) => packages.contains(package),
+            Refresh::Packages(packages, ..) => packages.contains(package),
         }
     }
 
@@ -270,12 +325,22 @@ impl Cache {
         match &self.refresh {
             Refresh::None(_) => false,
             Refresh::All(_) => true,
-            Refresh::Packages(_, paths, _) => paths
+            Refresh::Packages(_, paths, ..) => paths
                 .iter()
                 .any(|target| same_file::is_same_file(path, target).unwrap_or(false)),
         }
     }
 
+    /// Returns `true` if a cache entry for a remote URL must be revalidated given the [`Refresh`]
+    /// policy.
+    pub fn must_revalidate_url(&self, url: &DisplaySafeUrl) -> bool {
+        match &self.refresh {
+            Refresh::None(_) => false,
+            Refresh::All(_) => true,
+            Refresh::Packages(_, _, urls, _) => urls.iter().any(|target| same_url(url, target)),
+        }
+    }
+
     /// Returns the [`Freshness`] for a cache entry, validating it against the [`Refresh`] policy.
     ///
     /// A cache entry is considered fresh if it was created after the cache itself was
@@ -290,13 +355,14 @@ impl Cache {
         let timestamp = match &self.refresh {
             Refresh::None(_) => return Ok(Freshness::Fresh),
             Refresh::All(timestamp) => timestamp,
-            Refresh::Packages(packages, paths, timestamp) => {
+            Refresh::Packages(packages, paths, urls, timestamp) => {
                 if package.is_none_or(|package| packages.contains(package))
                     || path.is_some_and(|path| {
                         paths
                             .iter()
                             .any(|target| same_file::is_same_file(path, target).unwrap_or(false))
                     })
+                    || urls.iter().any(|url| url_shard_contains(url, entry.dir()))
                 {
                     timestamp
                 } else {
@@ -340,6 +406,17 @@ impl Cache {
         Ok(id)
     }
 
+    /// Move a file or directory that failed integrity verification into the quarantine, such that
+    /// it's never read from the cache.
+    ///
+    /// Returns the path to the quarantined entry.
+    pub fn quarantine(&self, path: impl AsRef<Path>) -> Result<PathBuf, io::Error> {
+        let target = self.bucket(CacheBucket::Quarantine).join(ArchiveId::new());
+        fs_err::create_dir_all(self.bucket(CacheBucket::Quarantine))?;
+        fs_err::rename(path.as_ref(), &target)?;
+        Ok(target)
+    }
+
     /// Returns `true` if the [`Cache`] is temporary.
     pub fn is_temporary(&self) -> bool {
         self.temp_dir.is_some()
@@ -366,6 +443,42 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
+        // Block cache removal operations from interfering.
+        let lock_file = match LockedFile::acquire_shared_blocking(
+            root.join(".lock"),
+            root.simplified_display(),
+        ) {
+            Ok(lock_file) => Some(Arc::new(lock_file)),
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                warn!(
+                    "Shared locking is not supported by the current platform or filesystem, \
+                    reduced parallel process safety with `uv cache clean` and `uv cache prune`."
+                );
+                None
+            }
+            Err(err) => return Err(err),
+        };
+
+        // Carry forward any cache buckets from previous versions of uv, where possible. This must
+        // happen before any of the buckets below are created. Migrations never modify the previous
+        // versions of a bucket, so they're safe to run under the shared lock.
+        if self.temp_dir.is_none() {
+            match migrate::migrate(root, migrate::MIGRATIONS) {
+                Ok(migrated) => {
+                    for bucket in migrated {
+                        debug!(
+                            "Migrated cache bucket from {} to {}",
+                            bucket.source.display(),
+                            bucket.target.display()
+                        );
+                    }
+                }
+                Err(err) => {
+                    warn!("Failed to migrate cache at `{}`: {err}", root.display());
+                }
+            }
+        }
+
         // Add an empty .gitignore to the build bucket, to ensure that the cache's own .gitignore
         // doesn't interfere with source distribution builds. Build backends (like hatchling) will
         // traverse upwards to look for .gitignore files.
@@ -394,22 +507,6 @@ impl Cache {
                 .join(".git"),
         )?;
 
-        // Block cache removal operations from interfering.
-        let lock_file = match LockedFile::acquire_shared_blocking(
-            root.join(".lock"),
-            root.simplified_display(),
-        ) {
-            Ok(lock_file) => Some(Arc::new(lock_file)),
-            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
-                warn!(
-                    "Shared locking is not supported by the current platform or filesystem, \
-                    reduced parallel process safety with `uv cache clean` and `uv cache prune`."
-                );
-                None
-            }
-            Err(err) => return Err(err),
-        };
-
         Ok(Self {
             root: std::path::absolute(root)?,
             lock_file,
@@ -417,6 +514,14 @@ impl Cache {
         })
     }
 
+    /// Migrate the cache buckets from previous versions of uv to their current versions.
+    ///
+    /// A bucket is only migrated if its current version doesn't exist yet, and a migration is
+    /// available for every version in between. Returns the buckets that were migrated.
+    pub fn migrate(&self) -> Result<Vec<MigratedBucket>, io::Error> {
+        migrate::migrate(&self.root, migrate::MIGRATIONS)
+    }
+
     /// Clear the cache, removing all entries.
     pub fn clear(self, reporter: Box<dyn CleanReporter>) -> Result<Removal, io::Error> {
         // Remove everything but `.lock`, for Windows locked file special cases.
@@ -437,26 +542,96 @@ impl Cache {
     ///
     /// Returns the number of entries removed from the cache.
     pub fn remove(&self, name: &PackageName) -> Result<Removal, io::Error> {
-        // Collect the set of referenced archives.
-        let references = self.find_archive_references()?;
+        let (entries, dangling) = self.find_removals(name)?;
 
         // Remove any entries for the package from the cache.
         let mut summary = Removal::default();
-        for bucket in CacheBucket::iter() {
-            summary += bucket.remove(self, name)?;
+        for entry in entries {
+            summary += rm_rf(entry)?;
         }
 
         // Remove any archives that are no longer referenced.
-        for (target, references) in references {
-            if references.iter().all(|path| !path.exists()) {
-                debug!("Removing dangling cache entry: {}", target.display());
-                summary += rm_rf(target)?;
-            }
+        for target in dangling {
+            debug!("Removing dangling cache entry: {}", target.display());
+            summary += rm_rf(target)?;
         }
 
         Ok(summary)
     }
 
+    /// Return the files and directories that [`Cache::remove`] would remove for a package: its
+    /// entries in every cache bucket, along with any archives that would no longer be referenced.
+    pub fn removals(&self, name: &PackageName) -> Result<Vec<PathBuf>, io::Error> {
+        let (mut entries, dangling) = self.find_removals(name)?;
+        entries.extend(dangling);
+        Ok(entries)
+    }
+
+    /// Find the entries for a package in every cache bucket, along with the archives that would no
+    /// longer be referenced once those entries are removed.
+    fn find_removals(&self, name: &PackageName) -> Result<(Vec<PathBuf>, Vec<PathBuf>), io::Error> {
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
+
+        // Collect any entries for the package.
+        let mut entries = Vec::new();
+        for bucket in CacheBucket::iter() {
+            entries.extend(bucket.entries(self, name)?);
+        }
+
+        // Collect any archives that would no longer be referenced.
+        let dangling = references
+            .into_iter()
+            .filter(|(_, references)| {
+                references.iter().all(|path| {
+                    !path.exists() || entries.iter().any(|entry| path.starts_with(entry))
+                })
+            })
+            .map(|(target, _)| target)
+            .collect();
+
+        Ok((entries, dangling))
+    }
+
+    /// Return the entries for a package in every cache bucket, along with the archives that they
+    /// reference (i.e., the unzipped wheels).
+    ///
+    /// Unlike [`Cache::removals`], the flat index is omitted, since it isn't keyed by package.
+    pub fn package_entries(
+        &self,
+        name: &PackageName,
+    ) -> Result<Vec<(CacheBucket, PathBuf)>, io::Error> {
+        let mut entries = Vec::new();
+        for bucket in CacheBucket::iter().filter(|bucket| *bucket != CacheBucket::FlatIndex) {
+            entries.extend(
+                bucket
+                    .entries(self, name)?
+                    .into_iter()
+                    .map(|entry| (bucket, entry)),
+            );
+        }
+
+        // Collect any archives referenced by the package's entries.
+        let mut archives = self
+            .find_archive_references()?
+            .into_iter()
+            .filter(|(_, references)| {
+                references
+                    .iter()
+                    .any(|path| entries.iter().any(|(_, entry)| path.starts_with(entry)))
+            })
+            .map(|(target, _)| target)
+            .collect::<Vec<_>>();
+        archives.sort();
+        entries.extend(
+            archives
+                .into_iter()
+                .map(|archive| (CacheBucket::Archive, archive)),
+        );
+
+        Ok(entries)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -505,7 +680,11 @@ impl Cache {
             Err(err) => return Err(err),
         }
 
-        // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
+        // Third, remove any ephemeral environments left behind by processes that exited without
+        // cleaning up.
+        summary += ephemeral::remove_stale(&self.bucket(CacheBucket::Builds))?;
+
+        // Fourth, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
             // Remove the entire pre-built wheel cache, since every entry is an unzipped wheel.
             match fs_err::read_dir(self.bucket(CacheBucket::Wheels)) {
@@ -562,7 +741,21 @@ impl Cache {
             }
         }
 
-        // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        // Fifth, remove any quarantined entries.
+        match fs_err::read_dir(self.bucket(CacheBucket::Quarantine)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    debug!("Removing quarantined cache entry: {}", path.display());
+                    summary += rm_rf(path)?;
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        // Sixth, remove any unused archives (by searching for archives that are not symlinked).
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -1053,23 +1246,35 @@ pub enum CacheBucket {
     Python,
     /// Downloaded tool binaries (e.g., Ruff).
     Binaries,
+    /// Artifacts that failed integrity verification, either when downloaded (i.e., a hash
+    /// mismatch) or when re-hashed by `uv cache verify`.
+    ///
+    /// Entries in the quarantine are never read by uv; they're retained for inspection until the
+    /// next `uv cache prune`.
+    ///
+    /// Cache structure: `quarantine-v0/<archive id>`
+    Quarantine,
 }
 
 impl CacheBucket {
+    /// Return the versioned directory name of the bucket.
+    ///
+    /// When bumping the version of a bucket, consider registering a migration from the previous
+    /// version in `migrate.rs`.
     fn to_str(self) -> &'static str {
         match self {
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::SourceDistributions => "sdists-v9",
+            Self::SourceDistributions => "sdists-v10",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
             Self::Interpreter => "interpreter-v4",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
-            Self::Simple => "simple-v18",
+            Self::Simple => "simple-v20",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::Wheels => "wheels-v5",
+            Self::Wheels => "wheels-v6",
             // Note that when bumping this, you'll also need to bump
             // `ARCHIVE_VERSION` in `crates/uv-cache/src/lib.rs`.
             Self::Archive => "archive-v0",
@@ -1077,13 +1282,13 @@ impl CacheBucket {
             Self::Environments => "environments-v2",
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
+            Self::Quarantine => "quarantine-v0",
         }
     }
 
-    /// Remove a package from the cache bucket.
-    ///
-    /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    /// Return the entries for a package in the cache bucket, i.e., the files and directories that
+    /// would need to be removed to remove the package from the bucket.
+    fn entries(self, cache: &Cache, name: &PackageName) -> Result<Vec<PathBuf>, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
         fn is_match(path: &Path, name: &PackageName) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
@@ -1095,37 +1300,37 @@ impl CacheBucket {
             metadata.name == *name
         }
 
-        let mut summary = Removal::default();
+        let mut entries = Vec::new();
         match self {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                entries.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    entries.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -1134,7 +1339,16 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
                     if directories(&url)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(url)?;
+                        entries.push(url);
+                    }
+                }
+
+                // For content-addressed archives, we expect a directory for every digest, followed
+                // by a directory per revision.
+                let root = cache.bucket(self).join(WheelCacheKind::Content);
+                for digest in directories(root)? {
+                    if directories(&digest)?.any(|revision| is_match(&revision, name)) {
+                        entries.push(digest);
                     }
                 }
 
@@ -1144,7 +1358,19 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
                     if directories(&path)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(path)?;
+                        entries.push(path);
+                    }
+                }
+
+                // For local dependencies keyed by Git commit, we expect a directory for every
+                // commit, followed by a directory for every path within the repository, followed
+                // by a directory per revision.
+                let root = cache.bucket(self).join(WheelCacheKind::Commit);
+                for commit in directories(root)? {
+                    for path in directories(commit)? {
+                        if directories(&path)?.any(|revision| is_match(&revision, name)) {
+                            entries.push(path);
+                        }
                     }
                 }
 
@@ -1155,7 +1381,7 @@ impl CacheBucket {
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
                         if is_match(&sha, name) {
-                            summary += rm_rf(sha)?;
+                            entries.push(sha);
                         }
                     }
                 }
@@ -1163,20 +1389,20 @@ impl CacheBucket {
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(format!("{name}.rkyv")))?;
+                entries.push(root.join(format!("{name}.rkyv")));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
+                    entries.push(directory.join(format!("{name}.rkyv")));
                 }
             }
             Self::FlatIndex => {
                 // We can't know if the flat index includes a package, so we just remove the entire
                 // cache entry.
                 let root = cache.bucket(self);
-                summary += rm_rf(root)?;
+                entries.push(root);
             }
             Self::Git
             | Self::Interpreter
@@ -1184,11 +1410,13 @@ impl CacheBucket {
             | Self::Builds
             | Self::Environments
             | Self::Python
-            | Self::Binaries => {
+            | Self::Binaries
+            | Self::Quarantine => {
                 // Nothing to do.
             }
         }
-        Ok(summary)
+        entries.retain(|entry| entry.exists());
+        Ok(entries)
     }
 
     /// Return an iterator over all cache buckets.
@@ -1204,6 +1432,7 @@ impl CacheBucket {
             Self::Builds,
             Self::Environments,
             Self::Binaries,
+            Self::Quarantine,
         ]
         .iter()
         .copied()
@@ -1216,6 +1445,17 @@ impl Display for CacheBucket {
     }
 }
 
+/// The freshness policy for cached index responses, i.e., Simple API pages and `--find-links`
+/// pages, as configured via `[tool.uv.cache]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IndexCachePolicy {
+    /// The duration for which a cached index response is considered fresh, overriding the
+    /// `Cache-Control` headers returned by the index.
+    pub ttl: Option<Duration>,
+    /// Whether to serve stale index responses immediately, revalidating them in the background.
+    pub stale_while_revalidate: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Freshness {
     /// The cache entry is fresh according to the [`Refresh`] policy.
@@ -1241,24 +1481,34 @@ impl Freshness {
 pub enum Refresh {
     /// Don't refresh any entries.
     None(Timestamp),
-    /// Refresh entries linked to the given packages, if created before the given timestamp.
-    Packages(Vec<PackageName>, Vec<Box<Path>>, Timestamp),
+    /// Refresh entries linked to the given packages, source trees, or remote URLs, if created
+    /// before the given timestamp.
+    Packages(
+        Vec<PackageName>,
+        Vec<Box<Path>>,
+        Vec<DisplaySafeUrl>,
+        Timestamp,
+    ),
     /// Refresh all entries created before the given timestamp.
     All(Timestamp),
 }
 
 impl Refresh {
     /// Determine the refresh strategy to use based on the command-line arguments.
-    pub fn from_args(refresh: Option<bool>, refresh_package: Vec<PackageName>) -> Self {
+    pub fn from_args(
+        refresh: Option<bool>,
+        refresh_package: Vec<PackageName>,
+        refresh_url: Vec<DisplaySafeUrl>,
+    ) -> Self {
         let timestamp = Timestamp::now();
         match refresh {
             Some(true) => Self::All(timestamp),
             Some(false) => Self::None(timestamp),
             None => {
-                if refresh_package.is_empty() {
+                if refresh_package.is_empty() && refresh_url.is_empty() {
                     Self::None(timestamp)
                 } else {
-                    Self::Packages(refresh_package, vec![], timestamp)
+                    Self::Packages(refresh_package, vec![], refresh_url, timestamp)
                 }
             }
         }
@@ -1286,8 +1536,8 @@ impl Refresh {
             // Take the `max` of the two timestamps.
             (Self::None(t1), Self::None(t2)) => Self::None(t1.max(t2)),
             (Self::None(t1), Self::All(t2)) => Self::All(t1.max(t2)),
-            (Self::None(t1), Self::Packages(packages, paths, t2)) => {
-                Self::Packages(packages, paths, t1.max(t2))
+            (Self::None(t1), Self::Packages(packages, paths, urls, t2)) => {
+                Self::Packages(packages, paths, urls, t1.max(t2))
             }
 
             // If the policy is `All`, refresh all packages.
@@ -1296,28 +1546,52 @@ impl Refresh {
             }
 
             // If the policy is `Packages`, take the "max" of the two policies.
-            (Self::Packages(packages, paths, t1), Self::None(t2)) => {
-                Self::Packages(packages, paths, t1.max(t2))
+            (Self::Packages(packages, paths, urls, t1), Self::None(t2)) => {
+                Self::Packages(packages, paths, urls, t1.max(t2))
             }
             (Self::Packages(.., t1), Self::All(t2)) => Self::All(t1.max(t2)),
-            (Self::Packages(packages1, paths1, t1), Self::Packages(packages2, paths2, t2)) => {
-                Self::Packages(
-                    packages1.into_iter().chain(packages2).collect(),
-                    paths1.into_iter().chain(paths2).collect(),
-                    t1.max(t2),
-                )
-            }
+            (
+                Self::Packages(packages1, paths1, urls1, t1),
+                Self::Packages(packages2, paths2, urls2, t2),
+            ) => Self::Packages(
+                packages1.into_iter().chain(packages2).collect(),
+                paths1.into_iter().chain(paths2).collect(),
+                urls1.into_iter().chain(urls2).collect(),
+                t1.max(t2),
+            ),
         }
     }
 }
 
+/// Returns `true` if two remote URLs refer to the same resource, ignoring any fragment (e.g., a
+/// `#sha256=...` hash).
+fn same_url(a: &DisplaySafeUrl, b: &DisplaySafeUrl) -> bool {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    a.set_fragment(None);
+    b.set_fragment(None);
+    CanonicalUrl::new(&a) == CanonicalUrl::new(&b)
+}
+
+/// Returns `true` if the given cache directory lies within the shard for a remote URL.
+fn url_shard_contains(url: &DisplaySafeUrl, dir: &Path) -> bool {
+    let mut without_fragment = url.clone();
+    without_fragment.set_fragment(None);
+    [url, &without_fragment].into_iter().any(|url| {
+        let shard = WheelCache::Url(url).root();
+        dir.ancestors().any(|ancestor| ancestor.ends_with(&shard))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::ArchiveId;
+    use uv_redacted::DisplaySafeUrl;
 
-    use super::Link;
+    use crate::{ArchiveId, WheelCache};
+
+    use super::{Link, same_url, url_shard_contains};
 
     #[test]
     fn test_link_round_trip() {
@@ -1336,4 +1610,20 @@ mod tests {
         assert!(Link::from_str("v1/foo").is_err());
         assert!(Link::from_str("archive-v0/").is_err());
     }
+
+    #[test]
+    fn test_refresh_url() {
+        let url = DisplaySafeUrl::parse("https://example.com/foo-1.0.tar.gz#sha256=abc").unwrap();
+        let other = DisplaySafeUrl::parse("https://example.com/foo-1.0.tar.gz").unwrap();
+        assert!(same_url(&url, &other));
+
+        // Entries within the URL's shard (with or without the fragment) are matched.
+        let dir = WheelCache::Url(&other).root().join("foo");
+        assert!(url_shard_contains(&url, &dir));
+        assert!(url_shard_contains(&other, &dir));
+
+        let unrelated = DisplaySafeUrl::parse("https://example.com/bar-1.0.tar.gz").unwrap();
+        assert!(!same_url(&url, &unrelated));
+        assert!(!url_shard_contains(&unrelated, &dir));
+    }
 }
@@ -0,0 +1,416 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use crate::CacheBucket;
+use crate::wheel::WheelCacheKind;
+
+/// A migration of a cache bucket from one version to the next.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Migration {
+    /// The cache bucket to which the migration applies.
+    pub(crate) bucket: CacheBucket,
+    /// The version of the bucket that the migration accepts. The migrated bucket is at version
+    /// `from + 1`.
+    pub(crate) from: u32,
+    /// Rewrite the contents of the bucket at the given path, in-place.
+    ///
+    /// The files in the bucket are hard links to the files in the previous version, so migrations
+    /// must replace or remove files rather than modify them.
+    pub(crate) migrate: fn(&Path) -> Result<(), io::Error>,
+}
+
+/// The migrations between consecutive versions of each cache bucket.
+///
+/// When bumping the version of a bucket in [`CacheBucket::to_str`], register a migration here if
+/// the existing entries can be carried forward (e.g., if the change only invalidates a subset of
+/// entries, which the migration can remove), such that expensive artifacts like built wheels
+/// survive the upgrade. Buckets without a complete chain of migrations from a previous version
+/// start out empty, and the previous version is left in place for `uv cache prune`.
+pub(crate) static MIGRATIONS: &[Migration] = &[
+    // The cache keys for local and editable distributions are computed from the canonicalized
+    // path (as of `wheels-v6` and `sdists-v10`); entries for remote distributions are unchanged.
+    Migration {
+        bucket: CacheBucket::Wheels,
+        from: 5,
+        migrate: remove_path_entries,
+    },
+    Migration {
+        bucket: CacheBucket::SourceDistributions,
+        from: 9,
+        migrate: remove_path_entries,
+    },
+];
+
+/// Remove the entries for local and editable distributions from a bucket that's keyed by
+/// [`WheelCacheKind`], which would otherwise be orphaned by a change to their cache keys.
+fn remove_path_entries(bucket: &Path) -> Result<(), io::Error> {
+    for kind in [WheelCacheKind::Path, WheelCacheKind::Editable] {
+        match fs_err::remove_dir_all(bucket.join(kind.root())) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// A cache bucket that was migrated from a previous version.
+#[derive(Debug, Clone)]
+pub struct MigratedBucket {
+    /// The directory of the previous version of the bucket.
+    pub source: PathBuf,
+    /// The directory of the current version of the bucket.
+    pub target: PathBuf,
+}
+
+/// Split the directory name of a cache bucket (e.g., `wheels-v6`) into its name and version.
+pub(crate) fn parse_bucket(dir: &str) -> Option<(&str, u32)> {
+    let (name, version) = dir.rsplit_once("-v")?;
+    Some((name, version.parse().ok()?))
+}
+
+/// Migrate every cache bucket in the cache at `root` from its most recent previous version, if the
+/// current version doesn't exist yet.
+pub(crate) fn migrate(
+    root: &Path,
+    migrations: &[Migration],
+) -> Result<Vec<MigratedBucket>, io::Error> {
+    // Avoid reading the cache directory if there's nothing to migrate.
+    if migrations.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let existing = existing_buckets(root)?;
+    let mut migrated = Vec::new();
+    for bucket in CacheBucket::iter() {
+        if let Some(bucket) = migrate_bucket(root, bucket, &existing, migrations)? {
+            migrated.push(bucket);
+        }
+    }
+    Ok(migrated)
+}
+
+/// Migrate a single cache bucket, applying each migration between the most recent previous
+/// version and the current version in order.
+fn migrate_bucket(
+    root: &Path,
+    bucket: CacheBucket,
+    existing: &[(String, u32)],
+    migrations: &[Migration],
+) -> Result<Option<MigratedBucket>, io::Error> {
+    let target = root.join(bucket.to_str());
+
+    // If the current version of the bucket exists, there's nothing to migrate.
+    if target.exists() {
+        return Ok(None);
+    }
+
+    let Some((name, version)) = parse_bucket(bucket.to_str()) else {
+        return Ok(None);
+    };
+
+    // Find the most recent previous version of the bucket.
+    let Some(from) = existing
+        .iter()
+        .filter(|(existing, from)| existing == name && *from < version)
+        .map(|(_, from)| *from)
+        .max()
+    else {
+        return Ok(None);
+    };
+
+    // Collect a migration for every step between the previous and the current version.
+    let Some(steps) = (from..version)
+        .map(|step| {
+            migrations
+                .iter()
+                .find(|migration| migration.bucket == bucket && migration.from == step)
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        debug!("No migration available for cache bucket `{name}` from v{from} to v{version}");
+        return Ok(None);
+    };
+
+    // Link the previous version into a staging directory and migrate it there, such that a failed
+    // migration never leaves a partially-migrated bucket behind. Migrations only hold a shared lock
+    // on the cache, so the previous version is left untouched for any concurrent readers (and for
+    // older versions of uv), and is removed by `uv cache prune` along with any leftover staging
+    // directories.
+    let source = root.join(format!("{name}-v{from}"));
+    let staging = tempfile::Builder::new()
+        .prefix(".migrate-")
+        .tempdir_in(root)?;
+    let staged = staging.path().join(bucket.to_str());
+    link_dir(&source, &staged)?;
+
+    for step in steps {
+        debug!(
+            "Migrating cache bucket `{name}` from v{} to v{}",
+            step.from,
+            step.from + 1
+        );
+        (step.migrate)(&staged)?;
+    }
+
+    match fs_err::rename(&staged, &target) {
+        Ok(()) => {}
+        // Another process populated the current version in the meantime; prefer it.
+        Err(_) if target.exists() => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    Ok(Some(MigratedBucket { source, target }))
+}
+
+/// Recreate the directory tree at `src` under `dst`, hard linking files (or copying them, if hard
+/// links aren't supported) and recreating symbolic links with the same target.
+fn link_dir(src: &Path, dst: &Path) -> Result<(), io::Error> {
+    for entry in WalkDir::new(src) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(src)
+            .expect("walkdir starts with root");
+        let path = dst.join(relative);
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            fs_err::create_dir_all(&path)?;
+        } else if file_type.is_symlink() {
+            uv_fs::create_symlink(fs_err::read_link(entry.path())?, &path)?;
+        } else if let Err(err) = fs_err::hard_link(entry.path(), &path) {
+            trace!(
+                "Failed to hard link `{}`, copying instead: {err}",
+                entry.path().display()
+            );
+            fs_err::copy(entry.path(), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the name and version of every versioned bucket directory in the cache.
+fn existing_buckets(root: &Path) -> Result<Vec<(String, u32)>, io::Error> {
+    let entries = match fs_err::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut buckets = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let file_name = entry.file_name();
+        let Some((name, version)) = file_name.to_str().and_then(parse_bucket) else {
+            continue;
+        };
+        buckets.push((name.to_string(), version));
+    }
+    Ok(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::path::Path;
+
+    use crate::CacheBucket;
+
+    use super::{MIGRATIONS, Migration, migrate, parse_bucket};
+
+    fn remove_http(path: &Path) -> Result<(), io::Error> {
+        fs_err::remove_file(path.join("pypi").join("stale.http"))
+    }
+
+    fn add_marker(path: &Path) -> Result<(), io::Error> {
+        fs_err::write(path.join("migrated"), "")
+    }
+
+    fn fail_partway(path: &Path) -> Result<(), io::Error> {
+        fs_err::write(path.join("partial"), "")?;
+        Err(io::Error::other("migration failed"))
+    }
+
+    /// Return the names of the entries in the cache root.
+    fn entries(root: &Path) -> Result<Vec<String>, io::Error> {
+        let mut entries = fs_err::read_dir(root)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<Result<Vec<_>, io::Error>>()?;
+        entries.sort();
+        Ok(entries)
+    }
+
+    #[test]
+    fn test_parse_bucket() {
+        assert_eq!(parse_bucket("wheels-v5"), Some(("wheels", 5)));
+        assert_eq!(parse_bucket("flat-index-v2"), Some(("flat-index", 2)));
+        assert_eq!(parse_bucket("wheels"), None);
+        assert_eq!(parse_bucket("wheels-vX"), None);
+    }
+
+    #[test]
+    fn test_migrate_incrementally() -> Result<(), io::Error> {
+        let root = tempfile::tempdir()?;
+        let bucket = CacheBucket::Wheels;
+        let (name, version) = parse_bucket(bucket.to_str()).unwrap();
+
+        // Populate a bucket from two versions ago.
+        let source = root.path().join(format!("{name}-v{}", version - 2));
+        fs_err::create_dir_all(source.join("pypi"))?;
+        fs_err::write(source.join("pypi").join("keep.whl"), "")?;
+        fs_err::write(source.join("pypi").join("stale.http"), "")?;
+
+        let migrations = [
+            Migration {
+                bucket,
+                from: version - 2,
+                migrate: remove_http,
+            },
+            Migration {
+                bucket,
+                from: version - 1,
+                migrate: add_marker,
+            },
+        ];
+        let migrated = migrate(root.path(), &migrations)?;
+        assert_eq!(migrated.len(), 1);
+
+        let target = root.path().join(bucket.to_str());
+        assert_eq!(migrated[0].target, target);
+        assert!(target.join("pypi").join("keep.whl").exists());
+        assert!(!target.join("pypi").join("stale.http").exists());
+        assert!(target.join("migrated").exists());
+
+        // The previous version is left untouched, for `uv cache prune`.
+        assert!(source.join("pypi").join("stale.http").exists());
+        assert!(!source.join("migrated").exists());
+
+        // Once migrated, the bucket is left alone.
+        assert!(migrate(root.path(), &migrations)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_migrate_symlinks() -> Result<(), io::Error> {
+        let root = tempfile::tempdir()?;
+        let bucket = CacheBucket::Wheels;
+        let (name, version) = parse_bucket(bucket.to_str()).unwrap();
+
+        let archive = root.path().join("archive-v0").join("entry");
+        fs_err::create_dir_all(&archive)?;
+        let source = root.path().join(format!("{name}-v{}", version - 1));
+        fs_err::create_dir_all(source.join("pypi"))?;
+        fs_err::os::unix::fs::symlink(&archive, source.join("pypi").join("wheel"))?;
+
+        // Symbolic links into the archive bucket are recreated, rather than followed.
+        let migrations = [Migration {
+            bucket,
+            from: version - 1,
+            migrate: add_marker,
+        }];
+        assert_eq!(migrate(root.path(), &migrations)?.len(), 1);
+        let target = root.path().join(bucket.to_str());
+        assert_eq!(
+            fs_err::read_link(target.join("pypi").join("wheel"))?,
+            archive
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_missing_step() -> Result<(), io::Error> {
+        let root = tempfile::tempdir()?;
+        let bucket = CacheBucket::Wheels;
+        let (name, version) = parse_bucket(bucket.to_str()).unwrap();
+
+        let source = root.path().join(format!("{name}-v{}", version - 2));
+        fs_err::create_dir_all(&source)?;
+
+        // Without a migration for every step, the previous version is left untouched.
+        let migrations = [Migration {
+            bucket,
+            from: version - 1,
+            migrate: add_marker,
+        }];
+        assert!(migrate(root.path(), &migrations)?.is_empty());
+        assert!(source.exists());
+        assert!(!root.path().join(bucket.to_str()).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_rollback() -> Result<(), io::Error> {
+        let root = tempfile::tempdir()?;
+        let bucket = CacheBucket::Wheels;
+        let (name, version) = parse_bucket(bucket.to_str()).unwrap();
+
+        let source = root.path().join(format!("{name}-v{}", version - 2));
+        fs_err::create_dir_all(source.join("pypi"))?;
+        fs_err::write(source.join("pypi").join("keep.whl"), "")?;
+        fs_err::write(source.join("pypi").join("stale.http"), "")?;
+
+        // If a later step fails, the previous version is left untouched (discarding any changes
+        // made by earlier steps), and the current version isn't created.
+        let migrations = [
+            Migration {
+                bucket,
+                from: version - 2,
+                migrate: remove_http,
+            },
+            Migration {
+                bucket,
+                from: version - 1,
+                migrate: fail_partway,
+            },
+        ];
+        let err = migrate(root.path(), &migrations).unwrap_err();
+        assert_eq!(err.to_string(), "migration failed");
+
+        assert!(source.join("pypi").join("keep.whl").exists());
+        assert!(source.join("pypi").join("stale.http").exists());
+        assert!(!source.join("partial").exists());
+        assert!(!root.path().join(bucket.to_str()).exists());
+
+        // The staging directory is removed.
+        assert_eq!(entries(root.path())?, [format!("{name}-v{}", version - 2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_path_entries() -> Result<(), io::Error> {
+        let root = tempfile::tempdir()?;
+
+        for (bucket, from) in [
+            (CacheBucket::Wheels, "wheels-v5"),
+            (CacheBucket::SourceDistributions, "sdists-v9"),
+        ] {
+            let source = root.path().join(from);
+            for kind in ["pypi", "index", "url", "git", "path", "editable"] {
+                fs_err::create_dir_all(source.join(kind).join("entry"))?;
+            }
+
+            let migrated = migrate(root.path(), MIGRATIONS)?;
+            assert_eq!(migrated.len(), 1);
+            assert_eq!(migrated[0].source, source);
+
+            // Entries for remote distributions are carried forward; entries for local
+            // distributions are removed, since their cache keys changed.
+            let target = root.path().join(bucket.to_str());
+            assert_eq!(entries(&target)?, ["git", "index", "pypi", "url"]);
+            assert!(source.join("path").join("entry").exists());
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,17 @@ pub enum WheelCache<'a> {
     /// Note that this variant only exists for source distributions; wheels can't be delivered
     /// through Git.
     Git(&'a DisplaySafeUrl, &'a str),
+    /// A remote source distribution whose SHA-256 digest is known up-front, which we key by
+    /// digest, such that identical archives share built wheels regardless of their URL.
+    ///
+    /// Note that this variant only exists for source distributions.
+    Content(&'a str),
+    /// A local source tree whose cache keys depend on the Git commit alone, which we key by commit
+    /// and by the path of the source tree within the repository, such that checkouts of the same
+    /// commit share built wheels regardless of their location.
+    ///
+    /// Note that this variant only exists for source distributions.
+    Commit(&'a str, &'a Path),
 }
 
 impl WheelCache<'_> {
@@ -35,14 +46,19 @@ impl WheelCache<'_> {
                 .join(cache_digest(&CanonicalUrl::new(url))),
             Self::Path(url) => WheelCacheKind::Path
                 .root()
-                .join(cache_digest(&CanonicalUrl::new(url))),
+                .join(cache_digest(&canonical_file_url(url))),
             Self::Editable(url) => WheelCacheKind::Editable
                 .root()
-                .join(cache_digest(&CanonicalUrl::new(url))),
+                .join(cache_digest(&canonical_file_url(url))),
             Self::Git(url, sha) => WheelCacheKind::Git
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url)))
                 .join(sha),
+            Self::Content(digest) => WheelCacheKind::Content.root().join(digest),
+            Self::Commit(commit, subdirectory) => WheelCacheKind::Commit
+                .root()
+                .join(commit)
+                .join(cache_digest(subdirectory)),
         }
     }
 
@@ -52,6 +68,21 @@ impl WheelCache<'_> {
     }
 }
 
+/// Canonicalize a `file://` URL, such that a path that's reached via a symbolic link, or spelled
+/// with different casing on a case-insensitive filesystem, maps to the same cache entry.
+fn canonical_file_url(url: &DisplaySafeUrl) -> CanonicalUrl {
+    let Some(mut canonical) = url
+        .to_file_path()
+        .ok()
+        .and_then(|path| DisplaySafeUrl::from_file_path(uv_fs::canonicalize_key(path)).ok())
+    else {
+        return CanonicalUrl::new(url);
+    };
+    canonical.set_query(url.query());
+    canonical.set_fragment(url.fragment());
+    CanonicalUrl::new(&canonical)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum WheelCacheKind {
     /// A cache of data from PyPI.
@@ -66,6 +97,10 @@ pub(crate) enum WheelCacheKind {
     Editable,
     /// A cache of data from a Git repository.
     Git,
+    /// A cache of data keyed by the digest of its contents.
+    Content,
+    /// A cache of data from a local source tree, keyed by Git commit.
+    Commit,
 }
 
 impl WheelCacheKind {
@@ -77,6 +112,8 @@ impl WheelCacheKind {
             Self::Path => "path",
             Self::Editable => "editable",
             Self::Git => "git",
+            Self::Content => "content",
+            Self::Commit => "commit",
         }
     }
 
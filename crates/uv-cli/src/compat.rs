@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use anyhow::{Result, anyhow};
 use clap::{Args, ValueEnum};
 
+use uv_normalize::PackageName;
 use uv_warnings::warn_user;
 
 pub trait CompatArgs {
@@ -10,20 +13,28 @@ pub trait CompatArgs {
 /// Arguments for `pip-compile` compatibility.
 ///
 /// These represent a subset of the `pip-compile` interface that uv supports by default.
-/// For example, users often pass `--allow-unsafe`, which is unnecessary with uv. But it's a
-/// nice user experience to warn, rather than fail, when users pass `--allow-unsafe`.
+/// For example, users often pass `--no-emit-trusted-host`, which is unnecessary with uv. But it's
+/// a nice user experience to warn, rather than fail, when users pass `--no-emit-trusted-host`.
+///
+/// Some options (e.g., `--unsafe-package` and `--allow-unsafe`) change uv's behavior to match
+/// that of `pip-compile`, such that existing invocations can be used as-is.
 #[derive(Args)]
 pub struct PipCompileCompatArgs {
+    /// Mark a package as "unsafe", omitting it from the output resolution unless `--allow-unsafe`
+    /// is provided. Its dependencies will still be included in the resolution.
     #[clap(long, hide = true)]
+    unsafe_package: Vec<PackageName>,
+
+    #[clap(long, hide = true, overrides_with("no_allow_unsafe"))]
     allow_unsafe: bool,
 
-    #[clap(long, hide = true)]
+    #[clap(long, hide = true, overrides_with("allow_unsafe"))]
     no_allow_unsafe: bool,
 
-    #[clap(long, hide = true)]
+    #[clap(long, hide = true, overrides_with("no_reuse_hashes"))]
     reuse_hashes: bool,
 
-    #[clap(long, hide = true)]
+    #[clap(long, hide = true, overrides_with("reuse_hashes"))]
     no_reuse_hashes: bool,
 
     #[clap(long, hide = true)]
@@ -67,28 +78,6 @@ impl CompatArgs for PipCompileCompatArgs {
     /// behavior. If an argument is passed that does _not_ match uv's behavior (e.g.,
     /// `--no-build-isolation`), this method will return an error.
     fn validate(&self) -> Result<()> {
-        if self.allow_unsafe {
-            warn_user!(
-                "pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages)"
-            );
-        }
-
-        if self.no_allow_unsafe {
-            warn_user!(
-                "pip-compile's `--no-allow-unsafe` has no effect (uv can safely pin `pip` and other packages)"
-            );
-        }
-
-        if self.reuse_hashes {
-            return Err(anyhow!(
-                "pip-compile's `--reuse-hashes` is unsupported (uv doesn't reuse hashes)"
-            ));
-        }
-
-        if self.no_reuse_hashes {
-            warn_user!("pip-compile's `--no-reuse-hashes` has no effect (uv doesn't reuse hashes)");
-        }
-
         if let Some(resolver) = self.resolver {
             match resolver {
                 Resolver::Backtracking => {
@@ -154,6 +143,38 @@ impl CompatArgs for PipCompileCompatArgs {
     }
 }
 
+impl PipCompileCompatArgs {
+    /// The packages that `pip-compile` considers unsafe by default, and omits from the output
+    /// with `--no-allow-unsafe`.
+    const DEFAULT_UNSAFE_PACKAGES: &'static [&'static str] = &["distribute", "pip", "setuptools"];
+
+    /// Return the packages to omit from the output resolution as "unsafe".
+    ///
+    /// Unlike `pip-compile`, uv pins all packages by default; packages are only omitted if they're
+    /// marked with `--unsafe-package`, or, with `--no-allow-unsafe`, if they're in the default set
+    /// of unsafe packages. `--allow-unsafe` pins all packages, including those marked as unsafe.
+    pub fn unsafe_packages(&self) -> Vec<PackageName> {
+        if self.allow_unsafe {
+            return Vec::new();
+        }
+        let mut packages = self.unsafe_package.clone();
+        if self.no_allow_unsafe {
+            packages.extend(
+                Self::DEFAULT_UNSAFE_PACKAGES
+                    .iter()
+                    .map(|name| PackageName::from_str(name).unwrap()),
+            );
+        }
+        packages
+    }
+
+    /// Whether to reuse the hashes from an existing output file for any unchanged pins, as opposed
+    /// to recomputing them (with `--no-reuse-hashes`).
+    pub fn reuse_hashes(&self) -> bool {
+        !self.no_reuse_hashes
+    }
+}
+
 /// Arguments for `pip list` compatibility.
 ///
 /// These represent a subset of the `pip list` interface that uv supports by default.
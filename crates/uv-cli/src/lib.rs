@@ -11,19 +11,21 @@ use clap::{Args, Parser, Subcommand};
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, ProjectBuildBackend,
-    TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
+    ProjectBuildBackend, RateLimit, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
     PipFindLinks, PipIndex,
 };
+use uv_fs::Quarantine;
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
 use uv_preview::PreviewFeatures;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerTimestamp, ForkStrategy, PrereleaseMode,
     ResolutionMode,
@@ -37,7 +39,9 @@ pub mod compat;
 pub mod options;
 pub mod version;
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum VersionFormat {
     /// Display the version as plain text.
     Text,
@@ -45,7 +49,9 @@ pub enum VersionFormat {
     Json,
 }
 
-#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PythonListFormat {
     /// Plain text (for humans).
     #[default]
@@ -54,7 +60,20 @@ pub enum PythonListFormat {
     Json,
 }
 
-#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonFindFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
     #[default]
@@ -63,7 +82,68 @@ pub enum SyncFormat {
     Json,
 }
 
-#[derive(Debug, Default, Clone, clap::ValueEnum)]
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AffectedFormat {
+    /// Display the affected members' names, one per line.
+    #[default]
+    Text,
+    /// Display the affected members in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum GraphFormat {
+    /// Render the graph in the Graphviz DOT language.
+    #[default]
+    Dot,
+    /// Render the graph in JSON format.
+    Json,
+    /// Render the graph as a Mermaid flowchart.
+    Mermaid,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ShowSettingsFormat {
+    /// Display the settings using their debug representation.
+    #[default]
+    Text,
+    /// Display the settings in JSON format, one line per settings type.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DiffFormat {
+    /// Display the differences in a human-readable table.
+    #[default]
+    Text,
+    /// Display the differences in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TreeFormat {
+    /// Display the dependency tree in a human-readable format.
+    #[default]
+    Text,
+    /// Display the dependency tree as nested JSON objects.
+    Json,
+    /// Display the dependency graph in the Graphviz DOT format.
+    Dot,
+}
+
+#[derive(Debug, Default, Clone, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
     #[default]
@@ -75,6 +155,19 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ListSort {
+    /// Sort packages by name.
+    #[default]
+    Name,
+    /// Sort packages by installed version.
+    Version,
+    /// Sort packages by installed size, from largest to smallest.
+    Size,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -280,6 +373,24 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// Limit the network bandwidth used for downloads.
+    ///
+    /// Accepts a number of bytes per second, with an optional unit and `/s` suffix (e.g., `5MB/s`,
+    /// `500K`, or `1MiB/s`). The limit is shared across all concurrent downloads.
+    #[arg(global = true, long, env = EnvVars::UV_LIMIT_RATE, value_name = "RATE")]
+    pub limit_rate: Option<RateLimit>,
+
+    /// Treat the network connection as metered.
+    ///
+    /// On a metered connection, uv avoids large optional downloads, such as automatic downloads of
+    /// managed Python installations, and reports the size of any distributions before downloading
+    /// them.
+    #[arg(global = true, long, overrides_with("no_metered"), env = EnvVars::UV_METERED, value_parser = clap::builder::BoolishValueParser::new())]
+    pub metered: bool,
+
+    #[arg(global = true, long, overrides_with("metered"), hide = true)]
+    pub no_metered: bool,
+
     /// Whether to enable all experimental preview features.
     ///
     /// Preview features may change without warning.
@@ -323,6 +434,12 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// The format in which to show the resolved settings.
+    ///
+    /// Used with `--show-settings`.
+    #[arg(global = true, long, hide = true, value_enum, default_value_t)]
+    pub show_settings_format: ShowSettingsFormat,
+
     /// Hide all progress outputs.
     ///
     /// For example, spinners or progress bars.
@@ -333,6 +450,22 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
 
+    /// How to treat the quarantine attributes of installed packages and Python installations.
+    ///
+    /// Security software may block or prompt before executing files that carry these attributes,
+    /// i.e., `com.apple.quarantine` on macOS and the Mark of the Web on Windows. By default, they
+    /// are left untouched.
+    #[arg(global = true, long, hide = true, value_enum, env = EnvVars::UV_QUARANTINE)]
+    pub quarantine: Option<Quarantine>,
+
+    /// The time to wait for an environment lock, in seconds.
+    ///
+    /// Commands that modify an environment, such as `uv sync` and `uv pip install`, hold a lock on
+    /// it to prevent concurrent modifications. By default, uv waits indefinitely for another
+    /// process to release the lock.
+    #[arg(global = true, long, env = EnvVars::UV_LOCKED_TIMEOUT, value_name = "SECONDS")]
+    pub locked_timeout: Option<u64>,
+
     /// Change to the given directory prior to running the command.
     ///
     /// Relative paths are resolved with the given directory as the base.
@@ -357,7 +490,9 @@ pub struct GlobalArgs {
     pub project: Option<PathBuf>,
 }
 
-#[derive(Debug, Copy, Clone, clap::ValueEnum)]
+#[derive(Debug, Copy, Clone, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
     Auto,
@@ -523,12 +658,36 @@ pub enum Commands {
         #[command(subcommand)]
         command: BuildBackendCommand,
     },
+    /// The implementation of the installer plugin protocol.
+    ///
+    /// These commands are not directly exposed to the user, instead they're invoked by tools that
+    /// manage their own environments (like tox and nox) to delegate environment creation to uv.
+    #[command(hide = true)]
+    Env(EnvNamespace),
     /// Manage uv's cache.
     #[command(
         after_help = "Use `uv help cache` for more details.",
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Manage the shared store of installed packages.
+    #[command(
+        after_help = "Use `uv help store` for more details.",
+        after_long_help = ""
+    )]
+    Store(StoreNamespace),
+    /// Manage directories of pre-downloaded distributions.
+    #[command(
+        after_help = "Use `uv help wheelhouse` for more details.",
+        after_long_help = ""
+    )]
+    Wheelhouse(WheelhouseNamespace),
+    /// Inspect the members of a workspace.
+    #[command(
+        after_help = "Use `uv help workspace` for more details.",
+        after_long_help = ""
+    )]
+    Workspace(WorkspaceNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -538,6 +697,10 @@ pub enum Commands {
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
+    /// Generate a JSON Schema for `uv.toml` and the `[tool.uv]` section of `pyproject.toml`, or
+    /// for the output of `--show-settings`
+    #[command(hide = true)]
+    GenerateSchema(GenerateSchemaArgs),
     /// Display documentation for a command.
     // To avoid showing the global options when displaying help for the help command, we are
     // responsible for maintaining the options using the `after_help`.
@@ -655,7 +818,9 @@ pub struct VersionArgs {
 
 // Note that the ordering of the variants is significant, as when given a list of operations
 // to perform, we sort them and apply them in order, so users don't have to think too hard about it.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum VersionBump {
     /// Increase the major version (e.g., 1.2.3 => 2.0.0)
     Major,
@@ -752,6 +917,26 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Migrate cache buckets from previous versions of uv.
+    ///
+    /// When a release changes the format of a cache bucket, uv carries forward the existing
+    /// entries (like built wheels) where a migration is available, rather than starting from an
+    /// empty bucket. Migrations run automatically whenever the cache is used; this command runs
+    /// them explicitly.
+    Migrate,
+    /// Verify the integrity of the cache.
+    ///
+    /// Re-hashes the files of every unzipped wheel in the cache against the wheel's `RECORD`,
+    /// to detect corruption (e.g., bit-rot on long-lived machines). Corrupted entries are moved to
+    /// a quarantine area, such that they're re-downloaded when next needed; quarantined entries
+    /// are removed by `uv cache prune`.
+    Verify,
+    /// Show the cache entries for a package.
+    ///
+    /// Lists the package's entries in every cache bucket, along with their sizes: downloaded
+    /// wheels, source distributions and the wheels built from them, index metadata, and the
+    /// unzipped wheels they reference.
+    Show(CacheShowArgs),
     /// Show the cache directory.
     ///
     ///
@@ -769,13 +954,191 @@ pub enum CacheCommand {
     Dir,
 }
 
+#[derive(Args)]
+pub struct StoreNamespace {
+    #[command(subcommand)]
+    pub command: StoreCommand,
+}
+
+#[derive(Subcommand)]
+pub enum StoreCommand {
+    /// Remove packages from the shared store that are no longer used by any environment.
+    ///
+    /// Environments are registered with the store when they're synced with
+    /// `--site-packages-dir`. Any package in the store that isn't linked into a registered
+    /// environment is removed, along with the registrations of environments that no longer exist.
+    Gc(StoreGcArgs),
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StoreGcArgs {
+    /// The path to the shared store.
+    #[arg(long, env = EnvVars::UV_SITE_PACKAGES_DIR)]
+    pub site_packages_dir: PathBuf,
+}
+
+#[derive(Args)]
+pub struct WheelhouseNamespace {
+    #[command(subcommand)]
+    pub command: WheelhouseCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WheelhouseCommand {
+    /// Verify every distribution in a wheelhouse against its integrity manifest.
+    ///
+    /// The manifest uses the `sha256sum` output format, with one `<hash>  <filename>` entry per
+    /// line; hashes for other algorithms can be provided as `<algorithm>:<hash>`. Verification
+    /// fails if any listed file is missing or doesn't match its hash, or if the directory contains
+    /// a wheel or source distribution that isn't listed in the manifest.
+    ///
+    /// When a wheelhouse containing a `SHA256SUMS` manifest is passed to `--find-links`, the
+    /// manifest hashes are used for `--require-hashes` checks during resolution.
+    Verify(WheelhouseVerifyArgs),
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WheelhouseVerifyArgs {
+    /// The path to the wheelhouse directory.
+    pub path: PathBuf,
+
+    /// The path to the integrity manifest.
+    ///
+    /// Defaults to the `SHA256SUMS` file in the wheelhouse directory.
+    #[arg(long)]
+    pub manifest: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// List the workspace members affected by changes since a Git revision.
+    ///
+    /// A member is affected if any of its files have changed since the revision, if any of its
+    /// path dependencies (i.e., dependencies with a `path` source in `tool.uv.sources`) have
+    /// changed, or if it depends, directly or transitively, on an affected member. Uncommitted
+    /// changes and untracked files are included.
+    ///
+    /// Members are listed in dependency order, such that each member follows the members it
+    /// depends on.
+    Affected(WorkspaceAffectedArgs),
+    /// Display the dependency graph of the workspace members.
+    ///
+    /// The graph includes each workspace member, along with its direct dependencies, as declared
+    /// in `project.dependencies`, `project.optional-dependencies`, and `dependency-groups`.
+    /// Dependencies are distinguished by kind: other workspace members, path dependencies (i.e.,
+    /// dependencies with a `path` source in `tool.uv.sources`), and external dependencies.
+    Graph(WorkspaceGraphArgs),
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WorkspaceAffectedArgs {
+    /// The Git revision to compare against, e.g., `origin/main` or a commit hash.
+    #[arg(long, value_name = "REV")]
+    pub since: String,
+
+    /// The format in which to list the affected members.
+    ///
+    /// The `json` format includes the path to each member, relative to the workspace root.
+    #[arg(long, value_enum, default_value_t = AffectedFormat::default())]
+    pub output_format: AffectedFormat,
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WorkspaceGraphArgs {
+    /// The format in which to render the graph.
+    ///
+    /// The `json` format includes the path to each member and path dependency, relative to the
+    /// workspace root.
+    #[arg(long, value_enum, default_value_t = GraphFormat::default())]
+    pub format: GraphFormat,
+}
+
+#[derive(Args)]
+pub struct EnvNamespace {
+    #[command(subcommand)]
+    pub command: EnvCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommand {
+    /// Create a virtual environment on behalf of another tool, and describe it as JSON.
+    ///
+    /// If a virtual environment already exists at the given path and was created from the same
+    /// base interpreter, it's reused as-is. Otherwise, it's (re)created. Packages can then be
+    /// installed into the environment with `uv pip install --python <python>`.
+    CreateFor(EnvCreateForArgs),
+    /// Write a snapshot of the environment markers of a Python interpreter as JSON.
+    ///
+    /// The snapshot can be provided to `uv pip compile --marker-snapshot` on another machine to
+    /// resolve for this interpreter's platform exactly, including markers like `platform_version`
+    /// that `--python-platform` can only approximate.
+    Snapshot(EnvSnapshotArgs),
+}
+
 #[derive(Args, Debug)]
+pub struct EnvCreateForArgs {
+    /// The path to the virtual environment.
+    pub path: PathBuf,
+
+    /// The Python interpreter to use for the virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(long, short)]
+    pub python: Option<String>,
+
+    /// Give the virtual environment access to the system site packages directory.
+    #[arg(long)]
+    pub system_site_packages: bool,
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnvSnapshotArgs {
+    /// The Python interpreter to snapshot.
+    ///
+    /// By default, uv uses the first Python interpreter it would use in the current directory,
+    /// including those in a virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(long, short)]
+    pub python: Option<String>,
+
+    /// Write the snapshot to the given file, rather than to `stdout`.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
     pub package: Vec<PackageName>,
+
+    /// Show the cache entries that would be removed, and how much space would be reclaimed,
+    /// without removing anything.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
-#[derive(Args, Debug)]
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CacheShowArgs {
+    /// The package to show the cache entries for.
+    pub package: PackageName,
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PruneArgs {
     /// Optimize the cache for persistence in a continuous integration environment, like GitHub
     /// Actions.
@@ -866,6 +1229,19 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Display the canonical form of one or more requirements.
+    ///
+    /// Package and extra names are normalized, extras are sorted and deduplicated, redundant
+    /// version specifiers are removed, and markers are simplified, such that equivalent
+    /// requirements produce identical output (e.g., `Foo_Bar[b,a]>=1.0,>=1.2` and `foo-bar[a,b]
+    /// >= 1.2` are both displayed as `foo-bar[a,b]>=1.2`).
+    ///
+    /// Each requirement is displayed on its own line, in the order in which it was provided.
+    #[command(
+        after_help = "Use `uv help pip normalize-requirements` for more details.",
+        after_long_help = ""
+    )]
+    NormalizeRequirements(PipNormalizeRequirementsArgs),
 }
 
 #[derive(Subcommand)]
@@ -996,6 +1372,26 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Lock(LockArgs),
+    /// Watch the project and re-lock it whenever its dependencies change.
+    ///
+    /// Monitors the `pyproject.toml` of each workspace member, along with the workspace `uv.toml`
+    /// (if present), and updates the project's lockfile (`uv.lock`) whenever one of them changes.
+    /// Changes are debounced, such that a burst of edits triggers a single re-lock.
+    ///
+    /// The project is locked once on startup. If locking fails, the error is reported and uv
+    /// continues to wait for further changes.
+    ///
+    /// With `--sync`, the project environment is also synced after each re-lock.
+    ///
+    /// Settings are read once on startup; changes to `[tool.uv]` settings take effect after
+    /// restarting the watcher.
+    ///
+    /// Runs until interrupted (e.g., with Ctrl-C).
+    #[command(
+        after_help = "Use `uv help watch` for more details.",
+        after_long_help = ""
+    )]
+    Watch(WatchArgs),
     /// Export the project's lockfile to an alternate format.
     ///
     /// At present, both `requirements.txt` and `pylock.toml` (PEP 751) formats are supported.
@@ -1013,8 +1409,37 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Bundle the project into a self-contained, relocatable directory.
+    ///
+    /// The bundle includes a managed Python installation, the project and its locked dependencies,
+    /// and launcher scripts for each of the installed entry points, such that it can be deployed
+    /// to a host without Python installed (of the same platform).
+    ///
+    /// The bundle is built entirely from the `uv.lock`; the project is re-locked before bundling
+    /// unless the `--locked` or `--frozen` flag is provided. Development dependencies are excluded
+    /// unless requested via `--group`.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help bundle` for more details.",
+        after_long_help = ""
+    )]
+    Bundle(BundleArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Compare the packages in two lockfiles or environments.
+    ///
+    /// Reports the packages that were added, removed, upgraded, or downgraded between the two,
+    /// along with the direct dependencies through which each changed package is required.
+    ///
+    /// Each side of the comparison can be a `uv.lock` file, a directory containing a `uv.lock`
+    /// file, or a virtual environment.
+    #[command(
+        after_help = "Use `uv help diff` for more details.",
+        after_long_help = ""
+    )]
+    Diff(DiffArgs),
     /// Format Python code in the project.
     ///
     /// Formats Python code using the Ruff formatter. By default, all Python files in the project
@@ -1066,7 +1491,7 @@ fn parse_index_url(input: &str) -> Result<Maybe<PipIndex>, String> {
             })
             .map(PipIndex::from)
             .map(Maybe::Some)
-            .map_err(|err| err.to_string())
+            .map_err(|err| redact_credentials(&err.to_string()).into_owned())
     }
 }
 
@@ -1083,7 +1508,7 @@ fn parse_extra_index_url(input: &str) -> Result<Maybe<PipExtraIndex>, String> {
             })
             .map(PipExtraIndex::from)
             .map(Maybe::Some)
-            .map_err(|err| err.to_string())
+            .map_err(|err| redact_credentials(&err.to_string()).into_owned())
     }
 }
 
@@ -1100,7 +1525,7 @@ fn parse_find_links(input: &str) -> Result<Maybe<PipFindLinks>, String> {
             })
             .map(PipFindLinks::from)
             .map(Maybe::Some)
-            .map_err(|err| err.to_string())
+            .map_err(|err| redact_credentials(&err.to_string()).into_owned())
     }
 }
 
@@ -1121,7 +1546,7 @@ fn parse_indices(input: &str) -> Result<Vec<Maybe<Index>>, String> {
                 origin: Some(Origin::Cli),
                 ..index
             })),
-            Err(e) => return Err(e.to_string()),
+            Err(err) => return Err(redact_credentials(&err.to_string()).into_owned()),
         }
     }
     Ok(indices)
@@ -1138,7 +1563,7 @@ fn parse_default_index(input: &str) -> Result<Maybe<Index>, String> {
                 origin: Some(Origin::Cli),
                 ..index
             })),
-            Err(err) => Err(err.to_string()),
+            Err(err) => Err(redact_credentials(&err.to_string()).into_owned()),
         }
     }
 }
@@ -1284,12 +1709,14 @@ pub struct PipCompileArgs {
 
     /// The format in which the resolution should be output.
     ///
-    /// Supports both `requirements.txt` and `pylock.toml` (PEP 751) output formats.
+    /// Supports both `requirements.txt` and `pylock.toml` (PEP 751) output formats. The
+    /// resolution graph can also be written in Graphviz DOT format (`dot`), with each edge
+    /// labeled by the markers under which the dependency applies.
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
     #[arg(long, value_enum)]
-    pub format: Option<ExportFormat>,
+    pub format: Option<PipCompileFormat>,
 
     /// Include extras in the output file.
     ///
@@ -1329,6 +1756,9 @@ pub struct PipCompileArgs {
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     ///
+    /// Trailing comments on the input requirements (e.g., `flask  # pinned for CI`) are carried
+    /// over to the output file, alongside the annotations for the corresponding package.
+    ///
     /// Defaults to `split`.
     #[arg(long, value_enum)]
     pub annotation_style: Option<AnnotationStyle>,
@@ -1460,6 +1890,19 @@ pub struct PipCompileArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// A snapshot of the environment markers of the target platform, as generated by
+    /// `uv env snapshot`.
+    ///
+    /// By default, the environment markers for the `--python-platform` are approximated from the
+    /// target triple, such that markers like `platform_version` or `platform_release` take generic
+    /// values. When a snapshot is provided, its markers are used instead, such that markers
+    /// evaluate exactly as they will on the target.
+    ///
+    /// The snapshot is a JSON object mapping marker names to values, e.g., as produced by
+    /// `packaging.markers.default_environment()`.
+    #[arg(long, requires = "python_platform")]
+    pub marker_snapshot: Option<PathBuf>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1481,8 +1924,11 @@ pub struct PipCompileArgs {
     pub no_universal: bool,
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
-    /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
-    #[arg(long, alias = "unsafe-package")]
+    /// included in the resolution.
+    ///
+    /// Similar to pip-compile's `--unsafe-package` option, which is also supported, but which can
+    /// be overridden with `--allow-unsafe`.
+    #[arg(long)]
     pub no_emit_package: Option<Vec<PackageName>>,
 
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
@@ -1526,6 +1972,84 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Include comment annotations indicating the license of each package, as reported by its
+    /// metadata (e.g., `# license: MIT`).
+    #[arg(long, overrides_with("no_emit_license"))]
+    pub emit_license: bool,
+
+    #[arg(long, overrides_with("emit_license"), hide = true)]
+    pub no_emit_license: bool,
+
+    /// Include comment annotations indicating the homepage of each package, as reported by its
+    /// metadata (e.g., `# homepage: https://example.com`).
+    #[arg(long, overrides_with("no_emit_homepage"))]
+    pub emit_homepage: bool,
+
+    #[arg(long, overrides_with("emit_homepage"), hide = true)]
+    pub no_emit_homepage: bool,
+
+    /// Include comment annotations indicating the latest version of each package known to the
+    /// index, if it's newer than the resolved version (e.g., `# latest: 2.0.0`).
+    ///
+    /// Only versions that could have been selected are considered, such that yanked versions and
+    /// versions excluded by `--exclude-newer` are skipped. Pre-releases are only considered for
+    /// packages that were resolved to a pre-release.
+    #[arg(long, overrides_with("no_emit_latest_version"))]
+    pub emit_latest_version: bool,
+
+    #[arg(long, overrides_with("emit_latest_version"), hide = true)]
+    pub no_emit_latest_version: bool,
+
+    /// Include a summary comment at the end of the generated output file, with the number of
+    /// resolved packages, split into direct and transitive dependencies, and the number of
+    /// packages resolved from each index.
+    #[arg(long, overrides_with("no_emit_summary"))]
+    pub emit_summary: bool,
+
+    #[arg(long, overrides_with("emit_summary"), hide = true)]
+    pub no_emit_summary: bool,
+
+    /// Include a comment at the end of the generated output file with the markers on which a
+    /// universal resolution forked, along with the versions selected in each fork for any package
+    /// that was resolved to more than one version.
+    #[arg(long, overrides_with("no_emit_fork_annotation"))]
+    pub emit_fork_annotation: bool,
+
+    #[arg(long, overrides_with("emit_fork_annotation"), hide = true)]
+    pub no_emit_fork_annotation: bool,
+
+    /// Include a provenance block in the header of the generated output file, recording the uv
+    /// version, the hashes of the input files, the index URLs (without credentials), the
+    /// `--exclude-newer` cutoff, and the targeted Python range.
+    ///
+    /// The provenance block can be checked against the current inputs with `--verify`.
+    #[arg(long, overrides_with("no_emit_provenance"))]
+    pub emit_provenance: bool,
+
+    #[arg(long, overrides_with("emit_provenance"), hide = true)]
+    pub no_emit_provenance: bool,
+
+    /// Verify that the output file is up-to-date with its inputs, without performing a resolution.
+    ///
+    /// Compares the provenance block in the existing output file (as written with
+    /// `--emit-provenance`) against the current inputs, and exits with a non-zero status if any of
+    /// them have changed.
+    #[arg(long, requires = "output_file")]
+    pub verify: bool,
+
+    /// Resolve as if it were the given date, to reconstruct a historical environment.
+    ///
+    /// Like `--exclude-newer`, limits candidate packages to those that were uploaded prior to the
+    /// given date. Files that have since been yanked are considered available if the index reports
+    /// that they were yanked after the given date; otherwise, they remain yanked.
+    ///
+    /// Takes precedence over `--exclude-newer`.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and local dates in the same
+    /// format (e.g., `2006-12-02`) in your system's configured time zone.
+    #[arg(long)]
+    pub as_of: Option<ExcludeNewerTimestamp>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -1801,6 +2325,17 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Resolve and install as if running on the given platform, for testing.
+    ///
+    /// As with `--python-platform`, uv will select wheels, and prioritize their tags, as if
+    /// running on the given platform (e.g., `x86_64-unknown-linux-gnu`). Unlike
+    /// `--python-platform`, uv will refuse to install a wheel that is incompatible with the
+    /// _current_ platform, unless installing into a `--target` or `--prefix` directory.
+    ///
+    /// Ignored if `--python-platform` is provided.
+    #[arg(long, env = EnvVars::UV_PLATFORM_OVERRIDE)]
+    pub platform_tag: Option<TargetTriple>,
+
     /// Validate the Python environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1814,6 +2349,27 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Allow installing packages that provide the same console or GUI scripts.
+    ///
+    /// By default, uv will error if a package being installed provides a script that is also
+    /// provided by another package in the environment, since the script from one package would
+    /// silently overwrite the other. When enabled, uv will warn instead.
+    #[arg(long)]
+    pub allow_script_conflicts: bool,
+
+    /// Remove the Python source files of installed packages, leaving only their bytecode.
+    ///
+    /// Requires `--compile-bytecode`. Each source file is compiled to a `.pyc` file alongside it,
+    /// after which the source is removed and the package's `RECORD` is updated to match. Sources
+    /// that fail to compile are retained. The bytecode is compiled reproducibly, with a fixed
+    /// `PYTHONHASHSEED` (unless set).
+    ///
+    /// Intended for deployment images in which installation size and start time are critical. The
+    /// resulting bytecode is specific to the Python minor version of the environment, and
+    /// tracebacks will not include source lines.
+    #[arg(long, help_heading = "Installer options")]
+    pub exclude_source: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2113,6 +2669,17 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Resolve and install as if running on the given platform, for testing.
+    ///
+    /// As with `--python-platform`, uv will select wheels, and prioritize their tags, as if
+    /// running on the given platform (e.g., `x86_64-unknown-linux-gnu`). Unlike
+    /// `--python-platform`, uv will refuse to install a wheel that is incompatible with the
+    /// _current_ platform, unless installing into a `--target` or `--prefix` directory.
+    ///
+    /// Ignored if `--python-platform` is provided.
+    #[arg(long, env = EnvVars::UV_PLATFORM_OVERRIDE)]
+    pub platform_tag: Option<TargetTriple>,
+
     /// Do not remove extraneous packages present in the environment.
     #[arg(long, overrides_with("exact"), alias = "no-exact", hide = true)]
     pub inexact: bool,
@@ -2138,6 +2705,39 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Allow installing packages that provide the same console or GUI scripts.
+    ///
+    /// By default, uv will error if a package being installed provides a script that is also
+    /// provided by another package in the environment, since the script from one package would
+    /// silently overwrite the other. When enabled, uv will warn instead.
+    #[arg(long)]
+    pub allow_script_conflicts: bool,
+
+    /// Remove the Python source files of installed packages, leaving only their bytecode.
+    ///
+    /// Requires `--compile-bytecode`. Each source file is compiled to a `.pyc` file alongside it,
+    /// after which the source is removed and the package's `RECORD` is updated to match. Sources
+    /// that fail to compile are retained. The bytecode is compiled reproducibly, with a fixed
+    /// `PYTHONHASHSEED` (unless set).
+    ///
+    /// Intended for deployment images in which installation size and start time are critical. The
+    /// resulting bytecode is specific to the Python minor version of the environment, and
+    /// tracebacks will not include source lines.
+    #[arg(long, help_heading = "Installer options")]
+    pub exclude_source: bool,
+
+    /// Download distributions into a temporary cache alongside the environment, rather than the
+    /// persistent cache.
+    ///
+    /// The temporary cache is placed on the same filesystem as the environment, such that the
+    /// unpacked distributions are linked into the environment rather than stored twice, and is
+    /// removed once the installation completes.
+    ///
+    /// Intended for space-constrained environments, like containers, in which the persistent cache
+    /// would otherwise retain a second copy of every installed package.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_cache_download: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2255,6 +2855,10 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub exclude_editable: bool,
 
+    /// Exclude the specified package(s) from the output.
+    #[arg(long)]
+    pub r#exclude: Vec<PackageName>,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2321,6 +2925,12 @@ pub struct PipListArgs {
     #[arg(long, value_enum, default_value_t = ListFormat::default())]
     pub format: ListFormat,
 
+    /// The key by which to sort the output.
+    ///
+    /// Packages with the same sort key are ordered by name.
+    #[arg(long, value_enum, default_value_t = ListSort::default())]
+    pub sort: ListSort,
+
     /// List outdated packages.
     ///
     /// The latest version of each package will be shown alongside the installed version. Up-to-date
@@ -2331,6 +2941,22 @@ pub struct PipListArgs {
     #[arg(long, overrides_with("outdated"), hide = true)]
     pub no_outdated: bool,
 
+    /// Include the installer metadata of each package in the output.
+    ///
+    /// Shows the tool that installed each package (from the `INSTALLER` file), whether it was
+    /// explicitly requested (from the `REQUESTED` file), and the URL it was installed from (from
+    /// the `direct_url.json` file). With `--format json`, the full contents of `direct_url.json`
+    /// are included.
+    #[arg(long, conflicts_with = "outdated")]
+    pub include_metadata: bool,
+
+    /// Include the installed size of each package in the output.
+    ///
+    /// The size is computed from the files listed in each package's `RECORD`. Packages without a
+    /// `RECORD` (e.g., those installed from `.egg-info` metadata) have no size.
+    #[arg(long)]
+    pub include_size: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2438,6 +3064,28 @@ pub struct PipCheckArgs {
     /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
+
+    /// Validate the integrity of each installed package, in addition to its compatibility.
+    ///
+    /// Detects packages with missing or incomplete `RECORD` files, files that were removed after
+    /// installation, and namespace packages that were damaged by a conflicting install. Each
+    /// issue is reported alongside a suggestion for repairing the environment.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+}
+
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PipNormalizeRequirementsArgs {
+    /// The requirements to normalize, e.g., `requests[socks]>=2.0`.
+    ///
+    /// If no requirements are provided, they're read from stdin, one per line. Empty lines and
+    /// comments (i.e., lines starting with `#`) are ignored.
+    #[arg(value_name = "REQUIREMENT")]
+    pub requirements: Vec<String>,
 }
 
 #[derive(Args)]
@@ -2457,6 +3105,13 @@ pub struct PipShowArgs {
     #[arg(short, long)]
     pub files: bool,
 
+    /// Show the console and GUI scripts provided by each package.
+    ///
+    /// If a script is provided by more than one installed package, the package that currently
+    /// owns the script (i.e., the package that was installed most recently) is shown alongside it.
+    #[arg(long)]
+    pub scripts: bool,
+
     /// The Python interpreter to find the package in.
     ///
     /// By default, uv looks for packages in a virtual environment but will look for packages in a
@@ -2499,6 +3154,14 @@ pub struct PipTreeArgs {
     #[arg(long)]
     pub show_version_specifiers: bool,
 
+    /// Select the output format.
+    ///
+    /// With `--format json`, the version constraint imposed on each package is always included.
+    /// With `--format dot`, the full dependency graph is rendered, and `--depth` and
+    /// `--no-dedupe` have no effect.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub format: TreeFormat,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -2912,7 +3575,9 @@ impl ExternalCommand {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, clap::ValueEnum)]
+#[derive(Debug, Default, Copy, Clone, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum AuthorFrom {
     /// Fetch the author information from some sources (e.g., Git) automatically.
     #[default]
@@ -2923,6 +3588,38 @@ pub enum AuthorFrom {
     None,
 }
 
+/// A template to initialize a project from, as provided to `uv init --template`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitTemplate {
+    /// A library, built with the default build backend.
+    Library,
+    /// An application.
+    Application,
+    /// A packaged application with a command-line entry point.
+    Cli,
+    /// A library with a Rust extension module, built with maturin.
+    Maturin,
+    /// A template in a Git repository, optionally followed by `@` and a branch or tag.
+    Git(String),
+}
+
+impl FromStr for InitTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lib" | "library" => Ok(Self::Library),
+            "app" | "application" => Ok(Self::Application),
+            "cli" => Ok(Self::Cli),
+            "maturin" => Ok(Self::Maturin),
+            _ if s.contains("://") || s.starts_with("git@") => Ok(Self::Git(s.to_string())),
+            _ => Err(format!(
+                "`{s}` is not a built-in template (`lib`, `app`, `cli`, or `maturin`) or a Git URL"
+            )),
+        }
+    }
+}
+
 #[derive(Args)]
 pub struct InitArgs {
     /// The path to use for the project/script.
@@ -3004,6 +3701,33 @@ pub struct InitArgs {
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Create a project from a template.
+    ///
+    /// The built-in templates are:
+    ///
+    /// - `lib`: A library, built with the default build backend.
+    /// - `app`: An application.
+    /// - `cli`: A packaged application with a `[project.scripts]` entry point.
+    /// - `maturin`: A library with a Rust extension module, built with maturin.
+    ///
+    /// Alternatively, provide the URL of a Git repository (e.g.,
+    /// `https://github.com/org/template` or `git@github.com:org/template.git`), optionally
+    /// followed by `@` and a branch or tag. The files in the repository are copied into the
+    /// project, with any `{{ project_name }}`, `{{ module_name }}`, and `{{ requires_python }}`
+    /// placeholders in file contents and paths replaced.
+    ///
+    /// Unless `--no-lock` is provided, the project is locked after it's created.
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with_all = ["app", "lib", "script", "bare", "no_package", "build_backend"]
+    )]
+    pub template: Option<InitTemplate>,
+
+    /// Do not lock the project after creating it from a template.
+    #[arg(long, requires = "template")]
+    pub no_lock: bool,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description")]
     pub description: Option<String>,
@@ -3255,9 +3979,23 @@ pub struct RunArgs {
     ///
     /// When used with `--with` or `--with-requirements`, the additional dependencies will still be
     /// layered in a second environment.
+    ///
+    /// The isolated environment is removed when the command exits. If uv is terminated before it
+    /// can clean up, the environment is removed by the next uv invocation that creates an isolated
+    /// environment, or by `uv cache prune`.
     #[arg(long, env = EnvVars::UV_ISOLATED, value_parser = clap::builder::BoolishValueParser::new())]
     pub isolated: bool,
 
+    /// Retain any ephemeral environment created for the command after it exits (e.g., for
+    /// debugging).
+    ///
+    /// Applies to the environment created with `--isolated`, the environment layered atop the
+    /// project with `--with`, and the environment created for a script with inline metadata. The
+    /// path to each retained environment is displayed when it's created; uv does not remove it
+    /// later, including via `uv cache prune`.
+    #[arg(long)]
+    pub keep: bool,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -3294,6 +4032,20 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Apply the named resolution profile, as defined in `tool.uv.profiles`.
+    ///
+    /// A profile can add indexes, dependency overrides, and supported environments on top of the
+    /// project's configuration, e.g., to switch between CPU and CUDA builds of a dependency.
+    #[arg(long, env = EnvVars::UV_PROFILE, value_name = "PROFILE", conflicts_with = "script")]
+    pub profile: Option<String>,
+
+    /// Run in the named environment, as defined in `tool.uv.named-environments`.
+    ///
+    /// Named environments are created at `.venvs/<name>` in the workspace root, and can use a
+    /// different Python version, extras, and dependency groups than the project environment.
+    #[arg(long, value_name = "ENV", conflicts_with = "script")]
+    pub env: Option<String>,
+
     /// Run the given path as a Python script.
     ///
     /// Using `--script` will attempt to parse the path as a PEP 723 script,
@@ -3329,8 +4081,33 @@ pub struct RunArgs {
     /// Run the command in a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
+    ///
+    /// With `--each`, may be provided multiple times to run the command in a subset of the
+    /// workspace members.
     #[arg(long, conflicts_with = "all_packages")]
-    pub package: Option<PackageName>,
+    pub package: Vec<PackageName>,
+
+    /// Run the command in each workspace member, rather than once.
+    ///
+    /// The command is run from the root directory of each member, in dependency order: a member's
+    /// command is started once the commands of all of the workspace members it depends on have
+    /// succeeded. If a command fails, the members that depend on it are skipped. Members that
+    /// don't depend on each other are run concurrently, up to the limit set by
+    /// `UV_CONCURRENT_BUILDS`.
+    ///
+    /// The environment is synced with all workspace members installed, as with `--all-packages`.
+    /// Use `--package` to run the command in specific members, or `--affected` to run the command
+    /// in the members affected by a change set.
+    #[arg(long, conflicts_with = "no_project")]
+    pub each: bool,
+
+    /// Only run the command in the workspace members affected by changes since the given Git
+    /// revision.
+    ///
+    /// A member is affected if any of its files have changed since the revision, including
+    /// uncommitted and untracked files, or if it depends on an affected member.
+    #[arg(long, requires = "each", value_name = "REV")]
+    pub affected: Option<String>,
 
     /// Avoid discovering the project or workspace.
     ///
@@ -3565,6 +4342,19 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Do not reinstall the current project if it's already installed.
+    ///
+    /// By default, the project is rebuilt and reinstalled whenever its metadata changes (e.g., an
+    /// edit to its `pyproject.toml`, or to any other `tool.uv.cache-keys`). With
+    /// `--no-reinstall-project`, an existing installation of the project is retained as-is, while
+    /// its dependencies are still synced. The project is installed if it's missing from the
+    /// environment, or if it was installed from a different source.
+    ///
+    /// Takes precedence over `--reinstall`, but not over `--reinstall-package` for the project
+    /// itself.
+    #[arg(long, conflicts_with = "no_install_project")]
+    pub no_reinstall_project: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
@@ -3581,6 +4371,28 @@ pub struct SyncArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Verify the integrity hash and signature of the `uv.lock` before syncing.
+    ///
+    /// Asserts that the lockfile includes an integrity hash (as written by `uv lock --integrity`),
+    /// and that the lockfile has not been modified since the hash was computed. If
+    /// `UV_LOCK_SIGNING_KEY` is set, also asserts that the detached signature (`uv.lock.sig`)
+    /// was produced with the same key.
+    ///
+    /// Verification is performed before any packages are installed. Implies `--locked`, unless
+    /// `--frozen` is provided, such that the verified lockfile is used as-is.
+    #[arg(long, conflicts_with = "upgrade")]
+    pub verify_lock_signature: bool,
+
+    /// Install packages into a shared store, and symlink them into the project environment.
+    ///
+    /// Each version of a package is stored once in the given directory, and linked into every
+    /// environment that uses it, which can significantly reduce disk usage across many projects.
+    /// Packages that are no longer used by any environment can be removed with `uv store gc`.
+    ///
+    /// Requires `--link-mode=symlink`.
+    #[arg(long, env = EnvVars::UV_SITE_PACKAGES_DIR)]
+    pub site_packages_dir: Option<PathBuf>,
+
     /// Perform a dry run, without writing the lockfile or modifying the project environment.
     ///
     /// In dry-run mode, uv will resolve the project's dependencies and report on the resulting
@@ -3588,6 +4400,27 @@ pub struct SyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Allow installing packages that provide the same console or GUI scripts.
+    ///
+    /// By default, uv will error if a package being installed provides a script that is also
+    /// provided by another package in the environment, since the script from one package would
+    /// silently overwrite the other. When enabled, uv will warn instead.
+    #[arg(long)]
+    pub allow_script_conflicts: bool,
+
+    /// Remove the Python source files of installed packages, leaving only their bytecode.
+    ///
+    /// Requires `--compile-bytecode`. Each source file is compiled to a `.pyc` file alongside it,
+    /// after which the source is removed and the package's `RECORD` is updated to match. Sources
+    /// that fail to compile are retained. The bytecode is compiled reproducibly, with a fixed
+    /// `PYTHONHASHSEED` (unless set).
+    ///
+    /// Intended for deployment images in which installation size and start time are critical. The
+    /// resulting bytecode is specific to the Python minor version of the environment, and
+    /// tracebacks will not include source lines.
+    #[arg(long, help_heading = "Installer options")]
+    pub exclude_source: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3641,6 +4474,36 @@ pub struct SyncArgs {
     )]
     pub script: Option<PathBuf>,
 
+    /// Apply the named resolution profile, as defined in `tool.uv.profiles`.
+    ///
+    /// A profile can add indexes, dependency overrides, and supported environments on top of the
+    /// project's configuration, e.g., to switch between CPU and CUDA builds of a dependency.
+    #[arg(long, env = EnvVars::UV_PROFILE, value_name = "PROFILE", conflicts_with = "script")]
+    pub profile: Option<String>,
+
+    /// Resolve as if it were the given date, to reconstruct a historical environment.
+    ///
+    /// Like `--exclude-newer`, limits candidate packages to those that were uploaded prior to the
+    /// given date. Files that have since been yanked are considered available if the index reports
+    /// that they were yanked after the given date; otherwise, they remain yanked.
+    ///
+    /// Takes precedence over `--exclude-newer`. If the lockfile is updated, the date is recorded
+    /// in the lockfile as `as-of`, and is retained by subsequent commands unless a different cutoff
+    /// is provided.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and local dates in the same
+    /// format (e.g., `2006-12-02`) in your system's configured time zone.
+    #[arg(long)]
+    pub as_of: Option<ExcludeNewerTimestamp>,
+
+    /// Sync the named environment, as defined in `tool.uv.named-environments`.
+    ///
+    /// Named environments are created at `.venvs/<name>` in the workspace root, and can use a
+    /// different Python version, extras, and dependency groups than the project environment, while
+    /// sharing the project's lockfile.
+    #[arg(long, value_name = "ENV", conflicts_with = "script")]
+    pub env: Option<String>,
+
     /// The Python interpreter to use for the project environment.
     ///
     /// By default, the first interpreter that meets the project's `requires-python` constraint is
@@ -3718,6 +4581,36 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
     pub dry_run: bool,
 
+    /// Resolve Git merge conflicts in the lockfile.
+    ///
+    /// If the `uv.lock` contains merge conflict markers, uv will parse both sides of the conflict,
+    /// retain the packages on which the two sides agree (or which only one side changed), and
+    /// re-resolve the packages that were changed on both sides.
+    ///
+    /// If the lockfile does not contain any conflict markers, this option has no effect.
+    #[arg(long, conflicts_with_all = ["check_exists", "check", "merge"])]
+    pub resolve_merge_conflicts: bool,
+
+    /// Merge two versions of a lockfile, for use as a Git merge driver.
+    ///
+    /// Accepts the paths to the current version, the common ancestor, and the other version of
+    /// the lockfile, in that order. The merged lockfile is written to the path of the current
+    /// version.
+    ///
+    /// Packages are merged without performing a resolution. If both versions changed the same
+    /// package, the conflicting versions are written with conflict markers, which can be resolved
+    /// with `uv lock --resolve-merge-conflicts`.
+    ///
+    /// To use uv as the merge driver for `uv.lock`, add `uv.lock merge=uv` to `.gitattributes`
+    /// and run `git config merge.uv.driver "uv lock --merge %A %O %B"`.
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["CURRENT", "BASE", "OTHER"],
+        conflicts_with_all = ["check_exists", "check", "dry_run", "script"]
+    )]
+    pub merge: Option<Vec<PathBuf>>,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3725,6 +4618,38 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Apply the named resolution profile, as defined in `tool.uv.profiles`.
+    ///
+    /// A profile can add indexes, dependency overrides, and supported environments on top of the
+    /// project's configuration, e.g., to switch between CPU and CUDA builds of a dependency.
+    #[arg(long, env = EnvVars::UV_PROFILE, value_name = "PROFILE", conflicts_with = "script")]
+    pub profile: Option<String>,
+
+    /// Resolve as if it were the given date, to reconstruct a historical environment.
+    ///
+    /// Like `--exclude-newer`, limits candidate packages to those that were uploaded prior to the
+    /// given date. Files that have since been yanked are considered available if the index reports
+    /// that they were yanked after the given date; otherwise, they remain yanked.
+    ///
+    /// Takes precedence over `--exclude-newer`. The date is recorded in the lockfile as `as-of`, and
+    /// is retained by subsequent commands unless a different cutoff is provided.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and local dates in the same
+    /// format (e.g., `2006-12-02`) in your system's configured time zone.
+    #[arg(long)]
+    pub as_of: Option<ExcludeNewerTimestamp>,
+
+    /// Embed an integrity hash of the lockfile contents in the `uv.lock`.
+    ///
+    /// The hash allows deployment systems to detect lockfiles that were modified outside of uv
+    /// (e.g., with `uv sync --verify-lock-signature`). Once added, the hash is retained and
+    /// updated whenever uv writes the lockfile.
+    ///
+    /// If `UV_LOCK_SIGNING_KEY` is set, uv will also write a detached signature of the hash to
+    /// `uv.lock.sig`.
+    #[arg(long, conflicts_with_all = ["check_exists", "check", "dry_run"])]
+    pub integrity: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3754,6 +4679,43 @@ pub struct LockArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Sync the project environment after each re-lock.
+    ///
+    /// The environment is synced with the default extras and dependency groups, as in `uv sync`.
+    #[arg(long)]
+    pub sync: bool,
+
+    /// The duration to wait for further changes before re-locking, in milliseconds.
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 500)]
+    pub debounce: u64,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// With `--sync`, the interpreter is also used for the project virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct AddArgs {
@@ -3779,6 +4741,9 @@ pub struct AddArgs {
     pub constraints: Vec<Maybe<PathBuf>>,
 
     /// Apply this marker to all added packages.
+    ///
+    /// If a package is already declared without a matching marker, the existing marker is
+    /// replaced, rather than adding a second entry for the package.
     #[arg(long, short, value_parser = MarkerTree::from_str)]
     pub marker: Option<MarkerTree>,
 
@@ -4019,6 +4984,14 @@ pub struct RemoveArgs {
     )]
     pub group: Option<GroupName>,
 
+    /// Remove the environment markers from the dependencies, rather than removing the
+    /// dependencies themselves.
+    ///
+    /// For example, `uv remove --marker-only numpy` rewrites `numpy; sys_platform == 'win32'` as
+    /// `numpy`.
+    #[arg(long)]
+    pub marker_only: bool,
+
     /// Avoid syncing the virtual environment after re-locking the project.
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
@@ -4083,6 +5056,19 @@ pub struct RemoveArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct DiffArgs {
+    /// The lockfile or environment to compare against.
+    pub old: PathBuf,
+
+    /// The lockfile or environment to compare.
+    pub new: PathBuf,
+
+    /// The format in which to display the differences.
+    #[arg(long, value_enum, default_value_t = DiffFormat::default())]
+    pub output_format: DiffFormat,
+}
+
 #[derive(Args)]
 pub struct TreeArgs {
     /// Show a platform-independent dependency tree.
@@ -4449,6 +5435,82 @@ pub struct ExportArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct BundleArgs {
+    /// The directory in which to create the bundle.
+    ///
+    /// Defaults to `bundle` in the project root. Any existing bundle in the directory will be
+    /// replaced.
+    #[arg(long, short)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Additionally write the bundle to a `.tar.gz` archive alongside the output directory.
+    #[arg(long)]
+    pub archive: bool,
+
+    /// Bundle a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// Unlike `uv sync`, no dependency groups are included by default.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub group: Vec<GroupName>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before bundling.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python version to include in the bundle.
+    ///
+    /// Only managed Python installations can be bundled; if a matching managed installation is not
+    /// found, uv will attempt to download one.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct FormatArgs {
     /// Check if files are formatted without applying changes.
@@ -4575,8 +5637,24 @@ pub enum ToolCommand {
     /// List installed tools.
     #[command(alias = "ls")]
     List(ToolListArgs),
+    /// Show the receipt and installed packages for a tool.
+    ///
+    /// The receipt records how the tool environment was created, including the requested
+    /// requirements, any additional requirements (e.g., from `--with`), the Python version, and the
+    /// resolver and index settings. The receipt is used to recreate the environment on `uv tool
+    /// upgrade`.
+    Show(ToolShowArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Check installed tools for broken environments and executables.
+    ///
+    /// Each tool's environment is validated, including its Python interpreter, which may have been
+    /// removed since the tool was installed (e.g., by a system package manager or `uv python
+    /// uninstall`). Each tool's executables are validated, too.
+    ///
+    /// If any tool is broken, uv will exit with an error. Use `--repair` to recreate broken tool
+    /// environments from the settings with which they were originally installed.
+    Check(ToolCheckArgs),
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -4890,6 +5968,29 @@ pub struct ToolListArgs {
     pub no_python_downloads: bool,
 }
 
+#[derive(Args)]
+pub struct ToolShowArgs {
+    /// The name of the tool to show.
+    pub name: PackageName,
+
+    // Hide unused global Python options.
+    #[arg(long, hide = true)]
+    pub python_preference: Option<PythonPreference>,
+
+    #[arg(long, hide = true)]
+    pub no_python_downloads: bool,
+}
+
+#[derive(Args)]
+pub struct ToolCheckArgs {
+    /// Recreate the environments of any broken tools.
+    ///
+    /// Environments are recreated from the tool receipt, respecting the requirements, Python
+    /// version, and settings with which each tool was originally installed.
+    #[arg(long)]
+    pub repair: bool,
+}
+
 #[derive(Args)]
 pub struct ToolDirArgs {
     /// Show the directory into which `uv tool` will install executables.
@@ -4966,6 +6067,15 @@ pub struct ToolUpgradeArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Drop any additional requirements (e.g., those provided via `--with` at install time) that
+    /// conflict with the upgraded tool.
+    ///
+    /// By default, the upgrade fails if the tool's additional requirements conflict with the
+    /// latest version of the tool. With `--relax-with`, the minimal set of conflicting
+    /// requirements is removed from the tool's environment and receipt instead.
+    #[arg(long)]
+    pub relax_with: bool,
+
     // The following is equivalent to flattening `ResolverInstallerArgs`, with the `--upgrade`, and
     // `--upgrade-package` options hidden, and the `--no-upgrade` option removed.
     /// Allow package upgrades, ignoring pinned versions in any existing output file. Implies
@@ -5329,6 +6439,13 @@ pub struct PythonListArgs {
     #[arg(long, alias = "all_architectures")]
     pub all_arches: bool,
 
+    /// List installed Python versions from all discovery sources, along with their source.
+    ///
+    /// By default, an installation that's discovered from multiple sources (e.g., from the `PATH`
+    /// and from a pyenv or asdf installation directory) is only shown once.
+    #[arg(long, conflicts_with("only_downloads"))]
+    pub all_sources: bool,
+
     /// Only show installed Python versions.
     ///
     /// By default, installed distributions and available downloads for the current platform are shown.
@@ -5483,6 +6600,22 @@ pub struct PythonInstallArgs {
     /// If multiple Python versions are requested, uv will exit with an error.
     #[arg(long, conflicts_with("no_bin"))]
     pub default: bool,
+
+    /// Install pip into the Python installation with `ensurepip`.
+    ///
+    /// Some distributions (e.g., PyPy) do not include pip by default.
+    #[arg(long)]
+    pub ensurepip: bool,
+
+    /// Check that the system libraries required by the Python installation are present.
+    ///
+    /// Some standard library modules (e.g., `tkinter`) depend on shared libraries provided by the
+    /// host system. If any are missing, uv will report them, along with a command to install them
+    /// with the system package manager, if known, and exit with an error.
+    ///
+    /// Only supported on Linux.
+    #[arg(long)]
+    pub check_libraries: bool,
 }
 
 #[derive(Args)]
@@ -5599,6 +6732,29 @@ pub struct PythonFindArgs {
     /// Show the Python version that would be used instead of the path to the interpreter.
     #[arg(long)]
     pub show_version: bool,
+
+    /// Show the base interpreter of a virtual environment, rather than the environment's
+    /// interpreter.
+    ///
+    /// When the discovered interpreter belongs to a virtual environment, the path of the
+    /// interpreter that the environment was created from (i.e., `sys._base_executable`) is shown
+    /// instead. Has no effect for interpreters outside of a virtual environment.
+    #[arg(long, conflicts_with = "show_version")]
+    pub base: bool,
+
+    /// Resolve symbolic links in the path to the interpreter.
+    ///
+    /// By default, the path is shown as discovered, which may be a symbolic link, e.g., to a
+    /// versioned executable or to a managed Python installation.
+    #[arg(long, conflicts_with = "show_version")]
+    pub resolve_symlinks: bool,
+
+    /// Select the output format.
+    ///
+    /// The JSON format includes the path, version, implementation, and platform of the
+    /// interpreter, using the same fields as `uv python list --output-format json`.
+    #[arg(long, value_enum, default_value_t = PythonFindFormat::default(), conflicts_with = "show_version")]
+    pub output_format: PythonFindFormat,
 }
 
 #[derive(Args)]
@@ -5729,6 +6885,22 @@ pub struct AuthDirArgs {
     pub service: Option<Service>,
 }
 
+#[derive(Args)]
+pub struct GenerateSchemaArgs {
+    /// The kind of schema to generate.
+    #[arg(long, value_enum, default_value_t = SchemaKind::default())]
+    pub kind: SchemaKind,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum SchemaKind {
+    /// The settings in a `uv.toml` file or the `[tool.uv]` section of a `pyproject.toml`.
+    #[default]
+    Config,
+    /// The output of `--show-settings --show-settings-format json`, one line per settings type.
+    ShowSettings,
+}
+
 #[derive(Args)]
 pub struct GenerateShellCompletionArgs {
     /// The shell to generate the completion script for
@@ -5784,7 +6956,7 @@ pub struct IndexArgs {
     // The nested Vec structure (`Vec<Vec<Maybe<Index>>>`) is required for clap's
     // value parsing mechanism, which processes one value at a time, in order to handle
     // `UV_INDEX` the same way pip handles `PIP_EXTRA_INDEX_URL`.
-    #[arg(long, env = EnvVars::UV_INDEX, value_parser = parse_indices, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_INDEX, hide_env_values = true, value_parser = parse_indices, help_heading = "Index options")]
     pub index: Option<Vec<Vec<Maybe<Index>>>>,
 
     /// The URL of the default package index (by default: <https://pypi.org/simple>).
@@ -5794,7 +6966,7 @@ pub struct IndexArgs {
     ///
     /// The index given by this flag is given lower priority than all other indexes specified via
     /// the `--index` flag.
-    #[arg(long, env = EnvVars::UV_DEFAULT_INDEX, value_parser = parse_default_index, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_DEFAULT_INDEX, hide_env_values = true, value_parser = parse_default_index, help_heading = "Index options")]
     pub default_index: Option<Maybe<Index>>,
 
     /// (Deprecated: use `--default-index` instead) The URL of the Python package index (by default:
@@ -5805,7 +6977,7 @@ pub struct IndexArgs {
     ///
     /// The index given by this flag is given lower priority than all other indexes specified via
     /// the `--extra-index-url` flag.
-    #[arg(long, short, env = EnvVars::UV_INDEX_URL, value_parser = parse_index_url, help_heading = "Index options")]
+    #[arg(long, short, env = EnvVars::UV_INDEX_URL, hide_env_values = true, value_parser = parse_index_url, help_heading = "Index options")]
     pub index_url: Option<Maybe<PipIndex>>,
 
     /// (Deprecated: use `--index` instead) Extra URLs of package indexes to use, in addition to
@@ -5817,7 +6989,7 @@ pub struct IndexArgs {
     /// All indexes provided via this flag take priority over the index specified by `--index-url`
     /// (which defaults to PyPI). When multiple `--extra-index-url` flags are provided, earlier
     /// values take priority.
-    #[arg(long, env = EnvVars::UV_EXTRA_INDEX_URL, value_delimiter = ' ', value_parser = parse_extra_index_url, help_heading = "Index options")]
+    #[arg(long, env = EnvVars::UV_EXTRA_INDEX_URL, hide_env_values = true, value_delimiter = ' ', value_parser = parse_extra_index_url, help_heading = "Index options")]
     pub extra_index_url: Option<Vec<Maybe<PipExtraIndex>>>,
 
     /// Locations to search for candidate distributions, in addition to those found in the registry
@@ -5832,6 +7004,7 @@ pub struct IndexArgs {
         long,
         short,
         env = EnvVars::UV_FIND_LINKS,
+        hide_env_values = true,
         value_delimiter = ',',
         value_parser = parse_find_links,
         help_heading = "Index options"
@@ -5840,7 +7013,12 @@ pub struct IndexArgs {
 
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// provided via `--find-links`.
-    #[arg(long, help_heading = "Index options")]
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_INDEX,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Index options"
+    )]
     pub no_index: bool,
 }
 
@@ -5867,6 +7045,14 @@ pub struct RefreshArgs {
     /// Refresh cached data for a specific package.
     #[arg(long, help_heading = "Cache options")]
     pub refresh_package: Vec<PackageName>,
+
+    /// Refresh cached data for a specific direct URL dependency.
+    ///
+    /// Remote archives that are pinned to a hash (e.g., via a `#sha256=...` fragment, or a
+    /// `uv.lock`) are treated as immutable, and are never revalidated once cached; this option
+    /// forces uv to download the archive again.
+    #[arg(long, value_name = "URL", help_heading = "Cache options")]
+    pub refresh_url: Vec<DisplaySafeUrl>,
 }
 
 #[derive(Args)]
@@ -6009,6 +7195,12 @@ pub struct InstallerArgs {
     )]
     pub no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    #[arg(long, help_heading = "Build options")]
+    pub no_build_isolation_package: Vec<PackageName>,
+
     #[arg(
         long,
         overrides_with("no_build_isolation"),
@@ -6189,6 +7381,21 @@ pub struct ResolverArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// Allow distributions for which the index only provides MD5 hashes.
+    ///
+    /// By default, uv refuses to lock (or generate hashes for) a distribution when the index only
+    /// provides an MD5 hash for it, as MD5 is not collision-resistant.
+    ///
+    /// WARNING: MD5 hashes do not protect against tampered distributions. Only use
+    /// `--allow-insecure-md5-hashes` with indexes you trust.
+    #[arg(
+        long,
+        env = EnvVars::UV_INSECURE_MD5_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub allow_insecure_md5_hashes: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6400,6 +7607,21 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// Allow distributions for which the index only provides MD5 hashes.
+    ///
+    /// By default, uv refuses to lock (or generate hashes for) a distribution when the index only
+    /// provides an MD5 hash for it, as MD5 is not collision-resistant.
+    ///
+    /// WARNING: MD5 hashes do not protect against tampered distributions. Only use
+    /// `--allow-insecure-md5-hashes` with indexes you trust.
+    #[arg(
+        long,
+        env = EnvVars::UV_INSECURE_MD5_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub allow_insecure_md5_hashes: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6589,7 +7811,8 @@ pub struct DisplayTreeArgs {
     pub show_sizes: bool,
 }
 
-#[derive(Args, Debug)]
+#[derive(Args, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PublishArgs {
     /// Paths to the files to upload. Accepts glob expressions.
     ///
@@ -6700,6 +7923,17 @@ pub struct PublishArgs {
     /// and will perform validation against the index if supported, but will not upload any files.
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Check the files for problems that would cause the upload to fail, without uploading them.
+    ///
+    /// Validates the metadata version, the rendering of the long description, the classifiers,
+    /// the wheel tags and the file sizes, applying the additional rules of PyPI when publishing
+    /// to PyPI or TestPyPI. If a check URL is provided (or inferred for PyPI), also checks whether
+    /// the project name is available and whether the files already exist on the index.
+    ///
+    /// Unlike `--dry-run`, no credentials are required.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub check: bool,
 }
 
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
@@ -43,9 +43,14 @@ impl From<RefreshArgs> for Refresh {
             refresh,
             no_refresh,
             refresh_package,
+            refresh_url,
         } = value;
 
-        Self::from_args(flag(refresh, no_refresh, "no-refresh"), refresh_package)
+        Self::from_args(
+            flag(refresh, no_refresh, "no-refresh"),
+            refresh_package,
+            refresh_url,
+        )
     }
 }
 
@@ -62,6 +67,7 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            allow_insecure_md5_hashes,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -80,6 +86,11 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            allow_insecure_md5_hashes: if allow_insecure_md5_hashes {
+                Some(true)
+            } else {
+                None
+            },
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -115,6 +126,7 @@ impl From<InstallerArgs> for PipOptions {
             config_setting,
             config_settings_package,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
             exclude_newer,
             link_mode,
@@ -137,6 +149,7 @@ impl From<InstallerArgs> for PipOptions {
                     .collect::<PackageConfigSettings>()
             }),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
+            no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
             link_mode,
@@ -163,6 +176,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            allow_insecure_md5_hashes,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -190,6 +204,11 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            allow_insecure_md5_hashes: if allow_insecure_md5_hashes {
+                Some(true)
+            } else {
+                None
+            },
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -284,6 +303,7 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        allow_insecure_md5_hashes,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -334,6 +354,7 @@ pub fn resolver_options(
                 .filter_map(Maybe::into_option)
                 .collect()
         }),
+        index_namespaces: None,
         upgrade: Upgrade::from_args(
             flag(upgrade, no_upgrade, "no-upgrade"),
             upgrade_package.into_iter().map(Requirement::from).collect(),
@@ -347,6 +368,11 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        allow_insecure_md5_hashes: if allow_insecure_md5_hashes {
+            Some(true)
+        } else {
+            None
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -393,6 +419,7 @@ pub fn resolver_installer_options(
         prerelease,
         pre,
         fork_strategy,
+        allow_insecure_md5_hashes,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -447,6 +474,7 @@ pub fn resolver_installer_options(
                 .filter_map(Maybe::into_option)
                 .collect()
         }),
+        index_namespaces: None,
         upgrade: Upgrade::from_args(
             flag(upgrade, no_upgrade, "upgrade"),
             upgrade_package.into_iter().map(Requirement::from).collect(),
@@ -464,6 +492,11 @@ pub fn resolver_installer_options(
             prerelease
         },
         fork_strategy,
+        allow_insecure_md5_hashes: if allow_insecure_md5_hashes {
+            Some(true)
+        } else {
+            None
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -480,6 +513,8 @@ pub fn resolver_installer_options(
         extra_build_variables: None,
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+        exclude_newer_than_days: None,
+        exclude_newer_than_days_package: None,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
         no_build: flag(no_build, build, "build"),
@@ -8,6 +8,8 @@ use std::time::Duration;
 use std::{env, io, iter};
 
 use anyhow::anyhow;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
 use http::{
     HeaderMap, HeaderName, HeaderValue, Method, StatusCode,
     header::{
@@ -29,8 +31,8 @@ use url::ParseError;
 use url::Url;
 
 use uv_auth::{AuthMiddleware, Credentials, Indexes, PyxTokenStore};
-use uv_configuration::{KeyringProviderType, TrustedHost};
-use uv_fs::Simplified;
+use uv_configuration::{KeyringProviderType, RateLimit, TrustedHost};
+use uv_fs::{Quarantine, Simplified};
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
 use uv_preview::Preview;
@@ -40,9 +42,10 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
+use crate::middleware::{MirrorMiddleware, OfflineMiddleware};
+use crate::rate_limit::RateLimiter;
 use crate::tls::read_identity;
-use crate::{Connectivity, WrappedReqwestError};
+use crate::{Connectivity, MirrorManifest, WrappedReqwestError};
 
 /// Do not use this value directly outside tests, use [`retries_from_env`] instead.
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -89,6 +92,14 @@ pub struct BaseClientBuilder<'a> {
     cross_origin_credential_policy: CrossOriginCredentialsPolicy,
     /// Optional custom reqwest client to use instead of creating a new one.
     custom_client: Option<Client>,
+    /// The maximum bandwidth to use for downloads.
+    rate_limit: Option<RateLimit>,
+    /// Whether the connection is metered, such that large optional downloads should be avoided.
+    metered: bool,
+    /// The manifest mapping upstream URLs to internal mirrors.
+    mirror_manifest: Option<Arc<MirrorManifest>>,
+    /// How to treat the quarantine attributes of downloaded files.
+    quarantine: Quarantine,
 }
 
 /// The policy for handling HTTP redirects.
@@ -143,6 +154,10 @@ impl Default for BaseClientBuilder<'_> {
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
             custom_client: None,
+            rate_limit: None,
+            metered: false,
+            mirror_manifest: None,
+            quarantine: Quarantine::default(),
         }
     }
 }
@@ -209,6 +224,30 @@ impl<'a> BaseClientBuilder<'a> {
         Ok(self)
     }
 
+    #[must_use]
+    pub fn rate_limit(mut self, rate_limit: Option<RateLimit>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    #[must_use]
+    pub fn metered(mut self, metered: bool) -> Self {
+        self.metered = metered;
+        self
+    }
+
+    #[must_use]
+    pub fn mirror_manifest(mut self, mirror_manifest: Option<Arc<MirrorManifest>>) -> Self {
+        self.mirror_manifest = mirror_manifest;
+        self
+    }
+
+    #[must_use]
+    pub fn quarantine(mut self, quarantine: Quarantine) -> Self {
+        self.quarantine = quarantine;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -343,6 +382,10 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_dangerous_client,
             timeout,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            metered: self.metered,
+            mirror_manifest: self.mirror_manifest.clone(),
+            quarantine: self.quarantine,
         }
     }
 
@@ -369,6 +412,10 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
             timeout: existing.timeout,
+            rate_limiter: existing.rate_limiter.clone(),
+            metered: existing.metered,
+            mirror_manifest: self.mirror_manifest.clone(),
+            quarantine: self.quarantine,
         }
     }
 
@@ -500,6 +547,12 @@ impl<'a> BaseClientBuilder<'a> {
 
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
+                // Redirect requests to any configured mirrors, such that the remaining middleware
+                // (e.g., authentication) applies to the mirror.
+                if let Some(mirror_manifest) = &self.mirror_manifest {
+                    client = client.with(MirrorMiddleware(mirror_manifest.clone()));
+                }
+
                 // Avoid uncloneable errors with a streaming body during publish.
                 if self.retries > 0 {
                     // Initialize the retry strategy.
@@ -575,6 +628,14 @@ pub struct BaseClient {
     allow_insecure_host: Vec<TrustedHost>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
+    /// The rate limiter shared by all downloads, if a bandwidth limit is configured.
+    rate_limiter: Option<RateLimiter>,
+    /// Whether the connection is metered.
+    metered: bool,
+    /// The manifest mapping upstream URLs to internal mirrors.
+    mirror_manifest: Option<Arc<MirrorManifest>>,
+    /// How to treat the quarantine attributes of downloaded files.
+    quarantine: Quarantine,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -622,6 +683,34 @@ impl BaseClient {
     pub fn retry_policy(&self) -> ExponentialBackoff {
         ExponentialBackoff::builder().build_with_max_retries(self.retries)
     }
+
+    /// Returns `true` if the connection is metered.
+    pub fn is_metered(&self) -> bool {
+        self.metered
+    }
+
+    /// The manifest mapping upstream URLs to internal mirrors, if any.
+    pub fn mirror_manifest(&self) -> Option<&MirrorManifest> {
+        self.mirror_manifest.as_deref()
+    }
+
+    /// How to treat the quarantine attributes of downloaded files, e.g., Python installations.
+    pub fn quarantine(&self) -> Quarantine {
+        self.quarantine
+    }
+
+    /// Apply the configured bandwidth limit, if any, to a stream of downloaded bytes.
+    pub fn throttle<S, T, E>(&self, stream: S) -> BoxStream<'static, Result<T, E>>
+    where
+        S: Stream<Item = Result<T, E>> + Send + 'static,
+        T: AsRef<[u8]> + Send + 'static,
+        E: Send + 'static,
+    {
+        match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.clone().throttle(stream).boxed(),
+            None => stream.boxed(),
+        }
+    }
 }
 
 /// Wrapper around [`ClientWithMiddleware`] that manages redirects.
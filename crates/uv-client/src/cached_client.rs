@@ -9,7 +9,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 
-use uv_cache::{CacheEntry, Freshness};
+use uv_cache::{CacheEntry, Freshness, IndexCachePolicy};
 use uv_fs::write_atomic;
 use uv_redacted::DisplaySafeUrl;
 
@@ -197,6 +197,9 @@ pub enum CacheControl<'a> {
     AllowStale,
     /// Override the cache control header with a custom value.
     Override(&'a str),
+    /// Consider cached responses fresh for the given duration, regardless of the `cache-control`
+    /// header from the response (unless the response is `immutable`).
+    MaxAge(Duration),
 }
 
 impl From<Freshness> for CacheControl<'_> {
@@ -209,6 +212,33 @@ impl From<Freshness> for CacheControl<'_> {
     }
 }
 
+impl CacheControl<'_> {
+    /// Determine the [`CacheControl`] for an index response (e.g., a Simple API page), applying
+    /// the [`IndexCachePolicy`] to any entries that aren't subject to a refresh.
+    pub fn from_index_policy(freshness: Freshness, policy: IndexCachePolicy) -> Self {
+        match (freshness, policy.ttl) {
+            (Freshness::Fresh | Freshness::Missing, Some(ttl)) => Self::MaxAge(ttl),
+            (freshness, _) => Self::from(freshness),
+        }
+    }
+}
+
+/// Returns the `cache-control` request header used to evaluate the freshness of a cached response
+/// against a [`CacheControl::MaxAge`].
+fn max_age_header(max_age: Duration) -> http::HeaderValue {
+    http::HeaderValue::from_str(&format!("max-age={}", max_age.as_secs()))
+        .expect("Cache-Control header must be valid UTF-8")
+}
+
+/// Returns `true` if the cached response is within the [`CacheControl::MaxAge`], and so can be
+/// used without revalidation, regardless of the `cache-control` header from the response.
+fn is_within_max_age(cache_control: CacheControl<'_>, cached: &DataWithCachePolicy) -> bool {
+    match cache_control {
+        CacheControl::MaxAge(max_age) => cached.cache_policy.current_age() <= max_age,
+        _ => false,
+    }
+}
+
 /// Custom caching layer over [`reqwest::Client`].
 ///
 /// The implementation takes inspiration from the `http-cache` crate, but adds support for running
@@ -493,14 +523,37 @@ impl CachedClient {
                     http::HeaderValue::from_static("no-cache"),
                 );
             }
+            CacheControl::MaxAge(max_age) => {
+                req.headers_mut()
+                    .insert(http::header::CACHE_CONTROL, max_age_header(max_age));
+            }
         }
-        Ok(match cached.cache_policy.before_request(&mut req) {
+        let within_max_age = is_within_max_age(cache_control, &cached);
+        let before_request = cached.cache_policy.before_request(&mut req);
+
+        // The `max-age` directive is only used to evaluate the freshness of the cached response,
+        // and shouldn't be forwarded to the index.
+        if matches!(cache_control, CacheControl::MaxAge(_)) {
+            req.headers_mut().remove(http::header::CACHE_CONTROL);
+        }
+
+        Ok(match before_request {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
                 CachedResponse::FreshCache(cached)
             }
+            BeforeRequest::Stale(_) if within_max_age => {
+                debug!(
+                    "Found stale (but within max age) response for: {}",
+                    req.url()
+                );
+                CachedResponse::FreshCache(cached)
+            }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(_) => {
+                CacheControl::None
+                | CacheControl::MustRevalidate
+                | CacheControl::Override(_)
+                | CacheControl::MaxAge(_) => {
                     debug!("Found stale response for: {}", req.url());
                     self.send_cached_handle_stale(
                         req,
@@ -708,6 +761,178 @@ impl CachedClient {
         }
     }
 
+    /// Perform a [`CachedClient::get_cacheable`] request, serving a stale cached response
+    /// immediately while revalidating it in the background, akin to the `stale-while-revalidate`
+    /// directive from [RFC 5861].
+    ///
+    /// If there is no usable cached response, this is equivalent to
+    /// [`CachedClient::get_cacheable_with_retry`]. The background revalidation is best-effort: if
+    /// it fails, or the process exits before it completes, the stale response is retained and
+    /// revalidated on a subsequent request. Since the revalidation outlives any lock held by the
+    /// caller, it acquires the cache entry's lock (`<entry>.lock`) before replacing the entry, and
+    /// replaces it atomically, such that an interrupted revalidation never leaves a partial write.
+    ///
+    /// [RFC 5861]: https://www.rfc-editor.org/rfc/rfc5861
+    #[instrument(skip_all)]
+    pub async fn get_cacheable_stale_while_revalidate<
+        Payload: Cacheable + Send + 'static,
+        CallBackError: std::error::Error + Send + 'static,
+        Callback: Fn(Response) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = Result<Payload, CallBackError>> + Send,
+    >(
+        &self,
+        req: Request,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+        response_callback: Callback,
+    ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
+        let Some(cached) = Self::read_cache(cache_entry).await else {
+            return self
+                .get_cacheable_with_retry(req, cache_entry, cache_control, response_callback)
+                .await;
+        };
+
+        let mut revalidation_req = req.try_clone().expect("HTTP request must be cloneable");
+        if let CacheControl::MaxAge(max_age) = cache_control {
+            revalidation_req
+                .headers_mut()
+                .insert(http::header::CACHE_CONTROL, max_age_header(max_age));
+        }
+        let within_max_age = is_within_max_age(cache_control, &cached);
+        let data = match cached.cache_policy.before_request(&mut revalidation_req) {
+            BeforeRequest::Fresh => {
+                debug!("Found fresh response for: {}", req.url());
+                cached.data
+            }
+            BeforeRequest::Stale(_) if within_max_age => {
+                debug!(
+                    "Found stale (but within max age) response for: {}",
+                    req.url()
+                );
+                cached.data
+            }
+            BeforeRequest::Stale(new_cache_policy_builder) => {
+                debug!(
+                    "Found stale response for: {}; revalidating in the background",
+                    req.url()
+                );
+                revalidation_req
+                    .headers_mut()
+                    .remove(http::header::CACHE_CONTROL);
+
+                // Copy the cached data, since the stale response is retained by the revalidation
+                // task (e.g., in case the index returns a `304 Not Modified`).
+                let mut data = AlignedVec::with_capacity(cached.data.len());
+                data.extend_from_slice(&cached.data);
+
+                let client = self.clone();
+                let cache_entry = cache_entry.clone();
+                let response_callback = response_callback.clone();
+                tokio::spawn(async move {
+                    let url = DisplaySafeUrl::from(revalidation_req.url().clone());
+                    if let Err(err) = client
+                        .revalidate(
+                            revalidation_req,
+                            &cache_entry,
+                            cached,
+                            new_cache_policy_builder,
+                            response_callback,
+                        )
+                        .await
+                    {
+                        debug!(
+                            "Failed to revalidate stale response for {url}: {}",
+                            err.error()
+                        );
+                    }
+                });
+
+                data
+            }
+            BeforeRequest::NoMatch => {
+                return self
+                    .get_cacheable_with_retry(req, cache_entry, cache_control, response_callback)
+                    .await;
+            }
+        };
+
+        match Payload::from_aligned_bytes(data) {
+            Ok(payload) => Ok(payload),
+            Err(err) => {
+                warn!(
+                    "Broken cache entry (for payload) at {}, removing: {err}",
+                    cache_entry.path().display()
+                );
+                self.resend_and_heal_cache(req, cache_entry, cache_control, response_callback)
+                    .await
+            }
+        }
+    }
+
+    /// Revalidate a stale cached response, updating the cache entry with the result.
+    async fn revalidate<
+        Payload: Cacheable + Send,
+        CallBackError: std::error::Error + Send + 'static,
+        Callback: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<Payload, CallBackError>> + Send,
+    >(
+        &self,
+        req: Request,
+        cache_entry: &CacheEntry,
+        cached: DataWithCachePolicy,
+        new_cache_policy_builder: CachePolicyBuilder,
+        response_callback: Callback,
+    ) -> Result<(), CachedClientError<CallBackError>> {
+        match self
+            .send_cached_handle_stale(req, CacheControl::None, cached, new_cache_policy_builder)
+            .await?
+        {
+            CachedResponse::NotModified { cached, new_policy } => {
+                let data_with_cache_policy_bytes =
+                    DataWithCachePolicy::serialize(&new_policy, &cached.data)?;
+                Self::write_revalidated(cache_entry, data_with_cache_policy_bytes).await?;
+            }
+            CachedResponse::ModifiedOrNew {
+                response,
+                cache_policy,
+            } => {
+                // If the response isn't storable (or is an unusable `304`), retain the stale
+                // response, and let the next request handle it.
+                let Some(cache_policy) = cache_policy else {
+                    return Ok(());
+                };
+                if response.status() == http::StatusCode::NOT_MODIFIED {
+                    return Ok(());
+                }
+                let data = response_callback(response)
+                    .await
+                    .map_err(|err| CachedClientError::Callback { retries: None, err })?;
+                let data_with_cache_policy_bytes =
+                    DataWithCachePolicy::serialize(&cache_policy, &data.to_bytes()?)?;
+                Self::write_revalidated(cache_entry, data_with_cache_policy_bytes).await?;
+            }
+            CachedResponse::FreshCache(_) => {}
+        }
+        debug!(
+            "Revalidated stale response at: {}",
+            cache_entry.path().display()
+        );
+        Ok(())
+    }
+
+    /// Replace a cache entry with a revalidated response, under the entry's lock.
+    ///
+    /// The lock file is shared with the callers that lock the entry for the duration of a request
+    /// (e.g., `<package>.lock` for `<package>.rkyv` in the Simple API cache).
+    async fn write_revalidated(cache_entry: &CacheEntry, data: Vec<u8>) -> Result<(), Error> {
+        let lock_entry = CacheEntry::from_path(cache_entry.path().with_extension("lock"));
+        let _lock = lock_entry.lock().await.map_err(ErrorKind::CacheWrite)?;
+        write_atomic(cache_entry.path(), data)
+            .await
+            .map_err(ErrorKind::CacheWrite)?;
+        Ok(())
+    }
+
     /// Perform a [`CachedClient::skip_cache`] request with a default retry strategy.
     ///
     /// See: <https://github.com/TrueLayer/reqwest-middleware/blob/8a494c165734e24c62823714843e1c9347027e8a/reqwest-retry/src/middleware.rs#L137>
@@ -1021,3 +1246,118 @@ impl DataWithCachePolicy {
         Ok(len_usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use reqwest::Response;
+    use url::Url;
+    use uv_cache::{Cache, CacheEntry};
+    use uv_redacted::DisplaySafeUrl;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::BaseClientBuilder;
+    use crate::cached_client::{CacheControl, Cacheable, CachedClient, SerdeCacheable};
+
+    /// Mount a response that must always be revalidated.
+    async fn mount(server: &MockServer, body: &str, times: u64) {
+        Mock::given(method("GET"))
+            .and(path("/simple/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("cache-control", "max-age=0, no-cache")
+                    .set_body_string(body),
+            )
+            .up_to_n_times(times)
+            .mount(server)
+            .await;
+    }
+
+    async fn get(client: &CachedClient, server: &MockServer, entry: &CacheEntry) -> String {
+        let url = DisplaySafeUrl::parse(&format!("{}/simple/", server.uri())).unwrap();
+        let req = client
+            .uncached()
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .build()
+            .unwrap();
+        client
+            .get_cacheable_stale_while_revalidate(
+                req,
+                entry,
+                CacheControl::None,
+                |response: Response| async move {
+                    response.text().await.map(|inner| SerdeCacheable { inner })
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    /// Read the response stored in the cache entry.
+    async fn read(entry: &CacheEntry) -> Option<String> {
+        let cached = CachedClient::read_cache(entry).await?;
+        SerdeCacheable::<String>::from_aligned_bytes(cached.data).ok()
+    }
+
+    /// Wait for the background revalidation to store the given response.
+    async fn wait_for(entry: &CacheEntry, expected: &str) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while read(entry).await.as_deref() != Some(expected) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the stale response was never revalidated");
+    }
+
+    #[tokio::test]
+    async fn stale_while_revalidate() -> Result<()> {
+        let cache = Cache::temp()?;
+        let entry = CacheEntry::new(cache.root(), "simple.msgpack");
+        let client = CachedClient::new(BaseClientBuilder::default().build());
+
+        let server = MockServer::start().await;
+        mount(&server, "v1", 1).await;
+        mount(&server, "v2", u64::MAX).await;
+
+        // Without a cached response, the request is performed in the foreground.
+        assert_eq!(get(&client, &server, &entry).await, "v1");
+
+        // With a stale response, the stale response is served while the request is performed in
+        // the background.
+        assert_eq!(get(&client, &server, &entry).await, "v1");
+        wait_for(&entry, "v2").await;
+        assert_eq!(get(&client, &server, &entry).await, "v2");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stale_while_revalidate_waits_for_lock() -> Result<()> {
+        let cache = Cache::temp()?;
+        let entry = CacheEntry::new(cache.root(), "simple.msgpack");
+        let client = CachedClient::new(BaseClientBuilder::default().build());
+
+        let server = MockServer::start().await;
+        mount(&server, "v1", 1).await;
+        mount(&server, "v2", u64::MAX).await;
+        assert_eq!(get(&client, &server, &entry).await, "v1");
+
+        // While the entry is locked (e.g., by another process), the revalidated response isn't
+        // written.
+        let lock = entry.with_file("simple.lock").lock().await?;
+        assert_eq!(get(&client, &server, &entry).await, "v1");
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(read(&entry).await.as_deref(), Some("v1"));
+
+        // Once the lock is released, the revalidated response is written.
+        drop(lock);
+        wait_for(&entry, "v2").await;
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use async_zip::error::ZipError;
 
 use uv_distribution_filename::{WheelFilename, WheelFilenameError};
 use uv_normalize::PackageName;
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
 
 use crate::middleware::OfflineError;
 use crate::{FlatIndexError, html};
@@ -221,6 +221,18 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the local index")]
     FileNotFound(String),
 
+    /// The package matches an index namespace, but the pinned index isn't defined.
+    #[error(
+        "Package `{0}` matches the index namespace `{1}`, which is pinned to index `{2}`, but no index named `{2}` is defined"
+    )]
+    MissingNamespaceIndex(String, String, String),
+
+    /// The package matches an index namespace, but was requested from a different index.
+    #[error(
+        "Package `{0}` matches the index namespace `{1}`, which is pinned to index `{2}`, but was requested from: {3}"
+    )]
+    NamespaceIndexMismatch(String, String, String, DisplaySafeUrl),
+
     /// The metadata file could not be parsed.
     #[error("Couldn't parse metadata of {0} from {1}")]
     MetadataParseError(
@@ -384,6 +396,21 @@ impl WrappedReqwestError {
         false
     }
 
+    /// Returns `true` if the error is caused by the network or the server, e.g., a connection
+    /// failure, a timeout, or a server error (5xx) response.
+    ///
+    /// Client errors (4xx), like a missing package or invalid credentials, are not considered
+    /// network errors.
+    pub fn is_network_error(&self) -> bool {
+        let Some(err) = self.inner() else {
+            return false;
+        };
+        if let Some(status) = err.status() {
+            return status.is_server_error();
+        }
+        err.is_connect() || err.is_timeout() || err.is_request() || err.is_body()
+    }
+
     /// Check if the error chain contains a `reqwest` error that looks like this:
     /// * invalid peer certificate: `UnknownIssuer`
     fn is_ssl(&self) -> bool {
@@ -431,8 +458,8 @@ impl Display for WrappedReqwestError {
             // Insert an extra hint, we'll show the wrapped error through `source`
             f.write_str("Could not connect, are you offline?")
         } else {
-            // Show the wrapped error
-            Display::fmt(&self.0, f)
+            // Show the wrapped error, which includes the request URL
+            Display::fmt(&redact_credentials(&self.0.to_string()), f)
         }
     }
 }
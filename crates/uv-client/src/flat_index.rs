@@ -15,6 +15,7 @@ use uv_small_str::SmallString;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
+use crate::wheelhouse::{WheelhouseManifest, WheelhouseManifestError};
 use crate::{CachedClient, Connectivity, Error, ErrorKind, OwnedArchive};
 
 #[derive(Debug, thiserror::Error)]
@@ -35,6 +36,8 @@ pub enum FindLinksDirectoryError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     VerbatimUrl(#[from] uv_pep508::VerbatimUrlError),
+    #[error(transparent)]
+    Manifest(#[from] WheelhouseManifestError),
 }
 
 /// An entry in a `--find-links` index.
@@ -168,10 +171,11 @@ impl<'a> FlatIndexClient<'a> {
             format!("{}.msgpack", cache_digest(&url.to_string())),
         );
         let cache_control = match self.connectivity {
-            Connectivity::Online => CacheControl::from(
+            Connectivity::Online => CacheControl::from_index_policy(
                 self.cache
                     .freshness(&cache_entry, None, None)
                     .map_err(ErrorKind::Io)?,
+                self.cache.index_policy(),
             ),
             Connectivity::Offline => CacheControl::AllowStale,
         };
@@ -262,6 +266,11 @@ impl<'a> FlatIndexClient<'a> {
         #[allow(clippy::disallowed_methods)]
         let entries = std::fs::read_dir(path)?;
 
+        // If the directory includes an integrity manifest, attach the expected hashes to each
+        // entry, such that `--require-hashes` can select a matching distribution. The selected
+        // archive is still hashed when it's installed.
+        let manifest = WheelhouseManifest::from_directory(path)?;
+
         let mut dists = Vec::new();
         for entry in entries {
             let entry = entry?;
@@ -299,12 +308,18 @@ impl<'a> FlatIndexClient<'a> {
             let file = File {
                 dist_info_metadata: false,
                 filename: filename.into(),
-                hashes: HashDigests::empty(),
+                hashes: manifest
+                    .as_ref()
+                    .and_then(|manifest| manifest.get(filename))
+                    .cloned()
+                    .map(HashDigests::from)
+                    .unwrap_or_else(HashDigests::empty),
                 requires_python: None,
                 size: None,
                 upload_time_utc_ms: None,
                 url: FileLocation::AbsoluteUrl(UrlString::from(url)),
                 yanked: None,
+                yanked_time_utc_ms: None,
                 zstd: None,
             };
 
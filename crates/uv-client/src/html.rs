@@ -221,6 +221,8 @@ impl SimpleHtml {
             url: path.into(),
             size,
             upload_time,
+            // The HTML API doesn't report when a file was yanked.
+            yanked_time: None,
         }))
     }
 }
@@ -313,6 +315,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -370,6 +373,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -430,6 +434,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -487,6 +492,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2+233fca715f49-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -544,6 +550,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -599,6 +606,7 @@ mod tests {
                     upload_time: None,
                     url: "cpu/torchtext-0.17.0%2Bcpu-cp39-cp39-win_amd64.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -654,6 +662,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -785,6 +794,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -840,6 +850,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl?project=legacy",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -896,6 +907,7 @@ mod tests {
                         upload_time: None,
                         url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                         yanked: None,
+                        yanked_time: None,
                     },
                 ],
             },
@@ -953,6 +965,7 @@ mod tests {
                         upload_time: None,
                         url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                         yanked: None,
+                        yanked_time: None,
                     },
                 ],
             },
@@ -1027,6 +1040,7 @@ mod tests {
                     upload_time: None,
                     url: "https://storage.googleapis.com/jax-releases/cuda100/jaxlib-0.1.52+cuda100-cp36-none-manylinux2010_x86_64.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: None,
@@ -1043,6 +1057,7 @@ mod tests {
                     upload_time: None,
                     url: "https://storage.googleapis.com/jax-releases/cuda100/jaxlib-0.1.52+cuda100-cp37-none-manylinux2010_x86_64.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -1111,6 +1126,7 @@ mod tests {
                     upload_time: None,
                     url: "0.1/Flask-0.1.tar.gz",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: None,
@@ -1129,6 +1145,7 @@ mod tests {
                     upload_time: None,
                     url: "0.10.1/Flask-0.10.1.tar.gz",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: None,
@@ -1158,6 +1175,7 @@ mod tests {
                     upload_time: None,
                     url: "3.0.1/flask-3.0.1.tar.gz",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -1225,6 +1243,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
@@ -1289,6 +1308,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: Some(
@@ -1309,6 +1329,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.3-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: Some(
@@ -1329,6 +1350,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.4-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: Some(
@@ -1349,6 +1371,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.5-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
                 PypiFile {
                     core_metadata: Some(
@@ -1369,6 +1392,7 @@ mod tests {
                     upload_time: None,
                     url: "/whl/Jinja2-3.1.6-py3-none-any.whl",
                     yanked: None,
+                    yanked_time: None,
                 },
             ],
         }
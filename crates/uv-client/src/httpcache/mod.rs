@@ -539,6 +539,14 @@ impl ArchivedCachePolicy {
         }
     }
 
+    /// Returns the current age of the cached response, as per [RFC 9111
+    /// S4.2.3].
+    ///
+    /// [RFC 9111 S4.2.3]: https://www.rfc-editor.org/rfc/rfc9111.html#name-calculating-age
+    pub fn current_age(&self) -> Duration {
+        self.age(SystemTime::now())
+    }
+
     /// Returns true if and only if the response is storable as per
     /// [RFC 9111 S3].
     ///
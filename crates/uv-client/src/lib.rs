@@ -7,11 +7,13 @@ pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithC
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use mirror::{MirrorManifest, MirrorManifestError};
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleMetadata,
     SimpleMetadatum, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use wheelhouse::{WHEELHOUSE_MANIFEST, WheelhouseManifest, WheelhouseManifestError};
 
 mod base_client;
 mod cached_client;
@@ -21,7 +23,10 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod mirror;
+mod rate_limit;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
 mod tls;
+mod wheelhouse;
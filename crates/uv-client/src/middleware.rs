@@ -1,10 +1,14 @@
 use http::Extensions;
 use std::fmt::Debug;
+use std::sync::Arc;
+use tracing::debug;
 use uv_redacted::DisplaySafeUrl;
 
 use reqwest::{Request, Response};
 use reqwest_middleware::{Middleware, Next};
 
+use crate::MirrorManifest;
+
 /// A custom error type for the offline middleware.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OfflineError {
@@ -49,3 +53,27 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// A middleware that redirects requests for upstream URLs to the mirrors listed in a
+/// [`MirrorManifest`].
+pub(crate) struct MirrorMiddleware(pub(crate) Arc<MirrorManifest>);
+
+#[async_trait::async_trait]
+impl Middleware for MirrorMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if let Some(mirror) = self.0.resolve(req.url()) {
+            debug!(
+                "Using mirror for `{}`: `{}`",
+                DisplaySafeUrl::from(req.url().clone()),
+                DisplaySafeUrl::from(mirror.clone())
+            );
+            *req.url_mut() = mirror;
+        }
+        next.run(req, extensions).await
+    }
+}
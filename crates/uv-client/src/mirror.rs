@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// A manifest mapping upstream URLs (e.g., for managed Python downloads or wheels) to internal
+/// mirrors.
+///
+/// The manifest is a TOML file with a list of `[[mirror]]` entries, each of which maps either an
+/// exact `url` or a URL `prefix` to a `mirror`:
+///
+/// ```toml
+/// [[mirror]]
+/// url = "https://github.com/astral-sh/python-build-standalone/releases/download/20250115/cpython-3.12.8%2B20250115-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz"
+/// mirror = "https://artifacts.example.com/python/cpython-3.12.8-x86_64-unknown-linux-gnu.tar.gz"
+/// sha256 = "..."
+///
+/// [[mirror]]
+/// prefix = "https://files.pythonhosted.org/packages/"
+/// mirror = "https://artifacts.example.com/pypi/packages/"
+/// ```
+///
+/// Exact entries take precedence over prefixes, and longer prefixes take precedence over shorter
+/// ones. An exact entry may include the SHA-256 checksum of the mirrored file, which is verified
+/// after downloading.
+#[derive(Debug, Clone, Default)]
+pub struct MirrorManifest {
+    entries: Vec<MirrorEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct MirrorEntry {
+    upstream: Upstream,
+    mirror: String,
+    digest: Option<HashDigest>,
+}
+
+#[derive(Debug, Clone)]
+enum Upstream {
+    Url(String),
+    Prefix(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct MirrorManifestWire {
+    #[serde(default)]
+    mirror: Vec<MirrorEntryWire>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct MirrorEntryWire {
+    url: Option<String>,
+    prefix: Option<String>,
+    mirror: String,
+    sha256: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MirrorManifestError {
+    #[error("Failed to read mirror manifest: `{}`", _0.user_display())]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse mirror manifest: `{}`", _0.user_display())]
+    Toml(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Mirror manifest entry for `{0}` must specify exactly one of `url` or `prefix`")]
+    UpstreamConflict(String),
+    #[error(
+        "Mirror manifest entry for `{0}` can only include a `sha256` when mapping an exact `url`"
+    )]
+    PrefixChecksum(String),
+    #[error("Mirror manifest entry for `{0}` has an invalid `sha256`: `{1}`")]
+    InvalidChecksum(String, String),
+}
+
+impl MirrorManifest {
+    /// Read a [`MirrorManifest`] from a TOML file.
+    pub fn from_path(path: &Path) -> Result<Self, MirrorManifestError> {
+        let contents = fs_err::read_to_string(path)
+            .map_err(|err| MirrorManifestError::Io(path.to_path_buf(), err))?;
+        let wire = toml::from_str::<MirrorManifestWire>(&contents)
+            .map_err(|err| MirrorManifestError::Toml(path.to_path_buf(), Box::new(err)))?;
+        Self::from_wire(wire)
+    }
+
+    fn from_wire(wire: MirrorManifestWire) -> Result<Self, MirrorManifestError> {
+        let entries = wire
+            .mirror
+            .into_iter()
+            .map(|entry| {
+                let upstream = match (entry.url, entry.prefix) {
+                    (Some(url), None) => Upstream::Url(url),
+                    (None, Some(prefix)) => Upstream::Prefix(prefix),
+                    (Some(_), Some(_)) | (None, None) => {
+                        return Err(MirrorManifestError::UpstreamConflict(entry.mirror));
+                    }
+                };
+                let digest = match entry.sha256 {
+                    Some(_) if matches!(upstream, Upstream::Prefix(_)) => {
+                        return Err(MirrorManifestError::PrefixChecksum(entry.mirror));
+                    }
+                    Some(sha256) => {
+                        if sha256.len() != 64 || !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                            return Err(MirrorManifestError::InvalidChecksum(entry.mirror, sha256));
+                        }
+                        Some(HashDigest {
+                            algorithm: HashAlgorithm::Sha256,
+                            digest: sha256.to_ascii_lowercase().into(),
+                        })
+                    }
+                    None => None,
+                };
+                Ok(MirrorEntry {
+                    upstream,
+                    mirror: entry.mirror,
+                    digest,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Find the entry that applies to the given URL, if any.
+    fn entry(&self, url: &str) -> Option<&MirrorEntry> {
+        self.entries
+            .iter()
+            .find(|entry| matches!(&entry.upstream, Upstream::Url(upstream) if upstream == url))
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .filter(|entry| {
+                        matches!(&entry.upstream, Upstream::Prefix(prefix) if url.starts_with(prefix.as_str()))
+                    })
+                    .max_by_key(|entry| match &entry.upstream {
+                        Upstream::Prefix(prefix) => prefix.len(),
+                        Upstream::Url(_) => 0,
+                    })
+            })
+    }
+
+    /// Return the mirror URL for the given upstream URL, if the manifest includes one.
+    pub fn resolve(&self, url: &Url) -> Option<Url> {
+        let entry = self.entry(url.as_str())?;
+        let mirror = match &entry.upstream {
+            Upstream::Url(_) => entry.mirror.clone(),
+            Upstream::Prefix(prefix) => {
+                format!("{}{}", entry.mirror, &url.as_str()[prefix.len()..])
+            }
+        };
+        Url::parse(&mirror).ok()
+    }
+
+    /// Return the checksum that the mirrored file for the given upstream URL must match, if any.
+    pub fn digests(&self, url: &Url) -> &[HashDigest] {
+        self.entry(url.as_str())
+            .map(|entry| entry.digest.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::{MirrorManifest, MirrorManifestWire};
+
+    fn manifest(contents: &str) -> MirrorManifest {
+        MirrorManifest::from_wire(toml::from_str::<MirrorManifestWire>(contents).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn resolve() {
+        let manifest = manifest(
+            r#"
+            [[mirror]]
+            url = "https://example.com/python/cpython-3.12.tar.gz"
+            mirror = "https://mirror.example.com/cpython-3.12.tar.gz"
+            sha256 = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+
+            [[mirror]]
+            prefix = "https://example.com/"
+            mirror = "https://mirror.example.com/all/"
+
+            [[mirror]]
+            prefix = "https://example.com/packages/"
+            mirror = "https://mirror.example.com/packages/"
+            "#,
+        );
+
+        let url = Url::parse("https://example.com/python/cpython-3.12.tar.gz").unwrap();
+        assert_eq!(
+            manifest.resolve(&url).unwrap().as_str(),
+            "https://mirror.example.com/cpython-3.12.tar.gz"
+        );
+        assert_eq!(
+            &*manifest.digests(&url)[0].digest,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let url = Url::parse("https://example.com/packages/pip-24.0-py3-none-any.whl").unwrap();
+        assert_eq!(
+            manifest.resolve(&url).unwrap().as_str(),
+            "https://mirror.example.com/packages/pip-24.0-py3-none-any.whl"
+        );
+        assert!(manifest.digests(&url).is_empty());
+
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        assert_eq!(
+            manifest.resolve(&url).unwrap().as_str(),
+            "https://mirror.example.com/all/simple/"
+        );
+
+        let url = Url::parse("https://pypi.org/simple/").unwrap();
+        assert!(manifest.resolve(&url).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::{Stream, StreamExt};
+
+use uv_configuration::RateLimit;
+
+/// A token-bucket rate limiter, shared across all downloads made by a client.
+///
+/// Each downloaded chunk consumes tokens from the bucket, which refills at the configured rate up
+/// to one second's worth of bytes. When the bucket runs dry, the balance goes negative, and the
+/// download that drained it sleeps until the debt has been repaid. As the bucket is shared,
+/// concurrent downloads split the available bandwidth between them.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// The number of bytes that may be read before waiting. Negative if in debt.
+    tokens: f64,
+    /// The last time the bucket was refilled.
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: 0.0,
+                updated: Instant::now(),
+            })),
+        }
+    }
+
+    /// Consume `bytes` tokens, waiting if the limit has been exceeded.
+    #[allow(clippy::cast_precision_loss)]
+    async fn acquire(&self, bytes: usize) {
+        let rate = self.limit.bytes_per_second() as f64;
+
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap();
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+            bucket.updated = now;
+
+            bucket.tokens -= bytes as f64;
+
+            if bucket.tokens < 0.0 {
+                Some(Duration::from_secs_f64(-bucket.tokens / rate))
+            } else {
+                None
+            }
+        };
+
+        if let Some(wait) = wait {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Throttle a stream of downloaded chunks.
+    pub(crate) fn throttle<S, T, E>(self, stream: S) -> impl Stream<Item = Result<T, E>>
+    where
+        S: Stream<Item = Result<T, E>>,
+        T: AsRef<[u8]>,
+    {
+        stream.then(move |chunk| {
+            let limiter = self.clone();
+            async move {
+                if let Ok(bytes) = &chunk {
+                    limiter.acquire(bytes.as_ref().len()).await;
+                }
+                chunk
+            }
+        })
+    }
+}
@@ -267,6 +267,11 @@ impl RegistryClient {
         self.timeout
     }
 
+    /// Return the [`IndexUrls`] this client is configured with.
+    pub fn index_urls(&self) -> &IndexUrls {
+        &self.index_urls
+    }
+
     /// Return the appropriate index URLs for the given [`PackageName`].
     fn index_urls_for(
         &self,
@@ -317,6 +322,30 @@ impl RegistryClient {
             return Err(ErrorKind::NoIndex(package_name.to_string()).into());
         }
 
+        // If the package matches an index namespace, only fetch it from the pinned index.
+        let index = if let Some(namespace) = self.index_urls.namespace_for(package_name) {
+            let Some(pinned) = namespace.index else {
+                return Err(ErrorKind::MissingNamespaceIndex(
+                    package_name.to_string(),
+                    namespace.pattern.to_string(),
+                    namespace.name.to_string(),
+                )
+                .into());
+            };
+            if let Some(index) = index.filter(|index| *index.url != pinned.url) {
+                return Err(ErrorKind::NamespaceIndexMismatch(
+                    package_name.to_string(),
+                    namespace.pattern.to_string(),
+                    namespace.name.to_string(),
+                    index.url.without_credentials().into_owned(),
+                )
+                .into());
+            }
+            Some(IndexMetadataRef::from(pinned))
+        } else {
+            index
+        };
+
         let indexes = if let Some(index) = index {
             Either::Left(std::iter::once(index))
         } else {
@@ -497,10 +526,11 @@ impl RegistryClient {
                 if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
+                    CacheControl::from_index_policy(
                         self.cache
                             .freshness(&cache_entry, Some(package_name), None)
                             .map_err(ErrorKind::Io)?,
+                        self.cache.index_policy(),
                     )
                 }
             }
@@ -581,91 +611,36 @@ impl RegistryClient {
             .header("Accept", accept)
             .build()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-        let parse_simple_response = |response: Response| {
-            async {
-                // Use the response URL, rather than the request URL, as the base for relative URLs.
-                // This ensures that we handle redirects and other URL transformations correctly.
-                let url = DisplaySafeUrl::from(response.url().clone());
-
-                let content_type = response
-                    .headers()
-                    .get("content-type")
-                    .ok_or_else(|| Error::from(ErrorKind::MissingContentType(url.clone())))?;
-                let content_type = content_type.to_str().map_err(|err| {
-                    Error::from(ErrorKind::InvalidContentTypeHeader(url.clone(), err))
-                })?;
-                let media_type = content_type.split(';').next().unwrap_or(content_type);
-                let media_type = MediaType::from_str(media_type).ok_or_else(|| {
-                    Error::from(ErrorKind::UnsupportedMediaType(
-                        url.clone(),
-                        media_type.to_string(),
-                    ))
-                })?;
-
-                let unarchived = match media_type {
-                    MediaType::PyxV1Msgpack => {
-                        let bytes = response
-                            .bytes()
-                            .await
-                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                        let data: PyxSimpleDetail = rmp_serde::from_slice(bytes.as_ref())
-                            .map_err(|err| Error::from_msgpack_err(err, url.clone()))?;
-
-                        SimpleMetadata::from_pyx_files(
-                            data.files,
-                            data.core_metadata,
-                            package_name,
-                            &url,
-                        )
-                    }
-                    MediaType::PyxV1Json => {
-                        let bytes = response
-                            .bytes()
-                            .await
-                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                        let data: PyxSimpleDetail = serde_json::from_slice(bytes.as_ref())
-                            .map_err(|err| Error::from_json_err(err, url.clone()))?;
-
-                        SimpleMetadata::from_pyx_files(
-                            data.files,
-                            data.core_metadata,
-                            package_name,
-                            &url,
-                        )
-                    }
-                    MediaType::PypiV1Json => {
-                        let bytes = response
-                            .bytes()
-                            .await
-                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-
-                        let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
-                            .map_err(|err| Error::from_json_err(err, url.clone()))?;
-
-                        SimpleMetadata::from_pypi_files(data.files, package_name, &url)
-                    }
-                    MediaType::PypiV1Html | MediaType::TextHtml => {
-                        let text = response
-                            .text()
-                            .await
-                            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                        SimpleMetadata::from_html(&text, package_name, &url)?
-                    }
-                };
-                OwnedArchive::from_unarchived(&unarchived)
+        let parse_simple_response = {
+            let package_name = package_name.clone();
+            move |response: Response| {
+                let span = info_span!("parse_simple_api", package = %package_name);
+                parse_simple_response(response, package_name.clone())
+                    .boxed()
+                    .instrument(span)
             }
-            .boxed_local()
-            .instrument(info_span!("parse_simple_api", package = %package_name))
         };
-        let simple = self
-            .cached_client()
-            .get_cacheable_with_retry(
-                simple_request,
-                cache_entry,
-                cache_control,
-                parse_simple_response,
-            )
-            .await?;
+        let simple = if self.cache.index_policy().stale_while_revalidate
+            && matches!(cache_control, CacheControl::None | CacheControl::MaxAge(_))
+        {
+            self.cached_client()
+                .get_cacheable_stale_while_revalidate(
+                    simple_request,
+                    cache_entry,
+                    cache_control,
+                    parse_simple_response,
+                )
+                .await?
+        } else {
+            self.cached_client()
+                .get_cacheable_with_retry(
+                    simple_request,
+                    cache_entry,
+                    cache_control,
+                    parse_simple_response,
+                )
+                .await?
+        };
         Ok(simple)
     }
 
@@ -1011,8 +986,10 @@ impl RegistryClient {
         // Stream the file, searching for the METADATA.
         let read_metadata_stream = |response: Response| {
             async {
-                let reader = response
-                    .bytes_stream()
+                let reader = self
+                    .client
+                    .uncached()
+                    .throttle(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -1045,6 +1022,73 @@ impl RegistryClient {
     }
 }
 
+/// Parse a Simple API response for the given package into [`SimpleMetadata`].
+async fn parse_simple_response(
+    response: Response,
+    package_name: PackageName,
+) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+    // Use the response URL, rather than the request URL, as the base for relative URLs.
+    // This ensures that we handle redirects and other URL transformations correctly.
+    let url = DisplaySafeUrl::from(response.url().clone());
+
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .ok_or_else(|| Error::from(ErrorKind::MissingContentType(url.clone())))?;
+    let content_type = content_type
+        .to_str()
+        .map_err(|err| Error::from(ErrorKind::InvalidContentTypeHeader(url.clone(), err)))?;
+    let media_type = content_type.split(';').next().unwrap_or(content_type);
+    let media_type = MediaType::from_str(media_type).ok_or_else(|| {
+        Error::from(ErrorKind::UnsupportedMediaType(
+            url.clone(),
+            media_type.to_string(),
+        ))
+    })?;
+
+    let unarchived = match media_type {
+        MediaType::PyxV1Msgpack => {
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let data: PyxSimpleDetail = rmp_serde::from_slice(bytes.as_ref())
+                .map_err(|err| Error::from_msgpack_err(err, url.clone()))?;
+
+            SimpleMetadata::from_pyx_files(data.files, data.core_metadata, &package_name, &url)
+        }
+        MediaType::PyxV1Json => {
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let data: PyxSimpleDetail = serde_json::from_slice(bytes.as_ref())
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+
+            SimpleMetadata::from_pyx_files(data.files, data.core_metadata, &package_name, &url)
+        }
+        MediaType::PypiV1Json => {
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+
+            let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+
+            SimpleMetadata::from_pypi_files(data.files, &package_name, &url)
+        }
+        MediaType::PypiV1Html | MediaType::TextHtml => {
+            let text = response
+                .text()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            SimpleMetadata::from_html(&text, &package_name, &url)?
+        }
+    };
+    OwnedArchive::from_unarchived(&unarchived)
+}
+
 #[derive(Debug)]
 pub(crate) enum SimpleMetadataSearchOutcome {
     /// Simple metadata was found
@@ -1247,6 +1291,8 @@ impl SimpleMetadata {
                                 requires_python: metadata.requires_python,
                                 provides_extra: metadata.provides_extra,
                                 dynamic: false,
+                                license: None,
+                                homepage: None,
                             });
                     SimpleMetadatum {
                         version,
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use uv_fs::Simplified;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashError};
+
+/// The name of the integrity manifest within a wheelhouse directory.
+pub const WHEELHOUSE_MANIFEST: &str = "SHA256SUMS";
+
+#[derive(Debug, thiserror::Error)]
+pub enum WheelhouseManifestError {
+    #[error("Failed to read wheelhouse manifest: `{}`", _0.user_display())]
+    Io(PathBuf, #[source] io::Error),
+
+    #[error(
+        "Invalid entry on line {0} of wheelhouse manifest (expected `<hash>  <filename>`): `{1}`"
+    )]
+    InvalidEntry(usize, String),
+
+    #[error("Invalid hash on line {0} of wheelhouse manifest")]
+    InvalidHash(usize, #[source] HashError),
+
+    #[error("Duplicate entry for `{1}` on line {0} of wheelhouse manifest")]
+    DuplicateEntry(usize, String),
+}
+
+/// An integrity manifest for a directory of wheels and source distributions (a "wheelhouse").
+///
+/// The manifest uses the `sha256sum` output format, with one `<hash>  <filename>` entry per
+/// line. Hashes without an algorithm prefix are assumed to be SHA-256; other algorithms can be
+/// provided via `<algorithm>:<hash>` (e.g., `sha512:...`).
+#[derive(Debug, Default, Clone)]
+pub struct WheelhouseManifest {
+    entries: BTreeMap<String, HashDigest>,
+}
+
+impl WheelhouseManifest {
+    /// Read the manifest from the given file.
+    pub fn from_path(path: &Path) -> Result<Self, WheelhouseManifestError> {
+        let contents = fs_err::read_to_string(path)
+            .map_err(|err| WheelhouseManifestError::Io(path.to_path_buf(), err))?;
+        Self::from_str(&contents)
+    }
+
+    /// Read the manifest from the given wheelhouse directory, if it exists.
+    pub fn from_directory(directory: &Path) -> Result<Option<Self>, WheelhouseManifestError> {
+        let path = directory.join(WHEELHOUSE_MANIFEST);
+        match fs_err::read_to_string(&path) {
+            Ok(contents) => Ok(Some(Self::from_str(&contents)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(WheelhouseManifestError::Io(path, err)),
+        }
+    }
+
+    /// Return the expected hash for the given filename, if any.
+    pub fn get(&self, filename: &str) -> Option<&HashDigest> {
+        self.entries.get(filename)
+    }
+
+    /// Iterate over the filenames and expected hashes in the manifest.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &HashDigest)> {
+        self.entries
+            .iter()
+            .map(|(filename, digest)| (filename.as_str(), digest))
+    }
+}
+
+impl FromStr for WheelhouseManifest {
+    type Err = WheelhouseManifestError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut entries = BTreeMap::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((hash, filename)) = line.split_once(char::is_whitespace) else {
+                return Err(WheelhouseManifestError::InvalidEntry(
+                    line_number,
+                    line.to_string(),
+                ));
+            };
+
+            // `sha256sum` prefixes the filename with `*` when reading in binary mode.
+            let filename = filename.trim_start();
+            let filename = filename.strip_prefix('*').unwrap_or(filename);
+            if filename.is_empty() || filename.contains(['/', '\\']) {
+                return Err(WheelhouseManifestError::InvalidEntry(
+                    line_number,
+                    line.to_string(),
+                ));
+            }
+
+            let digest = if hash.contains(':') {
+                HashDigest::from_str(hash)
+                    .map_err(|err| WheelhouseManifestError::InvalidHash(line_number, err))?
+            } else {
+                HashDigest {
+                    algorithm: HashAlgorithm::Sha256,
+                    digest: hash.into(),
+                }
+            };
+            if digest.digest.is_empty() || !digest.digest.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(WheelhouseManifestError::InvalidEntry(
+                    line_number,
+                    line.to_string(),
+                ));
+            }
+
+            // Normalize to lowercase, to match the digests computed by uv.
+            let digest = HashDigest {
+                algorithm: digest.algorithm,
+                digest: digest.digest.to_ascii_lowercase().into(),
+            };
+
+            if entries.insert(filename.to_string(), digest).is_some() {
+                return Err(WheelhouseManifestError::DuplicateEntry(
+                    line_number,
+                    filename.to_string(),
+                ));
+            }
+        }
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pypi_types::HashAlgorithm;
+
+    use super::{WheelhouseManifest, WheelhouseManifestError};
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = WheelhouseManifest::from_str(
+            "# Generated by sha256sum\n\
+             ABCDEF0123456789  anyio-4.3.0-py3-none-any.whl\n\
+             sha512:0123abcd *idna-3.6.tar.gz\n\
+             \n",
+        )
+        .unwrap();
+
+        let digest = manifest.get("anyio-4.3.0-py3-none-any.whl").unwrap();
+        assert_eq!(digest.algorithm, HashAlgorithm::Sha256);
+        assert_eq!(&*digest.digest, "abcdef0123456789");
+
+        let digest = manifest.get("idna-3.6.tar.gz").unwrap();
+        assert_eq!(digest.algorithm, HashAlgorithm::Sha512);
+        assert_eq!(&*digest.digest, "0123abcd");
+
+        assert_eq!(manifest.iter().count(), 2);
+    }
+
+    #[test]
+    fn parse_manifest_errors() {
+        assert!(matches!(
+            WheelhouseManifest::from_str("abcdef"),
+            Err(WheelhouseManifestError::InvalidEntry(1, _))
+        ));
+        assert!(matches!(
+            WheelhouseManifest::from_str("xyz  anyio-4.3.0-py3-none-any.whl"),
+            Err(WheelhouseManifestError::InvalidEntry(1, _))
+        ));
+        assert!(matches!(
+            WheelhouseManifest::from_str("sha1:abcdef  anyio-4.3.0-py3-none-any.whl"),
+            Err(WheelhouseManifestError::InvalidHash(1, _))
+        ));
+        assert!(matches!(
+            WheelhouseManifest::from_str("abcdef  ../anyio-4.3.0-py3-none-any.whl"),
+            Err(WheelhouseManifestError::InvalidEntry(1, _))
+        ));
+        assert!(matches!(
+            WheelhouseManifest::from_str(
+                "abcdef  anyio-4.3.0-py3-none-any.whl\n012345  anyio-4.3.0-py3-none-any.whl"
+            ),
+            Err(WheelhouseManifestError::DuplicateEntry(2, _))
+        ));
+    }
+}
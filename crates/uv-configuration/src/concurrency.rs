@@ -1,7 +1,8 @@
 use std::num::NonZeroUsize;
 
 /// Concurrency limit settings.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Concurrency {
     /// The maximum number of concurrent downloads.
     ///
@@ -15,6 +16,16 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of concurrent bytecode compilation workers.
+    ///
+    /// Note this value must be non-zero.
+    pub compiles: usize,
+    /// The maximum number of versions for which to prefetch metadata in a single batch during
+    /// resolution, and the maximum number of dependencies for which to prefetch metadata in the
+    /// background at once.
+    ///
+    /// Note this value must be non-zero.
+    pub prefetches: usize,
 }
 
 impl Default for Concurrency {
@@ -23,6 +34,8 @@ impl Default for Concurrency {
             downloads: Self::DEFAULT_DOWNLOADS,
             builds: Self::threads(),
             installs: Self::threads(),
+            compiles: Self::threads(),
+            prefetches: Self::DEFAULT_PREFETCHES,
         }
     }
 }
@@ -31,6 +44,9 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
+    // The default batch prefetch limit.
+    pub const DEFAULT_PREFETCHES: usize = 50;
+
     // The default concurrent builds and install limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
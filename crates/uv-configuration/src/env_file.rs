@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
 /// A collection of `.env` file paths.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EnvFile(Vec<PathBuf>);
 
 impl EnvFile {
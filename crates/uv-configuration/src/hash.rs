@@ -1,4 +1,6 @@
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum HashCheckingMode {
     /// Hashes should be validated against a pre-defined list of hashes. Every requirement must
     /// itself be hashable (e.g., Git dependencies are forbidden) _and_ have a hash in the lockfile.
@@ -13,7 +13,8 @@ pub struct InstallTarget<'a> {
     pub is_local: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InstallOptions {
     /// Omit the project itself from the resolution.
     pub no_install_project: bool,
@@ -23,6 +24,8 @@ pub struct InstallOptions {
     pub no_install_local: bool,
     /// Omit the specified packages from the resolution.
     pub no_install_package: Vec<PackageName>,
+    /// Retain any existing installation of the project itself, rather than reinstalling it.
+    pub no_reinstall_project: bool,
 }
 
 impl InstallOptions {
@@ -37,9 +40,18 @@ impl InstallOptions {
             no_install_workspace,
             no_install_local,
             no_install_package,
+            no_reinstall_project: false,
         }
     }
 
+    /// Retain any existing installation of the project itself, rather than reinstalling it (e.g.,
+    /// when its metadata has changed).
+    #[must_use]
+    pub fn with_no_reinstall_project(mut self, no_reinstall_project: bool) -> Self {
+        self.no_reinstall_project = no_reinstall_project;
+        self
+    }
+
     /// Returns `true` if a package passes the install filters.
     pub fn include_package(
         &self,
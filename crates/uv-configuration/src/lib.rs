@@ -14,6 +14,7 @@ pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use project_build_backend::*;
+pub use rate_limit::*;
 pub use required_version::*;
 pub use sources::*;
 pub use target_triple::*;
@@ -38,6 +39,7 @@ mod name_specifiers;
 mod overrides;
 mod package_options;
 mod project_build_backend;
+mod rate_limit;
 mod required_version;
 mod sources;
 mod target_triple;
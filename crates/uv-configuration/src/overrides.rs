@@ -8,8 +8,26 @@ use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
 /// A set of overrides for a set of requirements.
+///
+/// Overrides are either global, in which case they replace the requirements on a package from
+/// every dependent, or scoped, in which case they only replace the requirements on a package from
+/// a specific dependent (e.g., `anyio==3.7.0 ; when = "fastapi"`).
+///
+/// Global overrides replace the original requirement unconditionally, regardless of whether their
+/// markers evaluate to true (e.g., an override with a marker that's never satisfied removes the
+/// requirement entirely).
+///
+/// Scoped overrides take precedence over global overrides, but only apply in the environments in
+/// which their markers are satisfied; in any other environment, the global overrides (or, absent
+/// any global overrides, the original requirement) apply instead.
 #[derive(Debug, Default, Clone)]
-pub struct Overrides(FxHashMap<PackageName, Vec<Requirement>>);
+pub struct Overrides {
+    /// The overrides that apply to every dependent.
+    global: FxHashMap<PackageName, Vec<Requirement>>,
+    /// The overrides that only apply to a specific dependent, keyed by the name of the dependent
+    /// and then by the name of the overridden package.
+    scoped: FxHashMap<PackageName, FxHashMap<PackageName, Vec<Requirement>>>,
+}
 
 impl Overrides {
     /// Create a new set of overrides from a set of requirements.
@@ -22,56 +40,131 @@ impl Overrides {
                 .or_default()
                 .push(requirement);
         }
-        Self(overrides)
+        Self {
+            global: overrides,
+            scoped: FxHashMap::default(),
+        }
+    }
+
+    /// Add a set of overrides that only apply to the requirements of the given dependents.
+    #[must_use]
+    pub fn with_scoped(
+        mut self,
+        requirements: impl IntoIterator<Item = (PackageName, Requirement)>,
+    ) -> Self {
+        for (dependent, requirement) in requirements {
+            self.scoped
+                .entry(dependent)
+                .or_default()
+                .entry(requirement.name.clone())
+                .or_default()
+                .push(requirement);
+        }
+        self
     }
 
-    /// Return an iterator over all [`Requirement`]s in the override set.
+    /// Return an iterator over all [`Requirement`]s in the override set, including scoped
+    /// overrides.
     pub fn requirements(&self) -> impl Iterator<Item = &Requirement> {
-        self.0.values().flat_map(|requirements| requirements.iter())
+        self.global
+            .values()
+            .chain(self.scoped.values().flat_map(FxHashMap::values))
+            .flat_map(|requirements| requirements.iter())
     }
 
-    /// Get the overrides for a package.
+    /// Get the global overrides for a package.
     pub fn get(&self, name: &PackageName) -> Option<&Vec<Requirement>> {
-        self.0.get(name)
+        self.global.get(name)
     }
 
-    /// Apply the overrides to a set of requirements.
+    /// Get the overrides for a package that only apply to the requirements of the given
+    /// dependent.
+    pub fn get_scoped(
+        &self,
+        dependent: &PackageName,
+        name: &PackageName,
+    ) -> Option<&Vec<Requirement>> {
+        self.scoped.get(dependent)?.get(name)
+    }
+
+    /// Apply the overrides to a set of requirements declared by the given dependent (or by the
+    /// root, if `None`).
     ///
     /// NB: Change this method together with [`Constraints::apply`].
     pub fn apply<'a>(
         &'a self,
+        dependent: Option<&'a PackageName>,
         requirements: impl IntoIterator<Item = &'a Requirement>,
     ) -> impl Iterator<Item = Cow<'a, Requirement>> {
-        if self.0.is_empty() {
+        if self.global.is_empty() && self.scoped.is_empty() {
             // Fast path: There are no overrides.
             return Either::Left(requirements.into_iter().map(Cow::Borrowed));
         }
 
-        Either::Right(requirements.into_iter().flat_map(|requirement| {
-            let Some(overrides) = self.get(&requirement.name) else {
+        Either::Right(requirements.into_iter().flat_map(move |requirement| {
+            let scoped =
+                dependent.and_then(|dependent| self.get_scoped(dependent, &requirement.name));
+            let global = self.get(&requirement.name);
+            if scoped.is_none() && global.is_none() {
                 // Case 1: No override(s).
                 return Either::Left(std::iter::once(Cow::Borrowed(requirement)));
+            }
+
+            let applied = if let Some(scoped) = scoped {
+                // Scoped overrides only apply in the environments in which their markers are
+                // satisfied. In the remaining environments, fall back to the global overrides (or
+                // the original requirement), restricted to those environments.
+                let mut covered = MarkerTree::FALSE;
+                let mut applied = Vec::with_capacity(scoped.len());
+                for override_requirement in scoped {
+                    applied.push(Cow::Borrowed(override_requirement));
+                    covered.or(override_requirement.marker);
+                }
+                if !covered.is_true() {
+                    let remaining = covered.negate();
+                    let fallback =
+                        global.map_or_else(|| std::slice::from_ref(requirement), Vec::as_slice);
+                    for fallback_requirement in fallback {
+                        let mut marker = fallback_requirement.marker;
+                        marker.and(remaining);
+                        if !marker.is_false() {
+                            applied.push(Cow::Owned(Requirement {
+                                marker,
+                                ..fallback_requirement.clone()
+                            }));
+                        }
+                    }
+                }
+                applied
+            } else {
+                // Global overrides replace the requirement unconditionally, regardless of their
+                // markers.
+                global
+                    .into_iter()
+                    .flatten()
+                    .map(Cow::Borrowed)
+                    .collect::<Vec<_>>()
             };
 
             // ASSUMPTION: There is one `extra = "..."`, and it's either the only marker or part
             // of the main conjunction.
             let Some(extra_expression) = requirement.marker.top_level_extra() else {
                 // Case 2: A non-optional dependency with override(s).
-                return Either::Right(Either::Right(overrides.iter().map(Cow::Borrowed)));
+                return Either::Right(Either::Right(applied.into_iter()));
             };
 
             // Case 3: An optional dependency with override(s).
             //
             // When the original requirement is an optional dependency, the override(s) need to
             // be optional for the same extra, otherwise we activate extras that should be inactive.
-            Either::Right(Either::Left(overrides.iter().map(
+            Either::Right(Either::Left(applied.into_iter().map(
                 move |override_requirement| {
                     // Add the extra to the override marker.
                     let mut joint_marker = MarkerTree::expression(extra_expression.clone());
                     joint_marker.and(override_requirement.marker);
                     Cow::Owned(Requirement {
                         marker: joint_marker,
-                        ..override_requirement.clone()
+                        ..override_requirement.into_owned()
                     })
                 },
             )))
@@ -126,7 +126,7 @@ impl From<Reinstall> for Refresh {
             Reinstall::None => Self::None(Timestamp::now()),
             Reinstall::All => Self::All(Timestamp::now()),
             Reinstall::Packages(packages, paths) => {
-                Self::Packages(packages, paths, Timestamp::now())
+                Self::Packages(packages, paths, Vec::new(), Timestamp::now())
             }
         }
     }
@@ -243,6 +243,7 @@ impl From<Upgrade> for Refresh {
             Upgrade::Packages(packages) => Self::Packages(
                 packages.into_keys().collect::<Vec<_>>(),
                 Vec::new(),
+                Vec::new(),
                 Timestamp::now(),
             ),
         }
@@ -0,0 +1,114 @@
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+/// A limit on the network bandwidth used for downloads, in bytes per second.
+///
+/// Parsed from a number followed by an optional unit and an optional `/s` suffix, e.g., `5MB/s`,
+/// `500K`, or `1MiB/s`. Decimal units (`K`, `M`, `G`) are powers of 1000; binary units (`Ki`,
+/// `Mi`, `Gi`) are powers of 1024. The trailing `B` is optional.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RateLimit(NonZeroU64);
+
+impl RateLimit {
+    /// Create a [`RateLimit`] from a number of bytes per second.
+    pub fn from_bytes_per_second(bytes: NonZeroU64) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the limit in bytes per second.
+    pub fn bytes_per_second(self) -> u64 {
+        self.0.get()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RateLimitError {
+    #[error("invalid rate limit `{0}`; expected a number with an optional unit, e.g., `5MB/s`")]
+    Invalid(String),
+    #[error("unknown unit in rate limit `{0}`; expected one of `K`, `M`, `G`, `Ki`, `Mi`, or `Gi`")]
+    UnknownUnit(String),
+    #[error("rate limit must be greater than zero: `{0}`")]
+    Zero(String),
+}
+
+impl FromStr for RateLimit {
+    type Err = RateLimitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let value = value.strip_suffix("/s").unwrap_or(value);
+
+        // Split into the numeric part and the unit.
+        let split = value
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| RateLimitError::Invalid(s.to_string()))?;
+
+        let unit = unit.trim();
+        let unit = unit
+            .strip_suffix('B')
+            .or_else(|| unit.strip_suffix('b'))
+            .unwrap_or(unit);
+        let multiplier: u64 = match unit.to_ascii_lowercase().as_str() {
+            "" => 1,
+            "k" => 1000,
+            "m" => 1000 * 1000,
+            "g" => 1000 * 1000 * 1000,
+            "ki" => 1024,
+            "mi" => 1024 * 1024,
+            "gi" => 1024 * 1024 * 1024,
+            _ => return Err(RateLimitError::UnknownUnit(s.to_string())),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bytes = (number * multiplier as f64).round() as u64;
+
+        NonZeroU64::new(bytes)
+            .map(Self)
+            .ok_or_else(|| RateLimitError::Zero(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B/s", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimit;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "5MB/s".parse::<RateLimit>().unwrap().bytes_per_second(),
+            5_000_000
+        );
+        assert_eq!(
+            "500K".parse::<RateLimit>().unwrap().bytes_per_second(),
+            500_000
+        );
+        assert_eq!(
+            "1MiB/s".parse::<RateLimit>().unwrap().bytes_per_second(),
+            1_048_576
+        );
+        assert_eq!(
+            "1.5kb".parse::<RateLimit>().unwrap().bytes_per_second(),
+            1500
+        );
+        assert_eq!(
+            "2048".parse::<RateLimit>().unwrap().bytes_per_second(),
+            2048
+        );
+
+        assert!("0".parse::<RateLimit>().is_err());
+        assert!("fast".parse::<RateLimit>().is_err());
+        assert!("5XB/s".parse::<RateLimit>().is_err());
+    }
+}
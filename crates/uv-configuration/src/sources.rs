@@ -2,6 +2,7 @@
     Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
 )]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SourceStrategy {
     /// Use `tool.uv.sources` when resolving dependencies.
     #[default]
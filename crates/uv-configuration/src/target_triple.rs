@@ -8,7 +8,7 @@ use uv_static::EnvVars;
 /// system.
 ///
 /// See: <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
-#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -39,6 +39,13 @@ pub enum TargetTriple {
     #[serde(rename = "i686-pc-windows-msvc")]
     I686PcWindowsMsvc,
 
+    /// An ARM64EC Windows target.
+    ///
+    /// ARM64EC code is ABI-compatible with `x86_64` code, so `win_amd64` wheels are selected.
+    #[cfg_attr(feature = "clap", value(name = "arm64ec-pc-windows-msvc"))]
+    #[serde(rename = "arm64ec-pc-windows-msvc")]
+    Arm64ecPcWindowsMsvc,
+
     /// An x86 Linux target. Equivalent to `x86_64-manylinux_2_28`.
     #[cfg_attr(feature = "clap", value(name = "x86_64-unknown-linux-gnu"))]
     #[serde(rename = "x86_64-unknown-linux-gnu")]
@@ -79,10 +86,29 @@ pub enum TargetTriple {
     X8664UnknownLinuxMusl,
 
     /// A RISCV64 Linux target.
-    #[cfg_attr(feature = "clap", value(name = "riscv64-unknown-linux"))]
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "riscv64-unknown-linux", alias = "riscv64-unknown-linux-gnu")
+    )]
     #[serde(rename = "riscv64-unknown-linux")]
+    #[serde(alias = "riscv64-unknown-linux-gnu")]
     Riscv64UnknownLinuxGnu,
 
+    /// A RISCV64 Linux target, using musl.
+    #[cfg_attr(feature = "clap", value(name = "riscv64-unknown-linux-musl"))]
+    #[serde(rename = "riscv64-unknown-linux-musl")]
+    Riscv64UnknownLinuxMusl,
+
+    /// An ARMv7 Linux target, using the hard-float ABI. Equivalent to `armv7l-manylinux_2_17`.
+    #[cfg_attr(feature = "clap", value(name = "armv7-unknown-linux-gnueabihf"))]
+    #[serde(rename = "armv7-unknown-linux-gnueabihf")]
+    Armv7UnknownLinuxGnueabihf,
+
+    /// An ARMv7 Linux target, using musl and the hard-float ABI.
+    #[cfg_attr(feature = "clap", value(name = "armv7-unknown-linux-musleabihf"))]
+    #[serde(rename = "armv7-unknown-linux-musleabihf")]
+    Armv7UnknownLinuxMusleabihf,
+
     /// An `x86_64` target for the `manylinux2014` platform. Equivalent to `x86_64-manylinux_2_17`.
     #[cfg_attr(feature = "clap", value(name = "x86_64-manylinux2014"))]
     #[serde(rename = "x86_64-manylinux2014")]
@@ -254,10 +280,35 @@ pub enum TargetTriple {
     #[serde(rename = "x86_64-linux-android")]
     X8664LinuxAndroid,
 
+    /// An ARMv7 Android target.
+    ///
+    /// By default uses Android API level 24, but respects
+    /// the `ANDROID_API_LEVEL` environment variable if set.
+    #[cfg_attr(feature = "clap", value(name = "armv7-linux-androideabi"))]
+    #[serde(rename = "armv7-linux-androideabi")]
+    Armv7LinuxAndroideabi,
+
+    /// A 32-bit x86 Android target.
+    ///
+    /// By default uses Android API level 24, but respects
+    /// the `ANDROID_API_LEVEL` environment variable if set.
+    #[cfg_attr(feature = "clap", value(name = "i686-linux-android"))]
+    #[serde(rename = "i686-linux-android")]
+    I686LinuxAndroid,
+
     /// A wasm32 target using the Pyodide 2024 platform. Meant for use with Python 3.12.
     #[cfg_attr(feature = "clap", value(name = "wasm32-pyodide2024"))]
     Wasm32Pyodide2024,
 
+    /// A wasm32 target using the WebAssembly System Interface (WASI).
+    ///
+    /// There are no platform-specific wheel tags for WASI, so only pure Python wheels (and
+    /// source distributions) are compatible.
+    #[cfg_attr(feature = "clap", value(name = "wasm32-wasi", alias = "wasm32-wasip1"))]
+    #[serde(rename = "wasm32-wasi")]
+    #[serde(alias = "wasm32-wasip1")]
+    Wasm32Wasi,
+
     /// An ARM64 target for iOS device
     ///
     /// By default, iOS 13.0 is used, but respects the `IPHONEOS_DEPLOYMENT_TARGET`
@@ -304,6 +355,7 @@ impl TargetTriple {
                 Platform::new(Os::Macos { major, minor }, Arch::Aarch64)
             }
             Self::I686PcWindowsMsvc => Platform::new(Os::Windows, Arch::X86),
+            Self::Arm64ecPcWindowsMsvc => Platform::new(Os::Windows, Arch::X86_64),
             Self::X8664AppleDarwin => {
                 let (major, minor) = macos_deployment_target().map_or((13, 0), |(major, minor)| {
                     debug!("Found macOS deployment target: {}.{}", major, minor);
@@ -325,6 +377,19 @@ impl TargetTriple {
                 },
                 Arch::Riscv64,
             ),
+            Self::Riscv64UnknownLinuxMusl => {
+                Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::Riscv64)
+            }
+            Self::Armv7UnknownLinuxGnueabihf => Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 17,
+                },
+                Arch::Armv7L,
+            ),
+            Self::Armv7UnknownLinuxMusleabihf => {
+                Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::Armv7L)
+            }
             Self::Aarch64UnknownLinuxMusl => {
                 Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::Aarch64)
             }
@@ -520,6 +585,7 @@ impl TargetTriple {
                 },
                 Arch::Wasm32,
             ),
+            Self::Wasm32Wasi => Platform::new(Os::Wasi, Arch::Wasm32),
             Self::Aarch64LinuxAndroid => {
                 let api_level = android_api_level().map_or(24, |api_level| {
                     debug!("Found Android API level: {}", api_level);
@@ -534,6 +600,20 @@ impl TargetTriple {
                 });
                 Platform::new(Os::Android { api_level }, Arch::X86_64)
             }
+            Self::Armv7LinuxAndroideabi => {
+                let api_level = android_api_level().map_or(24, |api_level| {
+                    debug!("Found Android API level: {}", api_level);
+                    api_level
+                });
+                Platform::new(Os::Android { api_level }, Arch::Armv7L)
+            }
+            Self::I686LinuxAndroid => {
+                let api_level = android_api_level().map_or(24, |api_level| {
+                    debug!("Found Android API level: {}", api_level);
+                    api_level
+                });
+                Platform::new(Os::Android { api_level }, Arch::X86)
+            }
             Self::Arm64Ios => {
                 let (major, minor) = ios_deployment_target().map_or((13, 0), |(major, minor)| {
                     debug!("Found iOS deployment target: {}.{}", major, minor);
@@ -587,11 +667,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "x86_64",
             Self::Macos | Self::Aarch64AppleDarwin => "arm64",
             Self::I686PcWindowsMsvc => "x86",
+            Self::Arm64ecPcWindowsMsvc => "ARM64",
             Self::X8664AppleDarwin => "x86_64",
             Self::Aarch64UnknownLinuxGnu => "aarch64",
             Self::Aarch64UnknownLinuxMusl => "aarch64",
             Self::X8664UnknownLinuxMusl => "x86_64",
             Self::Riscv64UnknownLinuxGnu => "riscv64",
+            Self::Riscv64UnknownLinuxMusl => "riscv64",
+            Self::Armv7UnknownLinuxGnueabihf => "armv7l",
+            Self::Armv7UnknownLinuxMusleabihf => "armv7l",
             Self::X8664Manylinux2014 => "x86_64",
             Self::X8664Manylinux217 => "x86_64",
             Self::X8664Manylinux228 => "x86_64",
@@ -620,7 +704,10 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "aarch64",
             Self::Aarch64LinuxAndroid => "aarch64",
             Self::X8664LinuxAndroid => "x86_64",
+            Self::Armv7LinuxAndroideabi => "armv7l",
+            Self::I686LinuxAndroid => "i686",
             Self::Wasm32Pyodide2024 => "wasm32",
+            Self::Wasm32Wasi => "wasm32",
             Self::Arm64Ios => "arm64",
             Self::Arm64IosSimulator => "arm64",
             Self::X8664IosSimulator => "x86_64",
@@ -635,11 +722,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "Linux",
             Self::Macos | Self::Aarch64AppleDarwin => "Darwin",
             Self::I686PcWindowsMsvc => "Windows",
+            Self::Arm64ecPcWindowsMsvc => "Windows",
             Self::X8664AppleDarwin => "Darwin",
             Self::Aarch64UnknownLinuxGnu => "Linux",
             Self::Aarch64UnknownLinuxMusl => "Linux",
             Self::X8664UnknownLinuxMusl => "Linux",
             Self::Riscv64UnknownLinuxGnu => "Linux",
+            Self::Riscv64UnknownLinuxMusl => "Linux",
+            Self::Armv7UnknownLinuxGnueabihf => "Linux",
+            Self::Armv7UnknownLinuxMusleabihf => "Linux",
             Self::X8664Manylinux2014 => "Linux",
             Self::X8664Manylinux217 => "Linux",
             Self::X8664Manylinux228 => "Linux",
@@ -668,7 +759,10 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "Linux",
             Self::Aarch64LinuxAndroid => "Android",
             Self::X8664LinuxAndroid => "Android",
+            Self::Armv7LinuxAndroideabi => "Android",
+            Self::I686LinuxAndroid => "Android",
             Self::Wasm32Pyodide2024 => "Emscripten",
+            Self::Wasm32Wasi => "wasi",
             Self::Arm64Ios => "iOS",
             Self::Arm64IosSimulator => "iOS",
             Self::X8664IosSimulator => "iOS",
@@ -683,11 +777,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "",
             Self::Macos | Self::Aarch64AppleDarwin => "",
             Self::I686PcWindowsMsvc => "",
+            Self::Arm64ecPcWindowsMsvc => "",
             Self::X8664AppleDarwin => "",
             Self::Aarch64UnknownLinuxGnu => "",
             Self::Aarch64UnknownLinuxMusl => "",
             Self::X8664UnknownLinuxMusl => "",
             Self::Riscv64UnknownLinuxGnu => "",
+            Self::Riscv64UnknownLinuxMusl => "",
+            Self::Armv7UnknownLinuxGnueabihf => "",
+            Self::Armv7UnknownLinuxMusleabihf => "",
             Self::X8664Manylinux2014 => "",
             Self::X8664Manylinux217 => "",
             Self::X8664Manylinux228 => "",
@@ -716,10 +814,13 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "",
             Self::Aarch64LinuxAndroid => "",
             Self::X8664LinuxAndroid => "",
+            Self::Armv7LinuxAndroideabi => "",
+            Self::I686LinuxAndroid => "",
             // This is the value Emscripten gives for its version:
             // https://github.com/emscripten-core/emscripten/blob/4.0.8/system/lib/libc/emscripten_syscall_stubs.c#L63
             // It doesn't really seem to mean anything? But for completeness we include it here.
             Self::Wasm32Pyodide2024 => "#1",
+            Self::Wasm32Wasi => "",
             Self::Arm64Ios => "",
             Self::Arm64IosSimulator => "",
             Self::X8664IosSimulator => "",
@@ -734,11 +835,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "",
             Self::Macos | Self::Aarch64AppleDarwin => "",
             Self::I686PcWindowsMsvc => "",
+            Self::Arm64ecPcWindowsMsvc => "",
             Self::X8664AppleDarwin => "",
             Self::Aarch64UnknownLinuxGnu => "",
             Self::Aarch64UnknownLinuxMusl => "",
             Self::X8664UnknownLinuxMusl => "",
             Self::Riscv64UnknownLinuxGnu => "",
+            Self::Riscv64UnknownLinuxMusl => "",
+            Self::Armv7UnknownLinuxGnueabihf => "",
+            Self::Armv7UnknownLinuxMusleabihf => "",
             Self::X8664Manylinux2014 => "",
             Self::X8664Manylinux217 => "",
             Self::X8664Manylinux228 => "",
@@ -767,9 +872,12 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "",
             Self::Aarch64LinuxAndroid => "",
             Self::X8664LinuxAndroid => "",
+            Self::Armv7LinuxAndroideabi => "",
+            Self::I686LinuxAndroid => "",
             // This is the Emscripten compiler version for Pyodide 2024.
             // See https://pyodide.org/en/stable/development/abi.html#pyodide-2024-0
             Self::Wasm32Pyodide2024 => "3.1.58",
+            Self::Wasm32Wasi => "",
             Self::Arm64Ios => "",
             Self::Arm64IosSimulator => "",
             Self::X8664IosSimulator => "",
@@ -784,11 +892,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "posix",
             Self::Macos | Self::Aarch64AppleDarwin => "posix",
             Self::I686PcWindowsMsvc => "nt",
+            Self::Arm64ecPcWindowsMsvc => "nt",
             Self::X8664AppleDarwin => "posix",
             Self::Aarch64UnknownLinuxGnu => "posix",
             Self::Aarch64UnknownLinuxMusl => "posix",
             Self::X8664UnknownLinuxMusl => "posix",
             Self::Riscv64UnknownLinuxGnu => "posix",
+            Self::Riscv64UnknownLinuxMusl => "posix",
+            Self::Armv7UnknownLinuxGnueabihf => "posix",
+            Self::Armv7UnknownLinuxMusleabihf => "posix",
             Self::X8664Manylinux2014 => "posix",
             Self::X8664Manylinux217 => "posix",
             Self::X8664Manylinux228 => "posix",
@@ -817,7 +929,10 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "posix",
             Self::Aarch64LinuxAndroid => "posix",
             Self::X8664LinuxAndroid => "posix",
+            Self::Armv7LinuxAndroideabi => "posix",
+            Self::I686LinuxAndroid => "posix",
             Self::Wasm32Pyodide2024 => "posix",
+            Self::Wasm32Wasi => "posix",
             Self::Arm64Ios => "posix",
             Self::Arm64IosSimulator => "posix",
             Self::X8664IosSimulator => "posix",
@@ -832,11 +947,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => "linux",
             Self::Macos | Self::Aarch64AppleDarwin => "darwin",
             Self::I686PcWindowsMsvc => "win32",
+            Self::Arm64ecPcWindowsMsvc => "win32",
             Self::X8664AppleDarwin => "darwin",
             Self::Aarch64UnknownLinuxGnu => "linux",
             Self::Aarch64UnknownLinuxMusl => "linux",
             Self::X8664UnknownLinuxMusl => "linux",
             Self::Riscv64UnknownLinuxGnu => "linux",
+            Self::Riscv64UnknownLinuxMusl => "linux",
+            Self::Armv7UnknownLinuxGnueabihf => "linux",
+            Self::Armv7UnknownLinuxMusleabihf => "linux",
             Self::X8664Manylinux2014 => "linux",
             Self::X8664Manylinux217 => "linux",
             Self::X8664Manylinux228 => "linux",
@@ -865,7 +984,10 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => "linux",
             Self::Aarch64LinuxAndroid => "android",
             Self::X8664LinuxAndroid => "android",
+            Self::Armv7LinuxAndroideabi => "android",
+            Self::I686LinuxAndroid => "android",
             Self::Wasm32Pyodide2024 => "emscripten",
+            Self::Wasm32Wasi => "wasi",
             Self::Arm64Ios => "ios",
             Self::Arm64IosSimulator => "ios",
             Self::X8664IosSimulator => "ios",
@@ -880,11 +1002,15 @@ impl TargetTriple {
             Self::Linux | Self::X8664UnknownLinuxGnu => true,
             Self::Macos | Self::Aarch64AppleDarwin => false,
             Self::I686PcWindowsMsvc => false,
+            Self::Arm64ecPcWindowsMsvc => false,
             Self::X8664AppleDarwin => false,
             Self::Aarch64UnknownLinuxGnu => true,
             Self::Aarch64UnknownLinuxMusl => true,
             Self::X8664UnknownLinuxMusl => true,
             Self::Riscv64UnknownLinuxGnu => true,
+            Self::Riscv64UnknownLinuxMusl => true,
+            Self::Armv7UnknownLinuxGnueabihf => true,
+            Self::Armv7UnknownLinuxMusleabihf => true,
             Self::X8664Manylinux2014 => true,
             Self::X8664Manylinux217 => true,
             Self::X8664Manylinux228 => true,
@@ -913,7 +1039,10 @@ impl TargetTriple {
             Self::Aarch64Manylinux240 => true,
             Self::Aarch64LinuxAndroid => false,
             Self::X8664LinuxAndroid => false,
+            Self::Armv7LinuxAndroideabi => false,
+            Self::I686LinuxAndroid => false,
             Self::Wasm32Pyodide2024 => false,
+            Self::Wasm32Wasi => false,
             Self::Arm64Ios => false,
             Self::Arm64IosSimulator => false,
             Self::X8664IosSimulator => false,
@@ -198,6 +198,10 @@ impl BuildContext for BuildDispatch<'_> {
         &self.shared_state.capabilities
     }
 
+    fn concurrency(&self) -> &Concurrency {
+        &self.concurrency
+    }
+
     fn dependency_metadata(&self) -> &DependencyMetadata {
         self.dependency_metadata
     }
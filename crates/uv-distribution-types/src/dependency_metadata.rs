@@ -51,6 +51,8 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                license: None,
+                homepage: None,
             })
         } else {
             // If no version was requested (i.e., it's a direct URL dependency), allow a single
@@ -72,10 +74,27 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                license: None,
+                homepage: None,
             })
         }
     }
 
+    /// Retrieve the [`StaticMetadata`] entries for the given [`PackageName`], discarding all
+    /// other entries.
+    #[must_use]
+    pub fn subset(&self, package: &PackageName) -> Self {
+        self.0
+            .get(package)
+            .map(|entries| Self::from_entries(entries.iter().cloned()))
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if there are no [`StaticMetadata`] entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Retrieve all [`StaticMetadata`] entries.
     pub fn values(&self) -> impl Iterator<Item = &StaticMetadata> {
         self.0.values().flatten()
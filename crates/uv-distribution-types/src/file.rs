@@ -40,6 +40,9 @@ pub struct File {
     pub upload_time_utc_ms: Option<i64>,
     pub url: FileLocation,
     pub yanked: Option<Box<Yanked>>,
+    /// The time at which the file was yanked, as a UTC timestamp in milliseconds, if the index
+    /// reports it.
+    pub yanked_time_utc_ms: Option<i64>,
     pub zstd: Option<Box<Zstd>>,
 }
 
@@ -64,6 +67,7 @@ impl File {
             upload_time_utc_ms: file.upload_time.map(Timestamp::as_millisecond),
             url: FileLocation::new(file.url, base),
             yanked: file.yanked,
+            yanked_time_utc_ms: file.yanked_time.map(Timestamp::as_millisecond),
             zstd: None,
         })
     }
@@ -110,6 +114,7 @@ impl File {
             upload_time_utc_ms: file.upload_time.map(Timestamp::as_millisecond),
             url: FileLocation::new(file.url, base),
             yanked: file.yanked,
+            yanked_time_utc_ms: file.yanked_time.map(Timestamp::as_millisecond),
             zstd: file
                 .zstd
                 .map(|zstd| Zstd {
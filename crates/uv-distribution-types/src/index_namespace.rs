@@ -0,0 +1,232 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+use uv_small_str::SmallString;
+
+use crate::{Index, IndexName};
+
+/// A pattern that matches package names, in which `*` matches any sequence of characters (e.g.,
+/// `mycorp-*`).
+///
+/// Patterns are normalized in the same way as package names, such that `MyCorp_*` and `mycorp-*`
+/// are equivalent.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize)]
+pub struct PackageNamePattern(SmallString);
+
+impl PackageNamePattern {
+    /// Validate and normalize the given pattern.
+    pub fn new(pattern: &str) -> Result<Self, PackageNamePatternError> {
+        if pattern.is_empty() {
+            return Err(PackageNamePatternError::Empty);
+        }
+
+        let mut normalized = String::with_capacity(pattern.len());
+        let mut last = None;
+        for c in pattern.chars() {
+            let c = match c {
+                'A'..='Z' => c.to_ascii_lowercase(),
+                'a'..='z' | '0'..='9' | '*' => c,
+                '-' | '_' | '.' => {
+                    // Collapse runs of separators, as in package name normalization.
+                    if last == Some('-') {
+                        continue;
+                    }
+                    '-'
+                }
+                c => {
+                    return Err(PackageNamePatternError::UnsupportedCharacter(
+                        c,
+                        pattern.to_string(),
+                    ));
+                }
+            };
+            normalized.push(c);
+            last = Some(c);
+        }
+
+        Ok(Self(SmallString::from(normalized)))
+    }
+
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        let mut parts = self.0.split('*');
+        let mut name = name.as_ref();
+
+        // The first part must be a prefix of the name.
+        let first = parts.next().unwrap_or_default();
+        let Some(rest) = name.strip_prefix(first) else {
+            return false;
+        };
+        name = rest;
+
+        let mut parts = parts.peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                // The last part must be a suffix of the remaining name.
+                return name.ends_with(part);
+            }
+            let Some(index) = name.find(part) else {
+                return false;
+            };
+            name = &name[index + part.len()..];
+        }
+
+        // If the pattern doesn't contain a wildcard, it must match the name exactly.
+        name.is_empty()
+    }
+}
+
+impl FromStr for PackageNamePattern {
+    type Err = PackageNamePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for PackageNamePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = Cow::<'_, str>::deserialize(deserializer)?;
+        Self::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PackageNamePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PackageNamePattern {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("PackageNamePattern")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A pattern that matches package names, in which `*` matches any sequence of characters (e.g., `mycorp-*`).",
+        })
+    }
+}
+
+/// An error that can occur when parsing a [`PackageNamePattern`].
+#[derive(Error, Debug)]
+pub enum PackageNamePatternError {
+    #[error("Package name patterns must not be empty")]
+    Empty,
+    #[error(
+        "Package name patterns may only contain letters, digits, hyphens, underscores, periods, and `*`, but found unsupported character (`{0}`) in: `{1}`"
+    )]
+    UnsupportedCharacter(char, String),
+}
+
+/// A map from package name patterns to the index from which matching packages must be fetched.
+///
+/// For example, given:
+/// ```toml
+/// [[tool.uv.index]]
+/// name = "internal"
+/// url = "https://pypi.mycorp.internal/simple"
+///
+/// [tool.uv.index-namespaces]
+/// "mycorp-*" = "internal"
+/// ```
+///
+/// Any package whose name starts with `mycorp-` may only be fetched from the `internal` index,
+/// guarding against dependency confusion attacks in which a package of the same name is published
+/// to a public index.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct IndexNamespaces(BTreeMap<PackageNamePattern, IndexName>);
+
+impl IndexNamespaces {
+    /// Returns `true` if no namespaces are defined.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge two sets of [`IndexNamespaces`], preferring the namespaces in `self` when both
+    /// define the same pattern.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (pattern, index) in other.0 {
+            self.0.entry(pattern).or_insert(index);
+        }
+        self
+    }
+
+    /// Return the namespace that applies to the given package, if any, resolving the pinned
+    /// index against the given [`Index`] definitions.
+    ///
+    /// If multiple patterns match, the longest (i.e., most specific) pattern is used.
+    pub fn find<'a>(
+        &'a self,
+        package: &PackageName,
+        indexes: &'a [Index],
+    ) -> Option<IndexNamespace<'a>> {
+        let (pattern, name) = self
+            .0
+            .iter()
+            .filter(|(pattern, _)| pattern.matches(package))
+            .max_by_key(|(pattern, _)| pattern.0.len())?;
+        let index = indexes
+            .iter()
+            .find(|index| index.name.as_ref() == Some(name));
+        Some(IndexNamespace {
+            pattern,
+            name,
+            index,
+        })
+    }
+}
+
+/// A namespace that applies to a specific package.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexNamespace<'a> {
+    /// The pattern that matched the package.
+    pub pattern: &'a PackageNamePattern,
+    /// The name of the index to which the package is pinned.
+    pub name: &'a IndexName,
+    /// The pinned [`Index`], if an index with the given name is defined.
+    pub index: Option<&'a Index>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        PackageNamePattern::new(pattern)
+            .unwrap()
+            .matches(&PackageName::from_str(name).unwrap())
+    }
+
+    #[test]
+    fn pattern_matches() {
+        assert!(matches("mycorp-*", "mycorp-utils"));
+        assert!(matches("MyCorp_*", "mycorp-utils"));
+        assert!(!matches("mycorp-*", "mycorp"));
+        assert!(!matches("mycorp-*", "notmycorp-utils"));
+        assert!(matches("*-internal", "auth-internal"));
+        assert!(matches("mycorp-*-sdk", "mycorp-billing-sdk"));
+        assert!(!matches("mycorp-*-sdk", "mycorp-billing-sdk-extras"));
+        assert!(matches("mycorp", "mycorp"));
+        assert!(!matches("mycorp", "mycorp-utils"));
+        assert!(matches("*", "anything"));
+    }
+
+    #[test]
+    fn pattern_invalid() {
+        assert!(PackageNamePattern::new("").is_err());
+        assert!(PackageNamePattern::new("mycorp/*").is_err());
+    }
+}
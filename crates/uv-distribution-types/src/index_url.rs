@@ -11,11 +11,12 @@ use thiserror::Error;
 use tracing::trace;
 use url::{ParseError, Url};
 
+use uv_normalize::PackageName;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{Index, IndexNamespace, IndexNamespaces, IndexStatusCodeStrategy, Verbatim};
 
 static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -258,6 +259,8 @@ pub struct IndexLocations {
     indexes: Vec<Index>,
     flat_index: Vec<Index>,
     no_index: bool,
+    #[serde(default, skip_serializing_if = "IndexNamespaces::is_empty")]
+    namespaces: IndexNamespaces,
 }
 
 impl IndexLocations {
@@ -267,9 +270,17 @@ impl IndexLocations {
             indexes,
             flat_index,
             no_index,
+            namespaces: IndexNamespaces::default(),
         }
     }
 
+    /// Set the [`IndexNamespaces`] that restrict packages to specific indexes.
+    #[must_use]
+    pub fn with_namespaces(mut self, namespaces: IndexNamespaces) -> Self {
+        self.namespaces = namespaces;
+        self
+    }
+
     /// Combine a set of index locations.
     ///
     /// If either the current or the other index locations have `no_index` set, the result will
@@ -282,6 +293,16 @@ impl IndexLocations {
             indexes: self.indexes.into_iter().chain(indexes).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            namespaces: self.namespaces,
+        }
+    }
+
+    /// Prepend a set of indexes, such that they take priority over the existing indexes.
+    #[must_use]
+    pub fn prepend(self, indexes: Vec<Index>) -> Self {
+        Self {
+            indexes: indexes.into_iter().chain(self.indexes).collect(),
+            ..self
         }
     }
 
@@ -373,9 +394,16 @@ impl<'a> IndexLocations {
         IndexUrls {
             indexes: self.indexes.clone(),
             no_index: self.no_index,
+            namespaces: self.namespaces.clone(),
         }
     }
 
+    /// Return the [`IndexNamespace`] that restricts the given package to a specific index, if
+    /// any.
+    pub fn namespace_for(&'a self, package: &PackageName) -> Option<IndexNamespace<'a>> {
+        self.namespaces.find(package, &self.indexes)
+    }
+
     /// Return a vector containing all allowed [`Index`] entries.
     ///
     /// This includes explicit indexes, implicit indexes, flat indexes, and the default index.
@@ -501,6 +529,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
 pub struct IndexUrls {
     indexes: Vec<Index>,
     no_index: bool,
+    namespaces: IndexNamespaces,
 }
 
 impl<'a> IndexUrls {
@@ -508,9 +537,16 @@ impl<'a> IndexUrls {
         Self {
             indexes,
             no_index: false,
+            namespaces: IndexNamespaces::default(),
         }
     }
 
+    /// Return the [`IndexNamespace`] that restricts the given package to a specific index, if
+    /// any.
+    pub fn namespace_for(&'a self, package: &PackageName) -> Option<IndexNamespace<'a>> {
+        self.namespaces.find(package, &self.indexes)
+    }
+
     /// Return the default [`Index`] entry.
     ///
     /// If `--no-index` is set, return `None`.
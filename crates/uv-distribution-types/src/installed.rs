@@ -484,6 +484,14 @@ impl InstalledDist {
         }
     }
 
+    /// Return `true` if the distribution was explicitly requested, as indicated by the presence of
+    /// a `REQUESTED` file.
+    ///
+    /// See: <https://peps.python.org/pep-0376/#requested>
+    pub fn read_requested(&self) -> bool {
+        self.install_path().join("REQUESTED").is_file()
+    }
+
     /// Return the supported wheel tags for the distribution from the `WHEEL` file, if available.
     pub fn read_tags(&self) -> Result<Option<&ExpandedTags>, InstalledDistError> {
         if let Some(tags) = self.tags_cache.get() {
@@ -533,6 +541,17 @@ impl InstalledDist {
         }
     }
 
+    /// Return the [`DirectUrl`] of the distribution, if it was installed from a direct URL.
+    pub fn direct_url(&self) -> Option<&DirectUrl> {
+        match &self.kind {
+            InstalledDistKind::Url(dist) => Some(&dist.direct_url),
+            InstalledDistKind::Registry(_)
+            | InstalledDistKind::EggInfoFile(_)
+            | InstalledDistKind::EggInfoDirectory(_)
+            | InstalledDistKind::LegacyEditable(_) => None,
+        }
+    }
+
     /// Return true if the distribution refers to a local file or directory.
     pub fn is_local(&self) -> bool {
         match &self.kind {
@@ -68,6 +68,7 @@ pub use crate::hash::*;
 pub use crate::id::*;
 pub use crate::index::*;
 pub use crate::index_name::*;
+pub use crate::index_namespace::*;
 pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
@@ -99,6 +100,7 @@ mod hash;
 mod id;
 mod index;
 mod index_name;
+mod index_namespace;
 mod index_url;
 mod installed;
 mod known_platform;
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter};
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use thiserror::Error;
@@ -11,7 +11,8 @@ use uv_git_types::{GitOid, GitReference, GitUrl, GitUrlParseError, OidParseError
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{
-    MarkerEnvironment, MarkerTree, RequirementOrigin, VerbatimUrl, VersionOrUrl, marker,
+    MarkerEnvironment, MarkerTree, RequirementOrigin, VerbatimUrl, VersionOrUrl, contains_env_vars,
+    expand_path_vars, marker,
 };
 use uv_redacted::DisplaySafeUrl;
 
@@ -676,6 +677,9 @@ impl RequirementSource {
     }
 
     /// Convert the source to a [`RequirementSource`] relative to the given path.
+    ///
+    /// If the path was provided with variables (e.g., `${WORKSPACE_ROOT}/dist/foo.whl`), the
+    /// unexpanded path is retained as-is.
     pub fn relative_to(self, path: &Path) -> Result<Self, io::Error> {
         match self {
             Self::Registry { .. } | Self::Url { .. } | Self::Git { .. } => Ok(self),
@@ -684,9 +688,7 @@ impl RequirementSource {
                 ext,
                 url,
             } => Ok(Self::Path {
-                install_path: relative_to(&install_path, path)
-                    .or_else(|_| std::path::absolute(install_path))?
-                    .into_boxed_path(),
+                install_path: relative_install_path(&url, install_path, path)?,
                 ext,
                 url,
             }),
@@ -697,9 +699,7 @@ impl RequirementSource {
                 url,
                 ..
             } => Ok(Self::Directory {
-                install_path: relative_to(&install_path, path)
-                    .or_else(|_| std::path::absolute(install_path))?
-                    .into_boxed_path(),
+                install_path: relative_install_path(&url, install_path, path)?,
                 editable,
                 r#virtual,
                 url,
@@ -717,7 +717,10 @@ impl RequirementSource {
                 ext,
                 url,
             } => Self::Path {
-                install_path: uv_fs::normalize_path_buf(root.join(install_path)).into_boxed_path(),
+                install_path: uv_fs::normalize_path_buf(
+                    root.join(expand_path_vars(&install_path, root)),
+                )
+                .into_boxed_path(),
                 ext,
                 url,
             },
@@ -728,7 +731,10 @@ impl RequirementSource {
                 url,
                 ..
             } => Self::Directory {
-                install_path: uv_fs::normalize_path_buf(root.join(install_path)).into_boxed_path(),
+                install_path: uv_fs::normalize_path_buf(
+                    root.join(expand_path_vars(&install_path, root)),
+                )
+                .into_boxed_path(),
                 editable,
                 r#virtual,
                 url,
@@ -737,6 +743,21 @@ impl RequirementSource {
     }
 }
 
+/// Convert an install path to a path relative to the given root, retaining any unexpanded
+/// variables from the path as originally provided.
+fn relative_install_path(
+    url: &VerbatimUrl,
+    install_path: Box<Path>,
+    root: &Path,
+) -> Result<Box<Path>, io::Error> {
+    if let Some(given) = url.given().filter(|given| contains_env_vars(given)) {
+        return Ok(PathBuf::from(given).into_boxed_path());
+    }
+    Ok(relative_to(&install_path, root)
+        .or_else(|_| std::path::absolute(install_path))?
+        .into_boxed_path())
+}
+
 impl Display for RequirementSource {
     /// Display the [`RequirementSource`], with the intention of being shown directly to a user,
     /// rather than for inclusion in a `requirements.txt` file.
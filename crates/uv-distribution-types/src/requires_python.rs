@@ -600,6 +600,15 @@ impl SimplifiedMarkerTree {
         requires_python.complexify_markers(self.0)
     }
 
+    /// Further simplifies the given markers by assuming the given environment markers are true.
+    ///
+    /// Unlike `requires-python`, the assumption is not restored by [`Self::into_marker`]; the
+    /// caller is responsible for adding it back as a constraint.
+    #[must_use]
+    pub fn assuming(self, assumption: MarkerTree) -> Self {
+        Self(self.0.simplify_assuming(assumption))
+    }
+
     /// Attempts to convert this simplified marker to a string.
     ///
     /// This only returns `None` when the underlying marker is always true,
@@ -1,3 +1,6 @@
+use petgraph::Direction;
+use rustc_hash::FxHashSet;
+
 use uv_distribution_filename::DistExtension;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pypi_types::{HashDigest, HashDigests};
@@ -64,6 +67,21 @@ impl Resolution {
             })
     }
 
+    /// Return the names of the packages that were explicitly requested, i.e., the direct
+    /// dependencies of the root (such as the requirements passed to `uv pip install`, or the
+    /// workspace members in a project).
+    pub fn requested(&self) -> FxHashSet<PackageName> {
+        self.graph
+            .node_indices()
+            .filter(|index| matches!(self.graph[*index], Node::Root))
+            .flat_map(|root| self.graph.neighbors_directed(root, Direction::Outgoing))
+            .filter_map(|index| match &self.graph[index] {
+                Node::Dist { dist, .. } => Some(dist.name().clone()),
+                Node::Root => None,
+            })
+            .collect()
+    }
+
     /// Return the number of distributions in this resolution.
     pub fn len(&self) -> usize {
         self.distributions().count()
@@ -13,7 +13,7 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{Instrument, info_span, instrument, warn};
 use url::Url;
 
-use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{ArchiveId, Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -26,7 +26,7 @@ use uv_distribution_types::{
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
+use uv_pypi_types::{HashDigest, HashDigests, Hashes, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
 
@@ -558,6 +558,24 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .await
     }
 
+    /// If no hashes are otherwise required, return a policy that validates against the checksum
+    /// pinned by the mirror manifest for the given URL, if any.
+    fn with_mirror_hashes<'h>(&self, url: &DisplaySafeUrl, hashes: HashPolicy<'h>) -> HashPolicy<'h>
+    where
+        'a: 'h,
+    {
+        if !hashes.is_none() {
+            return hashes;
+        }
+        let client: &'a RegistryClient = self.client.unmanaged;
+        match client.cached_client().uncached().mirror_manifest() {
+            Some(manifest) if !manifest.digests(url).is_empty() => {
+                HashPolicy::Validate(manifest.digests(url))
+            }
+            _ => hashes,
+        }
+    }
+
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
     async fn stream_wheel(
         &self,
@@ -570,6 +588,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
     ) -> Result<Archive, Error> {
+        let hashes = self.with_mirror_hashes(&url, hashes);
+
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
         let _lock = {
@@ -589,8 +609,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                let reader = self
+                    .client
+                    .unmanaged
+                    .cached_client()
+                    .uncached()
+                    .throttle(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -638,6 +662,19 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
 
+                // Validate the hashes before admitting the wheel into the cache.
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<HashDigests>();
+                validate_download(
+                    self.build_context.cache(),
+                    dist,
+                    temp_dir.path(),
+                    hashes,
+                    digests.as_slice(),
+                )?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -650,11 +687,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                    filename.clone(),
-                ))
+                Ok(Archive::new(id, digests, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -672,12 +705,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 }) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
+                    let freshness = self
+                        .build_context
+                        .cache()
+                        .freshness(&http_entry, Some(&filename.name), None)
+                        .map_err(Error::CacheRead)?;
+                    if matches!(dist, BuiltDist::DirectUrl(_)) {
+                        direct_url_cache_control(freshness, &url, hashes)
+                    } else {
+                        CacheControl::from(freshness)
+                    }
                 }
             }
             Connectivity::Offline => CacheControl::AllowStale,
@@ -741,6 +778,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
     ) -> Result<Archive, Error> {
+        let hashes = self.with_mirror_hashes(&url, hashes);
+
         // Acquire an advisory lock, to guard against concurrent writes.
         #[cfg(windows)]
         let _lock = {
@@ -760,8 +799,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                let reader = self
+                    .client
+                    .unmanaged
+                    .cached_client()
+                    .uncached()
+                    .throttle(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -801,7 +844,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::CacheWrite)?;
 
                 // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
+                let digests = if hashes.is_none() {
                     let file = file.into_std().await;
                     tokio::task::spawn_blocking({
                         let target = temp_dir.path().to_owned();
@@ -847,6 +890,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hashers.into_iter().map(HashDigest::from).collect()
                 };
 
+                // Validate the hashes before admitting the wheel into the cache.
+                validate_download(
+                    self.build_context.cache(),
+                    dist,
+                    temp_dir.path(),
+                    hashes,
+                    digests.as_slice(),
+                )?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -859,7 +911,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(id, hashes, filename.clone()))
+                Ok(Archive::new(id, digests, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -877,12 +929,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 }) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
+                    let freshness = self
+                        .build_context
+                        .cache()
+                        .freshness(&http_entry, Some(&filename.name), None)
+                        .map_err(Error::CacheRead)?;
+                    if matches!(dist, BuiltDist::DirectUrl(_)) {
+                        direct_url_cache_control(freshness, &url, hashes)
+                    } else {
+                        CacheControl::from(freshness)
+                    }
                 }
             }
             Connectivity::Offline => CacheControl::AllowStale,
@@ -1330,6 +1386,61 @@ enum WheelExtension {
     WhlZst,
 }
 
+/// Determine the [`CacheControl`] policy for a direct URL archive, given its [`Freshness`].
+///
+/// Archives that are pinned to a hash (e.g., via a `#sha256=...` fragment, or the hashes recorded
+/// in a lockfile) are immutable: once cached, they're reused without contacting the remote, unless
+/// a refresh was requested. Other archives (e.g., a `latest` tarball) are mutable, and respect the
+/// caching semantics of the HTTP response.
+pub(crate) fn direct_url_cache_control(
+    freshness: Freshness,
+    url: &Url,
+    hashes: HashPolicy<'_>,
+) -> CacheControl<'static> {
+    let immutable = !hashes.digests().is_empty()
+        || url
+            .fragment()
+            .is_some_and(|fragment| Hashes::parse_fragment(fragment).is_ok());
+    if immutable && freshness.is_fresh() {
+        CacheControl::AllowStale
+    } else {
+        CacheControl::from(freshness)
+    }
+}
+
+/// Validate the digests computed while downloading an artifact against the [`HashPolicy`].
+///
+/// If the digests don't match, the extracted artifact is moved into the cache's quarantine, such
+/// that a mismatched artifact is never admitted into the cache.
+pub(crate) fn validate_download(
+    cache: &Cache,
+    distribution: &dyn std::fmt::Display,
+    path: &Path,
+    hashes: HashPolicy<'_>,
+    digests: &[HashDigest],
+) -> Result<(), Error> {
+    let HashPolicy::Validate(expected) = hashes else {
+        return Ok(());
+    };
+    if digests.iter().any(|digest| expected.contains(digest)) {
+        return Ok(());
+    }
+
+    match cache.quarantine(path) {
+        Ok(quarantine) => warn!(
+            "Quarantined `{distribution}` after a hash mismatch: {}",
+            quarantine.display()
+        ),
+        Err(err) => warn!("Failed to quarantine `{distribution}`: {err}"),
+    }
+
+    Err(Error::hash_mismatch(
+        distribution.to_string(),
+        expected,
+        digests,
+    ))
+}
+
 /// Add `.tar.zst` to the end of the URL path, if it doesn't already exist.
 #[must_use]
 fn add_tar_zst_extension(mut url: DisplaySafeUrl) -> DisplaySafeUrl {
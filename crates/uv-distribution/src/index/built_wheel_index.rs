@@ -14,7 +14,10 @@ use uv_types::HashStrategy;
 
 use crate::Error;
 use crate::index::cached_wheel::{CachedWheel, ResolvedWheel};
-use crate::source::{HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer};
+use crate::source::{
+    HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer, content_shard,
+    source_tree_shard,
+};
 
 /// A local index of built distributions for a specific source distribution.
 #[derive(Debug)]
@@ -55,11 +58,19 @@ impl<'a> BuiltWheelIndex<'a> {
     /// This method does not perform any freshness checks and assumes that the source distribution
     /// is already up-to-date.
     pub fn url(&self, source_dist: &DirectUrlSourceDist) -> Result<Option<CachedWheel>, Error> {
-        // For direct URLs, cache directly under the hash of the URL itself.
-        let cache_shard = self.cache.shard(
-            CacheBucket::SourceDistributions,
-            WheelCache::Url(source_dist.url.raw()).root(),
-        );
+        // For direct URLs, cache under the digest of the archive if it's known, and otherwise
+        // directly under the hash of the URL itself.
+        let cache_shard = content_shard(
+            self.cache,
+            source_dist.subdirectory.as_deref(),
+            self.hasher.get(source_dist),
+        )
+        .unwrap_or_else(|| {
+            self.cache.shard(
+                CacheBucket::SourceDistributions,
+                WheelCache::Url(source_dist.url.raw()).root(),
+            )
+        });
 
         // Read the revision from the cache.
         let Some(pointer) = HttpRevisionPointer::read_from(cache_shard.entry(HTTP_REVISION))?
@@ -146,14 +157,12 @@ impl<'a> BuiltWheelIndex<'a> {
         &self,
         source_dist: &DirectorySourceDist,
     ) -> Result<Option<CachedWheel>, Error> {
-        let cache_shard = self.cache.shard(
-            CacheBucket::SourceDistributions,
-            if source_dist.editable.unwrap_or(false) {
-                WheelCache::Editable(&source_dist.url).root()
-            } else {
-                WheelCache::Path(&source_dist.url).root()
-            },
-        );
+        let (cache_shard, cache_info) = source_tree_shard(
+            self.cache,
+            &source_dist.url,
+            &source_dist.install_path,
+            source_dist.editable.unwrap_or(false),
+        )?;
 
         // Read the revision from the cache.
         let Some(pointer) = LocalRevisionPointer::read_from(cache_shard.entry(LOCAL_REVISION))?
@@ -162,7 +171,6 @@ impl<'a> BuiltWheelIndex<'a> {
         };
 
         // If the distribution is stale, omit it from the index.
-        let cache_info = CacheInfo::from_directory(&source_dist.install_path)?;
         if cache_info != *pointer.cache_info() {
             return Ok(None);
         }
@@ -13,7 +13,9 @@ use uv_distribution_types::{
 use uv_git_types::{GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
-use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl, looks_like_git_repository};
+use uv_pep508::{
+    MarkerTree, VerbatimUrl, VersionOrUrl, expand_path_vars, looks_like_git_repository,
+};
 use uv_pypi_types::{ConflictItem, ParsedGitUrl, ParsedUrlError, VerbatimParsedUrl};
 use uv_redacted::DisplaySafeUrl;
 use uv_workspace::Workspace;
@@ -160,6 +162,7 @@ impl LoweredRequirement {
             sources
                 .into_iter()
                 .map(move |source| {
+                    let extras = merge_extras(&requirement.extras, source.extras());
                     let (source, mut marker) = match source {
                         Source::Git {
                             git,
@@ -335,7 +338,7 @@ impl LoweredRequirement {
 
                     Ok(Self(Requirement {
                         name: requirement.name.clone(),
-                        extras: requirement.extras.clone(),
+                        extras,
                         groups: Box::new([]),
                         marker,
                         source,
@@ -402,6 +405,7 @@ impl LoweredRequirement {
             source
                 .into_iter()
                 .map(move |source| {
+                    let extras = merge_extras(&requirement.extras, source.extras());
                     let (source, mut marker) = match source {
                         Source::Git {
                             git,
@@ -484,7 +488,7 @@ impl LoweredRequirement {
 
                     Ok(Self(Requirement {
                         name: requirement.name.clone(),
-                        extras: requirement.extras.clone(),
+                        extras,
                         groups: Box::new([]),
                         marker,
                         source,
@@ -576,6 +580,22 @@ impl std::fmt::Display for SourceKind {
     }
 }
 
+/// Combine the extras in a dependency specifier with those declared by its source, such that the
+/// extras don't need to be repeated in both places.
+fn merge_extras(requirement: &[ExtraName], source: &[ExtraName]) -> Box<[ExtraName]> {
+    if source.is_empty() {
+        return Box::from(requirement);
+    }
+    let mut extras = requirement
+        .iter()
+        .chain(source)
+        .cloned()
+        .collect::<Vec<_>>();
+    extras.sort_unstable();
+    extras.dedup();
+    extras.into_boxed_slice()
+}
+
 /// Convert a Git source into a [`RequirementSource`].
 fn git_source(
     git: &DisplaySafeUrl,
@@ -694,7 +714,10 @@ fn path_source(
         RequirementOrigin::Project => project_dir,
         RequirementOrigin::Workspace => workspace_root,
     };
-    let url = VerbatimUrl::from_path(path, base)?.with_given(path.to_string_lossy());
+    // Expand any variables (e.g., `${WORKSPACE_ROOT}`), but retain the unexpanded path as the
+    // given string, such that it's preserved in the lockfile.
+    let expanded = expand_path_vars(path, workspace_root);
+    let url = VerbatimUrl::from_path(&*expanded, base)?.with_given(path.to_string_lossy());
     let install_path = url
         .to_file_path()
         .map_err(|()| LoweringError::RelativeTo(io::Error::other("Invalid path in file URL")))?;
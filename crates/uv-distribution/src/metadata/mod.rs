@@ -63,6 +63,8 @@ pub struct Metadata {
     pub provides_extra: Box<[ExtraName]>,
     pub dependency_groups: BTreeMap<GroupName, Box<[Requirement]>>,
     pub dynamic: bool,
+    pub license: Option<String>,
+    pub homepage: Option<String>,
 }
 
 impl Metadata {
@@ -79,6 +81,8 @@ impl Metadata {
             provides_extra: metadata.provides_extra,
             dependency_groups: BTreeMap::default(),
             dynamic: metadata.dynamic,
+            license: metadata.license,
+            homepage: metadata.homepage,
         }
     }
 
@@ -124,6 +128,8 @@ impl Metadata {
             provides_extra,
             dependency_groups,
             dynamic,
+            license: metadata.license,
+            homepage: metadata.homepage,
         })
     }
 }
@@ -26,7 +26,7 @@ use zip::ZipArchive;
 use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_client::{
-    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    BaseClient, CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
 };
 use uv_configuration::{BuildKind, BuildOutput, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
@@ -36,7 +36,7 @@ use uv_distribution_types::{
     SourceUrl,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::{rename_with_retry, write_atomic};
+use uv_fs::{Simplified, rename_with_retry, write_atomic};
 use uv_git_types::{GitHubRepository, GitOid};
 use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
@@ -46,7 +46,7 @@ use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, Resol
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
 use uv_workspace::pyproject::ToolUvSources;
 
-use crate::distribution_database::ManagedClient;
+use crate::distribution_database::{ManagedClient, direct_url_cache_control, validate_download};
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
@@ -433,12 +433,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
+        // For direct URLs with a known digest, cache under the digest rather than the URL.
+        let content_shard = if index.is_none() {
+            content_shard(self.build_context.cache(), subdirectory, hashes)
+        } else {
+            None
+        };
+        let cache_shard = content_shard.as_ref().unwrap_or(cache_shard);
+
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
-        let revision = self
-            .url_revision(source, ext, url, index, cache_shard, hashes, client)
-            .await?;
+        let revision = match content_shard
+            .as_ref()
+            .map(|cache_shard| content_revision(cache_shard, hashes))
+            .transpose()?
+            .flatten()
+        {
+            Some(revision) => revision,
+            None => {
+                self.url_revision(source, ext, url, index, cache_shard, hashes, client)
+                    .await?
+            }
+        };
 
         // Before running the build, check that the hashes match.
         if !revision.satisfies(hashes) {
@@ -563,12 +580,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
+        // For direct URLs with a known digest, cache under the digest rather than the URL.
+        let content_shard = if index.is_none() {
+            content_shard(self.build_context.cache(), subdirectory, hashes)
+        } else {
+            None
+        };
+        let cache_shard = content_shard.as_ref().unwrap_or(cache_shard);
+
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
-        let revision = self
-            .url_revision(source, ext, url, index, cache_shard, hashes, client)
-            .await?;
+        let revision = match content_shard
+            .as_ref()
+            .map(|cache_shard| content_revision(cache_shard, hashes))
+            .transpose()?
+            .flatten()
+        {
+            Some(revision) => revision,
+            None => {
+                self.url_revision(source, ext, url, index, cache_shard, hashes, client)
+                    .await?
+            }
+        };
 
         // Before running the build, check that the hashes match.
         if !revision.satisfies(hashes) {
@@ -756,12 +790,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
-                            .map_err(Error::CacheRead)?,
-                    )
+                    let freshness = self
+                        .build_context
+                        .cache()
+                        .freshness(&cache_entry, source.name(), source.source_tree())
+                        .map_err(Error::CacheRead)?;
+                    if index.is_none() {
+                        direct_url_cache_control(freshness, url, hashes)
+                    } else {
+                        CacheControl::from(freshness)
+                    }
                 }
             }
             Connectivity::Offline => CacheControl::AllowStale,
@@ -778,7 +816,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
                 let algorithms = hashes.algorithms();
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        hashes,
+                        client.unmanaged.cached_client().uncached(),
+                    )
                     .await?;
 
                 Ok(revision.with_hashes(HashDigests::from(hashes)))
@@ -1151,14 +1197,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Err(Error::HashesNotSupportedSourceTree(source.to_string()));
         }
 
-        let cache_shard = self.build_context.cache().shard(
-            CacheBucket::SourceDistributions,
-            if resource.editable.unwrap_or(false) {
-                WheelCache::Editable(resource.url).root()
-            } else {
-                WheelCache::Path(resource.url).root()
-            },
-        );
+        let (cache_shard, cache_info) = source_tree_shard(
+            self.build_context.cache(),
+            resource.url,
+            &resource.install_path,
+            resource.editable.unwrap_or(false),
+        )?;
 
         // Acquire the advisory lock.
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
@@ -1168,7 +1212,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             cache_info,
             revision,
         } = self
-            .source_tree_revision(source, resource, &cache_shard)
+            .source_tree_revision(source, cache_info, &cache_shard)
             .await?;
 
         // Scope all operations to the revision. Within the revision, there's no need to check for
@@ -1192,6 +1236,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(BuiltWheelMetadata::from_file(
                 file,
                 revision.into_hashes(),
@@ -1273,21 +1318,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             StaticMetadata::None => false,
         };
 
-        let cache_shard = self.build_context.cache().shard(
-            CacheBucket::SourceDistributions,
-            if resource.editable.unwrap_or(false) {
-                WheelCache::Editable(resource.url).root()
-            } else {
-                WheelCache::Path(resource.url).root()
-            },
-        );
+        let (cache_shard, cache_info) = source_tree_shard(
+            self.build_context.cache(),
+            resource.url,
+            &resource.install_path,
+            resource.editable.unwrap_or(false),
+        )?;
 
         // Acquire the advisory lock.
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
-            .source_tree_revision(source, resource, &cache_shard)
+            .source_tree_revision(source, cache_info, &cache_shard)
             .await?;
 
         // Scope all operations to the revision. Within the revision, there's no need to check for
@@ -1437,17 +1480,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     async fn source_tree_revision(
         &self,
         source: &BuildableSource<'_>,
-        resource: &DirectorySourceUrl<'_>,
+        cache_info: CacheInfo,
         cache_shard: &CacheShard,
     ) -> Result<LocalRevisionPointer, Error> {
-        // Verify that the source tree exists.
-        if !resource.install_path.is_dir() {
-            return Err(Error::NotFound(resource.url.clone()));
-        }
-
-        // Determine the last-modified time of the source distribution.
-        let cache_info = CacheInfo::from_directory(&resource.install_path)?;
-
         // Read the existing metadata from the cache.
         let entry = cache_shard.entry(LOCAL_REVISION);
 
@@ -1600,6 +1635,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .flatten()
             .filter(|file| file.matches(source.name(), source.version()))
         {
+            debug!("Using cached wheel for: {source}");
             return Ok(BuiltWheelMetadata::from_file(
                 file, hashes, cache_info, build_info,
             ));
@@ -2175,12 +2211,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
-                            .map_err(Error::CacheRead)?,
-                    )
+                    let freshness = self
+                        .build_context
+                        .cache()
+                        .freshness(&cache_entry, source.name(), source.source_tree())
+                        .map_err(Error::CacheRead)?;
+                    if index.is_none() {
+                        direct_url_cache_control(freshness, url, hashes)
+                    } else {
+                        CacheControl::from(freshness)
+                    }
                 }
             }
             Connectivity::Offline => CacheControl::AllowStale,
@@ -2200,7 +2240,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                        hashes,
+                        client.unmanaged.cached_client().uncached(),
+                    )
                     .await?;
                 for existing in revision.hashes() {
                     if !hashes.contains(existing) {
@@ -2239,6 +2287,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         ext: SourceDistExtension,
         target: &Path,
         algorithms: &[HashAlgorithm],
+        hashes: HashPolicy<'_>,
+        client: &BaseClient,
     ) -> Result<Vec<HashDigest>, Error> {
         let temp_dir = tempfile::tempdir_in(
             self.build_context
@@ -2246,8 +2296,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .bucket(CacheBucket::SourceDistributions),
         )
         .map_err(Error::CacheWrite)?;
-        let reader = response
-            .bytes_stream()
+        let reader = client
+            .throttle(response.bytes_stream())
             .map_err(std::io::Error::other)
             .into_async_read();
 
@@ -2271,7 +2321,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             hasher.finish().await.map_err(Error::HashExhaustion)?;
         }
 
-        let hashes = hashers.into_iter().map(HashDigest::from).collect();
+        let digests = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+
+        // Validate the hashes before admitting the source distribution into the cache.
+        validate_download(
+            self.build_context.cache(),
+            source,
+            temp_dir.path(),
+            hashes,
+            &digests,
+        )?;
 
         // Extract the top-level directory.
         let extracted = match uv_extract::strip_component(temp_dir.path()) {
@@ -2298,7 +2360,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        Ok(hashes)
+        Ok(digests)
     }
 
     /// Extract a local archive, and store it at the given [`CacheEntry`].
@@ -2905,6 +2967,88 @@ pub(crate) struct HttpRevisionPointer {
     revision: Revision,
 }
 
+/// Return the content-addressed cache shard for a remote source distribution, if its SHA-256
+/// digest is known up-front (e.g., from a lockfile or `--require-hashes`).
+///
+/// Keying the cache on the digest (rather than the URL) allows identical archives to share
+/// downloads and builds regardless of the URL from which they're fetched. Archives built from a
+/// subdirectory are excluded, since the same archive can yield different distributions.
+pub(crate) fn content_shard(
+    cache: &Cache,
+    subdirectory: Option<&Path>,
+    hashes: HashPolicy<'_>,
+) -> Option<CacheShard> {
+    if subdirectory.is_some() {
+        return None;
+    }
+    let digest = hashes
+        .digests()
+        .iter()
+        .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?;
+    Some(cache.shard(
+        CacheBucket::SourceDistributions,
+        WheelCache::Content(&digest.digest).root(),
+    ))
+}
+
+/// Return the cache shard for a local source tree, along with its [`CacheInfo`].
+///
+/// Source trees are keyed by their URL. However, if a non-editable source tree's cache keys depend
+/// on the Git commit alone (e.g., `cache-keys = [{ git = { commit = true } }]`), it's instead keyed
+/// by the commit and its path within the repository, such that checkouts of the same commit share
+/// metadata and builds, e.g., across projects. Editable builds reference the source tree itself, so
+/// they're always keyed by URL.
+pub(crate) fn source_tree_shard(
+    cache: &Cache,
+    url: &DisplaySafeUrl,
+    install_path: &Path,
+    editable: bool,
+) -> Result<(CacheShard, CacheInfo), Error> {
+    // Verify that the source tree exists.
+    if !install_path.is_dir() {
+        return Err(Error::NotFound(url.clone()));
+    }
+
+    // Determine the last-modified time of the source distribution.
+    let cache_info = CacheInfo::from_directory(install_path)?;
+
+    let root = if editable {
+        WheelCache::Editable(url).root()
+    } else if let Some((commit, subdirectory)) = cache_info.git_revision(install_path) {
+        debug!(
+            "Caching source tree at `{}` by Git commit: {commit}",
+            install_path.user_display()
+        );
+        WheelCache::Commit(commit, &subdirectory).root()
+    } else {
+        WheelCache::Path(url).root()
+    };
+
+    Ok((
+        cache.shard(CacheBucket::SourceDistributions, root),
+        cache_info,
+    ))
+}
+
+/// Read the [`Revision`] from a content-addressed cache shard, if it satisfies the required
+/// hashes.
+///
+/// Since the shard is keyed by the digest of the archive, an existing revision is known to contain
+/// the same archive, so there's no need to revalidate it against the remote.
+fn content_revision(
+    cache_shard: &CacheShard,
+    hashes: HashPolicy<'_>,
+) -> Result<Option<Revision>, Error> {
+    let Some(pointer) = HttpRevisionPointer::read_from(cache_shard.entry(HTTP_REVISION))? else {
+        return Ok(None);
+    };
+    let revision = pointer.into_revision();
+    if !revision.satisfies(hashes) {
+        return Ok(None);
+    }
+    Ok(Some(revision))
+}
+
 impl HttpRevisionPointer {
     /// Read an [`HttpRevisionPointer`] from the cache.
     pub(crate) fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
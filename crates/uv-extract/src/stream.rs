@@ -121,11 +121,13 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
         let expected_uncompressed_size = entry.reader().entry().uncompressed_size();
         let expected_data_descriptor = entry.reader().entry().data_descriptor();
 
-        // Either create the directory or write the file to disk.
-        let path = target.join(&relpath);
+        // Either create the directory or write the file to disk. On Windows, use the
+        // extended-length form of the path, since wheels with deeply nested files can exceed
+        // `MAX_PATH`.
+        let path = uv_fs::verbatim_path(&target.join(&relpath)).into_owned();
         let is_dir = entry.reader().entry().dir()?;
         let computed = if is_dir {
-            if directories.insert(path.clone()) {
+            if directories.insert(uv_fs::normalize_path_case(&path).into_owned()) {
                 fs_err::tokio::create_dir_all(path)
                     .await
                     .map_err(Error::Io)?;
@@ -160,7 +162,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
             }
         } else {
             if let Some(parent) = path.parent() {
-                if directories.insert(parent.to_path_buf()) {
+                if directories.insert(uv_fs::normalize_path_case(parent).into_owned()) {
                     fs_err::tokio::create_dir_all(parent)
                         .await
                         .map_err(Error::Io)?;
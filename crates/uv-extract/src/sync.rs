@@ -32,11 +32,12 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
                 return Ok(());
             };
 
-            // Create necessary parent directories.
-            let path = target.join(enclosed_name);
+            // Create necessary parent directories. On Windows, use the extended-length form of the
+            // path, since wheels with deeply nested files can exceed `MAX_PATH`.
+            let path = uv_fs::verbatim_path(&target.join(enclosed_name)).into_owned();
             if file.is_dir() {
                 let mut directories = directories.lock().unwrap();
-                if directories.insert(path.clone()) {
+                if directories.insert(uv_fs::normalize_path_case(&path).into_owned()) {
                     fs_err::create_dir_all(path).map_err(Error::Io)?;
                 }
                 return Ok(());
@@ -44,7 +45,7 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
 
             if let Some(parent) = path.parent() {
                 let mut directories = directories.lock().unwrap();
-                if directories.insert(parent.to_path_buf()) {
+                if directories.insert(uv_fs::normalize_path_case(parent).into_owned()) {
                     fs_err::create_dir_all(parent).map_err(Error::Io)?;
                 }
             }
@@ -2,15 +2,18 @@ use std::borrow::Cow;
 use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use fs2::FileExt;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, trace, warn};
 
 pub use crate::path::*;
+pub use crate::quarantine::Quarantine;
 
 pub mod cachedir;
 mod path;
+mod quarantine;
 pub mod which;
 
 /// Append an extension to a [`PathBuf`].
@@ -63,6 +66,38 @@ pub fn is_same_file_allow_missing(left: &Path, right: &Path) -> Option<bool> {
     None
 }
 
+/// Resolve a path to a canonical form, for use as a cache or lock key.
+///
+/// Symbolic links are resolved and, on case-insensitive filesystems (like the defaults on macOS
+/// and Windows), the path is normalized to its casing on disk, such that different spellings of
+/// the same location map to the same key.
+///
+/// If the path doesn't exist, its nearest existing ancestor is resolved instead. If no ancestor
+/// can be resolved, the absolute path is returned.
+pub fn canonicalize_key(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    let Ok(absolute) = std::path::absolute(path) else {
+        return path.to_path_buf();
+    };
+
+    let mut current = absolute.as_path();
+    let mut names = Vec::new();
+    loop {
+        if let Ok(canonical) = dunce::canonicalize(current) {
+            return names
+                .into_iter()
+                .rev()
+                .fold(canonical, |path, name| path.join(name));
+        }
+        let (Some(parent), Some(name)) = (current.parent(), current.file_name()) else {
+            break;
+        };
+        names.push(name);
+        current = parent;
+    }
+    absolute
+}
+
 /// Reads data from the path and requires that it be valid UTF-8 or UTF-16.
 ///
 /// This uses BOM sniffing to determine if the data should be transcoded
@@ -278,6 +313,17 @@ pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io
     persist_with_retry_sync(temp_file, to.as_ref())
 }
 
+/// Returns `true` if an error indicates that a file is temporarily held open by another process.
+///
+/// Antivirus software commonly opens newly written files to scan them, causing sharing and lock
+/// violations (rather than only access denied errors) for concurrent renames, copies, and writes.
+#[cfg(windows)]
+fn is_transient_lock_error(err: &std::io::Error) -> bool {
+    // `ERROR_SHARING_VIOLATION` (32) and `ERROR_LOCK_VIOLATION` (33).
+    err.kind() == std::io::ErrorKind::PermissionDenied
+        || matches!(err.raw_os_error(), Some(32 | 33))
+}
+
 #[cfg(windows)]
 fn backoff_file_move() -> backon::ExponentialBackoff {
     use backon::BackoffBuilder;
@@ -313,7 +359,7 @@ pub async fn rename_with_retry(
         rename
             .retry(backoff_file_move())
             .sleep(tokio::time::sleep)
-            .when(|e| e.kind() == std::io::ErrorKind::PermissionDenied)
+            .when(is_transient_lock_error)
             .notify(|err, _dur| {
                 warn!(
                     "Retrying rename from {} to {} due to transient error: {}",
@@ -353,7 +399,7 @@ pub fn with_retry_sync(
         operation
             .retry(backoff_file_move())
             .sleep(std::thread::sleep)
-            .when(|err| err.kind() == std::io::ErrorKind::PermissionDenied)
+            .when(is_transient_lock_error)
             .notify(|err, _dur| {
                 warn!(
                     "Retrying {} from {} to {} due to transient error: {}",
@@ -552,6 +598,12 @@ pub fn persist_with_retry_sync(
     }
 }
 
+/// Return the space available to the current user on the filesystem containing the given path,
+/// in bytes.
+pub fn available_space(path: impl AsRef<Path>) -> std::io::Result<u64> {
+    fs2::available_space(path.as_ref())
+}
+
 /// Iterate over the subdirectories of a directory.
 ///
 /// If the directory does not exist, returns an empty iterator.
@@ -733,6 +785,106 @@ impl LockedFile {
         }
     }
 
+    /// Inner implementation for [`LockedFile::acquire_with_timeout`].
+    ///
+    /// If a timeout is given, polls the lock rather than blocking on it, such that we can give up
+    /// after the timeout.
+    fn lock_file_with_timeout_blocking(
+        file: fs_err::File,
+        resource: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Self, std::io::Error> {
+        trace!(
+            "Checking lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        let start = Instant::now();
+        let mut waiting = false;
+        loop {
+            match file.file().try_lock_exclusive() {
+                Ok(()) => {
+                    debug!("Acquired lock for `{resource}`");
+                    return Ok(Self(file));
+                }
+                Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {}
+                Err(err) => {
+                    // Not an fs_err method, we need to build our own path context
+                    return Err(std::io::Error::other(format!(
+                        "Could not acquire lock for `{resource}` at `{}`: {}",
+                        file.path().user_display(),
+                        err
+                    )));
+                }
+            }
+
+            let holder = || {
+                Self::holder(&file)
+                    .map(|pid| format!(" (held by process {pid})"))
+                    .unwrap_or_default()
+            };
+            if !waiting {
+                info!(
+                    "Waiting to acquire lock for `{resource}` at `{}`{}",
+                    file.path().user_display(),
+                    holder()
+                );
+                waiting = true;
+            }
+
+            // Without a timeout, block until the lock is released.
+            let Some(timeout) = timeout else {
+                file.file().lock_exclusive().map_err(|err| {
+                    // Not an fs_err method, we need to build our own path context
+                    std::io::Error::other(format!(
+                        "Could not acquire lock for `{resource}` at `{}`: {}",
+                        file.path().user_display(),
+                        err
+                    ))
+                })?;
+                debug!("Acquired lock for `{resource}`");
+                return Ok(Self(file));
+            };
+
+            if start.elapsed() >= timeout {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!(
+                        "Timed out after {}s waiting for the lock on `{resource}` at `{}`{}",
+                        timeout.as_secs(),
+                        file.path().user_display(),
+                        holder()
+                    ),
+                ));
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    /// Return the ID of the process holding the lock, as recorded in the lock file.
+    fn holder(file: &fs_err::File) -> Option<u32> {
+        fs_err::read_to_string(file.path())
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    /// Record the ID of the current process in the lock file.
+    fn record_holder(&self) {
+        use std::io::Write;
+
+        let mut file = self.0.file();
+        if let Err(err) = file
+            .set_len(0)
+            .and_then(|()| write!(file, "{}", std::process::id()))
+        {
+            debug!(
+                "Failed to record lock holder in `{}`: {err}",
+                self.0.path().user_display()
+            );
+        }
+    }
+
     /// The same as [`LockedFile::acquire`], but for synchronous contexts.
     ///
     /// Do not use from an async context, as this can block the runtime while waiting for another
@@ -746,6 +898,33 @@ impl LockedFile {
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// Acquire a cross-process lock for a resource using a file at the provided path, returning
+    /// `None` rather than waiting if the lock is held by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = Self::create(path)?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                trace!("Lock for `{resource}` is held by another process");
+                Ok(None)
+            }
+            Err(err) => {
+                // Not an fs_err method, we need to build our own path context
+                Err(std::io::Error::other(format!(
+                    "Could not acquire lock for `{resource}` at `{}`: {}",
+                    file.path().user_display(),
+                    err
+                )))
+            }
+        }
+    }
+
     /// The same as [`LockedFile::acquire_blocking`], but for synchronous contexts.
     ///
     /// Do not use from an async context, as this can block the runtime while waiting for another
@@ -770,6 +949,27 @@ impl LockedFile {
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Acquire a cross-process lock for a resource using a dedicated lock file at the provided
+    /// path, giving up if the lock isn't released by another process within the timeout.
+    ///
+    /// The ID of the current process is recorded in the lock file, such that waiting processes
+    /// can report the holder of the lock. As such, the file must not be used for anything else.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_with_timeout(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        timeout: Option<Duration>,
+    ) -> Result<Self, std::io::Error> {
+        let file = Self::create(path)?;
+        let resource = resource.to_string();
+        tokio::task::spawn_blocking(move || {
+            let lock = Self::lock_file_with_timeout_blocking(file, &resource, timeout)?;
+            lock.record_holder();
+            Ok(lock)
+        })
+        .await?
+    }
+
     /// Acquire a cross-process read lock for a shared resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire_shared(
@@ -903,6 +1103,34 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_canonicalize_key() -> std::io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = dunce::canonicalize(temp_dir.path())?;
+        let project = root.join("project");
+        fs_err::create_dir(&project)?;
+
+        assert_eq!(canonicalize_key(&project), project);
+        assert_eq!(
+            canonicalize_key(project.join("missing")),
+            project.join("missing")
+        );
+
+        // Paths that traverse a symbolic link resolve to the link target.
+        #[cfg(unix)]
+        {
+            let link = root.join("link");
+            fs_err::os::unix::fs::symlink(&project, &link)?;
+            assert_eq!(canonicalize_key(&link), project);
+            assert_eq!(
+                canonicalize_key(link.join("missing")),
+                project.join("missing")
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_with_added_extension() {
         // Test with simple package name (no dots)
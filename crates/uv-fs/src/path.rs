@@ -232,6 +232,102 @@ pub fn normalize_path_buf(path: PathBuf) -> PathBuf {
     }
 }
 
+/// Convert an absolute [`Path`] to its extended-length form on Windows, lifting the 260-character
+/// `MAX_PATH` limit.
+///
+/// For example, `C:\foo` becomes `\\?\C:\foo`, and `\\server\share\foo` becomes
+/// `\\?\UNC\server\share\foo`. Extended-length paths are passed to the file system as-is, so the
+/// path is normalized in the process (i.e., `.` and `..` are resolved, and `/` is replaced with
+/// `\`). Relative paths and paths that are already in extended-length form are returned
+/// unchanged.
+///
+/// The conversion is applied regardless of the length of the path, such that it can be used on a
+/// root directory (e.g., `site-packages`) to which long relative paths will later be joined.
+///
+/// On other platforms, this is a no-op.
+pub fn verbatim_path(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::path::Prefix;
+
+        let mut components = path.components();
+        let Some(Component::Prefix(prefix)) = components.next() else {
+            return Cow::Borrowed(path);
+        };
+        let mut verbatim = match prefix.kind() {
+            Prefix::Disk(_) => {
+                let mut verbatim = OsString::from(r"\\?\");
+                verbatim.push(prefix.as_os_str());
+                verbatim
+            }
+            Prefix::UNC(server, share) => {
+                let mut verbatim = OsString::from(r"\\?\UNC\");
+                verbatim.push(server);
+                verbatim.push(r"\");
+                verbatim.push(share);
+                verbatim
+            }
+            // The path is already in extended-length (or device) form.
+            Prefix::Verbatim(_)
+            | Prefix::VerbatimUNC(..)
+            | Prefix::VerbatimDisk(_)
+            | Prefix::DeviceNS(_) => return Cow::Borrowed(path),
+        };
+
+        // Drive-relative paths (e.g., `C:foo`) can't be converted.
+        if components.next() != Some(Component::RootDir) {
+            return Cow::Borrowed(path);
+        }
+
+        let mut parts = Vec::new();
+        for component in components {
+            match component {
+                Component::Normal(part) => parts.push(part),
+                Component::ParentDir => {
+                    parts.pop();
+                }
+                Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+            }
+        }
+
+        verbatim.push(r"\");
+        for (index, part) in parts.into_iter().enumerate() {
+            if index > 0 {
+                verbatim.push(r"\");
+            }
+            verbatim.push(part);
+        }
+        Cow::Owned(PathBuf::from(verbatim))
+    }
+
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
+/// Normalize the case of a [`Path`], for use as a key when deduplicating paths.
+///
+/// On Windows, file systems are case-insensitive, such that `C:\Foo` and `c:\foo` refer to the
+/// same file; as such, the path is lowercased. On other platforms, this is a no-op.
+pub fn normalize_path_case(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        match path.to_str() {
+            Some(path_str) if path_str.chars().any(char::is_uppercase) => {
+                Cow::Owned(PathBuf::from(path_str.to_lowercase()))
+            }
+            _ => Cow::Borrowed(path),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
 /// Normalize a [`Path`].
 ///
 /// Unlike [`normalize_absolute_path`], this works with relative paths and does never error.
@@ -550,4 +646,50 @@ mod tests {
             assert_eq!(normalize_path(Path::new(input)), Path::new(expected));
         }
     }
+
+    #[test]
+    fn test_verbatim_path() {
+        let long = "a".repeat(300);
+
+        // Relative paths are returned unchanged.
+        let relative = format!(r"{long}\foo");
+        assert_eq!(verbatim_path(Path::new(&relative)), Path::new(&relative));
+
+        let disk = format!(r"C:\Users\{long}\..\ferris/./site-packages");
+        let unc = format!(r"\\server\share\{long}\site-packages");
+        let verbatim = format!(r"\\?\C:\{long}\site-packages");
+        if cfg!(windows) {
+            assert_eq!(
+                verbatim_path(Path::new(r"C:\Users\ferris")),
+                Path::new(r"\\?\C:\Users\ferris")
+            );
+            assert_eq!(
+                verbatim_path(Path::new(&disk)),
+                Path::new(r"\\?\C:\Users\ferris\site-packages")
+            );
+            assert_eq!(
+                verbatim_path(Path::new(&unc)),
+                Path::new(&format!(r"\\?\UNC\server\share\{long}\site-packages"))
+            );
+        } else {
+            assert_eq!(verbatim_path(Path::new(&disk)), Path::new(&disk));
+            assert_eq!(verbatim_path(Path::new(&unc)), Path::new(&unc));
+        }
+        assert_eq!(verbatim_path(Path::new(&verbatim)), Path::new(&verbatim));
+    }
+
+    #[test]
+    fn test_normalize_path_case() {
+        if cfg!(windows) {
+            assert_eq!(
+                normalize_path_case(Path::new(r"C:\Users\Ferris\Lib\site-packages")),
+                Path::new(r"c:\users\ferris\lib\site-packages")
+            );
+        } else {
+            assert_eq!(
+                normalize_path_case(Path::new("/home/Ferris/lib/site-packages")),
+                Path::new("/home/Ferris/lib/site-packages")
+            );
+        }
+    }
 }
@@ -0,0 +1,164 @@
+//! Handling of the attributes that operating systems use to mark files as downloaded from the
+//! internet: the `com.apple.quarantine` extended attribute on macOS, and the `Zone.Identifier`
+//! alternate data stream (the "Mark of the Web") on Windows.
+//!
+//! Security software (e.g., Gatekeeper, SmartScreen, or antivirus software in enterprise
+//! environments) may block or prompt before executing files that carry these attributes.
+
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use tracing::trace;
+
+/// How to treat the quarantine attributes of downloaded and installed files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Quarantine {
+    /// Leave any quarantine attributes untouched.
+    #[default]
+    Preserve,
+    /// Remove any quarantine attributes, such that installed binaries can be executed without
+    /// being blocked by security software.
+    Strip,
+    /// Mark files as downloaded from the internet, such that they're subject to the same checks as
+    /// files downloaded by a browser.
+    Mark,
+}
+
+impl FromStr for Quarantine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "strip" => Ok(Self::Strip),
+            "mark" => Ok(Self::Mark),
+            _ => Err(format!(
+                "Expected one of `preserve`, `strip`, or `mark`, got `{s}`"
+            )),
+        }
+    }
+}
+
+impl Quarantine {
+    /// Returns `true` if files should be left untouched.
+    pub fn is_preserve(self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    /// Apply the quarantine mode to a single file.
+    ///
+    /// Note that hard-linked files share their attributes, and that the attributes of a symbolic
+    /// link's target are modified rather than those of the link, such that applying the mode to a
+    /// linked file also applies it to its source (e.g., in the cache). Callers should apply the
+    /// mode to copies instead.
+    pub fn apply(self, path: &Path) -> io::Result<()> {
+        match self {
+            Self::Preserve => Ok(()),
+            Self::Strip => {
+                trace!("Removing quarantine attributes from: {}", path.display());
+                strip(path)
+            }
+            Self::Mark => {
+                trace!("Adding quarantine attributes to: {}", path.display());
+                mark(path)
+            }
+        }
+    }
+
+    /// Apply the quarantine mode to every file in a directory, recursively.
+    ///
+    /// Symbolic links are skipped, as the attributes belong to their targets.
+    pub fn apply_all(self, dir: &Path) -> io::Result<()> {
+        if self.is_preserve() {
+            return Ok(());
+        }
+        for entry in fs_err::read_dir(dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                self.apply_all(&entry.path())?;
+            } else if file_type.is_file() {
+                self.apply(&entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+const QUARANTINE_ATTRIBUTE: &str = "com.apple.quarantine";
+
+#[cfg(target_os = "macos")]
+fn strip(path: &Path) -> io::Result<()> {
+    match rustix::fs::removexattr(path, QUARANTINE_ATTRIBUTE) {
+        Ok(()) | Err(rustix::io::Errno::NOATTR) => Ok(()),
+        Err(err) => Err(io::Error::from(err)),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn mark(path: &Path) -> io::Result<()> {
+    // The attribute is formatted as `<flags>;<timestamp>;<agent>;<event>`, where `0081` marks
+    // the file as downloaded, but not yet approved by the user.
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let value = format!("0081;{timestamp:08x};uv;");
+    rustix::fs::setxattr(
+        path,
+        QUARANTINE_ATTRIBUTE,
+        value.as_bytes(),
+        rustix::fs::XattrFlags::empty(),
+    )
+    .map_err(io::Error::from)
+}
+
+#[cfg(windows)]
+fn zone_identifier(path: &Path) -> std::path::PathBuf {
+    let mut stream = path.as_os_str().to_owned();
+    stream.push(":Zone.Identifier");
+    std::path::PathBuf::from(stream)
+}
+
+#[cfg(windows)]
+fn strip(path: &Path) -> io::Result<()> {
+    match fs_err::remove_file(zone_identifier(path)) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+fn mark(path: &Path) -> io::Result<()> {
+    // Zone 3 is the "Internet" zone.
+    fs_err::write(zone_identifier(path), "[ZoneTransfer]\r\nZoneId=3\r\n")
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+#[allow(clippy::unnecessary_wraps)]
+fn strip(_path: &Path) -> io::Result<()> {
+    // Other platforms have no equivalent attributes.
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+#[allow(clippy::unnecessary_wraps)]
+fn mark(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Quarantine;
+
+    #[test]
+    fn parse() {
+        assert_eq!("strip".parse::<Quarantine>(), Ok(Quarantine::Strip));
+        assert_eq!("mark".parse::<Quarantine>(), Ok(Quarantine::Mark));
+        assert_eq!("preserve".parse::<Quarantine>(), Ok(Quarantine::Preserve));
+        assert!("remove".parse::<Quarantine>().is_err());
+    }
+}
@@ -1,6 +1,7 @@
 //! Git support is derived from Cargo's implementation.
 //! Cargo is dual-licensed under either Apache 2.0 or MIT, at the user's choice.
 //! Source: <https://github.com/rust-lang/cargo/blob/23eb492cf920ce051abfc56bbaf838514dc8365c/src/cargo/sources/git/utils.rs>
+use std::borrow::Cow;
 use std::env;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
@@ -16,7 +17,7 @@ use url::Url;
 
 use uv_fs::Simplified;
 use uv_git_types::{GitHubRepository, GitOid, GitReference};
-use uv_redacted::DisplaySafeUrl;
+use uv_redacted::{DisplaySafeUrl, redact_credentials};
 use uv_static::EnvVars;
 use uv_version::version;
 
@@ -537,7 +538,10 @@ fn fetch(
         }
     }
 
-    debug!("Performing a Git fetch for: {remote_url}");
+    debug!(
+        "Performing a Git fetch for: {}",
+        DisplaySafeUrl::ref_cast(remote_url)
+    );
     let result = match refspec_strategy {
         RefspecStrategy::All => fetch_with_cli(
             repo,
@@ -650,7 +654,7 @@ fn fetch_with_cli(
         if msg.contains("transport '") && msg.contains("' not allowed") && offline {
             return GitError::TransportNotAllowed.into();
         }
-        err
+        redact_process_error(err)
     })?;
 
     Ok(())
@@ -701,10 +705,20 @@ fn fetch_lfs(
         .env_remove(EnvVars::GIT_ALTERNATE_OBJECT_DIRECTORIES)
         .cwd(&repo.path);
 
-    cmd.exec_with_output()?;
+    cmd.exec_with_output().map_err(redact_process_error)?;
     Ok(())
 }
 
+/// Redact any credentials in the error for a failed Git command, since the error message includes
+/// both the command line (with the remote URL) and the command's output.
+fn redact_process_error(err: anyhow::Error) -> anyhow::Error {
+    let msg = err.to_string();
+    match redact_credentials(&msg) {
+        Cow::Owned(msg) => anyhow::anyhow!(msg),
+        Cow::Borrowed(_) => err,
+    }
+}
+
 /// The result of GitHub fast path check. See [`github_fast_path`] for more.
 enum FastPathRev {
     /// The local rev (determined by `reference.resolve(repo)`) is already up to
@@ -0,0 +1,151 @@
+//! A catalog of localized user-facing messages.
+//!
+//! Messages are identified by a stable key and carry their English text inline, such that the
+//! English output doesn't depend on the catalog. When `UV_LANG` is set to a locale with a bundled
+//! catalog (e.g., `es` or `es_ES.UTF-8`), the translated text is used instead, falling back to
+//! English for any message that hasn't been translated.
+//!
+//! Messages may contain named placeholders (e.g., `{count}`), which are substituted at runtime
+//! via [`message!`].
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use rustc_hash::FxHashMap;
+
+use uv_static::EnvVars;
+
+/// The bundled catalogs, keyed by language.
+///
+/// To add a language, add a catalog to the `locales` directory and register it here.
+const CATALOGS: &[(&str, &str)] = &[("es", include_str!("../locales/es.toml"))];
+
+/// The catalog for the locale requested via `UV_LANG`, if any.
+static CATALOG: LazyLock<Option<Catalog>> = LazyLock::new(|| {
+    let locale = std::env::var(EnvVars::UV_LANG).ok()?;
+    Catalog::for_locale(&locale)
+});
+
+/// A set of translated messages for a single language.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Catalog(FxHashMap<String, String>);
+
+impl Catalog {
+    /// Parse a catalog from a TOML document mapping message keys to translated text.
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        Ok(Self(toml::from_str(contents)?))
+    }
+
+    /// Return the bundled catalog for a locale (e.g., `es`, `es-MX`, or `es_ES.UTF-8`), if any.
+    ///
+    /// Only the language is considered; the region and encoding are ignored.
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let (_, contents) = CATALOGS.iter().find(|(name, _)| *name == language)?;
+        // The bundled catalogs are validated in tests.
+        Self::from_toml(contents).ok()
+    }
+
+    /// Return the translated text for a message, if present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Iterate over the message keys in the catalog.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+/// Return the localized text for a message, or the English text if no translation is available.
+pub fn translate(key: &str, english: &'static str) -> Cow<'static, str> {
+    match CATALOG.as_ref().and_then(|catalog| catalog.get(key)) {
+        Some(text) => Cow::Owned(text.to_string()),
+        None => Cow::Borrowed(english),
+    }
+}
+
+/// Substitute the named placeholders (e.g., `{count}`) in a message.
+///
+/// Placeholders without a corresponding argument are left as-is.
+pub fn substitute(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let name = &rest[1..end];
+        if let Some((_, value)) = args.iter().find(|(arg, _)| *arg == name) {
+            output.push_str(&value.to_string());
+        } else {
+            output.push_str(&rest[..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Format a localized message.
+///
+/// The first argument is the message key, the second is the English text, and any remaining
+/// arguments are substituted for the named placeholders in the message:
+///
+/// ```text
+/// message!("installed", "Installed {packages} {duration}", packages = packages, duration = duration)
+/// ```
+#[macro_export]
+macro_rules! message {
+    ($key:literal, $english:literal $(,)?) => {
+        $crate::translate($key, $english)
+    };
+    ($key:literal, $english:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        ::std::borrow::Cow::<'static, str>::Owned($crate::substitute(
+            &$crate::translate($key, $english),
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CATALOGS, Catalog, substitute};
+
+    #[test]
+    fn bundled_catalogs() {
+        for (language, contents) in CATALOGS {
+            if let Err(err) = Catalog::from_toml(contents) {
+                panic!("Invalid catalog for `{language}`: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn locale() {
+        assert!(Catalog::for_locale("es").is_some());
+        assert!(Catalog::for_locale("es_ES.UTF-8").is_some());
+        assert!(Catalog::for_locale("ES-mx").is_some());
+        assert!(Catalog::for_locale("en_US.UTF-8").is_none());
+        assert!(Catalog::for_locale("").is_none());
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            substitute(
+                "Installed {packages} {duration}",
+                &[("packages", &"3 packages"), ("duration", &"in 2ms")]
+            ),
+            "Installed 3 packages in 2ms"
+        );
+        assert_eq!(substitute("{missing} {", &[]), "{missing} {");
+    }
+}
@@ -0,0 +1,176 @@
+//! Platform-optimized copying of the files in a wheel, as used by [`LinkMode::Copy`].
+//!
+//! Most of the files in a wheel are small, so copying them one at a time is dominated by syscall
+//! overhead. Instead, the files in each directory are copied as a batch, with a backend selected
+//! for the platform:
+//!
+//! - On Windows, files are copied with `CopyFile2`, in parallel.
+//! - On macOS, files are cloned with `clonefile` (falling back to a copy), in parallel.
+//!
+//! On other platforms, the files are copied one at a time (with `copy_file_range` on Linux), as
+//! with the other link modes' copy fallback.
+//!
+//! [`LinkMode::Copy`]: crate::LinkMode::Copy
+
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+
+/// Copy each file with `CopyFile2`, in parallel, overwriting any existing file.
+///
+/// The parent directories of the destination paths must exist.
+#[cfg(windows)]
+pub(crate) fn copy_files(files: &[(PathBuf, PathBuf)]) -> std::io::Result<()> {
+    copy_parallel(files, |from, to| {
+        uv_fs::with_retry_sync(from, to, "copying", || copy_file2(from, to))
+    })
+}
+
+/// Clone each file with `clonefile`, falling back to a copy, in parallel, overwriting any existing
+/// file.
+///
+/// The parent directories of the destination paths must exist.
+#[cfg(target_os = "macos")]
+pub(crate) fn copy_files(files: &[(PathBuf, PathBuf)]) -> std::io::Result<()> {
+    copy_parallel(files, |from, to| {
+        reflink_copy::reflink_or_copy(from, to)?;
+        Ok(())
+    })
+}
+
+/// Apply `copy` to each file, in parallel across a small set of scoped threads.
+///
+/// Scoped threads are used rather than Rayon, since the caller holds a directory lock and may
+/// itself be running on a Rayon thread: while waiting on nested Rayon work, the thread could pick
+/// up another wheel's installation, which would then block on the lock held by the same thread.
+#[cfg(any(windows, target_os = "macos"))]
+fn copy_parallel(
+    files: &[(PathBuf, PathBuf)],
+    copy: impl Fn(&Path, &Path) -> std::io::Result<()> + Sync,
+) -> std::io::Result<()> {
+    /// The minimum number of files for which to spawn threads.
+    const MIN_PARALLEL_FILES: usize = 16;
+    /// The maximum number of threads per batch.
+    const MAX_THREADS: usize = 8;
+
+    let threads = std::thread::available_parallelism()
+        .map_or(1, std::num::NonZeroUsize::get)
+        .min(MAX_THREADS);
+    if threads == 1 || files.len() < MIN_PARALLEL_FILES {
+        return files.iter().try_for_each(|(from, to)| copy(from, to));
+    }
+
+    let copy = &copy;
+    std::thread::scope(|scope| {
+        let handles = files
+            .chunks(files.len().div_ceil(threads))
+            .map(|chunk| {
+                scope.spawn(move || chunk.iter().try_for_each(|(from, to)| copy(from, to)))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("copy thread panicked"))
+            .collect()
+    })
+}
+
+/// Copy each file, one at a time, overwriting any existing file.
+///
+/// The parent directories of the destination paths must exist.
+#[cfg(not(any(windows, target_os = "macos")))]
+pub(crate) fn copy_files(files: &[(PathBuf, PathBuf)]) -> std::io::Result<()> {
+    files.iter().try_for_each(|(from, to)| copy_file(from, to))
+}
+
+/// Copy a single file, which will also set its permissions.
+///
+/// On Windows, retry if the target is held open by another process (e.g., antivirus software
+/// scanning a previous version).
+pub(crate) fn copy_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    uv_fs::with_retry_sync(from, to, "copying", || {
+        fs::copy(from, to)?;
+        Ok(())
+    })
+}
+
+/// Copy a file with `CopyFile2`, which copies its attributes and overwrites any existing file.
+#[cfg(windows)]
+#[allow(unsafe_code)] // We need to do an FFI call through the windows-* crates.
+fn copy_file2(from: &Path, to: &Path) -> std::io::Result<()> {
+    use std::os::windows::ffi::OsStrExt;
+
+    use windows::Win32::Storage::FileSystem::CopyFile2;
+    use windows::core::PCWSTR;
+
+    let wide = |path: &Path| {
+        path.as_os_str()
+            .encode_wide()
+            .chain(Some(0))
+            .collect::<Vec<u16>>()
+    };
+    let from = wide(from);
+    let to = wide(to);
+    // SAFETY: winapi call, with NUL-terminated paths that outlive it.
+    unsafe { CopyFile2(PCWSTR(from.as_ptr()), PCWSTR(to.as_ptr()), None) }
+        .map_err(std::io::Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use fs_err as fs;
+
+    use super::copy_files;
+
+    #[test]
+    fn copy_files_batches() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let src = temp_dir.path().join("src");
+        let dst = temp_dir.path().join("dst");
+        fs::create_dir_all(&src).unwrap();
+        fs::create_dir_all(&dst).unwrap();
+
+        // Include enough files to be copied in parallel, along with empty and large files.
+        let mut files = Vec::new();
+        for i in 0..150 {
+            let contents = match i {
+                0 => Vec::new(),
+                1 => vec![b'x'; 2 * 1024 * 1024 + 1],
+                _ => format!("file {i}\n").repeat(i).into_bytes(),
+            };
+            fs::write(src.join(format!("{i}.py")), &contents).unwrap();
+            files.push((src.join(format!("{i}.py")), dst.join(format!("{i}.py"))));
+        }
+
+        // Existing files are overwritten.
+        fs::write(
+            dst.join("2.py"),
+            "a previous version that's longer than the new one",
+        )
+        .unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(src.join("3.py"), std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        copy_files(&files).unwrap();
+
+        for (from, to) in &files {
+            assert_eq!(
+                fs::read(from).unwrap(),
+                fs::read(to).unwrap(),
+                "{}",
+                to.display()
+            );
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dst.join("3.py")).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+    }
+}
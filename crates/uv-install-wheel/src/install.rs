@@ -3,12 +3,14 @@
 
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Instant;
 
 use fs_err as fs;
 use fs_err::File;
 use tracing::{instrument, trace};
 
 use uv_distribution_filename::WheelFilename;
+use uv_fs::Quarantine;
 use uv_pep440::Version;
 use uv_pypi_types::{DirectUrl, Metadata10};
 
@@ -33,11 +35,13 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     wheel: impl AsRef<Path>,
     filename: &WheelFilename,
     direct_url: Option<&DirectUrl>,
+    requested: bool,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
+    quarantine: Quarantine,
     locks: &Locks,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
@@ -75,8 +79,10 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
+    let start = Instant::now();
     let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks, filename)?;
     trace!(?name, "Extracted {num_unpacked} files");
+    let link_time = start.elapsed();
 
     // Read the RECORD file.
     let mut record_file = File::open(
@@ -86,6 +92,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     )?;
     let mut record = read_record_file(&mut record_file)?;
 
+    let phase = Instant::now();
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
 
@@ -113,8 +120,11 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
         )?;
     }
 
+    let entrypoints_time = phase.elapsed();
+
     // 2.a Unpacked archive includes distribution-1.0.dist-info/ and (if there is data) distribution-1.0.data/.
     // 2.b Move each subtree of distribution-1.0.data/ onto its destination path. Each subdirectory of distribution-1.0.data/ is a key into a dict of destination directories, such as distribution-1.0.data/(purelib|platlib|headers|scripts|data). The initially supported paths are taken from distutils.command.install.
+    let phase = Instant::now();
     let data_dir = site_packages.join(format!("{dist_info_prefix}.data"));
     if data_dir.is_dir() {
         install_data(
@@ -134,13 +144,32 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     } else {
         trace!(?name, "No data");
     }
+    let data_time = phase.elapsed();
+
+    // Apply the quarantine mode to the installed files, including any scripts and data.
+    if !quarantine.is_preserve() {
+        trace!(?name, "Applying quarantine mode: {quarantine:?}");
+        for entry in &record {
+            let path = site_packages.join(&entry.path);
+            if !path.is_file() {
+                continue;
+            }
+            // Linked files may share their attributes with their source in the cache, so replace
+            // them with copies first.
+            if link_mode != LinkMode::Copy {
+                copy_in_place(&path)?;
+            }
+            quarantine.apply(&path)?;
+        }
+    }
 
+    let phase = Instant::now();
     if installer_metadata {
         trace!(?name, "Writing installer metadata");
         write_installer_metadata(
             site_packages,
             &dist_info_prefix,
-            true,
+            requested,
             direct_url,
             cache_info,
             build_info,
@@ -158,6 +187,29 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     for entry in record {
         record_writer.serialize(entry)?;
     }
+    let metadata_time = phase.elapsed();
+
+    trace!(
+        ?name,
+        "Installed in {:.3}s (link: {:.3}s, entrypoints: {:.3}s, data: {:.3}s, metadata: {:.3}s)",
+        start.elapsed().as_secs_f32(),
+        link_time.as_secs_f32(),
+        entrypoints_time.as_secs_f32(),
+        data_time.as_secs_f32(),
+        metadata_time.as_secs_f32(),
+    );
+
+    Ok(())
+}
 
+/// Replace a file (or a link to a file) with a copy of its contents, such that it no longer shares
+/// an inode with its source.
+fn copy_in_place(path: &Path) -> Result<(), Error> {
+    let parent = path
+        .parent()
+        .expect("installed files have a parent directory");
+    let temp = tempfile::NamedTempFile::new_in(parent)?;
+    fs::copy(path, temp.path())?;
+    temp.persist(path).map_err(|err| err.error)?;
     Ok(())
 }
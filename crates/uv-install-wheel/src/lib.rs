@@ -12,13 +12,24 @@ use uv_pypi_types::Scheme;
 
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
-pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
-pub use wheel::{LibKind, WheelFile, read_record_file};
+pub use record::hash_file;
+pub use script::Script;
+pub use sourceless::remove_compiled_sources;
+pub use uninstall::{
+    DirectoryPruner, Uninstall, remove_wheel_files, uninstall_egg, uninstall_legacy_editable,
+    uninstall_wheel,
+};
+pub use wheel::{
+    LibKind, WheelFile, read_installed_entry_points, read_installed_scripts, read_record_file,
+    read_wheel_scripts,
+};
 
+mod copy;
 mod install;
 mod linker;
 mod record;
 mod script;
+mod sourceless;
 mod uninstall;
 mod wheel;
 
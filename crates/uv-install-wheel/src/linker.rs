@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -17,14 +18,14 @@ use uv_fs::Simplified;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_warnings::{warn_user, warn_user_once};
 
-use crate::Error;
+use crate::{Error, copy};
 
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Default)]
 pub struct Locks {
-    /// The parent directory of a file in a synchronized copy
+    /// The parent directory of a file in a synchronized copy, normalized for case on Windows.
     copy_dir_locks: Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>,
-    /// Top level modules (excluding namespaces) we write to.
+    /// Top level modules (excluding namespaces) we write to, normalized for case on Windows.
     modules: Mutex<FxHashMap<OsString, WheelFilename>>,
     /// Preview settings for feature flags.
     preview: Preview,
@@ -40,14 +41,24 @@ impl Locks {
         }
     }
 
-    /// Warn when a module exists in multiple packages.
-    fn warn_module_conflict(&self, module: &OsStr, wheel_a: &WheelFilename) {
-        if let Some(wheel_b) = self
-            .modules
+    /// Return the lock for copying files into the given directory, normalized for case on Windows.
+    fn copy_dir_lock(&self, dir: PathBuf) -> Arc<Mutex<()>> {
+        self.copy_dir_locks
             .lock()
             .unwrap()
-            .insert(module.to_os_string(), wheel_a.clone())
-        {
+            .entry(dir)
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    }
+
+    /// Warn when a module exists in multiple packages.
+    fn warn_module_conflict(&self, module: &OsStr, wheel_a: &WheelFilename) {
+        if let Some(wheel_b) = self.modules.lock().unwrap().insert(
+            uv_fs::normalize_path_case(Path::new(module))
+                .into_owned()
+                .into_os_string(),
+            wheel_a.clone(),
+        ) {
             // Only warn if the preview feature is enabled
             if !self
                 .preview
@@ -111,6 +122,10 @@ impl LinkMode {
         locks: &Locks,
         filename: &WheelFilename,
     ) -> Result<usize, Error> {
+        // On Windows, use the extended-length form of the paths, since deeply nested files can
+        // exceed `MAX_PATH`.
+        let site_packages = uv_fs::verbatim_path(site_packages.as_ref());
+        let wheel = uv_fs::verbatim_path(wheel.as_ref());
         match self {
             Self::Clone => clone_wheel_files(site_packages, wheel, locks, filename),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks, filename),
@@ -252,7 +267,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         if reflink::reflink(&from, &tempfile).is_ok() {
-                            fs::rename(&tempfile, to)?;
+                            replace_with_retry(&tempfile, &to)?;
                         } else {
                             debug!(
                                 "Failed to clone `{}` to temporary location `{}`, attempting to copy files as a fallback",
@@ -288,7 +303,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         reflink::reflink(&from, &tempfile)?;
-                        fs::rename(&tempfile, to)?;
+                        replace_with_retry(&tempfile, &to)?;
                     }
                 } else {
                     return Err(Error::Reflink { from, to, err });
@@ -317,6 +332,9 @@ fn clone_recursive(
 }
 
 /// Extract a wheel by copying all of its files into site packages.
+///
+/// The directories are created upfront, after which the files in each directory are copied as a
+/// batch, using the platform-optimized backend (see [`copy::copy_files`]).
 fn copy_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
@@ -325,6 +343,9 @@ fn copy_wheel_files(
 ) -> Result<usize, Error> {
     let mut count = 0usize;
 
+    // Group the files by their parent directory, normalized for case on Windows.
+    let mut files: BTreeMap<PathBuf, Vec<(PathBuf, PathBuf)>> = BTreeMap::new();
+
     // Walk over the directory.
     for entry in WalkDir::new(&wheel) {
         let entry = entry?;
@@ -339,11 +360,22 @@ fn copy_wheel_files(
             continue;
         }
 
-        synchronized_copy(path, &out_path, locks)?;
-
+        let dir = uv_fs::normalize_path_case(out_path.parent().unwrap()).into_owned();
+        files
+            .entry(dir)
+            .or_default()
+            .push((path.to_path_buf(), out_path));
         count += 1;
     }
 
+    // Copy the files in each directory while holding the lock for that directory only, as in
+    // `synchronized_copy`.
+    for (dir, files) in files {
+        let dir_lock = locks.copy_dir_lock(dir);
+        let _dir_guard = dir_lock.lock().unwrap();
+        copy::copy_files(&files)?;
+    }
+
     Ok(count)
 }
 
@@ -394,7 +426,7 @@ fn hardlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
+                            replace_with_retry(&tempfile, &out_path)?;
                         } else {
                             debug!(
                                 "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
@@ -427,7 +459,7 @@ fn hardlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         fs::hard_link(path, &tempfile)?;
-                        fs_err::rename(&tempfile, &out_path)?;
+                        replace_with_retry(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
                     }
@@ -494,7 +526,7 @@ fn symlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         if create_symlink(path, &tempfile).is_ok() {
-                            fs::rename(&tempfile, &out_path)?;
+                            replace_with_retry(&tempfile, &out_path)?;
                         } else {
                             debug!(
                                 "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
@@ -527,7 +559,7 @@ fn symlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         create_symlink(path, &tempfile)?;
-                        fs::rename(&tempfile, &out_path)?;
+                        replace_with_retry(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
                     }
@@ -553,21 +585,20 @@ fn symlink_wheel_files(
 /// See: <https://github.com/astral-sh/uv/issues/4831>
 fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<()> {
     // Ensure we have a lock for the directory.
-    let dir_lock = {
-        let mut locks_guard = locks.copy_dir_locks.lock().unwrap();
-        locks_guard
-            .entry(to.parent().unwrap().to_path_buf())
-            .or_insert_with(|| Arc::new(Mutex::new(())))
-            .clone()
-    };
+    let dir_lock =
+        locks.copy_dir_lock(uv_fs::normalize_path_case(to.parent().unwrap()).into_owned());
 
     // Acquire a lock on the directory.
     let _dir_guard = dir_lock.lock().unwrap();
 
-    // Copy the file, which will also set its permissions.
-    fs::copy(from, to)?;
+    copy::copy_file(from, to)
+}
 
-    Ok(())
+/// Replace `to` with the file at `from` (a temporary file in the same directory tree), retrying on
+/// Windows if `to` is temporarily held open by another process, as is common when antivirus
+/// software scans installed files.
+fn replace_with_retry(from: &Path, to: &Path) -> std::io::Result<()> {
+    uv_fs::with_retry_sync(from, to, "renaming", || fs_err::rename(from, to))
 }
 
 /// Warn when a module exists in multiple packages.
@@ -1,5 +1,10 @@
+use std::io;
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
+use crate::wheel::copy_and_hash;
+
 /// Line in a RECORD file
 /// <https://www.python.org/dev/peps/pep-0376/#record>
 ///
@@ -11,6 +16,13 @@ use serde::{Deserialize, Serialize};
 pub struct RecordEntry {
     pub path: String,
     pub hash: Option<String>,
-    #[allow(dead_code)]
     pub size: Option<u64>,
 }
+
+/// Compute the hash of a file, in the format used by `RECORD` files (e.g.,
+/// `sha256=x_c8nmc4Huc-lKEsAXj78ZiyqSJ9hJ71j7vltY67icw`).
+pub fn hash_file(path: &Path) -> io::Result<String> {
+    let mut file = fs_err::File::open(path)?;
+    let (_, hash) = copy_and_hash(&mut file, &mut io::sink())?;
+    Ok(hash)
+}
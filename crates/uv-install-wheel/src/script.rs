@@ -9,10 +9,10 @@ use crate::{Error, wheel};
 /// A script defining the name of the runnable entrypoint and the module and function that should be
 /// run.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
-pub(crate) struct Script {
-    pub(crate) name: String,
-    pub(crate) module: String,
-    pub(crate) function: String,
+pub struct Script {
+    pub name: String,
+    pub module: String,
+    pub function: String,
 }
 
 impl Script {
@@ -57,7 +57,8 @@ impl Script {
         }))
     }
 
-    pub(crate) fn import_name(&self) -> &str {
+    /// The name to import from the module, i.e., the first component of the function path.
+    pub fn import_name(&self) -> &str {
         self.function
             .split_once('.')
             .map_or(&self.function, |(import_name, _)| import_name)
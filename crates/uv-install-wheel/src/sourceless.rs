@@ -0,0 +1,108 @@
+//! Support for bytecode-only ("sourceless") installs.
+
+use std::io;
+use std::path::{Component, Path};
+
+use data_encoding::BASE64URL_NOPAD;
+use fs_err as fs;
+use sha2::{Digest, Sha256};
+use tracing::trace;
+
+use uv_fs::Simplified;
+
+use crate::Error;
+use crate::record::RecordEntry;
+use crate::wheel::read_record_file;
+
+/// Remove the Python source files of an installed distribution that have a sourceless (i.e.,
+/// legacy) `.pyc` file alongside them, replacing the sources with the bytecode in the `RECORD`.
+///
+/// Sources without a `.pyc` file (e.g., those that failed to compile) are retained, as are any
+/// files outside of `site-packages` (e.g., in the `scripts` directory).
+///
+/// Returns the number of source files that were removed.
+pub fn remove_compiled_sources(dist_info: &Path) -> Result<usize, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record_path = dist_info.join("RECORD");
+    let mut record = {
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    let mut removed = 0usize;
+    for entry in &mut record {
+        let relative = Path::new(&entry.path);
+        if relative
+            .extension()
+            .is_none_or(|extension| extension != "py")
+        {
+            continue;
+        }
+        if relative
+            .components()
+            .any(|component| matches!(component, Component::ParentDir))
+        {
+            continue;
+        }
+
+        let source = site_packages.join(relative);
+        let bytecode = source.with_extension("pyc");
+        let contents = match fs::read(&bytecode) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        match fs::remove_file(&source) {
+            Ok(()) => trace!("Removed source file: {}", source.display()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let path = relative
+            .with_extension("pyc")
+            .portable_display()
+            .to_string();
+        let hash = Sha256::new().chain_update(&contents).finalize();
+        *entry = RecordEntry {
+            path,
+            hash: Some(format!("sha256={}", BASE64URL_NOPAD.encode(&hash))),
+            size: Some(contents.len() as u64),
+        };
+        removed += 1;
+    }
+
+    if removed == 0 {
+        return Ok(0);
+    }
+
+    // Write the updated RECORD file. The file is replaced atomically, since it may be linked from
+    // the cache.
+    record.sort();
+    record.dedup_by(|a, b| a.path == b.path);
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_writer(Vec::new());
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+    let contents = record_writer
+        .into_inner()
+        .map_err(|err| Error::Io(err.into_error()))?;
+    uv_fs::write_atomic_sync(&record_path, contents)?;
+
+    Ok(removed)
+}
@@ -1,7 +1,9 @@
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use fs_err as fs;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::sync::{LazyLock, Mutex};
 use tracing::trace;
 use uv_fs::write_atomic_sync;
@@ -11,6 +13,17 @@ use crate::wheel::read_record_file;
 
 /// Uninstall the wheel represented by the given `.dist-info` directory.
 pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
+    let pruner = DirectoryPruner::default();
+    let mut uninstall = remove_wheel_files(dist_info, &pruner)?;
+    uninstall.dir_count += pruner.prune()?;
+    Ok(uninstall)
+}
+
+/// Remove the files of the wheel represented by the given `.dist-info` directory, in parallel.
+///
+/// Any directories that may have been left empty are recorded in the [`DirectoryPruner`], such
+/// that they can be removed in a single pass once all distributions have been uninstalled.
+pub fn remove_wheel_files(dist_info: &Path, pruner: &DirectoryPruner) -> Result<Uninstall, Error> {
     let Some(site_packages) = dist_info.parent() else {
         return Err(Error::BrokenVenv(
             "dist-info directory is not in a site-packages directory".to_string(),
@@ -30,15 +43,14 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         read_record_file(&mut record_file)?
     };
 
-    let mut file_count = 0usize;
-    let mut dir_count = 0usize;
+    let file_count = AtomicUsize::new(0);
+    let dir_count = AtomicUsize::new(0);
 
     #[cfg(windows)]
     let itself = std::env::current_exe().ok();
 
     // Uninstall the files, keeping track of any directories that are left empty.
-    let mut visited = BTreeSet::new();
-    for entry in &record {
+    record.par_iter().try_for_each(|entry| {
         let path = site_packages.join(&entry.path);
 
         // On Windows, deleting the current executable is a special case.
@@ -53,100 +65,164 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                     match self_replace::self_delete_outside_path(site_packages) {
                         Ok(()) => {
                             trace!("Removed file: {}", path.display());
-                            file_count += 1;
-                            if let Some(parent) = path.parent() {
-                                visited.insert(normalize_path(parent));
-                            }
+                            file_count.fetch_add(1, Ordering::Relaxed);
+                            pruner.insert(site_packages, &path);
                         }
                         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
                         Err(err) => return Err(err.into()),
                     }
-                    continue;
+                    return Ok(());
                 }
             }
         }
 
-        match fs::remove_file(&path) {
+        // On Windows, use the extended-length form of the path, since deeply nested files can
+        // exceed `MAX_PATH`.
+        match remove_file(&uv_fs::verbatim_path(&path)) {
             Ok(()) => {
                 trace!("Removed file: {}", path.display());
-                file_count += 1;
-                if let Some(parent) = path.parent() {
-                    visited.insert(normalize_path(parent));
-                }
+                file_count.fetch_add(1, Ordering::Relaxed);
+                pruner.insert(site_packages, &path);
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-            Err(err) => match fs::remove_dir_all(&path) {
+            Err(err) => match fs::remove_dir_all(uv_fs::verbatim_path(&path)) {
                 Ok(()) => {
                     trace!("Removed directory: {}", path.display());
-                    dir_count += 1;
+                    dir_count.fetch_add(1, Ordering::Relaxed);
                 }
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
                 Err(_) => return Err(err.into()),
             },
         }
-    }
 
-    // If any directories were left empty, remove them. Iterate in reverse order such that we visit
-    // the deepest directories first.
-    for path in visited.iter().rev() {
+        Ok::<(), Error>(())
+    })?;
+
+    Ok(Uninstall {
+        file_count: file_count.into_inner(),
+        dir_count: dir_count.into_inner(),
+    })
+}
+
+/// A set of directories that may have been left empty by one or more uninstallations.
+///
+/// Directories are pruned in a single pass, rather than once per distribution, since distributions
+/// frequently share parent directories (e.g., namespace packages like `google`).
+#[derive(Debug, Default)]
+pub struct DirectoryPruner(Mutex<BTreeMap<PathBuf, PathBuf>>);
+
+impl DirectoryPruner {
+    /// Record that the given file was removed from the given `site-packages` directory.
+    fn insert(&self, site_packages: &Path, path: &Path) {
+        let Some(parent) = path.parent() else {
+            return;
+        };
+
         // No need to look at directories outside of `site-packages` (like `bin`).
-        if !path.starts_with(site_packages) {
-            continue;
+        let parent = normalize_path(parent);
+        if !parent.starts_with(site_packages) {
+            return;
         }
 
-        // Iterate up the directory tree, removing any empty directories. It's insufficient to
-        // rely on `visited` alone here, because we may end up removing a directory whose parent
-        // directory doesn't contain any files, leaving the _parent_ directory empty.
-        let mut path = path.as_path();
-        loop {
-            // If we reach the site-packages directory, we're done.
-            if path == site_packages {
-                break;
-            }
+        // On Windows, deduplicate directories that differ only in case (e.g., `Foo` and `foo`
+        // recorded by different distributions), since they refer to the same directory.
+        self.0.lock().unwrap().insert(
+            uv_fs::normalize_path_case(&parent).into_owned(),
+            uv_fs::normalize_path_case(site_packages).into_owned(),
+        );
+    }
 
-            // If the directory contains a `__pycache__` directory, always remove it. `__pycache__`
-            // may or may not be listed in the RECORD, but installers are expected to be smart
-            // enough to remove it either way.
-            let pycache = path.join("__pycache__");
-            match fs::remove_dir_all(&pycache) {
-                Ok(()) => {
-                    trace!("Removed directory: {}", pycache.display());
-                    dir_count += 1;
+    /// Remove any directories that were left empty, returning the number of directories removed.
+    pub fn prune(self) -> Result<usize, Error> {
+        let mut dir_count = 0usize;
+
+        // Iterate in reverse order such that we visit the deepest directories first.
+        let visited = self.0.into_inner().unwrap();
+        for (path, site_packages) in visited.iter().rev() {
+            // Iterate up the directory tree, removing any empty directories. It's insufficient to
+            // rely on `visited` alone here, because we may end up removing a directory whose
+            // parent directory doesn't contain any files, leaving the _parent_ directory empty.
+            let mut path = path.as_path();
+            loop {
+                // If we reach the site-packages directory, we're done.
+                if path == site_packages {
+                    break;
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-                Err(err) => return Err(err.into()),
-            }
 
-            // Try to read from the directory. If it doesn't exist, assume we deleted it in a
-            // previous iteration.
-            let mut read_dir = match fs::read_dir(path) {
-                Ok(read_dir) => read_dir,
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
-                Err(err) => return Err(err.into()),
-            };
+                // If the directory contains a `__pycache__` directory, always remove it.
+                // `__pycache__` may or may not be listed in the RECORD, but installers are
+                // expected to be smart enough to remove it either way.
+                let pycache = path.join("__pycache__");
+                match fs::remove_dir_all(uv_fs::verbatim_path(&pycache)) {
+                    Ok(()) => {
+                        trace!("Removed directory: {}", pycache.display());
+                        dir_count += 1;
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
 
-            // If the directory is not empty, we're done.
-            if read_dir.next().is_some() {
-                break;
-            }
+                // Try to read from the directory. If it doesn't exist, assume we deleted it in a
+                // previous iteration.
+                let mut read_dir = match fs::read_dir(uv_fs::verbatim_path(path)) {
+                    Ok(read_dir) => read_dir,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
+                    Err(err) => return Err(err.into()),
+                };
 
-            fs::remove_dir(path)?;
+                // If the directory is not empty, we're done.
+                if read_dir.next().is_some() {
+                    break;
+                }
 
-            trace!("Removed directory: {}", path.display());
-            dir_count += 1;
+                fs::remove_dir(uv_fs::verbatim_path(path))?;
+
+                trace!("Removed directory: {}", path.display());
+                dir_count += 1;
 
-            if let Some(parent) = path.parent() {
-                path = parent;
-            } else {
-                break;
+                if let Some(parent) = path.parent() {
+                    path = parent;
+                } else {
+                    break;
+                }
             }
         }
+
+        Ok(dir_count)
     }
+}
 
-    Ok(Uninstall {
-        file_count,
-        dir_count,
-    })
+/// Like [`fs::remove_file`], but clears the readonly attribute if necessary to remove the file.
+///
+/// On Windows, files marked as readonly (e.g., those extracted from some archives) can't be
+/// removed until the attribute is cleared.
+fn remove_file(path: &Path) -> std::io::Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err)
+            if err.kind() == std::io::ErrorKind::PermissionDenied
+                && set_not_readonly(path).unwrap_or(false) =>
+        {
+            fs::remove_file(path)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// If the file is readonly, change the permissions to make it _not_ readonly.
+fn set_not_readonly(path: &Path) -> std::io::Result<bool> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    if !permissions.readonly() {
+        return Ok(false);
+    }
+
+    // We're about to delete the file, so it's fine to set the permissions to world-writable.
+    #[allow(clippy::permissions_set_readonly_false)]
+    permissions.set_readonly(false);
+
+    fs::set_permissions(path, permissions)?;
+
+    Ok(true)
 }
 
 /// Uninstall the egg represented by the `.egg-info` directory.
@@ -348,3 +424,96 @@ fn normalize_path(path: &Path) -> PathBuf {
     }
     ret
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+    use std::path::{Path, PathBuf};
+
+    use fs_err as fs;
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+    use super::{DirectoryPruner, remove_wheel_files};
+
+    /// Create a distribution with the given files in `site-packages`, returning the path to its
+    /// `.dist-info` directory.
+    fn install(site_packages: &Path, name: &str, files: &[String]) -> PathBuf {
+        let dist_info = site_packages.join(format!("{name}-1.0.dist-info"));
+        fs::create_dir_all(&dist_info).unwrap();
+        fs::write(dist_info.join("METADATA"), "").unwrap();
+
+        let mut record = String::new();
+        for file in files {
+            let path = site_packages.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "").unwrap();
+            writeln!(record, "{file},,").unwrap();
+        }
+        writeln!(record, "{name}-1.0.dist-info/METADATA,,").unwrap();
+        writeln!(record, "{name}-1.0.dist-info/RECORD,,").unwrap();
+        fs::write(dist_info.join("RECORD"), record).unwrap();
+
+        dist_info
+    }
+
+    #[test]
+    fn prune_shared_parents() {
+        const DISTS: usize = 16;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let site_packages = temp_dir.path();
+
+        // Install several distributions into the `google` namespace package, with one
+        // distribution that remains installed.
+        let dists = (0..DISTS)
+            .map(|i| {
+                install(
+                    site_packages,
+                    &format!("google_{i}"),
+                    &[
+                        format!("google/pkg{i}/__init__.py"),
+                        format!("google/pkg{i}/nested/module.py"),
+                        format!("google/shared/sub{i}/module.py"),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>();
+        let remaining = install(
+            site_packages,
+            "google_remaining",
+            &["google/remaining/__init__.py".to_string()],
+        );
+
+        // Bytecode isn't listed in the `RECORD`, but is removed along with its directory.
+        fs::create_dir_all(site_packages.join("google/shared/sub0/__pycache__")).unwrap();
+        fs::write(
+            site_packages.join("google/shared/sub0/__pycache__/module.cpython-312.pyc"),
+            "",
+        )
+        .unwrap();
+
+        // Remove the distributions in parallel, sharing a single pruner.
+        let pruner = DirectoryPruner::default();
+        let file_count = dists
+            .par_iter()
+            .map(|dist_info| remove_wheel_files(dist_info, &pruner).unwrap().file_count)
+            .sum::<usize>();
+        assert_eq!(file_count, DISTS * 5);
+        pruner.prune().unwrap();
+
+        for (i, dist_info) in dists.iter().enumerate() {
+            assert!(!dist_info.exists());
+            assert!(!site_packages.join(format!("google/pkg{i}")).exists());
+        }
+        assert!(!site_packages.join("google/shared").exists());
+        assert!(site_packages.join("google/remaining/__init__.py").exists());
+        assert!(remaining.join("RECORD").exists());
+
+        // Removing the last distribution removes the namespace package, but not `site-packages`.
+        let pruner = DirectoryPruner::default();
+        remove_wheel_files(&remaining, &pruner).unwrap();
+        pruner.prune().unwrap();
+        assert!(site_packages.is_dir());
+        assert_eq!(fs::read_dir(site_packages).unwrap().count(), 0);
+    }
+}
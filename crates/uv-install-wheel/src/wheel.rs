@@ -78,7 +78,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -373,7 +376,9 @@ pub(crate) fn move_folder_recorded(
                         src.simplified_display()
                     ))
                 })?;
-            entry.path = relative_to(&target, site_packages)?.display().to_string();
+            entry.path = relative_to(&target, site_packages)?
+                .portable_display()
+                .to_string();
         }
     }
     Ok(())
@@ -449,10 +454,24 @@ fn install_script(
         let mut is_gui = false;
         let mut first = true;
         let mut byte = [0u8; 1];
+        // The first byte of the second line, if it was consumed while looking for a `\r\n`.
+        let mut leftover = Vec::new();
         loop {
             match script.read_exact(&mut byte) {
                 Ok(()) => {
-                    if byte[0] == b'\n' || byte[0] == b'\r' {
+                    if byte[0] == b'\n' {
+                        break;
+                    }
+
+                    // Treat `\r\n` (as in scripts authored on Windows) as a single line ending, so
+                    // that the rewritten script doesn't gain a blank line.
+                    if byte[0] == b'\r' {
+                        match script.read_exact(&mut byte) {
+                            Ok(()) if byte[0] == b'\n' => {}
+                            Ok(()) => leftover.push(byte[0]),
+                            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {}
+                            Err(err) => return Err(Error::Io(err)),
+                        }
                         break;
                     }
 
@@ -481,7 +500,10 @@ fn install_script(
         }
 
         let mut target = uv_fs::tempfile_in(&layout.scheme.scripts)?;
-        let size_and_encoded_hash = copy_and_hash(&mut start.chain(script), &mut target)?;
+        let size_and_encoded_hash = copy_and_hash(
+            &mut start.chain(leftover.as_slice()).chain(script),
+            &mut target,
+        )?;
 
         persist_with_retry_sync(target, &script_absolute)?;
         fs::remove_file(&path)?;
@@ -576,7 +598,7 @@ fn install_script(
         })?;
 
     // Update the entry in the `RECORD`.
-    entry.path = script_relative.simplified_display().to_string();
+    entry.path = script_relative.portable_display().to_string();
     if let Some((size, encoded_hash)) = size_and_encoded_hash {
         entry.size = Some(size);
         entry.hash = Some(encoded_hash);
@@ -621,6 +643,22 @@ pub(crate) fn install_data(
                 for file in fs::read_dir(path)? {
                     let file = file?;
 
+                    // Some legacy wheels include bytecode compiled from their scripts; install it
+                    // alongside the scripts (as pip does), rather than rejecting the wheel.
+                    if file.file_name() == "__pycache__" && file.file_type()?.is_dir() {
+                        if !initialized {
+                            fs::create_dir_all(&layout.scheme.scripts)?;
+                            initialized = true;
+                        }
+                        move_folder_recorded(
+                            &file.path(),
+                            &layout.scheme.scripts.join("__pycache__"),
+                            site_packages,
+                            record,
+                        )?;
+                        continue;
+                    }
+
                     // Couldn't find any docs for this, took it directly from
                     // https://github.com/pypa/pip/blob/b5457dfee47dd9e9f6ec45159d9d410ba44e5ea1/src/pip/_internal/operations/install/wheel.py#L565-L583
                     let name = file.file_name().to_string_lossy().to_string();
@@ -909,6 +947,44 @@ pub(crate) fn parse_scripts(
     scripts_from_ini(extras, python_minor, ini)
 }
 
+/// Read the names of the console and GUI scripts declared by an unpacked wheel.
+pub fn read_wheel_scripts(wheel: impl AsRef<Path>, python_minor: u8) -> Result<Vec<String>, Error> {
+    let dist_info_prefix = find_dist_info(&wheel)?;
+    let (console_scripts, gui_scripts) =
+        parse_scripts(&wheel, &dist_info_prefix, None, python_minor)?;
+    Ok(console_scripts
+        .into_iter()
+        .chain(gui_scripts)
+        .map(|script| script.name)
+        .collect())
+}
+
+/// Read the names of the console and GUI scripts declared by an installed distribution, given the
+/// path to its `.dist-info` (or `.egg-info`) directory.
+pub fn read_installed_scripts(
+    metadata_dir: impl AsRef<Path>,
+    python_minor: u8,
+) -> Result<Vec<String>, Error> {
+    Ok(read_installed_entry_points(metadata_dir, python_minor)?
+        .into_iter()
+        .map(|script| script.name)
+        .collect())
+}
+
+/// Read the console and GUI scripts declared by an installed distribution, given the path to its
+/// `.dist-info` (or `.egg-info`) directory.
+pub fn read_installed_entry_points(
+    metadata_dir: impl AsRef<Path>,
+    python_minor: u8,
+) -> Result<Vec<Script>, Error> {
+    // Read the entry points mapping. If the file doesn't exist, there are no scripts.
+    let Ok(ini) = fs::read_to_string(metadata_dir.as_ref().join("entry_points.txt")) else {
+        return Ok(Vec::new());
+    };
+    let (console_scripts, gui_scripts) = scripts_from_ini(None, python_minor, ini)?;
+    Ok(console_scripts.into_iter().chain(gui_scripts).collect())
+}
+
 /// Rename a file with a fallback to copy that switches over on the first failure.
 #[derive(Default, Copy, Clone)]
 enum RenameOrCopy {
@@ -944,15 +1020,21 @@ impl RenameOrCopy {
 mod test {
     use std::io::Cursor;
     use std::path::Path;
+    use std::str::FromStr;
 
     use anyhow::Result;
     use assert_fs::prelude::*;
+    use fs_err as fs;
     use indoc::{formatdoc, indoc};
 
+    use uv_normalize::PackageName;
+    use uv_pypi_types::Scheme;
+
     use super::{
-        Error, RecordEntry, Script, WheelFile, format_shebang, get_script_executable,
+        Error, RecordEntry, Script, WheelFile, format_shebang, get_script_executable, install_data,
         parse_email_message_file, read_record_file, write_installer_metadata,
     };
+    use crate::Layout;
 
     #[test]
     fn test_parse_email_message_file() {
@@ -1264,4 +1346,110 @@ mod test {
             .collect::<Vec<String>>();
         assert_eq!(expected, actual);
     }
+
+    /// Install the `.data` directory of a wheel that exercises the edge cases seen in legacy wheels:
+    /// scripts with `\r\n` line endings, GUI scripts, binary scripts, scripts that duplicate entry
+    /// points, compiled bytecode in the `scripts` directory, and headers.
+    #[test]
+    fn test_install_data_legacy() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python = temp_dir.child("bin").child("python");
+        python.write_str("")?;
+        let site_packages = temp_dir.child("site-packages");
+        let layout = Layout {
+            sys_executable: python.to_path_buf(),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.to_path_buf(),
+                platlib: site_packages.to_path_buf(),
+                scripts: temp_dir.child("bin").to_path_buf(),
+                data: temp_dir.to_path_buf(),
+                include: temp_dir.child("include").to_path_buf(),
+            },
+        };
+
+        let files: [(&str, &[u8]); 9] = [
+            ("scripts/unix", b"#!python\nprint('unix')\n"),
+            ("scripts/windows", b"#!python\r\nprint('windows')\r\n"),
+            ("scripts/carriage", b"#!python\rprint('carriage')\r"),
+            ("scripts/gui", b"#!pythonw\nprint('gui')\n"),
+            ("scripts/binary", b"\x7fELF\x00\x01"),
+            ("scripts/foo.exe", b"MZ"),
+            ("scripts/__pycache__/unix.cpython-312.pyc", b"\x00"),
+            ("headers/foo.h", b"#pragma once\n"),
+            ("data/share/foo.txt", b"foo\n"),
+        ];
+        let data_dir = site_packages.child("foo-1.0.data");
+        let mut record = Vec::new();
+        for (path, contents) in files {
+            data_dir.child(path).write_binary(contents)?;
+            record.push(RecordEntry {
+                path: format!("foo-1.0.data/{path}"),
+                hash: None,
+                size: None,
+            });
+        }
+
+        let console_scripts = [Script::from_value("foo", "foo:main", None)?.unwrap()];
+        install_data(
+            &layout,
+            false,
+            site_packages.path(),
+            data_dir.path(),
+            &PackageName::from_str("foo")?,
+            &console_scripts,
+            &[],
+            &mut record,
+        )?;
+
+        let shebang = format_shebang(python.path(), "posix", false);
+        let bin = temp_dir.child("bin").to_path_buf();
+        assert_eq!(
+            fs::read_to_string(bin.join("unix"))?,
+            format!("{shebang}\nprint('unix')\n")
+        );
+        assert_eq!(
+            fs::read_to_string(bin.join("windows"))?,
+            format!("{shebang}\nprint('windows')\r\n")
+        );
+        assert_eq!(
+            fs::read_to_string(bin.join("carriage"))?,
+            format!("{shebang}\nprint('carriage')\r")
+        );
+        assert_eq!(
+            fs::read_to_string(bin.join("gui"))?,
+            format!("{shebang}\nprint('gui')\n")
+        );
+        assert_eq!(fs::read(bin.join("binary"))?, b"\x7fELF\x00\x01");
+        assert!(bin.join("__pycache__/unix.cpython-312.pyc").is_file());
+        assert!(temp_dir.child("include/foo/foo.h").path().is_file());
+        assert!(temp_dir.child("share/foo.txt").path().is_file());
+
+        // The launcher for the `foo` entry point is generated separately.
+        assert!(!bin.join("foo.exe").exists());
+
+        // The `RECORD` should reference the installed files, with portable paths.
+        let mut paths = record
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            [
+                "../bin/__pycache__/unix.cpython-312.pyc",
+                "../bin/binary",
+                "../bin/carriage",
+                "../bin/gui",
+                "../bin/unix",
+                "../bin/windows",
+                "../include/foo/foo.h",
+                "../share/foo.txt",
+                "foo-1.0.data/scripts/foo.exe",
+            ]
+        );
+
+        Ok(())
+    }
 }
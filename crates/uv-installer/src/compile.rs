@@ -69,19 +69,26 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
+///
+/// If `sourceless` is set, the bytecode is written alongside each source file (rather than in
+/// `__pycache__`), such that it can be imported once the sources are removed. Since the bytecode
+/// can't be regenerated from the sources, it's compiled reproducibly: with a fixed hash seed and
+/// without embedding the source modification time, unless overridden by `PYTHONHASHSEED` or
+/// `PYC_INVALIDATION_MODE`, respectively.
 #[instrument(skip(python_executable))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
+    sourceless: bool,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths: `{}`",
         dir.display()
     );
-    let worker_count = concurrency.installs;
+    let worker_count = concurrency.compiles;
 
     // A larger buffer is significantly faster than just 1 or the worker count.
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count * 10);
@@ -125,6 +132,7 @@ pub async fn compile_tree(
             pip_compileall_py.clone(),
             receiver.clone(),
             timeout,
+            sourceless,
         );
 
         // Spawn each worker on a dedicated thread.
@@ -217,6 +225,7 @@ async fn worker(
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
+    sourceless: bool,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
@@ -230,7 +239,7 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, sourceless).await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -299,6 +308,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    sourceless: bool,
 ) -> Result<
     Option<(
         Child,
@@ -309,16 +319,26 @@ async fn launch_bytecode_compiler(
     CompileError,
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
-    let mut bytecode_compiler = Command::new(interpreter)
+    let mut command = Command::new(interpreter);
+    command
         .arg(pip_compileall_py)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .current_dir(dir)
         // Otherwise stdout is buffered and we'll wait forever for a response
-        .env(EnvVars::PYTHONUNBUFFERED, "1")
-        .spawn()
-        .map_err(CompileError::PythonSubcommand)?;
+        .env(EnvVars::PYTHONUNBUFFERED, "1");
+    if sourceless {
+        command.arg("--legacy");
+        // The hash seed determines the order of set constants in the bytecode.
+        if env::var_os(EnvVars::PYTHONHASHSEED).is_none() {
+            command.env(EnvVars::PYTHONHASHSEED, "0");
+        }
+        if env::var_os(EnvVars::PYC_INVALIDATION_MODE).is_none() {
+            command.env(EnvVars::PYC_INVALIDATION_MODE, "UNCHECKED_HASH");
+        }
+    }
+    let mut bytecode_compiler = command.spawn().map_err(CompileError::PythonSubcommand)?;
 
     // https://stackoverflow.com/questions/49218599/write-to-child-process-stdin-in-rust/49597789#comment120223107_49597789
     // Unbuffered, we need to write immediately or the python process will get stuck waiting
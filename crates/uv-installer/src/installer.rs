@@ -1,27 +1,41 @@
 use std::convert;
+use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
 use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::FxHashSet;
 use tokio::sync::oneshot;
-use tracing::instrument;
+use tracing::{instrument, trace};
 
 use uv_cache::Cache;
 use uv_configuration::RAYON_INITIALIZE;
-use uv_distribution_types::CachedDist;
+use uv_distribution_types::{CachedDist, Name};
+use uv_fs::{LockedFile, Quarantine};
 use uv_install_wheel::{Layout, LinkMode};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
 
+use crate::Store;
+
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: LinkMode,
+    /// How to treat the quarantine attributes of the installed files.
+    quarantine: Quarantine,
     cache: Option<&'a Cache>,
+    /// The shared [`Store`] to install from, if any.
+    store: Option<&'a Store>,
     reporter: Option<Arc<dyn Reporter>>,
     /// The name of the [`Installer`].
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// The packages that were explicitly requested, which are marked with a `REQUESTED` file.
+    ///
+    /// If `None`, all packages are considered to be requested.
+    requested: Option<FxHashSet<PackageName>>,
     /// Preview settings for the installer.
     preview: Preview,
 }
@@ -32,10 +46,13 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: LinkMode::default(),
+            quarantine: Quarantine::default(),
             cache: None,
+            store: None,
             reporter: None,
             name: Some("uv".to_string()),
             metadata: true,
+            requested: None,
             preview,
         }
     }
@@ -46,6 +63,12 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`Quarantine`] mode to use for this installer.
+    #[must_use]
+    pub fn with_quarantine(self, quarantine: Quarantine) -> Self {
+        Self { quarantine, ..self }
+    }
+
     /// Set the [`Cache`] to use for this installer.
     #[must_use]
     pub fn with_cache(self, cache: &'a Cache) -> Self {
@@ -55,6 +78,15 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the shared [`Store`] to use for this installer.
+    ///
+    /// Wheels are copied into the store before installation, and linked into the environment
+    /// from there, rather than from the cache.
+    #[must_use]
+    pub fn with_store(self, store: Option<&'a Store>) -> Self {
+        Self { store, ..self }
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -82,16 +114,29 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the packages that were explicitly requested by the user (as opposed to installed as
+    /// dependencies), per [PEP 376](https://peps.python.org/pep-0376/#requested).
+    #[must_use]
+    pub fn with_requested(self, requested: FxHashSet<PackageName>) -> Self {
+        Self {
+            requested: Some(requested),
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
         let Self {
             venv,
             cache,
+            store,
             link_mode,
+            quarantine,
             reporter,
             name: installer_name,
             metadata: installer_metadata,
+            requested,
             preview,
         } = self;
 
@@ -107,19 +152,25 @@ impl<'a> Installer<'a> {
 
         let layout = venv.interpreter().layout();
         let relocatable = venv.relocatable();
+        let store = store.cloned();
+        let root = venv.root().to_path_buf();
         // Initialize the threadpool with the user settings.
         LazyLock::force(&RAYON_INITIALIZE);
         rayon::spawn(move || {
-            let result = install(
-                wheels,
-                &layout,
-                installer_name.as_deref(),
-                link_mode,
-                reporter.as_ref(),
-                relocatable,
-                installer_metadata,
-                preview,
-            );
+            let result = materialize(wheels, store.as_ref(), &root).and_then(|(wheels, _lock)| {
+                install(
+                    wheels,
+                    &layout,
+                    installer_name.as_deref(),
+                    link_mode,
+                    quarantine,
+                    reporter.as_ref(),
+                    relocatable,
+                    installer_metadata,
+                    requested.as_ref(),
+                    preview,
+                )
+            });
 
             // This may fail if the main task was cancelled.
             let _ = tx.send(result);
@@ -141,19 +192,41 @@ impl<'a> Installer<'a> {
             }
         }
 
+        let (wheels, _lock) = materialize(wheels, self.store, self.venv.root())?;
+
         install(
             wheels,
             &self.venv.interpreter().layout(),
             self.name.as_deref(),
             self.link_mode,
+            self.quarantine,
             self.reporter.as_ref(),
             self.venv.relocatable(),
             self.metadata,
+            self.requested.as_ref(),
             self.preview,
         )
     }
 }
 
+/// Copy a set of wheels into the shared [`Store`], if any, registering the environment at the
+/// given root with the store.
+///
+/// Returns a shared lock on the store, which must be held until the wheels are linked into the
+/// environment, such that a concurrent garbage collection can't remove them in the interim.
+fn materialize(
+    wheels: Vec<CachedDist>,
+    store: Option<&Store>,
+    root: &Path,
+) -> Result<(Vec<CachedDist>, Option<LockedFile>)> {
+    let Some(store) = store else {
+        return Ok((wheels, None));
+    };
+    let lock = store.lock_shared()?;
+    store.register(root)?;
+    Ok((store.materialize(wheels)?, Some(lock)))
+}
+
 /// Install a set of wheels into a Python virtual environment synchronously.
 #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
 fn install(
@@ -161,13 +234,16 @@ fn install(
     layout: &Layout,
     installer_name: Option<&str>,
     link_mode: LinkMode,
+    quarantine: Quarantine,
     reporter: Option<&Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
+    requested: Option<&FxHashSet<PackageName>>,
     preview: Preview,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
     LazyLock::force(&RAYON_INITIALIZE);
+    let start = std::time::Instant::now();
     let locks = uv_install_wheel::Locks::new(preview);
     wheels.par_iter().try_for_each(|wheel| {
         uv_install_wheel::install_wheel(
@@ -175,10 +251,8 @@ fn install(
             relocatable,
             wheel.path(),
             wheel.filename(),
-            wheel
-                .parsed_url()
-                .map(uv_pypi_types::DirectUrl::from)
-                .as_ref(),
+            direct_url(wheel).as_ref(),
+            requested.is_none_or(|requested| requested.contains(wheel.name())),
             if wheel.cache_info().is_empty() {
                 None
             } else {
@@ -188,6 +262,7 @@ fn install(
             installer_name,
             installer_metadata,
             link_mode,
+            quarantine,
             &locks,
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
@@ -199,6 +274,13 @@ fn install(
         Ok::<(), Error>(())
     })?;
 
+    trace!(
+        "Linked {} wheel{} with {link_mode:?} in {:.3}s",
+        wheels.len(),
+        if wheels.len() == 1 { "" } else { "s" },
+        start.elapsed().as_secs_f32()
+    );
+
     Ok(wheels)
 }
 
@@ -209,3 +291,12 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when the resolution is complete.
     fn on_install_complete(&self);
 }
+
+/// Return the [`DirectUrl`](uv_pypi_types::DirectUrl) to record for a wheel, if it was installed
+/// from a direct URL, including the archive hashes (if known).
+fn direct_url(wheel: &CachedDist) -> Option<uv_pypi_types::DirectUrl> {
+    let CachedDist::Url(dist) = wheel else {
+        return None;
+    };
+    Some(uv_pypi_types::DirectUrl::from(&dist.url.parsed_url).with_hashes(dist.hashes.as_slice()))
+}
@@ -2,10 +2,13 @@ pub use compile::{CompileError, compile_tree};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
+pub use scripts::{ScriptConflict, find_script_conflicts};
 pub use site_packages::{
     InstallationStrategy, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
 };
-pub use uninstall::{UninstallError, uninstall};
+pub use store::{Store, StoreGcSummary};
+pub use uninstall::{Reporter as UninstallReporter, UninstallError, Uninstaller, uninstall};
+pub use validation::{ValidationMode, validate_environment};
 
 mod compile;
 mod preparer;
@@ -13,5 +16,8 @@ mod preparer;
 mod installer;
 mod plan;
 mod satisfies;
+mod scripts;
 mod site_packages;
+mod store;
 mod uninstall;
+mod validation;
@@ -51,6 +51,10 @@ with warnings.catch_warnings():
         # based and has a matching mtime (unless force=True).
         force = True
 
+    # In sourceless mode, write the bytecode alongside the source rather than in `__pycache__`, such
+    # that it can be imported once the source is removed.
+    legacy = "--legacy" in sys.argv[1:]
+
     # In rust, we provide one line per file to compile.
     for path in sys.stdin:
         # Remove trailing newlines.
@@ -61,7 +65,11 @@ with warnings.catch_warnings():
         # We'd like to show those errors, but given that pip thinks that's totally fine,
         # we can't really change that.
         success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
+            path,
+            invalidation_mode=invalidation_mode,
+            force=force,
+            quiet=2,
+            legacy=legacy,
         )
         # We're ready for the next file.
         print(path)
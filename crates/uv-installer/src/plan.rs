@@ -14,13 +14,14 @@ use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuiltDist, CachedDirectUrlDist, CachedDist, ConfigSettings, Dist, Error, ExtraBuildRequires,
     ExtraBuildVariables, Hashed, IndexLocations, InstalledDist, Name, PackageConfigSettings,
-    RequirementSource, Resolution, ResolvedDist, SourceDist,
+    RemoteSource, RequirementSource, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::PythonEnvironment;
+use uv_redacted::DisplaySafeUrl;
 use uv_types::HashStrategy;
 
 use crate::satisfies::RequirementSatisfaction;
@@ -184,6 +185,7 @@ impl<'a> Planner<'a> {
                 || dist
                     .source_tree()
                     .is_some_and(|source_tree| cache.must_revalidate_path(source_tree))
+                || remote_url(dist.as_ref()).is_some_and(|url| cache.must_revalidate_url(url))
             {
                 debug!("Must revalidate requirement: {}", dist.name());
                 remote.push(dist.clone());
@@ -523,6 +525,15 @@ impl<'a> Planner<'a> {
     }
 }
 
+/// Returns the remote URL of a direct URL distribution, if any.
+fn remote_url(dist: &Dist) -> Option<&DisplaySafeUrl> {
+    match dist {
+        Dist::Built(BuiltDist::DirectUrl(wheel)) => Some(wheel.url.raw()),
+        Dist::Source(SourceDist::DirectUrl(sdist)) => Some(sdist.url.raw()),
+        _ => None,
+    }
+}
+
 /// Returns `true` if the given distribution is a seed package.
 fn is_seed_package(dist_info: &InstalledDist, venv: &PythonEnvironment) -> bool {
     if venv.interpreter().python_tuple() >= (3, 12) {
@@ -690,6 +701,14 @@ impl Plan {
             && self.extraneous.is_empty()
     }
 
+    /// Returns the combined size of the distributions to download, in bytes.
+    ///
+    /// Distributions of unknown size (e.g., those from a registry that doesn't report file sizes)
+    /// are not counted.
+    pub fn download_size(&self) -> u64 {
+        self.remote.iter().filter_map(|dist| dist.size()).sum()
+    }
+
     /// Partition the remote distributions based on a predicate function.
     ///
     /// Returns a tuple of plans, where the first plan contains the remote distributions that match
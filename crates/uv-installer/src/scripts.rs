@@ -0,0 +1,81 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use uv_distribution_types::{CachedDist, InstalledDist, Name};
+use uv_normalize::PackageName;
+
+use crate::SitePackages;
+
+/// A console or GUI script that would be provided by more than one package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptConflict {
+    /// The name of the script.
+    pub name: String,
+    /// The packages that provide the script.
+    pub packages: BTreeSet<PackageName>,
+}
+
+impl Display for ScriptConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` (provided by {})",
+            self.name,
+            self.packages
+                .iter()
+                .map(|package| format!("`{package}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Find any scripts that would be provided by more than one package once the given wheels are
+/// installed and the given distributions are removed.
+///
+/// Only scripts provided by at least one of the wheels being installed are considered, such that
+/// conflicts between already-installed packages are left as-is.
+pub fn find_script_conflicts<'a>(
+    site_packages: &SitePackages,
+    installs: &[CachedDist],
+    removals: impl IntoIterator<Item = &'a InstalledDist>,
+) -> Result<Vec<ScriptConflict>, uv_install_wheel::Error> {
+    let python_minor = site_packages.interpreter().python_minor();
+
+    // Collect the scripts provided by the wheels being installed.
+    let mut providers: BTreeMap<String, BTreeSet<PackageName>> = BTreeMap::new();
+    for wheel in installs {
+        for script in uv_install_wheel::read_wheel_scripts(wheel.path(), python_minor)? {
+            providers
+                .entry(script)
+                .or_default()
+                .insert(wheel.name().clone());
+        }
+    }
+    if providers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Collect the scripts provided by the installed packages that will be retained.
+    let removals = removals
+        .into_iter()
+        .map(InstalledDist::install_path)
+        .collect::<BTreeSet<_>>();
+    let replaced = installs.iter().map(Name::name).collect::<BTreeSet<_>>();
+    for dist in site_packages.iter() {
+        if replaced.contains(dist.name()) || removals.contains(dist.install_path()) {
+            continue;
+        }
+        for script in uv_install_wheel::read_installed_scripts(dist.install_path(), python_minor)? {
+            if let Some(packages) = providers.get_mut(&script) {
+                packages.insert(dist.name().clone());
+            }
+        }
+    }
+
+    Ok(providers
+        .into_iter()
+        .filter(|(_, packages)| packages.len() > 1)
+        .map(|(name, packages)| ScriptConflict { name, packages })
+        .collect())
+}
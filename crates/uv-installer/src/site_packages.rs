@@ -309,12 +309,16 @@ impl SitePackages {
     }
 
     /// Returns if the installed packages satisfy the given requirements.
+    ///
+    /// If `transitive` is `false`, only the given requirements are validated, and not their
+    /// dependencies (as with `--no-deps`).
     pub fn satisfies_spec(
         &self,
         requirements: &[UnresolvedRequirementSpecification],
         constraints: &[NameRequirementSpecification],
         overrides: &[UnresolvedRequirementSpecification],
         installation: InstallationStrategy,
+        transitive: bool,
         markers: &ResolverMarkerEnvironment,
         tags: &Tags,
         config_settings: &ConfigSettings,
@@ -406,6 +410,7 @@ impl SitePackages {
             constraints.iter().map(|constraint| &constraint.requirement),
             overrides.iter().map(Cow::as_ref),
             installation,
+            transitive,
             markers,
             tags,
             config_settings,
@@ -422,6 +427,7 @@ impl SitePackages {
         constraints: impl Iterator<Item = &'a Requirement>,
         overrides: impl Iterator<Item = &'a Requirement>,
         installation: InstallationStrategy,
+        transitive: bool,
         markers: &ResolverMarkerEnvironment,
         tags: &Tags,
         config_settings: &ConfigSettings,
@@ -452,7 +458,12 @@ impl SitePackages {
 
         // Add the direct requirements to the queue.
         for requirement in requirements {
-            if let Some(r#overrides) = overrides.get(&requirement.name) {
+            // An override only applies in the environments in which its markers are satisfied.
+            if let Some(r#overrides) = overrides.get(&requirement.name).filter(|r#overrides| {
+                r#overrides
+                    .iter()
+                    .any(|dependency| dependency.evaluate_markers(Some(markers), &[]))
+            }) {
                 for dependency in r#overrides {
                     if dependency.evaluate_markers(Some(markers), &[]) {
                         if seen.insert((*dependency).clone()) {
@@ -527,6 +538,10 @@ impl SitePackages {
                         }
                     }
 
+                    if !transitive {
+                        continue;
+                    }
+
                     // Recurse into the dependencies.
                     let metadata = distribution
                         .read_metadata()
@@ -535,7 +550,13 @@ impl SitePackages {
                     // Add the dependencies to the queue.
                     for dependency in &metadata.requires_dist {
                         let dependency = Requirement::from(dependency.clone());
-                        if let Some(r#overrides) = overrides.get(&dependency.name) {
+                        if let Some(r#overrides) =
+                            overrides.get(&dependency.name).filter(|r#overrides| {
+                                r#overrides.iter().any(|dependency| {
+                                    dependency.evaluate_markers(Some(markers), &requirement.extras)
+                                })
+                            })
+                        {
                             for dependency in r#overrides {
                                 if dependency.evaluate_markers(Some(markers), &requirement.extras) {
                                     if seen.insert((*dependency).clone()) {
@@ -654,6 +675,63 @@ pub enum SitePackagesDiagnostic {
         /// The installed versions of the package.
         paths: Vec<PathBuf>,
     },
+    RecordUnavailable {
+        /// The package that is missing a `RECORD`.
+        package: PackageName,
+        /// The path to the package.
+        path: PathBuf,
+    },
+    BrokenRecord {
+        /// The package with missing files.
+        package: PackageName,
+        /// The path to the package.
+        path: PathBuf,
+        /// The files listed in the `RECORD` that are missing, relative to `site-packages`.
+        missing: Vec<PathBuf>,
+    },
+    DamagedNamespace {
+        /// The top-level directory shared by the packages.
+        namespace: String,
+        /// The packages that install files into the directory.
+        packages: Vec<PackageName>,
+    },
+}
+
+impl SitePackagesDiagnostic {
+    /// Return a suggestion for repairing the environment, if any.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::MetadataUnavailable { .. }
+            | Self::TagsUnavailable { .. }
+            | Self::IncompatiblePythonVersion { .. } => None,
+            Self::IncompatiblePlatform { package } => Some(format!(
+                "Reinstall the package for the current platform with `uv pip install --reinstall-package {package} {package}`"
+            )),
+            Self::MissingDependency { requirement, .. }
+            | Self::IncompatibleDependency { requirement, .. } => Some(format!(
+                "Install a compatible version with `uv pip install \"{}\"`",
+                requirement.name
+            )),
+            Self::DuplicatePackage { package, .. } => Some(format!(
+                "Remove the duplicate distributions with `uv pip uninstall {package}`, then reinstall it with `uv pip install {package}`"
+            )),
+            Self::RecordUnavailable { package, path } => Some(format!(
+                "Remove the package directory at `{}`, then reinstall it with `uv pip install {package}`",
+                path.user_display()
+            )),
+            Self::BrokenRecord { package, .. } => Some(format!(
+                "Reinstall the package with `uv pip install --reinstall-package {package} {package}`"
+            )),
+            Self::DamagedNamespace { packages, .. } => Some(format!(
+                "Reinstall the packages with `uv pip install --reinstall {}`",
+                packages
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )),
+        }
+    }
 }
 
 impl Diagnostic for SitePackagesDiagnostic {
@@ -700,6 +778,32 @@ impl Diagnostic for SitePackagesDiagnostic {
                         + &format!("\n  - {}", path.display()))
                 )
             }
+            Self::RecordUnavailable { package, path } => format!(
+                "The package `{package}` is broken or incomplete (unable to read `RECORD`) at: {}",
+                path.display(),
+            ),
+            Self::BrokenRecord {
+                package, missing, ..
+            } => {
+                let s = if missing.len() == 1 { "" } else { "s" };
+                format!(
+                    "The package `{package}` is missing {} file{s} listed in its `RECORD`:{}",
+                    missing.len(),
+                    missing.iter().fold(String::new(), |acc, path| acc
+                        + &format!("\n  - {}", path.display()))
+                )
+            }
+            Self::DamagedNamespace {
+                namespace,
+                packages,
+            } => format!(
+                "The namespace package `{namespace}` is shared by {}, but only some of them install `{namespace}/__init__.py`",
+                packages
+                    .iter()
+                    .map(|package| format!("`{package}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 
@@ -717,6 +821,9 @@ impl Diagnostic for SitePackagesDiagnostic {
                 ..
             } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
+            Self::RecordUnavailable { package, .. } => name == package,
+            Self::BrokenRecord { package, .. } => name == package,
+            Self::DamagedNamespace { packages, .. } => packages.contains(name),
         }
     }
 }
@@ -0,0 +1,230 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashSet;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::rm_rf;
+use uv_cache_key::cache_digest;
+use uv_distribution_types::CachedDist;
+use uv_fs::{LockedFile, Simplified};
+
+/// The prefix for the temporary directories into which entries are copied before they're moved
+/// into place.
+const TEMP_PREFIX: &str = ".tmp";
+
+/// A shared store of unpacked wheels, into which multiple environments can symlink.
+///
+/// Each entry in the store holds the unpacked contents of a single wheel, keyed by the package
+/// name, version, and cache archive. Environments that install from the store are registered with
+/// it, such that entries that are no longer referenced by any environment can be removed.
+///
+/// The store is laid out as follows:
+///
+/// ```text
+/// <root>/
+///   .lock
+///   packages/
+///     <name>-<version>-<archive>/
+///   environments/
+///     <digest>
+/// ```
+///
+/// Installations hold a shared lock on the store while they add and link entries, and garbage
+/// collection holds an exclusive lock, such that entries are never removed while an installation
+/// is in progress.
+#[derive(Debug, Clone)]
+pub struct Store {
+    root: PathBuf,
+}
+
+/// The result of garbage-collecting a [`Store`].
+#[derive(Debug, Default)]
+pub struct StoreGcSummary {
+    /// The number of entries that were removed.
+    pub num_entries: usize,
+    /// The number of bytes that were removed.
+    pub total_bytes: u64,
+    /// The number of stale environment registrations that were removed.
+    pub num_environments: usize,
+}
+
+impl Store {
+    /// Create a [`Store`] rooted at the given directory.
+    pub fn from_path(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Return the root of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the directory containing the unpacked wheels.
+    fn packages(&self) -> PathBuf {
+        self.root.join("packages")
+    }
+
+    /// Return the directory containing the environment registrations.
+    fn environments(&self) -> PathBuf {
+        self.root.join("environments")
+    }
+
+    /// Acquire a shared lock on the store, to be held while entries are added to the store and
+    /// linked into an environment.
+    pub fn lock_shared(&self) -> io::Result<LockedFile> {
+        fs_err::create_dir_all(&self.root)?;
+        LockedFile::acquire_shared_blocking(self.root.join(".lock"), self.root.user_display())
+    }
+
+    /// Acquire an exclusive lock on the store, to be held while entries are removed.
+    fn lock_exclusive(&self) -> io::Result<LockedFile> {
+        fs_err::create_dir_all(&self.root)?;
+        LockedFile::acquire_blocking(self.root.join(".lock"), self.root.user_display())
+    }
+
+    /// Copy the given wheels into the store (if they aren't present already), returning the wheels
+    /// with their paths rewritten to point into the store.
+    ///
+    /// Callers should hold a shared lock on the store (see [`Store::lock_shared`]) until the
+    /// wheels are linked into the environment.
+    pub fn materialize(&self, wheels: Vec<CachedDist>) -> io::Result<Vec<CachedDist>> {
+        let packages = self.packages();
+        fs_err::create_dir_all(&packages)?;
+
+        wheels
+            .into_iter()
+            .map(|wheel| {
+                let filename = wheel.filename();
+                let archive = wheel
+                    .path()
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let entry = packages.join(format!(
+                    "{}-{}-{archive}",
+                    filename.name.as_dist_info_name(),
+                    filename.version
+                ));
+
+                if !entry.is_dir() {
+                    debug!(
+                        "Copying `{}` into store at: {}",
+                        filename,
+                        entry.user_display()
+                    );
+
+                    // Copy into a temporary directory, then move into place, such that concurrent
+                    // installations never observe a partially-populated entry.
+                    let temp_dir = tempfile::Builder::new()
+                        .prefix(TEMP_PREFIX)
+                        .tempdir_in(&packages)?;
+                    uv_fs::copy_dir_all(wheel.path(), temp_dir.path())?;
+                    if let Err(err) = fs_err::rename(temp_dir.path(), &entry) {
+                        if !entry.is_dir() {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(match wheel {
+                    CachedDist::Registry(mut dist) => {
+                        dist.path = entry.into_boxed_path();
+                        CachedDist::Registry(dist)
+                    }
+                    CachedDist::Url(mut dist) => {
+                        dist.path = entry.into_boxed_path();
+                        CachedDist::Url(dist)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Register an environment as a consumer of the store.
+    ///
+    /// Callers should hold a shared lock on the store (see [`Store::lock_shared`]).
+    pub fn register(&self, environment: &Path) -> io::Result<()> {
+        let environment = std::path::absolute(environment)?;
+        let environments = self.environments();
+        fs_err::create_dir_all(&environments)?;
+        fs_err::write(
+            environments.join(cache_digest(&environment)),
+            environment.to_string_lossy().as_bytes(),
+        )
+    }
+
+    /// Remove any entries that aren't referenced by a registered environment.
+    ///
+    /// Registrations for environments that no longer exist are removed, too.
+    pub fn gc(&self) -> io::Result<StoreGcSummary> {
+        let mut summary = StoreGcSummary::default();
+
+        // Wait for any in-progress installations to finish linking their entries.
+        let _lock = self.lock_exclusive()?;
+
+        let packages = self.packages();
+        let Ok(packages) = fs_err::canonicalize(&packages) else {
+            return Ok(summary);
+        };
+
+        // Collect the entries referenced by each registered environment.
+        let mut referenced = FxHashSet::default();
+        match fs_err::read_dir(self.environments()) {
+            Ok(entries) => {
+                for registration in entries {
+                    let registration = registration?.path();
+                    let environment = PathBuf::from(fs_err::read_to_string(&registration)?);
+                    if !environment.is_dir() {
+                        debug!(
+                            "Removing stale environment from store: {}",
+                            environment.user_display()
+                        );
+                        fs_err::remove_file(&registration)?;
+                        summary.num_environments += 1;
+                        continue;
+                    }
+
+                    for file in WalkDir::new(&environment).follow_links(false) {
+                        let file = file.map_err(io::Error::other)?;
+                        if !file.path_is_symlink() {
+                            continue;
+                        }
+                        let Ok(target) = fs_err::canonicalize(file.path()) else {
+                            continue;
+                        };
+                        if let Ok(relative) = target.strip_prefix(&packages) {
+                            if let Some(entry) = relative.components().next() {
+                                referenced.insert(entry.as_os_str().to_os_string());
+                            }
+                        }
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        // Remove any unreferenced entries.
+        for entry in fs_err::read_dir(&packages)? {
+            let entry = entry?;
+            if referenced.contains(&entry.file_name()) {
+                continue;
+            }
+            // Skip any entries that are still being copied into place.
+            if entry.file_name().to_string_lossy().starts_with(TEMP_PREFIX) {
+                continue;
+            }
+            debug!(
+                "Removing unreferenced store entry: {}",
+                entry.path().user_display()
+            );
+            let removal = rm_rf(entry.path())?;
+            summary.num_entries += 1;
+            summary.total_bytes += removal.total_bytes;
+        }
+
+        Ok(summary)
+    }
+}
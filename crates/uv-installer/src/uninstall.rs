@@ -1,4 +1,13 @@
+use std::convert;
+use std::sync::{Arc, LazyLock};
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use tokio::sync::oneshot;
+use tracing::{instrument, trace};
+
+use uv_configuration::RAYON_INITIALIZE;
 use uv_distribution_types::{InstalledDist, InstalledDistKind, InstalledEggInfoFile};
+use uv_install_wheel::DirectoryPruner;
 
 /// Uninstall a package from the specified Python environment.
 pub async fn uninstall(
@@ -24,6 +33,103 @@ pub async fn uninstall(
     Ok(uninstall)
 }
 
+/// Uninstall a set of packages from a Python environment in parallel.
+#[derive(Default)]
+pub struct Uninstaller {
+    reporter: Option<Arc<dyn Reporter>>,
+}
+
+impl Uninstaller {
+    /// Set the [`Reporter`] to use for this uninstaller.
+    #[must_use]
+    pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
+        Self {
+            reporter: Some(reporter),
+        }
+    }
+
+    /// Uninstall a set of packages, returning the result of each uninstallation (in the order of
+    /// the input).
+    ///
+    /// Packages (and the files within each package) are removed in parallel, with concurrency
+    /// bounded by the installer thread pool. Any directories left empty are pruned in a single
+    /// pass once all packages have been removed.
+    #[instrument(skip_all, fields(num_dists = %dists.len()))]
+    pub async fn uninstall(
+        self,
+        dists: Vec<InstalledDist>,
+    ) -> Result<Vec<Result<uv_install_wheel::Uninstall, UninstallError>>, UninstallError> {
+        let Self { reporter } = self;
+
+        let (tx, rx) = oneshot::channel();
+
+        // Initialize the threadpool with the user settings.
+        LazyLock::force(&RAYON_INITIALIZE);
+        rayon::spawn(move || {
+            let pruner = DirectoryPruner::default();
+            let results = dists
+                .par_iter()
+                .map(|dist| {
+                    let result = uninstall_dist(dist, &pruner);
+                    if let Some(reporter) = reporter.as_ref() {
+                        reporter.on_uninstall_progress(dist);
+                    }
+                    result
+                })
+                .collect::<Vec<_>>();
+            let result = pruner
+                .prune()
+                .map(|dir_count| {
+                    trace!(
+                        "Pruned {dir_count} empty director{}",
+                        if dir_count == 1 { "y" } else { "ies" }
+                    );
+                    results
+                })
+                .map_err(UninstallError::from);
+
+            if let Some(reporter) = reporter.as_ref() {
+                reporter.on_uninstall_complete();
+            }
+
+            // This may fail if the main task was cancelled.
+            let _ = tx.send(result);
+        });
+
+        rx.await
+            .map_err(|_| UninstallError::Panicked)
+            .and_then(convert::identity)
+    }
+}
+
+/// Uninstall a single package, deferring the removal of any empty directories to the
+/// [`DirectoryPruner`].
+fn uninstall_dist(
+    dist: &InstalledDist,
+    pruner: &DirectoryPruner,
+) -> Result<uv_install_wheel::Uninstall, UninstallError> {
+    match &dist.kind {
+        InstalledDistKind::Registry(_) | InstalledDistKind::Url(_) => Ok(
+            uv_install_wheel::remove_wheel_files(dist.install_path(), pruner)?,
+        ),
+        InstalledDistKind::EggInfoDirectory(_) => {
+            Ok(uv_install_wheel::uninstall_egg(dist.install_path())?)
+        }
+        InstalledDistKind::LegacyEditable(dist) => {
+            Ok(uv_install_wheel::uninstall_legacy_editable(&dist.egg_link)?)
+        }
+        InstalledDistKind::EggInfoFile(dist) => Err(UninstallError::Distutils(dist.clone())),
+    }
+}
+
+pub trait Reporter: Send + Sync {
+    /// Callback to invoke when a package is uninstalled.
+    fn on_uninstall_progress(&self, dist: &InstalledDist);
+
+    /// Callback to invoke when all packages have been uninstalled.
+    fn on_uninstall_complete(&self);
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum UninstallError {
     #[error(
@@ -34,4 +140,6 @@ pub enum UninstallError {
     Uninstall(#[from] uv_install_wheel::Error),
     #[error(transparent)]
     Join(#[from] tokio::task::JoinError),
+    #[error("The uninstall task panicked")]
+    Panicked,
 }
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+
+use anyhow::Result;
+use fs_err as fs;
+use rustc_hash::FxHashSet;
+
+use uv_distribution_types::{InstalledDist, InstalledDistKind, Name};
+use uv_normalize::PackageName;
+use uv_platform_tags::Tags;
+use uv_pypi_types::ResolverMarkerEnvironment;
+
+use crate::{SitePackages, SitePackagesDiagnostic};
+
+/// The extent to which an environment should be validated.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Validate that the installed packages are compatible with the interpreter and with one
+    /// another (e.g., that all dependencies are installed).
+    #[default]
+    Compatibility,
+    /// Additionally validate the integrity of each installed distribution, to detect packages
+    /// whose files were modified or removed after installation.
+    Strict,
+}
+
+impl ValidationMode {
+    /// Determine the [`ValidationMode`] from a `--strict` flag.
+    pub fn from_strict(strict: bool) -> Self {
+        if strict {
+            Self::Strict
+        } else {
+            Self::Compatibility
+        }
+    }
+}
+
+/// Validate the packages installed in an environment, returning any diagnostics.
+///
+/// This is the single entrypoint for environment validation, shared by `uv pip check` and the
+/// `--strict` mode of `uv pip list`, `uv pip show`, `uv pip freeze` and `uv pip tree`.
+pub fn validate_environment(
+    site_packages: &SitePackages,
+    markers: &ResolverMarkerEnvironment,
+    tags: &Tags,
+    mode: ValidationMode,
+) -> Result<Vec<SitePackagesDiagnostic>> {
+    let mut diagnostics = site_packages.diagnostics(markers, tags)?;
+
+    if mode == ValidationMode::Strict {
+        let mut namespaces = NamespaceIndex::default();
+        for distribution in site_packages.iter() {
+            diagnostics.extend(validate_record(distribution, &mut namespaces)?);
+        }
+        diagnostics.extend(namespaces.diagnostics());
+    }
+
+    Ok(diagnostics)
+}
+
+/// Validate the `RECORD` of an installed distribution, ensuring that all listed files exist.
+///
+/// Any top-level packages installed by the distribution are added to the [`NamespaceIndex`].
+fn validate_record(
+    distribution: &InstalledDist,
+    namespaces: &mut NamespaceIndex,
+) -> Result<Option<SitePackagesDiagnostic>> {
+    // Only `.dist-info` distributions are required to include a `RECORD`.
+    if !matches!(
+        distribution.kind,
+        InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+    ) {
+        return Ok(None);
+    }
+
+    let dist_info = distribution.install_path();
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(None);
+    };
+
+    let record_path = dist_info.join("RECORD");
+    let record = match fs::File::open(&record_path) {
+        Ok(mut file) => match uv_install_wheel::read_record_file(&mut file) {
+            Ok(record) => record,
+            Err(_) => {
+                return Ok(Some(SitePackagesDiagnostic::RecordUnavailable {
+                    package: distribution.name().clone(),
+                    path: dist_info.to_path_buf(),
+                }));
+            }
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(Some(SitePackagesDiagnostic::RecordUnavailable {
+                package: distribution.name().clone(),
+                path: dist_info.to_path_buf(),
+            }));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut missing = Vec::new();
+    for entry in &record {
+        let path = Path::new(&entry.path);
+
+        // Bytecode may be removed (or never written) without affecting the distribution.
+        if path
+            .components()
+            .any(|component| component.as_os_str() == "__pycache__")
+        {
+            continue;
+        }
+
+        if !site_packages.join(path).exists() {
+            missing.push(path.to_path_buf());
+        }
+
+        namespaces.insert(distribution.name(), path);
+    }
+
+    if missing.is_empty() {
+        Ok(None)
+    } else {
+        missing.sort();
+        Ok(Some(SitePackagesDiagnostic::BrokenRecord {
+            package: distribution.name().clone(),
+            path: dist_info.to_path_buf(),
+            missing,
+        }))
+    }
+}
+
+/// An index from each top-level package directory in `site-packages` to the distributions that
+/// install files into it.
+#[derive(Debug, Default)]
+struct NamespaceIndex(BTreeMap<String, NamespaceEntry>);
+
+#[derive(Debug, Default)]
+struct NamespaceEntry {
+    /// The distributions that install files into the directory.
+    packages: FxHashSet<PackageName>,
+    /// The distributions that install an `__init__.py` file at the root of the directory.
+    initializers: FxHashSet<PackageName>,
+}
+
+impl NamespaceIndex {
+    /// Record that the given distribution installs a file at the given path, relative to
+    /// `site-packages`.
+    fn insert(&mut self, package: &PackageName, path: &Path) {
+        let mut components = path.components();
+        let Some(Component::Normal(directory)) = components.next() else {
+            return;
+        };
+        let Some(directory) = directory.to_str() else {
+            return;
+        };
+        if directory.ends_with(".dist-info") || directory.ends_with(".data") {
+            return;
+        }
+        let Some(Component::Normal(file)) = components.next() else {
+            // Top-level modules can't be shared.
+            return;
+        };
+
+        let entry = self.0.entry(directory.to_string()).or_default();
+        entry.packages.insert(package.clone());
+        if file == "__init__.py" && components.next().is_none() {
+            entry.initializers.insert(package.clone());
+        }
+    }
+
+    /// Return a diagnostic for each directory that is shared by multiple distributions, but
+    /// only initialized as a regular package by some of them.
+    ///
+    /// Namespace packages (e.g., `google`) must either omit `__init__.py` entirely (per PEP 420)
+    /// or include it in every distribution (as with `pkgutil`-style namespaces). If only some
+    /// distributions include it, uninstalling one of them will leave the namespace broken.
+    fn diagnostics(self) -> impl Iterator<Item = SitePackagesDiagnostic> {
+        self.0.into_iter().filter_map(|(namespace, entry)| {
+            if entry.packages.len() < 2
+                || entry.initializers.is_empty()
+                || entry.initializers.len() == entry.packages.len()
+            {
+                return None;
+            }
+            let mut packages = entry.packages.into_iter().collect::<Vec<_>>();
+            packages.sort();
+            Some(SitePackagesDiagnostic::DamagedNamespace {
+                namespace,
+                packages,
+            })
+        })
+    }
+}
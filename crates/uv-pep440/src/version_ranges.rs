@@ -22,6 +22,50 @@ impl From<VersionSpecifiers> for Ranges<Version> {
     }
 }
 
+impl VersionSpecifiers {
+    /// Remove any redundant specifiers, i.e., specifiers that don't narrow the set of versions
+    /// allowed by the remaining specifiers.
+    ///
+    /// For example, `>=1.0,>=1.2,<2,!=3.0` is minimized to `>=1.2,<2`. The remaining specifiers
+    /// are ordered by version, then by operator, such that equivalent sets of specifiers produce
+    /// the same output.
+    #[must_use]
+    pub fn minimize(self) -> Self {
+        let mut specifiers = self.into_iter().collect::<Vec<_>>();
+        specifiers.sort_by(|a, b| {
+            a.version()
+                .cmp(b.version())
+                .then_with(|| a.operator().cmp(b.operator()))
+        });
+        specifiers.dedup();
+
+        let ranges = specifiers
+            .iter()
+            .cloned()
+            .map(Ranges::from)
+            .collect::<Vec<Ranges<Version>>>();
+        let mut retained = vec![true; specifiers.len()];
+        for (index, range) in ranges.iter().enumerate() {
+            // Compute the range allowed by every other retained specifier; if it's already
+            // contained in the range of this specifier, the specifier is redundant.
+            let others = ranges
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index && retained[*other])
+                .fold(Ranges::full(), |acc, (_, range)| acc.intersection(range));
+            if others.subset_of(range) {
+                retained[index] = false;
+            }
+        }
+
+        specifiers
+            .into_iter()
+            .zip(retained)
+            .filter_map(|(specifier, retained)| retained.then_some(specifier))
+            .collect()
+    }
+}
+
 impl From<VersionSpecifier> for Ranges<Version> {
     /// Convert the [`VersionSpecifier`] to a PubGrub-compatible version range, using PEP 440
     /// semantics.
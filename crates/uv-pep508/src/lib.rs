@@ -42,8 +42,8 @@ pub use crate::origin::RequirementOrigin;
 #[cfg(feature = "non-pep508-extensions")]
 pub use crate::unnamed::{UnnamedRequirement, UnnamedRequirementUrl};
 pub use crate::verbatim_url::{
-    Scheme, VerbatimUrl, VerbatimUrlError, expand_env_vars, looks_like_git_repository,
-    split_scheme, strip_host,
+    Scheme, VerbatimUrl, VerbatimUrlError, contains_env_vars, expand_env_vars, expand_path_vars,
+    looks_like_git_repository, split_scheme, strip_host,
 };
 /// Version and version specifiers used in requirements (reexport).
 // https://github.com/konstin/pep508_rs/issues/19
@@ -325,6 +325,45 @@ impl<T: Pep508Url> Requirement<T> {
             ..self
         }
     }
+
+    /// Return the canonical form of the requirement, such that equivalent requirements compare
+    /// (and display) as equal.
+    ///
+    /// The package name and extras are normalized as part of parsing; here, the extras are sorted
+    /// and deduplicated, and redundant version specifiers are removed (e.g., `>=1.0,>=1.2` becomes
+    /// `>=1.2`). Markers are always stored in a canonical, simplified form (e.g., redundant bounds
+    /// like those in `python_version >= '3.8' and python_version >= '3.9'` are already collapsed),
+    /// so they're left as-is.
+    #[must_use]
+    pub fn canonicalize(mut self) -> Self {
+        let mut extras = self.extras.into_vec();
+        extras.sort_unstable();
+        extras.dedup();
+        self.extras = extras.into_boxed_slice();
+
+        if let Some(VersionOrUrl::VersionSpecifier(specifiers)) = self.version_or_url {
+            let specifiers = specifiers.minimize();
+            self.version_or_url = if specifiers.is_empty() {
+                None
+            } else {
+                Some(VersionOrUrl::VersionSpecifier(specifiers))
+            };
+        }
+
+        self.origin = None;
+        self
+    }
+}
+
+/// Parse a [Dependency Specifier](https://packaging.python.org/en/latest/specifications/dependency-specifiers/)
+/// and return its canonical form.
+///
+/// Equivalent requirements (e.g., `Foo_Bar[b,a]>=1.0,>=1.2` and `foo-bar[a,b] >= 1.2`) produce
+/// the same output. See [`Requirement::canonicalize`] for details. Credentials in URLs are
+/// retained.
+pub fn canonicalize_requirement(input: &str) -> Result<String, Pep508Error<VerbatimUrl>> {
+    let requirement = Requirement::<VerbatimUrl>::from_str(input)?.canonicalize();
+    Ok(requirement.displayable_with_credentials().to_string())
 }
 
 /// Type to parse URLs from `name @ <url>` into. Defaults to [`Url`].
@@ -1900,4 +1939,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn canonicalize() {
+        assert_eq!(
+            crate::canonicalize_requirement("Foo_Bar [b, a, b] >= 1.0, >=1.2, < 2, != 3.0")
+                .unwrap(),
+            "foo-bar[a,b]>=1.2,<2"
+        );
+        assert_eq!(
+            crate::canonicalize_requirement("foo == 1.0.*, ~= 1.0.1").unwrap(),
+            crate::canonicalize_requirement("foo ~=1.0.1, ==1.0.*").unwrap(),
+        );
+        assert_eq!(
+            crate::canonicalize_requirement(
+                "foo ; python_version >= '3.8' and python_version >= '3.9'"
+            )
+            .unwrap(),
+            crate::canonicalize_requirement("foo ; python_version >= '3.9'").unwrap(),
+        );
+        assert_eq!(
+            crate::canonicalize_requirement(
+                "foo @ https://example.com/foo-1.0.tar.gz ; os_name == 'nt'"
+            )
+            .unwrap(),
+            "foo @ https://example.com/foo-1.0.tar.gz ; os_name == 'nt'"
+        );
+    }
 }
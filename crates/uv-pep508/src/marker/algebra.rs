@@ -546,6 +546,162 @@ impl InternerGuard<'_> {
         self.create_node(node.var.clone(), children)
     }
 
+    /// Simplify this tree by *assuming* that the given `assumption` is true.
+    ///
+    /// The result is equivalent to this tree in every environment in which the assumption holds,
+    /// but may evaluate to anything outside of it. That is, `simplify_assuming(x, a) and a` is
+    /// always equal to `x and a`.
+    ///
+    /// For example, assuming `sys_platform == 'linux'`, the marker
+    /// `sys_platform == 'linux' and python_full_version >= '3.12'` simplifies to
+    /// `python_full_version >= '3.12'`, and `sys_platform == 'win32'` simplifies to `false`.
+    pub(crate) fn simplify_assuming(&mut self, i: NodeId, assumption: NodeId) -> NodeId {
+        if matches!(i, NodeId::TRUE | NodeId::FALSE)
+            || matches!(assumption, NodeId::TRUE | NodeId::FALSE)
+        {
+            return i;
+        }
+
+        // If the tree can't be satisfied under the assumption, or is implied by it, it collapses
+        // to a terminal node.
+        if self.is_disjoint(i, assumption) {
+            return NodeId::FALSE;
+        }
+        if self.is_disjoint(i.not(), assumption) {
+            return NodeId::TRUE;
+        }
+
+        let (node, assumed) = (self.shared.node(i), self.shared.node(assumption));
+        match node.var.cmp(&assumed.var) {
+            // The assumption constrains a variable that doesn't appear in this tree, so we
+            // weaken the assumption by allowing any value for that variable.
+            Ordering::Greater => {
+                let mut weakened = NodeId::FALSE;
+                for child in assumed.children.nodes() {
+                    weakened = self.or(weakened, child.negate(assumption));
+                }
+                self.simplify_assuming(i, weakened)
+            }
+            // The assumption doesn't constrain this variable; simplify all nodes recursively.
+            Ordering::Less => {
+                let children = node
+                    .children
+                    .map(i, |node| self.simplify_assuming(node, assumption));
+                self.create_node(node.var.clone(), children)
+            }
+            // The assumption constrains this variable: simplify each edge under the assumption
+            // for the same outcome, and drop any edges that the assumption excludes.
+            Ordering::Equal => {
+                let children = match (&node.children, &assumed.children) {
+                    (
+                        Edges::Version { edges },
+                        Edges::Version {
+                            edges: assumed_edges,
+                        },
+                    ) => Edges::Version {
+                        edges: self.simplify_ranges_assuming(edges, i, assumed_edges, assumption),
+                    },
+                    (
+                        Edges::String { edges },
+                        Edges::String {
+                            edges: assumed_edges,
+                        },
+                    ) => Edges::String {
+                        edges: self.simplify_ranges_assuming(edges, i, assumed_edges, assumption),
+                    },
+                    (
+                        Edges::Boolean { high, low },
+                        Edges::Boolean {
+                            high: assumed_high,
+                            low: assumed_low,
+                        },
+                    ) => {
+                        let (high, low) = (high.negate(i), low.negate(i));
+                        let (assumed_high, assumed_low) = (
+                            assumed_high.negate(assumption),
+                            assumed_low.negate(assumption),
+                        );
+                        if assumed_low.is_false() {
+                            return self.simplify_assuming(high, assumed_high);
+                        }
+                        if assumed_high.is_false() {
+                            return self.simplify_assuming(low, assumed_low);
+                        }
+                        Edges::Boolean {
+                            high: self.simplify_assuming(high, assumed_high),
+                            low: self.simplify_assuming(low, assumed_low),
+                        }
+                    }
+                    _ => unreachable!("cannot simplify two `Edges` of different types"),
+                };
+                self.create_node(node.var.clone(), children)
+            }
+        }
+    }
+
+    /// Simplify the edges of a version or string variable, assuming the edges of the same
+    /// variable in `assumption`.
+    ///
+    /// Each edge is simplified under the union of the assumed edges that overlap with its range.
+    /// If all edges that aren't excluded by the assumption lead to the same node, the variable is
+    /// irrelevant under the assumption and all edges are redirected to that node. Otherwise, the
+    /// excluded edges are retained as-is, rather than merged into their neighbors, to avoid
+    /// replacing equality checks with unintuitive range comparisons.
+    fn simplify_ranges_assuming<T>(
+        &mut self,
+        edges: &SmallVec<(Ranges<T>, NodeId)>,
+        parent: NodeId,
+        assumed_edges: &SmallVec<(Ranges<T>, NodeId)>,
+        assumption: NodeId,
+    ) -> SmallVec<(Ranges<T>, NodeId)>
+    where
+        T: Clone + Ord,
+    {
+        let mut simplified = SmallVec::new();
+        let mut uniform = None;
+        let mut is_uniform = true;
+        for (range, child) in edges {
+            let mut overlapping = NodeId::FALSE;
+            for (assumed_range, assumed_child) in assumed_edges {
+                if !range.intersection(assumed_range).is_empty() {
+                    overlapping = self.or(overlapping, assumed_child.negate(assumption));
+                }
+            }
+
+            let child = if overlapping.is_false() {
+                child.negate(parent)
+            } else {
+                let child = self.simplify_assuming(child.negate(parent), overlapping);
+                match uniform {
+                    None => uniform = Some(child),
+                    Some(uniform) if uniform != child => is_uniform = false,
+                    Some(_) => {}
+                }
+                child
+            };
+            simplified.push((range.clone(), child));
+        }
+
+        if let (true, Some(uniform)) = (is_uniform, uniform) {
+            return simplified
+                .into_iter()
+                .map(|(range, _)| (range, uniform))
+                .collect();
+        }
+
+        // Merge any adjacent edges that now lead to the same node.
+        let mut combined: SmallVec<(Ranges<T>, NodeId)> = SmallVec::new();
+        for (range, child) in simplified {
+            match combined.last_mut() {
+                Some((prev, prev_child)) if *prev_child == child && can_conjoin(prev, &range) => {
+                    *prev = prev.union(&range);
+                }
+                _ => combined.push((range, child)),
+            }
+        }
+        combined
+    }
+
     /// Returns a new tree where the only nodes remaining are non-`extra`
     /// nodes.
     ///
@@ -1291,6 +1291,21 @@ impl MarkerTree {
         )
     }
 
+    /// Simplify this marker tree by *assuming* that the given marker tree is true.
+    ///
+    /// The result is only equivalent to this marker in environments that satisfy the assumption.
+    /// For example, assuming `sys_platform == 'linux'`, the marker
+    /// `sys_platform == 'linux' and platform_machine == 'x86_64'` simplifies to
+    /// `platform_machine == 'x86_64'`.
+    ///
+    /// Like [`MarkerTree::simplify_python_versions`], this is lossy: the inverse operation is to
+    /// take the conjunction with the assumption, which recovers the original marker only within the
+    /// assumed environments.
+    #[must_use]
+    pub fn simplify_assuming(self, assumption: Self) -> Self {
+        Self(INTERNER.lock().simplify_assuming(self.0, assumption.0))
+    }
+
     /// Remove the extras from a marker, returning `None` if the marker tree evaluates to `true`.
     ///
     /// Any `extra` markers that are always `true` given the provided extras will be removed.
@@ -2114,6 +2129,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn simplify_assuming() {
+        let linux = m("sys_platform == 'linux'");
+
+        assert_eq!(
+            m("sys_platform == 'linux' and python_full_version >= '3.12'").simplify_assuming(linux),
+            m("python_full_version >= '3.12'")
+        );
+        assert_eq!(
+            m("sys_platform == 'linux' or sys_platform == 'win32'").simplify_assuming(linux),
+            MarkerTree::TRUE
+        );
+        assert_eq!(
+            m("sys_platform == 'win32'").simplify_assuming(linux),
+            MarkerTree::FALSE
+        );
+
+        // Markers on variables that aren't constrained by the assumption are retained.
+        assert_eq!(
+            m("platform_machine == 'x86_64' or sys_platform == 'darwin'").simplify_assuming(linux),
+            m("platform_machine == 'x86_64'")
+        );
+
+        // Conjunctions in the assumption apply to each variable.
+        let linux_x86_64 = m("sys_platform == 'linux' and platform_machine == 'x86_64'");
+        assert_eq!(
+            m("(platform_machine == 'x86_64' and sys_platform == 'linux' and python_full_version < '3.13') or (platform_machine == 'aarch64' and sys_platform == 'linux')")
+                .simplify_assuming(linux_x86_64),
+            m("python_full_version < '3.13'")
+        );
+
+        // Equality checks aren't replaced with ranges when the variable remains relevant.
+        let linux_or_win32 = m("sys_platform == 'linux' or sys_platform == 'win32'");
+        assert_eq!(linux.simplify_assuming(linux_or_win32), linux);
+
+        // The conjunction with the assumption recovers the original marker.
+        let marker =
+            m("sys_platform == 'linux' and platform_machine == 'x86_64' and extra == 'cpu'");
+        let mut simplified = marker.simplify_assuming(linux_x86_64);
+        assert_eq!(simplified, m("extra == 'cpu'"));
+        simplified.and(linux_x86_64);
+        assert_eq!(simplified, marker);
+
+        // A trivial assumption has no effect.
+        assert_eq!(marker.simplify_assuming(MarkerTree::TRUE), marker);
+    }
+
     #[test]
     fn release_only() {
         assert!(m("python_full_version > '3.10' or python_full_version <= '3.10'").is_true());
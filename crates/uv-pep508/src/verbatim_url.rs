@@ -442,10 +442,7 @@ pub fn expand_env_vars(s: &str) -> Cow<'_, str> {
         project_root.to_string_lossy().to_string()
     });
 
-    static RE: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"(?P<var>\$\{(?P<name>[A-Z0-9_]+)})").unwrap());
-
-    RE.replace_all(s, |caps: &regex::Captures<'_>| {
+    ENV_VAR_RE.replace_all(s, |caps: &regex::Captures<'_>| {
         let name = caps.name("name").unwrap().as_str();
         std::env::var(name).unwrap_or_else(|_| match name {
             "PROJECT_ROOT" => PROJECT_ROOT_FRAGMENT.to_string(),
@@ -454,6 +451,39 @@ pub fn expand_env_vars(s: &str) -> Cow<'_, str> {
     })
 }
 
+/// Expand all available environment variables in a path, along with `${WORKSPACE_ROOT}`, which
+/// expands to the given workspace root.
+///
+/// Used for paths that are stored in the `pyproject.toml` or lockfile (e.g., a `tool.uv.sources`
+/// entry), such that the stored path remains portable across machines. Unlike
+/// [`expand_env_vars`], `${WORKSPACE_ROOT}` takes precedence over the environment.
+pub fn expand_path_vars<'a>(path: &'a Path, workspace_root: &Path) -> Cow<'a, Path> {
+    let Some(s) = path.to_str() else {
+        return Cow::Borrowed(path);
+    };
+    if !contains_env_vars(s) {
+        return Cow::Borrowed(path);
+    }
+    let expanded = ENV_VAR_RE.replace_all(s, |caps: &regex::Captures<'_>| {
+        let name = caps.name("name").unwrap().as_str();
+        match name {
+            "WORKSPACE_ROOT" => workspace_root.to_string_lossy().into_owned(),
+            _ => std::env::var(name).unwrap_or_else(|_| caps["var"].to_owned()),
+        }
+    });
+    Cow::Owned(PathBuf::from(expanded.into_owned()))
+}
+
+/// Returns `true` if the string contains any environment variable references (e.g.,
+/// `${WORKSPACE_ROOT}`).
+pub fn contains_env_vars(s: &str) -> bool {
+    ENV_VAR_RE.is_match(s)
+}
+
+/// A regular expression to match environment variable references, like `${MY_VARIABLE_1}`.
+static ENV_VAR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?P<var>\$\{(?P<name>[A-Z0-9_]+)})").unwrap());
+
 /// Like [`Url::parse`], but only splits the scheme. Derived from the `url` crate.
 pub fn split_scheme(s: &str) -> Option<(&str, &str)> {
     /// <https://url.spec.whatwg.org/#c0-controls-and-space>
@@ -55,6 +55,7 @@ pub enum Os {
         major: u16,
         minor: u16,
     },
+    Wasi,
     Macos {
         major: u16,
         minor: u16,
@@ -103,6 +104,7 @@ impl fmt::Display for Os {
             Self::Haiku { .. } => write!(f, "haiku"),
             Self::Android { .. } => write!(f, "android"),
             Self::Pyodide { .. } => write!(f, "pyodide"),
+            Self::Wasi => write!(f, "wasi"),
             Self::Ios { .. } => write!(f, "ios"),
         }
     }
@@ -636,6 +636,8 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<PlatformTag>, PlatformErro
                 minor: *minor,
             }]
         }
+        // There are no platform tags for WASI, so only pure Python wheels are compatible.
+        (Os::Wasi, Arch::Wasm32) => vec![],
         (
             Os::Ios {
                 major,
@@ -1358,6 +1360,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_tags_android_armv7() {
+        let tags =
+            compatible_tags(&Platform::new(Os::Android { api_level: 18 }, Arch::Armv7L)).unwrap();
+        let tags = tags.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_debug_snapshot!(
+            tags,
+            @r#"
+    [
+        "android_18_armeabi_v7a",
+        "android_17_armeabi_v7a",
+        "android_16_armeabi_v7a",
+    ]
+    "#
+        );
+    }
+
+    #[test]
+    fn test_platform_tags_wasi() {
+        let tags = compatible_tags(&Platform::new(Os::Wasi, Arch::Wasm32)).unwrap();
+        assert!(tags.is_empty());
+    }
+
     #[test]
     fn test_platform_tags_ios() {
         let tags = compatible_tags(&Platform::new(
@@ -19,6 +19,8 @@ bitflags::bitflags! {
         const DETECT_MODULE_CONFLICTS = 1 << 7;
         const FORMAT = 1 << 8;
         const NATIVE_AUTH = 1 << 9;
+        const TOOL_SHIMS = 1 << 10;
+        const TORCH_BACKEND = 1 << 11;
     }
 }
 
@@ -38,6 +40,8 @@ impl PreviewFeatures {
             Self::DETECT_MODULE_CONFLICTS => "detect-module-conflicts",
             Self::FORMAT => "format",
             Self::NATIVE_AUTH => "native-auth",
+            Self::TOOL_SHIMS => "tool-shims",
+            Self::TORCH_BACKEND => "torch-backend",
             _ => panic!("`flag_as_str` can only be used for exactly one feature flag"),
         }
     }
@@ -85,6 +89,8 @@ impl FromStr for PreviewFeatures {
                 "detect-module-conflicts" => Self::DETECT_MODULE_CONFLICTS,
                 "format" => Self::FORMAT,
                 "native-auth" => Self::NATIVE_AUTH,
+                "tool-shims" => Self::TOOL_SHIMS,
+                "torch-backend" => Self::TORCH_BACKEND,
                 _ => {
                     warn_user_once!("Unknown preview feature: `{part}`");
                     continue;
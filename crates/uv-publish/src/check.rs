@@ -0,0 +1,511 @@
+//! Checks for distributions before publishing, i.e., `uv publish --check`.
+//!
+//! These checks mirror the validation performed by the registry on upload (and by `twine check`),
+//! such that problems are reported before any file is uploaded.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use tokio::sync::Semaphore;
+use tracing::debug;
+
+use uv_cache::Refresh;
+use uv_distribution_filename::DistFilename;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::{AbiTag, PlatformTag};
+use uv_pypi_types::Metadata23;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::{CheckUrlClient, PublishError, PublishPrepareError, metadata};
+
+/// The metadata versions accepted by the registries.
+const METADATA_VERSIONS: &[&str] = &["1.0", "1.1", "1.2", "2.0", "2.1", "2.2", "2.3", "2.4"];
+
+/// PyPI's default upload limit per file, which may be raised per project on request.
+const PYPI_MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
+
+/// PyPI's maximum length of the `Summary` field.
+const PYPI_MAX_SUMMARY_LENGTH: usize = 512;
+
+/// The index-specific rules to check the files against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexRules {
+    /// PyPI or TestPyPI, which enforce additional restrictions on uploads.
+    Pypi,
+    /// Any other index, for which we only check the standards.
+    Generic,
+}
+
+impl IndexRules {
+    /// Determine the rules from the publish URL.
+    pub fn from_publish_url(publish_url: &DisplaySafeUrl) -> Self {
+        match publish_url.host_str() {
+            Some("upload.pypi.org" | "test.pypi.org") => Self::Pypi,
+            _ => Self::Generic,
+        }
+    }
+
+    /// The Simple API URL of the index, to check name availability against, if it can be
+    /// inferred from the publish URL.
+    pub fn simple_url(publish_url: &DisplaySafeUrl) -> Option<&'static str> {
+        match publish_url.host_str() {
+            Some("upload.pypi.org") => Some("https://pypi.org/simple/"),
+            Some("test.pypi.org") => Some("https://test.pypi.org/simple/"),
+            _ => None,
+        }
+    }
+
+    /// The maximum size of a single file, if known.
+    fn max_file_size(self) -> Option<u64> {
+        match self {
+            Self::Pypi => Some(PYPI_MAX_FILE_SIZE),
+            Self::Generic => None,
+        }
+    }
+}
+
+/// The severity of a [`CheckDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The file can be uploaded, but likely not as intended.
+    Warning,
+    /// The file will be rejected by the index.
+    Error,
+}
+
+/// A problem found while checking a file for publishing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckDiagnostic {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl CheckDiagnostic {
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CheckDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Check a wheel or source distribution for problems that would cause the upload to fail or the
+/// project page to render incorrectly.
+pub async fn check_file(
+    file: &Path,
+    filename: &DistFilename,
+    rules: IndexRules,
+) -> Result<Vec<CheckDiagnostic>, PublishPrepareError> {
+    let mut diagnostics = Vec::new();
+
+    let size = fs_err::tokio::metadata(file).await?.len();
+    if let Some(max_file_size) = rules.max_file_size() {
+        if size > max_file_size {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "File is {size} bytes, which exceeds the index's upload limit of {max_file_size} \
+                bytes (the limit can be raised per project by the index administrators)"
+            )));
+        }
+    }
+
+    if let DistFilename::WheelFilename(wheel) = filename {
+        check_wheel_tags(
+            wheel.abi_tags(),
+            wheel.platform_tags(),
+            rules,
+            &mut diagnostics,
+        );
+    }
+
+    let metadata = metadata(file, filename).await?;
+    check_metadata(&metadata, filename, rules, &mut diagnostics);
+
+    Ok(diagnostics)
+}
+
+/// Check whether a project with the given name exists on the index.
+///
+/// Names are compared after normalization, as registries don't allow registering names that only
+/// differ in case or separators.
+pub async fn project_exists(
+    check_url_client: &CheckUrlClient<'_>,
+    name: &PackageName,
+    download_concurrency: &Semaphore,
+) -> Result<bool, PublishError> {
+    let CheckUrlClient {
+        index_url,
+        registry_client_builder,
+        client,
+        index_capabilities,
+        cache,
+    } = check_url_client;
+
+    // Avoid using the PyPI 10min default cache.
+    let cache_refresh =
+        (*cache)
+            .clone()
+            .with_refresh(Refresh::from_args(None, vec![name.clone()], vec![]));
+    let registry_client = registry_client_builder
+        .clone()
+        .cache(cache_refresh)
+        .wrap_existing(client);
+
+    debug!("Checking for project `{name}` in the registry");
+    match registry_client
+        .package_metadata(
+            name,
+            Some(index_url.into()),
+            index_capabilities,
+            download_concurrency,
+        )
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(err) => match err.kind() {
+            uv_client::ErrorKind::PackageNotFound(_) => Ok(false),
+            _ => Err(PublishError::CheckUrlIndex(err)),
+        },
+    }
+}
+
+/// Check the wheel tags for combinations the index won't accept or that are likely mistakes.
+fn check_wheel_tags(
+    abi_tags: &[AbiTag],
+    platform_tags: &[PlatformTag],
+    rules: IndexRules,
+    diagnostics: &mut Vec<CheckDiagnostic>,
+) {
+    for platform_tag in platform_tags {
+        if rules == IndexRules::Pypi && matches!(platform_tag, PlatformTag::Linux { .. }) {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Platform tag `{platform_tag}` is not allowed on PyPI, use a `manylinux` or \
+                `musllinux` tag instead (e.g., by repairing the wheel with `auditwheel`)"
+            )));
+        }
+    }
+
+    let is_pure = platform_tags.iter().all(|tag| *tag == PlatformTag::Any);
+    if is_pure && abi_tags.iter().any(|tag| *tag != AbiTag::None) {
+        diagnostics.push(CheckDiagnostic::warning(format!(
+            "Platform-independent wheel has an ABI tag other than `none`: `{}`",
+            abi_tags.iter().join(".")
+        )));
+    }
+}
+
+/// Check the core metadata of a distribution.
+fn check_metadata(
+    metadata: &Metadata23,
+    filename: &DistFilename,
+    rules: IndexRules,
+    diagnostics: &mut Vec<CheckDiagnostic>,
+) {
+    // Metadata version.
+    let metadata_version = metadata.metadata_version.as_str();
+    if !METADATA_VERSIONS.contains(&metadata_version) {
+        diagnostics.push(CheckDiagnostic::error(format!(
+            "Unsupported metadata version `{metadata_version}`, expected one of: {}",
+            METADATA_VERSIONS.join(", ")
+        )));
+    } else if metadata_version != "2.4"
+        && (metadata.license_expression.is_some() || !metadata.license_files.is_empty())
+    {
+        diagnostics.push(CheckDiagnostic::error(format!(
+            "`License-Expression` and `License-File` require metadata version 2.4, but the file \
+            uses metadata version {metadata_version}"
+        )));
+    }
+
+    // Name and version, which must match the filename.
+    match PackageName::from_str(&metadata.name) {
+        Ok(name) if &name != filename.name() => {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Metadata name `{}` does not match the filename name `{}`",
+                metadata.name,
+                filename.name()
+            )));
+        }
+        Ok(_) => {}
+        Err(err) => diagnostics.push(CheckDiagnostic::error(format!(
+            "Invalid name `{}` in metadata: {err}",
+            metadata.name
+        ))),
+    }
+    match Version::from_str(&metadata.version) {
+        Ok(version) if &version != filename.version() => {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Metadata version `{}` does not match the filename version `{}`",
+                metadata.version,
+                filename.version()
+            )));
+        }
+        Ok(version) if version.is_local() && rules == IndexRules::Pypi => {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Local versions such as `{version}` are not allowed on PyPI"
+            )));
+        }
+        Ok(_) => {}
+        Err(err) => diagnostics.push(CheckDiagnostic::error(format!(
+            "Invalid version `{}` in metadata: {err}",
+            metadata.version
+        ))),
+    }
+
+    // Summary.
+    if let Some(summary) = &metadata.summary {
+        if summary.contains('\n') {
+            diagnostics.push(CheckDiagnostic::error(
+                "`Summary` must be a single line".to_string(),
+            ));
+        }
+        if rules == IndexRules::Pypi && summary.chars().count() > PYPI_MAX_SUMMARY_LENGTH {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "`Summary` is longer than {PYPI_MAX_SUMMARY_LENGTH} characters"
+            )));
+        }
+    }
+
+    check_description(
+        metadata.description.as_deref(),
+        metadata.description_content_type.as_deref(),
+        diagnostics,
+    );
+    check_classifiers(
+        &metadata.classifiers,
+        metadata.license_expression.is_some(),
+        rules,
+        diagnostics,
+    );
+}
+
+/// The markup of a long description, per its `Description-Content-Type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Markup {
+    Plain,
+    Markdown,
+    Rst,
+}
+
+/// Check that the long description has a known content type and renders.
+fn check_description(
+    description: Option<&str>,
+    content_type: Option<&str>,
+    diagnostics: &mut Vec<CheckDiagnostic>,
+) {
+    let Some(description) = description.filter(|description| !description.trim().is_empty()) else {
+        diagnostics.push(CheckDiagnostic::warning(
+            "No long description, the project page will be empty (set `readme` in \
+            `pyproject.toml`)",
+        ));
+        return;
+    };
+
+    let markup = if let Some(content_type) = content_type {
+        match parse_content_type(content_type) {
+            Ok(markup) => markup,
+            Err(message) => {
+                diagnostics.push(CheckDiagnostic::error(message));
+                return;
+            }
+        }
+    } else {
+        diagnostics.push(CheckDiagnostic::warning(
+            "`Description-Content-Type` is missing, the long description will be rendered as \
+            reStructuredText",
+        ));
+        Markup::Rst
+    };
+
+    if markup == Markup::Rst {
+        for line in rst_short_underlines(description) {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "The long description failed to render as reStructuredText: title underline too \
+                short on line {line}"
+            )));
+        }
+    }
+}
+
+/// Parse a `Description-Content-Type`, e.g., `text/markdown; charset=UTF-8; variant=GFM`.
+fn parse_content_type(content_type: &str) -> Result<Markup, String> {
+    let mut parts = content_type.split(';').map(str::trim);
+    let mime = parts.next().unwrap_or_default();
+    let markup = if mime.eq_ignore_ascii_case("text/plain") {
+        Markup::Plain
+    } else if mime.eq_ignore_ascii_case("text/markdown") {
+        Markup::Markdown
+    } else if mime.eq_ignore_ascii_case("text/x-rst") {
+        Markup::Rst
+    } else {
+        return Err(format!(
+            "Unsupported `Description-Content-Type` `{mime}`, expected one of: `text/plain`, \
+            `text/markdown`, `text/x-rst`"
+        ));
+    };
+
+    for parameter in parts.filter(|parameter| !parameter.is_empty()) {
+        let Some((key, value)) = parameter.split_once('=') else {
+            return Err(format!(
+                "Invalid parameter `{parameter}` in `Description-Content-Type`"
+            ));
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" if !value.eq_ignore_ascii_case("utf-8") => {
+                return Err(format!(
+                    "Unsupported charset `{value}` in `Description-Content-Type`, expected `UTF-8`"
+                ));
+            }
+            "variant" if markup != Markup::Markdown => {
+                return Err(format!(
+                    "The `variant` parameter is only supported for `text/markdown`, found in \
+                    `{content_type}`"
+                ));
+            }
+            "variant" if !matches!(value, "GFM" | "CommonMark") => {
+                return Err(format!(
+                    "Unsupported Markdown variant `{value}`, expected `GFM` or `CommonMark`"
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(markup)
+}
+
+/// Return the (one-based) line numbers of reStructuredText section titles whose underline is
+/// shorter than the title, which docutils reports as an error and registries refuse to render.
+fn rst_short_underlines(description: &str) -> Vec<usize> {
+    let lines = description.lines().collect::<Vec<_>>();
+    let mut short = Vec::new();
+    for (index, pair) in lines.windows(2).enumerate() {
+        let [title, underline] = pair else {
+            continue;
+        };
+        // Indented text (e.g., a literal block) can't be a section title.
+        if title.trim().is_empty() || title.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let underline = underline.trim_end();
+        let Some(adornment) = underline.chars().next() else {
+            continue;
+        };
+        // Shorter lines are treated as regular text rather than an underline by docutils.
+        if underline.len() < 4
+            || !adornment.is_ascii_punctuation()
+            || !underline.chars().all(|c| c == adornment)
+        {
+            continue;
+        }
+        // A line of the same characters is an overline or transition, not a title.
+        if title.trim_end().chars().all(|c| c == adornment) {
+            continue;
+        }
+        if underline.chars().count() < title.trim_end().chars().count() {
+            short.push(index + 2);
+        }
+    }
+    short
+}
+
+/// Check the trove classifiers for malformed or disallowed entries.
+fn check_classifiers(
+    classifiers: &[String],
+    has_license_expression: bool,
+    rules: IndexRules,
+    diagnostics: &mut Vec<CheckDiagnostic>,
+) {
+    for classifier in classifiers {
+        if !classifier.contains("::") || classifier.split("::").any(|part| part.trim().is_empty()) {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Invalid classifier `{classifier}`, expected `Category :: Value`"
+            )));
+            continue;
+        }
+
+        // `Private ::` classifiers exist precisely to prevent accidental uploads to PyPI.
+        if rules == IndexRules::Pypi && classifier.starts_with("Private ::") {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "Classifier `{classifier}` prevents uploading to PyPI"
+            )));
+        }
+
+        // See PEP 639.
+        if has_license_expression && classifier.starts_with("License ::") {
+            diagnostics.push(CheckDiagnostic::error(format!(
+                "License classifier `{classifier}` can't be combined with `License-Expression`"
+            )));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use insta::assert_snapshot;
+    use itertools::Itertools;
+
+    use uv_distribution_filename::DistFilename;
+
+    use super::{IndexRules, Markup, check_file, parse_content_type, rst_short_underlines};
+
+    #[test]
+    fn content_type() {
+        assert_eq!(parse_content_type("text/markdown"), Ok(Markup::Markdown));
+        assert_eq!(
+            parse_content_type("text/markdown; charset=UTF-8; variant=GFM"),
+            Ok(Markup::Markdown)
+        );
+        assert_eq!(parse_content_type("TEXT/X-RST"), Ok(Markup::Rst));
+        assert_snapshot!(
+            parse_content_type("text/html").unwrap_err(),
+            @"Unsupported `Description-Content-Type` `text/html`, expected one of: `text/plain`, `text/markdown`, `text/x-rst`"
+        );
+        assert_snapshot!(
+            parse_content_type("text/x-rst; variant=GFM").unwrap_err(),
+            @"The `variant` parameter is only supported for `text/markdown`, found in `text/x-rst; variant=GFM`"
+        );
+        assert_snapshot!(
+            parse_content_type("text/plain; charset=latin-1").unwrap_err(),
+            @"Unsupported charset `latin-1` in `Description-Content-Type`, expected `UTF-8`"
+        );
+    }
+
+    #[test]
+    fn rst_underlines() {
+        let description =
+            "Title\n=====\n\nA longer section\n------\n\n====\nOver\n====\n\nText\n---\n";
+        assert_eq!(rst_short_underlines(description), vec![5]);
+    }
+
+    /// The `tqdm` source distribution uses `License-Expression` with metadata version 2.3.
+    #[tokio::test]
+    async fn check_source_dist() {
+        let raw_filename = "tqdm-999.0.0.tar.gz";
+        let file = PathBuf::from("../../scripts/links/").join(raw_filename);
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+
+        let diagnostics = check_file(&file, &filename, IndexRules::Pypi)
+            .await
+            .unwrap();
+        assert_snapshot!(diagnostics.iter().join("\n"), @"`License-Expression` and `License-File` require metadata version 2.4, but the file uses metadata version 2.3");
+    }
+}
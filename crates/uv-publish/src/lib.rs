@@ -1,3 +1,4 @@
+mod check;
 mod trusted_publishing;
 
 use std::path::{Path, PathBuf};
@@ -39,6 +40,7 @@ use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
+pub use crate::check::{CheckDiagnostic, IndexRules, Severity, check_file, project_exists};
 use crate::trusted_publishing::{TrustedPublishingError, TrustedPublishingToken};
 
 #[derive(Error, Debug)]
@@ -519,9 +521,11 @@ pub async fn check_url(
     } = check_url_client;
 
     // Avoid using the PyPI 10min default cache.
-    let cache_refresh = (*cache)
-        .clone()
-        .with_refresh(Refresh::from_args(None, vec![filename.name().clone()]));
+    let cache_refresh = (*cache).clone().with_refresh(Refresh::from_args(
+        None,
+        vec![filename.name().clone()],
+        vec![],
+    ));
     let registry_client = registry_client_builder
         .clone()
         .cache(cache_refresh)
@@ -1,9 +1,14 @@
 use std::collections::BTreeMap;
 use std::path::Path;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use uv_small_str::SmallString;
+
+use crate::{HashAlgorithm, HashDigest};
+
 /// Metadata for a distribution that was installed via a direct URL.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/direct-url-data-structure/>
@@ -46,6 +51,27 @@ pub enum DirectUrl {
     },
 }
 
+impl DirectUrl {
+    /// Record the given hashes of the archive in the `archive_info`, if this is an archive URL.
+    ///
+    /// Directory and VCS URLs are returned unchanged, as are archives that already have hashes.
+    #[must_use]
+    pub fn with_hashes(mut self, hashes: &[HashDigest]) -> Self {
+        if hashes.is_empty() {
+            return self;
+        }
+        if let Self::ArchiveUrl { archive_info, .. } = &mut self {
+            archive_info.hashes.get_or_insert_with(|| {
+                hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                    .collect()
+            });
+        }
+        self
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct DirInfo {
@@ -62,6 +88,31 @@ pub struct ArchiveInfo {
     pub hashes: Option<BTreeMap<String, String>>,
 }
 
+impl ArchiveInfo {
+    /// Return the hash of the archive in `<algorithm>=<digest>` form, as in a URL fragment.
+    ///
+    /// Prefers the legacy `hash` field, falling back to the strongest secure entry in `hashes`.
+    pub fn fragment_hash(&self) -> Option<String> {
+        if let Some(hash) = &self.hash {
+            return Some(hash.clone());
+        }
+        let digests = self
+            .hashes
+            .as_ref()?
+            .iter()
+            .filter_map(|(algorithm, digest)| {
+                Some(HashDigest {
+                    algorithm: HashAlgorithm::from_str(algorithm).ok()?,
+                    digest: SmallString::from(digest.as_str()),
+                })
+            })
+            .filter(|digest| digest.algorithm.is_secure())
+            .collect::<Vec<_>>();
+        let digest = HashDigest::strongest(&digests)?;
+        Some(format!("{}={}", digest.algorithm, digest.digest))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct VcsInfo {
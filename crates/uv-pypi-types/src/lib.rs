@@ -6,6 +6,7 @@ pub use identifier::*;
 pub use lenient_requirement::*;
 pub use marker_environment::*;
 pub use metadata::*;
+pub use override_dependency::*;
 pub use parsed_url::*;
 pub use scheme::*;
 pub use simple_json::*;
@@ -19,6 +20,7 @@ mod identifier;
 mod lenient_requirement;
 mod marker_environment;
 mod metadata;
+mod override_dependency;
 mod parsed_url;
 mod scheme;
 mod simple_json;
@@ -36,6 +36,12 @@ pub struct ResolutionMetadata {
     /// Whether the version field is dynamic.
     #[serde(default)]
     pub dynamic: bool,
+    /// The license of the distribution (e.g., `MIT`), if known.
+    #[serde(default)]
+    pub license: Option<String>,
+    /// The URL of the distribution's homepage, if known.
+    #[serde(default)]
+    pub homepage: Option<String>,
 }
 
 /// From <https://github.com/PyO3/python-pkginfo-rs/blob/d719988323a0cfea86d4737116d7917f30e819e2/src/metadata.rs#LL78C2-L91C26>
@@ -80,6 +86,8 @@ impl ResolutionMetadata {
         let dynamic = headers
             .get_all_values("Dynamic")
             .any(|field| field == "Version");
+        let license = parse_license(&headers);
+        let homepage = parse_homepage(&headers);
 
         Ok(Self {
             name,
@@ -88,6 +96,8 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license,
+            homepage,
         })
     }
 
@@ -157,6 +167,8 @@ impl ResolutionMetadata {
                 },
             )
             .collect::<Box<_>>();
+        let license = parse_license(&headers);
+        let homepage = parse_homepage(&headers);
 
         Ok(Self {
             name,
@@ -165,6 +177,8 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license,
+            homepage,
         })
     }
 
@@ -264,10 +278,68 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license: None,
+            homepage: None,
         })
     }
 }
 
+/// Extract the license of a distribution from its metadata headers.
+///
+/// Prefers the SPDX `License-Expression` field, then the free-form `License` field, then the last
+/// segment of the first `License ::` classifier (e.g., `MIT License` for
+/// `License :: OSI Approved :: MIT License`). The free-form field often contains the full license
+/// text, in which case it's skipped in favor of the classifier.
+fn parse_license(headers: &Headers) -> Option<String> {
+    /// The maximum length of a free-form `License` field that's treated as a license name, rather
+    /// than as the license text.
+    const MAX_LICENSE_NAME_LENGTH: usize = 80;
+
+    if let Some(license_expression) = headers.get_first_value("License-Expression") {
+        let license_expression = license_expression.trim();
+        if !license_expression.is_empty() {
+            return Some(license_expression.to_string());
+        }
+    }
+    if let Some(license) = headers.get_first_value("License") {
+        let license = license.trim();
+        if !license.is_empty() && license.len() <= MAX_LICENSE_NAME_LENGTH {
+            return Some(license.to_string());
+        }
+    }
+    headers.get_all_values("Classifier").find_map(|classifier| {
+        let license = classifier.strip_prefix("License ::")?;
+        let license = license.rsplit("::").next()?.trim();
+        (!license.is_empty()).then(|| license.to_string())
+    })
+}
+
+/// Extract the homepage of a distribution from its metadata headers.
+///
+/// Prefers the `Home-page` field, then a `Project-URL` labeled as the homepage (e.g.,
+/// `Project-URL: Homepage, https://example.com`), with labels normalized as in the core metadata
+/// specification.
+fn parse_homepage(headers: &Headers) -> Option<String> {
+    if let Some(home_page) = headers.get_first_value("Home-page") {
+        let home_page = home_page.trim();
+        if !home_page.is_empty() {
+            return Some(home_page.to_string());
+        }
+    }
+    headers
+        .get_all_values("Project-URL")
+        .find_map(|project_url| {
+            let (label, url) = project_url.split_once(',')?;
+            let label = label
+                .chars()
+                .filter(|c| !c.is_ascii_punctuation() && !c.is_whitespace())
+                .collect::<String>()
+                .to_lowercase();
+            let url = url.trim();
+            (label == "homepage" && !url.is_empty()).then(|| url.to_string())
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -308,6 +380,34 @@ mod tests {
         assert!(matches!(meta, Err(MetadataError::InvalidName(_))));
     }
 
+    #[test]
+    fn test_parse_license_and_homepage() {
+        let s = "Metadata-Version: 2.4\nName: asdf\nVersion: 1.0";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(meta.license, None);
+        assert_eq!(meta.homepage, None);
+
+        let s = "Metadata-Version: 2.4\nName: asdf\nVersion: 1.0\nLicense-Expression: MIT OR Apache-2.0\nLicense: MIT\nHome-page: https://example.com\nProject-URL: Homepage, https://example.org";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(meta.license.as_deref(), Some("MIT OR Apache-2.0"));
+        assert_eq!(meta.homepage.as_deref(), Some("https://example.com"));
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: BSD\nProject-URL: Source, https://example.org/source\nProject-URL: Home-Page, https://example.org";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(meta.license.as_deref(), Some("BSD"));
+        assert_eq!(meta.homepage.as_deref(), Some("https://example.org"));
+
+        // The full license text is skipped in favor of the classifier.
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: Copyright (c) Someone\n        \n        Permission is hereby granted, free of charge, to any person obtaining a copy\nClassifier: License :: OSI Approved :: MIT License";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(meta.license.as_deref(), Some("MIT License"));
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: UNKNOWN\nClassifier: Programming Language :: Python\nClassifier: License :: OSI Approved :: MIT License";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(meta.license.as_deref(), Some("MIT License"));
+        assert_eq!(meta.homepage, None);
+    }
+
     #[test]
     fn test_parse_pkg_info() {
         let s = "Metadata-Version: 2.1";
@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep508::{Pep508Error, Requirement};
+
+use crate::VerbatimParsedUrl;
+
+/// A dependency override, as declared in `override-dependencies`.
+///
+/// In addition to a PEP 508 requirement, an override can be scoped to the dependencies of a
+/// specific package with a trailing `when` clause, as in `anyio==3.7.0 ; when = "fastapi"` or
+/// `anyio==3.7.0 ; sys_platform == 'linux' ; when = "fastapi"`. The marker on a scoped override
+/// limits it to specific environments, whereas an unscoped override replaces the original
+/// requirement regardless of its marker.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OverrideDependency {
+    /// The overriding requirement.
+    pub requirement: Requirement<VerbatimParsedUrl>,
+    /// The package to whose dependencies the override is limited, if any.
+    pub when: Option<PackageName>,
+}
+
+impl OverrideDependency {
+    /// Split a trailing `when` clause from an override, returning the requirement and the
+    /// (unquoted) name of the dependent.
+    fn split_when(s: &str) -> Option<(&str, &str)> {
+        let (requirement, clause) = s.rsplit_once(';')?;
+        let value = clause
+            .trim()
+            .strip_prefix("when")?
+            .trim_start()
+            .strip_prefix('=')?
+            .trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .or_else(|| {
+                value
+                    .strip_prefix('\'')
+                    .and_then(|value| value.strip_suffix('\''))
+            })?;
+        Some((requirement, value))
+    }
+}
+
+impl From<Requirement<VerbatimParsedUrl>> for OverrideDependency {
+    fn from(requirement: Requirement<VerbatimParsedUrl>) -> Self {
+        Self {
+            requirement,
+            when: None,
+        }
+    }
+}
+
+impl FromStr for OverrideDependency {
+    type Err = OverrideDependencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (requirement, when) = match Self::split_when(s) {
+            Some((requirement, when)) => (
+                requirement,
+                Some(
+                    PackageName::from_str(when)
+                        .map_err(|err| OverrideDependencyError::When(when.to_string(), err))?,
+                ),
+            ),
+            None => (s, None),
+        };
+        let requirement = Requirement::from_str(requirement).map_err(Box::new)?;
+        Ok(Self { requirement, when })
+    }
+}
+
+impl Display for OverrideDependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.requirement)?;
+        if let Some(when) = &self.when {
+            write!(f, " ; when = \"{when}\"")?;
+        }
+        Ok(())
+    }
+}
+
+impl serde::Serialize for OverrideDependency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for OverrideDependency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// An error that can occur when parsing an [`OverrideDependency`].
+#[derive(Debug, Error)]
+pub enum OverrideDependencyError {
+    #[error(transparent)]
+    Requirement(#[from] Box<Pep508Error<VerbatimParsedUrl>>),
+    #[error("Invalid package name in `when` clause: `{0}`")]
+    When(String, #[source] InvalidNameError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::OverrideDependency;
+
+    #[test]
+    fn parse_when() {
+        let dependency = OverrideDependency::from_str("anyio==3.7.0 ; when = \"fastapi\"").unwrap();
+        assert_eq!(dependency.requirement.name.as_ref(), "anyio");
+        assert!(dependency.requirement.marker.is_true());
+        assert_eq!(dependency.when.unwrap().as_ref(), "fastapi");
+
+        let dependency = OverrideDependency::from_str(
+            "anyio==3.7.0 ; sys_platform == 'linux' ; when = 'fastapi'",
+        )
+        .unwrap();
+        assert_eq!(
+            dependency.requirement.marker.try_to_string().unwrap(),
+            "sys_platform == 'linux'"
+        );
+        assert_eq!(dependency.when.unwrap().as_ref(), "fastapi");
+
+        let dependency =
+            OverrideDependency::from_str("anyio==3.7.0 ; sys_platform == 'linux'").unwrap();
+        assert!(dependency.when.is_none());
+
+        assert!(OverrideDependency::from_str("anyio==3.7.0 ; when = \"not a name\"").is_err());
+    }
+}
@@ -52,6 +52,9 @@ pub struct PypiFile {
     pub upload_time: Option<Timestamp>,
     pub url: SmallString,
     pub yanked: Option<Box<Yanked>>,
+    /// The time at which the file was yanked, if the index reports it (via the non-standard
+    /// `yanked-time` field).
+    pub yanked_time: Option<Timestamp>,
 }
 
 impl<'de> Deserialize<'de> for PypiFile {
@@ -80,6 +83,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                 let mut upload_time = None;
                 let mut url = None;
                 let mut yanked = None;
+                let mut yanked_time = None;
 
                 while let Some(key) = access.next_key::<String>()? {
                     match key.as_str() {
@@ -103,6 +107,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                         "upload-time" => upload_time = Some(access.next_value()?),
                         "url" => url = Some(access.next_value()?),
                         "yanked" => yanked = Some(access.next_value()?),
+                        "yanked-time" => yanked_time = Some(access.next_value()?),
                         _ => {
                             let _: serde::de::IgnoredAny = access.next_value()?;
                         }
@@ -119,6 +124,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                     upload_time,
                     url: url.ok_or_else(|| serde::de::Error::missing_field("url"))?,
                     yanked,
+                    yanked_time,
                 })
             }
         }
@@ -150,6 +156,9 @@ pub struct PyxFile {
     pub upload_time: Option<Timestamp>,
     pub url: SmallString,
     pub yanked: Option<Box<Yanked>>,
+    /// The time at which the file was yanked, if the index reports it (via the non-standard
+    /// `yanked-time` field).
+    pub yanked_time: Option<Timestamp>,
     pub zstd: Option<Zstd>,
 }
 
@@ -179,6 +188,7 @@ impl<'de> Deserialize<'de> for PyxFile {
                 let mut upload_time = None;
                 let mut url = None;
                 let mut yanked = None;
+                let mut yanked_time = None;
                 let mut zstd = None;
 
                 while let Some(key) = access.next_key::<String>()? {
@@ -203,6 +213,7 @@ impl<'de> Deserialize<'de> for PyxFile {
                         "upload-time" => upload_time = Some(access.next_value()?),
                         "url" => url = Some(access.next_value()?),
                         "yanked" => yanked = Some(access.next_value()?),
+                        "yanked-time" => yanked_time = Some(access.next_value()?),
                         "zstd" => {
                             zstd = Some(access.next_value()?);
                         }
@@ -221,6 +232,7 @@ impl<'de> Deserialize<'de> for PyxFile {
                     upload_time,
                     url: url.ok_or_else(|| serde::de::Error::missing_field("url"))?,
                     yanked,
+                    yanked_time,
                     zstd,
                 })
             }
@@ -508,6 +520,27 @@ impl FromStr for HashAlgorithm {
     }
 }
 
+impl HashAlgorithm {
+    /// Returns `true` if the algorithm is considered secure (i.e., any algorithm other than MD5).
+    pub fn is_secure(self) -> bool {
+        !matches!(self, Self::Md5)
+    }
+
+    /// Returns the relative strength of the algorithm.
+    ///
+    /// Algorithms are ranked by digest size. At equal sizes, SHA-256 is preferred over BLAKE2b, as
+    /// it's more widely supported by other tools.
+    fn strength(self) -> u8 {
+        match self {
+            Self::Md5 => 0,
+            Self::Blake2b => 1,
+            Self::Sha256 => 2,
+            Self::Sha384 => 3,
+            Self::Sha512 => 4,
+        }
+    }
+}
+
 impl std::fmt::Display for HashAlgorithm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -546,6 +579,16 @@ impl HashDigest {
     pub fn algorithm(&self) -> HashAlgorithm {
         self.algorithm
     }
+
+    /// Return the digest computed with the strongest algorithm, if any.
+    pub fn strongest<'a>(digests: impl IntoIterator<Item = &'a Self>) -> Option<&'a Self> {
+        digests.into_iter().max_by(|a, b| {
+            a.algorithm
+                .strength()
+                .cmp(&b.algorithm.strength())
+                .then_with(|| a.digest.cmp(&b.digest))
+        })
+    }
 }
 
 impl std::fmt::Display for HashDigest {
@@ -642,6 +685,7 @@ impl From<Hashes> for HashDigests {
             usize::from(value.sha512.is_some())
                 + usize::from(value.sha384.is_some())
                 + usize::from(value.sha256.is_some())
+                + usize::from(value.blake2b.is_some())
                 + usize::from(value.md5.is_some()),
         );
         if let Some(sha512) = value.sha512 {
@@ -662,6 +706,12 @@ impl From<Hashes> for HashDigests {
                 digest: sha256,
             });
         }
+        if let Some(blake2b) = value.blake2b {
+            digests.push(HashDigest {
+                algorithm: HashAlgorithm::Blake2b,
+                digest: blake2b,
+            });
+        }
         if let Some(md5) = value.md5 {
             digests.push(HashDigest {
                 algorithm: HashAlgorithm::Md5,
@@ -737,7 +787,7 @@ pub enum HashError {
 
 #[cfg(test)]
 mod tests {
-    use crate::{HashError, Hashes};
+    use crate::{HashAlgorithm, HashDigest, HashDigests, HashError, Hashes};
 
     #[test]
     fn parse_hashes() -> Result<(), HashError> {
@@ -826,4 +876,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn strongest_hash() {
+        let hashes = Hashes {
+            md5: Some("090376d812fb6ac5f171e5938e82e7f2".into()),
+            sha256: Some("40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f".into()),
+            sha384: None,
+            sha512: None,
+            blake2b: Some(
+                "af4793213ee66ef8fae3b93b3e29206f6b251e65c97bd91d8e1c5596ef15af0a".into(),
+            ),
+        };
+        let digests = HashDigests::from(hashes);
+        assert_eq!(digests.as_slice().len(), 3);
+        assert_eq!(
+            HashDigest::strongest(digests.iter()).map(HashDigest::algorithm),
+            Some(HashAlgorithm::Sha256)
+        );
+
+        let digests = HashDigests::from(Hashes {
+            md5: Some("090376d812fb6ac5f171e5938e82e7f2".into()),
+            sha256: None,
+            sha384: None,
+            sha512: Some("40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f".into()),
+            blake2b: Some(
+                "af4793213ee66ef8fae3b93b3e29206f6b251e65c97bd91d8e1c5596ef15af0a".into(),
+            ),
+        });
+        assert_eq!(
+            HashDigest::strongest(digests.iter()).map(HashDigest::algorithm),
+            Some(HashAlgorithm::Sha512)
+        );
+    }
 }
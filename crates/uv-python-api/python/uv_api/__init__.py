@@ -0,0 +1,108 @@
+"""Python bindings to the uv API.
+
+The blocking functions (`resolve`, `install`, and `lock`) release the GIL while they run. The
+`*_async` variants run them in the event loop's default executor, for use with `asyncio`.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import functools
+import os
+from typing import Optional, Sequence, Union
+
+from ._native import (
+    Lockfile,
+    Package,
+    Resolution,
+    UvError,
+    install,
+    lock,
+    resolve,
+    version,
+)
+
+__all__ = [
+    "Lockfile",
+    "Package",
+    "Resolution",
+    "UvError",
+    "install",
+    "install_async",
+    "lock",
+    "lock_async",
+    "resolve",
+    "resolve_async",
+    "version",
+]
+
+StrPath = Union[str, "os.PathLike[str]"]
+
+
+async def resolve_async(
+    requirements: Sequence[str],
+    *,
+    python: Optional[str] = None,
+    constraints: Sequence[StrPath] = (),
+    overrides: Sequence[StrPath] = (),
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> Resolution:
+    """Resolve a set of requirements, as in `uv pip compile`."""
+    return await _run(
+        resolve,
+        list(requirements),
+        python=python,
+        constraints=list(constraints),
+        overrides=list(overrides),
+        cache_dir=cache_dir,
+        offline=offline,
+    )
+
+
+async def install_async(
+    environment: StrPath,
+    *,
+    packages: Sequence[str] = (),
+    requirements: Sequence[StrPath] = (),
+    editables: Sequence[str] = (),
+    constraints: Sequence[StrPath] = (),
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> None:
+    """Install a set of requirements into an environment, as in `uv pip install`."""
+    return await _run(
+        install,
+        environment,
+        packages=list(packages),
+        requirements=list(requirements),
+        editables=list(editables),
+        constraints=list(constraints),
+        cache_dir=cache_dir,
+        offline=offline,
+    )
+
+
+async def lock_async(
+    project_dir: StrPath,
+    *,
+    python: Optional[str] = None,
+    locked: bool = False,
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> Lockfile:
+    """Lock the project in the given directory, as in `uv lock`."""
+    return await _run(
+        lock,
+        project_dir,
+        python=python,
+        locked=locked,
+        cache_dir=cache_dir,
+        offline=offline,
+    )
+
+
+async def _run(function, *args, **kwargs):
+    # `asyncio.to_thread` requires Python 3.9.
+    loop = asyncio.get_running_loop()
+    return await loop.run_in_executor(None, functools.partial(function, *args, **kwargs))
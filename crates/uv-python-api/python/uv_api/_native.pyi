@@ -0,0 +1,55 @@
+import os
+from typing import List, Optional, Sequence, Union
+
+StrPath = Union[str, os.PathLike[str]]
+
+class UvError(Exception): ...
+
+class Package:
+    @property
+    def name(self) -> str: ...
+    @property
+    def version(self) -> Optional[str]: ...
+
+class Resolution:
+    @property
+    def packages(self) -> List[Package]: ...
+    def to_pylock_toml(self) -> str: ...
+
+class Lockfile:
+    @staticmethod
+    def from_path(path: StrPath) -> Lockfile: ...
+    @property
+    def schema_version(self) -> int: ...
+    @property
+    def packages(self) -> List[Package]: ...
+    def to_toml(self) -> str: ...
+
+def resolve(
+    requirements: Sequence[str],
+    *,
+    python: Optional[str] = None,
+    constraints: Sequence[StrPath] = ...,
+    overrides: Sequence[StrPath] = ...,
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> Resolution: ...
+def install(
+    environment: StrPath,
+    *,
+    packages: Sequence[str] = ...,
+    requirements: Sequence[StrPath] = ...,
+    editables: Sequence[str] = ...,
+    constraints: Sequence[StrPath] = ...,
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> None: ...
+def lock(
+    project_dir: StrPath,
+    *,
+    python: Optional[str] = None,
+    locked: bool = False,
+    cache_dir: Optional[StrPath] = None,
+    offline: bool = False,
+) -> Lockfile: ...
+def version() -> str: ...
@@ -0,0 +1,265 @@
+//! Python bindings to the public API of the `uv` crate (see [`uv::api`]).
+//!
+//! The operations are exposed as blocking functions, which release the GIL while they run. The
+//! `uv_api` Python package wraps them in coroutines for use with `asyncio`.
+
+use std::path::PathBuf;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+use uv::api::cache::Cache;
+use uv::api::settings::{GlobalSettings, LockSettings, PipCompileSettings, PipInstallSettings};
+
+create_exception!(
+    _native,
+    UvError,
+    PyException,
+    "An error raised by a uv operation."
+);
+
+/// Convert an error from the uv API into a Python exception.
+fn to_py_err(err: uv::api::Error) -> PyErr {
+    // Include the chain of causes, as displayed by the uv CLI.
+    let mut message = err.to_string();
+    let mut source = std::error::Error::source(&err);
+    while let Some(cause) = source {
+        message.push_str("\n  Caused by: ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    UvError::new_err(message)
+}
+
+/// Construct the global settings and cache shared by all operations.
+fn globals(offline: bool, cache_dir: Option<PathBuf>) -> PyResult<(GlobalSettings, Cache)> {
+    let globals = GlobalSettings::builder()
+        .offline(offline)
+        .no_progress(true)
+        .build(None);
+    let cache = match cache_dir {
+        Some(cache_dir) => Cache::from_path(cache_dir),
+        None => Cache::from_user_dir(),
+    }
+    .map_err(to_py_err)?;
+    Ok((globals, cache))
+}
+
+/// Write the given requirements to a `requirements.in` file in a temporary directory, such that
+/// they can be passed to the requirements-file-based settings.
+fn requirements_file(requirements: &[String]) -> PyResult<(tempfile::TempDir, PathBuf)> {
+    let temp_dir = tempfile::tempdir().map_err(|err| UvError::new_err(err.to_string()))?;
+    let path = temp_dir.path().join("requirements.in");
+    let mut contents = requirements.join("\n");
+    contents.push('\n');
+    fs_err::write(&path, contents).map_err(|err| UvError::new_err(err.to_string()))?;
+    Ok((temp_dir, path))
+}
+
+/// A package in a resolution, a lockfile, or an environment.
+#[pyclass(module = "uv_api", frozen, eq, str)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Package {
+    #[pyo3(get)]
+    name: String,
+    #[pyo3(get)]
+    version: Option<String>,
+}
+
+impl std::fmt::Display for Package {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}=={version}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[pymethods]
+impl Package {
+    fn __repr__(&self) -> String {
+        match &self.version {
+            Some(version) => format!("Package(name={:?}, version={version:?})", self.name),
+            None => format!("Package(name={:?}, version=None)", self.name),
+        }
+    }
+}
+
+impl From<&uv::api::Package> for Package {
+    fn from(package: &uv::api::Package) -> Self {
+        Self {
+            name: package.name().to_string(),
+            version: package.version().map(ToString::to_string),
+        }
+    }
+}
+
+/// The packages selected by `resolve`.
+#[pyclass(module = "uv_api", frozen)]
+struct Resolution(uv::api::Resolution);
+
+#[pymethods]
+impl Resolution {
+    /// The packages in the resolution.
+    #[getter]
+    fn packages(&self) -> Vec<Package> {
+        self.0.packages().iter().map(Package::from).collect()
+    }
+
+    /// The resolution, serialized as a `pylock.toml` file.
+    fn to_pylock_toml(&self) -> &str {
+        self.0.to_pylock_toml()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Resolution(packages={})", self.0.packages().len())
+    }
+}
+
+/// A `uv.lock` lockfile, as produced by `lock`.
+#[pyclass(module = "uv_api", frozen)]
+struct Lockfile(uv::api::Lockfile);
+
+#[pymethods]
+impl Lockfile {
+    /// Read a `uv.lock` file from disk.
+    #[staticmethod]
+    fn from_path(path: PathBuf) -> PyResult<Self> {
+        uv::api::Lockfile::from_path(path)
+            .map(Self)
+            .map_err(to_py_err)
+    }
+
+    /// The version of the lockfile schema.
+    #[getter]
+    fn schema_version(&self) -> u32 {
+        self.0.schema_version()
+    }
+
+    /// The packages in the lockfile.
+    #[getter]
+    fn packages(&self) -> Vec<Package> {
+        self.0.packages().iter().map(Package::from).collect()
+    }
+
+    /// Serialize the lockfile to TOML, as written to `uv.lock`.
+    fn to_toml(&self) -> PyResult<String> {
+        self.0.to_toml().map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Lockfile(schema_version={}, packages={})",
+            self.0.schema_version(),
+            self.0.packages().len()
+        )
+    }
+}
+
+/// Resolve a set of requirements, as in `uv pip compile`.
+#[pyfunction]
+#[pyo3(signature = (requirements, *, python=None, constraints=Vec::new(), overrides=Vec::new(), cache_dir=None, offline=false))]
+fn resolve(
+    py: Python<'_>,
+    requirements: Vec<String>,
+    python: Option<String>,
+    constraints: Vec<PathBuf>,
+    overrides: Vec<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    offline: bool,
+) -> PyResult<Resolution> {
+    let (globals, cache) = globals(offline, cache_dir)?;
+    let (_temp_dir, requirements) = requirements_file(&requirements)?;
+    let mut settings = PipCompileSettings::builder()
+        .requirements([requirements])
+        .constraints(constraints)
+        .overrides(overrides);
+    if let Some(python) = python {
+        settings = settings.python(python);
+    }
+    let settings = settings.build(None);
+
+    py.allow_threads(|| {
+        uv::api::block_on(|| async { uv::api::resolve(settings, &globals, &cache).await })
+    })
+    .map(Resolution)
+    .map_err(to_py_err)
+}
+
+/// Install a set of requirements into the environment at the given root, as in
+/// `uv pip install`.
+#[pyfunction]
+#[pyo3(signature = (environment, *, packages=Vec::new(), requirements=Vec::new(), editables=Vec::new(), constraints=Vec::new(), cache_dir=None, offline=false))]
+fn install(
+    py: Python<'_>,
+    environment: PathBuf,
+    packages: Vec<String>,
+    requirements: Vec<PathBuf>,
+    editables: Vec<String>,
+    constraints: Vec<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    offline: bool,
+) -> PyResult<()> {
+    let (globals, cache) = globals(offline, cache_dir)?;
+    let settings = PipInstallSettings::builder()
+        .packages(packages)
+        .requirements(requirements)
+        .editables(editables)
+        .constraints(constraints)
+        .build(None);
+
+    py.allow_threads(|| {
+        uv::api::block_on(|| async {
+            let environment = uv::api::Environment::from_root(&environment, &cache)?;
+            uv::api::install(settings, &environment, &globals, &cache).await
+        })
+    })
+    .map_err(to_py_err)
+}
+
+/// Lock the project in the given directory, as in `uv lock`.
+#[pyfunction]
+#[pyo3(signature = (project_dir, *, python=None, locked=false, cache_dir=None, offline=false))]
+fn lock(
+    py: Python<'_>,
+    project_dir: PathBuf,
+    python: Option<String>,
+    locked: bool,
+    cache_dir: Option<PathBuf>,
+    offline: bool,
+) -> PyResult<Lockfile> {
+    let (globals, cache) = globals(offline, cache_dir)?;
+    let mut settings = LockSettings::builder().locked(locked);
+    if let Some(python) = python {
+        settings = settings.python(python);
+    }
+    let settings = settings.build(None);
+
+    py.allow_threads(|| {
+        uv::api::block_on(|| async {
+            uv::api::lock(&project_dir, settings, &globals, &cache).await
+        })
+    })
+    .map(Lockfile)
+    .map_err(to_py_err)
+}
+
+/// Return the version of uv.
+#[pyfunction]
+fn version() -> &'static str {
+    uv::api::version()
+}
+
+#[pymodule]
+fn _native(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("UvError", m.py().get_type::<UvError>())?;
+    m.add_class::<Package>()?;
+    m.add_class::<Resolution>()?;
+    m.add_class::<Lockfile>()?;
+    m.add_function(wrap_pyfunction!(resolve, m)?)?;
+    m.add_function(wrap_pyfunction!(install, m)?)?;
+    m.add_function(wrap_pyfunction!(lock, m)?)?;
+    m.add_function(wrap_pyfunction!(version, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+"""Tests for the `uv_api` bindings; run with `pytest` after `maturin develop`."""
+
+from __future__ import annotations
+
+import asyncio
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+import uv_api
+
+
+def test_resolve() -> None:
+    resolution = uv_api.resolve(["iniconfig==2.0.0"])
+    assert [(package.name, package.version) for package in resolution.packages] == [
+        ("iniconfig", "2.0.0")
+    ]
+    assert "iniconfig" in resolution.to_pylock_toml()
+
+
+def test_resolve_async() -> None:
+    resolution = asyncio.run(uv_api.resolve_async(["iniconfig==2.0.0"]))
+    assert [package.name for package in resolution.packages] == ["iniconfig"]
+
+
+def test_install(tmp_path: Path) -> None:
+    venv = tmp_path / ".venv"
+    subprocess.run([sys.executable, "-m", "venv", "--without-pip", venv], check=True)
+    uv_api.install(venv, packages=["iniconfig==2.0.0"])
+    assert list(venv.glob("**/site-packages/iniconfig-2.0.0.dist-info"))
+
+
+def test_lock(tmp_path: Path) -> None:
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "project"\nversion = "0.1.0"\n'
+        'requires-python = ">=3.8"\ndependencies = ["iniconfig==2.0.0"]\n'
+    )
+    lockfile = asyncio.run(uv_api.lock_async(tmp_path))
+    assert sorted(package.name for package in lockfile.packages) == [
+        "iniconfig",
+        "project",
+    ]
+    assert uv_api.Lockfile.from_path(tmp_path / "uv.lock").to_toml() == lockfile.to_toml()
+
+    # Once the requirements change, `locked` lockfiles are rejected.
+    (tmp_path / "pyproject.toml").write_text(
+        '[project]\nname = "project"\nversion = "0.1.0"\n'
+        'requires-python = ">=3.8"\ndependencies = ["iniconfig<2"]\n'
+    )
+    with pytest.raises(uv_api.UvError):
+        uv_api.lock(tmp_path, locked=True)
@@ -28,7 +28,10 @@ use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
 use crate::managed::{ManagedPythonInstallations, PythonMinorVersionLink};
 #[cfg(windows)]
 use crate::microsoft_store::find_microsoft_store_pythons;
+#[cfg(windows)]
+use crate::py_launcher::py_launcher_pythons;
 use crate::python_version::python_build_versions_from_env;
+use crate::version_managers::VersionManager;
 use crate::virtualenv::Error as VirtualEnvError;
 use crate::virtualenv::{
     CondaEnvironmentKind, conda_environment_from_env, virtualenv_from_env,
@@ -88,7 +91,7 @@ impl serde::Serialize for PythonRequest {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -109,7 +112,7 @@ pub enum PythonPreference {
     OnlySystem,
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -222,6 +225,12 @@ pub enum PythonSource {
     Registry,
     /// An executable was found in the known Microsoft Store locations
     MicrosoftStore,
+    /// An executable was listed by the Python launcher for Windows, i.e. `py --list-paths`
+    PyLauncher,
+    /// The Python installation was found in the pyenv versions directory
+    Pyenv,
+    /// The Python installation was found in the asdf installs directory
+    Asdf,
     /// The Python installation was found in the uv managed Python directory
     Managed,
     /// The Python installation was found via the invoking interpreter i.e. via `python -m uv ...`
@@ -422,6 +431,39 @@ fn python_executables_from_installed<'a>(
     })
     .flatten();
 
+    let from_version_managers = iter::once_with(move || {
+        preference
+            .allows_version_managers()
+            .then(|| {
+                [
+                    (VersionManager::Pyenv, PythonSource::Pyenv),
+                    (VersionManager::Asdf, PythonSource::Asdf),
+                ]
+                .into_iter()
+                .flat_map(move |(manager, source)| {
+                    manager
+                        .executables()
+                        .into_iter()
+                        // Skip interpreter probing if we already know the version doesn't match.
+                        .filter(move |(executable, found)| {
+                            if found.as_ref().is_none_or(|found| version.matches_version(found)) {
+                                true
+                            } else {
+                                debug!(
+                                    "Skipping {manager} installation `{}`: does not satisfy `{version}`",
+                                    executable.user_display()
+                                );
+                                false
+                            }
+                        })
+                        .map(move |(executable, _)| Ok((source, executable)))
+                })
+            })
+            .into_iter()
+            .flatten()
+    })
+    .flatten();
+
     let from_windows_registry = iter::once_with(move || {
         #[cfg(windows)]
         {
@@ -453,6 +495,12 @@ fn python_executables_from_installed<'a>(
                                         .filter(version_filter)
                                         .map(|entry| (PythonSource::MicrosoftStore, entry.path)),
                                 )
+                                .chain(
+                                    iter::once_with(py_launcher_pythons)
+                                        .flatten()
+                                        .filter(version_filter)
+                                        .map(|entry| (PythonSource::PyLauncher, entry.path)),
+                                )
                         })
                         .map_err(Error::from)
                 })
@@ -477,17 +525,25 @@ fn python_executables_from_installed<'a>(
                 Box::new(from_managed_installations)
             }
         }
+        // Installations managed by pyenv or asdf are searched after the search path, such that
+        // their shims (which respect the version manager's own version selection) take precedence.
         PythonPreference::Managed => Box::new(
             from_managed_installations
                 .chain(from_search_path)
+                .chain(from_version_managers)
                 .chain(from_windows_registry),
         ),
         PythonPreference::System => Box::new(
             from_search_path
+                .chain(from_version_managers)
                 .chain(from_windows_registry)
                 .chain(from_managed_installations),
         ),
-        PythonPreference::OnlySystem => Box::new(from_search_path.chain(from_windows_registry)),
+        PythonPreference::OnlySystem => Box::new(
+            from_search_path
+                .chain(from_version_managers)
+                .chain(from_windows_registry),
+        ),
     }
 }
 
@@ -926,6 +982,9 @@ pub fn satisfies_python_preference(
         | PythonSource::SearchPathFirst
         | PythonSource::Registry
         | PythonSource::MicrosoftStore
+        | PythonSource::PyLauncher
+        | PythonSource::Pyenv
+        | PythonSource::Asdf
         | PythonSource::BaseCondaPrefix => false,
     };
 
@@ -987,6 +1046,9 @@ pub(crate) fn is_system_interpreter(source: PythonSource, interpreter: &Interpre
         | PythonSource::SearchPath
         | PythonSource::SearchPathFirst
         | PythonSource::Registry
+        | PythonSource::PyLauncher
+        | PythonSource::Pyenv
+        | PythonSource::Asdf
         | PythonSource::BaseCondaPrefix => !interpreter.is_managed(),
         // Managed interpreters should never be found in the store
         PythonSource::MicrosoftStore => true,
@@ -1745,7 +1807,8 @@ impl PythonRequest {
             return request;
         }
 
-        let value_as_path = PathBuf::from(value);
+        // e.g. ~/.venv, which may not be expanded by the shell (as in `--python=~/.venv`)
+        let value_as_path = expand_tilde(value);
         // e.g. /path/to/.venv
         if value_as_path.is_dir() {
             return Self::Directory(value_as_path);
@@ -2166,7 +2229,13 @@ impl PythonSource {
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
             | Self::SearchPathFirst => true,
-            Self::Managed | Self::SearchPath | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::SearchPath
+            | Self::Registry
+            | Self::MicrosoftStore
+            | Self::PyLauncher
+            | Self::Pyenv
+            | Self::Asdf => false,
         }
     }
 
@@ -2181,7 +2250,10 @@ impl PythonSource {
             | Self::SearchPath
             | Self::SearchPathFirst
             | Self::Registry
-            | Self::MicrosoftStore => true,
+            | Self::MicrosoftStore
+            | Self::PyLauncher
+            | Self::Pyenv
+            | Self::Asdf => true,
             Self::ActiveEnvironment | Self::DiscoveredEnvironment => false,
         }
     }
@@ -2192,21 +2264,34 @@ impl PythonPreference {
         // If not dealing with a system interpreter source, we don't care about the preference
         if !matches!(
             source,
-            PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+            PythonSource::Managed
+                | PythonSource::SearchPath
+                | PythonSource::Registry
+                | PythonSource::PyLauncher
+                | PythonSource::Pyenv
+                | PythonSource::Asdf
         ) {
             return true;
         }
 
         match self {
             Self::OnlyManaged => matches!(source, PythonSource::Managed),
-            Self::Managed | Self::System => matches!(
-                source,
-                PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
-            ),
-            Self::OnlySystem => {
-                matches!(source, PythonSource::SearchPath | PythonSource::Registry)
-            }
+            Self::Managed | Self::System => true,
+            Self::OnlySystem => !matches!(source, PythonSource::Managed),
+        }
+    }
+
+    /// Whether installations managed by third-party version managers (i.e., pyenv and asdf)
+    /// should be discovered.
+    ///
+    /// These installations are only discovered if system interpreters are allowed. Like the
+    /// search path, they're excluded during tests unless explicitly enabled.
+    pub(crate) fn allows_version_managers(self) -> bool {
+        if !self.allows(PythonSource::Pyenv) {
+            return false;
         }
+        env::var_os(EnvVars::UV_TEST_PYTHON_PATH).is_none()
+            || env::var_os(EnvVars::UV_INTERNAL__TEST_VERSION_MANAGERS).is_some()
     }
 
     pub(crate) fn allows_managed(self) -> bool {
@@ -2625,6 +2710,9 @@ impl VersionRequest {
                 | PythonSource::SearchPathFirst
                 | PythonSource::Registry
                 | PythonSource::MicrosoftStore
+                | PythonSource::PyLauncher
+                | PythonSource::Pyenv
+                | PythonSource::Asdf
                 | PythonSource::Managed => Self::Default,
             },
             _ => self,
@@ -3071,6 +3159,9 @@ impl fmt::Display for PythonSource {
             Self::SearchPathFirst => f.write_str("first executable in the search path"),
             Self::Registry => f.write_str("registry"),
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
+            Self::PyLauncher => f.write_str("Python launcher"),
+            Self::Pyenv => f.write_str("pyenv installations"),
+            Self::Asdf => f.write_str("asdf installations"),
             Self::Managed => f.write_str("managed installations"),
             Self::ParentInterpreter => f.write_str("parent interpreter"),
         }
@@ -3183,8 +3274,13 @@ impl fmt::Display for PythonNotFound {
             PythonRequest::File(_) => {
                 write!(f, "No interpreter found at {}", self.request)
             }
-            PythonRequest::Directory(_) => {
-                write!(f, "No interpreter found in {}", self.request)
+            PythonRequest::Directory(ref path) => {
+                write!(
+                    f,
+                    "No interpreter found in {} (expected an executable at `{}`)",
+                    self.request,
+                    virtualenv_python_executable(path).user_display()
+                )
             }
             _ => {
                 write!(f, "No interpreter found for {} in {sources}", self.request)
@@ -3193,6 +3289,21 @@ impl fmt::Display for PythonNotFound {
     }
 }
 
+/// Expand a leading `~` in a path to the user's home directory.
+///
+/// Shells don't expand `~` in all positions, e.g., in `--python=~/.venv` or in `UV_PYTHON`.
+fn expand_tilde(value: &str) -> PathBuf {
+    let rest = value
+        .strip_prefix("~/")
+        .or_else(|| value.strip_prefix(r"~\").filter(|_| cfg!(windows)));
+    if let Some(rest) = rest {
+        if let Ok(home) = etcetera::home_dir() {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(value)
+}
+
 /// Join a series of items with `or` separators, making use of commas when necessary.
 fn disjunction(items: &[&str]) -> String {
     match items.len() {
@@ -3501,6 +3612,11 @@ mod tests {
             PythonRequest::File(PathBuf::from_str("./foo").unwrap()),
             "A string with a file system separator is treated as a file"
         );
+        assert_eq!(
+            PythonRequest::parse("~/uv-missing-python"),
+            PythonRequest::File(etcetera::home_dir().unwrap().join("uv-missing-python")),
+            "A leading tilde is expanded to the home directory"
+        );
         assert_eq!(
             PythonRequest::parse("3.13t"),
             PythonRequest::Version(VersionRequest::from_str("3.13t").unwrap())
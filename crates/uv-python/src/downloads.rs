@@ -25,7 +25,7 @@ use url::Url;
 use uv_client::{BaseClient, WrappedReqwestError, is_transient_network_error};
 use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
-use uv_fs::{Simplified, rename_with_retry};
+use uv_fs::{LockedFile, Simplified, rename_with_retry};
 use uv_platform::{self as platform, Arch, Libc, Os, Platform};
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_redacted::DisplaySafeUrl;
@@ -1011,6 +1011,30 @@ impl ManagedPythonDownload {
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
+
+        // If the mirror manifest pins a checksum for this download, verify against it instead.
+        if let Some(sha256) = client
+            .mirror_manifest()
+            .and_then(|manifest| manifest.digests(&url).first())
+            .map(|digest| &*digest.digest)
+            .filter(|sha256| self.sha256.as_deref() != Some(*sha256))
+        {
+            let download = Self {
+                sha256: Some(Cow::Owned(sha256.to_string())),
+                ..self.clone()
+            };
+            return Box::pin(download.fetch(
+                client,
+                installation_dir,
+                scratch_dir,
+                reinstall,
+                python_install_mirror,
+                pypy_install_mirror,
+                reporter,
+            ))
+            .await;
+        }
+
         let path = installation_dir.join(self.key().to_string());
 
         // If it is not a reinstall and the dir already exists, return it.
@@ -1075,14 +1099,8 @@ impl ManagedPythonDownload {
                             });
                         }
 
-                        self.download_archive(
-                            &url,
-                            client,
-                            reporter,
-                            &python_builds_dir,
-                            &target_cache_file,
-                        )
-                        .await?;
+                        self.download_archive(&url, client, reporter, &target_cache_file)
+                            .await?;
 
                         debug!("Extracting `{}`", target_cache_file.simplified_display());
                         let file = fs_err::tokio::File::open(&target_cache_file).await?;
@@ -1094,16 +1112,27 @@ impl ManagedPythonDownload {
                 };
 
             // Extract the downloaded archive into a temporary directory.
-            self.extract_reader(
-                reader,
-                temp_dir.path(),
-                &filename,
-                ext,
-                size,
-                reporter,
-                Direction::Extract,
-            )
-            .await?;
+            let result = self
+                .extract_reader(
+                    reader,
+                    temp_dir.path(),
+                    &filename,
+                    ext,
+                    size,
+                    reporter,
+                    Direction::Extract,
+                )
+                .await;
+
+            // If the cached archive is corrupt, remove it, such that it's downloaded again.
+            if let Err(Error::HashMismatch { .. }) = &result {
+                debug!(
+                    "Removing corrupt archive `{}`",
+                    target_cache_file.simplified_display()
+                );
+                fs_err::tokio::remove_file(&target_cache_file).await?;
+            }
+            result?;
         } else {
             // Avoid overlong log lines
             debug!("Downloading {url}");
@@ -1171,6 +1200,13 @@ impl ManagedPythonDownload {
             }
         }
 
+        // Apply the quarantine mode to the extracted installation.
+        let quarantine = client.quarantine();
+        if !quarantine.is_preserve() {
+            debug!("Applying quarantine mode to Python installation: {quarantine:?}");
+            quarantine.apply_all(&extracted)?;
+        }
+
         // Remove the target if it already exists.
         if path.is_dir() {
             debug!("Removing existing directory: {}", path.user_display());
@@ -1190,31 +1226,104 @@ impl ManagedPythonDownload {
     }
 
     /// Download the managed Python archive into the cache directory.
+    ///
+    /// The archive is downloaded to a partial file alongside the target, which is retained if the
+    /// download is interrupted such that a subsequent attempt can resume it. The archive is only
+    /// moved into place once it's complete and matches the expected hash, such that a truncated
+    /// or corrupt download is never extracted from the cache.
     async fn download_archive(
         &self,
         url: &Url,
         client: &BaseClient,
         reporter: Option<&dyn Reporter>,
-        python_builds_dir: &Path,
         target_cache_file: &Path,
     ) -> Result<(), Error> {
-        debug!(
-            "Downloading {} to `{}`",
-            url,
-            target_cache_file.simplified_display()
-        );
+        let sibling = |suffix: &str| {
+            let mut path = target_cache_file.as_os_str().to_owned();
+            path.push(suffix);
+            PathBuf::from(path)
+        };
+        let partial_file = sibling(".part");
 
-        let (mut reader, size) = read_url(url, client).await?;
-        let temp_dir = tempfile::tempdir_in(python_builds_dir)?;
-        let temp_file = temp_dir.path().join("download");
+        // Avoid concurrent writes to the partial file.
+        let _lock =
+            LockedFile::acquire(sibling(".lock"), target_cache_file.simplified_display()).await?;
 
-        // Download to a temporary file. We verify the hash when unpacking the file.
-        {
-            let mut archive_writer = BufWriter::new(fs_err::tokio::File::create(&temp_file).await?);
+        // Another process may have completed the download while we were waiting for the lock.
+        if target_cache_file.is_file() {
+            return Ok(());
+        }
+
+        let mut offset = match fs_err::tokio::metadata(&partial_file).await {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err.into()),
+        };
+
+        loop {
+            if offset > 0 {
+                debug!(
+                    "Resuming download of {} to `{}` at byte {offset}",
+                    url,
+                    partial_file.simplified_display()
+                );
+            } else {
+                debug!(
+                    "Downloading {} to `{}`",
+                    url,
+                    partial_file.simplified_display()
+                );
+            }
+
+            let (mut reader, size, file) = match read_url_from(url, client, offset).await? {
+                RangeResponse::Full { reader, size } => {
+                    if offset > 0 {
+                        debug!("Server does not support resuming downloads; restarting download");
+                        offset = 0;
+                    }
+                    let file = fs_err::tokio::File::create(&partial_file).await?;
+                    (reader, size, file)
+                }
+                RangeResponse::Partial { reader, size } => {
+                    let file = fs_err::tokio::OpenOptions::new()
+                        .append(true)
+                        .open(&partial_file)
+                        .await?;
+                    (reader, size, file)
+                }
+                RangeResponse::Unsatisfiable { size } => {
+                    // The partial file extends to (or beyond) the end of the archive, e.g., if a
+                    // previous attempt was interrupted after the download completed. Unless it's
+                    // larger than the archive, treat it as complete, and rely on the hash check.
+                    if size.is_some_and(|size| size != offset) {
+                        debug!(
+                            "Partial download at `{}` does not match the size of the archive; restarting download",
+                            partial_file.simplified_display()
+                        );
+                        offset = 0;
+                        continue;
+                    }
+                    debug!(
+                        "Partial download at `{}` is already complete",
+                        partial_file.simplified_display()
+                    );
+                    break;
+                }
+                RangeResponse::Mismatch => {
+                    debug!("Server returned an unexpected range; restarting download");
+                    offset = 0;
+                    continue;
+                }
+            };
+            let mut archive_writer = BufWriter::new(file);
 
             // Download with or without progress bar.
             if let Some(reporter) = reporter {
                 let key = reporter.on_request_start(Direction::Download, &self.key, size);
+                // Account for the bytes downloaded by a previous attempt.
+                if offset > 0 {
+                    reporter.on_request_progress(key, offset);
+                }
                 tokio::io::copy(
                     &mut ProgressReader::new(reader, key, reporter),
                     &mut archive_writer,
@@ -1226,13 +1335,44 @@ impl ManagedPythonDownload {
             }
 
             archive_writer.flush().await?;
+            break;
         }
-        // Move the completed file into place, invalidating the `File` instance.
-        match rename_with_retry(&temp_file, target_cache_file).await {
-            Ok(()) => {}
-            Err(_) if target_cache_file.is_file() => {}
-            Err(err) => return Err(err.into()),
+
+        // Verify the completed archive before moving it into the cache. On failure, discard it,
+        // since it can't be resumed.
+        if let Err(err) = self.verify_archive(&partial_file).await {
+            fs_err::tokio::remove_file(&partial_file).await?;
+            return Err(err);
+        }
+
+        // Move the completed file into place.
+        rename_with_retry(&partial_file, target_cache_file).await?;
+        Ok(())
+    }
+
+    /// Verify that a downloaded archive matches the expected hash, if any.
+    async fn verify_archive(&self, archive: &Path) -> Result<(), Error> {
+        let Some(expected) = self.sha256.as_deref() else {
+            return Ok(());
+        };
+
+        let file = fs_err::tokio::File::open(archive).await?;
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader =
+            uv_extract::hash::HashReader::new(tokio::io::BufReader::new(file), &mut hashers);
+        tokio::io::copy(&mut reader, &mut tokio::io::sink())
+            .await
+            .map_err(Error::HashExhaustion)?;
+
+        let actual = HashDigest::from(hashers.pop().unwrap()).digest;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::HashMismatch {
+                installation: self.key.to_string(),
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
         }
+
         Ok(())
     }
 
@@ -1555,6 +1695,55 @@ async fn read_url(
     url: &Url,
     client: &BaseClient,
 ) -> Result<(impl AsyncRead + Unpin, Option<u64>), Error> {
+    match read_url_from(url, client, 0).await? {
+        RangeResponse::Full { reader, size } | RangeResponse::Partial { reader, size } => {
+            Ok((reader, size))
+        }
+        RangeResponse::Unsatisfiable { .. } | RangeResponse::Mismatch => {
+            unreachable!("a range is only requested for a non-zero offset")
+        }
+    }
+}
+
+/// The response to a request for a resource starting at a byte offset.
+enum RangeResponse<R> {
+    /// The server returned the entire resource, ignoring the offset.
+    Full { reader: R, size: Option<u64> },
+    /// The server returned the resource starting at the offset. The size is the size of the
+    /// entire resource, including the bytes prior to the offset.
+    Partial { reader: R, size: Option<u64> },
+    /// The server rejected the offset as beyond the end of the resource (HTTP 416), along with
+    /// the size of the resource, if reported.
+    Unsatisfiable { size: Option<u64> },
+    /// The server returned a range that doesn't start at the offset.
+    Mismatch,
+}
+
+/// Parse a `Content-Range` header (e.g., `bytes 100-199/200` or `bytes */200`) into the first
+/// byte position, if any, and the size of the resource, if known.
+fn parse_content_range(value: &str) -> Option<(Option<u64>, Option<u64>)> {
+    let (range, size) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let start = if range == "*" {
+        None
+    } else {
+        Some(range.split_once('-')?.0.parse().ok()?)
+    };
+    let size = if size == "*" {
+        None
+    } else {
+        Some(size.parse().ok()?)
+    };
+    Some((start, size))
+}
+
+/// Convert a [`Url`] into an [`AsyncRead`] stream, starting at the given byte offset.
+///
+/// If the server doesn't honor the offset, the stream starts at the beginning of the resource.
+async fn read_url_from(
+    url: &Url,
+    client: &BaseClient,
+    offset: u64,
+) -> Result<RangeResponse<impl AsyncRead + Unpin>, Error> {
     let url = DisplaySafeUrl::from(url.clone());
     if url.scheme() == "file" {
         // Loads downloaded distribution from the given `file://` URL.
@@ -1565,11 +1754,16 @@ async fn read_url(
         let size = fs_err::tokio::metadata(&path).await?.len();
         let reader = fs_err::tokio::File::open(&path).await?;
 
-        Ok((Either::Left(reader), Some(size)))
+        Ok(RangeResponse::Full {
+            reader: Either::Left(reader),
+            size: Some(size),
+        })
     } else {
-        let response = client
-            .for_host(&url)
-            .get(Url::from(url.clone()))
+        let mut request = client.for_host(&url).get(Url::from(url.clone()));
+        if offset > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={offset}-"));
+        }
+        let response = request
             .send()
             .await
             .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
@@ -1579,18 +1773,47 @@ async fn read_url(
             .get::<reqwest_retry::RetryCount>()
             .map(|retries| retries.value());
 
+        let content_range = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_content_range);
+
+        if offset > 0 && response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            return Ok(RangeResponse::Unsatisfiable {
+                size: content_range.and_then(|(_, size)| size),
+            });
+        }
+
         // Check the status code.
         let response = response
             .error_for_status()
             .map_err(|err| Error::from_reqwest(url, err, retry_count))?;
 
-        let size = response.content_length();
-        let stream = response
-            .bytes_stream()
+        let resumed = offset > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+        if resumed && content_range.is_some_and(|(start, _)| start != Some(offset)) {
+            return Ok(RangeResponse::Mismatch);
+        }
+
+        let content_length = response.content_length();
+        let stream = client
+            .throttle(response.bytes_stream())
             .map_err(io::Error::other)
             .into_async_read();
-
-        Ok((Either::Right(stream.compat()), size))
+        let reader = Either::Right(stream.compat());
+
+        if resumed {
+            // Report the size of the entire archive, rather than the remaining bytes.
+            let size = content_range
+                .and_then(|(_, size)| size)
+                .or_else(|| content_length.map(|length| offset + length));
+            Ok(RangeResponse::Partial { reader, size })
+        } else {
+            Ok(RangeResponse::Full {
+                reader,
+                size: content_length,
+            })
+        }
     }
 }
 
@@ -1899,4 +2122,237 @@ mod tests {
             "cpython-3.12.0-linux-x86_64-gnu"
         );
     }
+
+    /// The contents of the archive served in the download tests.
+    const ARCHIVE: &[u8] = b"0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789";
+
+    /// The SHA-256 of [`ARCHIVE`].
+    const ARCHIVE_SHA256: &str = "9cfe7faff7054298ca87557e15a10262de8d3eee77827417fbdfea1c41b9ec23";
+
+    /// A [`Reporter`] that records the reported size and progress of a download.
+    #[derive(Default)]
+    struct RecordingReporter {
+        size: std::sync::Mutex<Option<u64>>,
+        progress: std::sync::atomic::AtomicU64,
+    }
+
+    impl Reporter for RecordingReporter {
+        fn on_request_start(
+            &self,
+            _direction: Direction,
+            _name: &PythonInstallationKey,
+            size: Option<u64>,
+        ) -> usize {
+            *self.size.lock().unwrap() = size;
+            0
+        }
+
+        fn on_request_progress(&self, _id: usize, inc: u64) {
+            self.progress
+                .fetch_add(inc, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        fn on_request_complete(&self, _direction: Direction, _id: usize) {}
+    }
+
+    /// Download [`ARCHIVE`] from the given server to `python.tar.gz` in the given directory, after
+    /// seeding the partial download with the given contents.
+    async fn resume_download(
+        server: &wiremock::MockServer,
+        dir: &Path,
+        partial: &[u8],
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<PathBuf, Error> {
+        let download = ManagedPythonDownload {
+            key: PythonInstallationKey::new(
+                LenientImplementationName::Known(
+                    crate::implementation::ImplementationName::CPython,
+                ),
+                3,
+                12,
+                0,
+                None,
+                Platform::new(
+                    Os::from_str("linux").unwrap(),
+                    Arch::from_str("x86_64").unwrap(),
+                    Libc::from_str("gnu").unwrap(),
+                ),
+                crate::PythonVariant::default(),
+            ),
+            url: Cow::Owned(format!("{}/python.tar.gz", server.uri())),
+            sha256: Some(Cow::Borrowed(ARCHIVE_SHA256)),
+            build: None,
+        };
+        let target = dir.join("python.tar.gz");
+        fs_err::write(dir.join("python.tar.gz.part"), partial)?;
+
+        let client = uv_client::BaseClientBuilder::default().build();
+        let url = Url::parse(&download.url).unwrap();
+        download
+            .download_archive(&url, &client, reporter, &target)
+            .await?;
+        Ok(target)
+    }
+
+    /// Mount a response to requests for the entire archive.
+    async fn mount_full(server: &wiremock::MockServer) {
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path("/python.tar.gz"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_bytes(ARCHIVE))
+            .mount(server)
+            .await;
+    }
+
+    /// Mount a response to requests for the archive starting at the given offset, which takes
+    /// precedence over [`mount_full`].
+    async fn mount_range(
+        server: &wiremock::MockServer,
+        offset: usize,
+        response: wiremock::ResponseTemplate,
+    ) {
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path("/python.tar.gz"))
+            .and(wiremock::matchers::header(
+                "range",
+                format!("bytes={offset}-").as_str(),
+            ))
+            .respond_with(response)
+            .with_priority(1)
+            .mount(server)
+            .await;
+    }
+
+    /// Resume an interrupted download, reporting progress against the size of the entire archive.
+    #[tokio::test]
+    async fn download_archive_resume() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_range(
+            &server,
+            40,
+            wiremock::ResponseTemplate::new(206)
+                .insert_header("content-range", "bytes 40-99/100")
+                .set_body_bytes(&ARCHIVE[40..]),
+        )
+        .await;
+
+        let dir = tempfile::tempdir()?;
+        let reporter = RecordingReporter::default();
+        let target = resume_download(&server, dir.path(), &ARCHIVE[..40], Some(&reporter)).await?;
+
+        assert_eq!(fs_err::read(&target)?, ARCHIVE);
+        assert!(!dir.path().join("python.tar.gz.part").exists());
+        assert_eq!(*reporter.size.lock().unwrap(), Some(100));
+        assert_eq!(
+            reporter.progress.load(std::sync::atomic::Ordering::Relaxed),
+            100
+        );
+        Ok(())
+    }
+
+    /// If the server ignores the range, restart the download from the beginning.
+    #[tokio::test]
+    async fn download_archive_resume_unsupported() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_full(&server).await;
+
+        let dir = tempfile::tempdir()?;
+        let target = resume_download(&server, dir.path(), b"garbage", None).await?;
+
+        assert_eq!(fs_err::read(&target)?, ARCHIVE);
+        Ok(())
+    }
+
+    /// If the server returns a different range than requested, restart the download from the
+    /// beginning.
+    #[tokio::test]
+    async fn download_archive_resume_mismatch() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_full(&server).await;
+        mount_range(
+            &server,
+            40,
+            wiremock::ResponseTemplate::new(206)
+                .insert_header("content-range", "bytes 50-99/100")
+                .set_body_bytes(&ARCHIVE[50..]),
+        )
+        .await;
+
+        let dir = tempfile::tempdir()?;
+        let target = resume_download(&server, dir.path(), &ARCHIVE[..40], None).await?;
+
+        assert_eq!(fs_err::read(&target)?, ARCHIVE);
+        Ok(())
+    }
+
+    /// If the partial download is already complete, the server rejects the range; the partial
+    /// download is used as-is.
+    #[tokio::test]
+    async fn download_archive_resume_complete() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_range(
+            &server,
+            100,
+            wiremock::ResponseTemplate::new(416).insert_header("content-range", "bytes */100"),
+        )
+        .await;
+
+        let dir = tempfile::tempdir()?;
+        let target = resume_download(&server, dir.path(), ARCHIVE, None).await?;
+
+        assert_eq!(fs_err::read(&target)?, ARCHIVE);
+        Ok(())
+    }
+
+    /// If the partial download is larger than the archive, restart the download from the
+    /// beginning.
+    #[tokio::test]
+    async fn download_archive_resume_oversized() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_full(&server).await;
+        mount_range(
+            &server,
+            110,
+            wiremock::ResponseTemplate::new(416).insert_header("content-range", "bytes */100"),
+        )
+        .await;
+
+        let dir = tempfile::tempdir()?;
+        let partial = [ARCHIVE, &b"0123456789"[..]].concat();
+        let target = resume_download(&server, dir.path(), &partial, None).await?;
+
+        assert_eq!(fs_err::read(&target)?, ARCHIVE);
+        Ok(())
+    }
+
+    /// A complete partial download that doesn't match the expected hash is discarded.
+    #[tokio::test]
+    async fn download_archive_resume_complete_corrupt() -> anyhow::Result<()> {
+        let server = wiremock::MockServer::start().await;
+        mount_range(
+            &server,
+            100,
+            wiremock::ResponseTemplate::new(416).insert_header("content-range", "bytes */100"),
+        )
+        .await;
+
+        let dir = tempfile::tempdir()?;
+        let partial = vec![b'x'; 100];
+        let result = resume_download(&server, dir.path(), &partial, None).await;
+
+        assert!(matches!(result, Err(Error::HashMismatch { .. })));
+        assert!(!dir.path().join("python.tar.gz").exists());
+        assert!(!dir.path().join("python.tar.gz.part").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(
+            parse_content_range("bytes 40-99/100"),
+            Some((Some(40), Some(100)))
+        );
+        assert_eq!(parse_content_range("bytes 40-99/*"), Some((Some(40), None)));
+        assert_eq!(parse_content_range("bytes */100"), Some((None, Some(100))));
+        assert_eq!(parse_content_range("items 40-99/100"), None);
+    }
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use owo_colors::OwoColorize;
 use tracing::debug;
@@ -312,8 +313,10 @@ impl PythonEnvironment {
     }
 
     /// Grab a file lock for the environment to prevent concurrent writes across processes.
-    pub async fn lock(&self) -> Result<LockedFile, std::io::Error> {
-        self.0.interpreter.lock().await
+    ///
+    /// Gives up if the lock isn't released within the given timeout, if any.
+    pub async fn lock(&self, timeout: Option<Duration>) -> Result<LockedFile, std::io::Error> {
+        self.0.interpreter.lock(timeout).await
     }
 
     /// Return the [`Interpreter`] for this environment.
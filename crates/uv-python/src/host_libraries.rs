@@ -0,0 +1,238 @@
+//! Detection of shared libraries that a Python installation requires but that are missing from
+//! the host.
+//!
+//! The managed Python distributions are largely self-contained, but some extension modules (e.g.,
+//! `_tkinter`) link against system libraries that may not be installed, in which case the module
+//! fails to import at runtime.
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// The extension modules that are known to link against system libraries.
+const MODULES: &[&str] = &[
+    "_ssl", "_hashlib", "_tkinter", "_sqlite3", "_ctypes", "_curses", "readline",
+];
+
+/// A script that imports each module, printing the modules that fail to import alongside the
+/// error.
+const SCRIPT: &str = r#"
+import importlib, sys
+for module in sys.argv[1:]:
+    try:
+        importlib.import_module(module)
+    except ImportError as err:
+        print(module, str(err).replace("\n", " "), sep="\t")
+"#;
+
+/// A shared library that is required by an extension module but missing from the host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingLibrary {
+    /// The extension module that failed to import, e.g., `_tkinter`.
+    pub module: String,
+    /// The file name of the missing library, e.g., `libX11.so.6`.
+    pub library: String,
+}
+
+impl Display for MissingLibrary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` (required by `{}`)", self.library, self.module)
+    }
+}
+
+/// Return the shared libraries that are required by the extension modules of the given Python
+/// executable but missing from the host.
+///
+/// Modules that fail to import for any other reason (e.g., because the implementation doesn't
+/// provide them) are ignored. On platforms other than Linux, no libraries are reported.
+pub fn find_missing(executable: &Path) -> io::Result<Vec<MissingLibrary>> {
+    static LIBRARY: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(lib[\w+.-]+?\.so(?:\.[\d.]+)?):").unwrap());
+
+    if !cfg!(target_os = "linux") {
+        return Ok(Vec::new());
+    }
+
+    let output = Command::new(executable)
+        .arg("-I")
+        .arg("-c")
+        .arg(SCRIPT)
+        .args(MODULES)
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "Failed to query extension modules: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (module, error) = line.split_once('\t')?;
+            let library = LIBRARY.captures(error)?.get(1)?.as_str();
+            Some(MissingLibrary {
+                module: module.to_string(),
+                library: library.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// A Linux package manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PackageManager {
+    Apt,
+    Dnf,
+    Pacman,
+    Apk,
+    Zypper,
+}
+
+impl PackageManager {
+    /// Detect the package manager of the host from the `ID` and `ID_LIKE` fields of
+    /// `/etc/os-release`.
+    fn from_os_release(contents: &str) -> Option<Self> {
+        contents
+            .lines()
+            .filter_map(|line| {
+                line.strip_prefix("ID=")
+                    .or_else(|| line.strip_prefix("ID_LIKE="))
+            })
+            .flat_map(|value| value.trim_matches('"').split_whitespace())
+            .find_map(|id| match id {
+                "debian" | "ubuntu" => Some(Self::Apt),
+                "fedora" | "rhel" | "centos" => Some(Self::Dnf),
+                "arch" => Some(Self::Pacman),
+                "alpine" => Some(Self::Apk),
+                "suse" | "opensuse" => Some(Self::Zypper),
+                _ => None,
+            })
+    }
+
+    /// Return the command used to install packages.
+    fn install_command(self) -> &'static str {
+        match self {
+            Self::Apt => "apt-get install",
+            Self::Dnf => "dnf install",
+            Self::Pacman => "pacman -S",
+            Self::Apk => "apk add",
+            Self::Zypper => "zypper install",
+        }
+    }
+
+    /// Return the package that provides the given library, if known.
+    fn package(self, library: &str) -> Option<&'static str> {
+        let stem = library.split(".so").next()?;
+        let packages = match stem {
+            "libssl" | "libcrypto" => [
+                "libssl3",
+                "openssl-libs",
+                "openssl",
+                "libssl3",
+                "libopenssl3",
+            ],
+            "libX11" => ["libx11-6", "libX11", "libx11", "libx11", "libX11-6"],
+            "libXext" => ["libxext6", "libXext", "libxext", "libxext", "libXext6"],
+            "libXft" => ["libxft2", "libXft", "libxft", "libxft", "libXft2"],
+            "libXss" => [
+                "libxss1",
+                "libXScrnSaver",
+                "libxss",
+                "libxscrnsaver",
+                "libXss1",
+            ],
+            "libtk8.6" => ["libtk8.6", "tk", "tk", "tk", "tk"],
+            "libtcl8.6" => ["libtcl8.6", "tcl", "tcl", "tcl", "tcl"],
+            "libsqlite3" => [
+                "libsqlite3-0",
+                "sqlite-libs",
+                "sqlite",
+                "sqlite-libs",
+                "libsqlite3-0",
+            ],
+            "libffi" => ["libffi8", "libffi", "libffi", "libffi", "libffi8"],
+            "libncursesw" | "libtinfo" => [
+                "libncursesw6",
+                "ncurses-libs",
+                "ncurses",
+                "ncurses-libs",
+                "libncurses6",
+            ],
+            _ => return None,
+        };
+        Some(match self {
+            Self::Apt => packages[0],
+            Self::Dnf => packages[1],
+            Self::Pacman => packages[2],
+            Self::Apk => packages[3],
+            Self::Zypper => packages[4],
+        })
+    }
+}
+
+/// Return a hint for installing the given missing libraries with the package manager of the host,
+/// if it can be determined.
+pub fn install_hint(missing: &[MissingLibrary]) -> Option<String> {
+    let contents = fs_err::read_to_string("/etc/os-release").ok()?;
+    install_hint_for(&contents, missing)
+}
+
+fn install_hint_for(os_release: &str, missing: &[MissingLibrary]) -> Option<String> {
+    let manager = PackageManager::from_os_release(os_release)?;
+    let mut packages = missing
+        .iter()
+        .filter_map(|missing| manager.package(&missing.library))
+        .collect::<Vec<_>>();
+    packages.sort_unstable();
+    packages.dedup();
+    if packages.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{} {}",
+        manager.install_command(),
+        packages.join(" ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MissingLibrary, install_hint_for};
+
+    #[test]
+    fn hint() {
+        let missing = [
+            MissingLibrary {
+                module: "_tkinter".to_string(),
+                library: "libX11.so.6".to_string(),
+            },
+            MissingLibrary {
+                module: "_ssl".to_string(),
+                library: "libssl.so.3".to_string(),
+            },
+            MissingLibrary {
+                module: "_hashlib".to_string(),
+                library: "libcrypto.so.3".to_string(),
+            },
+        ];
+
+        let ubuntu = "NAME=\"Ubuntu\"\nID=ubuntu\nID_LIKE=debian\n";
+        assert_eq!(
+            install_hint_for(ubuntu, &missing).as_deref(),
+            Some("apt-get install libssl3 libx11-6")
+        );
+
+        let rocky = "ID=\"rocky\"\nID_LIKE=\"rhel centos fedora\"\n";
+        assert_eq!(
+            install_hint_for(rocky, &missing).as_deref(),
+            Some("dnf install libX11 openssl-libs")
+        );
+
+        let unknown = "ID=nixos\n";
+        assert_eq!(install_hint_for(unknown, &missing), None);
+    }
+}
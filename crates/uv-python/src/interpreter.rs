@@ -4,6 +4,7 @@ use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::sync::OnceLock;
+use std::time::Duration;
 use std::{env, io};
 
 use configparser::ini::Ini;
@@ -24,6 +25,7 @@ use uv_pep508::{MarkerEnvironment, StringVersion};
 use uv_platform::{Arch, Libc, Os};
 use uv_platform_tags::{Platform, Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
+use uv_static::EnvVars;
 
 use crate::implementation::LenientImplementationName;
 use crate::managed::ManagedPythonInstallations;
@@ -280,9 +282,7 @@ impl Interpreter {
     ///
     /// Returns `false` if we cannot determine the path of the uv managed Python interpreters.
     pub fn is_managed(&self) -> bool {
-        if let Ok(test_managed) =
-            std::env::var(uv_static::EnvVars::UV_INTERNAL__TEST_PYTHON_MANAGED)
-        {
+        if let Ok(test_managed) = std::env::var(EnvVars::UV_INTERNAL__TEST_PYTHON_MANAGED) {
             // During testing, we collect interpreters into an artificial search path and need to
             // be able to mock whether an interpreter is managed or not.
             return test_managed.split_ascii_whitespace().any(|item| {
@@ -319,32 +319,30 @@ impl Interpreter {
             return None;
         }
 
-        let Ok(contents) = fs::read_to_string(self.stdlib.join("EXTERNALLY-MANAGED")) else {
-            return None;
-        };
-
-        let mut ini = Ini::new_cs();
-        ini.set_multiline(true);
-
-        let Ok(mut sections) = ini.read(contents) else {
-            // If a file exists but is not a valid INI file, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        let Some(section) = sections.get_mut("externally-managed") else {
-            // If the file exists but does not contain an "externally-managed" section, we assume
-            // the environment is externally managed.
-            return Some(ExternallyManaged::default());
+        let path = self.stdlib.join("EXTERNALLY-MANAGED");
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                // Per the spec, the presence of the file is what marks the environment as
+                // externally managed, even if it can't be read.
+                warn!(
+                    "Failed to read `EXTERNALLY-MANAGED` file at `{}`: {err}",
+                    path.user_display()
+                );
+                return Some(ExternallyManaged { path, error: None });
+            }
         };
 
-        let Some(error) = section.remove("Error") else {
-            // If the file exists but does not contain an "Error" key, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
+        let locale = [EnvVars::LC_ALL, EnvVars::LC_MESSAGES, EnvVars::LANG]
+            .into_iter()
+            .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()));
 
-        Some(ExternallyManaged { error })
+        Some(ExternallyManaged::from_contents(
+            path,
+            &contents,
+            locale.as_deref(),
+        ))
     }
 
     /// Returns the `python_full_version` marker corresponding to this Python version.
@@ -653,25 +651,39 @@ impl Interpreter {
     }
 
     /// Grab a file lock for the environment to prevent concurrent writes across processes.
-    pub async fn lock(&self) -> Result<LockedFile, io::Error> {
+    ///
+    /// Gives up if the lock isn't released within the given timeout, if any.
+    pub async fn lock(&self, timeout: Option<Duration>) -> Result<LockedFile, io::Error> {
         if let Some(target) = self.target() {
             // If we're installing into a `--target`, use a target-specific lockfile.
-            LockedFile::acquire(target.root().join(".lock"), target.root().user_display()).await
+            LockedFile::acquire_with_timeout(
+                target.root().join(".lock"),
+                target.root().user_display(),
+                timeout,
+            )
+            .await
         } else if let Some(prefix) = self.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
-            LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+            LockedFile::acquire_with_timeout(
+                prefix.root().join(".lock"),
+                prefix.root().user_display(),
+                timeout,
+            )
+            .await
         } else if self.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
-            LockedFile::acquire(
+            LockedFile::acquire_with_timeout(
                 self.sys_prefix.join(".lock"),
                 self.sys_prefix.user_display(),
+                timeout,
             )
             .await
         } else {
             // Otherwise, use a global lockfile.
-            LockedFile::acquire(
+            LockedFile::acquire_with_timeout(
                 env::temp_dir().join(format!("uv-{}.lock", cache_digest(&self.sys_executable))),
                 self.sys_prefix.user_display(),
+                timeout,
             )
             .await
         }
@@ -704,12 +716,65 @@ pub fn canonicalize_executable(path: impl AsRef<Path>) -> std::io::Result<PathBu
 /// The `EXTERNALLY-MANAGED` file in a Python installation.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/externally-managed-environments/>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct ExternallyManaged {
+    /// The path to the `EXTERNALLY-MANAGED` file.
+    path: PathBuf,
+    /// The error message from the `EXTERNALLY-MANAGED` file, if any.
     error: Option<String>,
 }
 
 impl ExternallyManaged {
+    /// Parse the contents of an `EXTERNALLY-MANAGED` file, preferring the error message for the
+    /// given locale (e.g., `de_DE.UTF-8`), if present.
+    fn from_contents(path: PathBuf, contents: &str, locale: Option<&str>) -> Self {
+        let mut ini = Ini::new_cs();
+        ini.set_multiline(true);
+
+        let Ok(mut sections) = ini.read(contents.to_string()) else {
+            // If a file exists but is not a valid INI file, we assume the environment is
+            // externally managed.
+            return Self { path, error: None };
+        };
+
+        let Some(section) = sections.get_mut("externally-managed") else {
+            // If the file exists but does not contain an "externally-managed" section, we assume
+            // the environment is externally managed.
+            return Self { path, error: None };
+        };
+
+        // Per the spec, look for an `Error-<locale>` key, first with the full locale (e.g.,
+        // `Error-en_GB`), then with the language alone (e.g., `Error-en`), before falling back to
+        // the `Error` key. Any encoding or modifier (e.g., `.UTF-8`) is ignored.
+        let mut keys = Vec::with_capacity(3);
+        if let Some(locale) = locale
+            .map(|locale| locale.split(['.', '@']).next().unwrap_or(locale))
+            .filter(|locale| !matches!(*locale, "" | "C" | "POSIX"))
+        {
+            keys.push(format!("Error-{locale}"));
+            if let Some((language, _)) = locale.split_once('_') {
+                keys.push(format!("Error-{language}"));
+            }
+        }
+        keys.push("Error".to_string());
+
+        let error = keys
+            .iter()
+            .find_map(|key| section.remove(key.as_str()).flatten());
+
+        Self { path, error }
+    }
+
+    /// Return the path to the `EXTERNALLY-MANAGED` file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the `EXTERNALLY-MANAGED` error message, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     /// Return the `EXTERNALLY-MANAGED` error message, if any.
     pub fn into_error(self) -> Option<String> {
         self.error
@@ -1248,6 +1313,7 @@ fn find_base_python(
 #[cfg(unix)]
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
     use std::str::FromStr;
 
     use fs_err as fs;
@@ -1258,6 +1324,7 @@ mod tests {
     use uv_pep440::Version;
 
     use crate::Interpreter;
+    use crate::interpreter::ExternallyManaged;
 
     #[test]
     fn test_cache_invalidation() {
@@ -1356,4 +1423,34 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn test_externally_managed_locale() {
+        let contents = indoc! {"
+            [externally-managed]
+            Error=To install Python packages system-wide, try apt install python3-xyz.
+            Error-de=Um Python-Pakete systemweit zu installieren, verwenden Sie apt install python3-xyz.
+            Error-pt_BR=Para instalar pacotes Python em todo o sistema, use apt install python3-xyz.
+        "};
+        let path = PathBuf::from("/usr/lib/python3.12/EXTERNALLY-MANAGED");
+
+        let error = |locale| {
+            ExternallyManaged::from_contents(path.clone(), contents, locale)
+                .into_error()
+                .unwrap()
+        };
+
+        assert!(error(None).starts_with("To install"));
+        assert!(error(Some("C.UTF-8")).starts_with("To install"));
+        assert!(error(Some("fr_FR.UTF-8")).starts_with("To install"));
+        assert!(error(Some("de_AT.UTF-8")).starts_with("Um Python-Pakete"));
+        assert!(error(Some("pt_BR")).starts_with("Para instalar"));
+        assert!(error(Some("pt_PT")).starts_with("To install"));
+
+        // A file without an `Error` key still marks the environment as externally managed.
+        let externally_managed =
+            ExternallyManaged::from_contents(path.clone(), "[externally-managed]\n", None);
+        assert_eq!(externally_managed.path(), path);
+        assert!(externally_managed.into_error().is_none());
+    }
 }
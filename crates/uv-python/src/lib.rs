@@ -17,7 +17,8 @@ pub use crate::installation::{
     PythonInstallation, PythonInstallationKey, PythonInstallationMinorVersionKey,
 };
 pub use crate::interpreter::{
-    BrokenSymlink, Error as InterpreterError, Interpreter, canonicalize_executable,
+    BrokenSymlink, Error as InterpreterError, ExternallyManaged, Interpreter,
+    canonicalize_executable,
 };
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
@@ -32,6 +33,7 @@ pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, Virtu
 mod discovery;
 pub mod downloads;
 mod environment;
+pub mod host_libraries;
 mod implementation;
 mod installation;
 mod interpreter;
@@ -41,10 +43,13 @@ pub mod managed;
 mod microsoft_store;
 mod pointer_size;
 mod prefix;
+#[cfg(windows)]
+mod py_launcher;
 mod python_version;
 mod sysconfig;
 mod target;
 mod version_files;
+mod version_managers;
 mod virtualenv;
 #[cfg(windows)]
 pub mod windows_registry;
@@ -107,6 +107,11 @@ pub enum Error {
     LibcDetection(#[from] LibcDetectionError),
     #[error(transparent)]
     MacOsDylib(#[from] macos_dylib::Error),
+    #[error("Failed to install pip with `ensurepip` ({status}):\n{stderr}")]
+    Ensurepip {
+        status: std::process::ExitStatus,
+        stderr: String,
+    },
 }
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -636,6 +641,23 @@ impl ManagedPythonInstallation {
         Ok(())
     }
 
+    /// Ensure that pip is installed, by running the standard library's `ensurepip` module.
+    pub fn ensure_pip(&self) -> Result<(), Error> {
+        let output = std::process::Command::new(self.executable(false))
+            .arg("-m")
+            .arg("ensurepip")
+            // The installation is marked as externally managed, which pip otherwise respects.
+            .env(EnvVars::PIP_BREAK_SYSTEM_PACKAGES, "1")
+            .output()?;
+        if !output.status.success() {
+            return Err(Error::Ensurepip {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(())
+    }
+
     /// Ensure the build version is written to a BUILD file in the installation directory.
     pub fn ensure_build_file(&self) -> Result<(), Error> {
         if let Some(ref build) = self.build {
@@ -0,0 +1,118 @@
+//! Discovery of the Python installations known to the Python launcher for Windows (`py`).
+//!
+//! The launcher finds installations through the PEP 514 registry keys (which are also read
+//! directly, see [`crate::windows_registry`]), but also through its own search (e.g., of
+//! Microsoft Store packages that aren't registered), so installations that are only known to the
+//! launcher are discovered by asking it to list them.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::str::FromStr;
+
+use tracing::debug;
+
+use crate::PythonVersion;
+use crate::windows_registry::WindowsPython;
+
+/// Find the Python installations listed by `py --list-paths`.
+///
+/// Returns an empty list if the launcher isn't installed, or if it fails.
+pub(crate) fn py_launcher_pythons() -> Vec<WindowsPython> {
+    let output = match Command::new("py").arg("--list-paths").output() {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => {
+            debug!(
+                "Ignoring Python launcher, `py --list-paths` failed with {}",
+                output.status
+            );
+            return Vec::new();
+        }
+        Err(err) => {
+            debug!("Ignoring Python launcher, `py` is unavailable: {err}");
+            return Vec::new();
+        }
+    };
+    parse_list_paths(&String::from_utf8_lossy(&output.stdout))
+        .into_iter()
+        .filter(|python| python.path.is_file())
+        .collect()
+}
+
+/// Parse the output of `py --list-paths`.
+///
+/// Each installation is listed on its own line, as a tag followed by the path to its executable,
+/// with the default installation marked with an asterisk, e.g.:
+///
+/// ```text
+///  -V:3.12 *        C:\Program Files\Python312\python.exe
+///  -V:3.11          C:\Users\ferris\AppData\Local\Programs\Python\Python311\python.exe
+/// ```
+///
+/// Older versions of the launcher use tags like `-3.11-64`, and mark the default installation
+/// with a trailing asterisk instead.
+fn parse_list_paths(output: &str) -> Vec<WindowsPython> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let (tag, path) = line.strip_prefix('-')?.split_once(char::is_whitespace)?;
+            let path = path
+                .trim()
+                .trim_start_matches('*')
+                .trim_end_matches('*')
+                .trim();
+            if path.is_empty() {
+                return None;
+            }
+
+            // Parse the version from the tag, ignoring tags for other distributions (e.g.,
+            // `-V:ContinuumAnalytics/Anaconda39-64`).
+            let tag = tag.strip_prefix("V:").unwrap_or(tag);
+            let tag = tag
+                .strip_suffix("-64")
+                .or_else(|| tag.strip_suffix("-32"))
+                .or_else(|| tag.strip_suffix("-arm64"))
+                .unwrap_or(tag);
+            let version = PythonVersion::from_str(tag).ok();
+
+            Some(WindowsPython {
+                path: PathBuf::from(path),
+                version,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::parse_list_paths;
+
+    #[test]
+    fn list_paths() {
+        let output = " -V:3.12 *        C:\\Program Files\\Python312\\python.exe\n -V:3.11          C:\\Python311\\python.exe\n -V:ContinuumAnalytics/Anaconda39-64 C:\\Anaconda3\\python.exe\n";
+        let pythons = parse_list_paths(output);
+        assert_eq!(pythons.len(), 3);
+        assert_eq!(
+            pythons[0].path,
+            Path::new("C:\\Program Files\\Python312\\python.exe")
+        );
+        assert_eq!(pythons[0].version.as_ref().unwrap().to_string(), "3.12");
+        assert_eq!(pythons[1].path, Path::new("C:\\Python311\\python.exe"));
+        assert_eq!(pythons[1].version.as_ref().unwrap().to_string(), "3.11");
+        assert_eq!(pythons[2].path, Path::new("C:\\Anaconda3\\python.exe"));
+        assert!(pythons[2].version.is_none());
+    }
+
+    #[test]
+    fn list_paths_legacy() {
+        let output = "Installed Pythons found by py Launcher for Windows\n -3.11-64        C:\\Python311\\python.exe *\n -3.9-32         C:\\Python39-32\\python.exe\n";
+        let pythons = parse_list_paths(output);
+        assert_eq!(pythons.len(), 2);
+        assert_eq!(pythons[0].path, Path::new("C:\\Python311\\python.exe"));
+        assert_eq!(pythons[0].version.as_ref().unwrap().to_string(), "3.11");
+        assert_eq!(pythons[1].path, Path::new("C:\\Python39-32\\python.exe"));
+        assert_eq!(pythons[1].version.as_ref().unwrap().to_string(), "3.9");
+    }
+}
@@ -0,0 +1,110 @@
+//! Discovery of Python installations managed by third-party version managers.
+use std::cmp::Reverse;
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_pep440::Version;
+use uv_static::EnvVars;
+
+/// A third-party tool that manages Python installations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VersionManager {
+    /// [pyenv](https://github.com/pyenv/pyenv), or [pyenv-win](https://github.com/pyenv-win/pyenv-win)
+    /// on Windows.
+    Pyenv,
+    /// [asdf](https://asdf-vm.com/), with the Python plugin.
+    Asdf,
+}
+
+impl VersionManager {
+    /// Return the directory in which the version manager stores its Python installations.
+    ///
+    /// Each installation is a subdirectory, named after its version (e.g., `3.12.1`).
+    fn versions_dir(self) -> Option<PathBuf> {
+        let root = |var: &str, default: &str| {
+            std::env::var_os(var)
+                .filter(|root| !root.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| Some(etcetera::home_dir().ok()?.join(default)))
+        };
+        match self {
+            Self::Pyenv => {
+                let root = root(EnvVars::PYENV_ROOT, ".pyenv")?;
+                // pyenv-win nests its installations in a `pyenv-win` subdirectory.
+                let root = if cfg!(windows) && root.join("pyenv-win").is_dir() {
+                    root.join("pyenv-win")
+                } else {
+                    root
+                };
+                Some(root.join("versions"))
+            }
+            Self::Asdf => Some(
+                root(EnvVars::ASDF_DATA_DIR, ".asdf")?
+                    .join("installs")
+                    .join("python"),
+            ),
+        }
+    }
+
+    /// Return the Python executables of the installations managed by the version manager, along
+    /// with their versions (if the installation is named after one), ordered from newest to
+    /// oldest.
+    ///
+    /// Symbolic links in the versions directory (e.g., virtual environments created by
+    /// `pyenv-virtualenv`) are skipped.
+    pub(crate) fn executables(self) -> Vec<(PathBuf, Option<Version>)> {
+        let Some(versions_dir) = self.versions_dir() else {
+            return Vec::new();
+        };
+        let Ok(entries) = fs_err::read_dir(&versions_dir) else {
+            return Vec::new();
+        };
+        debug!(
+            "Searching for {self} installations in `{}`",
+            versions_dir.user_display()
+        );
+
+        let mut installations = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .map(|entry| {
+                let version = entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| Version::from_str(name).ok());
+                (entry.path(), version)
+            })
+            .collect::<Vec<_>>();
+        installations.sort_by(|(a, a_version), (b, b_version)| {
+            Reverse(a_version)
+                .cmp(&Reverse(b_version))
+                .then_with(|| a.cmp(b))
+        });
+
+        installations
+            .into_iter()
+            .map(|(dir, version)| {
+                let executable = if cfg!(windows) {
+                    dir.join("python.exe")
+                } else {
+                    dir.join("bin").join("python3")
+                };
+                (executable, version)
+            })
+            .filter(|(executable, _)| executable.is_file())
+            .collect()
+    }
+}
+
+impl Display for VersionManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+        }
+    }
+}
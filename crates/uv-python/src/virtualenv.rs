@@ -45,6 +45,8 @@ pub struct PyVenvConfiguration {
     pub(crate) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(crate) version: Option<PythonVersion>,
+    /// The directory containing the base interpreter, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -220,6 +222,7 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut home = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -246,6 +249,9 @@ impl PyVenvConfiguration {
                 "include-system-site-packages" => {
                     include_system_site_packages = value.trim().to_lowercase() == "true";
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 "version" | "version_info" => {
                     version = Some(
                         PythonVersion::from_str(value.trim())
@@ -263,6 +269,7 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            home,
         })
     }
 
@@ -291,6 +298,21 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the directory containing the base interpreter, if it no longer exists.
+    ///
+    /// The base interpreter may have been removed (e.g., by uninstalling the Python version), or
+    /// `home` may refer to a symbolic link that's now dangling (e.g., after a system upgrade of the
+    /// underlying Python installation), in which case the virtual environment is unusable, even if
+    /// its own interpreter still appears to be intact (e.g., the launcher on Windows).
+    pub fn missing_home(&self) -> Option<&Path> {
+        let home = self.home.as_deref()?;
+        if home.is_absolute() && matches!(home.try_exists(), Ok(false)) {
+            Some(home)
+        } else {
+            None
+        }
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -389,6 +411,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_missing_home() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+
+        // The base interpreter exists.
+        fs::write(&cfg, format!("home = {}\n", temp_dir.path().display()))?;
+        assert_eq!(PyVenvConfiguration::parse(&cfg)?.missing_home(), None);
+
+        // The base interpreter was removed.
+        let home = temp_dir.path().join("python").join("bin");
+        fs::write(&cfg, format!("home = {}\n", home.display()))?;
+        assert_eq!(
+            PyVenvConfiguration::parse(&cfg)?.missing_home(),
+            Some(home.as_path())
+        );
+
+        // The base interpreter is a dangling symbolic link.
+        #[cfg(unix)]
+        {
+            let link = temp_dir.path().join("link");
+            fs_err::os::unix::fs::symlink(&home, &link)?;
+            fs::write(&cfg, format!("home = {}\n", link.display()))?;
+            assert_eq!(
+                PyVenvConfiguration::parse(&cfg)?.missing_home(),
+                Some(link.as_path())
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_set_empty_content() {
         let content = "";
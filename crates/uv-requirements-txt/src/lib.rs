@@ -107,6 +107,9 @@ pub struct RequirementEntry {
     pub requirement: RequirementsTxtRequirement,
     /// Hashes of the downloadable packages.
     pub hashes: Vec<String>,
+    /// The trailing comment on the requirement's line, if any (e.g., `# pinned for CI`), without
+    /// the leading `#`.
+    pub comment: Option<String>,
 }
 
 // We place the impl here instead of next to `UnresolvedRequirementSpecification` because
@@ -133,6 +136,7 @@ impl From<RequirementsTxtRequirement> for UnresolvedRequirementSpecification {
         Self::from(RequirementEntry {
             requirement: value,
             hashes: vec![],
+            comment: None,
         })
     }
 }
@@ -638,7 +642,7 @@ fn parse_entry(
             Some(requirements_txt)
         };
 
-        let (requirement, hashes) =
+        let (requirement, hashes, comment) =
             parse_requirement_and_hashes(s, content, source, working_dir, true)?;
         let requirement =
             requirement
@@ -651,6 +655,7 @@ fn parse_entry(
         RequirementsTxtStatement::EditableRequirementEntry(RequirementEntry {
             requirement,
             hashes,
+            comment,
         })
     } else if s.eat_if("-i") || s.eat_if("--index-url") {
         let given = parse_value("--index-url", content, s, |c: char| !is_terminal(c))?;
@@ -786,11 +791,12 @@ fn parse_entry(
             Some(requirements_txt)
         };
 
-        let (requirement, hashes) =
+        let (requirement, hashes, comment) =
             parse_requirement_and_hashes(s, content, source, working_dir, false)?;
         RequirementsTxtStatement::RequirementEntry(RequirementEntry {
             requirement,
             hashes,
+            comment,
         })
     } else if let Some(char) = s.peek() {
         // Identify an unsupported option, like `--trusted-host`.
@@ -851,16 +857,17 @@ fn eat_trailing_line(content: &str, s: &mut Scanner) -> Result<(), RequirementsT
     Ok(())
 }
 
-/// Parse a PEP 508 requirement with optional trailing hashes
+/// Parse a PEP 508 requirement with optional trailing hashes and an optional trailing comment
 fn parse_requirement_and_hashes(
     s: &mut Scanner,
     content: &str,
     source: Option<&Path>,
     working_dir: &Path,
     editable: bool,
-) -> Result<(RequirementsTxtRequirement, Vec<String>), RequirementsTxtParserError> {
+) -> Result<(RequirementsTxtRequirement, Vec<String>, Option<String>), RequirementsTxtParserError> {
     // PEP 508 requirement
     let start = s.cursor();
+    let mut comment = None;
     // Termination: s.eat() eventually becomes None
     let (end, has_hashes) = loop {
         let end = s.cursor();
@@ -878,7 +885,7 @@ fn parse_requirement_and_hashes(
             if s.after().starts_with("--") {
                 break (end, true);
             } else if s.eat_if('#') {
-                s.eat_until(['\r', '\n']);
+                comment = parse_comment(s.eat_until(['\r', '\n']));
                 if s.at('\r') {
                     s.eat_if('\n'); // `\r\n`, but just `\r` is also accepted
                 }
@@ -929,11 +936,22 @@ fn parse_requirement_and_hashes(
         })?;
 
     let hashes = if has_hashes {
-        parse_hashes(content, s)?
+        let hashes = parse_hashes(content, s)?;
+        // A comment may follow the hashes; leave the line ending to be consumed as trivia.
+        if s.eat_if('#') {
+            comment = parse_comment(s.eat_until(['\r', '\n']));
+        }
+        hashes
     } else {
         Vec::new()
     };
-    Ok((requirement, hashes))
+    Ok((requirement, hashes, comment))
+}
+
+/// Normalize the text of a trailing comment (after the `#`), returning `None` if it's empty.
+fn parse_comment(comment: &str) -> Option<String> {
+    let comment = comment.trim();
+    (!comment.is_empty()).then(|| comment.to_string())
 }
 
 /// Parse `--hash=... --hash ...` after a requirement
@@ -2014,6 +2032,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                 ],
                 constraints: [],
@@ -2078,6 +2097,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                 ],
                 constraints: [],
@@ -2189,6 +2209,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                 ],
                 index_url: None,
@@ -2303,6 +2324,9 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: Some(
+                            "comment",
+                        ),
                     },
                     RequirementEntry {
                         requirement: Named(
@@ -2334,6 +2358,9 @@ mod test {
                         hashes: [
                             "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
                         ],
+                        comment: Some(
+                            "comment",
+                        ),
                     },
                     RequirementEntry {
                         requirement: Named(
@@ -2365,6 +2392,9 @@ mod test {
                         hashes: [
                             "sha256:fedcba0987654321fedcba0987654321fedcba0987654321fedcba0987654321",
                         ],
+                        comment: Some(
+                            "comment",
+                        ),
                     },
                     RequirementEntry {
                         requirement: Named(
@@ -2394,6 +2424,9 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: Some(
+                            "comment",
+                        ),
                     },
                     RequirementEntry {
                         requirement: Named(
@@ -2423,6 +2456,9 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: Some(
+                            "comment",
+                        ),
                     },
                 ],
                 constraints: [],
@@ -2549,6 +2585,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                     RequirementEntry {
                         requirement: Unnamed(
@@ -2598,6 +2635,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                     RequirementEntry {
                         requirement: Unnamed(
@@ -2651,6 +2689,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                     RequirementEntry {
                         requirement: Unnamed(
@@ -2700,6 +2739,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                     RequirementEntry {
                         requirement: Unnamed(
@@ -2749,6 +2789,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                     RequirementEntry {
                         requirement: Unnamed(
@@ -2802,6 +2843,7 @@ mod test {
                             },
                         ),
                         hashes: [],
+                        comment: None,
                     },
                 ],
                 constraints: [],
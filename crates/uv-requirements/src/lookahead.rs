@@ -90,7 +90,7 @@ impl<'a, Context: BuildContext> LookaheadResolver<'a, Context> {
         // Queue up the initial requirements.
         let mut queue: VecDeque<_> = self
             .constraints
-            .apply(self.overrides.apply(self.requirements))
+            .apply(self.overrides.apply(None, self.requirements))
             .filter(|requirement| requirement.evaluate_markers(env.marker_environment(), &[]))
             .map(|requirement| (*requirement).clone())
             .collect();
@@ -106,10 +106,10 @@ impl<'a, Context: BuildContext> LookaheadResolver<'a, Context> {
 
             while let Some(result) = futures.next().await {
                 if let Some(lookahead) = result? {
-                    for requirement in self
-                        .constraints
-                        .apply(self.overrides.apply(lookahead.requirements()))
-                    {
+                    for requirement in self.constraints.apply(
+                        self.overrides
+                            .apply(Some(lookahead.name()), lookahead.requirements()),
+                    ) {
                         if requirement
                             .evaluate_markers(env.marker_environment(), lookahead.extras())
                         {
@@ -206,6 +206,7 @@ impl<'a, Context: BuildContext> LookaheadResolver<'a, Context> {
 
         // Return the requirements from the metadata.
         Ok(Some(RequestedRequirements::new(
+            requirement.name,
             requirement.extras,
             requires_dist,
             direct,
@@ -61,6 +61,8 @@ pub struct RequirementsSpecification {
     pub constraints: Vec<NameRequirementSpecification>,
     /// The overrides for the project.
     pub overrides: Vec<UnresolvedRequirementSpecification>,
+    /// The overrides for the project that only apply to the dependencies of a specific package.
+    pub scoped_overrides: Vec<(PackageName, Requirement)>,
     /// The `pylock.toml` file from which to extract the resolution.
     pub pylock: Option<PathBuf>,
     /// The source trees from which to extract requirements.
@@ -81,6 +83,9 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The trailing comments attached to named requirements in a `requirements.txt` file (e.g.,
+    /// `flask  # pinned for CI`), keyed by package name.
+    pub comments: BTreeMap<PackageName, Vec<String>>,
 }
 
 impl RequirementsSpecification {
@@ -125,6 +130,22 @@ impl RequirementsSpecification {
                     }
                 }
 
+                let mut comments = BTreeMap::<PackageName, Vec<String>>::new();
+                for entry in requirements_txt
+                    .requirements
+                    .iter()
+                    .chain(&requirements_txt.editables)
+                {
+                    if let (RequirementsTxtRequirement::Named(requirement), Some(comment)) =
+                        (&entry.requirement, &entry.comment)
+                    {
+                        comments
+                            .entry(requirement.name.clone())
+                            .or_default()
+                            .push(comment.clone());
+                    }
+                }
+
                 Self {
                     requirements: requirements_txt
                         .requirements
@@ -157,6 +178,7 @@ impl RequirementsSpecification {
                         .collect(),
                     no_binary: requirements_txt.no_binary,
                     no_build: requirements_txt.only_binary,
+                    comments,
                     ..Self::default()
                 }
             }
@@ -233,25 +255,22 @@ impl RequirementsSpecification {
                         })
                         .unwrap_or_default();
 
-                    let overrides = tool_uv
-                        .override_dependencies
-                        .as_ref()
-                        .map(|dependencies| {
-                            dependencies
-                                .iter()
-                                .map(|dependency| {
-                                    UnresolvedRequirementSpecification::from(Requirement::from(
-                                        dependency.to_owned(),
-                                    ))
-                                })
-                                .collect::<Vec<UnresolvedRequirementSpecification>>()
-                        })
-                        .unwrap_or_default();
+                    let mut overrides = Vec::new();
+                    let mut scoped_overrides = Vec::new();
+                    for dependency in tool_uv.override_dependencies.iter().flatten() {
+                        let requirement = Requirement::from(dependency.requirement.clone());
+                        if let Some(dependent) = &dependency.when {
+                            scoped_overrides.push((dependent.clone(), requirement));
+                        } else {
+                            overrides.push(UnresolvedRequirementSpecification::from(requirement));
+                        }
+                    }
 
                     Self {
                         requirements,
                         constraints,
                         overrides,
+                        scoped_overrides,
                         index_url: tool_uv
                             .top_level
                             .index_url
@@ -483,8 +502,12 @@ impl RequirementsSpecification {
             spec.requirements.extend(source.requirements);
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
+            spec.scoped_overrides.extend(source.scoped_overrides);
             spec.extras.extend(source.extras);
             spec.source_trees.extend(source.source_trees);
+            for (name, comments) in source.comments {
+                spec.comments.entry(name).or_default().extend(comments);
+            }
 
             // Allow at most one `pylock.toml`.
             if let Some(pylock) = source.pylock {
@@ -564,6 +587,7 @@ impl RequirementsSpecification {
             let source = Self::from_source(source, client_builder).await?;
             spec.overrides.extend(source.requirements);
             spec.overrides.extend(source.overrides);
+            spec.scoped_overrides.extend(source.scoped_overrides);
 
             if let Some(index_url) = source.index_url {
                 if let Some(existing) = spec.index_url {
@@ -0,0 +1,246 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use itertools::Itertools;
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
+use uv_resolver::{Lock, Package};
+
+use crate::universe::{Dependency, Universe};
+
+/// An invariant that was violated by a lockfile or environment.
+#[derive(Debug, Error)]
+pub enum Violation {
+    #[error("Locked package `{0}` is missing a version")]
+    MissingVersion(PackageName),
+    #[error("Locked package `{0}=={1}` does not exist in the universe")]
+    UnknownPackage(PackageName, Version),
+    #[error("`{0}` has a locked dependency on `{1}`, which does not exist in the universe")]
+    UnexpectedDependency(String, PackageName),
+    #[error("`{0}` depends on `{1}`, which is missing from the lockfile")]
+    MissingDependency(String, Dependency),
+    #[error("`{0}` depends on `{1}`, but is locked to `{2}=={3}`")]
+    UnsatisfiedDependency(String, Dependency, PackageName, Version),
+    #[error("`{0}` depends on `{1}`, but the locked dependency applies under `{2}`")]
+    MarkerNotImplied(String, Dependency, String),
+    #[error("Expected disjoint markers for {0}, but `{1}` and `{2}` overlap")]
+    OverlappingMarkers(String, String, String),
+    #[error("`{0}=={1}` is installed, but is not required by the lockfile")]
+    UnexpectedInstall(PackageName, Version),
+    #[error("`{0}=={1}` is required by the lockfile, but is not installed")]
+    MissingInstall(PackageName, Version),
+}
+
+/// Check that a [`Lock`] is a valid resolution of the given [`Universe`].
+///
+/// Specifically:
+///
+/// - Every locked package exists in the universe, and every locked dependency corresponds to a
+///   dependency in the universe, at a version that satisfies its specifiers, under a marker that
+///   implies its marker.
+/// - Every dependency in the universe that can apply under the lockfile's `requires-python` (and
+///   the package's own forks, if any) is locked.
+/// - The lockfile's forks are pairwise disjoint, as are the forks of any package that's locked at
+///   multiple versions, and the markers of any dependency that's locked at multiple versions.
+///
+/// Any locked package that's not part of the universe (i.e., the project itself) is treated as
+/// the root, and checked against [`Universe::requirements`].
+pub fn check_lock(universe: &Universe, lock: &Lock) -> Result<(), Violation> {
+    let requires_python = lock.requires_python().to_marker_tree();
+
+    for package in lock.packages() {
+        let (label, expected) = if universe.contains(package.name()) {
+            let Some(version) = package.version() else {
+                return Err(Violation::MissingVersion(package.name().clone()));
+            };
+            let Some(expected) = universe.dependencies(package.name(), version) else {
+                return Err(Violation::UnknownPackage(
+                    package.name().clone(),
+                    version.clone(),
+                ));
+            };
+            (format!("{}=={version}", package.name()), expected)
+        } else {
+            (package.name().to_string(), universe.requirements())
+        };
+
+        for dependency in package.dependencies() {
+            let Some(expected) = expected
+                .iter()
+                .find(|expected| expected.name == *dependency.package_name())
+            else {
+                return Err(Violation::UnexpectedDependency(
+                    label,
+                    dependency.package_name().clone(),
+                ));
+            };
+            if let Some(version) = dependency.package_version() {
+                if !expected.specifiers.contains(version) {
+                    return Err(Violation::UnsatisfiedDependency(
+                        label,
+                        expected.clone(),
+                        dependency.package_name().clone(),
+                        version.clone(),
+                    ));
+                }
+            }
+            let marker = dependency.complexified_marker().pep508();
+            if !marker.is_disjoint(expected.marker.negate()) {
+                return Err(Violation::MarkerNotImplied(
+                    label,
+                    expected.clone(),
+                    marker.try_to_string().unwrap_or_default(),
+                ));
+            }
+        }
+
+        // The environments in which the package itself is locked.
+        let reachable = if package.fork_markers().is_empty() {
+            requires_python
+        } else {
+            let mut reachable = MarkerTree::FALSE;
+            for fork in package.fork_markers() {
+                reachable.or(fork.pep508());
+            }
+            reachable.and(requires_python);
+            reachable
+        };
+
+        for expected in expected {
+            let mut marker = expected.marker;
+            marker.and(reachable);
+            if marker.is_false() {
+                continue;
+            }
+            if !package
+                .dependencies()
+                .iter()
+                .any(|dependency| *dependency.package_name() == expected.name)
+            {
+                return Err(Violation::MissingDependency(label, expected.clone()));
+            }
+        }
+
+        for (first, second) in package.dependencies().iter().tuple_combinations() {
+            if first.package_name() == second.package_name()
+                && first.package_version() != second.package_version()
+            {
+                check_disjoint(
+                    &format!(
+                        "the dependencies of `{label}` on `{}`",
+                        first.package_name()
+                    ),
+                    first.complexified_marker().combined(),
+                    second.complexified_marker().combined(),
+                )?;
+            }
+        }
+    }
+
+    for (first, second) in lock.fork_markers().iter().tuple_combinations() {
+        check_disjoint("the lockfile's forks", first.combined(), second.combined())?;
+    }
+
+    for (first, second) in lock.packages().iter().tuple_combinations() {
+        if first.name() != second.name() {
+            continue;
+        }
+        let context = format!("the versions of `{}`", first.name());
+        for (first, second) in first
+            .fork_markers()
+            .iter()
+            .cartesian_product(second.fork_markers())
+        {
+            check_disjoint(&context, first.combined(), second.combined())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the packages installed in an environment match those required by a [`Lock`] for
+/// the given [`MarkerEnvironment`].
+///
+/// The lockfile is traversed from its workspace members (or, for a single project, its root),
+/// following the dependencies whose markers apply to the environment. The members themselves are
+/// excluded from the comparison.
+pub fn check_install(
+    lock: &Lock,
+    marker_env: &MarkerEnvironment,
+    installed: impl IntoIterator<Item = (PackageName, Version)>,
+) -> Result<(), Violation> {
+    let is_member = |package: &Package| {
+        lock.members().contains(package.name())
+            || lock
+                .root()
+                .is_some_and(|root| root.name() == package.name())
+    };
+
+    // Traverse the lockfile from the members, following any applicable dependencies.
+    let mut seen = BTreeSet::new();
+    let mut queue = lock
+        .packages()
+        .iter()
+        .filter(|package| is_member(package))
+        .collect::<VecDeque<_>>();
+    while let Some(package) = queue.pop_front() {
+        for dependency in package.dependencies() {
+            if !dependency
+                .complexified_marker()
+                .combined()
+                .evaluate(marker_env, &[])
+            {
+                continue;
+            }
+            let Some(next) = lock.packages().iter().find(|next| {
+                next.name() == dependency.package_name()
+                    && next.version() == dependency.package_version()
+            }) else {
+                continue;
+            };
+            if seen.insert((next.name(), next.version())) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let expected = lock
+        .packages()
+        .iter()
+        .filter(|package| !is_member(package))
+        .filter(|package| seen.contains(&(package.name(), package.version())))
+        .filter_map(|package| Some((package.name().clone(), package.version()?.clone())))
+        .collect::<BTreeSet<_>>();
+    let installed = installed
+        .into_iter()
+        .filter(|(name, _)| {
+            !lock
+                .packages()
+                .iter()
+                .any(|package| package.name() == name && is_member(package))
+        })
+        .collect::<BTreeSet<_>>();
+
+    if let Some((name, version)) = installed.difference(&expected).next() {
+        return Err(Violation::UnexpectedInstall(name.clone(), version.clone()));
+    }
+    if let Some((name, version)) = expected.difference(&installed).next() {
+        return Err(Violation::MissingInstall(name.clone(), version.clone()));
+    }
+
+    Ok(())
+}
+
+/// Check that two markers are disjoint, using the decision diagram underlying [`MarkerTree`].
+fn check_disjoint(context: &str, first: MarkerTree, second: MarkerTree) -> Result<(), Violation> {
+    if first.is_disjoint(second) {
+        return Ok(());
+    }
+    Err(Violation::OverlappingMarkers(
+        context.to_string(),
+        first.try_to_string().unwrap_or_else(|| "true".to_string()),
+        second.try_to_string().unwrap_or_else(|| "true".to_string()),
+    ))
+}
@@ -0,0 +1,12 @@
+//! Utilities for regression-testing the resolver against synthetic package universes.
+//!
+//! A [`Universe`] is a randomly generated, but reproducible, set of packages, versions, and
+//! (possibly conditional) dependencies, which can be written to disk as a flat directory of
+//! wheels and resolved with `--find-links`. The resulting lockfile and environment can then be
+//! checked against the universe with [`check_lock`] and [`check_install`].
+
+pub use invariants::{Violation, check_install, check_lock};
+pub use universe::{Dependency, Error, Universe, UniverseOptions};
+
+mod invariants;
+mod universe;
@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use thiserror::Error;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerEnvironment, MarkerTree};
+
+/// The markers from which conditional dependencies are drawn.
+///
+/// The pool mixes overlapping and disjoint markers, such that universal resolution is forced to
+/// fork.
+const MARKERS: &[&str] = &[
+    "sys_platform == 'linux'",
+    "sys_platform == 'win32'",
+    "sys_platform == 'darwin'",
+    "platform_machine == 'x86_64'",
+    "python_full_version >= '3.13'",
+];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+}
+
+/// Options for generating a synthetic [`Universe`].
+#[derive(Debug, Clone)]
+pub struct UniverseOptions {
+    /// The number of packages in the universe.
+    pub packages: usize,
+    /// The maximum number of versions of each package.
+    pub versions: usize,
+    /// The maximum number of dependencies of each package version.
+    pub dependencies: usize,
+    /// The percentage of dependencies (from 0 to 100) that are conditional on a marker.
+    pub marker_percentage: usize,
+}
+
+impl Default for UniverseOptions {
+    fn default() -> Self {
+        Self {
+            packages: 8,
+            versions: 3,
+            dependencies: 3,
+            marker_percentage: 30,
+        }
+    }
+}
+
+/// A dependency of a package (or of the root project) in a [`Universe`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The versions of the package that satisfy the dependency.
+    pub specifiers: VersionSpecifiers,
+    /// The environments in which the dependency applies.
+    pub marker: MarkerTree,
+}
+
+impl Display for Dependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.name, self.specifiers)?;
+        if let Some(marker) = self.marker.try_to_string() {
+            write!(f, " ; {marker}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A synthetic set of packages, versions, and dependencies.
+///
+/// Packages are named `pkg-0` through `pkg-N`, and each package only depends on packages with a
+/// higher index, such that the dependency graph is acyclic.
+#[derive(Debug, Clone)]
+pub struct Universe {
+    /// The names of the packages, in order of their index.
+    names: Vec<PackageName>,
+    /// The requirements of the root project.
+    requirements: Vec<Dependency>,
+    /// The dependencies of each version of each package.
+    packages: BTreeMap<PackageName, BTreeMap<Version, Vec<Dependency>>>,
+}
+
+impl Universe {
+    /// Generate a [`Universe`] from the given seed.
+    ///
+    /// The same seed and options always produce the same universe.
+    pub fn generate(seed: u64, options: &UniverseOptions) -> Self {
+        let mut rng = Rng(seed);
+
+        let names = (0..options.packages.max(1))
+            .map(|index| {
+                PackageName::from_str(&format!("pkg-{index}")).expect("valid package name")
+            })
+            .collect::<Vec<_>>();
+
+        let mut packages = BTreeMap::new();
+        for (index, name) in names.iter().enumerate() {
+            let count = 1 + rng.below(options.versions.max(1));
+            let versions = (1..=count)
+                .map(|major| {
+                    let version = Version::new([u64::try_from(major).expect("valid version"), 0]);
+                    let dependencies = rng.dependencies(&names[index + 1..], options);
+                    (version, dependencies)
+                })
+                .collect();
+            packages.insert(name.clone(), versions);
+        }
+
+        // The root project always depends on the first package, to ensure that the resolution is
+        // non-trivial.
+        let mut requirements = rng.dependencies(&names[1..], options);
+        requirements.insert(
+            0,
+            Dependency {
+                name: names[0].clone(),
+                specifiers: VersionSpecifiers::empty(),
+                marker: MarkerTree::TRUE,
+            },
+        );
+
+        Self {
+            names,
+            requirements,
+            packages,
+        }
+    }
+
+    /// Returns the requirements of the root project.
+    pub fn requirements(&self) -> &[Dependency] {
+        &self.requirements
+    }
+
+    /// Returns `true` if the universe contains a package with the given name.
+    pub fn contains(&self, name: &PackageName) -> bool {
+        self.packages.contains_key(name)
+    }
+
+    /// Returns the dependencies of the given package version, if it exists in the universe.
+    pub fn dependencies(&self, name: &PackageName, version: &Version) -> Option<&[Dependency]> {
+        self.packages.get(name)?.get(version).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over every package version in the universe.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &Version, &[Dependency])> {
+        self.packages.iter().flat_map(|(name, versions)| {
+            versions
+                .iter()
+                .map(move |(version, dependencies)| (name, version, dependencies.as_slice()))
+        })
+    }
+
+    /// Returns `true` if there's a version of each required package that satisfies every
+    /// dependency that applies in the given environment.
+    ///
+    /// A universal resolution must hold in every environment, so a universe that's unsatisfiable
+    /// in any one environment must fail to lock. If the universe has no conditional dependencies,
+    /// the converse holds, too.
+    pub fn is_satisfiable(&self, marker_env: &MarkerEnvironment) -> bool {
+        let constraints = self
+            .requirements
+            .iter()
+            .filter(|dependency| dependency.marker.evaluate(marker_env, &[]))
+            .collect::<Vec<_>>();
+        self.search(0, constraints, marker_env)
+    }
+
+    /// Search for a version of each required package, starting from the package at the given
+    /// index, that satisfies the given constraints.
+    ///
+    /// Since packages only depend on packages with a higher index, every constraint on a package
+    /// is known by the time it's reached, such that deciding packages in order is exhaustive.
+    fn search<'a>(
+        &'a self,
+        index: usize,
+        constraints: Vec<&'a Dependency>,
+        marker_env: &MarkerEnvironment,
+    ) -> bool {
+        let Some(name) = self.names.get(index) else {
+            return true;
+        };
+
+        let applicable = constraints
+            .iter()
+            .filter(|dependency| dependency.name == *name)
+            .collect::<Vec<_>>();
+        if applicable.is_empty() {
+            return self.search(index + 1, constraints, marker_env);
+        }
+
+        self.packages[name].iter().any(|(version, dependencies)| {
+            if !applicable
+                .iter()
+                .all(|dependency| dependency.specifiers.contains(version))
+            {
+                return false;
+            }
+            let constraints = constraints
+                .iter()
+                .copied()
+                .chain(
+                    dependencies
+                        .iter()
+                        .filter(|dependency| dependency.marker.evaluate(marker_env, &[])),
+                )
+                .collect();
+            self.search(index + 1, constraints, marker_env)
+        })
+    }
+
+    /// Write a minimal, pure-Python wheel for every package version in the universe to the given
+    /// directory, for use with `--find-links`.
+    pub fn write_wheels(&self, directory: &Path) -> Result<(), Error> {
+        fs_err::create_dir_all(directory)?;
+        for (name, version, dependencies) in self.iter() {
+            let stem = format!("{}-{version}", name.as_dist_info_name());
+            let dist_info = format!("{stem}.dist-info");
+
+            let metadata = format!(
+                "Metadata-Version: 2.3\nName: {name}\nVersion: {version}\n{}",
+                dependencies
+                    .iter()
+                    .map(|dependency| format!("Requires-Dist: {dependency}\n"))
+                    .collect::<String>()
+            );
+            let wheel = "Wheel-Version: 1.0\nGenerator: uv-resolver-testkit\nRoot-Is-Purelib: true\nTag: py3-none-any\n";
+            let record =
+                format!("{dist_info}/METADATA,,\n{dist_info}/WHEEL,,\n{dist_info}/RECORD,,\n");
+
+            let file = fs_err::File::create(directory.join(format!("{stem}-py3-none-any.whl")))?;
+            let mut writer = ZipWriter::new(file);
+            let options = SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored)
+                .unix_permissions(0o644);
+            for (path, contents) in [
+                ("METADATA", metadata.as_str()),
+                ("WHEEL", wheel),
+                ("RECORD", record.as_str()),
+            ] {
+                writer.start_file(format!("{dist_info}/{path}"), options)?;
+                writer.write_all(contents.as_bytes())?;
+            }
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// A small, deterministic pseudo-random number generator (SplitMix64).
+///
+/// We avoid a dependency on `rand` so that universes are stable across dependency upgrades.
+struct Rng(u64);
+
+impl Rng {
+    /// Return the next pseudo-random value.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Return a pseudo-random value in `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        let bound = u64::try_from(bound).expect("bound fits in a `u64`");
+        usize::try_from(self.next() % bound).expect("value is less than the bound")
+    }
+
+    /// Generate a set of dependencies on the given candidate packages, with at most one
+    /// dependency per package.
+    fn dependencies(
+        &mut self,
+        candidates: &[PackageName],
+        options: &UniverseOptions,
+    ) -> Vec<Dependency> {
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+
+        let count = self.below(options.dependencies + 1);
+        let mut dependencies: Vec<Dependency> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = &candidates[self.below(candidates.len())];
+            if dependencies
+                .iter()
+                .any(|dependency| dependency.name == *name)
+            {
+                continue;
+            }
+
+            let version = Version::new([
+                u64::try_from(1 + self.below(options.versions.max(1))).expect("valid version"),
+                0,
+            ]);
+            let specifiers = match self.below(4) {
+                0 => VersionSpecifiers::empty(),
+                1 => VersionSpecifiers::from(VersionSpecifier::greater_than_equal_version(version)),
+                2 => VersionSpecifiers::from(VersionSpecifier::less_than_version(version)),
+                _ => VersionSpecifiers::from(VersionSpecifier::equals_version(version)),
+            };
+
+            let marker = if self.below(100) < options.marker_percentage {
+                MarkerTree::from_str(MARKERS[self.below(MARKERS.len())]).expect("valid marker")
+            } else {
+                MarkerTree::TRUE
+            };
+
+            dependencies.push(Dependency {
+                name: name.clone(),
+                specifiers,
+                marker,
+            });
+        }
+        dependencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_pep508::MarkerEnvironmentBuilder;
+
+    use super::*;
+
+    #[test]
+    fn generate_deterministic() {
+        let options = UniverseOptions::default();
+        let first = Universe::generate(42, &options);
+        let second = Universe::generate(42, &options);
+        assert_eq!(first.requirements, second.requirements);
+        assert_eq!(first.packages, second.packages);
+    }
+
+    /// Construct a CPython 3.12 marker environment for the given platform.
+    fn marker_env(sys_platform: &str, platform_system: &str) -> MarkerEnvironment {
+        MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: "3.12.0",
+            os_name: if sys_platform == "win32" {
+                "nt"
+            } else {
+                "posix"
+            },
+            platform_machine: "x86_64",
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system,
+            platform_version: "",
+            python_full_version: "3.12.0",
+            python_version: "3.12",
+            sys_platform,
+        })
+        .unwrap()
+    }
+
+    fn dependency(name: &str, specifiers: &str, marker: MarkerTree) -> Dependency {
+        Dependency {
+            name: PackageName::from_str(name).unwrap(),
+            specifiers: VersionSpecifiers::from_str(specifiers).unwrap(),
+            marker,
+        }
+    }
+
+    #[test]
+    fn satisfiable() {
+        let linux = marker_env("linux", "Linux");
+        let windows = marker_env("win32", "Windows");
+        let on_linux = MarkerTree::from_str("sys_platform == 'linux'").unwrap();
+        let [pkg0, pkg1] = ["pkg-0", "pkg-1"].map(|name| PackageName::from_str(name).unwrap());
+
+        // `pkg-0` requires a version of `pkg-1` on Linux that doesn't exist.
+        let mut universe = Universe {
+            names: vec![pkg0.clone(), pkg1.clone()],
+            requirements: vec![dependency("pkg-0", "", MarkerTree::TRUE)],
+            packages: BTreeMap::from([
+                (
+                    pkg0.clone(),
+                    BTreeMap::from([(
+                        Version::new([1, 0]),
+                        vec![dependency("pkg-1", "==2.0", on_linux)],
+                    )]),
+                ),
+                (pkg1, BTreeMap::from([(Version::new([1, 0]), Vec::new())])),
+            ]),
+        };
+        assert!(!universe.is_satisfiable(&linux));
+        assert!(universe.is_satisfiable(&windows));
+
+        // A second version of `pkg-0` makes the universe satisfiable on Linux, too.
+        universe.packages.get_mut(&pkg0).unwrap().insert(
+            Version::new([2, 0]),
+            vec![dependency("pkg-1", "<2.0", MarkerTree::TRUE)],
+        );
+        assert!(universe.is_satisfiable(&linux));
+
+        // Unless the root project excludes it.
+        universe.requirements = vec![dependency("pkg-0", "<2.0", MarkerTree::TRUE)];
+        assert!(!universe.is_satisfiable(&linux));
+    }
+
+    #[test]
+    fn generate_acyclic() {
+        fn index(name: &PackageName) -> usize {
+            name.as_ref().trim_start_matches("pkg-").parse().unwrap()
+        }
+
+        let universe = Universe::generate(7, &UniverseOptions::default());
+        for (name, _, dependencies) in universe.iter() {
+            for dependency in dependencies {
+                assert!(
+                    index(&dependency.name) > index(name),
+                    "{name} depends on {dependency}"
+                );
+            }
+        }
+    }
+}
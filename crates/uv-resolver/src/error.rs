@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::fmt::Formatter;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use indexmap::IndexSet;
@@ -12,11 +13,14 @@ use rustc_hash::FxHashMap;
 use tracing::trace;
 
 use uv_distribution_types::{
-    DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
+    DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexName, IndexUrl,
+    PackageNamePattern, RequestedDist, SourceAnnotation, SourceAnnotations,
 };
 use uv_normalize::{ExtraName, InvalidNameError, PackageName};
 use uv_pep440::{LocalVersionSlice, LowerBound, Version, VersionSpecifier};
-use uv_pep508::{MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion};
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion, RequirementOrigin,
+};
 use uv_platform_tags::Tags;
 use uv_pypi_types::ParsedUrl;
 use uv_redacted::DisplaySafeUrl;
@@ -31,7 +35,8 @@ use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
-    MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
+    CandidateVetoError, MetadataUnavailable, ResolverEnvironment, UnavailablePackage,
+    UnavailableReason,
 };
 use crate::{InMemoryIndex, Options};
 
@@ -54,6 +59,9 @@ pub enum ResolveError {
     #[error("The channel closed unexpectedly")]
     ChannelClosed,
 
+    #[error("Failed to filter the candidate versions of `{0}`")]
+    CandidateVeto(PackageName, #[source] CandidateVetoError),
+
     #[error(transparent)]
     Join(#[from] tokio::task::JoinError),
 
@@ -131,6 +139,11 @@ pub enum ResolveError {
     )]
     UnhashedPackage(PackageName),
 
+    #[error(
+        "Package `{0}` matches the index namespace `{1}`, which is pinned to index `{2}`, but was requested from: {3}"
+    )]
+    IndexNamespaceSource(PackageName, PackageNamePattern, IndexName, DisplaySafeUrl),
+
     #[error("found conflicting distribution in resolution: {0}")]
     ConflictingDistribution(ConflictingDistributionError),
 
@@ -187,6 +200,7 @@ pub struct NoSolutionError {
     current_environment: MarkerEnvironment,
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
+    sources: SourceAnnotations,
     options: Options,
 }
 
@@ -209,6 +223,7 @@ impl NoSolutionError {
         current_environment: MarkerEnvironment,
         tags: Option<Tags>,
         workspace_members: BTreeSet<PackageName>,
+        sources: SourceAnnotations,
         options: Options,
     ) -> Self {
         Self {
@@ -228,6 +243,7 @@ impl NoSolutionError {
             current_environment,
             tags,
             workspace_members,
+            sources,
             options,
         }
     }
@@ -421,6 +437,74 @@ impl NoSolutionError {
         Ok(())
     }
 
+    /// Attribute the requirements involved in the error to their sources (e.g., the
+    /// requirements file and line on which they were declared).
+    ///
+    /// Only applicable when the involved requirements were provided by more than one source, as
+    /// the origin is otherwise unambiguous.
+    fn hint_requirement_sources(&self, tree: &ErrorTree, f: &mut Formatter) -> std::fmt::Result {
+        let packages = tree
+            .packages()
+            .into_iter()
+            .filter_map(|package| package.name())
+            .collect::<BTreeSet<_>>();
+
+        // Omit workspace-level sources, which can't be attributed to a single file.
+        let sources = packages
+            .into_iter()
+            .filter_map(|name| {
+                let annotations = self
+                    .sources
+                    .get(name)?
+                    .iter()
+                    .filter(|annotation| {
+                        !matches!(
+                            annotation,
+                            SourceAnnotation::Requirement(RequirementOrigin::Workspace)
+                                | SourceAnnotation::Constraint(RequirementOrigin::Workspace)
+                                | SourceAnnotation::Override(RequirementOrigin::Workspace)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                (!annotations.is_empty()).then_some((name, annotations))
+            })
+            .collect::<Vec<_>>();
+        if sources
+            .iter()
+            .flat_map(|(_, annotations)| annotations)
+            .unique()
+            .count()
+            < 2
+        {
+            return Ok(());
+        }
+
+        for (name, annotations) in sources {
+            let annotations = annotations
+                .into_iter()
+                .map(|annotation| match source_line(annotation, name) {
+                    Some(line) => format!("`{annotation}:{line}`"),
+                    None => format!("`{annotation}`"),
+                })
+                .collect::<Vec<_>>();
+            let annotations = match annotations.as_slice() {
+                [first, second] => format!("{first} and {second}"),
+                [rest @ .., last] if !rest.is_empty() => {
+                    format!("{}, and {last}", rest.join(", "))
+                }
+                _ => annotations.join(", "),
+            };
+            write!(
+                f,
+                "\n\n{}{} `{}` was declared via {annotations}",
+                "hint".bold().cyan(),
+                ":".bold(),
+                name.cyan(),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Get the packages that are involved in this error.
     pub fn packages(&self) -> impl Iterator<Item = &PackageName> {
         self.error
@@ -431,6 +515,28 @@ impl NoSolutionError {
     }
 }
 
+/// Return the (one-indexed) line on which a requirement for the given package was declared, if
+/// it was provided via a requirements file.
+fn source_line(annotation: &SourceAnnotation, name: &PackageName) -> Option<usize> {
+    let (SourceAnnotation::Requirement(RequirementOrigin::File(path))
+    | SourceAnnotation::Constraint(RequirementOrigin::File(path))
+    | SourceAnnotation::Override(RequirementOrigin::File(path))) = annotation
+    else {
+        return None;
+    };
+    let contents = fs_err::read_to_string(path).ok()?;
+    contents
+        .lines()
+        .position(|line| {
+            let line = line.trim_start();
+            let end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                .unwrap_or(line.len());
+            PackageName::from_str(&line[..end]).is_ok_and(|candidate| candidate == *name)
+        })
+        .map(|index| index + 1)
+}
+
 impl std::fmt::Debug for NoSolutionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // Include every field except `index`, which doesn't implement `Debug`.
@@ -451,6 +557,7 @@ impl std::fmt::Debug for NoSolutionError {
             current_environment,
             tags,
             workspace_members,
+            sources,
             options,
         } = self;
         f.debug_struct("NoSolutionError")
@@ -469,6 +576,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("current_environment", current_environment)
             .field("tags", tags)
             .field("workspace_members", workspace_members)
+            .field("sources", sources)
             .field("options", options)
             .finish()
     }
@@ -551,6 +659,7 @@ impl std::fmt::Display for NoSolutionError {
             write!(f, "\n\n{hint}")?;
         }
 
+        self.hint_requirement_sources(&tree, f)?;
         self.hint_disjoint_targets(f)?;
 
         Ok(())
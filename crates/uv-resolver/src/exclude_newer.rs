@@ -1,16 +1,19 @@
 #[cfg(feature = "schemars")]
 use std::borrow::Cow;
 use std::{
+    collections::BTreeMap,
     ops::{Deref, DerefMut},
     str::FromStr,
 };
 
-use jiff::{Timestamp, ToSpan, tz::TimeZone};
+use jiff::{SignedDuration, Timestamp, ToSpan, tz::TimeZone};
 use rustc_hash::FxHashMap;
 use uv_normalize::PackageName;
 
 /// A timestamp that excludes files newer than it.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize,
+)]
 pub struct ExcludeNewerTimestamp(Timestamp);
 
 impl ExcludeNewerTimestamp {
@@ -18,6 +21,22 @@ impl ExcludeNewerTimestamp {
     pub fn timestamp_millis(&self) -> i64 {
         self.0.as_millisecond()
     }
+
+    /// Returns the timestamp the given number of days before the current time.
+    ///
+    /// Saturates to the minimum timestamp if the number of days is out of range.
+    pub fn days_ago(days: u64) -> Self {
+        let timestamp = days
+            .checked_mul(24 * 60 * 60)
+            .and_then(|seconds| i64::try_from(seconds).ok())
+            .and_then(|seconds| {
+                Timestamp::now()
+                    .checked_sub(SignedDuration::from_secs(seconds))
+                    .ok()
+            })
+            .unwrap_or(Timestamp::MIN);
+        Self(timestamp)
+    }
 }
 
 impl From<Timestamp> for ExcludeNewerTimestamp {
@@ -170,6 +189,17 @@ pub struct ExcludeNewer {
     /// Per-package timestamps that override the global timestamp.
     #[serde(default, skip_serializing_if = "FxHashMap::is_empty")]
     pub package: ExcludeNewerPackage,
+    /// Global minimum age, in days, that applies to all packages if no package-specific minimum
+    /// age is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global_days: Option<u64>,
+    /// Per-package minimum ages, in days, that override the global minimum age.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub package_days: BTreeMap<PackageName, u64>,
+    /// Whether the global timestamp is a "time machine" cutoff, in which case files that were
+    /// yanked after the cutoff are considered available.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub as_of: bool,
 }
 
 impl ExcludeNewer {
@@ -177,13 +207,17 @@ impl ExcludeNewer {
     pub fn global(global: ExcludeNewerTimestamp) -> Self {
         Self {
             global: Some(global),
-            package: ExcludeNewerPackage::default(),
+            ..Self::default()
         }
     }
 
     /// Create a new exclude newer configuration.
     pub fn new(global: Option<ExcludeNewerTimestamp>, package: ExcludeNewerPackage) -> Self {
-        Self { global, package }
+        Self {
+            global,
+            package,
+            ..Self::default()
+        }
     }
 
     /// Create from CLI arguments.
@@ -193,37 +227,88 @@ impl ExcludeNewer {
     ) -> Self {
         let package: ExcludeNewerPackage = package.into_iter().collect();
 
-        Self { global, package }
+        Self {
+            global,
+            package,
+            ..Self::default()
+        }
+    }
+
+    /// Set the minimum age, in days, for all packages and for specific packages.
+    #[must_use]
+    pub fn with_days(mut self, global: Option<u64>, package: BTreeMap<PackageName, u64>) -> Self {
+        self.global_days = global;
+        self.package_days = package;
+        self
+    }
+
+    /// Mark the global timestamp as a "time machine" cutoff.
+    ///
+    /// In this mode, yanked files are considered available if the index reports that they were
+    /// yanked after the cutoff. Files without a reported yank time retain their yank status.
+    #[must_use]
+    pub fn with_as_of(mut self, as_of: bool) -> Self {
+        self.as_of = as_of;
+        self
     }
 
     /// Returns the timestamp for a specific package, falling back to the global timestamp if set.
+    ///
+    /// If a minimum age is set for the package (or globally), the earlier of the timestamp and the
+    /// minimum age cutoff is returned.
     pub fn exclude_newer_package(
         &self,
         package_name: &PackageName,
     ) -> Option<ExcludeNewerTimestamp> {
-        self.package.get(package_name).copied().or(self.global)
+        let timestamp = self.package.get(package_name).copied().or(self.global);
+        let cutoff = self
+            .package_days
+            .get(package_name)
+            .copied()
+            .or(self.global_days)
+            .map(ExcludeNewerTimestamp::days_ago);
+        match (timestamp, cutoff) {
+            (Some(timestamp), Some(cutoff)) => Some(timestamp.min(cutoff)),
+            (timestamp, cutoff) => timestamp.or(cutoff),
+        }
     }
 
     /// Returns true if this has any configuration (global or per-package).
     pub fn is_empty(&self) -> bool {
-        self.global.is_none() && self.package.is_empty()
+        self.global.is_none()
+            && self.package.is_empty()
+            && self.global_days.is_none()
+            && self.package_days.is_empty()
     }
 }
 
 impl std::fmt::Display for ExcludeNewer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(global) = self.global {
-            write!(f, "global: {global}")?;
-            if !self.package.is_empty() {
-                write!(f, ", ")?;
+        let global = self.global.iter().map(|global| {
+            if self.as_of {
+                format!("as of: {global}")
+            } else {
+                format!("global: {global}")
             }
-        }
+        });
+        let package = self
+            .package
+            .iter()
+            .map(|(name, timestamp)| format!("{name}: {timestamp}"));
+        let global_days = self
+            .global_days
+            .iter()
+            .map(|days| format!("global: {days} days"));
+        let package_days = self
+            .package_days
+            .iter()
+            .map(|(name, days)| format!("{name}: {days} days"));
         let mut first = true;
-        for (name, timestamp) in &self.package {
+        for entry in global.chain(package).chain(global_days).chain(package_days) {
             if !first {
                 write!(f, ", ")?;
             }
-            write!(f, "{name}: {timestamp}")?;
+            write!(f, "{entry}")?;
             first = false;
         }
         Ok(())
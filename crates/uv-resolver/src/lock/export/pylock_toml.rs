@@ -1370,6 +1370,7 @@ impl PylockTomlWheel {
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
             url: FileLocation::AbsoluteUrl(file_url),
             yanked: None,
+            yanked_time_utc_ms: None,
             zstd: None,
         });
 
@@ -1527,6 +1528,7 @@ impl PylockTomlSdist {
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
             url: FileLocation::AbsoluteUrl(file_url),
             yanked: None,
+            yanked_time_utc_ms: None,
             zstd: None,
         });
 
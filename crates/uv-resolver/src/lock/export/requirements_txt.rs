@@ -205,11 +205,15 @@ impl<'lock> From<&'lock Package> for RequirementComparator<'lock> {
 
 /// Modify a relative [`Path`] to anchor it at the current working directory.
 ///
-/// For example, given `foo/bar`, returns `./foo/bar`.
+/// For example, given `foo/bar`, returns `./foo/bar`. Paths that start with a variable (e.g.,
+/// `${WORKSPACE_ROOT}/foo`) are returned as-is.
 fn anchor(path: &Path) -> Cow<'_, Path> {
     match path.components().next() {
         None => Cow::Owned(PathBuf::from(".")),
         Some(Component::CurDir | Component::ParentDir) => Cow::Borrowed(path),
+        Some(Component::Normal(first)) if first.to_string_lossy().starts_with("${") => {
+            Cow::Borrowed(path)
+        }
         _ => Cow::Owned(PathBuf::from("./").join(path)),
     }
 }
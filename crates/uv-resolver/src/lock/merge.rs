@@ -0,0 +1,232 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use uv_normalize::PackageName;
+
+use crate::lock::{Lock, LockError, Package};
+
+/// The result of merging two lockfiles with [`Lock::merge`].
+#[derive(Debug)]
+pub struct LockMerge {
+    /// The merged lockfile.
+    lock: Lock,
+    /// The packages that were changed on both sides of the merge, in different ways.
+    conflicts: BTreeSet<PackageName>,
+}
+
+impl LockMerge {
+    /// Return the merged [`Lock`].
+    pub fn lock(&self) -> &Lock {
+        &self.lock
+    }
+
+    /// Return the merged [`Lock`], consuming the [`LockMerge`].
+    pub fn into_lock(self) -> Lock {
+        self.lock
+    }
+
+    /// Return the packages that were changed on both sides of the merge, in different ways.
+    ///
+    /// For these packages, the merged lockfile retains the entries from `ours`.
+    pub fn conflicts(&self) -> &BTreeSet<PackageName> {
+        &self.conflicts
+    }
+}
+
+impl Lock {
+    /// Merge two lockfiles that were derived from a common ancestor.
+    ///
+    /// Packages are merged by name. If only one side changed the entries for a given package,
+    /// relative to `base`, the changed entries are retained. If both sides changed a package in
+    /// different ways, the package is marked as conflicting and the entries from `ours` are
+    /// retained. Without a `base`, any package that differs between the two sides is treated as
+    /// conflicting.
+    ///
+    /// The top-level lockfile metadata (e.g., `requires-python`) is taken from `ours`, since it's
+    /// derived from the project itself and re-validated on the next resolution.
+    pub fn merge(ours: Self, base: Option<&Self>, theirs: &Self) -> Result<LockMerge, LockError> {
+        let ours_packages = packages_by_name(&ours);
+        let base_packages = base.map(packages_by_name);
+        let theirs_packages = packages_by_name(theirs);
+
+        let names = ours_packages
+            .keys()
+            .chain(theirs_packages.keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut packages = Vec::with_capacity(ours.packages.len());
+        let mut conflicts = BTreeSet::default();
+        for name in names {
+            let ours = ours_packages.get(name);
+            let theirs = theirs_packages.get(name);
+
+            let merged = if ours == theirs {
+                ours
+            } else if let Some(base_packages) = base_packages.as_ref() {
+                let base = base_packages.get(name);
+                if base == ours {
+                    theirs
+                } else if base == theirs {
+                    ours
+                } else {
+                    conflicts.insert(name.clone());
+                    ours.or(theirs)
+                }
+            } else {
+                conflicts.insert(name.clone());
+                ours.or(theirs)
+            };
+
+            if let Some(merged) = merged {
+                packages.extend(merged.iter().map(|package| (*package).clone()));
+            }
+        }
+
+        let mut lock = Self::new(
+            ours.version,
+            ours.revision,
+            packages,
+            ours.requires_python,
+            ours.options,
+            ours.manifest,
+            ours.conflicts,
+            ours.supported_environments,
+            ours.required_environments,
+            ours.fork_markers,
+        )?;
+        lock.environment_assumptions = ours.environment_assumptions;
+
+        Ok(LockMerge { lock, conflicts })
+    }
+}
+
+/// Group the packages in a [`Lock`] by name.
+fn packages_by_name(lock: &Lock) -> BTreeMap<&PackageName, Vec<&Package>> {
+    let mut packages: BTreeMap<&PackageName, Vec<&Package>> = BTreeMap::default();
+    for package in &lock.packages {
+        packages.entry(package.name()).or_default().push(package);
+    }
+    packages
+}
+
+/// The sides of a lockfile that contains Git merge conflict markers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictedLock {
+    /// The contents of the lockfile on the current branch.
+    pub ours: String,
+    /// The contents of the lockfile in the common ancestor, if the conflict markers include the
+    /// base (as with `merge.conflictStyle = diff3`).
+    pub base: Option<String>,
+    /// The contents of the lockfile on the branch being merged.
+    pub theirs: String,
+}
+
+impl ConflictedLock {
+    /// Split the contents of a lockfile with Git merge conflict markers into its sides.
+    ///
+    /// Returns `None` if the lockfile doesn't contain any conflict markers, or if the markers are
+    /// malformed.
+    pub fn parse(contents: &str) -> Option<Self> {
+        /// The section of the conflicted file that a given line belongs to.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Section {
+            Common,
+            Ours,
+            Base,
+            Theirs,
+        }
+
+        let mut ours = String::with_capacity(contents.len());
+        let mut base = String::with_capacity(contents.len());
+        let mut theirs = String::with_capacity(contents.len());
+        let mut has_conflicts = false;
+        let mut has_base = false;
+
+        let mut section = Section::Common;
+        for line in contents.split_inclusive('\n') {
+            if is_marker(line, "<<<<<<<") {
+                if section != Section::Common {
+                    return None;
+                }
+                has_conflicts = true;
+                section = Section::Ours;
+            } else if is_marker(line, "|||||||") {
+                if section != Section::Ours {
+                    return None;
+                }
+                has_base = true;
+                section = Section::Base;
+            } else if is_marker(line, "=======") {
+                if !matches!(section, Section::Ours | Section::Base) {
+                    return None;
+                }
+                section = Section::Theirs;
+            } else if is_marker(line, ">>>>>>>") {
+                if section != Section::Theirs {
+                    return None;
+                }
+                section = Section::Common;
+            } else {
+                match section {
+                    Section::Common => {
+                        ours.push_str(line);
+                        base.push_str(line);
+                        theirs.push_str(line);
+                    }
+                    Section::Ours => ours.push_str(line),
+                    Section::Base => base.push_str(line),
+                    Section::Theirs => theirs.push_str(line),
+                }
+            }
+        }
+
+        if !has_conflicts || section != Section::Common {
+            return None;
+        }
+
+        Some(Self {
+            ours,
+            base: has_base.then_some(base),
+            theirs,
+        })
+    }
+}
+
+/// Returns `true` if the line is a conflict marker of the given kind (e.g., `<<<<<<< HEAD`).
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\n', '\r']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicted_lock() {
+        let contents = "version = 1\n<<<<<<< HEAD\nours = 1\n=======\ntheirs = 1\n>>>>>>> feature\nrevision = 3\n";
+        let conflicted = ConflictedLock::parse(contents).unwrap();
+        assert_eq!(conflicted.ours, "version = 1\nours = 1\nrevision = 3\n");
+        assert_eq!(conflicted.base, None);
+        assert_eq!(conflicted.theirs, "version = 1\ntheirs = 1\nrevision = 3\n");
+    }
+
+    #[test]
+    fn conflicted_lock_diff3() {
+        let contents = "<<<<<<< HEAD\nours = 1\n||||||| base\nbase = 1\n=======\ntheirs = 1\n>>>>>>> feature\n";
+        let conflicted = ConflictedLock::parse(contents).unwrap();
+        assert_eq!(conflicted.ours, "ours = 1\n");
+        assert_eq!(conflicted.base.as_deref(), Some("base = 1\n"));
+        assert_eq!(conflicted.theirs, "theirs = 1\n");
+    }
+
+    #[test]
+    fn conflicted_lock_malformed() {
+        assert_eq!(ConflictedLock::parse("version = 1\n"), None);
+        assert_eq!(
+            ConflictedLock::parse("<<<<<<< HEAD\nours = 1\n=======\ntheirs = 1\n"),
+            None
+        );
+        assert_eq!(ConflictedLock::parse("=======\n>>>>>>> feature\n"), None);
+    }
+}
@@ -36,7 +36,10 @@ use uv_git::{RepositoryReference, ResolvedRepositoryReference};
 use uv_git_types::{GitOid, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
-use uv_pep508::{MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError, split_scheme};
+use uv_pep508::{
+    MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError, contains_env_vars,
+    expand_path_vars, split_scheme,
+};
 use uv_platform_tags::{
     AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
 };
@@ -44,6 +47,7 @@ use uv_pypi_types::{
     ConflictKind, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
     ParsedGitUrl, PyProjectToml,
 };
+use uv_python::ImplementationName;
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_types::{BuildContext, HashStrategy};
@@ -55,8 +59,9 @@ pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
+pub use crate::lock::merge::{ConflictedLock, LockMerge};
 pub use crate::lock::tree::TreeDisplay;
-use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::resolution::{AnnotatedDist, InsecureHashError, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     ExcludeNewer, ExcludeNewerTimestamp, InMemoryIndex, MetadataResponse, PrereleaseMode,
@@ -66,6 +71,7 @@ use crate::{
 mod export;
 mod installable;
 mod map;
+mod merge;
 mod tree;
 
 /// The current version of the lockfile format.
@@ -197,6 +203,10 @@ pub struct Lock {
     supported_environments: Vec<MarkerTree>,
     /// The list of required platforms specified by the user.
     required_environments: Vec<MarkerTree>,
+    /// The markers that are assumed to hold in every environment, as specified by the user.
+    ///
+    /// These are omitted from the markers written to the lockfile, and restored when it's read.
+    environment_assumptions: MarkerTree,
     /// The range of supported Python versions.
     requires_python: RequiresPython,
     /// We discard the lockfile if these options don't match.
@@ -217,6 +227,9 @@ pub struct Lock {
     by_id: FxHashMap<PackageId, usize>,
     /// The input requirements to the resolution.
     manifest: ResolverManifest,
+    /// A hash of the lockfile contents (excluding the hash itself), used to detect lockfiles that
+    /// were modified outside of uv.
+    integrity: Option<String>,
 }
 
 impl Lock {
@@ -262,7 +275,12 @@ impl Lock {
                 vec![]
             };
 
-            let mut package = Package::from_annotated_dist(dist, fork_markers, root)?;
+            let mut package = Package::from_annotated_dist(
+                dist,
+                fork_markers,
+                root,
+                resolution.options.allow_insecure_md5_hashes,
+            )?;
             Self::remove_unreachable_wheels(resolution, &requires_python, node_index, &mut package);
 
             // Add all dependencies
@@ -342,15 +360,28 @@ impl Lock {
         }
 
         let packages = packages.into_values().collect();
-        let (exclude_newer, exclude_newer_package) = {
+        let (exclude_newer, exclude_newer_package, as_of) = {
             let exclude_newer = &resolution.options.exclude_newer;
-            let global_exclude_newer = exclude_newer.global;
+            let (global_exclude_newer, as_of) = if exclude_newer.as_of {
+                (None, exclude_newer.global)
+            } else {
+                (exclude_newer.global, None)
+            };
             let package_exclude_newer = if exclude_newer.package.is_empty() {
                 None
             } else {
                 Some(exclude_newer.package.clone().into_inner())
             };
-            (global_exclude_newer, package_exclude_newer)
+            (global_exclude_newer, package_exclude_newer, as_of)
+        };
+        let (exclude_newer_than_days, exclude_newer_than_days_package) = {
+            let exclude_newer = &resolution.options.exclude_newer;
+            let package_days = if exclude_newer.package_days.is_empty() {
+                None
+            } else {
+                Some(exclude_newer.package_days.clone())
+            };
+            (exclude_newer.global_days, package_days)
         };
 
         let options = ResolverOptions {
@@ -359,6 +390,9 @@ impl Lock {
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer,
             exclude_newer_package,
+            exclude_newer_than_days,
+            exclude_newer_than_days_package,
+            as_of,
         };
         let lock = Self::new(
             VERSION,
@@ -706,11 +740,13 @@ impl Lock {
             conflicts,
             supported_environments,
             required_environments,
+            environment_assumptions: MarkerTree::TRUE,
             requires_python,
             options,
             packages,
             by_id,
             manifest,
+            integrity: None,
         };
         Ok(lock)
     }
@@ -722,6 +758,13 @@ impl Lock {
         self
     }
 
+    /// Record the integrity hash of the lockfile contents.
+    #[must_use]
+    pub fn with_integrity(mut self, integrity: Option<String>) -> Self {
+        self.integrity = integrity;
+        self
+    }
+
     /// Record the conflicting groups that were used to generate this lock.
     #[must_use]
     pub fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
@@ -758,6 +801,58 @@ impl Lock {
         self
     }
 
+    /// Record the environment assumptions that were used to generate this lock.
+    ///
+    /// The assumptions are added to the markers of every dependency and fork, and the markers
+    /// written to the lockfile are simplified accordingly.
+    #[must_use]
+    pub fn with_environment_assumptions(mut self, environment_assumptions: MarkerTree) -> Self {
+        if environment_assumptions.is_true() {
+            return self;
+        }
+        self.assume_environments(environment_assumptions);
+        for package in &mut self.packages {
+            for dependency in package
+                .dependencies
+                .iter_mut()
+                .chain(package.optional_dependencies.values_mut().flatten())
+                .chain(package.dependency_groups.values_mut().flatten())
+            {
+                dependency.simplified_marker = SimplifiedMarkerTree::new(
+                    &self.requires_python,
+                    dependency.complexified_marker.combined(),
+                )
+                .assuming(environment_assumptions);
+            }
+        }
+        self
+    }
+
+    /// Add the given environment assumptions to the markers of every dependency and fork.
+    fn assume_environments(&mut self, environment_assumptions: MarkerTree) {
+        self.environment_assumptions = environment_assumptions;
+        if environment_assumptions.is_true() {
+            return;
+        }
+        let assumption = UniversalMarker::from_combined(environment_assumptions);
+        for marker in &mut self.fork_markers {
+            marker.and(assumption);
+        }
+        for package in &mut self.packages {
+            for marker in &mut package.fork_markers {
+                marker.and(assumption);
+            }
+            for dependency in package
+                .dependencies
+                .iter_mut()
+                .chain(package.optional_dependencies.values_mut().flatten())
+                .chain(package.dependency_groups.values_mut().flatten())
+            {
+                dependency.complexified_marker.and(assumption);
+            }
+        }
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -781,6 +876,11 @@ impl Lock {
         self.revision
     }
 
+    /// Returns the integrity hash recorded in the lockfile, if any.
+    pub fn integrity(&self) -> Option<&str> {
+        self.integrity.as_deref()
+    }
+
     /// Returns the number of packages in the lockfile.
     pub fn len(&self) -> usize {
         self.packages.len()
@@ -836,6 +936,42 @@ impl Lock {
         &self.required_environments
     }
 
+    /// Returns the environment assumptions that were used to generate this lock.
+    pub fn environment_assumptions(&self) -> MarkerTree {
+        self.environment_assumptions
+    }
+
+    /// Returns the Python implementations supported by the lockfile, as determined by the wheels
+    /// of the packages that can only be installed from wheels.
+    ///
+    /// Returns `None` if every package can be installed on any implementation, i.e., if every
+    /// package includes a source distribution or a wheel that isn't specific to an implementation.
+    pub fn supported_implementations(&self) -> Option<BTreeSet<ImplementationName>> {
+        self.packages
+            .iter()
+            .filter_map(Package::wheel_implementations)
+            .reduce(|left, right| left.intersection(&right).copied().collect())
+    }
+
+    /// Returns the packages that can only be installed from wheels, but have no wheel for the
+    /// given Python implementation, along with the implementations that their wheels support.
+    pub fn unsupported_packages(
+        &self,
+        implementation: ImplementationName,
+    ) -> Vec<(&Package, BTreeSet<ImplementationName>)> {
+        self.packages
+            .iter()
+            .filter_map(|package| {
+                let implementations = package.wheel_implementations()?;
+                if implementations.contains(&implementation) {
+                    None
+                } else {
+                    Some((package, implementations))
+                }
+            })
+            .collect()
+    }
+
     /// Returns the workspace members that were used to generate this lock.
     pub fn members(&self) -> &BTreeSet<PackageName> {
         &self.manifest.members
@@ -872,6 +1008,57 @@ impl Lock {
         })
     }
 
+    /// Returns the direct dependencies of the workspace members through which each package in
+    /// the lockfile is required.
+    ///
+    /// Each direct dependency is included in its own set. Workspace members are omitted.
+    pub fn required_by(&self) -> BTreeMap<&PackageName, BTreeSet<&PackageName>> {
+        /// Iterate over all the dependencies of a package, including any extras and groups.
+        fn edges(package: &Package) -> impl Iterator<Item = &PackageId> {
+            package
+                .dependencies
+                .iter()
+                .chain(package.optional_dependencies.values().flatten())
+                .chain(package.dependency_groups.values().flatten())
+                .map(|dependency| &dependency.package_id)
+        }
+
+        let members: Vec<&Package> = if self.members().is_empty() {
+            self.root().into_iter().collect()
+        } else {
+            self.packages
+                .iter()
+                .filter(|package| self.members().contains(&package.id.name))
+                .collect()
+        };
+        let is_member = |id: &PackageId| members.iter().any(|member| member.id == *id);
+
+        let mut required_by: BTreeMap<&PackageName, BTreeSet<&PackageName>> = BTreeMap::new();
+        for member in &members {
+            for direct in edges(member) {
+                if is_member(direct) {
+                    continue;
+                }
+
+                let mut seen = FxHashSet::default();
+                let mut queue = VecDeque::from([direct]);
+                seen.insert(direct);
+                while let Some(id) = queue.pop_front() {
+                    required_by
+                        .entry(&id.name)
+                        .or_default()
+                        .insert(&direct.name);
+                    for dependency in edges(self.find_by_id(id)) {
+                        if !is_member(dependency) && seen.insert(dependency) {
+                            queue.push_back(dependency);
+                        }
+                    }
+                }
+            }
+        }
+        required_by
+    }
+
     /// Returns the supported environments that were used to generate this
     /// lock.
     ///
@@ -911,6 +1098,14 @@ impl Lock {
         self.fork_markers.as_slice()
     }
 
+    /// Remove the fork markers from the [`Lock`], such that a resolution seeded from it retains
+    /// the locked versions, but is free to choose its own forks.
+    #[must_use]
+    pub fn without_fork_markers(mut self) -> Self {
+        self.fork_markers = vec![];
+        self
+    }
+
     /// Checks whether the fork markers cover the entire supported marker space.
     ///
     /// Returns the actually covered and the expected marker space on validation error.
@@ -935,6 +1130,7 @@ impl Lock {
         };
         // When a user defines environments, they are implicitly constrained by requires-python.
         environments_union.and(self.requires_python.to_marker_tree());
+        environments_union.and(self.environment_assumptions);
         if fork_markers_union.negate().is_disjoint(environments_union) {
             Ok(())
         } else {
@@ -987,11 +1183,20 @@ impl Lock {
             doc.insert("revision", value(i64::from(self.revision)));
         }
 
+        if let Some(integrity) = &self.integrity {
+            doc.insert("integrity", value(integrity.as_str()));
+        }
+
         doc.insert("requires-python", value(self.requires_python.to_string()));
 
         if !self.fork_markers.is_empty() {
             let fork_markers = each_element_on_its_line_array(
-                simplified_universal_markers(&self.fork_markers, &self.requires_python).into_iter(),
+                simplified_universal_markers(
+                    &self.fork_markers,
+                    &self.requires_python,
+                    self.environment_assumptions,
+                )
+                .into_iter(),
             );
             if !fork_markers.is_empty() {
                 doc.insert("resolution-markers", value(fork_markers));
@@ -1020,6 +1225,10 @@ impl Lock {
             doc.insert("required-markers", value(required_environments));
         }
 
+        if let Some(environment_assumptions) = self.environment_assumptions.try_to_string() {
+            doc.insert("assumed-markers", value(environment_assumptions));
+        }
+
         if !self.conflicts.is_empty() {
             let mut list = Array::new();
             for set in self.conflicts.iter() {
@@ -1067,9 +1276,14 @@ impl Lock {
             }
             let exclude_newer = &self.options.exclude_newer();
             if !exclude_newer.is_empty() {
-                // Always serialize global exclude-newer as a string
+                // Always serialize global exclude-newer as a string, recording a "time machine"
+                // cutoff separately
                 if let Some(global) = exclude_newer.global {
-                    options_table.insert("exclude-newer", value(global.to_string()));
+                    if exclude_newer.as_of {
+                        options_table.insert("as-of", value(global.to_string()));
+                    } else {
+                        options_table.insert("exclude-newer", value(global.to_string()));
+                    }
                 }
 
                 // Serialize package-specific exclusions as a separate field
@@ -1080,6 +1294,28 @@ impl Lock {
                     }
                     options_table.insert("exclude-newer-package", Item::Table(package_table));
                 }
+
+                // Serialize the minimum age (rather than the derived timestamp), such that the
+                // lockfile remains valid as time passes
+                if let Some(days) = exclude_newer.global_days {
+                    options_table.insert(
+                        "exclude-newer-than-days",
+                        value(i64::try_from(days).unwrap_or(i64::MAX)),
+                    );
+                }
+                if !exclude_newer.package_days.is_empty() {
+                    let mut package_table = toml_edit::Table::new();
+                    for (name, days) in &exclude_newer.package_days {
+                        package_table.insert(
+                            name.as_ref(),
+                            value(i64::try_from(*days).unwrap_or(i64::MAX)),
+                        );
+                    }
+                    options_table.insert(
+                        "exclude-newer-than-days-package",
+                        Item::Table(package_table),
+                    );
+                }
             }
 
             if !options_table.is_empty() {
@@ -1163,6 +1399,32 @@ impl Lock {
                 manifest_table.insert("overrides", value(overrides));
             }
 
+            if !self.manifest.scoped_overrides.is_empty() {
+                let mut scoped_overrides = Table::new();
+                for (dependent, requirements) in &self.manifest.scoped_overrides {
+                    let requirements = requirements
+                        .iter()
+                        .map(|requirement| {
+                            serde::Serialize::serialize(
+                                &requirement,
+                                toml_edit::ser::ValueSerializer::new(),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let requirements = match requirements.as_slice() {
+                        [] => Array::new(),
+                        [requirement] => Array::from_iter([requirement]),
+                        requirements => each_element_on_its_line_array(requirements.iter()),
+                    };
+                    if !requirements.is_empty() {
+                        scoped_overrides.insert(dependent.as_ref(), value(requirements));
+                    }
+                }
+                if !scoped_overrides.is_empty() {
+                    manifest_table.insert("scoped-overrides", Item::Table(scoped_overrides));
+                }
+            }
+
             if !self.manifest.build_constraints.is_empty() {
                 let build_constraints = self
                     .manifest
@@ -1259,7 +1521,11 @@ impl Lock {
 
         let mut packages = ArrayOfTables::new();
         for dist in &self.packages {
-            packages.push(dist.to_toml(&self.requires_python, &dist_count_by_name)?);
+            packages.push(dist.to_toml(
+                &self.requires_python,
+                self.environment_assumptions,
+                &dist_count_by_name,
+            )?);
         }
 
         doc.insert("package", Item::ArrayOfTables(packages));
@@ -1447,6 +1713,7 @@ impl Lock {
         requirements: &[Requirement],
         constraints: &[Requirement],
         overrides: &[Requirement],
+        scoped_overrides: &[(PackageName, Requirement)],
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
@@ -1562,6 +1829,42 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same scoped overrides.
+        {
+            let mut expected: BTreeMap<PackageName, BTreeSet<Requirement>> = BTreeMap::new();
+            for (dependent, requirement) in scoped_overrides {
+                expected
+                    .entry(dependent.clone())
+                    .or_default()
+                    .insert(normalize_requirement(
+                        requirement.clone(),
+                        root,
+                        &self.requires_python,
+                    )?);
+            }
+            let actual: BTreeMap<PackageName, BTreeSet<Requirement>> = self
+                .manifest
+                .scoped_overrides
+                .iter()
+                .filter(|(_, requirements)| !requirements.is_empty())
+                .map(|(dependent, requirements)| {
+                    Ok::<_, LockError>((
+                        dependent.clone(),
+                        requirements
+                            .iter()
+                            .cloned()
+                            .map(|requirement| {
+                                normalize_requirement(requirement, root, &self.requires_python)
+                            })
+                            .collect::<Result<_, _>>()?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?;
+            if expected != actual {
+                return Ok(SatisfiesResult::MismatchedScopedOverrides(expected, actual));
+            }
+        }
+
         // Validate that the lockfile was generated with the same build constraints.
         {
             let expected: BTreeSet<_> = build_constraints
@@ -1808,7 +2111,7 @@ impl Lock {
                 // even if the version is dynamic, we can still extract the requirements without
                 // performing a build, unlike in the database where we typically construct a "complete"
                 // metadata object.
-                let parent = root.join(source_tree);
+                let parent = root.join(expand_path_vars(source_tree, root));
                 let path = parent.join("pyproject.toml");
                 let metadata =
                     match fs_err::tokio::read_to_string(&path).await {
@@ -2043,6 +2346,11 @@ pub enum SatisfiesResult<'lock> {
     MismatchedConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of overrides.
     MismatchedOverrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The lockfile uses a different set of scoped overrides.
+    MismatchedScopedOverrides(
+        BTreeMap<PackageName, BTreeSet<Requirement>>,
+        BTreeMap<PackageName, BTreeSet<Requirement>>,
+    ),
     /// The lockfile uses a different set of build constraints.
     MismatchedBuildConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of dependency groups.
@@ -2100,13 +2408,19 @@ struct ResolverOptions {
     exclude_newer: Option<ExcludeNewerTimestamp>,
     /// Package-specific [`ExcludeNewer`] timestamps.
     exclude_newer_package: Option<FxHashMap<PackageName, ExcludeNewerTimestamp>>,
+    /// The global [`ExcludeNewer`] minimum age, in days.
+    exclude_newer_than_days: Option<u64>,
+    /// Package-specific [`ExcludeNewer`] minimum ages, in days.
+    exclude_newer_than_days_package: Option<BTreeMap<PackageName, u64>>,
+    /// The "time machine" cutoff, used in lieu of the global [`ExcludeNewer`] timestamp.
+    as_of: Option<ExcludeNewerTimestamp>,
 }
 
 impl ResolverOptions {
     /// Get the combined exclude-newer configuration.
     fn exclude_newer(&self) -> ExcludeNewer {
         ExcludeNewer::from_args(
-            self.exclude_newer,
+            self.exclude_newer.or(self.as_of),
             self.exclude_newer_package
                 .clone()
                 .unwrap_or_default()
@@ -2114,6 +2428,13 @@ impl ResolverOptions {
                 .map(Into::into)
                 .collect(),
         )
+        .with_days(
+            self.exclude_newer_than_days,
+            self.exclude_newer_than_days_package
+                .clone()
+                .unwrap_or_default(),
+        )
+        .with_as_of(self.as_of.is_some())
     }
 }
 
@@ -2142,6 +2463,10 @@ pub struct ResolverManifest {
     /// The overrides provided to the resolver.
     #[serde(default)]
     overrides: BTreeSet<Requirement>,
+    /// The overrides provided to the resolver that only apply to the dependencies of a specific
+    /// package, keyed by the name of that package.
+    #[serde(default)]
+    scoped_overrides: BTreeMap<PackageName, BTreeSet<Requirement>>,
     /// The build constraints provided to the resolver.
     #[serde(default)]
     build_constraints: BTreeSet<Requirement>,
@@ -2158,15 +2483,21 @@ impl ResolverManifest {
         requirements: impl IntoIterator<Item = Requirement>,
         constraints: impl IntoIterator<Item = Requirement>,
         overrides: impl IntoIterator<Item = Requirement>,
+        scoped_overrides: impl IntoIterator<Item = (PackageName, Requirement)>,
         build_constraints: impl IntoIterator<Item = Requirement>,
         dependency_groups: impl IntoIterator<Item = (GroupName, Vec<Requirement>)>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
     ) -> Self {
+        let mut scoped = BTreeMap::<PackageName, BTreeSet<Requirement>>::new();
+        for (dependent, requirement) in scoped_overrides {
+            scoped.entry(dependent).or_default().insert(requirement);
+        }
         Self {
             members: members.into_iter().collect(),
             requirements: requirements.into_iter().collect(),
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
+            scoped_overrides: scoped,
             build_constraints: build_constraints.into_iter().collect(),
             dependency_groups: dependency_groups
                 .into_iter()
@@ -2195,6 +2526,19 @@ impl ResolverManifest {
                 .into_iter()
                 .map(|requirement| requirement.relative_to(root))
                 .collect::<Result<BTreeSet<_>, _>>()?,
+            scoped_overrides: self
+                .scoped_overrides
+                .into_iter()
+                .map(|(dependent, requirements)| {
+                    Ok::<_, io::Error>((
+                        dependent,
+                        requirements
+                            .into_iter()
+                            .map(|requirement| requirement.relative_to(root))
+                            .collect::<Result<BTreeSet<_>, _>>()?,
+                    ))
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()?,
             build_constraints: self
                 .build_constraints
                 .into_iter()
@@ -2223,6 +2567,7 @@ impl ResolverManifest {
 struct LockWire {
     version: u32,
     revision: Option<u32>,
+    integrity: Option<String>,
     requires_python: RequiresPython,
     /// If this lockfile was built from a forking resolution with non-identical forks, store the
     /// forks in the lockfile so we can recreate them in subsequent resolutions.
@@ -2232,6 +2577,8 @@ struct LockWire {
     supported_environments: Vec<SimplifiedMarkerTree>,
     #[serde(rename = "required-markers", default)]
     required_environments: Vec<SimplifiedMarkerTree>,
+    #[serde(rename = "assumed-markers", default)]
+    environment_assumptions: MarkerTree,
     #[serde(rename = "conflicts", default)]
     conflicts: Option<Conflicts>,
     /// We discard the lockfile if these options match.
@@ -2285,7 +2632,7 @@ impl TryFrom<LockWire> for Lock {
             .map(|simplified_marker| simplified_marker.into_marker(&wire.requires_python))
             .map(UniversalMarker::from_combined)
             .collect();
-        let lock = Self::new(
+        let mut lock = Self::new(
             wire.version,
             wire.revision.unwrap_or(0),
             packages,
@@ -2297,8 +2644,11 @@ impl TryFrom<LockWire> for Lock {
             required_environments,
             fork_markers,
         )?;
+        // The markers in the lockfile were simplified under the environment assumptions, so we
+        // restore them here.
+        lock.assume_environments(wire.environment_assumptions);
 
-        Ok(lock)
+        Ok(lock.with_integrity(wire.integrity))
     }
 }
 
@@ -2344,10 +2694,12 @@ impl Package {
         annotated_dist: &AnnotatedDist,
         fork_markers: Vec<UniversalMarker>,
         root: &Path,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Self, LockError> {
         let id = PackageId::from_annotated_dist(annotated_dist, root)?;
-        let sdist = SourceDist::from_annotated_dist(&id, annotated_dist)?;
-        let wheels = Wheel::from_annotated_dist(annotated_dist)?;
+        let sdist =
+            SourceDist::from_annotated_dist(&id, annotated_dist, allow_insecure_md5_hashes)?;
+        let wheels = Wheel::from_annotated_dist(annotated_dist, allow_insecure_md5_hashes)?;
         let requires_dist = if id.source.is_immutable() {
             BTreeSet::default()
         } else {
@@ -2624,6 +2976,43 @@ impl Package {
         }
     }
 
+    /// Returns the Python implementations supported by the package's wheels, if the package can
+    /// only be installed from wheels.
+    ///
+    /// Returns `None` if the package has a source distribution, is built from source, or has a
+    /// wheel that isn't specific to an implementation (e.g., `py3-none-any`).
+    fn wheel_implementations(&self) -> Option<BTreeSet<ImplementationName>> {
+        if self.sdist.is_some()
+            || self.wheels.is_empty()
+            || self.id.source.is_source_tree()
+            || matches!(self.id.source, Source::Git(..))
+        {
+            return None;
+        }
+
+        let mut implementations = BTreeSet::new();
+        for tag in self
+            .wheels
+            .iter()
+            .flat_map(|wheel| wheel.filename.python_tags())
+        {
+            match tag {
+                LanguageTag::None | LanguageTag::Python { .. } => return None,
+                LanguageTag::CPython { .. } => {
+                    implementations.insert(ImplementationName::CPython);
+                }
+                LanguageTag::PyPy { .. } => {
+                    implementations.insert(ImplementationName::PyPy);
+                }
+                LanguageTag::GraalPy { .. } => {
+                    implementations.insert(ImplementationName::GraalPy);
+                }
+                LanguageTag::Pyston { .. } => {}
+            }
+        }
+        Some(implementations)
+    }
+
     /// Generate a [`WheelTagHint`] based on wheel-tag incompatibilities.
     fn tag_hint(&self, tag_policy: TagPolicy<'_>) -> Option<WheelTagHint> {
         let filenames = self
@@ -2792,6 +3181,7 @@ impl Package {
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
                     url: FileLocation::AbsoluteUrl(file_url.clone()),
                     yanked: None,
+                    yanked_time_utc_ms: None,
                     zstd: None,
                 });
 
@@ -2867,6 +3257,7 @@ impl Package {
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
                     url: file_url,
                     yanked: None,
+                    yanked_time_utc_ms: None,
                     zstd: None,
                 });
 
@@ -2893,6 +3284,7 @@ impl Package {
     fn to_toml(
         &self,
         requires_python: &RequiresPython,
+        environment_assumptions: MarkerTree,
         dist_count_by_name: &FxHashMap<PackageName, u64>,
     ) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
@@ -2901,7 +3293,12 @@ impl Package {
 
         if !self.fork_markers.is_empty() {
             let fork_markers = each_element_on_its_line_array(
-                simplified_universal_markers(&self.fork_markers, requires_python).into_iter(),
+                simplified_universal_markers(
+                    &self.fork_markers,
+                    requires_python,
+                    environment_assumptions,
+                )
+                .into_iter(),
             );
             if !fork_markers.is_empty() {
                 table.insert("resolution-markers", value(fork_markers));
@@ -3194,13 +3591,57 @@ fn verbatim_url(path: &Path, id: &PackageId) -> Result<VerbatimUrl, LockError> {
     Ok(url)
 }
 
-/// Attempts to construct an absolute path from the given `Path`.
+/// Select the hash to record for an artifact provided by a registry, i.e., the digest computed
+/// with the strongest available algorithm.
+///
+/// Returns an error if the registry only provides insecure (i.e., MD5) hashes for the artifact,
+/// unless `allow_insecure_md5_hashes` is set.
+fn registry_hash(
+    name: &PackageName,
+    version: &Version,
+    hashes: &HashDigests,
+    allow_insecure_md5_hashes: bool,
+) -> Result<Option<Hash>, LockError> {
+    let Some(digest) = HashDigest::strongest(hashes.iter()) else {
+        return Ok(None);
+    };
+    if !digest.algorithm().is_secure() && !allow_insecure_md5_hashes {
+        return Err(LockErrorKind::InsecureHash(InsecureHashError {
+            name: name.clone(),
+            version: version.clone(),
+        })
+        .into());
+    }
+    Ok(Some(Hash::from(digest.clone())))
+}
+
+/// Attempts to construct an absolute path from the given `Path`, expanding any variables (e.g.,
+/// `${WORKSPACE_ROOT}`).
 fn absolute_path(workspace_root: &Path, path: &Path) -> Result<PathBuf, LockError> {
+    let path = expand_path_vars(path, workspace_root);
     let path = uv_fs::normalize_absolute_path(&workspace_root.join(path))
         .map_err(LockErrorKind::AbsolutePath)?;
     Ok(path)
 }
 
+/// Construct the path to a local distribution relative to the workspace root.
+///
+/// If the path was provided with variables (e.g., `${WORKSPACE_ROOT}/dist/foo.whl`), the
+/// unexpanded path is retained, such that the lockfile remains portable.
+fn relative_dist_path(
+    url: &VerbatimUrl,
+    install_path: &Path,
+    root: &Path,
+) -> Result<PathBuf, LockError> {
+    if let Some(given) = url.given().filter(|given| contains_env_vars(given)) {
+        return Ok(PathBuf::from(given));
+    }
+    let path = relative_to(install_path, root)
+        .or_else(|_| std::path::absolute(install_path))
+        .map_err(LockErrorKind::DistributionRelativePath)?;
+    Ok(path)
+}
+
 #[derive(Clone, Debug, serde::Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct PackageWire {
@@ -3540,16 +3981,12 @@ impl Source {
     }
 
     fn from_path_built_dist(path_dist: &PathBuiltDist, root: &Path) -> Result<Self, LockError> {
-        let path = relative_to(&path_dist.install_path, root)
-            .or_else(|_| std::path::absolute(&path_dist.install_path))
-            .map_err(LockErrorKind::DistributionRelativePath)?;
+        let path = relative_dist_path(&path_dist.url, &path_dist.install_path, root)?;
         Ok(Self::Path(path.into_boxed_path()))
     }
 
     fn from_path_source_dist(path_dist: &PathSourceDist, root: &Path) -> Result<Self, LockError> {
-        let path = relative_to(&path_dist.install_path, root)
-            .or_else(|_| std::path::absolute(&path_dist.install_path))
-            .map_err(LockErrorKind::DistributionRelativePath)?;
+        let path = relative_dist_path(&path_dist.url, &path_dist.install_path, root)?;
         Ok(Self::Path(path.into_boxed_path()))
     }
 
@@ -3557,9 +3994,7 @@ impl Source {
         directory_dist: &DirectorySourceDist,
         root: &Path,
     ) -> Result<Self, LockError> {
-        let path = relative_to(&directory_dist.install_path, root)
-            .or_else(|_| std::path::absolute(&directory_dist.install_path))
-            .map_err(LockErrorKind::DistributionRelativePath)?;
+        let path = relative_dist_path(&directory_dist.url, &directory_dist.install_path, root)?;
         if directory_dist.editable.unwrap_or(false) {
             Ok(Self::Editable(path.into_boxed_path()))
         } else if directory_dist.r#virtual.unwrap_or(false) {
@@ -4046,6 +4481,7 @@ impl SourceDist {
     fn from_annotated_dist(
         id: &PackageId,
         annotated_dist: &AnnotatedDist,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Option<Self>, LockError> {
         match annotated_dist.dist {
             // We pass empty installed packages for locking.
@@ -4055,6 +4491,7 @@ impl SourceDist {
                 dist,
                 annotated_dist.hashes.as_slice(),
                 annotated_dist.index(),
+                allow_insecure_md5_hashes,
             ),
         }
     }
@@ -4064,16 +4501,19 @@ impl SourceDist {
         dist: &Dist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Option<Self>, LockError> {
         match *dist {
             Dist::Built(BuiltDist::Registry(ref built_dist)) => {
                 let Some(sdist) = built_dist.sdist.as_ref() else {
                     return Ok(None);
                 };
-                Self::from_registry_dist(sdist, index)
+                Self::from_registry_dist(sdist, index, allow_insecure_md5_hashes)
             }
             Dist::Built(_) => Ok(None),
-            Dist::Source(ref source_dist) => Self::from_source_dist(id, source_dist, hashes, index),
+            Dist::Source(ref source_dist) => {
+                Self::from_source_dist(id, source_dist, hashes, index, allow_insecure_md5_hashes)
+            }
         }
     }
 
@@ -4082,10 +4522,11 @@ impl SourceDist {
         source_dist: &uv_distribution_types::SourceDist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Option<Self>, LockError> {
         match *source_dist {
             uv_distribution_types::SourceDist::Registry(ref reg_dist) => {
-                Self::from_registry_dist(reg_dist, index)
+                Self::from_registry_dist(reg_dist, index, allow_insecure_md5_hashes)
             }
             uv_distribution_types::SourceDist::DirectUrl(_) => {
                 Self::from_direct_dist(id, hashes).map(Some)
@@ -4104,6 +4545,7 @@ impl SourceDist {
     fn from_registry_dist(
         reg_dist: &RegistrySourceDist,
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Option<Self>, LockError> {
         // Reject distributions from registries that don't match the index URL, as can occur with
         // `--find-links`.
@@ -4116,7 +4558,12 @@ impl SourceDist {
                 let url = normalize_file_location(&reg_dist.file.url)
                     .map_err(LockErrorKind::InvalidUrl)
                     .map_err(LockError::from)?;
-                let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                let hash = registry_hash(
+                    &reg_dist.name,
+                    &reg_dist.version,
+                    &reg_dist.file.hashes,
+                    allow_insecure_md5_hashes,
+                )?;
                 let size = reg_dist.file.size;
                 let upload_time = reg_dist
                     .file
@@ -4151,7 +4598,12 @@ impl SourceDist {
                         .or_else(|_| std::path::absolute(&reg_dist_path))
                         .map_err(LockErrorKind::DistributionRelativePath)?
                         .into_boxed_path();
-                    let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                    let hash = registry_hash(
+                        &reg_dist.name,
+                        &reg_dist.version,
+                        &reg_dist.file.hashes,
+                        allow_insecure_md5_hashes,
+                    )?;
                     let size = reg_dist.file.size;
                     let upload_time = reg_dist
                         .file
@@ -4171,7 +4623,12 @@ impl SourceDist {
                     let url = normalize_file_location(&reg_dist.file.url)
                         .map_err(LockErrorKind::InvalidUrl)
                         .map_err(LockError::from)?;
-                    let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                    let hash = registry_hash(
+                        &reg_dist.name,
+                        &reg_dist.version,
+                        &reg_dist.file.hashes,
+                        allow_insecure_md5_hashes,
+                    )?;
                     let size = reg_dist.file.size;
                     let upload_time = reg_dist
                         .file
@@ -4193,7 +4650,7 @@ impl SourceDist {
     }
 
     fn from_direct_dist(id: &PackageId, hashes: &[HashDigest]) -> Result<Self, LockError> {
-        let Some(hash) = hashes.iter().max().cloned().map(Hash::from) else {
+        let Some(hash) = HashDigest::strongest(hashes).cloned().map(Hash::from) else {
             let kind = LockErrorKind::Hash {
                 id: id.clone(),
                 artifact_type: "direct URL source distribution",
@@ -4211,7 +4668,7 @@ impl SourceDist {
     }
 
     fn from_path_dist(id: &PackageId, hashes: &[HashDigest]) -> Result<Self, LockError> {
-        let Some(hash) = hashes.iter().max().cloned().map(Hash::from) else {
+        let Some(hash) = HashDigest::strongest(hashes).cloned().map(Hash::from) else {
             let kind = LockErrorKind::Hash {
                 id: id.clone(),
                 artifact_type: "path source distribution",
@@ -4407,7 +4864,10 @@ struct Wheel {
 }
 
 impl Wheel {
-    fn from_annotated_dist(annotated_dist: &AnnotatedDist) -> Result<Vec<Self>, LockError> {
+    fn from_annotated_dist(
+        annotated_dist: &AnnotatedDist,
+        allow_insecure_md5_hashes: bool,
+    ) -> Result<Vec<Self>, LockError> {
         match annotated_dist.dist {
             // We pass empty installed packages for locking.
             ResolvedDist::Installed { .. } => unreachable!(),
@@ -4415,6 +4875,7 @@ impl Wheel {
                 dist,
                 annotated_dist.hashes.as_slice(),
                 annotated_dist.index(),
+                allow_insecure_md5_hashes,
             ),
         }
     }
@@ -4423,9 +4884,12 @@ impl Wheel {
         dist: &Dist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Vec<Self>, LockError> {
         match *dist {
-            Dist::Built(ref built_dist) => Self::from_built_dist(built_dist, hashes, index),
+            Dist::Built(ref built_dist) => {
+                Self::from_built_dist(built_dist, hashes, index, allow_insecure_md5_hashes)
+            }
             Dist::Source(uv_distribution_types::SourceDist::Registry(ref source_dist)) => {
                 source_dist
                     .wheels
@@ -4435,7 +4899,7 @@ impl Wheel {
                         // `--find-links`.
                         index.is_some_and(|index| *index == wheel.index)
                     })
-                    .map(Self::from_registry_wheel)
+                    .map(|wheel| Self::from_registry_wheel(wheel, allow_insecure_md5_hashes))
                     .collect()
             }
             Dist::Source(_) => Ok(vec![]),
@@ -4446,9 +4910,12 @@ impl Wheel {
         built_dist: &BuiltDist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Vec<Self>, LockError> {
         match *built_dist {
-            BuiltDist::Registry(ref reg_dist) => Self::from_registry_dist(reg_dist, index),
+            BuiltDist::Registry(ref reg_dist) => {
+                Self::from_registry_dist(reg_dist, index, allow_insecure_md5_hashes)
+            }
             BuiltDist::DirectUrl(ref direct_dist) => {
                 Ok(vec![Self::from_direct_dist(direct_dist, hashes)])
             }
@@ -4459,6 +4926,7 @@ impl Wheel {
     fn from_registry_dist(
         reg_dist: &RegistryBuiltDist,
         index: Option<&IndexUrl>,
+        allow_insecure_md5_hashes: bool,
     ) -> Result<Vec<Self>, LockError> {
         reg_dist
             .wheels
@@ -4468,11 +4936,14 @@ impl Wheel {
                 // `--find-links`.
                 index.is_some_and(|index| *index == wheel.index)
             })
-            .map(Self::from_registry_wheel)
+            .map(|wheel| Self::from_registry_wheel(wheel, allow_insecure_md5_hashes))
             .collect()
     }
 
-    fn from_registry_wheel(wheel: &RegistryBuiltWheel) -> Result<Self, LockError> {
+    fn from_registry_wheel(
+        wheel: &RegistryBuiltWheel,
+        allow_insecure_md5_hashes: bool,
+    ) -> Result<Self, LockError> {
         let url = match &wheel.index {
             IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
                 let url = normalize_file_location(&wheel.file.url)
@@ -4504,7 +4975,12 @@ impl Wheel {
             }
         };
         let filename = wheel.filename.clone();
-        let hash = wheel.file.hashes.iter().max().cloned().map(Hash::from);
+        let hash = registry_hash(
+            &wheel.filename.name,
+            &wheel.filename.version,
+            &wheel.file.hashes,
+            allow_insecure_md5_hashes,
+        )?;
         let size = wheel.file.size;
         let upload_time = wheel
             .file
@@ -4512,10 +4988,18 @@ impl Wheel {
             .map(Timestamp::from_millisecond)
             .transpose()
             .map_err(LockErrorKind::InvalidTimestamp)?;
-        let zstd = wheel.file.zstd.as_ref().map(|zstd| ZstdWheel {
-            hash: zstd.hashes.iter().max().cloned().map(Hash::from),
-            size: zstd.size,
-        });
+        let zstd = match wheel.file.zstd.as_ref() {
+            Some(zstd) => Some(ZstdWheel {
+                hash: registry_hash(
+                    &wheel.filename.name,
+                    &wheel.filename.version,
+                    &zstd.hashes,
+                    allow_insecure_md5_hashes,
+                )?,
+                size: zstd.size,
+            }),
+            None => None,
+        };
         Ok(Self {
             url,
             hash,
@@ -4531,7 +5015,7 @@ impl Wheel {
             url: WheelWireSource::Url {
                 url: normalize_url(direct_dist.url.to_url()),
             },
-            hash: hashes.iter().max().cloned().map(Hash::from),
+            hash: HashDigest::strongest(hashes).cloned().map(Hash::from),
             size: None,
             upload_time: None,
             filename: direct_dist.filename.clone(),
@@ -4544,7 +5028,7 @@ impl Wheel {
             url: WheelWireSource::Filename {
                 filename: path_dist.filename.clone(),
             },
-            hash: hashes.iter().max().cloned().map(Hash::from),
+            hash: HashDigest::strongest(hashes).cloned().map(Hash::from),
             size: None,
             upload_time: None,
             filename: path_dist.filename.clone(),
@@ -4582,6 +5066,7 @@ impl Wheel {
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
                     url: file_location,
                     yanked: None,
+                    yanked_time_utc_ms: None,
                     zstd: self
                         .zstd
                         .as_ref()
@@ -4632,6 +5117,7 @@ impl Wheel {
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
                     url: file_location,
                     yanked: None,
+                    yanked_time_utc_ms: None,
                     zstd: self
                         .zstd
                         .as_ref()
@@ -4883,6 +5369,16 @@ impl Dependency {
     pub fn extra(&self) -> &BTreeSet<ExtraName> {
         &self.extra
     }
+
+    /// Returns the version of the package referenced by this dependency, if any.
+    pub fn package_version(&self) -> Option<&Version> {
+        self.package_id.version.as_ref()
+    }
+
+    /// Returns the universal marker under which this dependency applies.
+    pub fn complexified_marker(&self) -> UniversalMarker {
+        self.complexified_marker
+    }
 }
 
 impl Display for Dependency {
@@ -5114,7 +5610,8 @@ fn normalize_requirement(
             url: _,
         } => {
             let install_path =
-                uv_fs::normalize_path_buf(root.join(&install_path)).into_boxed_path();
+                uv_fs::normalize_path_buf(root.join(expand_path_vars(&install_path, root)))
+                    .into_boxed_path();
             let url = VerbatimUrl::from_normalized_path(&install_path)
                 .map_err(LockErrorKind::RequirementVerbatimUrl)?;
 
@@ -5138,7 +5635,8 @@ fn normalize_requirement(
             url: _,
         } => {
             let install_path =
-                uv_fs::normalize_path_buf(root.join(&install_path)).into_boxed_path();
+                uv_fs::normalize_path_buf(root.join(expand_path_vars(&install_path, root)))
+                    .into_boxed_path();
             let url = VerbatimUrl::from_normalized_path(&install_path)
                 .map_err(LockErrorKind::RequirementVerbatimUrl)?;
 
@@ -5245,6 +5743,16 @@ impl LockError {
     pub fn is_resolution(&self) -> bool {
         matches!(&*self.kind, LockErrorKind::Resolution { .. })
     }
+
+    /// Returns the name of the package that couldn't be installed, if the [`LockError`] is due to
+    /// a package without a source distribution or compatible wheel.
+    pub fn missing_wheel(&self) -> Option<&PackageName> {
+        match &*self.kind {
+            LockErrorKind::IncompatibleWheelOnly { id }
+            | LockErrorKind::NeitherSourceDistNorWheel { id } => Some(&id.name),
+            _ => None,
+        }
+    }
 }
 
 impl<E> From<E> for LockError
@@ -5914,6 +6422,9 @@ enum LockErrorKind {
     },
     #[error(transparent)]
     GitUrlParse(#[from] GitUrlParseError),
+    /// An error that occurs when a registry only provides insecure hashes for an artifact.
+    #[error(transparent)]
+    InsecureHash(#[from] InsecureHashError),
     #[error("Failed to read `{path}`")]
     UnreadablePyprojectToml {
         path: PathBuf,
@@ -6000,12 +6511,14 @@ fn each_element_on_its_line_array(elements: impl Iterator<Item = impl Into<Value
 fn simplified_universal_markers(
     markers: &[UniversalMarker],
     requires_python: &RequiresPython,
+    environment_assumptions: MarkerTree,
 ) -> Vec<String> {
     let mut pep508_only = vec![];
     let mut seen = FxHashSet::default();
     for marker in markers {
-        let simplified =
-            SimplifiedMarkerTree::new(requires_python, marker.pep508()).as_simplified_marker_tree();
+        let simplified = SimplifiedMarkerTree::new(requires_python, marker.pep508())
+            .assuming(environment_assumptions)
+            .as_simplified_marker_tree();
         if seen.insert(simplified) {
             pep508_only.push(simplified);
         }
@@ -6021,6 +6534,7 @@ fn simplified_universal_markers(
             .iter()
             .map(|marker| {
                 SimplifiedMarkerTree::new(requires_python, marker.combined())
+                    .assuming(environment_assumptions)
                     .as_simplified_marker_tree()
             })
             .collect()
@@ -6355,4 +6869,57 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn supported_implementations() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [{ url = "https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+
+[[package]]
+name = "numpy"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://example.com/numpy-2.0.0-cp312-cp312-manylinux_2_17_x86_64.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+    { url = "https://example.com/numpy-2.0.0-pp310-pypy310_pp73-manylinux_2_17_x86_64.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+
+[[package]]
+name = "orjson"
+version = "3.10.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [{ url = "https://example.com/orjson-3.10.0-cp312-cp312-manylinux_2_17_x86_64.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" }]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+
+        // Universal wheels are supported by any implementation.
+        assert_eq!(
+            lock.supported_implementations(),
+            Some(BTreeSet::from([ImplementationName::CPython]))
+        );
+
+        let unsupported = lock
+            .unsupported_packages(ImplementationName::PyPy)
+            .into_iter()
+            .map(|(package, implementations)| (package.name().to_string(), implementations))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unsupported,
+            [(
+                "orjson".to_string(),
+                BTreeSet::from([ImplementationName::CPython])
+            )]
+        );
+        assert!(
+            lock.unsupported_packages(ImplementationName::CPython)
+                .is_empty()
+        );
+    }
 }
@@ -121,19 +121,17 @@ impl Manifest {
                     .iter()
                     .flat_map(move |lookahead| {
                         self.overrides
-                            .apply(lookahead.requirements())
+                            .apply(Some(lookahead.name()), lookahead.requirements())
                             .filter(move |requirement| {
                                 requirement
                                     .evaluate_markers(env.marker_environment(), lookahead.extras())
                             })
                     })
-                    .chain(
-                        self.overrides
-                            .apply(&self.requirements)
-                            .filter(move |requirement| {
-                                requirement.evaluate_markers(env.marker_environment(), &[])
-                            }),
-                    )
+                    .chain(self.overrides.apply(None, &self.requirements).filter(
+                        move |requirement| {
+                            requirement.evaluate_markers(env.marker_environment(), &[])
+                        },
+                    ))
                     .chain(
                         self.constraints
                             .requirements()
@@ -146,7 +144,7 @@ impl Manifest {
             // Include direct requirements, with constraints and overrides applied.
             DependencyMode::Direct => Either::Right(
                 self.overrides
-                    .apply(&self.requirements)
+                    .apply(None, &self.requirements)
                     .chain(self.constraints.requirements().map(Cow::Borrowed))
                     .filter(move |requirement| {
                         requirement.evaluate_markers(env.marker_environment(), &[])
@@ -207,24 +205,22 @@ impl Manifest {
                     .filter(|lookahead| lookahead.direct())
                     .flat_map(move |lookahead| {
                         self.overrides
-                            .apply(lookahead.requirements())
+                            .apply(Some(lookahead.name()), lookahead.requirements())
                             .filter(move |requirement| {
                                 requirement
                                     .evaluate_markers(env.marker_environment(), lookahead.extras())
                             })
                     })
-                    .chain(
-                        self.overrides
-                            .apply(&self.requirements)
-                            .filter(move |requirement| {
-                                requirement.evaluate_markers(env.marker_environment(), &[])
-                            }),
-                    ),
+                    .chain(self.overrides.apply(None, &self.requirements).filter(
+                        move |requirement| {
+                            requirement.evaluate_markers(env.marker_environment(), &[])
+                        },
+                    )),
             ),
 
             // Restrict to the direct requirements.
             DependencyMode::Direct => {
-                Either::Right(self.overrides.apply(self.requirements.iter()).filter(
+                Either::Right(self.overrides.apply(None, self.requirements.iter()).filter(
                     move |requirement| requirement.evaluate_markers(env.marker_environment(), &[]),
                 ))
             }
@@ -241,19 +237,22 @@ impl Manifest {
         env: &'a ResolverEnvironment,
     ) -> impl Iterator<Item = Cow<'a, Requirement>> + 'a {
         self.overrides
-            .apply(self.requirements.iter())
+            .apply(None, self.requirements.iter())
             .filter(move |requirement| requirement.evaluate_markers(env.marker_environment(), &[]))
     }
 
-    /// Apply the overrides and constraints to a set of requirements.
+    /// Apply the overrides and constraints to a set of requirements declared by the given
+    /// dependent (or by the root, if `None`).
     ///
     /// Constraints are always applied _on top_ of overrides, such that constraints are applied
     /// even if a requirement is overridden.
     pub fn apply<'a>(
         &'a self,
+        dependent: Option<&'a PackageName>,
         requirements: impl IntoIterator<Item = &'a Requirement>,
     ) -> impl Iterator<Item = Cow<'a, Requirement>> {
-        self.constraints.apply(self.overrides.apply(requirements))
+        self.constraints
+            .apply(self.overrides.apply(dependent, requirements))
     }
 
     /// Returns the number of input requirements.
@@ -18,6 +18,7 @@ pub struct Options {
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub allow_insecure_md5_hashes: bool,
 }
 
 /// Builder for [`Options`].
@@ -33,6 +34,7 @@ pub struct OptionsBuilder {
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    allow_insecure_md5_hashes: bool,
 }
 
 impl OptionsBuilder {
@@ -111,6 +113,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to allow distributions that only provide MD5 hashes.
+    #[must_use]
+    pub fn allow_insecure_md5_hashes(mut self, allow_insecure_md5_hashes: bool) -> Self {
+        self.allow_insecure_md5_hashes = allow_insecure_md5_hashes;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -124,6 +133,7 @@ impl OptionsBuilder {
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            allow_insecure_md5_hashes: self.allow_insecure_md5_hashes,
         }
     }
 }
@@ -145,6 +145,15 @@ impl Preference {
     pub fn version(&self) -> &Version {
         &self.version
     }
+
+    /// Drop any hashes from the [`Preference`], such that they're recomputed for the pin.
+    #[must_use]
+    pub fn without_hashes(self) -> Self {
+        Self {
+            hashes: HashDigests::empty(),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use owo_colors::OwoColorize;
 use petgraph::visit::EdgeRef;
@@ -7,10 +7,11 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 
 use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
-use crate::{ResolverEnvironment, ResolverOutput};
+use crate::{InMemoryIndex, ResolverEnvironment, ResolverOutput, VersionsResponse};
 
 /// A [`std::fmt::Display`] implementation for the resolution graph.
 #[derive(Debug)]
@@ -32,9 +33,27 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// Whether to include the license of each package in the output (e.g., `# license: MIT`).
+    include_license: bool,
+    /// Whether to include the homepage of each package in the output (e.g.,
+    /// `# homepage: https://example.com`).
+    include_homepage: bool,
+    /// Whether to include the latest version of each package known to the index, if it's newer
+    /// than the resolved version (e.g., `# latest: 2.0.0`).
+    include_latest_version: bool,
+    /// The index used for the resolution, used to determine the latest version of each package.
+    index: &'a InMemoryIndex,
+    /// Whether to include a summary of the resolved packages at the end of the output.
+    include_summary: bool,
+    /// Whether to include the markers on which a universal resolution forked, along with the
+    /// versions selected in each fork, at the end of the output.
+    include_fork_annotation: bool,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The comments attached to each package in the input files (e.g., `flask  # pinned for CI`),
+    /// to be carried over to the output.
+    comments: &'a BTreeMap<PackageName, Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -60,7 +79,14 @@ impl<'a> DisplayResolutionGraph<'a> {
         include_markers: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        include_license: bool,
+        include_homepage: bool,
+        include_latest_version: bool,
+        index: &'a InMemoryIndex,
+        include_summary: bool,
+        include_fork_annotation: bool,
         annotation_style: AnnotationStyle,
+        comments: &'a BTreeMap<PackageName, Vec<String>>,
     ) -> Self {
         for fork_marker in &underlying.fork_markers {
             assert!(
@@ -78,7 +104,14 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_markers,
             include_annotations,
             include_index_annotation,
+            include_license,
+            include_homepage,
+            include_latest_version,
+            index,
+            include_summary,
+            include_fork_annotation,
             annotation_style,
+            comments,
         }
     }
 }
@@ -179,8 +212,21 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             })
             .collect::<Vec<_>>();
 
-        // Sort the nodes by name, but with editable packages first.
-        nodes.sort_unstable_by_key(|(index, node)| (node.to_comparator(), *index));
+        // Sort the nodes by name, but with editable packages first. Nodes that only differ in their
+        // markers are ordered by their markers, rather than by their position in the graph, which
+        // depends on the order in which the resolver visited them.
+        nodes.sort_by_cached_key(|&(_, node)| {
+            (
+                node.to_comparator(),
+                node.to_requirements_txt(&self.resolution.requires_python, true)
+                    .into_owned(),
+            )
+        });
+
+        // If enabled, summarize the emitted packages before they're consumed below.
+        let summary = self
+            .include_summary
+            .then(|| Summary::from_nodes(self, nodes.iter().map(|(_, node)| *node)));
 
         // Print out the dependency graph.
         for (index, node) in nodes {
@@ -192,7 +238,13 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             // Display the distribution hashes, if any.
             let mut has_hashes = false;
             if self.show_hashes {
-                for hash in node.hashes {
+                // Omit any insecure hashes, if secure alternatives are available.
+                let secure = node.hashes.iter().any(|hash| hash.algorithm().is_secure());
+                for hash in node
+                    .hashes
+                    .iter()
+                    .filter(|hash| !secure || hash.algorithm().is_secure())
+                {
                     has_hashes = true;
                     line.push_str(" \\\n");
                     line.push_str("    --hash=");
@@ -274,6 +326,38 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 }
             }
 
+            // Include any comments carried over from the input files (e.g., `# pinned for CI`),
+            // ahead of the annotations.
+            if let Some(comments) = self
+                .comments
+                .get(node.name())
+                .filter(|comments| !comments.is_empty())
+            {
+                annotation = Some(match self.annotation_style {
+                    AnnotationStyle::Line => {
+                        let separator = if has_hashes { "\n    " } else { "  " };
+                        let comment = format!("# {}", comments.join("; ")).green().to_string();
+                        match annotation {
+                            Some((_, via)) => (separator, format!("{comment}  {via}")),
+                            None => (separator, comment),
+                        }
+                    }
+                    AnnotationStyle::Split => {
+                        let comment = comments
+                            .iter()
+                            .map(|comment| format!("    # {comment}"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                            .green()
+                            .to_string();
+                        match annotation {
+                            Some((_, via)) => ("\n", format!("{comment}\n{via}")),
+                            None => ("\n", comment),
+                        }
+                    }
+                });
+            }
+
             if let Some((separator, comment)) = annotation {
                 // Assemble the line with the annotations and remove trailing whitespaces.
                 for line in format!("{line:24}{separator}{comment}").lines() {
@@ -293,15 +377,194 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     writeln!(f, "{}", format!("    # from {url}").green())?;
                 }
             }
+
+            // If enabled, include the license and homepage of each package, as reported by its
+            // metadata (e.g., `# license: MIT`).
+            if self.include_license {
+                if let Some(license) = node.metadata.and_then(|metadata| metadata.license.as_ref())
+                {
+                    writeln!(f, "{}", format!("    # license: {license}").green())?;
+                }
+            }
+            if self.include_homepage {
+                if let Some(homepage) = node
+                    .metadata
+                    .and_then(|metadata| metadata.homepage.as_ref())
+                {
+                    writeln!(f, "{}", format!("    # homepage: {homepage}").green())?;
+                }
+            }
+
+            // If enabled, include the latest version of each package, if it's newer than the
+            // resolved version (e.g., `# latest: 2.0.0`).
+            if self.include_latest_version {
+                if let Some(version) = self.latest_version(node) {
+                    writeln!(f, "{}", format!("    # latest: {version}").green())?;
+                }
+            }
+        }
+
+        // If enabled, include the markers on which the resolution forked, along with the versions
+        // selected in each fork (e.g., `#    python_full_version < '3.10': numpy==1.26.4`).
+        if self.include_fork_annotation {
+            let forks = self.resolution.forked_versions();
+            if !forks.is_empty() {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{}",
+                    "# The resolution forked on the following markers:".green()
+                )?;
+                for (marker, versions) in forks {
+                    let marker = marker
+                        .try_to_string()
+                        .unwrap_or_else(|| "(all environments)".to_string());
+                    if versions.is_empty() {
+                        writeln!(f, "{}", format!("#    {marker}").green())?;
+                    } else {
+                        let versions = versions
+                            .iter()
+                            .map(|(name, version)| format!("{name}=={version}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        writeln!(f, "{}", format!("#    {marker}: {versions}").green())?;
+                    }
+                }
+            }
+        }
+
+        // If enabled, include a summary of the resolved packages (e.g.,
+        // `# Summary: 3 packages (1 direct, 2 transitive)`).
+        if let Some(summary) = summary {
+            write!(f, "{summary}")?;
         }
 
         Ok(())
     }
 }
 
+impl DisplayResolutionGraph<'_> {
+    /// Return the latest version of the package that's known to the index, if it's newer than the
+    /// resolved version.
+    ///
+    /// Only versions that the resolver could have selected are considered (e.g., yanked versions
+    /// and versions excluded by `--exclude-newer` are skipped), and pre-releases are only
+    /// considered if the resolved version is itself a pre-release.
+    fn latest_version(&self, node: &RequirementsTxtDist) -> Option<Version> {
+        let index_url = node.dist.index()?;
+        let response = self
+            .index
+            .explicit()
+            .get(&(node.name().clone(), index_url.clone()))
+            .or_else(|| self.index.implicit().get(node.name()))?;
+        let VersionsResponse::Found(version_maps) = &*response else {
+            return None;
+        };
+        version_maps
+            .iter()
+            .filter_map(|version_map| {
+                version_map.versions().rev().find(|version| {
+                    (node.version.any_prerelease() || !version.any_prerelease())
+                        && version_map
+                            .get(version)
+                            .is_some_and(|dist| dist.get().is_some())
+                })
+            })
+            .max()
+            .filter(|version| *version > node.version)
+            .cloned()
+    }
+}
+
+/// A summary of the packages included in a [`DisplayResolutionGraph`].
+#[derive(Debug)]
+struct Summary<'dist> {
+    /// The number of packages that were requested directly.
+    direct: usize,
+    /// The number of packages that were only required by other packages.
+    transitive: usize,
+    /// The packages resolved from each index, keyed by the index URL (without credentials).
+    indexes: BTreeMap<String, BTreeSet<&'dist PackageName>>,
+    /// The packages that weren't resolved from an index (e.g., direct URL or path dependencies).
+    other: BTreeSet<&'dist PackageName>,
+}
+
+impl<'dist> Summary<'dist> {
+    /// Summarize the given nodes.
+    ///
+    /// Packages are counted once, regardless of how many versions or extras were emitted for them.
+    fn from_nodes(
+        graph: &DisplayResolutionGraph,
+        nodes: impl Iterator<Item = &'dist RequirementsTxtDist<'dist>>,
+    ) -> Self {
+        let requested = graph
+            .resolution
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.evaluate_markers(graph.env.marker_environment(), &[]))
+            .map(|requirement| &requirement.name)
+            .collect::<BTreeSet<_>>();
+
+        let mut names = BTreeSet::default();
+        let mut indexes: BTreeMap<String, BTreeSet<&PackageName>> = BTreeMap::default();
+        let mut other = BTreeSet::default();
+        for node in nodes {
+            let name = node.name();
+            names.insert(name);
+            if let Some(index) = node.dist.index() {
+                indexes
+                    .entry(index.without_credentials().to_string())
+                    .or_default()
+                    .insert(name);
+            } else {
+                other.insert(name);
+            }
+        }
+
+        let direct = names
+            .iter()
+            .filter(|name| requested.contains(*name))
+            .count();
+        Self {
+            direct,
+            transitive: names.len() - direct,
+            indexes,
+            other,
+        }
+    }
+}
+
+impl std::fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.direct + self.transitive;
+        let s = if total == 1 { "" } else { "s" };
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{}",
+            format!(
+                "# Summary: {total} package{s} ({} direct, {} transitive)",
+                self.direct, self.transitive
+            )
+            .green()
+        )?;
+        for (url, names) in &self.indexes {
+            writeln!(f, "{}", format!("#   {} from {url}", names.len()).green())?;
+        }
+        if !self.other.is_empty() {
+            writeln!(
+                f,
+                "{}",
+                format!("#   {} from direct URLs or local paths", self.other.len()).green()
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Indicate the style of annotation comments, used to indicate the dependencies that requested each
 /// package.
-#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
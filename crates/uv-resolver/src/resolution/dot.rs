@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use petgraph::visit::EdgeRef;
+
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+
+use crate::ResolverOutput;
+use crate::resolution::ResolutionGraphNode;
+
+/// A [`std::fmt::Display`] implementation for the resolution graph, in the Graphviz DOT format.
+///
+/// Each node represents a pinned package (with any extras and dependency groups folded into the
+/// base package), and each edge represents a dependency, labeled with the markers under which it
+/// applies. Packages that were resolved to more than one version across forks are labeled with
+/// the markers under which each version is selected.
+#[derive(Debug)]
+pub struct DisplayResolutionDot<'a> {
+    /// The underlying graph.
+    resolution: &'a ResolverOutput,
+    /// The packages to exclude from the output.
+    no_emit_packages: &'a [PackageName],
+}
+
+impl<'a> DisplayResolutionDot<'a> {
+    /// Create a new [`DisplayResolutionDot`] for the given graph.
+    pub fn new(resolution: &'a ResolverOutput, no_emit_packages: &'a [PackageName]) -> Self {
+        Self {
+            resolution,
+            no_emit_packages,
+        }
+    }
+
+    /// Returns `true` if the given node should be excluded from the output.
+    fn is_excluded(&self, node: &ResolutionGraphNode) -> bool {
+        node.package_name()
+            .is_some_and(|name| self.no_emit_packages.contains(name))
+    }
+
+    /// Return the DOT identifier for the given node.
+    fn id(node: &ResolutionGraphNode) -> String {
+        match node {
+            ResolutionGraphNode::Root => "(root)".to_string(),
+            ResolutionGraphNode::Dist(dist) => format!("{}=={}", dist.name, dist.version),
+        }
+    }
+
+    /// Simplify the given marker under the resolution's `requires-python`, returning `None` if
+    /// the marker is always true.
+    fn simplify(&self, marker: MarkerTree) -> Option<String> {
+        self.resolution
+            .requires_python
+            .simplify_markers(marker)
+            .try_to_string()
+    }
+}
+
+/// Write the graph as a Graphviz `digraph`.
+impl std::fmt::Display for DisplayResolutionDot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let graph = &self.resolution.graph;
+
+        // Collect the nodes, merging extras and groups into their base package.
+        let mut nodes: BTreeMap<String, Option<String>> = BTreeMap::new();
+        for node in graph.node_weights() {
+            if self.is_excluded(node) {
+                continue;
+            }
+            let marker = match node {
+                ResolutionGraphNode::Root => None,
+                ResolutionGraphNode::Dist(dist) => {
+                    if !dist.is_base() || !self.resolution.is_divergent(&dist.name) {
+                        None
+                    } else {
+                        self.simplify(dist.marker.pep508())
+                    }
+                }
+            };
+            let entry = nodes.entry(Self::id(node)).or_default();
+            if marker.is_some() {
+                *entry = marker;
+            }
+        }
+
+        // Collect the edges, combining the markers of any parallel edges.
+        let mut edges: BTreeMap<(String, String), MarkerTree> = BTreeMap::new();
+        for edge in graph.edge_references() {
+            if self.is_excluded(&graph[edge.source()]) || self.is_excluded(&graph[edge.target()]) {
+                continue;
+            }
+            let source = Self::id(&graph[edge.source()]);
+            let target = Self::id(&graph[edge.target()]);
+            if source == target {
+                continue;
+            }
+            edges
+                .entry((source, target))
+                .or_insert(MarkerTree::FALSE)
+                .or(edge.weight().pep508());
+        }
+
+        writeln!(f, "digraph {{")?;
+        for (id, marker) in &nodes {
+            if let Some(marker) = marker {
+                let label = format!("{id}\\n{marker}");
+                writeln!(f, "    {} [label={}]", quote(id), quote(&label))?;
+            } else {
+                writeln!(f, "    {}", quote(id))?;
+            }
+        }
+        for ((source, target), marker) in edges {
+            write!(f, "    {} -> {}", quote(&source), quote(&target))?;
+            if let Some(marker) = self.simplify(marker) {
+                write!(f, " [label={}]", quote(&marker))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+/// Quote a string as a DOT identifier.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
@@ -10,12 +10,16 @@ use uv_pep440::Version;
 use uv_pypi_types::HashDigests;
 
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
+pub use crate::resolution::dot::DisplayResolutionDot;
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
-pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
+pub use crate::resolution::output::{
+    ConflictingDistributionError, InsecureHashError, ResolverOutput,
+};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 use crate::universal_marker::UniversalMarker;
 
 mod display;
+mod dot;
 mod output;
 mod requirements_txt;
 
@@ -1,8 +1,9 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 use indexmap::IndexSet;
+use owo_colors::OwoColorize;
 use petgraph::{
     Directed, Direction,
     graph::{Graph, NodeIndex},
@@ -20,6 +21,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
 use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
+use uv_static::EnvVars;
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
@@ -618,6 +620,77 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Validate that a secure hash is available for every distribution in the graph that has
+    /// hashes.
+    ///
+    /// Returns an error if a distribution is only associated with insecure (i.e., MD5) hashes,
+    /// unless insecure hashes were explicitly allowed (i.e., via `--allow-insecure-md5-hashes`).
+    pub fn validate_hashes(&self) -> Result<(), InsecureHashError> {
+        if self.options.allow_insecure_md5_hashes {
+            return Ok(());
+        }
+        for dist in self.dists() {
+            if !dist.hashes.is_empty()
+                && !dist
+                    .hashes
+                    .iter()
+                    .any(|digest| digest.algorithm().is_secure())
+            {
+                return Err(InsecureHashError {
+                    name: dist.name.clone(),
+                    version: dist.version.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the forks of a universal resolution, along with the versions selected in each fork
+    /// for any package that was resolved to more than one version.
+    ///
+    /// Each fork marker is simplified under the resolution's `requires-python`. If the resolution
+    /// didn't fork, the returned list is empty.
+    pub(crate) fn forked_versions(&self) -> Vec<(MarkerTree, BTreeSet<(&PackageName, &Version)>)> {
+        if self.fork_markers.is_empty() {
+            return Vec::new();
+        }
+
+        let mut versions: FxHashMap<&PackageName, FxHashSet<&Version>> = FxHashMap::default();
+        for dist in self.dists().filter(|dist| dist.is_base()) {
+            versions
+                .entry(&dist.name)
+                .or_default()
+                .insert(&dist.version);
+        }
+
+        self.fork_markers
+            .iter()
+            .map(|fork| {
+                let selected = self
+                    .dists()
+                    .filter(|dist| dist.is_base())
+                    .filter(|dist| versions.get(&dist.name).is_some_and(|set| set.len() > 1))
+                    .filter(|dist| !dist.marker.is_disjoint(*fork))
+                    .map(|dist| (&dist.name, &dist.version))
+                    .collect();
+                (
+                    self.requires_python.simplify_markers(fork.pep508()),
+                    selected,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given package was resolved to more than one version.
+    pub(crate) fn is_divergent(&self, name: &PackageName) -> bool {
+        self.dists()
+            .filter(|dist| dist.is_base() && dist.name == *name)
+            .map(|dist| &dist.version)
+            .collect::<FxHashSet<_>>()
+            .len()
+            > 1
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -727,10 +800,10 @@ impl ResolverOutput {
                     dist.version_id()
                 )
             };
-            for req in self
-                .constraints
-                .apply(self.overrides.apply(archive.metadata.requires_dist.iter()))
-            {
+            for req in self.constraints.apply(
+                self.overrides
+                    .apply(Some(dist.name()), archive.metadata.requires_dist.iter()),
+            ) {
                 add_marker_params_from_tree(req.marker, &mut seen_marker_values);
             }
         }
@@ -738,7 +811,7 @@ impl ResolverOutput {
         // Ensure that we consider markers from direct dependencies.
         for direct_req in self
             .constraints
-            .apply(self.overrides.apply(self.requirements.iter()))
+            .apply(self.overrides.apply(None, self.requirements.iter()))
         {
             add_marker_params_from_tree(direct_req.marker, &mut seen_marker_values);
         }
@@ -848,6 +921,30 @@ impl Display for ConflictingDistributionError {
     }
 }
 
+/// An error that occurs when an index only provides insecure (i.e., MD5) hashes for a
+/// distribution.
+#[derive(Debug)]
+pub struct InsecureHashError {
+    pub(crate) name: PackageName,
+    pub(crate) version: Version,
+}
+
+impl std::error::Error for InsecureHashError {}
+
+impl Display for InsecureHashError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "The index only provides an MD5 hash for `{}`, which is not secure\n\n{}{} Pass `{}` (or set `{}=1`) to allow MD5 hashes",
+            format!("{}=={}", self.name, self.version).cyan(),
+            "hint".bold().cyan(),
+            ":".bold(),
+            "--allow-insecure-md5-hashes".green(),
+            EnvVars::UV_INSECURE_MD5_HASHES,
+        )
+    }
+}
+
 /// Convert a [`ResolverOutput`] into a [`uv_distribution_types::Resolution`].
 ///
 /// This involves converting [`ResolutionGraphNode`]s into [`Node`]s, which in turn involves
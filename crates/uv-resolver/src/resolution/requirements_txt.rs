@@ -4,6 +4,7 @@ use std::path::Path;
 
 use itertools::Itertools;
 
+use uv_distribution::Metadata;
 use uv_distribution_types::{
     DistributionMetadata, Name, RequiresPython, ResolvedDist, SimplifiedMarkerTree, Verbatim,
     VersionOrUrlRef,
@@ -21,6 +22,7 @@ pub(crate) struct RequirementsTxtDist<'dist> {
     pub(crate) dist: &'dist ResolvedDist,
     pub(crate) version: &'dist Version,
     pub(crate) hashes: &'dist [HashDigest],
+    pub(crate) metadata: Option<&'dist Metadata>,
     pub(crate) markers: MarkerTree,
     pub(crate) extras: Vec<ExtraName>,
 }
@@ -178,6 +180,7 @@ impl<'dist> RequirementsTxtDist<'dist> {
             dist: &annotated.dist,
             version: &annotated.version,
             hashes: annotated.hashes.as_slice(),
+            metadata: annotated.metadata.as_ref(),
             // OK because we've asserted above that this dist
             // does not have a non-trivial conflicting marker
             // that we would otherwise need to care about.
@@ -47,6 +47,8 @@ pub(crate) struct BatchPrefetcher {
     // Types to determine whether we need to prefetch.
     tried_versions: FxHashMap<PackageName, FxHashSet<Version>>,
     last_prefetch: FxHashMap<PackageName, usize>,
+    /// The maximum number of versions to prefetch in a single batch.
+    max_prefetch: usize,
     // Types to execute the prefetch.
     prefetch_runner: BatchPrefetcherRunner,
 }
@@ -67,10 +69,12 @@ impl BatchPrefetcher {
         capabilities: IndexCapabilities,
         index: InMemoryIndex,
         request_sink: Sender<Request>,
+        max_prefetch: usize,
     ) -> Self {
         Self {
             tried_versions: FxHashMap::default(),
             last_prefetch: FxHashMap::default(),
+            max_prefetch,
             prefetch_runner: BatchPrefetcherRunner {
                 capabilities,
                 index,
@@ -105,7 +109,7 @@ impl BatchPrefetcher {
         if !do_prefetch {
             return Ok(());
         }
-        let total_prefetch = min(num_tried, 50);
+        let total_prefetch = min(num_tried, self.max_prefetch);
 
         // This is immediate, we already fetched the version map.
         let versions_response = if let Some(index) = index {
@@ -162,7 +166,8 @@ impl BatchPrefetcher {
     }
 
     /// After 5, 10, 20, 40 tried versions, prefetch that many versions to start early but not
-    /// too aggressive. Later we schedule the prefetch of 50 versions every 20 versions, this gives
+    /// too aggressive. Later we schedule the prefetch of up to `max_prefetch` (by default, 50)
+    /// versions every 20 versions, this gives
     /// us a good buffer until we see prefetch again and is high enough to saturate the task pool.
     fn should_prefetch(&self, next: &PubGrubPackage) -> (usize, bool) {
         let PubGrubPackageInner::Package {
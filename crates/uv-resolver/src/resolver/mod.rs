@@ -15,18 +15,19 @@ use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use pubgrub::{Id, IncompId, Incompatibility, Kind, Range, Ranges, State};
 use rustc_hash::{FxHashMap, FxHashSet};
-use tokio::sync::mpsc::{self, Receiver, Sender};
-use tokio::sync::oneshot;
+use tokio::sync::mpsc::{self, Receiver, Sender, WeakSender};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, oneshot};
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::{Level, debug, info, instrument, trace, warn};
 
-use uv_configuration::{Constraints, Overrides, SourceStrategy};
+use uv_configuration::{Concurrency, Constraints, Overrides, SourceStrategy};
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_distribution_types::{
     BuiltDist, CompatibleDist, DerivationChain, Dist, DistErrorKind, DistributionMetadata,
     IncompatibleDist, IncompatibleSource, IncompatibleWheel, IndexCapabilities, IndexLocations,
     IndexMetadata, IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource, Requirement,
-    ResolvedDist, ResolvedDistRef, SourceDist, VersionOrUrlRef,
+    RequirementSource, ResolvedDist, ResolvedDistRef, SourceAnnotation, SourceAnnotations,
+    SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -48,7 +49,7 @@ use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
-use crate::preferences::{PreferenceSource, Preferences};
+use crate::preferences::{PreferenceIndex, PreferenceSource, Preferences};
 use crate::pubgrub::{
     PubGrubDependency, PubGrubDistribution, PubGrubPackage, PubGrubPackageInner, PubGrubPriorities,
     PubGrubPython,
@@ -76,6 +77,7 @@ pub use crate::resolver::provider::{
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::resolver::system::SystemDependency;
 pub(crate) use crate::resolver::urls::Urls;
+pub use crate::resolver::veto::{CandidateVeto, CandidateVetoError, VetoCandidate};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::yanks::AllowedYanks;
 use crate::{
@@ -95,6 +97,7 @@ mod provider;
 mod reporter;
 mod system;
 mod urls;
+mod veto;
 
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
 const CONFLICT_THRESHOLD: usize = 5;
@@ -114,6 +117,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     preferences: Preferences,
     git: GitResolver,
     capabilities: IndexCapabilities,
+    concurrency: Concurrency,
     locations: IndexLocations,
     exclusions: Exclusions,
     urls: Urls,
@@ -134,10 +138,14 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     unavailable_packages: DashMap<PackageName, UnavailablePackage>,
     /// Incompatibilities for packages that are unavailable at specific versions.
     incomplete_packages: DashMap<PackageName, DashMap<Version, MetadataUnavailable>>,
+    /// The budget for speculative prefetches of dependencies that are in flight.
+    prefetch_permits: Arc<Semaphore>,
     /// The options that were used to configure this resolver.
     options: Options,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The hook used to reject individual versions of a package, if any.
+    candidate_veto: Option<Arc<dyn CandidateVeto>>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -186,6 +194,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             options.exclude_newer.clone(),
             build_context.build_options(),
             build_context.capabilities(),
+            build_context.dependency_metadata(),
         );
 
         Self::new_custom_io(
@@ -200,6 +209,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             index,
             build_context.git(),
             build_context.capabilities(),
+            build_context.concurrency(),
             build_context.locations(),
             build_context.sources(),
             provider,
@@ -224,6 +234,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         index: &InMemoryIndex,
         git: &GitResolver,
         capabilities: &IndexCapabilities,
+        concurrency: &Concurrency,
         locations: &IndexLocations,
         source_strategy: SourceStrategy,
         provider: Provider,
@@ -233,6 +244,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             index: index.clone(),
             git: git.clone(),
             capabilities: capabilities.clone(),
+            concurrency: *concurrency,
             selector: CandidateSelector::for_resolution(&options, &manifest, &env, source_strategy),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode),
@@ -254,8 +266,10 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             installed_packages,
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
+            prefetch_permits: Arc::new(Semaphore::new(concurrency.prefetches)),
             options,
             reporter: None,
+            candidate_veto: None,
         };
         Ok(Self { state, provider })
     }
@@ -274,6 +288,18 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         }
     }
 
+    /// Set the [`CandidateVeto`] used to reject individual versions of a package.
+    #[must_use]
+    pub fn with_candidate_veto(self, candidate_veto: Arc<dyn CandidateVeto>) -> Self {
+        Self {
+            state: ResolverState {
+                candidate_veto: Some(candidate_veto),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
+
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
         let state = Arc::new(self.state);
@@ -285,7 +311,10 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let (request_sink, request_stream) = mpsc::channel(300);
 
         // Run the fetcher.
-        let requests_fut = state.clone().fetch(provider.clone(), request_stream).fuse();
+        let requests_fut = state
+            .clone()
+            .fetch(provider.clone(), request_sink.downgrade(), request_stream)
+            .fuse();
 
         // Spawn the PubGrub solver on a dedicated thread.
         let solver = state.clone();
@@ -333,6 +362,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.capabilities.clone(),
             self.index.clone(),
             request_sink.clone(),
+            self.concurrency.prefetches,
         );
         let state = ForkState::new(
             pubgrub,
@@ -999,6 +1029,21 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 return Err(ResolveError::UnhashedPackage(name.clone()));
             }
 
+            // Packages matching an index namespace may only come from the pinned index, with the
+            // exception of workspace members.
+            if let Some(namespace) = self
+                .locations
+                .namespace_for(name)
+                .filter(|_| !self.workspace_members.contains(name))
+            {
+                return Err(ResolveError::IndexNamespaceSource(
+                    name.clone(),
+                    namespace.pattern.clone(),
+                    namespace.name.clone(),
+                    url.verbatim.to_url(),
+                ));
+            }
+
             // Emit a request to fetch the metadata for this distribution.
             let dist = Dist::from_url(name.clone(), url.clone())?;
             if self.index.distributions().register(dist.version_id()) {
@@ -1970,7 +2015,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         dev_dependencies: &'a BTreeMap<GroupName, Box<[Requirement]>>,
         extra: Option<&'a ExtraName>,
         dev: Option<&'a GroupName>,
-        name: Option<&PackageName>,
+        name: Option<&'a PackageName>,
         env: &'a ResolverEnvironment,
         python_requirement: &'a PythonRequirement,
     ) -> impl Iterator<Item = Cow<'a, Requirement>> {
@@ -1980,6 +2025,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             // Dependency groups can include the project itself, so no need to flatten recursive
             // dependencies.
             Either::Left(Either::Left(self.requirements_for_extra(
+                name,
                 dev_dependencies.get(dev).into_iter().flatten(),
                 extra,
                 env,
@@ -1992,6 +2038,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         {
             // If the project doesn't define any recursive dependencies, take the fast path.
             Either::Left(Either::Right(self.requirements_for_extra(
+                name,
                 dependencies.iter(),
                 extra,
                 env,
@@ -2001,6 +2048,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         } else {
             let mut requirements = self
                 .requirements_for_extra(
+                    name,
                     dependencies.iter(),
                     extra,
                     env,
@@ -2022,6 +2070,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     continue;
                 }
                 for requirement in self.requirements_for_extra(
+                    name,
                     dependencies,
                     Some(&extra),
                     env,
@@ -2091,6 +2140,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     /// the markers of this fork and the requested extra.
     fn requirements_for_extra<'data, 'parameters>(
         &'data self,
+        name: Option<&'data PackageName>,
         dependencies: impl IntoIterator<Item = &'data Requirement> + 'parameters,
         extra: Option<&'parameters ExtraName>,
         env: &'parameters ResolverEnvironment,
@@ -2101,7 +2151,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         'data: 'parameters,
     {
         self.overrides
-            .apply(dependencies)
+            .apply(name, dependencies)
             .filter(move |requirement| {
                 Self::is_requirement_applicable(
                     requirement,
@@ -2294,11 +2344,20 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     }
 
     /// Fetch the metadata for a stream of packages and versions.
+    ///
+    /// As metadata arrives, the dependencies that it declares are prefetched in the background
+    /// (see [`ResolverState::prefetch_dependencies`]), such that the solver rarely stalls on the
+    /// network. The request sink is held weakly, such that the stream ends once the solver
+    /// finishes.
     async fn fetch<Provider: ResolverProvider>(
         self: Arc<Self>,
         provider: Arc<Provider>,
+        request_sink: WeakSender<Request>,
         request_stream: Receiver<Request>,
     ) -> Result<(), ResolveError> {
+        // Seed the pipeline with the preferred versions (e.g., from an existing lockfile).
+        self.prefetch_preferences(&request_sink);
+
         let mut response_stream = ReceiverStream::new(request_stream)
             .map(|request| self.process_request(request, &*provider).boxed_local())
             // Allow as many futures as possible to start in the background.
@@ -2320,6 +2379,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
                 Some(Response::Installed { dist, metadata }) => {
                     trace!("Received installed distribution metadata for: {dist}");
+                    if let MetadataResponse::Found(archive) = &metadata {
+                        self.prefetch_dependencies(&archive.metadata.requires_dist, &request_sink);
+                    }
                     self.index
                         .distributions()
                         .done(dist.version_id(), Arc::new(metadata));
@@ -2339,10 +2401,38 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             warn!("{dist} {message}");
                         }
                     }
+                    if let MetadataResponse::Found(archive) = &metadata {
+                        self.prefetch_dependencies(&archive.metadata.requires_dist, &request_sink);
+                    }
                     self.index
                         .distributions()
                         .done(dist.version_id(), Arc::new(metadata));
                 }
+                Some(Response::Speculative {
+                    package_name,
+                    versions,
+                    dist,
+                }) => {
+                    trace!("Received speculative package metadata for: {package_name}");
+                    // If the solver requested the package in the meantime, its own request is
+                    // already in flight.
+                    if self.index.implicit().register(package_name.clone()) {
+                        self.index.implicit().done(package_name, Arc::new(versions));
+                    }
+                    if let Some((dist, archive)) = dist {
+                        if self.index.distributions().register(dist.version_id()) {
+                            trace!("Received speculative distribution metadata for: {dist}");
+                            self.prefetch_dependencies(
+                                &archive.metadata.requires_dist,
+                                &request_sink,
+                            );
+                            self.index.distributions().done(
+                                dist.version_id(),
+                                Arc::new(MetadataResponse::Found(archive)),
+                            );
+                        }
+                    }
+                }
                 None => {}
             }
         }
@@ -2350,6 +2440,237 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok::<(), ResolveError>(())
     }
 
+    /// Prefetch the metadata for the preferred versions of each package, ahead of the solver
+    /// reaching them.
+    fn prefetch_preferences(&self, request_sink: &WeakSender<Request>) {
+        if !self.dependency_mode.is_transitive() {
+            return;
+        }
+        let Some(request_sink) = request_sink.upgrade() else {
+            return;
+        };
+        for (name, preferences) in self.preferences.iter() {
+            let range = preferences
+                .filter(|(_, index, _)| !matches!(index, PreferenceIndex::Explicit(_)))
+                .fold(Range::empty(), |range, (.., version)| {
+                    range.union(&Range::singleton(version.clone()))
+                });
+            if range.is_empty() {
+                continue;
+            }
+            if !self.speculate(name, range, &request_sink) {
+                break;
+            }
+        }
+    }
+
+    /// Prefetch the metadata for the dependencies of a distribution whose metadata was just
+    /// fetched, ahead of the solver reaching them.
+    ///
+    /// Only unconditional registry dependencies are prefetched: dependencies that require an extra
+    /// or that don't apply to the target environment may never be visited.
+    fn prefetch_dependencies(
+        &self,
+        requires_dist: &[Requirement],
+        request_sink: &WeakSender<Request>,
+    ) {
+        if !self.dependency_mode.is_transitive() {
+            return;
+        }
+        // If the solver has finished, there's nothing left to prefetch.
+        let Some(request_sink) = request_sink.upgrade() else {
+            return;
+        };
+        for requirement in requires_dist {
+            if !requirement
+                .marker
+                .evaluate_optional_environment(self.env.marker_environment(), &[])
+            {
+                continue;
+            }
+            let RequirementSource::Registry {
+                specifier,
+                index: None,
+                ..
+            } = &requirement.source
+            else {
+                continue;
+            };
+            if !self.speculate(
+                &requirement.name,
+                Range::from(specifier.clone()),
+                &request_sink,
+            ) {
+                break;
+            }
+        }
+    }
+
+    /// Speculatively fetch the versions of a package that the solver hasn't requested yet, along
+    /// with the metadata for its best-guess version in the given range.
+    ///
+    /// Returns `false` if the prefetch budget is exhausted.
+    fn speculate(
+        &self,
+        name: &PackageName,
+        range: Range<Version>,
+        request_sink: &Sender<Request>,
+    ) -> bool {
+        // Avoid packages that may use a URL or an explicit index, or whose requirements are
+        // overridden.
+        if self.urls.any_url(name)
+            || self.indexes.contains_key(name)
+            || self.overrides.get(name).is_some()
+        {
+            return true;
+        }
+        // Avoid packages that were already requested.
+        if self.index.implicit().contains_key(name) {
+            return true;
+        }
+        let Ok(permit) = self.prefetch_permits.clone().try_acquire_owned() else {
+            return false;
+        };
+        // Never wait on the solver's requests.
+        let Ok(slot) = request_sink.try_reserve() else {
+            return false;
+        };
+        trace!("Speculatively prefetching {name} {range}");
+        slot.send(Request::Speculative(name.clone(), range, permit));
+        true
+    }
+
+    /// Fetch the versions of a package that the solver hasn't requested yet, along with the
+    /// metadata for its best-guess wheel in the given range.
+    ///
+    /// Nothing is registered in the in-memory index here: the results are only registered once
+    /// the fetch succeeds (see [`ResolverState::fetch`]), such that the solver never waits on a
+    /// failed speculative fetch.
+    async fn fetch_speculative<Provider: ResolverProvider>(
+        &self,
+        package_name: PackageName,
+        range: &Range<Version>,
+        provider: &Provider,
+    ) -> Result<Option<Response>, ResolveError> {
+        let versions = self
+            .get_package_versions(&package_name, None, provider)
+            .await?;
+
+        // Avoid speculatively building source distributions, which could be expensive for a
+        // package that the solver may never visit.
+        let dist = if let VersionsResponse::Found(version_maps) = &versions {
+            let env = ResolverEnvironment::universal(vec![]);
+            self.selector
+                .select(
+                    &package_name,
+                    range,
+                    version_maps,
+                    &self.preferences,
+                    &self.installed_packages,
+                    &self.exclusions,
+                    None,
+                    &env,
+                    self.tags.as_ref(),
+                )
+                .and_then(|candidate| {
+                    let dist = candidate.compatible()?;
+                    let wheel = dist.wheel()?;
+                    if let Some(requires_python) = wheel.file.requires_python.as_ref() {
+                        if !self
+                            .python_requirement
+                            .target()
+                            .is_contained_by(requires_python)
+                        {
+                            return None;
+                        }
+                    }
+                    if !self
+                        .hasher
+                        .allows_package(candidate.name(), candidate.version())
+                    {
+                        return None;
+                    }
+                    match dist.for_resolution().to_owned() {
+                        ResolvedDist::Installable { dist, .. } => Some(dist),
+                        ResolvedDist::Installed { .. } => None,
+                    }
+                })
+        } else {
+            None
+        };
+
+        // Skip the distribution metadata if the solver already requested it.
+        let dist = match dist {
+            Some(dist) if !self.index.distributions().contains_key(&dist.version_id()) => {
+                let metadata = provider
+                    .get_or_build_wheel_metadata(&dist)
+                    .boxed_local()
+                    .await?;
+                match metadata {
+                    MetadataResponse::Found(archive) => {
+                        if &archive.metadata.name != dist.name() {
+                            return Err(ResolveError::MismatchedPackageName {
+                                request: "distribution metadata",
+                                expected: dist.name().clone(),
+                                actual: archive.metadata.name.clone(),
+                            });
+                        }
+                        Some(((*dist).clone(), archive))
+                    }
+                    // Leave any errors to the solver's own request, if the solver gets there.
+                    MetadataResponse::Unavailable(_) | MetadataResponse::Error(..) => None,
+                }
+            }
+            _ => None,
+        };
+
+        Ok(Some(Response::Speculative {
+            package_name,
+            versions,
+            dist,
+        }))
+    }
+
+    /// Fetch the versions of a package from the registry, removing any versions rejected by the
+    /// candidate veto.
+    async fn get_package_versions<Provider: ResolverProvider>(
+        &self,
+        package_name: &PackageName,
+        index: Option<&IndexMetadata>,
+        provider: &Provider,
+    ) -> Result<VersionsResponse, ResolveError> {
+        let mut package_versions = provider
+            .get_package_versions(package_name, index)
+            .boxed_local()
+            .await
+            .map_err(ResolveError::Client)?;
+
+        // Remove any versions rejected by the candidate veto.
+        if let Some(candidate_veto) = &self.candidate_veto {
+            if let VersionsResponse::Found(version_maps) = &mut package_versions {
+                for version_map in version_maps {
+                    let candidates = version_map.candidates();
+                    if candidates.is_empty() {
+                        continue;
+                    }
+                    let vetoed = candidate_veto
+                        .veto(package_name, version_map.index(), &candidates)
+                        .await
+                        .map_err(|err| ResolveError::CandidateVeto(package_name.clone(), err))?;
+                    if !vetoed.is_empty() {
+                        debug!(
+                            "Excluding vetoed versions of {package_name}: {}",
+                            vetoed.iter().join(", ")
+                        );
+                        version_map.remove_versions(&vetoed);
+                    }
+                }
+            }
+        }
+
+        Ok(package_versions)
+    }
+
     #[instrument(skip_all, fields(%request))]
     async fn process_request<Provider: ResolverProvider>(
         &self,
@@ -2359,11 +2680,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
-                let package_versions = provider
-                    .get_package_versions(&package_name, index.as_ref())
-                    .boxed_local()
-                    .await
-                    .map_err(ResolveError::Client)?;
+                let package_versions = self
+                    .get_package_versions(&package_name, index.as_ref(), provider)
+                    .await?;
 
                 Ok(Some(Response::Package(
                     package_name,
@@ -2390,7 +2709,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     return Ok(Some(Response::Dist {
                                         dist,
                                         metadata: MetadataResponse::Found(
-                                            ArchiveMetadata::from_metadata23(metadata.clone()),
+                                            ArchiveMetadata::from_metadata23(metadata),
                                         ),
                                     }));
                                 }
@@ -2413,7 +2732,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 return Ok(Some(Response::Dist {
                                     dist,
                                     metadata: MetadataResponse::Found(
-                                        ArchiveMetadata::from_metadata23(metadata.clone()),
+                                        ArchiveMetadata::from_metadata23(metadata),
                                     ),
                                 }));
                             }
@@ -2515,37 +2834,39 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 // If the registry provided metadata for this distribution, use it.
                 for version_map in version_map {
+                    // Check the index before deserializing the metadata, which is otherwise
+                    // discarded.
+                    let dist = dist.for_resolution();
+                    if version_map.index() != dist.index() {
+                        continue;
+                    }
                     if let Some(metadata) = version_map.get_metadata(candidate.version()) {
-                        let dist = dist.for_resolution();
-                        if version_map.index() == dist.index() {
-                            debug!("Found registry-provided metadata for: {dist}");
-
-                            let metadata = MetadataResponse::Found(
-                                ArchiveMetadata::from_metadata23(metadata.clone()),
-                            );
+                        debug!("Found registry-provided metadata for: {dist}");
+
+                        let metadata =
+                            MetadataResponse::Found(ArchiveMetadata::from_metadata23(metadata));
+
+                        let dist = dist.to_owned();
+                        if &package_name != dist.name() {
+                            return Err(ResolveError::MismatchedPackageName {
+                                request: "distribution",
+                                expected: package_name,
+                                actual: dist.name().clone(),
+                            });
+                        }
 
-                            let dist = dist.to_owned();
-                            if &package_name != dist.name() {
-                                return Err(ResolveError::MismatchedPackageName {
-                                    request: "distribution",
-                                    expected: package_name,
-                                    actual: dist.name().clone(),
-                                });
-                            }
+                        let response = match dist {
+                            ResolvedDist::Installable { dist, .. } => Response::Dist {
+                                dist: (*dist).clone(),
+                                metadata,
+                            },
+                            ResolvedDist::Installed { dist } => Response::Installed {
+                                dist: (*dist).clone(),
+                                metadata,
+                            },
+                        };
 
-                            let response = match dist {
-                                ResolvedDist::Installable { dist, .. } => Response::Dist {
-                                    dist: (*dist).clone(),
-                                    metadata,
-                                },
-                                ResolvedDist::Installed { dist } => Response::Installed {
-                                    dist: (*dist).clone(),
-                                    metadata,
-                                },
-                            };
-
-                            return Ok(Some(response));
-                        }
+                        return Ok(Some(response));
                     }
                 }
 
@@ -2629,6 +2950,23 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     Ok(None)
                 }
             }
+
+            // Speculatively pre-fetch the metadata for a dependency, holding a permit from the
+            // prefetch budget until the prefetch completes. Errors are logged and dropped.
+            Request::Speculative(package_name, range, _permit) => {
+                match self
+                    .fetch_speculative(package_name.clone(), &range, provider)
+                    .await
+                {
+                    Ok(response) => Ok(response),
+                    Err(err) => {
+                        // The solver may never visit the package; if it does, its own request
+                        // will surface the error.
+                        debug!("Ignoring failed speculative prefetch for {package_name}: {err}");
+                        Ok(None)
+                    }
+                }
+            }
         }
     }
 
@@ -2730,6 +3068,33 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        // Track the sources of the root requirements, to attribute them in the error message.
+        let mut sources = SourceAnnotations::default();
+        for requirement in &self.requirements {
+            if let Some(origin) = &requirement.origin {
+                sources.add(
+                    &requirement.name,
+                    SourceAnnotation::Requirement(origin.clone()),
+                );
+            }
+        }
+        for requirement in self.constraints.requirements() {
+            if let Some(origin) = &requirement.origin {
+                sources.add(
+                    &requirement.name,
+                    SourceAnnotation::Constraint(origin.clone()),
+                );
+            }
+        }
+        for requirement in self.overrides.requirements() {
+            if let Some(origin) = &requirement.origin {
+                sources.add(
+                    &requirement.name,
+                    SourceAnnotation::Override(origin.clone()),
+                );
+            }
+        }
+
         ResolveError::NoSolution(Box::new(NoSolutionError::new(
             err,
             self.index.clone(),
@@ -2747,6 +3112,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             current_environment,
             self.tags.clone(),
             self.workspace_members.clone(),
+            sources,
             self.options.clone(),
         )))
     }
@@ -3431,6 +3797,9 @@ pub(crate) enum Request {
     Installed(InstalledDist),
     /// A request to pre-fetch the metadata for a package and the best-guess distribution.
     Prefetch(PackageName, Range<Version>, PythonRequirement),
+    /// A request to pre-fetch the metadata for the best-guess distribution of a dependency that
+    /// the solver hasn't visited yet, within the prefetch budget.
+    Speculative(PackageName, Range<Version>, OwnedSemaphorePermit),
 }
 
 impl<'a> From<ResolvedDistRef<'a>> for Request {
@@ -3485,6 +3854,9 @@ impl Display for Request {
             Self::Prefetch(package_name, range, _) => {
                 write!(f, "Prefetch {package_name} {range}")
             }
+            Self::Speculative(package_name, range, _) => {
+                write!(f, "Speculative prefetch {package_name} {range}")
+            }
         }
     }
 }
@@ -3504,6 +3876,13 @@ enum Response {
         dist: InstalledDist,
         metadata: MetadataResponse,
     },
+    /// The returned metadata for a speculatively prefetched package, along with the metadata for
+    /// its best-guess wheel, if any.
+    Speculative {
+        package_name: PackageName,
+        versions: VersionsResponse,
+        dist: Option<(Dist, ArchiveMetadata)>,
+    },
 }
 
 /// Information about the dependencies for a particular package.
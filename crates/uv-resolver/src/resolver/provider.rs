@@ -4,8 +4,8 @@ use uv_client::MetadataFormat;
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
 use uv_distribution_types::{
-    Dist, IndexCapabilities, IndexMetadata, IndexMetadataRef, InstalledDist, RequestedDist,
-    RequiresPython,
+    DependencyMetadata, Dist, IndexCapabilities, IndexMetadata, IndexMetadataRef, InstalledDist,
+    RequestedDist, RequiresPython,
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
@@ -119,6 +119,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     exclude_newer: ExcludeNewer,
     build_options: &'a BuildOptions,
     capabilities: &'a IndexCapabilities,
+    dependency_metadata: &'a DependencyMetadata,
 }
 
 impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
@@ -133,6 +134,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         exclude_newer: ExcludeNewer,
         build_options: &'a BuildOptions,
         capabilities: &'a IndexCapabilities,
+        dependency_metadata: &'a DependencyMetadata,
     ) -> Self {
         Self {
             fetcher,
@@ -144,6 +146,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             exclude_newer,
             build_options,
             capabilities,
+            dependency_metadata,
         }
     }
 }
@@ -168,8 +171,17 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
             })
             .await;
 
-        // If a package is pinned to an explicit index, ignore any `--find-links` entries.
-        let flat_index = index.is_none().then_some(&self.flat_index);
+        // If a package is pinned to an explicit index (or matches an index namespace), ignore any
+        // `--find-links` entries.
+        let flat_index = (index.is_none()
+            && self
+                .fetcher
+                .client()
+                .unmanaged
+                .index_urls()
+                .namespace_for(package_name)
+                .is_none())
+        .then_some(&self.flat_index);
 
         match result {
             Ok(results) => Ok(VersionsResponse::Found(
@@ -189,6 +201,7 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                                 .and_then(|flat_index| flat_index.get(package_name))
                                 .cloned(),
                             self.build_options,
+                            self.dependency_metadata,
                         ),
                         MetadataFormat::Flat(metadata) => VersionMap::from_flat_metadata(
                             metadata,
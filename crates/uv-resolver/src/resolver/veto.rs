@@ -0,0 +1,31 @@
+use futures::future::LocalBoxFuture;
+
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// The error returned by a [`CandidateVeto`].
+pub type CandidateVetoError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A hook to reject individual versions of a package before the resolver considers them.
+///
+/// The hook is invoked once per package and index, as soon as the available versions are known,
+/// such that any rejected versions are treated as though they don't exist on the index.
+pub trait CandidateVeto: Send + Sync {
+    /// Return the versions of the package that should be excluded from the resolution.
+    fn veto<'a>(
+        &'a self,
+        package: &'a PackageName,
+        index: Option<&'a IndexUrl>,
+        candidates: &'a [VetoCandidate],
+    ) -> LocalBoxFuture<'a, Result<Vec<Version>, CandidateVetoError>>;
+}
+
+/// A version of a package that's available to the resolver.
+#[derive(Debug, Clone)]
+pub struct VetoCandidate {
+    /// The version of the package.
+    pub version: Version,
+    /// The earliest upload time across the distributions for the version, if known.
+    pub upload_time: Option<jiff::Timestamp>,
+}
@@ -4,16 +4,15 @@ use std::ops::RangeBounds;
 use std::sync::OnceLock;
 
 use pubgrub::Ranges;
-use rustc_hash::FxHashMap;
 use tracing::instrument;
 
 use uv_client::{FlatIndexEntry, OwnedArchive, SimpleMetadata, VersionFiles};
 use uv_configuration::BuildOptions;
 use uv_distribution_filename::{DistFilename, WheelFilename};
 use uv_distribution_types::{
-    HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl, PrioritizedDist,
-    RegistryBuiltWheel, RegistrySourceDist, RequiresPython, SourceDistCompatibility,
-    WheelCompatibility,
+    DependencyMetadata, HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl,
+    PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist, RequiresPython,
+    SourceDistCompatibility, WheelCompatibility,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -23,7 +22,7 @@ use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatDistributions;
-use crate::{ExcludeNewer, ExcludeNewerTimestamp, yanks::AllowedYanks};
+use crate::{ExcludeNewer, ExcludeNewerTimestamp, VetoCandidate, yanks::AllowedYanks};
 
 /// A map from versions to distributions.
 #[derive(Debug)]
@@ -54,29 +53,21 @@ impl VersionMap {
         exclude_newer: Option<&ExcludeNewer>,
         flat_index: Option<FlatDistributions>,
         build_options: &BuildOptions,
+        dependency_metadata: &DependencyMetadata,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
         let mut map = BTreeMap::new();
-        let mut core_metadata = FxHashMap::default();
         // Create stubs for each entry in simple metadata. The full conversion
         // from a `VersionFiles` to a PrioritizedDist for each version
-        // isn't done until that specific version is requested.
+        // isn't done until that specific version is requested. Similarly, any
+        // registry-provided metadata isn't deserialized until it's requested,
+        // since most versions are never considered by the resolver.
         for (datum_index, datum) in simple_metadata.iter().enumerate() {
             // Deserialize the version.
             let version = rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
                 .expect("archived version always deserializes");
 
-            // Deserialize the metadata.
-            let core_metadatum =
-                rkyv::deserialize::<Option<ResolutionMetadata>, rkyv::rancor::Error>(
-                    &datum.metadata,
-                )
-                .expect("archived metadata always deserializes");
-            if let Some(core_metadatum) = core_metadatum {
-                core_metadata.insert(version.clone(), core_metadatum);
-            }
-
             stable |= version.is_stable();
             local |= version.is_local();
             map.insert(
@@ -118,7 +109,6 @@ impl VersionMap {
                 map,
                 stable,
                 local,
-                core_metadata,
                 simple_metadata,
                 no_binary: build_options.no_binary_package(package_name),
                 no_build: build_options.no_build_package(package_name),
@@ -127,7 +117,10 @@ impl VersionMap {
                 allowed_yanks: allowed_yanks.clone(),
                 hasher: hasher.clone(),
                 requires_python: requires_python.clone(),
+                package_name: package_name.clone(),
+                dependency_metadata: dependency_metadata.subset(package_name),
                 exclude_newer: exclude_newer.and_then(|en| en.exclude_newer_package(package_name)),
+                as_of: exclude_newer.is_some_and(|en| en.as_of),
             }),
         }
     }
@@ -157,10 +150,10 @@ impl VersionMap {
     }
 
     /// Return the [`ResolutionMetadata`] for the given version, if any.
-    pub fn get_metadata(&self, version: &Version) -> Option<&ResolutionMetadata> {
+    pub fn get_metadata(&self, version: &Version) -> Option<ResolutionMetadata> {
         match self.inner {
             VersionMapInner::Eager(_) => None,
-            VersionMapInner::Lazy(ref lazy) => lazy.core_metadata.get(version),
+            VersionMapInner::Lazy(ref lazy) => lazy.get_metadata(version),
         }
     }
 
@@ -258,6 +251,53 @@ impl VersionMap {
         }
     }
 
+    /// Return the versions in this map, along with the earliest upload time of each version's
+    /// distributions, for use by a [`CandidateVeto`](crate::CandidateVeto).
+    pub(crate) fn candidates(&self) -> Vec<VetoCandidate> {
+        self.versions()
+            .map(|version| VetoCandidate {
+                version: version.clone(),
+                upload_time: self
+                    .upload_time(version)
+                    .and_then(|upload_time| jiff::Timestamp::from_millisecond(upload_time).ok()),
+            })
+            .collect()
+    }
+
+    /// Return the earliest upload time, in milliseconds since the epoch, of the distributions for
+    /// the given version, if any.
+    fn upload_time(&self, version: &Version) -> Option<i64> {
+        match self.inner {
+            VersionMapInner::Eager(ref eager) => eager
+                .map
+                .get(version)?
+                .files()
+                .filter_map(|file| file.upload_time_utc_ms)
+                .min(),
+            VersionMapInner::Lazy(ref lazy) => lazy.upload_time(version),
+        }
+    }
+
+    /// Remove the given versions from this map, such that they're treated as unavailable.
+    pub(crate) fn remove_versions(&mut self, versions: &[Version]) {
+        match self.inner {
+            VersionMapInner::Eager(ref mut eager) => {
+                for version in versions {
+                    eager.map.remove(version);
+                }
+                eager.stable = eager.map.keys().any(Version::is_stable);
+                eager.local = eager.map.keys().any(Version::is_local);
+            }
+            VersionMapInner::Lazy(ref mut lazy) => {
+                for version in versions {
+                    lazy.map.remove(version);
+                }
+                lazy.stable = lazy.map.keys().any(Version::is_stable);
+                lazy.local = lazy.map.keys().any(Version::is_local);
+            }
+        }
+    }
+
     /// Returns the total number of distinct versions in this map.
     ///
     /// Note that this may include versions of distributions that are not
@@ -375,8 +415,6 @@ struct VersionMapLazy {
     stable: bool,
     /// Whether the version map contains at least one local version.
     local: bool,
-    /// The pre-populated metadata for each version.
-    core_metadata: FxHashMap<Version, ResolutionMetadata>,
     /// The raw simple metadata from which `PrioritizedDist`s should
     /// be constructed.
     simple_metadata: OwnedArchive<SimpleMetadata>,
@@ -391,12 +429,20 @@ struct VersionMapLazy {
     tags: Option<Tags>,
     /// Whether files newer than this timestamp should be excluded or not.
     exclude_newer: Option<ExcludeNewerTimestamp>,
+    /// Whether to resolve as of the `exclude_newer` cutoff, in which case files that were yanked
+    /// after the cutoff are considered available.
+    as_of: bool,
     /// Which yanked versions are allowed
     allowed_yanks: AllowedYanks,
     /// The hashes of allowed distributions.
     hasher: HashStrategy,
     /// The `requires-python` constraint for the resolution.
     requires_python: RequiresPython,
+    /// The name of the package.
+    package_name: PackageName,
+    /// The user-provided metadata for the package, which takes precedence over any metadata
+    /// provided by the registry.
+    dependency_metadata: DependencyMetadata,
 }
 
 impl VersionMapLazy {
@@ -407,6 +453,68 @@ impl VersionMapLazy {
         Some(priority_dist)
     }
 
+    /// Returns the registry-provided metadata for the given version, if it exists.
+    ///
+    /// The metadata is deserialized from the underlying `SimpleMetadata` on each access, rather
+    /// than retained: the resolver stores the metadata in its own index once requested, so
+    /// retaining it here would keep a second copy of every requested version's metadata alive for
+    /// the duration of the resolution.
+    fn get_metadata(&self, version: &Version) -> Option<ResolutionMetadata> {
+        let simple = match self.map.get(version)? {
+            LazyPrioritizedDist::OnlyFlat(_) => return None,
+            LazyPrioritizedDist::OnlySimple(simple) => simple,
+            LazyPrioritizedDist::Both { simple, .. } => simple,
+        };
+        // If the metadata was provided by the user directly, prefer it.
+        if let Some(metadata) = self.user_metadata(version) {
+            return Some(metadata);
+        }
+        rkyv::deserialize::<Option<ResolutionMetadata>, rkyv::rancor::Error>(
+            &self
+                .simple_metadata
+                .datum(simple.datum_index)
+                .expect("index to lazy dist is correct")
+                .metadata,
+        )
+        .expect("archived metadata always deserializes")
+    }
+
+    /// Returns the earliest upload time of the distributions for the given version, without
+    /// materializing the distribution.
+    fn upload_time(&self, version: &Version) -> Option<i64> {
+        let (flat, simple) = match self.map.get(version)? {
+            LazyPrioritizedDist::OnlyFlat(flat) => (Some(flat), None),
+            LazyPrioritizedDist::OnlySimple(simple) => (None, Some(simple)),
+            LazyPrioritizedDist::Both { flat, simple } => (Some(flat), Some(simple)),
+        };
+        let flat = flat
+            .into_iter()
+            .flat_map(PrioritizedDist::files)
+            .filter_map(|file| file.upload_time_utc_ms);
+        let simple = simple.into_iter().flat_map(|simple| {
+            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(
+                &self
+                    .simple_metadata
+                    .datum(simple.datum_index)
+                    .expect("index to lazy dist is correct")
+                    .files,
+            )
+            .expect("archived version files always deserializes")
+            .all()
+            .filter_map(|(_, file)| file.upload_time_utc_ms)
+        });
+        flat.chain(simple).min()
+    }
+
+    /// Returns the user-provided metadata for the given version, if it exists.
+    fn user_metadata(&self, version: &Version) -> Option<ResolutionMetadata> {
+        if self.dependency_metadata.is_empty() {
+            return None;
+        }
+        self.dependency_metadata
+            .get(&self.package_name, Some(version))
+    }
+
     /// Given a reference to a possibly-initialized distribution that is in
     /// this lazy map, return the corresponding distribution.
     ///
@@ -442,7 +550,13 @@ impl VersionMapLazy {
             )
             .expect("archived version files always deserializes");
             let mut priority_dist = init.cloned().unwrap_or_default();
-            for (filename, file) in files.all() {
+            for (filename, mut file) in files.all() {
+                // If the metadata was provided by the user directly, its `requires-python` takes
+                // precedence over the `requires-python` reported by the registry.
+                if let Some(metadata) = self.user_metadata(filename.version()) {
+                    file.requires_python = metadata.requires_python;
+                }
+
                 // Support resolving as if it were an earlier timestamp, at least as long files have
                 // upload time information.
                 let (excluded, upload_time) = if let Some(exclude_newer) = &self.exclude_newer {
@@ -464,7 +578,27 @@ impl VersionMapLazy {
                 };
 
                 // Prioritize amongst all available files.
-                let yanked = file.yanked.as_deref();
+                // When resolving as of a cutoff, files that were yanked after the cutoff were still
+                // available at the time. If the index doesn't report when a file was yanked, we
+                // retain the yank.
+                let yanked = match (&self.exclude_newer, file.yanked.as_deref()) {
+                    (Some(exclude_newer), Some(yanked)) if self.as_of && yanked.is_yanked() => {
+                        match file.yanked_time_utc_ms {
+                            Some(yanked_time) if yanked_time > exclude_newer.timestamp_millis() => {
+                                None
+                            }
+                            Some(_) => Some(yanked),
+                            None => {
+                                warn_user_once!(
+                                    "{} is yanked, but the index doesn't report when it was yanked; treating it as yanked as of: {exclude_newer}",
+                                    file.filename,
+                                );
+                                Some(yanked)
+                            }
+                        }
+                    }
+                    (_, yanked) => yanked,
+                };
                 let hashes = file.hashes.clone();
                 match filename {
                     DistFilename::WheelFilename(filename) => {
@@ -8,10 +8,11 @@ use uv_configuration::{
     TargetTriple, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    ConfigSettings, ExtraBuildVariables, Index, IndexNamespaces, IndexUrl, PackageConfigSettings,
+    PipExtraIndex, PipFindLinks, PipIndex,
 };
 use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
@@ -23,7 +24,9 @@ use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{
+    CacheOptions, FilesystemOptions, Options, PipOptions, Plugins, TerminalColor, TerminalOptions,
+};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -72,6 +75,24 @@ impl Combine for Option<PipOptions> {
     }
 }
 
+impl Combine for Option<CacheOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<TerminalOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 macro_rules! impl_combine_or {
     ($name:ident) => {
         impl Combine for Option<$name> {
@@ -109,10 +130,12 @@ impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
 impl_combine_or!(TargetTriple);
+impl_combine_or!(TerminalColor);
 impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -143,6 +166,21 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for Option<BTreeMap<PackageName, u64>> {
+    /// Combine two maps by merging them, with the values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
@@ -159,6 +197,17 @@ impl Combine for Option<ExcludeNewerPackage> {
     }
 }
 
+impl Combine for Option<IndexNamespaces> {
+    /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
+    /// `Some`.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
@@ -181,6 +230,17 @@ impl Combine for Option<PackageConfigSettings> {
     }
 }
 
+impl Combine for Option<Plugins> {
+    /// Combine two sets of plugins, preferring the plugins in `self` when both define a plugin
+    /// with the same name.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<Upgrade> {
     fn combine(self, other: Self) -> Self {
         match (self, other) {
@@ -222,6 +282,9 @@ impl Combine for Option<serde::de::IgnoredAny> {
 
 impl Combine for ExcludeNewer {
     fn combine(mut self, other: Self) -> Self {
+        if self.global.is_none() {
+            self.as_of = other.as_of;
+        }
         self.global = self.global.combine(other.global);
 
         if !other.package.is_empty() {
@@ -235,6 +298,11 @@ impl Combine for ExcludeNewer {
             }
         }
 
+        self.global_days = self.global_days.combine(other.global_days);
+        for (pkg, days) in other.package_days {
+            self.package_days.entry(pkg).or_insert(days);
+        }
+
         self
     }
 }
@@ -7,9 +7,13 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+#[cfg(feature = "schemars")]
+pub use crate::schema::json_schema;
 pub use crate::settings::*;
 
 mod combine;
+#[cfg(feature = "schemars")]
+mod schema;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
@@ -177,6 +181,19 @@ impl FilesystemOptions {
         validate_uv_toml(path, &options)?;
         Ok(Self(options))
     }
+
+    /// Load a [`FilesystemOptions`] from the contents of a `uv.toml` file, as if the file were
+    /// located in the given directory.
+    ///
+    /// This allows embedders to construct settings programmatically (e.g., from an in-memory
+    /// document) while applying the same parsing and validation as a `uv.toml` file on disk.
+    /// Relative paths are resolved against `dir`.
+    pub fn from_toml_str(content: &str, dir: &Path) -> Result<Self, Error> {
+        let path = dir.join("uv.toml");
+        let options = parse_uv_toml(content, &path)?;
+        validate_uv_toml(&path, &options)?;
+        Ok(Self(options))
+    }
 }
 
 impl From<Options> for FilesystemOptions {
@@ -188,7 +205,13 @@ impl From<Options> for FilesystemOptions {
 /// Load [`Options`] from a `uv.toml` file.
 fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
-    let options = toml::from_str::<Options>(&content)
+    parse_uv_toml(&content, path)
+}
+
+/// Parse [`Options`] from the contents of a `uv.toml` file at the given path, resolving relative
+/// paths against the file's parent directory.
+fn parse_uv_toml(content: &str, path: &Path) -> Result<Options, Error> {
+    let options = toml::from_str::<Options>(content)
         .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
     let options = if let Some(parent) = std::path::absolute(path)?.parent() {
         options.relative_to(parent)?
@@ -207,12 +230,17 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         publish: _,
         add: _,
         pip: _,
+        terminal: _,
+        cache: _,
         cache_keys: _,
+        plugins: _,
         override_dependencies: _,
         constraint_dependencies: _,
         build_constraint_dependencies: _,
         environments,
         required_environments,
+        environment_assumptions,
+        torch_backend,
         conflicts,
         workspace,
         sources,
@@ -221,6 +249,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dependency_groups,
         managed,
         package,
+        environment_path,
+        profiles,
+        named_environments,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -259,12 +290,27 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if environment_path.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "environment-path",
+        ));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
             "build-backend",
         ));
     }
+    if profiles.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "profiles"));
+    }
+    if named_environments.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "named-environments",
+        ));
+    }
     if environments.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -277,6 +323,18 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "required-environments",
         ));
     }
+    if environment_assumptions.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "environment-assumptions",
+        ));
+    }
+    if torch_backend.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "torch-backend",
+        ));
+    }
     Ok(())
 }
 
@@ -298,7 +356,10 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_compiles,
+                concurrent_prefetches,
                 allow_insecure_host,
+                mirror_manifest,
             },
         top_level:
             ResolverInstallerSchema {
@@ -307,11 +368,13 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 extra_index_url,
                 no_index,
                 find_links,
+                index_namespaces,
                 index_strategy,
                 keyring_provider,
                 resolution,
                 prerelease,
                 fork_strategy,
+                allow_insecure_md5_hashes,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -321,6 +384,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 extra_build_variables,
                 exclude_newer,
                 exclude_newer_package,
+                exclude_newer_than_days,
+                exclude_newer_than_days_package,
                 link_mode,
                 compile_bytecode,
                 no_sources,
@@ -347,12 +412,17 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             },
         add: AddOptions { add_bounds },
         pip,
+        terminal,
+        cache,
         cache_keys,
+        plugins,
         override_dependencies,
         constraint_dependencies,
         build_constraint_dependencies,
         environments: _,
         required_environments: _,
+        environment_assumptions: _,
+        torch_backend: _,
         conflicts: _,
         workspace: _,
         sources: _,
@@ -361,6 +431,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dependency_groups: _,
         managed: _,
         package: _,
+        environment_path: _,
+        profiles: _,
+        named_environments: _,
         build_backend: _,
     } = options;
 
@@ -399,9 +472,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if concurrent_installs.is_some() {
         masked_fields.push("concurrent-installs");
     }
+    if concurrent_compiles.is_some() {
+        masked_fields.push("concurrent-compiles");
+    }
+    if concurrent_prefetches.is_some() {
+        masked_fields.push("concurrent-prefetches");
+    }
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
+    if mirror_manifest.is_some() {
+        masked_fields.push("mirror-manifest");
+    }
     if index.is_some() {
         masked_fields.push("index");
     }
@@ -417,6 +499,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if find_links.is_some() {
         masked_fields.push("find-links");
     }
+    if index_namespaces.is_some() {
+        masked_fields.push("index-namespaces");
+    }
     if index_strategy.is_some() {
         masked_fields.push("index-strategy");
     }
@@ -432,6 +517,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if allow_insecure_md5_hashes.is_some() {
+        masked_fields.push("allow-insecure-md5-hashes");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -459,6 +547,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if exclude_newer_package.is_some() {
         masked_fields.push("exclude-newer-package");
     }
+    if exclude_newer_than_days.is_some() {
+        masked_fields.push("exclude-newer-than-days");
+    }
+    if exclude_newer_than_days_package.is_some() {
+        masked_fields.push("exclude-newer-than-days-package");
+    }
     if link_mode.is_some() {
         masked_fields.push("link-mode");
     }
@@ -516,9 +610,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if pip.is_some() {
         masked_fields.push("pip");
     }
+    if terminal.is_some() {
+        masked_fields.push("terminal");
+    }
+    if cache.is_some() {
+        masked_fields.push("cache");
+    }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
+    if plugins.is_some() {
+        masked_fields.push("plugins");
+    }
     if override_dependencies.is_some() {
         masked_fields.push("override-dependencies");
     }
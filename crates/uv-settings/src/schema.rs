@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use uv_workspace::pyproject::ToolUv as WorkspaceOptions;
+
+use crate::Options as SettingsOptions;
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+// The names and docstrings of this struct and the types it contains are used as `title` and
+// `description` in uv.schema.json, see https://github.com/SchemaStore/schemastore/blob/master/editor-features.md#title-as-an-expected-object-type
+/// Metadata and configuration for uv.
+struct CombinedOptions {
+    #[serde(flatten)]
+    options: SettingsOptions,
+    #[serde(flatten)]
+    workspace: WorkspaceOptions,
+}
+
+const REPLACEMENTS: &[(&str, &str)] = &[
+    // Use the fully-resolved URL rather than the relative Markdown path.
+    (
+        "(../concepts/projects/dependencies.md)",
+        "(https://docs.astral.sh/uv/concepts/projects/dependencies/)",
+    ),
+];
+
+/// Generate the JSON Schema for the settings in a `uv.toml` file or the `[tool.uv]` section of a
+/// `pyproject.toml`, as a pretty-printed string.
+pub fn json_schema() -> String {
+    let settings = schemars::generate::SchemaSettings::draft07();
+    let generator = schemars::SchemaGenerator::new(settings);
+    let schema = generator.into_root_schema_for::<CombinedOptions>();
+
+    let mut output = serde_json::to_string_pretty(&schema).unwrap();
+
+    for (value, replacement) in REPLACEMENTS {
+        assert_ne!(
+            value, replacement,
+            "`value` and `replacement` must be different, but both are `{value}`"
+        );
+        let before = &output;
+        let after = output.replace(value, replacement);
+        assert_ne!(*before, after, "Could not find `{value}` in the output");
+        output = after;
+    }
+
+    output
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
@@ -8,14 +9,14 @@ use uv_configuration::{
     RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PackageConfigSettings,
-    PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    ConfigSettings, ExtraBuildVariables, Index, IndexNamespaces, IndexUrl, IndexUrlError,
+    PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::Requirement;
-use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
+use uv_pypi_types::{OverrideDependency, SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
@@ -66,6 +67,12 @@ pub struct Options {
     #[option_group]
     pub pip: Option<PipOptions>,
 
+    #[option_group]
+    pub terminal: Option<TerminalOptions>,
+
+    #[option_group]
+    pub cache: Option<CacheOptions>,
+
     /// The keys to consider when caching builds for the project.
     ///
     /// Cache keys enable you to specify the files or directories that should trigger a rebuild when
@@ -110,11 +117,44 @@ pub struct Options {
     )]
     pub cache_keys: Option<Vec<CacheKey>>,
 
+    /// Plugins to invoke at well-defined points during resolution and installation.
+    ///
+    /// Each plugin is an external command, keyed by name, that receives a JSON description of the
+    /// event on standard input, including the affected packages. A plugin can observe an event, or
+    /// veto it by exiting with a non-zero status, in which case uv aborts the operation and
+    /// displays the plugin's standard error output (e.g., to enforce an organization policy).
+    ///
+    /// Plugins are invoked with the `resolve` event when `uv pip install`, `uv lock`, or `uv sync`
+    /// produce a new resolution (before the lockfile is written), and with the `install` event
+    /// before `uv pip install` or `uv sync` modify an environment. Use `events` to limit the
+    /// events for which a plugin is invoked.
+    ///
+    /// Plugins that opt in to the `candidates` event are started once per resolution. For each
+    /// package, such a plugin receives the versions available on an index (and their upload times)
+    /// as a single line of JSON on standard input, and must respond with a single line of JSON on
+    /// standard output. A plugin can reject individual versions by responding with
+    /// `{"reject": ["1.0.0"]}`, in which case the resolver treats those versions as unavailable.
+    /// Standard input is closed once the resolution completes.
+    ///
+    /// Instead of a `command`, a plugin can provide the path to a WebAssembly (WASI) module as
+    /// `wasm`. uv doesn't embed a WebAssembly runtime: the module is run with the `wasmtime` CLI
+    /// (which must be available on the `PATH`), as though it were a `command`.
+    ///
+    /// Commands are run from the directory containing the configuration file.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            plugins = { policy = { command = ["python", "scripts/policy.py"], events = ["resolve"] } }
+        "#
+    )]
+    pub plugins: Option<Plugins>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
     #[cfg_attr(feature = "schemars", schemars(skip))]
-    pub override_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    pub override_dependencies: Option<Vec<OverrideDependency>>,
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -128,6 +168,12 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub required_environments: Option<SupportedEnvironments>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub environment_assumptions: Option<SupportedEnvironments>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub torch_backend: Option<TorchMode>,
+
     // NOTE(charlie): These fields should be kept in-sync with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're only respected in `pyproject.toml` files, and should be rejected in `uv.toml` files.
@@ -155,6 +201,15 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub environment_path: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub profiles: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub named_environments: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_backend: Option<serde::de::IgnoredAny>,
 }
@@ -174,6 +229,7 @@ impl Options {
         Ok(Self {
             top_level: self.top_level.relative_to(root_dir)?,
             pip: self.pip.map(|pip| pip.relative_to(root_dir)).transpose()?,
+            plugins: self.plugins.map(|plugins| plugins.relative_to(root_dir)),
             ..self
         })
     }
@@ -309,6 +365,28 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The maximum number of Python processes that uv will use to compile bytecode concurrently.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-compiles = 4
+        "#
+    )]
+    pub concurrent_compiles: Option<NonZeroUsize>,
+    /// The maximum number of package versions for which uv will prefetch metadata in a single
+    /// batch during resolution, and the maximum number of dependencies for which uv will prefetch
+    /// metadata in the background at once.
+    #[option(
+        default = "50",
+        value_type = "int",
+        example = r#"
+            concurrent-prefetches = 10
+        "#
+    )]
+    pub concurrent_prefetches: Option<NonZeroUsize>,
     /// Allow insecure connections to host.
     ///
     /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
@@ -325,6 +403,23 @@ pub struct GlobalOptions {
         "#
     )]
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
+    /// Path to a manifest that maps upstream URLs to internal mirrors.
+    ///
+    /// The manifest is a TOML file with a list of `[[mirror]]` entries, each of which maps either
+    /// an exact `url` or a URL `prefix` to a `mirror`. Exact entries may include the `sha256` of
+    /// the mirrored file, which is verified after downloading.
+    ///
+    /// The manifest is consulted for all downloads, including managed Python installations and
+    /// the seed packages installed by `uv venv --seed`, allowing air-gapped environments to
+    /// redirect them to an internal mirror.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            mirror-manifest = "/etc/uv/mirrors.toml"
+        "#
+    )]
+    pub mirror_manifest: Option<PathBuf>,
 }
 
 /// Settings relevant to all installer operations.
@@ -358,11 +453,13 @@ pub struct ResolverOptions {
     pub extra_index_url: Option<Vec<PipExtraIndex>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
+    pub index_namespaces: Option<IndexNamespaces>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub allow_insecure_md5_hashes: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -388,11 +485,13 @@ pub struct ResolverInstallerOptions {
     pub extra_index_url: Option<Vec<PipExtraIndex>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<PipFindLinks>>,
+    pub index_namespaces: Option<IndexNamespaces>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub allow_insecure_md5_hashes: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -401,6 +500,8 @@ pub struct ResolverInstallerOptions {
     pub extra_build_variables: Option<ExtraBuildVariables>,
     pub exclude_newer: Option<ExcludeNewerTimestamp>,
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    pub exclude_newer_than_days: Option<u64>,
+    pub exclude_newer_than_days_package: Option<BTreeMap<PackageName, u64>>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
     pub no_sources: Option<bool>,
@@ -420,11 +521,13 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             extra_index_url,
             no_index,
             find_links,
+            index_namespaces,
             index_strategy,
             keyring_provider,
             resolution,
             prerelease,
             fork_strategy,
+            allow_insecure_md5_hashes,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -434,6 +537,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             extra_build_variables,
             exclude_newer,
             exclude_newer_package,
+            exclude_newer_than_days,
+            exclude_newer_than_days_package,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -452,11 +557,13 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             extra_index_url,
             no_index,
             find_links,
+            index_namespaces,
             index_strategy,
             keyring_provider,
             resolution,
             prerelease,
             fork_strategy,
+            allow_insecure_md5_hashes,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -468,6 +575,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             extra_build_variables,
             exclude_newer,
             exclude_newer_package,
+            exclude_newer_than_days,
+            exclude_newer_than_days_package,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -634,6 +743,26 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub find_links: Option<Vec<PipFindLinks>>,
+    /// Restrict packages whose names match a pattern to a named index.
+    ///
+    /// Maps package name patterns, in which `*` matches any sequence of characters, to the name of
+    /// an index defined via `[[tool.uv.index]]`. Packages matching a pattern may only be resolved
+    /// from the given index, regardless of the index strategy or any other configured indexes,
+    /// which guards against "dependency confusion" attacks in which an attacker publishes a
+    /// package of the same name to a public index. Matching packages can't be provided by direct
+    /// URL, path, or Git sources, or by `--find-links` entries, with the exception of workspace
+    /// members. If multiple patterns match a package, the longest pattern takes precedence.
+    ///
+    /// The pinned index is also verified against the lockfile when syncing, such that a package
+    /// locked from any other index (or from a source other than an index) is rejected.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            index-namespaces = { "mycorp-*" = "internal" }
+        "#
+    )]
+    pub index_namespaces: Option<IndexNamespaces>,
     /// The strategy to use when resolving against multiple index URLs.
     ///
     /// By default, uv will stop at the first index on which a given package is available, and
@@ -707,6 +836,21 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Allow distributions for which the index only provides MD5 hashes.
+    ///
+    /// By default, uv refuses to lock (or generate hashes for) a distribution when the index only
+    /// provides an MD5 hash for it, as MD5 is not collision-resistant.
+    ///
+    /// WARNING: MD5 hashes do not protect against tampered distributions. Only enable this setting
+    /// for indexes you trust.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            allow-insecure-md5-hashes = true
+        "#
+    )]
+    pub allow_insecure_md5_hashes: Option<bool>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -825,6 +969,37 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    /// Limit candidate packages to those that were uploaded at least the given number of days
+    /// ago.
+    ///
+    /// Unlike `exclude-newer`, the cutoff moves with the current time, such that recently
+    /// published releases are avoided without pinning a fixed date. If both are set, the earlier
+    /// cutoff applies.
+    ///
+    /// The number of days is recorded in the lockfile, such that the lockfile remains valid as
+    /// time passes; run `uv lock --upgrade` to pick up releases that have since aged past the
+    /// cutoff.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            exclude-newer-than-days = 14
+        "#
+    )]
+    pub exclude_newer_than_days: Option<u64>,
+    /// Limit candidate packages for specific packages to those that were uploaded at least the
+    /// given number of days ago.
+    ///
+    /// Accepts package-days pairs in a dictionary format, overriding `exclude-newer-than-days`
+    /// for the given packages (e.g., `0` to accept the latest releases of a trusted package).
+    #[option(
+        default = "None",
+        value_type = "dict",
+        example = r#"
+            exclude-newer-than-days-package = { ruff = 0, tqdm = 30 }
+        "#
+    )]
+    pub exclude_newer_than_days_package: Option<BTreeMap<PackageName, u64>>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -958,7 +1133,7 @@ pub struct ResolverInstallerSchema {
 }
 
 /// Shared settings, relevant to all operations that might create managed python installations.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PythonInstallMirrors {
@@ -1415,6 +1590,21 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Allow distributions for which the index only provides MD5 hashes.
+    ///
+    /// By default, uv refuses to lock (or generate hashes for) a distribution when the index only
+    /// provides an MD5 hash for it, as MD5 is not collision-resistant.
+    ///
+    /// WARNING: MD5 hashes do not protect against tampered distributions. Only enable this setting
+    /// for indexes you trust.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            allow-insecure-md5-hashes = true
+        "#
+    )]
+    pub allow_insecure_md5_hashes: Option<bool>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1660,8 +1850,80 @@ pub struct PipOptions {
         "#
     )]
     pub emit_index_annotation: Option<bool>,
+    /// Include comment annotations indicating the license of each package, as reported by its
+    /// metadata (e.g., `# license: MIT`).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-license = true
+        "#
+    )]
+    pub emit_license: Option<bool>,
+    /// Include comment annotations indicating the homepage of each package, as reported by its
+    /// metadata (e.g., `# homepage: https://example.com`).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-homepage = true
+        "#
+    )]
+    pub emit_homepage: Option<bool>,
+    /// Include comment annotations indicating the latest version of each package known to the
+    /// index, if it's newer than the resolved version (e.g., `# latest: 2.0.0`).
+    ///
+    /// Only versions that could have been selected are considered, such that yanked versions and
+    /// versions excluded by `exclude-newer` are skipped. Pre-releases are only considered for
+    /// packages that were resolved to a pre-release.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-latest-version = true
+        "#
+    )]
+    pub emit_latest_version: Option<bool>,
+    /// Include a summary comment at the end of the output file generated by `uv pip compile`,
+    /// with the number of resolved packages and the number of packages resolved from each index.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-summary = true
+        "#
+    )]
+    pub emit_summary: Option<bool>,
+    /// Include a comment at the end of the output file generated by `uv pip compile` with the
+    /// markers on which a universal resolution forked, along with the versions selected in each
+    /// fork for any package that was resolved to more than one version.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-fork-annotation = true
+        "#
+    )]
+    pub emit_fork_annotation: Option<bool>,
+    /// Include a provenance block in the header of the output file generated by `uv pip compile`,
+    /// recording the uv version, the hashes of the input files, the index URLs (without
+    /// credentials), the `exclude-newer` cutoff, and the targeted Python range.
+    ///
+    /// The provenance block can be checked against the current inputs with
+    /// `uv pip compile --verify`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-provenance = true
+        "#
+    )]
+    pub emit_provenance: Option<bool>,
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
+    ///
+    /// Trailing comments on the input requirements (e.g., `flask  # pinned for CI`) are carried
+    /// over to the output file, alongside the annotations for the corresponding package.
     #[option(
         default = "\"split\"",
         value_type = "str",
@@ -1861,11 +2123,13 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            index_namespaces: value.index_namespaces,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            allow_insecure_md5_hashes: value.allow_insecure_md5_hashes,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -1877,6 +2141,10 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
                     .into_iter()
                     .map(Into::into)
                     .collect(),
+            )
+            .with_days(
+                value.exclude_newer_than_days,
+                value.exclude_newer_than_days_package.unwrap_or_default(),
             ),
             link_mode: value.link_mode,
             upgrade: Upgrade::from_args(
@@ -2020,11 +2288,13 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             extra_index_url: value.extra_index_url,
             no_index: value.no_index,
             find_links: value.find_links,
+            index_namespaces: None,
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            allow_insecure_md5_hashes: None,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2033,6 +2303,8 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             extra_build_variables: value.extra_build_variables,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
+            exclude_newer_than_days: None,
+            exclude_newer_than_days_package: None,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -2064,6 +2336,9 @@ pub struct OptionsWire {
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    concurrent_compiles: Option<NonZeroUsize>,
+    concurrent_prefetches: Option<NonZeroUsize>,
+    mirror_manifest: Option<PathBuf>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -2072,12 +2347,14 @@ pub struct OptionsWire {
     extra_index_url: Option<Vec<PipExtraIndex>>,
     no_index: Option<bool>,
     find_links: Option<Vec<PipFindLinks>>,
+    index_namespaces: Option<IndexNamespaces>,
     index_strategy: Option<IndexStrategy>,
     keyring_provider: Option<KeyringProviderType>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    allow_insecure_md5_hashes: Option<bool>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2087,6 +2364,8 @@ pub struct OptionsWire {
     extra_build_variables: Option<ExtraBuildVariables>,
     exclude_newer: Option<ExcludeNewerTimestamp>,
     exclude_newer_package: Option<ExcludeNewerPackage>,
+    exclude_newer_than_days: Option<u64>,
+    exclude_newer_than_days_package: Option<BTreeMap<PackageName, u64>>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -2116,16 +2395,21 @@ pub struct OptionsWire {
     add_bounds: Option<AddBoundsKind>,
 
     pip: Option<PipOptions>,
+    terminal: Option<TerminalOptions>,
+    cache: Option<CacheOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    plugins: Option<Plugins>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
-    override_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+    override_dependencies: Option<Vec<OverrideDependency>>,
     constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     build_constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     environments: Option<SupportedEnvironments>,
     required_environments: Option<SupportedEnvironments>,
+    environment_assumptions: Option<SupportedEnvironments>,
+    torch_backend: Option<TorchMode>,
 
     // NOTE(charlie): These fields should be kept in-sync with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -2138,6 +2422,9 @@ pub struct OptionsWire {
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
+    environment_path: Option<serde::de::IgnoredAny>,
+    profiles: Option<serde::de::IgnoredAny>,
+    named_environments: Option<serde::de::IgnoredAny>,
 
     // Build backend
     build_backend: Option<serde::de::IgnoredAny>,
@@ -2160,17 +2447,22 @@ impl From<OptionsWire> for Options {
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            concurrent_compiles,
+            concurrent_prefetches,
+            mirror_manifest,
             index,
             index_url,
             extra_index_url,
             no_index,
             find_links,
+            index_namespaces,
             index_strategy,
             keyring_provider,
             allow_insecure_host,
             resolution,
             prerelease,
             fork_strategy,
+            allow_insecure_md5_hashes,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -2178,6 +2470,8 @@ impl From<OptionsWire> for Options {
             no_build_isolation_package,
             exclude_newer,
             exclude_newer_package,
+            exclude_newer_than_days,
+            exclude_newer_than_days_package,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -2190,12 +2484,17 @@ impl From<OptionsWire> for Options {
             no_binary,
             no_binary_package,
             pip,
+            terminal,
+            cache,
             cache_keys,
+            plugins,
             override_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
             environments,
             required_environments,
+            environment_assumptions,
+            torch_backend,
             conflicts,
             publish_url,
             trusted_publishing,
@@ -2209,6 +2508,9 @@ impl From<OptionsWire> for Options {
             dev_dependencies,
             managed,
             package,
+            environment_path,
+            profiles,
+            named_environments,
             add_bounds: bounds,
             // Used by the build backend
             build_backend,
@@ -2227,8 +2529,11 @@ impl From<OptionsWire> for Options {
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_compiles,
+                concurrent_prefetches,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
+                mirror_manifest,
             },
             top_level: ResolverInstallerSchema {
                 index,
@@ -2236,11 +2541,13 @@ impl From<OptionsWire> for Options {
                 extra_index_url,
                 no_index,
                 find_links,
+                index_namespaces,
                 index_strategy,
                 keyring_provider,
                 resolution,
                 prerelease,
                 fork_strategy,
+                allow_insecure_md5_hashes,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -2250,6 +2557,8 @@ impl From<OptionsWire> for Options {
                 extra_build_variables,
                 exclude_newer,
                 exclude_newer_package,
+                exclude_newer_than_days,
+                exclude_newer_than_days_package,
                 link_mode,
                 compile_bytecode,
                 no_sources,
@@ -2263,13 +2572,18 @@ impl From<OptionsWire> for Options {
                 no_binary_package,
             },
             pip,
+            terminal,
+            cache,
             cache_keys,
+            plugins,
             build_backend,
             override_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
             environments,
             required_environments,
+            environment_assumptions,
+            torch_backend,
             install_mirrors: PythonInstallMirrors::resolve(
                 python_install_mirror,
                 pypy_install_mirror,
@@ -2289,6 +2603,9 @@ impl From<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
+            environment_path,
+            profiles,
+            named_environments,
         }
     }
 }
@@ -2370,3 +2687,239 @@ pub struct AddOptions {
     )]
     pub add_bounds: Option<AddBoundsKind>,
 }
+
+/// Settings for the appearance of uv's terminal output.
+///
+/// Colors are only applied when colored output is enabled, as determined by `--color`,
+/// `NO_COLOR`, and `FORCE_COLOR`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TerminalOptions {
+    /// The color used to highlight package names, paths, versions, and other notable values.
+    #[option(
+        default = "\"cyan\"",
+        value_type = "str",
+        example = r#"
+            accent-color = "blue"
+        "#
+    )]
+    pub accent_color: Option<TerminalColor>,
+    /// The color used for suggested commands, added packages, and other successful outcomes.
+    #[option(
+        default = "\"green\"",
+        value_type = "str",
+        example = r#"
+            success-color = "bright-green"
+        "#
+    )]
+    pub success_color: Option<TerminalColor>,
+    /// The color used for warnings.
+    #[option(
+        default = "\"yellow\"",
+        value_type = "str",
+        example = r#"
+            warning-color = "magenta"
+        "#
+    )]
+    pub warning_color: Option<TerminalColor>,
+    /// The color used for errors and removed packages.
+    #[option(
+        default = "\"red\"",
+        value_type = "str",
+        example = r#"
+            error-color = "bright-red"
+        "#
+    )]
+    pub error_color: Option<TerminalColor>,
+    /// Restrict output to ASCII characters, e.g., for CI logs or terminals without Unicode
+    /// support.
+    ///
+    /// When enabled, the box-drawing characters in `uv tree` and `uv pip tree`, and the symbols
+    /// used in progress spinners and bars, are replaced with ASCII equivalents.
+    ///
+    /// Enabled by default when `TERM` is set to `dumb`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            ascii-only = true
+        "#
+    )]
+    pub ascii_only: Option<bool>,
+}
+
+/// Settings for caching responses from package indexes.
+///
+/// Applies to Simple API pages (and `--find-links` pages), which list the available versions of
+/// each package, and are revalidated with the index once stale. Archives and metadata for specific
+/// distributions are unaffected, as they're typically immutable.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CacheOptions {
+    /// The duration (in seconds) for which cached index pages are considered fresh.
+    ///
+    /// By default, uv respects the `Cache-Control` headers returned by the index (e.g., PyPI
+    /// considers its pages fresh for 10 minutes). When set, the TTL takes precedence over the
+    /// headers, such that a larger value reduces the number of requests to the index, at the cost
+    /// of discovering new releases later, while `0` revalidates on every request.
+    ///
+    /// The per-index `cache-control` setting takes precedence over the TTL, and `--refresh` always
+    /// revalidates cached pages.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            index-ttl = 3600
+        "#
+    )]
+    pub index_ttl: Option<u64>,
+    /// Serve stale index pages from the cache immediately, revalidating them in the background.
+    ///
+    /// When enabled, uv won't wait on the index to revalidate a stale page; instead, the cached
+    /// page is used for the current operation, and the refreshed page (if any) is used by the next
+    /// invocation. This speeds up interactive workflows, at the cost of occasionally resolving
+    /// against an outdated view of the index.
+    ///
+    /// If uv exits before a revalidation completes, the page is revalidated on a subsequent
+    /// invocation. Pages are never served stale when `--refresh` is provided.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            stale-while-revalidate = true
+        "#
+    )]
+    pub stale_while_revalidate: Option<bool>,
+}
+
+/// A color from the terminal's palette.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TerminalColor {
+    /// The terminal's default foreground color.
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+/// The plugins to invoke during resolution and installation, keyed by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Plugins(BTreeMap<String, Plugin>);
+
+impl Plugins {
+    /// Returns `true` if no plugins are configured.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the configured plugins, in order of name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Plugin)> {
+        self.0.iter().map(|(name, plugin)| (name.as_str(), plugin))
+    }
+
+    /// Resolve the [`Plugins`] relative to the given root directory, such that each plugin is run
+    /// from that directory.
+    #[must_use]
+    pub fn relative_to(self, root_dir: &Path) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|(name, plugin)| {
+                    (
+                        name,
+                        Plugin {
+                            working_directory: Some(root_dir.to_path_buf()),
+                            ..plugin
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Merge two sets of [`Plugins`], preferring the plugins in `self` when both define a plugin
+    /// with the same name.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for (name, plugin) in other.0 {
+            self.0.entry(name).or_insert(plugin);
+        }
+        self
+    }
+}
+
+/// An external command or WebAssembly module invoked during resolution and installation.
+///
+/// Exactly one of `command` and `wasm` must be provided.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Plugin {
+    /// The command to invoke, as a program followed by its arguments.
+    pub command: Option<Vec<String>>,
+    /// The path to a WebAssembly (WASI) module to invoke, which is run with the `wasmtime` CLI.
+    pub wasm: Option<PathBuf>,
+    /// The events for which the plugin should be invoked. Defaults to the `resolve` and
+    /// `install` events.
+    pub events: Option<Vec<PluginEvent>>,
+    /// The directory from which the command should be run, i.e., the directory containing the
+    /// configuration file.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub working_directory: Option<PathBuf>,
+}
+
+impl Plugin {
+    /// Returns `true` if the plugin should be invoked for the given event.
+    ///
+    /// The `candidates` event is invoked once per package, so plugins only receive it if they
+    /// opt in explicitly.
+    pub fn handles(&self, event: PluginEvent) -> bool {
+        match &self.events {
+            Some(events) => events.contains(&event),
+            None => matches!(event, PluginEvent::Resolve | PluginEvent::Install),
+        }
+    }
+}
+
+/// An event for which a plugin can be invoked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PluginEvent {
+    /// A new set of packages was resolved.
+    Resolve,
+    /// A set of packages is about to be installed into an environment.
+    Install,
+    /// The candidate versions of a package were fetched from an index, before the resolver
+    /// considers them.
+    Candidates,
+}
+
+impl std::fmt::Display for PluginEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Resolve => write!(f, "resolve"),
+            Self::Install => write!(f, "install"),
+            Self::Candidates => write!(f, "candidates"),
+        }
+    }
+}
@@ -19,6 +19,18 @@ impl EnvVars {
     /// Equivalent to the `--offline` command-line argument. If set, uv will disable network access.
     pub const UV_OFFLINE: &'static str = "UV_OFFLINE";
 
+    /// Equivalent to the `--limit-rate` command-line argument. If set, uv will limit the network
+    /// bandwidth used for downloads, e.g., `5MB/s`.
+    pub const UV_LIMIT_RATE: &'static str = "UV_LIMIT_RATE";
+
+    /// Equivalent to the `--metered` command-line argument. If set, uv will avoid large optional
+    /// downloads and report download sizes before fetching.
+    pub const UV_METERED: &'static str = "UV_METERED";
+
+    /// Equivalent to the `mirror-manifest` setting. The path to a TOML manifest that maps upstream
+    /// URLs, such as those of managed Python downloads, to internal mirrors.
+    pub const UV_MIRROR_MANIFEST: &'static str = "UV_MIRROR_MANIFEST";
+
     /// Equivalent to the `--default-index` command-line argument. If set, uv will use
     /// this URL as the default index when searching for packages.
     pub const UV_DEFAULT_INDEX: &'static str = "UV_DEFAULT_INDEX";
@@ -41,6 +53,10 @@ impl EnvVars {
     /// comma-separated list of additional locations to search for packages.
     pub const UV_FIND_LINKS: &'static str = "UV_FIND_LINKS";
 
+    /// Equivalent to the `--no-index` command-line argument. If set, uv will ignore the registry
+    /// index and only use direct URL dependencies and those provided via `--find-links`.
+    pub const UV_NO_INDEX: &'static str = "UV_NO_INDEX";
+
     /// Equivalent to the `--cache-dir` command-line argument. If set, uv will use this
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
@@ -115,6 +131,12 @@ impl EnvVars {
     /// a link mode.
     pub const UV_LINK_MODE: &'static str = "UV_LINK_MODE";
 
+    /// Equivalent to the `--quarantine` command-line argument. How uv treats the quarantine
+    /// attributes (`com.apple.quarantine` on macOS, and the Mark of the Web on Windows) of
+    /// installed packages and Python installations: `preserve` (the default) leaves them
+    /// untouched, `strip` removes them, and `mark` marks files as downloaded from the internet.
+    pub const UV_QUARANTINE: &'static str = "UV_QUARANTINE";
+
     /// Equivalent to the `--no-build-isolation` command-line argument. If set, uv will
     /// skip isolation when building source distributions.
     pub const UV_NO_BUILD_ISOLATION: &'static str = "UV_NO_BUILD_ISOLATION";
@@ -229,14 +251,27 @@ impl EnvVars {
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Equivalent to the `--platform-tag` command-line argument. If set, uv will resolve and
+    /// install as if running on the given platform.
+    pub const UV_PLATFORM_OVERRIDE: &'static str = "UV_PLATFORM_OVERRIDE";
+
     /// Equivalent to the `--locked` command-line argument. If set, uv will assert that the
     /// `uv.lock` remains unchanged.
     pub const UV_LOCKED: &'static str = "UV_LOCKED";
 
+    /// Equivalent to the `--locked-timeout` command-line argument. If set, uv will wait at most
+    /// this many seconds for another process to release a lock on the target environment.
+    pub const UV_LOCKED_TIMEOUT: &'static str = "UV_LOCKED_TIMEOUT";
+
     /// Equivalent to the `--frozen` command-line argument. If set, uv will run without
     /// updating the `uv.lock` file.
     pub const UV_FROZEN: &'static str = "UV_FROZEN";
 
+    /// A secret key used to sign lockfiles. If set, uv will write a detached signature
+    /// (`uv.lock.sig`) alongside any lockfile that includes an integrity hash, and
+    /// `--verify-lock-signature` will verify the signature against the key.
+    pub const UV_LOCK_SIGNING_KEY: &'static str = "UV_LOCK_SIGNING_KEY";
+
     /// Equivalent to the `--preview` argument. Enables preview mode.
     pub const UV_PREVIEW: &'static str = "UV_PREVIEW";
 
@@ -253,6 +288,14 @@ impl EnvVars {
     /// Equivalent to the `--allow-insecure-host` argument.
     pub const UV_INSECURE_HOST: &'static str = "UV_INSECURE_HOST";
 
+    /// Equivalent to the `--allow-insecure-md5-hashes` argument. Allow distributions for which an
+    /// index only provides an MD5 hash.
+    ///
+    /// By default, uv refuses to record an MD5 hash in the `uv.lock` or when generating hashes with
+    /// `uv pip compile --generate-hashes`, as MD5 is not collision-resistant. Enabling this option
+    /// allows the use of indexes that only provide MD5 hashes.
+    pub const UV_INSECURE_MD5_HASHES: &'static str = "UV_INSECURE_MD5_HASHES";
+
     /// Disable ZIP validation for streamed wheels and ZIP-based source distributions.
     ///
     /// WARNING: Disabling ZIP validation can expose your system to security risks by bypassing
@@ -273,10 +316,25 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of Python processes that uv will use to compile bytecode
+    /// concurrently.
+    pub const UV_CONCURRENT_COMPILES: &'static str = "UV_CONCURRENT_COMPILES";
+
+    /// Sets the maximum number of package versions for which uv will prefetch
+    /// metadata in a single batch during resolution, and the maximum number of
+    /// dependencies for which uv will prefetch metadata in the background at once.
+    pub const UV_CONCURRENT_PREFETCHES: &'static str = "UV_CONCURRENT_PREFETCHES";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// The language to use for user-facing messages (e.g., `es` or `es_ES.UTF-8`).
+    ///
+    /// Messages that haven't been translated, and languages without a bundled catalog, fall back
+    /// to English.
+    pub const UV_LANG: &'static str = "UV_LANG";
+
     /// Specifies the directory where uv stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
@@ -293,6 +351,10 @@ impl EnvVars {
     /// for more details.
     pub const UV_PROJECT_ENVIRONMENT: &'static str = "UV_PROJECT_ENVIRONMENT";
 
+    /// Equivalent to the `--site-packages-dir` argument for `uv sync` and `uv store gc`. Specifies
+    /// the shared store into which packages are installed and symlinked from.
+    pub const UV_SITE_PACKAGES_DIR: &'static str = "UV_SITE_PACKAGES_DIR";
+
     /// Specifies the directory to place links to installed, managed Python executables.
     pub const UV_PYTHON_BIN_DIR: &'static str = "UV_PYTHON_BIN_DIR";
 
@@ -315,6 +377,9 @@ impl EnvVars {
 
     /// Specifies the directory for caching the archives of managed Python installations before
     /// installation.
+    ///
+    /// Archives are verified against their expected hashes before they're added to the cache, and
+    /// interrupted downloads are resumed where supported by the server.
     pub const UV_PYTHON_CACHE_DIR: &'static str = "UV_PYTHON_CACHE_DIR";
 
     /// Managed Python installations are downloaded from the Astral
@@ -434,6 +499,16 @@ impl EnvVars {
     #[attr_hidden]
     pub const UV_INTERNAL__TEST_PYTHON_MANAGED: &'static str = "UV_INTERNAL__TEST_PYTHON_MANAGED";
 
+    /// Used to enable discovery of pyenv and asdf installations during tests, which is otherwise
+    /// disabled when `UV_TEST_PYTHON_PATH` is set.
+    #[attr_hidden]
+    pub const UV_INTERNAL__TEST_VERSION_MANAGERS: &'static str =
+        "UV_INTERNAL__TEST_VERSION_MANAGERS";
+
+    /// Used to override the disk space reported as available before installations during tests.
+    #[attr_hidden]
+    pub const UV_INTERNAL__TEST_AVAILABLE_SPACE: &'static str = "UV_INTERNAL__TEST_AVAILABLE_SPACE";
+
     /// Path to system-level configuration directory on Unix systems.
     pub const XDG_CONFIG_DIRS: &'static str = "XDG_CONFIG_DIRS";
 
@@ -494,6 +569,11 @@ impl EnvVars {
     /// See [`PycInvalidationMode`](https://docs.python.org/3/library/py_compile.html#py_compile.PycInvalidationMode).
     pub const PYC_INVALIDATION_MODE: &'static str = "PYC_INVALIDATION_MODE";
 
+    /// The hash seed to use when compiling bytecode with `--exclude-source`.
+    ///
+    /// Defaults to `0`, such that the compiled bytecode is reproducible.
+    pub const PYTHONHASHSEED: &'static str = "PYTHONHASHSEED";
+
     /// Used to detect an activated virtual environment.
     pub const VIRTUAL_ENV: &'static str = "VIRTUAL_ENV";
 
@@ -506,6 +586,14 @@ impl EnvVars {
     /// Used to determine the root install path of Conda.
     pub const CONDA_ROOT: &'static str = "_CONDA_ROOT";
 
+    /// Used to determine the root directory of pyenv, to discover the Python installations it
+    /// manages.
+    pub const PYENV_ROOT: &'static str = "PYENV_ROOT";
+
+    /// Used to determine the data directory of asdf, to discover the Python installations it
+    /// manages.
+    pub const ASDF_DATA_DIR: &'static str = "ASDF_DATA_DIR";
+
     /// If set to `1` before a virtual environment is activated, then the
     /// virtual environment name will not be prepended to the terminal prompt.
     pub const VIRTUAL_ENV_DISABLE_PROMPT: &'static str = "VIRTUAL_ENV_DISABLE_PROMPT";
@@ -562,6 +650,9 @@ impl EnvVars {
     /// Use to control color via `anstyle`.
     pub const CLICOLOR_FORCE: &'static str = "CLICOLOR_FORCE";
 
+    /// Used to detect dumb terminals, in which case uv restricts its output to ASCII characters.
+    pub const TERM: &'static str = "TERM";
+
     /// The standard `PATH` env var.
     pub const PATH: &'static str = "PATH";
 
@@ -571,6 +662,12 @@ impl EnvVars {
     /// The standard `SHELL` posix env var.
     pub const SHELL: &'static str = "SHELL";
 
+    /// The Unix timestamp to use for file modification times in built source distributions and
+    /// wheels, for reproducible builds.
+    ///
+    /// See: <https://reproducible-builds.org/specs/source-date-epoch/>
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// The standard `PWD` posix env var.
     pub const PWD: &'static str = "PWD";
 
@@ -658,6 +755,14 @@ impl EnvVars {
     #[attr_hidden]
     pub const LC_ALL: &'static str = "LC_ALL";
 
+    /// Used to select a localized error message from an `EXTERNALLY-MANAGED` file.
+    #[attr_hidden]
+    pub const LC_MESSAGES: &'static str = "LC_MESSAGES";
+
+    /// Used to select a localized error message from an `EXTERNALLY-MANAGED` file.
+    #[attr_hidden]
+    pub const LANG: &'static str = "LANG";
+
     /// Typically set by CI runners, used to detect a CI runner.
     #[attr_hidden]
     pub const CI: &'static str = "CI";
@@ -674,6 +779,11 @@ impl EnvVars {
     #[attr_hidden]
     pub const PIP_IS_CI: &'static str = "PIP_IS_CI";
 
+    /// Pip environment variable to allow installing into an externally managed environment, used
+    /// when bootstrapping pip into a managed Python installation with `ensurepip`.
+    #[attr_hidden]
+    pub const PIP_BREAK_SYSTEM_PACKAGES: &'static str = "PIP_BREAK_SYSTEM_PACKAGES";
+
     /// Use to set the .netrc file location.
     pub const NETRC: &'static str = "NETRC";
 
@@ -876,6 +986,10 @@ impl EnvVars {
     /// Equivalent to the `--project` command-line argument.
     pub const UV_PROJECT: &'static str = "UV_PROJECT";
 
+    /// Equivalent to the `--profile` command-line argument. If set, uv will apply the named
+    /// resolution profile from `tool.uv.profiles` in `uv lock`, `uv sync`, and `uv run`.
+    pub const UV_PROFILE: &'static str = "UV_PROFILE";
+
     /// Disable GitHub-specific requests that allow uv to skip `git fetch` in some circumstances.
     pub const UV_NO_GITHUB_FAST_PATH: &'static str = "UV_NO_GITHUB_FAST_PATH";
 
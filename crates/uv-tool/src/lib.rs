@@ -87,6 +87,11 @@ impl InstalledTools {
         self.root.join(name.to_string())
     }
 
+    /// Return the path to the `uv-receipt.toml` for a tool with the given [`PackageName`].
+    pub fn receipt_path(&self, name: &PackageName) -> PathBuf {
+        self.tool_dir(name).join("uv-receipt.toml")
+    }
+
     /// Return the metadata for all installed tools.
     ///
     /// If a tool is present, but is missing a receipt or the receipt is invalid, the tool will be
@@ -132,7 +137,7 @@ impl InstalledTools {
     ///
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
     pub fn get_tool_receipt(&self, name: &PackageName) -> Result<Option<Tool>, Error> {
-        let path = self.tool_dir(name).join("uv-receipt.toml");
+        let path = self.receipt_path(name);
         match ToolReceipt::from_path(&path) {
             Ok(tool_receipt) => Ok(Some(tool_receipt.tool)),
             Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(None),
@@ -152,7 +157,7 @@ impl InstalledTools {
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
     pub fn add_tool_receipt(&self, name: &PackageName, tool: Tool) -> Result<(), Error> {
         let tool_receipt = ToolReceipt::from(tool);
-        let path = self.tool_dir(name).join("uv-receipt.toml");
+        let path = self.receipt_path(name);
 
         debug!(
             "Adding metadata entry for tool `{name}` at {}",
@@ -426,6 +426,7 @@ impl TorchStrategy {
                     | Os::Haiku { .. }
                     | Os::Android { .. }
                     | Os::Pyodide { .. }
+                    | Os::Wasi
                     | Os::Ios { .. } => Either::Right(Either::Left(std::iter::once(
                         TorchBackend::Cpu.index_url(*source),
                     ))),
@@ -458,6 +459,7 @@ impl TorchStrategy {
                 | Os::Haiku { .. }
                 | Os::Android { .. }
                 | Os::Pyodide { .. }
+                | Os::Wasi
                 | Os::Ios { .. } => Either::Right(Either::Left(std::iter::once(
                     TorchBackend::Cpu.index_url(*source),
                 ))),
@@ -477,6 +479,7 @@ impl TorchStrategy {
                 | Os::Haiku { .. }
                 | Os::Android { .. }
                 | Os::Pyodide { .. }
+                | Os::Wasi
                 | Os::Ios { .. } => Either::Right(Either::Left(std::iter::once(
                     TorchBackend::Cpu.index_url(*source),
                 ))),
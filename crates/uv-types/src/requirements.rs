@@ -1,5 +1,5 @@
 use uv_distribution_types::Requirement;
-use uv_normalize::ExtraName;
+use uv_normalize::{ExtraName, PackageName};
 
 /// A set of requirements as requested by a parent requirement.
 ///
@@ -8,6 +8,8 @@ use uv_normalize::ExtraName;
 /// including their unevaluated markers.
 #[derive(Debug, Clone)]
 pub struct RequestedRequirements {
+    /// The name of the originating requirement.
+    name: PackageName,
     /// The set of extras included on the originating requirement.
     extras: Box<[ExtraName]>,
     /// The set of requirements that were requested by the originating requirement.
@@ -17,15 +19,26 @@ pub struct RequestedRequirements {
 }
 
 impl RequestedRequirements {
-    /// Instantiate a [`RequestedRequirements`] with the given `extras` and `requirements`.
-    pub fn new(extras: Box<[ExtraName]>, requirements: Box<[Requirement]>, direct: bool) -> Self {
+    /// Instantiate a [`RequestedRequirements`] with the given `name`, `extras`, and `requirements`.
+    pub fn new(
+        name: PackageName,
+        extras: Box<[ExtraName]>,
+        requirements: Box<[Requirement]>,
+        direct: bool,
+    ) -> Self {
         Self {
+            name,
             extras,
             requirements,
             direct,
         }
     }
 
+    /// Return the name of the originating requirement.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
     /// Return the extras that were included on the originating requirement.
     pub fn extras(&self) -> &[ExtraName] {
         &self.extras
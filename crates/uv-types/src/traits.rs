@@ -7,7 +7,7 @@ use anyhow::Result;
 use rustc_hash::FxHashSet;
 
 use uv_cache::Cache;
-use uv_configuration::{BuildKind, BuildOptions, BuildOutput, SourceStrategy};
+use uv_configuration::{BuildKind, BuildOptions, BuildOutput, Concurrency, SourceStrategy};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, ConfigSettings, DependencyMetadata, DistributionId, ExtraBuildRequires,
@@ -78,6 +78,9 @@ pub trait BuildContext {
     /// Return a reference to the discovered registry capabilities.
     fn capabilities(&self) -> &IndexCapabilities;
 
+    /// Return the concurrency limits for the operation.
+    fn concurrency(&self) -> &Concurrency;
+
     /// Return a reference to any pre-defined static metadata.
     fn dependency_metadata(&self) -> &DependencyMetadata;
 
@@ -10,6 +10,8 @@ pub use anstream;
 pub use owo_colors;
 use owo_colors::{DynColor, OwoColorize};
 use rustc_hash::FxHashSet;
+#[doc(hidden)]
+pub use uv_i18n;
 
 /// Whether user-facing warnings are enabled.
 pub static ENABLED: AtomicBool = AtomicBool::new(false);
@@ -34,7 +36,8 @@ macro_rules! warn_user {
         if $crate::ENABLED.load(std::sync::atomic::Ordering::Relaxed) {
             let message = format!("{}", format_args!($($arg)*));
             let formatted = message.bold();
-            eprintln!("{}{} {formatted}", "warning".yellow().bold(), ":".bold());
+            let warning = $crate::uv_i18n::message!("warning", "warning");
+            eprintln!("{}{} {formatted}", warning.yellow().bold(), ":".bold());
         }
     }};
 }
@@ -53,7 +56,8 @@ macro_rules! warn_user_once {
             if let Ok(mut states) = $crate::WARNINGS.lock() {
                 let message = format!("{}", format_args!($($arg)*));
                 if states.insert(message.clone()) {
-                    eprintln!("{}{} {}", "warning".yellow().bold(), ":".bold(), message.bold());
+                    let warning = $crate::uv_i18n::message!("warning", "warning");
+                    eprintln!("{}{} {}", warning.yellow().bold(), ":".bold(), message.bold());
                 }
             }
         }
@@ -91,7 +95,9 @@ pub fn write_error_chain(
         writeln!(
             &mut stream,
             "  {}: {}",
-            "Caused by".color(color).bold(),
+            uv_i18n::message!("caused-by", "Caused by")
+                .color(color)
+                .bold(),
             source.to_string().trim()
         )?;
     }
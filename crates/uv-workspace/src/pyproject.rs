@@ -31,9 +31,11 @@ use uv_options_metadata::{OptionSet, OptionsMetadata, Visit};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{
-    Conflicts, DependencyGroups, SchemaConflicts, SupportedEnvironments, VerbatimParsedUrl,
+    Conflicts, DependencyGroups, OverrideDependency, SchemaConflicts, SupportedEnvironments,
+    VerbatimParsedUrl,
 };
 use uv_redacted::DisplaySafeUrl;
+use uv_torch::TorchMode;
 
 #[derive(Error, Debug)]
 pub enum PyprojectTomlError {
@@ -398,6 +400,25 @@ pub struct ToolUv {
     )]
     pub package: Option<bool>,
 
+    /// The path to the project virtual environment.
+    ///
+    /// If a relative path is provided, it will be resolved relative to the workspace root. If an
+    /// absolute path is provided, it will be used as-is.
+    ///
+    /// The `{platform}` placeholder is replaced with the name of the current operating system
+    /// (`linux`, `macos`, or `windows`), such that a single project directory can be shared between
+    /// platforms (e.g., with a development container) without the environments conflicting.
+    ///
+    /// The `UV_PROJECT_ENVIRONMENT` environment variable takes precedence over this setting.
+    #[option(
+        default = r#"".venv""#,
+        value_type = "str",
+        example = r#"
+            environment-path = ".venv-{platform}"
+        "#
+    )]
+    pub environment_path: Option<String>,
+
     /// The list of `dependency-groups` to install by default.
     ///
     /// Can also be the literal `"all"` to default enable all groups.
@@ -467,6 +488,13 @@ pub struct ToolUv {
     /// own; instead, the package must be requested elsewhere in the project's first-party or
     /// transitive dependencies.
     ///
+    /// An override can be limited to the dependencies of a specific package with a trailing `when`
+    /// clause (e.g., `anyio==3.7.0 ; when = "fastapi"`), in which case it takes precedence over any
+    /// override that applies to every package. Unlike other overrides, which replace the original
+    /// requirement regardless of their markers, an override with a `when` clause only applies in
+    /// the environments in which its marker is satisfied. `when` clauses aren't supported in
+    /// overrides files passed with `--override`.
+    ///
     /// !!! note
     ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `override-dependencies` from
     ///     the `pyproject.toml` at the workspace root, and will ignore any declarations in other
@@ -487,7 +515,7 @@ pub struct ToolUv {
             override-dependencies = ["werkzeug==2.3.0"]
         "#
     )]
-    pub override_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+    pub override_dependencies: Option<Vec<OverrideDependency>>,
 
     /// Constraints to apply when resolving the project's dependencies.
     ///
@@ -615,6 +643,62 @@ pub struct ToolUv {
     )]
     pub required_environments: Option<SupportedEnvironments>,
 
+    /// A list of environment markers that are assumed to hold in every environment in which the
+    /// project is installed.
+    ///
+    /// Unlike `environments`, in which each entry is an alternative, the entries in
+    /// `environment-assumptions` are combined: uv will only resolve for environments that satisfy
+    /// _all_ of the given markers.
+    ///
+    /// In addition to restricting the resolution, the assumptions are omitted from the markers in
+    /// the `uv.lock` file. For example, assuming `sys_platform == 'linux'`, a dependency that is
+    /// only required on Linux will be written without a marker, and forks that only differ in their
+    /// handling of other platforms will be skipped entirely.
+    ///
+    /// The lockfile is only valid for environments that satisfy the assumptions; installing from
+    /// it in any other environment will fail.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "A list of environment markers, e.g., `sys_platform == 'linux'`."
+        )
+    )]
+    #[option(
+        default = "[]",
+        value_type = "str | list[str]",
+        example = r#"
+            # Only support Linux on x86-64.
+            environment-assumptions = ["sys_platform == 'linux'", "platform_machine == 'x86_64'"]
+        "#
+    )]
+    pub environment_assumptions: Option<SupportedEnvironments>,
+
+    /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`,
+    /// or `auto`).
+    ///
+    /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem
+    /// (like `torch` and `torchvision`), and will instead resolve them from the PyTorch index for
+    /// the defined backend, removing the need to define the index and pin local versions (like
+    /// `+cu126`) by hand.
+    ///
+    /// The `auto` mode will attempt to detect the appropriate PyTorch index based on the currently
+    /// installed CUDA drivers or AMD GPU, falling back to the CPU-only index.
+    ///
+    /// As the backend depends on the machine on which uv is run, the PyTorch packages in the
+    /// `uv.lock` file are specific to the selected backend; locking with a different backend will
+    /// update them.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[option(
+        default = "null",
+        value_type = "str",
+        example = r#"
+            torch-backend = "auto"
+        "#
+    )]
+    pub torch_backend: Option<TorchMode>,
+
     /// Declare collections of extras or dependency groups that are conflicting
     /// (i.e., mutually exclusive).
     ///
@@ -659,6 +743,58 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// Named resolution profiles, selected via `--profile`.
+    ///
+    /// Each profile can define additional indexes, dependency overrides, and supported
+    /// environments, which are applied on top of the project's configuration when the profile is
+    /// selected (e.g., `uv sync --profile cu121`). Indexes defined by a profile take priority over
+    /// those defined in `tool.uv.index`, overrides are added to `tool.uv.override-dependencies`,
+    /// and environments replace `tool.uv.environments`.
+    ///
+    /// Profiles are useful for switching between variants of a dependency stack, such as CPU and
+    /// CUDA builds of PyTorch, without editing the `pyproject.toml`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(description = "Named resolution profiles, selected via `--profile`.")
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.profiles.cu121]
+            index = [{ name = "pytorch-cu121", url = "https://download.pytorch.org/whl/cu121" }]
+            override-dependencies = ["torch==2.5.1+cu121"]
+            environments = ["sys_platform == 'linux'"]
+        "#
+    )]
+    pub profiles: Option<BTreeMap<String, ToolUvProfile>>,
+
+    /// Named project environments, selected via `--env`.
+    ///
+    /// Each named environment is a separate virtual environment, created at `.venvs/<name>` in the
+    /// workspace root, that's synced from the same lockfile as the project environment (e.g., with
+    /// `uv sync --env py311`). A named environment can request a different Python version, and
+    /// different extras and dependency groups to install by default, such that the project can be
+    /// tested against several configurations side-by-side.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(description = "Named project environments, selected via `--env`.")
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.named-environments.py311]
+            python = "3.11"
+
+            [tool.uv.named-environments.py312-all]
+            python = "3.12"
+            extras = ["cli", "server"]
+            groups = ["dev", "test"]
+        "#
+    )]
+    pub named_environments: Option<BTreeMap<String, ToolUvNamedEnvironment>>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -669,6 +805,59 @@ pub struct ToolUv {
     pub build_backend: Option<BuildBackendSettingsSchema>,
 }
 
+/// A named resolution profile, defined in `tool.uv.profiles`.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ToolUvProfile {
+    /// The indexes to use when the profile is selected, with a higher priority than the indexes
+    /// defined in `tool.uv.index`.
+    #[serde(deserialize_with = "deserialize_index_vec", default)]
+    pub index: Option<Vec<Index>>,
+
+    /// Overrides to apply when the profile is selected, in addition to those defined in
+    /// `tool.uv.override-dependencies`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "PEP 508-style requirements, e.g., `ruff==0.5.0`, or `ruff @ https://...`."
+        )
+    )]
+    pub override_dependencies: Option<Vec<OverrideDependency>>,
+
+    /// The supported environments to use when the profile is selected, in lieu of those defined
+    /// in `tool.uv.environments`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "A list of environment markers, e.g., `python_version >= '3.6'`."
+        )
+    )]
+    pub environments: Option<SupportedEnvironments>,
+}
+
+/// A named project environment, defined in `tool.uv.named-environments`.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ToolUvNamedEnvironment {
+    /// The Python version or interpreter to use for the environment, e.g., `3.11`.
+    ///
+    /// Takes precedence over the `.python-version` file, but not over the `--python` flag.
+    pub python: Option<String>,
+
+    /// The extras to install by default when syncing the environment.
+    pub extras: Option<Vec<ExtraName>>,
+
+    /// The dependency groups to install by default when syncing the environment, in lieu of those
+    /// defined in `tool.uv.default-groups`.
+    pub groups: Option<Vec<GroupName>>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -1091,6 +1280,10 @@ pub enum Source {
         rev: Option<String>,
         tag: Option<String>,
         branch: Option<String>,
+        /// Extras to enable for the dependency, in addition to any extras in the dependency
+        /// specifier (e.g., `extras = ["cli"]`).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extras: Vec<ExtraName>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1135,6 +1328,10 @@ pub enum Source {
         /// When omitted, the package status is inferred based on the presence of a `[build-system]`
         /// in the project's `pyproject.toml`.
         package: Option<bool>,
+        /// Extras to enable for the dependency, in addition to any extras in the dependency
+        /// specifier (e.g., `extras = ["cli"]`).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extras: Vec<ExtraName>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1195,6 +1392,8 @@ impl<'de> Deserialize<'de> for Source {
             package: Option<bool>,
             index: Option<IndexName>,
             workspace: Option<bool>,
+            #[serde(default)]
+            extras: Vec<ExtraName>,
             #[serde(
                 skip_serializing_if = "uv_pep508::marker::ser::is_empty",
                 serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1218,6 +1417,7 @@ impl<'de> Deserialize<'de> for Source {
             package,
             index,
             workspace,
+            extras,
             marker,
             extra,
             group,
@@ -1289,6 +1489,7 @@ impl<'de> Deserialize<'de> for Source {
                 rev,
                 tag,
                 branch,
+                extras,
                 marker,
                 extra,
                 group,
@@ -1343,6 +1544,12 @@ impl<'de> Deserialize<'de> for Source {
                 ));
             }
 
+            if !extras.is_empty() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `extras`",
+                ));
+            }
+
             return Ok(Self::Url {
                 url,
                 subdirectory,
@@ -1401,6 +1608,7 @@ impl<'de> Deserialize<'de> for Source {
                 path,
                 editable,
                 package,
+                extras,
                 marker,
                 extra,
                 group,
@@ -1455,6 +1663,12 @@ impl<'de> Deserialize<'de> for Source {
                 ));
             }
 
+            if !extras.is_empty() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `extras`",
+                ));
+            }
+
             return Ok(Self::Registry {
                 index,
                 marker,
@@ -1506,6 +1720,12 @@ impl<'de> Deserialize<'de> for Source {
                 ));
             }
 
+            if !extras.is_empty() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `extras`",
+                ));
+            }
+
             return Ok(Self::Workspace {
                 workspace,
                 editable,
@@ -1586,6 +1806,7 @@ impl Source {
                         if let Self::Git {
                             git,
                             subdirectory,
+                            extras,
                             marker,
                             extra,
                             group,
@@ -1598,6 +1819,7 @@ impl Source {
                                 rev,
                                 tag,
                                 branch,
+                                extras: extras.clone(),
                                 marker: *marker,
                                 extra: extra.clone(),
                                 group: group.clone(),
@@ -1676,6 +1898,7 @@ impl Source {
                         .map_err(SourceError::Absolute)?
                         .into_boxed_path(),
                 ),
+                extras: Vec::new(),
                 marker: MarkerTree::TRUE,
                 extra: None,
                 group: None,
@@ -1709,6 +1932,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        extras: Vec::new(),
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1720,6 +1944,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        extras: Vec::new(),
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1742,6 +1967,15 @@ impl Source {
         }
     }
 
+    /// Return the extras to enable for the dependency, as declared by the source.
+    pub fn extras(&self) -> &[ExtraName] {
+        match self {
+            Self::Git { extras, .. } => extras,
+            Self::Path { extras, .. } => extras,
+            Self::Url { .. } | Self::Registry { .. } | Self::Workspace { .. } => &[],
+        }
+    }
+
     /// Return the extra name for the source.
     pub fn extra(&self) -> Option<&ExtraName> {
         match self {
@@ -71,6 +71,8 @@ enum CommentType {
     OwnLine,
     /// A comment that appears at the end of a line.
     EndOfLine,
+    /// An empty line, used to separate groups of entries.
+    BlankLine,
 }
 
 #[derive(Debug, Clone)]
@@ -842,54 +844,84 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
-    /// Removes all occurrences of dependencies with the given name.
-    pub fn remove_dependency(&mut self, name: &PackageName) -> Result<Vec<Requirement>, Error> {
-        // Try to get `project.dependencies`.
-        let Some(dependencies) = self
-            .project_mut()?
-            .and_then(|project| project.get_mut("dependencies"))
+    /// Retrieves an optional mutable reference to the dependency array for the given
+    /// [`DependencyType`], returning `None` if it doesn't exist.
+    fn existing_dependencies_array(
+        &mut self,
+        dependency_type: &DependencyType,
+    ) -> Result<Option<&mut Array>, Error> {
+        let dependencies = match dependency_type {
+            // Try to get `project.dependencies`.
+            DependencyType::Production => self
+                .project_mut()?
+                .and_then(|project| project.get_mut("dependencies")),
+            // Try to get `tool.uv.dev-dependencies`.
+            DependencyType::Dev => self
+                .doc
+                .get_mut("tool")
+                .map(|tool| tool.as_table_mut().ok_or(Error::MalformedDependencies))
+                .transpose()?
+                .and_then(|tool| tool.get_mut("uv"))
+                .map(|tool_uv| tool_uv.as_table_mut().ok_or(Error::MalformedDependencies))
+                .transpose()?
+                .and_then(|tool_uv| tool_uv.get_mut("dev-dependencies")),
+            // Try to get `project.optional-dependencies.<extra>`.
+            DependencyType::Optional(extra) => self
+                .project_mut()?
+                .and_then(|project| project.get_mut("optional-dependencies"))
+                .map(|extras| {
+                    extras
+                        .as_table_like_mut()
+                        .ok_or(Error::MalformedDependencies)
+                })
+                .transpose()?
+                .and_then(|extras| {
+                    extras.iter_mut().find_map(|(key, value)| {
+                        if ExtraName::from_str(key.get()).is_ok_and(|e| e == *extra) {
+                            Some(value)
+                        } else {
+                            None
+                        }
+                    })
+                }),
+            // Try to get `dependency-groups.<group>`.
+            DependencyType::Group(group) => self
+                .doc
+                .get_mut("dependency-groups")
+                .map(|groups| {
+                    groups
+                        .as_table_like_mut()
+                        .ok_or(Error::MalformedDependencies)
+                })
+                .transpose()?
+                .and_then(|groups| {
+                    groups.iter_mut().find_map(|(key, value)| {
+                        if GroupName::from_str(key.get()).is_ok_and(|g| g == *group) {
+                            Some(value)
+                        } else {
+                            None
+                        }
+                    })
+                }),
+        };
+
+        dependencies
             .map(|dependencies| {
                 dependencies
                     .as_array_mut()
                     .ok_or(Error::MalformedDependencies)
             })
-            .transpose()?
-        else {
-            return Ok(Vec::new());
-        };
-
-        let requirements = remove_dependency(name, dependencies);
-        self.remove_source(name)?;
+            .transpose()
+    }
 
-        Ok(requirements)
+    /// Removes all occurrences of dependencies with the given name.
+    pub fn remove_dependency(&mut self, name: &PackageName) -> Result<Vec<Requirement>, Error> {
+        self.remove_dependency_of_type(name, &DependencyType::Production)
     }
 
     /// Removes all occurrences of development dependencies with the given name.
     pub fn remove_dev_dependency(&mut self, name: &PackageName) -> Result<Vec<Requirement>, Error> {
-        // Try to get `tool.uv.dev-dependencies`.
-        let Some(dev_dependencies) = self
-            .doc
-            .get_mut("tool")
-            .map(|tool| tool.as_table_mut().ok_or(Error::MalformedDependencies))
-            .transpose()?
-            .and_then(|tool| tool.get_mut("uv"))
-            .map(|tool_uv| tool_uv.as_table_mut().ok_or(Error::MalformedDependencies))
-            .transpose()?
-            .and_then(|tool_uv| tool_uv.get_mut("dev-dependencies"))
-            .map(|dependencies| {
-                dependencies
-                    .as_array_mut()
-                    .ok_or(Error::MalformedDependencies)
-            })
-            .transpose()?
-        else {
-            return Ok(Vec::new());
-        };
-
-        let requirements = remove_dependency(name, dev_dependencies);
-        self.remove_source(name)?;
-
-        Ok(requirements)
+        self.remove_dependency_of_type(name, &DependencyType::Dev)
     }
 
     /// Removes all occurrences of optional dependencies in the group with the given name.
@@ -898,39 +930,7 @@ impl PyProjectTomlMut {
         name: &PackageName,
         group: &ExtraName,
     ) -> Result<Vec<Requirement>, Error> {
-        // Try to get `project.optional-dependencies.<group>`.
-        let Some(optional_dependencies) = self
-            .project_mut()?
-            .and_then(|project| project.get_mut("optional-dependencies"))
-            .map(|extras| {
-                extras
-                    .as_table_like_mut()
-                    .ok_or(Error::MalformedDependencies)
-            })
-            .transpose()?
-            .and_then(|extras| {
-                extras.iter_mut().find_map(|(key, value)| {
-                    if ExtraName::from_str(key.get()).is_ok_and(|g| g == *group) {
-                        Some(value)
-                    } else {
-                        None
-                    }
-                })
-            })
-            .map(|dependencies| {
-                dependencies
-                    .as_array_mut()
-                    .ok_or(Error::MalformedDependencies)
-            })
-            .transpose()?
-        else {
-            return Ok(Vec::new());
-        };
-
-        let requirements = remove_dependency(name, optional_dependencies);
-        self.remove_source(name)?;
-
-        Ok(requirements)
+        self.remove_dependency_of_type(name, &DependencyType::Optional(group.clone()))
     }
 
     /// Removes all occurrences of the dependency in the group with the given name.
@@ -939,41 +939,61 @@ impl PyProjectTomlMut {
         name: &PackageName,
         group: &GroupName,
     ) -> Result<Vec<Requirement>, Error> {
-        // Try to get `project.optional-dependencies.<group>`.
-        let Some(group_dependencies) = self
-            .doc
-            .get_mut("dependency-groups")
-            .map(|groups| {
-                groups
-                    .as_table_like_mut()
-                    .ok_or(Error::MalformedDependencies)
-            })
-            .transpose()?
-            .and_then(|groups| {
-                groups.iter_mut().find_map(|(key, value)| {
-                    if GroupName::from_str(key.get()).is_ok_and(|g| g == *group) {
-                        Some(value)
-                    } else {
-                        None
-                    }
-                })
-            })
-            .map(|dependencies| {
-                dependencies
-                    .as_array_mut()
-                    .ok_or(Error::MalformedDependencies)
-            })
-            .transpose()?
-        else {
+        self.remove_dependency_of_type(name, &DependencyType::Group(group.clone()))
+    }
+
+    /// Removes all occurrences of dependencies with the given name from the array for the given
+    /// [`DependencyType`].
+    fn remove_dependency_of_type(
+        &mut self,
+        name: &PackageName,
+        dependency_type: &DependencyType,
+    ) -> Result<Vec<Requirement>, Error> {
+        let Some(dependencies) = self.existing_dependencies_array(dependency_type)? else {
             return Ok(Vec::new());
         };
 
-        let requirements = remove_dependency(name, group_dependencies);
+        let requirements = remove_dependency(name, dependencies);
         self.remove_source(name)?;
 
         Ok(requirements)
     }
 
+    /// Replaces the marker expression on an existing dependency with the given name.
+    ///
+    /// Passing [`MarkerTree::TRUE`] strips the marker entirely. Returns the updated requirement,
+    /// or `None` if the dependency could not be found. Returns an error if multiple entries exist
+    /// for the dependency, as the update would be ambiguous.
+    pub fn set_dependency_marker(
+        &mut self,
+        name: &PackageName,
+        dependency_type: &DependencyType,
+        marker: MarkerTree,
+    ) -> Result<Option<Requirement>, Error> {
+        let Some(dependencies) = self.existing_dependencies_array(dependency_type)? else {
+            return Ok(None);
+        };
+
+        let mut to_replace = find_dependencies(name, None, dependencies);
+        match to_replace.as_slice() {
+            [] => Ok(None),
+            [_] => {
+                let (i, mut req) = to_replace.remove(0);
+                req.marker = marker;
+                dependencies.replace(i, req.to_string());
+                reformat_array_multiline(dependencies);
+                Ok(Some(req))
+            }
+            _ => Err(Error::Ambiguous {
+                package_name: name.clone(),
+                requirements: to_replace
+                    .into_iter()
+                    .map(|(_, requirement)| requirement)
+                    .collect(),
+            }),
+        }
+    }
+
     /// Remove a matching source from `tool.uv.sources`, if it exists.
     fn remove_source(&mut self, name: &PackageName) -> Result<(), Error> {
         // If the dependency is still in use, don't remove the source.
@@ -1459,7 +1479,9 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
         .into_iter()
         .rev() // Reverse to preserve indices as we remove them.
         .filter_map(|(i, _)| {
-            deps.remove(i)
+            let removed = deps.remove(i);
+            retain_removed_comments(&removed, i, deps);
+            removed
                 .as_str()
                 .and_then(|req| Requirement::from_str(req).ok())
         })
@@ -1472,6 +1494,60 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     removed
 }
 
+/// Retain the comments and blank lines that preceded an entry removed from a multi-line array.
+///
+/// In a multi-line array, the prefix of each entry contains the end-of-line comment of the
+/// preceding entry, followed by any own-line comments and blank lines above the entry itself. The
+/// end-of-line comment of the removed entry lives in the prefix of the following entry (or in the
+/// array's trailing whitespace).
+///
+/// For example, given:
+/// ```toml
+/// dependencies = [
+///     "anyio", # end-of-line comment
+///     # own-line comment
+///     "flask", # removed
+///     "pydantic",
+/// ]
+/// ```
+///
+/// If we remove `flask`, we want to retain the comments that don't belong to it:
+/// ```toml
+/// dependencies = [
+///     "anyio", # end-of-line comment
+///     # own-line comment
+///     "pydantic",
+/// ]
+/// ```
+fn retain_removed_comments(removed: &Value, index: usize, deps: &mut Array) {
+    let Some(prefix) = removed.decor().prefix().and_then(RawString::as_str) else {
+        return;
+    };
+
+    // Drop the indentation of the removed entry, retaining everything above it.
+    let Some(position) = prefix.rfind('\n') else {
+        return;
+    };
+    let head = &prefix[..=position];
+
+    // Drop the end-of-line comment of the removed entry, retaining everything below it.
+    let next = if let Some(next) = deps.get(index) {
+        next.decor().prefix().and_then(RawString::as_str)
+    } else {
+        deps.trailing().as_str()
+    };
+    let Some((_, tail)) = next.and_then(|next| next.split_once('\n')) else {
+        return;
+    };
+
+    let merged = format!("{head}{tail}");
+    if let Some(next) = deps.get_mut(index) {
+        next.decor_mut().set_prefix(merged);
+    } else {
+        deps.set_trailing(merged);
+    }
+}
+
 /// Returns a `Vec` containing the all dependencies with the given name, along with their positions
 /// in the array.
 fn find_dependencies(
@@ -1528,15 +1604,27 @@ fn try_parse_requirement(req: &str) -> Option<Requirement> {
 /// and move them around. This also formats the array to have a trailing comma.
 fn reformat_array_multiline(deps: &mut Array) {
     fn find_comments(s: Option<&RawString>) -> Box<dyn Iterator<Item = Comment> + '_> {
+        let s = s.and_then(|x| x.as_str()).unwrap_or("");
+
+        // The first line is the remainder of the preceding line, and the last line is the
+        // indentation of the following entry; any empty lines in between are blank lines.
+        let last = s.lines().count().saturating_sub(1);
+
         let iter = s
-            .and_then(|x| x.as_str())
-            .unwrap_or("")
             .lines()
+            .enumerate()
             .scan(
                 (false, false),
-                |(prev_line_was_empty, prev_line_was_comment), line| {
+                move |(prev_line_was_empty, prev_line_was_comment), (i, line)| {
                     let trimmed_line = line.trim();
-                    if let Some(index) = trimmed_line.find('#') {
+                    if trimmed_line.is_empty() && i > 0 && i < last {
+                        *prev_line_was_empty = true;
+                        *prev_line_was_comment = false;
+                        Some(Some(Comment {
+                            text: String::new(),
+                            comment_type: CommentType::BlankLine,
+                        }))
+                    } else if let Some(index) = trimmed_line.find('#') {
                         let comment_text = trimmed_line[index..].trim().to_string();
                         let comment_type = if (*prev_line_was_empty) || (*prev_line_was_comment) {
                             CommentType::OwnLine
@@ -1594,6 +1682,9 @@ fn reformat_array_multiline(deps: &mut Array) {
                 CommentType::EndOfLine => {
                     prefix.push(' ');
                 }
+                CommentType::BlankLine => {
+                    prefix.push('\n');
+                }
             }
             prefix.push_str(&comment.text);
         }
@@ -1616,6 +1707,9 @@ fn reformat_array_multiline(deps: &mut Array) {
                     CommentType::EndOfLine => {
                         rv.push(' ');
                     }
+                    CommentType::BlankLine => {
+                        rv.push('\n');
+                    }
                 }
                 rv.push_str(&comment.text);
             }
@@ -1646,9 +1740,11 @@ fn split_specifiers(req: &str) -> (&str, &str) {
 
 #[cfg(test)]
 mod test {
-    use super::{AddBoundsKind, split_specifiers};
+    use super::{AddBoundsKind, DependencyTarget, PyProjectTomlMut, split_specifiers};
     use std::str::FromStr;
+    use uv_normalize::PackageName;
     use uv_pep440::Version;
+    use uv_pep508::Requirement;
 
     #[test]
     fn split() {
@@ -1772,4 +1868,78 @@ mod test {
             assert_eq!(actual, expected, "{version}");
         }
     }
+
+    #[test]
+    fn add_dependency_preserves_blank_lines() {
+        let raw = indoc::indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "anyio",
+                "idna",
+
+                # Testing
+                "pytest",
+            ]
+        "#};
+        let mut pyproject =
+            PyProjectTomlMut::from_toml(raw, DependencyTarget::PyProjectToml).unwrap();
+        pyproject
+            .add_dependency(&Requirement::from_str("flask").unwrap(), None, false)
+            .unwrap();
+
+        insta::assert_snapshot!(pyproject.to_string(), @r#"
+        [project]
+        name = "project"
+        dependencies = [
+            "anyio",
+            "flask",
+            "idna",
+
+            # Testing
+            "pytest",
+        ]
+        "#);
+    }
+
+    #[test]
+    fn remove_dependency_preserves_comments() {
+        let raw = indoc::indoc! {r#"
+            [project]
+            name = "project"
+            dependencies = [
+                "anyio", # async
+                # web
+                "flask", # removed
+                "pydantic",
+            ]
+        "#};
+        let mut pyproject =
+            PyProjectTomlMut::from_toml(raw, DependencyTarget::PyProjectToml).unwrap();
+
+        pyproject
+            .remove_dependency(&PackageName::from_str("flask").unwrap())
+            .unwrap();
+        insta::assert_snapshot!(pyproject.to_string(), @r#"
+        [project]
+        name = "project"
+        dependencies = [
+            "anyio", # async
+            # web
+            "pydantic",
+        ]
+        "#);
+
+        pyproject
+            .remove_dependency(&PackageName::from_str("pydantic").unwrap())
+            .unwrap();
+        insta::assert_snapshot!(pyproject.to_string(), @r#"
+        [project]
+        name = "project"
+        dependencies = [
+            "anyio", # async
+            # web
+        ]
+        "#);
+    }
 }
@@ -4,28 +4,35 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+use futures::StreamExt;
 use glob::{GlobError, PatternError, glob};
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
+use uv_cache_key::hash_digest;
 use uv_configuration::DependencyGroupsWithDefaults;
-use uv_distribution_types::{Index, Requirement, RequirementSource};
+use uv_distribution_types::{Index, IndexUrlError, Requirement, RequirementSource};
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl};
-use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pypi_types::{Conflicts, OverrideDependency, SupportedEnvironments, VerbatimParsedUrl};
 use uv_static::EnvVars;
+use uv_torch::TorchMode;
 use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
+    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvNamedEnvironment,
+    ToolUvProfile, ToolUvSources, ToolUvWorkspace,
 };
 
 type WorkspaceMembers = Arc<BTreeMap<PackageName, WorkspaceMember>>;
 
+/// The maximum number of workspace member `pyproject.toml` files to read concurrently.
+const MEMBER_CONCURRENCY: usize = 32;
+
 /// Cache key for workspace discovery.
 ///
 /// Given this key, the discovered workspace member list is the same.
@@ -38,9 +45,47 @@ struct WorkspaceCacheKey {
 /// Cache for workspace discovery.
 ///
 /// Avoid re-reading the `pyproject.toml` files in a workspace for each member by caching the
-/// workspace members by their workspace root.
+/// workspace members by their workspace root, and avoid re-parsing a `pyproject.toml` (e.g., the
+/// workspace root, which is read by each discovery that passes through it) by caching the parsed
+/// files by the hash of their contents.
 #[derive(Debug, Default, Clone)]
-pub struct WorkspaceCache(Arc<Mutex<FxHashMap<WorkspaceCacheKey, WorkspaceMembers>>>);
+pub struct WorkspaceCache {
+    members: Arc<Mutex<FxHashMap<WorkspaceCacheKey, WorkspaceMembers>>>,
+    pyproject_tomls: Arc<Mutex<FxHashMap<String, PyProjectToml>>>,
+}
+
+impl WorkspaceCache {
+    /// Parse a `pyproject.toml` from its contents, reusing the result of a previous parse of
+    /// identical contents.
+    fn parse_pyproject_toml(&self, contents: String) -> Result<PyProjectToml, PyprojectTomlError> {
+        let digest = hash_digest(&contents);
+        {
+            // Acquire the lock for the minimal required region
+            let pyproject_tomls = self
+                .pyproject_tomls
+                .lock()
+                .expect("there was a panic in another thread");
+            // Compare the contents, rather than trusting the hash alone.
+            if let Some(pyproject_toml) = pyproject_tomls
+                .get(&digest)
+                .filter(|pyproject_toml| pyproject_toml.raw == contents)
+            {
+                return Ok(pyproject_toml.clone());
+            }
+        }
+
+        let pyproject_toml = PyProjectToml::from_string(contents)?;
+        {
+            // Acquire the lock for the minimal required region
+            let mut pyproject_tomls = self
+                .pyproject_tomls
+                .lock()
+                .expect("there was a panic in another thread");
+            pyproject_tomls.insert(digest, pyproject_toml.clone());
+        }
+        Ok(pyproject_toml)
+    }
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum WorkspaceError {
@@ -81,6 +126,12 @@ pub enum WorkspaceError {
     Toml(PathBuf, #[source] Box<PyprojectTomlError>),
     #[error("Failed to normalize workspace member path")]
     Normalize(#[source] std::io::Error),
+    #[error("Profile `{0}` is not defined in `tool.uv.profiles`")]
+    MissingProfile(String),
+    #[error("Invalid index URL in profile `{0}`")]
+    ProfileIndex(String, #[source] IndexUrlError),
+    #[error("Environment `{0}` is not defined in `tool.uv.named-environments`")]
+    MissingEnvironment(String),
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
@@ -169,6 +220,11 @@ pub struct Workspace {
     indexes: Vec<Index>,
     /// The `pyproject.toml` of the workspace root.
     pyproject_toml: PyProjectToml,
+    /// The path to the selected named environment, if any.
+    ///
+    /// Takes precedence over `UV_PROJECT_ENVIRONMENT` and `tool.uv.environment-path`.
+    #[cfg_attr(test, serde(skip))]
+    environment: Option<PathBuf>,
 }
 
 impl Workspace {
@@ -210,7 +266,8 @@ impl Workspace {
 
         let pyproject_path = project_path.join("pyproject.toml");
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
-        let pyproject_toml = PyProjectToml::from_string(contents)
+        let pyproject_toml = cache
+            .parse_pyproject_toml(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
         // Check if the project is explicitly marked as unmanaged.
@@ -249,7 +306,7 @@ impl Workspace {
             } else if pyproject_toml.project.is_none() {
                 // Without a project, it can't be an implicit root
                 return Err(WorkspaceError::MissingProject(pyproject_path));
-            } else if let Some(workspace) = find_workspace(&project_path, options).await? {
+            } else if let Some(workspace) = find_workspace(&project_path, options, cache).await? {
                 // We have found an explicit root above.
                 workspace
             } else {
@@ -557,6 +614,103 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the environment assumptions for the workspace.
+    pub fn environment_assumptions(&self) -> Option<&SupportedEnvironments> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.environment_assumptions.as_ref())
+    }
+
+    /// Returns the PyTorch backend for the workspace.
+    pub fn torch_backend(&self) -> Option<TorchMode> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.torch_backend)
+    }
+
+    /// Returns the resolution profile with the given name, as defined in `tool.uv.profiles`.
+    pub fn profile(&self, name: &str) -> Option<&ToolUvProfile> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.profiles.as_ref())
+            .and_then(|profiles| profiles.get(name))
+    }
+
+    /// Returns the indexes defined by the resolution profile with the given name, resolved
+    /// relative to the workspace root.
+    pub fn profile_indexes(&self, name: &str) -> Result<Vec<Index>, WorkspaceError> {
+        let profile = self
+            .profile(name)
+            .ok_or_else(|| WorkspaceError::MissingProfile(name.to_string()))?;
+        profile
+            .index
+            .iter()
+            .flatten()
+            .cloned()
+            .map(|index| index.relative_to(&self.install_path))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| WorkspaceError::ProfileIndex(name.to_string(), err))
+    }
+
+    /// Apply the resolution profile with the given name to the workspace.
+    ///
+    /// The profile's indexes take priority over the workspace indexes, its overrides are added to
+    /// the workspace overrides, and its environments (if any) replace the workspace environments.
+    pub fn with_profile(mut self, name: &str) -> Result<Self, WorkspaceError> {
+        let indexes = self.profile_indexes(name)?;
+        let profile = self
+            .profile(name)
+            .cloned()
+            .ok_or_else(|| WorkspaceError::MissingProfile(name.to_string()))?;
+
+        self.indexes = indexes.into_iter().chain(self.indexes).collect();
+
+        if let Some(uv) = self
+            .pyproject_toml
+            .tool
+            .as_mut()
+            .and_then(|tool| tool.uv.as_mut())
+        {
+            if let Some(overrides) = profile.override_dependencies {
+                uv.override_dependencies
+                    .get_or_insert_with(Vec::new)
+                    .extend(overrides);
+            }
+            if let Some(environments) = profile.environments {
+                uv.environments = Some(environments);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the named environment with the given name, as defined in
+    /// `tool.uv.named-environments`.
+    pub fn named_environment(&self, name: &str) -> Option<&ToolUvNamedEnvironment> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.named_environments.as_ref())
+            .and_then(|environments| environments.get(name))
+    }
+
+    /// Select the named environment with the given name, such that [`Workspace::venv`] returns
+    /// its path (i.e., `.venvs/<name>` in the workspace root).
+    pub fn with_named_environment(mut self, name: &str) -> Result<Self, WorkspaceError> {
+        if self.named_environment(name).is_none() {
+            return Err(WorkspaceError::MissingEnvironment(name.to_string()));
+        }
+        self.environment = Some(self.install_path.join(".venvs").join(name));
+        Ok(self)
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Conflicts {
         let mut conflicting = Conflicts::empty();
@@ -644,7 +798,7 @@ impl Workspace {
     }
 
     /// Returns the set of overrides for the workspace.
-    pub fn overrides(&self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
+    pub fn overrides(&self) -> Vec<OverrideDependency> {
         let Some(overrides) = self
             .pyproject_toml
             .tool
@@ -695,8 +849,9 @@ impl Workspace {
     ///
     /// Uses `.venv` in the install path directory by default.
     ///
-    /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence. If a relative path is provided,
-    /// it is resolved relative to the install path.
+    /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence, followed by the
+    /// `tool.uv.environment-path` setting. If a relative path is provided, it is resolved relative
+    /// to the install path.
     ///
     /// If `active` is `true`, the `VIRTUAL_ENV` variable will be preferred. If it is `false`, any
     /// warnings about mismatch between the active environment and the project environment will be
@@ -719,6 +874,29 @@ impl Workspace {
             Some(workspace.install_path.join(path))
         }
 
+        /// Resolve the `tool.uv.environment-path` setting, if any.
+        fn from_environment_path_setting(workspace: &Workspace) -> Option<PathBuf> {
+            let value = workspace
+                .pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.environment_path.as_deref())?;
+
+            if value.is_empty() {
+                return None;
+            }
+
+            // Expand the `{platform}` placeholder, e.g., to `.venv-linux`.
+            let path = PathBuf::from(value.replace("{platform}", std::env::consts::OS));
+            if path.is_absolute() {
+                return Some(path);
+            }
+
+            // Resolve the path relative to the install path.
+            Some(workspace.install_path.join(path))
+        }
+
         /// Resolve the `VIRTUAL_ENV` variable, if any.
         fn from_virtual_env_variable() -> Option<PathBuf> {
             let value = std::env::var_os(EnvVars::VIRTUAL_ENV)?;
@@ -738,7 +916,11 @@ impl Workspace {
         }
 
         // Determine the default value
-        let project_env = from_project_environment_variable(self)
+        let project_env = self
+            .environment
+            .clone()
+            .or_else(|| from_project_environment_variable(self))
+            .or_else(|| from_environment_path_setting(self))
             .unwrap_or_else(|| self.install_path.join(".venv"));
 
         // Warn if it conflicts with `VIRTUAL_ENV`
@@ -840,7 +1022,10 @@ impl Workspace {
         };
         let cache_entry = {
             // Acquire the lock for the minimal required region
-            let cache = cache.0.lock().expect("there was a panic in another thread");
+            let cache = cache
+                .members
+                .lock()
+                .expect("there was a panic in another thread");
             cache.get(&cache_key).cloned()
         };
         let mut workspace_members = if let Some(workspace_members) = cache_entry {
@@ -859,11 +1044,15 @@ impl Workspace {
                 &workspace_definition,
                 &workspace_pyproject_toml,
                 options,
+                cache,
             )
             .await?;
             {
                 // Acquire the lock for the minimal required region
-                let mut cache = cache.0.lock().expect("there was a panic in another thread");
+                let mut cache = cache
+                    .members
+                    .lock()
+                    .expect("there was a panic in another thread");
                 cache.insert(cache_key, Arc::new(workspace_members.clone()));
             }
             Arc::new(workspace_members)
@@ -929,6 +1118,7 @@ impl Workspace {
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
+            environment: None,
         })
     }
 
@@ -937,6 +1127,7 @@ impl Workspace {
         workspace_definition: &ToolUvWorkspace,
         workspace_pyproject_toml: &PyProjectToml,
         options: &DiscoveryOptions,
+        cache: &WorkspaceCache,
     ) -> Result<BTreeMap<PackageName, WorkspaceMember>, WorkspaceError> {
         let mut workspace_members = BTreeMap::new();
         // Avoid reading a `pyproject.toml` more than once.
@@ -947,7 +1138,8 @@ impl Workspace {
         if let Some(project) = &workspace_pyproject_toml.project {
             let pyproject_path = workspace_root.join("pyproject.toml");
             let contents = fs_err::read_to_string(&pyproject_path)?;
-            let pyproject_toml = PyProjectToml::from_string(contents)
+            let pyproject_toml = cache
+                .parse_pyproject_toml(contents)
                 .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
             debug!(
@@ -966,7 +1158,8 @@ impl Workspace {
             );
         }
 
-        // Add all other workspace members.
+        // Collect the candidate workspace members.
+        let mut candidates = Vec::new();
         for member_glob in workspace_definition.clone().members.unwrap_or_default() {
             let absolute_glob = PathBuf::from(glob::Pattern::escape(
                 workspace_root.simplified().to_string_lossy().as_ref(),
@@ -1009,90 +1202,114 @@ impl Workspace {
                     continue;
                 }
 
-                trace!(
-                    "Processing workspace member: `{}`",
-                    member_root.user_display()
-                );
+                candidates.push((member_root, member_glob.clone()));
+            }
+        }
+
+        // Read and parse the member `pyproject.toml` files in parallel, since this dominates
+        // discovery in workspaces with many members. The results are processed in order, such
+        // that errors and duplicates are reported deterministically.
+        let pyproject_tomls = futures::stream::iter(candidates.iter().map(|(member_root, _)| {
+            let pyproject_path = member_root.join("pyproject.toml");
+            let cache = cache.clone();
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    fs_err::read_to_string(&pyproject_path)
+                        .map(|contents| cache.parse_pyproject_toml(contents))
+                })
+                .await
+                .unwrap_or_else(|err| Err(std::io::Error::from(err)))
+            }
+        }))
+        .buffered(MEMBER_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+        // Add all other workspace members.
+        for ((member_root, member_glob), pyproject_toml) in
+            candidates.into_iter().zip(pyproject_tomls)
+        {
+            trace!(
+                "Processing workspace member: `{}`",
+                member_root.user_display()
+            );
+
+            let pyproject_path = member_root.join("pyproject.toml");
+            let pyproject_toml = match pyproject_toml {
+                Ok(pyproject_toml) => pyproject_toml
+                    .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?,
+                Err(err) => {
+                    if !fs_err::metadata(&member_root)?.is_dir() {
+                        warn!(
+                            "Ignoring non-directory workspace member: `{}`",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
 
-                // Read the member `pyproject.toml`.
-                let pyproject_path = member_root.join("pyproject.toml");
-                let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
-                    Ok(contents) => contents,
-                    Err(err) => {
-                        if !fs_err::metadata(&member_root)?.is_dir() {
-                            warn!(
-                                "Ignoring non-directory workspace member: `{}`",
+                    // A directory exists, but it doesn't contain a `pyproject.toml`.
+                    if err.kind() == std::io::ErrorKind::NotFound {
+                        // If the directory is hidden, skip it.
+                        if member_root
+                            .file_name()
+                            .map(|name| name.as_encoded_bytes().starts_with(b"."))
+                            .unwrap_or(false)
+                        {
+                            debug!(
+                                "Ignoring hidden workspace member: `{}`",
                                 member_root.simplified_display()
                             );
                             continue;
                         }
 
-                        // A directory exists, but it doesn't contain a `pyproject.toml`.
-                        if err.kind() == std::io::ErrorKind::NotFound {
-                            // If the directory is hidden, skip it.
-                            if member_root
-                                .file_name()
-                                .map(|name| name.as_encoded_bytes().starts_with(b"."))
-                                .unwrap_or(false)
-                            {
-                                debug!(
-                                    "Ignoring hidden workspace member: `{}`",
-                                    member_root.simplified_display()
-                                );
-                                continue;
-                            }
-
-                            return Err(WorkspaceError::MissingPyprojectTomlMember(
-                                member_root,
-                                member_glob.to_string(),
-                            ));
-                        }
-
-                        return Err(err.into());
+                        return Err(WorkspaceError::MissingPyprojectTomlMember(
+                            member_root,
+                            member_glob.to_string(),
+                        ));
                     }
-                };
-                let pyproject_toml = PyProjectToml::from_string(contents)
-                    .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
-                // Check if the current project is explicitly marked as unmanaged.
-                if pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.managed)
-                    == Some(false)
-                {
-                    debug!(
-                        "Project `{}` is marked as unmanaged; omitting from workspace members",
-                        pyproject_toml.project.as_ref().unwrap().name
-                    );
-                    continue;
+                    return Err(err.into());
                 }
+            };
 
-                // Extract the package name.
-                let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::MissingProject(pyproject_path));
-                };
-
+            // Check if the current project is explicitly marked as unmanaged.
+            if pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.managed)
+                == Some(false)
+            {
                 debug!(
-                    "Adding discovered workspace member: `{}`",
-                    member_root.simplified_display()
+                    "Project `{}` is marked as unmanaged; omitting from workspace members",
+                    pyproject_toml.project.as_ref().unwrap().name
                 );
+                continue;
+            }
 
-                if let Some(existing) = workspace_members.insert(
-                    project.name.clone(),
-                    WorkspaceMember {
-                        root: member_root.clone(),
-                        project,
-                        pyproject_toml,
-                    },
-                ) {
-                    return Err(WorkspaceError::DuplicatePackage {
-                        name: existing.project.name,
-                        first: existing.root.clone(),
-                        second: member_root,
-                    });
-                }
+            // Extract the package name.
+            let Some(project) = pyproject_toml.project.clone() else {
+                return Err(WorkspaceError::MissingProject(pyproject_path));
+            };
+
+            debug!(
+                "Adding discovered workspace member: `{}`",
+                member_root.simplified_display()
+            );
+
+            if let Some(existing) = workspace_members.insert(
+                project.name.clone(),
+                WorkspaceMember {
+                    root: member_root.clone(),
+                    project,
+                    pyproject_toml,
+                },
+            ) {
+                return Err(WorkspaceError::DuplicatePackage {
+                    name: existing.project.name,
+                    first: existing.root.clone(),
+                    second: member_root,
+                });
             }
         }
 
@@ -1274,7 +1491,8 @@ impl ProjectWorkspace {
         // Read the current `pyproject.toml`.
         let pyproject_path = project_root.join("pyproject.toml");
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
-        let pyproject_toml = PyProjectToml::from_string(contents)
+        let pyproject_toml = cache
+            .parse_pyproject_toml(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
         // It must have a `[project]` table.
@@ -1299,7 +1517,8 @@ impl ProjectWorkspace {
             // No `pyproject.toml`, but there may still be a `setup.py` or `setup.cfg`.
             return Ok(None);
         };
-        let pyproject_toml = PyProjectToml::from_string(contents)
+        let pyproject_toml = cache
+            .parse_pyproject_toml(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
         // Extract the `[project]` metadata.
@@ -1393,7 +1612,7 @@ impl ProjectWorkspace {
         if workspace.is_none() {
             // The project isn't an explicit workspace root, check if we're a regular workspace
             // member by looking for an explicit workspace root above.
-            workspace = find_workspace(&project_path, options).await?;
+            workspace = find_workspace(&project_path, options, cache).await?;
         }
 
         let current_project = WorkspaceMember {
@@ -1431,6 +1650,7 @@ impl ProjectWorkspace {
                     sources: workspace_sources,
                     indexes: Vec::default(),
                     pyproject_toml: project_pyproject_toml.clone(),
+                    environment: None,
                 },
             });
         };
@@ -1462,6 +1682,7 @@ impl ProjectWorkspace {
 async fn find_workspace(
     project_root: &Path,
     options: &DiscoveryOptions,
+    cache: &WorkspaceCache,
 ) -> Result<Option<(PathBuf, ToolUvWorkspace, PyProjectToml)>, WorkspaceError> {
     // Skip 1 to ignore the current project itself.
     for workspace_root in project_root
@@ -1488,7 +1709,8 @@ async fn find_workspace(
 
         // Read the `pyproject.toml`.
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
-        let pyproject_toml = PyProjectToml::from_string(contents)
+        let pyproject_toml = cache
+            .parse_pyproject_toml(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
         return if let Some(workspace) = pyproject_toml
@@ -1649,7 +1871,8 @@ impl VirtualProject {
         // Read the current `pyproject.toml`.
         let pyproject_path = project_root.join("pyproject.toml");
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
-        let pyproject_toml = PyProjectToml::from_string(contents)
+        let pyproject_toml = cache
+            .parse_pyproject_toml(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
         if let Some(project) = pyproject_toml.project.as_ref() {
@@ -1735,6 +1958,30 @@ impl VirtualProject {
         })
     }
 
+    /// Apply the resolution profile with the given name to the project's workspace.
+    pub fn with_profile(self, name: &str) -> Result<Self, WorkspaceError> {
+        Ok(match self {
+            Self::Project(project) => Self::Project(ProjectWorkspace {
+                workspace: project.workspace.with_profile(name)?,
+                ..project
+            }),
+            Self::NonProject(workspace) => Self::NonProject(workspace.with_profile(name)?),
+        })
+    }
+
+    /// Select the named environment with the given name for the project's workspace.
+    pub fn with_named_environment(self, name: &str) -> Result<Self, WorkspaceError> {
+        Ok(match self {
+            Self::Project(project) => Self::Project(ProjectWorkspace {
+                workspace: project.workspace.with_named_environment(name)?,
+                ..project
+            }),
+            Self::NonProject(workspace) => {
+                Self::NonProject(workspace.with_named_environment(name)?)
+            }
+        })
+    }
+
     /// Return the root of the project.
     pub fn root(&self) -> &Path {
         match self {
@@ -1783,9 +2030,10 @@ mod tests {
     use anyhow::Result;
     use assert_fs::fixture::ChildPath;
     use assert_fs::prelude::*;
+    use indoc::{formatdoc, indoc};
     use insta::{assert_json_snapshot, assert_snapshot};
 
-    use uv_normalize::GroupName;
+    use uv_normalize::{GroupName, PackageName};
     use uv_pypi_types::DependencyGroupSpecifier;
 
     use crate::pyproject::PyProjectToml;
@@ -2041,6 +2289,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2049,7 +2298,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2141,6 +2394,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2149,7 +2403,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2354,6 +2612,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2362,7 +2621,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2463,6 +2726,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2471,7 +2735,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2585,6 +2853,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2593,7 +2862,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2681,6 +2954,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "environment-path": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "dev-dependencies": null,
@@ -2689,7 +2963,11 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "environment-assumptions": null,
+                      "torch-backend": null,
                       "conflicts": null,
+                      "profiles": null,
+                      "named-environments": null,
                       "build-backend": null
                     }
                   },
@@ -2849,4 +3127,76 @@ bar = ["b"]
 
         Ok(())
     }
+
+    #[test]
+    fn parse_cache_keyed_by_contents() -> Result<()> {
+        let cache = WorkspaceCache::default();
+        let contents = indoc! {r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+        "#};
+
+        // Identical contents are parsed once.
+        let first = cache.parse_pyproject_toml(contents.to_string())?;
+        let second = cache.parse_pyproject_toml(contents.to_string())?;
+        assert_eq!(first.raw, second.raw);
+        assert_eq!(cache.pyproject_tomls.lock().unwrap().len(), 1);
+
+        // Modified contents are parsed again.
+        let modified = cache.parse_pyproject_toml(contents.replace("albatross", "bird-feeder"))?;
+        assert_eq!(
+            modified.project.unwrap().name,
+            PackageName::from_str("bird-feeder")?
+        );
+        assert_eq!(cache.pyproject_tomls.lock().unwrap().len(), 2);
+
+        // Parse errors aren't cached.
+        assert!(cache.parse_pyproject_toml("[project".to_string()).is_err());
+        assert_eq!(cache.pyproject_tomls.lock().unwrap().len(), 2);
+
+        Ok(())
+    }
+
+    /// Discovering the workspace from each of its members parses each `pyproject.toml` once.
+    #[tokio::test]
+    async fn parse_cache_shared_across_members() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        root.child("pyproject.toml").write_str(indoc! {r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+        "#})?;
+        for name in ["bird-feeder", "seeds"] {
+            root.child("packages")
+                .child(name)
+                .child("pyproject.toml")
+                .write_str(&formatdoc! {r#"
+                    [project]
+                    name = "{name}"
+                    version = "1.0.0"
+                    requires-python = ">=3.12"
+                "#})?;
+        }
+
+        let cache = WorkspaceCache::default();
+        for member in ["packages/bird-feeder", "packages/seeds"] {
+            let project = ProjectWorkspace::discover(
+                &root.join(member),
+                &DiscoveryOptions::default(),
+                &cache,
+            )
+            .await?;
+            assert_eq!(project.workspace().packages().len(), 3);
+        }
+        assert_eq!(cache.pyproject_tomls.lock().unwrap().len(), 3);
+
+        Ok(())
+    }
 }
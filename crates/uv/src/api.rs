@@ -0,0 +1,800 @@
+//! The public Rust API of the `uv` crate.
+//!
+//! uv's internal `uv-*` crates are published alongside uv, but provide no stability guarantees:
+//! their interfaces may change in any release. This module exposes the operations that embedders
+//! need (resolving, installing, and locking), along with builders for their settings and opaque
+//! handles to their inputs and results, such that embedders can depend on the `uv` crate alone. No
+//! type from an internal crate is part of this module's interface.
+//!
+//! Breaking changes to the items exposed here are considered breaking changes to uv, and follow
+//! uv's versioning policy.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), uv::api::Error> {
+//! use std::path::PathBuf;
+//!
+//! use uv::api::cache::Cache;
+//! use uv::api::settings::{GlobalSettings, PipCompileSettings};
+//!
+//! let cache = Cache::from_path(".cache")?;
+//! let globals = GlobalSettings::builder().build(None);
+//! let settings = PipCompileSettings::builder()
+//!     .requirements([PathBuf::from("requirements.in")])
+//!     .build(None);
+//!
+//! let resolution = uv::api::resolve(settings, &globals, &cache).await?;
+//! for package in resolution.packages() {
+//!     println!("{}", package.name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+use uv_configuration::{DryRun, PipCompileFormat};
+use uv_distribution_types::Name;
+use uv_fs::CWD;
+use uv_installer::SitePackages;
+use uv_python::PythonEnvironment;
+use uv_resolver::{Lock, PylockToml};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::api::cache::Cache;
+use crate::api::settings::{GlobalSettings, LockSettings, PipCompileSettings, PipInstallSettings};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Return the version of uv.
+pub fn version() -> &'static str {
+    uv_version::version()
+}
+
+/// A normalized package name, as defined in PEP 503.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageName(uv_normalize::PackageName);
+
+impl PackageName {
+    /// The normalized name, as a string.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Display for PackageName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for PackageName {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        uv_normalize::PackageName::from_str(name)
+            .map(Self)
+            .map_err(|err| Error(err.into()))
+    }
+}
+
+/// A package version, as defined in PEP 440.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version(uv_pep440::Version);
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        uv_pep440::Version::from_str(version)
+            .map(Self)
+            .map_err(|err| Error(err.into()))
+    }
+}
+
+/// The uv cache.
+pub mod cache {
+    use std::path::{Path, PathBuf};
+
+    use super::Error;
+
+    /// A handle to an initialized uv cache, which can be shared across operations.
+    #[derive(Debug, Clone)]
+    pub struct Cache(pub(super) uv_cache::Cache);
+
+    impl Cache {
+        /// Use the cache in the given directory, creating it if necessary.
+        pub fn from_path(root: impl Into<PathBuf>) -> Result<Self, Error> {
+            Self::init(uv_cache::Cache::from_path(root))
+        }
+
+        /// Use the cache in the default location for the current user, as the uv CLI does,
+        /// creating it if necessary.
+        pub fn from_user_dir() -> Result<Self, Error> {
+            let cache =
+                uv_cache::Cache::from_settings(false, None).map_err(|err| Error(err.into()))?;
+            Self::init(cache)
+        }
+
+        /// The root directory of the cache.
+        pub fn root(&self) -> &Path {
+            self.0.root()
+        }
+
+        fn init(cache: uv_cache::Cache) -> Result<Self, Error> {
+            cache.init().map(Self).map_err(|err| Error(err.into()))
+        }
+    }
+}
+
+/// Settings for the operations in this module, constructed programmatically, with fallbacks read
+/// from `uv.toml` and `pyproject.toml` files.
+pub mod settings {
+    use std::path::{Path, PathBuf};
+
+    use uv_settings::{Combine, FilesystemOptions, PipOptions};
+
+    use super::Error;
+
+    /// Persistent configuration, as read from `uv.toml` and `pyproject.toml` files.
+    #[derive(Debug, Clone)]
+    pub struct Configuration(FilesystemOptions);
+
+    impl Configuration {
+        /// Read the configuration from the given `uv.toml` file.
+        pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+            FilesystemOptions::from_file(path)
+                .map(Self)
+                .map_err(|err| Error(err.into()))
+        }
+
+        /// Discover the configuration for the given directory, as the uv CLI does: the nearest
+        /// `uv.toml` or `pyproject.toml` file in the directory or its ancestors, combined with the
+        /// user- and system-level configuration.
+        ///
+        /// Returns `None` if no configuration is found.
+        pub fn discover(dir: impl AsRef<Path>) -> Result<Option<Self>, Error> {
+            let discover = || -> Result<Option<FilesystemOptions>, uv_settings::Error> {
+                let project = FilesystemOptions::find(dir.as_ref())?;
+                let system = FilesystemOptions::system()?;
+                let user = FilesystemOptions::user()?;
+                Ok(project.combine(user).combine(system))
+            };
+            discover()
+                .map(|options| options.map(Self))
+                .map_err(|err| Error(err.into()))
+        }
+    }
+
+    /// The preference for managed and system Python installations.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum PythonPreference {
+        /// Only use managed Python installations; never use system Python installations.
+        OnlyManaged,
+        /// Prefer managed Python installations over system Python installations.
+        #[default]
+        Managed,
+        /// Prefer system Python installations over managed Python installations.
+        System,
+        /// Only use system Python installations; never use managed Python installations.
+        OnlySystem,
+    }
+
+    /// Whether Python installations can be downloaded automatically.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum PythonDownloads {
+        /// Automatically download managed Python installations when needed.
+        #[default]
+        Automatic,
+        /// Do not automatically download managed Python installations.
+        Manual,
+        /// Do not ever allow Python downloads.
+        Never,
+    }
+
+    /// The settings shared by all operations, like network and Python discovery settings.
+    #[derive(Debug, Clone)]
+    pub struct GlobalSettings(pub(super) crate::settings::GlobalSettings);
+
+    impl GlobalSettings {
+        /// Create a [`GlobalSettingsBuilder`].
+        pub fn builder() -> GlobalSettingsBuilder {
+            GlobalSettingsBuilder::default()
+        }
+    }
+
+    /// A builder for [`GlobalSettings`].
+    ///
+    /// Any setting that isn't provided explicitly is read from the [`Configuration`] passed to
+    /// [`GlobalSettingsBuilder::build`], then from the environment, as for a command-line
+    /// invocation.
+    #[derive(Debug, Clone, Default)]
+    pub struct GlobalSettingsBuilder(crate::settings::GlobalSettingsBuilder);
+
+    impl GlobalSettingsBuilder {
+        /// Disable network access.
+        #[must_use]
+        pub fn offline(self, offline: bool) -> Self {
+            Self(self.0.offline(offline))
+        }
+
+        /// Load TLS certificates from the platform's native certificate store.
+        #[must_use]
+        pub fn native_tls(self, native_tls: bool) -> Self {
+            Self(self.0.native_tls(native_tls))
+        }
+
+        /// Enable preview features.
+        #[must_use]
+        pub fn preview(self, preview: bool) -> Self {
+            Self(self.0.preview(preview))
+        }
+
+        /// Set the preference for managed and system Python installations.
+        #[must_use]
+        pub fn python_preference(self, python_preference: PythonPreference) -> Self {
+            Self(self.0.python_preference(match python_preference {
+                PythonPreference::OnlyManaged => uv_python::PythonPreference::OnlyManaged,
+                PythonPreference::Managed => uv_python::PythonPreference::Managed,
+                PythonPreference::System => uv_python::PythonPreference::System,
+                PythonPreference::OnlySystem => uv_python::PythonPreference::OnlySystem,
+            }))
+        }
+
+        /// Set whether Python installations can be downloaded automatically.
+        #[must_use]
+        pub fn python_downloads(self, python_downloads: PythonDownloads) -> Self {
+            Self(self.0.python_downloads(match python_downloads {
+                PythonDownloads::Automatic => uv_python::PythonDownloads::Automatic,
+                PythonDownloads::Manual => uv_python::PythonDownloads::Manual,
+                PythonDownloads::Never => uv_python::PythonDownloads::Never,
+            }))
+        }
+
+        /// Hide all progress outputs.
+        #[must_use]
+        pub fn no_progress(self, no_progress: bool) -> Self {
+            Self(self.0.no_progress(no_progress))
+        }
+
+        /// Build the [`GlobalSettings`], falling back to the given configuration, if any.
+        pub fn build(self, configuration: Option<&Configuration>) -> GlobalSettings {
+            GlobalSettings(
+                self.0
+                    .build(configuration.map(|configuration| &configuration.0)),
+            )
+        }
+    }
+
+    /// The settings for [`lock`](super::lock).
+    #[derive(Debug, Clone)]
+    pub struct LockSettings(pub(super) crate::settings::LockSettings);
+
+    impl LockSettings {
+        /// Create a [`LockSettingsBuilder`].
+        pub fn builder() -> LockSettingsBuilder {
+            LockSettingsBuilder::default()
+        }
+    }
+
+    /// A builder for [`LockSettings`].
+    ///
+    /// Resolver options (like the index URL or the resolution strategy) are read from the
+    /// [`Configuration`] passed to [`LockSettingsBuilder::build`].
+    #[derive(Debug, Clone, Default)]
+    pub struct LockSettingsBuilder(crate::settings::LockSettingsBuilder);
+
+    impl LockSettingsBuilder {
+        /// Assert that the lockfile will remain unchanged, as in `uv lock --check`.
+        #[must_use]
+        pub fn locked(self, locked: bool) -> Self {
+            Self(self.0.locked(locked))
+        }
+
+        /// Assert that a lockfile exists, without checking that it's up-to-date, as in
+        /// `uv lock --check-exists`.
+        #[must_use]
+        pub fn frozen(self, frozen: bool) -> Self {
+            Self(self.0.frozen(frozen))
+        }
+
+        /// Resolve with the given resolution profile.
+        #[must_use]
+        pub fn profile(self, profile: impl Into<String>) -> Self {
+            Self(self.0.profile(profile))
+        }
+
+        /// Set the Python interpreter to use during resolution, as a request (e.g., `3.12`).
+        #[must_use]
+        pub fn python(self, python: impl Into<String>) -> Self {
+            Self(self.0.python(python))
+        }
+
+        /// Build the [`LockSettings`], falling back to the given configuration, if any.
+        pub fn build(self, configuration: Option<&Configuration>) -> LockSettings {
+            LockSettings(
+                self.0
+                    .build(configuration.map(|configuration| configuration.0.clone())),
+            )
+        }
+    }
+
+    /// The settings for [`resolve`](super::resolve).
+    #[derive(Debug, Clone)]
+    pub struct PipCompileSettings(pub(super) crate::settings::PipCompileSettings);
+
+    impl PipCompileSettings {
+        /// Create a [`PipCompileSettingsBuilder`].
+        pub fn builder() -> PipCompileSettingsBuilder {
+            PipCompileSettingsBuilder::default()
+        }
+    }
+
+    /// A builder for [`PipCompileSettings`].
+    ///
+    /// Resolver options (like the index URL or the resolution strategy) are read from the
+    /// `[tool.uv.pip]` section of the [`Configuration`] passed to
+    /// [`PipCompileSettingsBuilder::build`].
+    #[derive(Debug, Clone, Default)]
+    pub struct PipCompileSettingsBuilder {
+        builder: crate::settings::PipCompileSettingsBuilder,
+        options: PipOptions,
+    }
+
+    impl PipCompileSettingsBuilder {
+        /// Add files from which to read the requirements to resolve, like `requirements.in` or
+        /// `pyproject.toml`.
+        #[must_use]
+        pub fn requirements(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            self.builder = self.builder.requirements(paths);
+            self
+        }
+
+        /// Add files from which to read constraints.
+        #[must_use]
+        pub fn constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            self.builder = self.builder.constraints(paths);
+            self
+        }
+
+        /// Add files from which to read overrides.
+        #[must_use]
+        pub fn overrides(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            self.builder = self.builder.overrides(paths);
+            self
+        }
+
+        /// Add files from which to read build constraints.
+        #[must_use]
+        pub fn build_constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            self.builder = self.builder.build_constraints(paths);
+            self
+        }
+
+        /// Set the Python interpreter to resolve against, as a request (e.g., `3.12`) or a path
+        /// to an executable.
+        #[must_use]
+        pub fn python(mut self, python: impl Into<String>) -> Self {
+            self.options.python = Some(python.into());
+            self
+        }
+
+        /// Perform a universal resolution, attempting to generate a single resolution that's
+        /// compatible with all operating systems, architectures, and Python implementations.
+        #[must_use]
+        pub fn universal(mut self, universal: bool) -> Self {
+            self.options.universal = Some(universal);
+            self
+        }
+
+        /// Build the [`PipCompileSettings`], falling back to the given configuration, if any.
+        pub fn build(self, configuration: Option<&Configuration>) -> PipCompileSettings {
+            PipCompileSettings(
+                self.builder
+                    .options(self.options)
+                    .build(configuration.map(|configuration| configuration.0.clone())),
+            )
+        }
+    }
+
+    /// The settings for [`install`](super::install).
+    #[derive(Debug, Clone)]
+    pub struct PipInstallSettings(pub(super) crate::settings::PipInstallSettings);
+
+    impl PipInstallSettings {
+        /// Create a [`PipInstallSettingsBuilder`].
+        pub fn builder() -> PipInstallSettingsBuilder {
+            PipInstallSettingsBuilder::default()
+        }
+    }
+
+    /// A builder for [`PipInstallSettings`].
+    ///
+    /// Resolver and installer options (like the index URL or the link mode) are read from the
+    /// `[tool.uv.pip]` section of the [`Configuration`] passed to
+    /// [`PipInstallSettingsBuilder::build`].
+    #[derive(Debug, Clone, Default)]
+    pub struct PipInstallSettingsBuilder(crate::settings::PipInstallSettingsBuilder);
+
+    impl PipInstallSettingsBuilder {
+        /// Add packages to install, as PEP 508 requirements (e.g., `flask>=2`).
+        #[must_use]
+        pub fn packages(self, packages: impl IntoIterator<Item = String>) -> Self {
+            Self(self.0.packages(packages))
+        }
+
+        /// Add files from which to read the requirements to install, like `requirements.txt`.
+        #[must_use]
+        pub fn requirements(self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            Self(self.0.requirements(paths))
+        }
+
+        /// Add local directories to install in editable mode.
+        #[must_use]
+        pub fn editables(self, editables: impl IntoIterator<Item = String>) -> Self {
+            Self(self.0.editables(editables))
+        }
+
+        /// Add files from which to read constraints.
+        #[must_use]
+        pub fn constraints(self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            Self(self.0.constraints(paths))
+        }
+
+        /// Add files from which to read overrides.
+        #[must_use]
+        pub fn overrides(self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            Self(self.0.overrides(paths))
+        }
+
+        /// Add files from which to read build constraints.
+        #[must_use]
+        pub fn build_constraints(self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+            Self(self.0.build_constraints(paths))
+        }
+
+        /// Perform a dry run, i.e., don't actually install anything.
+        #[must_use]
+        pub fn dry_run(self, dry_run: bool) -> Self {
+            Self(self.0.dry_run(dry_run))
+        }
+
+        /// Remove any extraneous packages from the environment, as in `uv pip install --exact`.
+        #[must_use]
+        pub fn exact(self, exact: bool) -> Self {
+            Self(self.0.exact(exact))
+        }
+
+        /// Build the [`PipInstallSettings`], falling back to the given configuration, if any.
+        pub fn build(self, configuration: Option<&Configuration>) -> PipInstallSettings {
+            PipInstallSettings(
+                self.0
+                    .build(configuration.map(|configuration| configuration.0.clone())),
+            )
+        }
+    }
+}
+
+/// An error from an operation in the public API.
+#[derive(Debug)]
+pub struct Error(anyhow::Error);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(err: anyhow::Error) -> Self {
+        Self(err)
+    }
+}
+
+/// Run an operation from this module to completion, blocking the current thread.
+///
+/// The futures returned by the operations in this module are not `Send`, and require a larger
+/// stack than is available on most threads, so they're run on a dedicated thread with a
+/// single-threaded Tokio runtime, mirroring the uv CLI.
+pub fn block_on<F, Fut, T>(operation: F) -> Result<T, Error>
+where
+    F: FnOnce() -> Fut + Send,
+    Fut: Future<Output = Result<T, Error>>,
+    T: Send,
+{
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .name("uv-api".to_string())
+            .stack_size(uv_configuration::min_stack_size())
+            .spawn_scoped(scope, move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|err| Error(err.into()))?;
+                runtime.block_on(operation())
+            })
+            .map_err(|err| Error(err.into()))?
+            .join()
+            .map_err(|_| Error(anyhow::anyhow!("The uv operation panicked")))?
+    })
+}
+
+/// Return an error if a command didn't succeed.
+fn check_status(command: &str, status: ExitStatus) -> anyhow::Result<()> {
+    match status {
+        ExitStatus::Success => Ok(()),
+        status => Err(anyhow::anyhow!("`{command}` did not succeed ({status:?})")),
+    }
+}
+
+/// A package in a [`Resolution`], a [`Lockfile`], or an [`Environment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    name: PackageName,
+    version: Option<Version>,
+}
+
+impl Package {
+    /// The name of the package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// The version of the package, if it's known (i.e., for registry distributions).
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+}
+
+/// The packages selected by [`resolve`].
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    packages: Vec<Package>,
+    pylock: String,
+}
+
+impl Resolution {
+    /// The packages in the resolution.
+    pub fn packages(&self) -> &[Package] {
+        &self.packages
+    }
+
+    /// The resolution, serialized as a `pylock.toml` file.
+    pub fn to_pylock_toml(&self) -> &str {
+        &self.pylock
+    }
+}
+
+/// A `uv.lock` lockfile, as produced by [`lock`].
+#[derive(Debug, Clone)]
+pub struct Lockfile(Lock);
+
+impl Lockfile {
+    /// Read a `uv.lock` file from disk.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = fs_err::read_to_string(path.as_ref()).map_err(|err| Error(err.into()))?;
+        let lock = toml::from_str::<Lock>(&contents).map_err(|err| Error(err.into()))?;
+        Ok(Self(lock))
+    }
+
+    /// The version of the lockfile schema.
+    pub fn schema_version(&self) -> u32 {
+        self.0.version()
+    }
+
+    /// The packages in the lockfile.
+    pub fn packages(&self) -> Vec<Package> {
+        self.0
+            .packages()
+            .iter()
+            .map(|package| Package {
+                name: PackageName(package.name().clone()),
+                version: package.version().cloned().map(Version),
+            })
+            .collect()
+    }
+
+    /// Serialize the lockfile to TOML, as written to `uv.lock`.
+    pub fn to_toml(&self) -> Result<String, Error> {
+        self.0.to_toml().map_err(|err| Error(err.into()))
+    }
+}
+
+/// A Python environment, like a virtual environment, into which packages can be installed.
+#[derive(Debug, Clone)]
+pub struct Environment(PythonEnvironment);
+
+impl Environment {
+    /// Open the Python environment at the given root directory (e.g., `.venv`).
+    pub fn from_root(root: impl AsRef<Path>, cache: &Cache) -> Result<Self, Error> {
+        let environment =
+            PythonEnvironment::from_root(root, &cache.0).map_err(|err| Error(err.into()))?;
+        Ok(Self(environment))
+    }
+
+    /// The root of the environment.
+    pub fn root(&self) -> &Path {
+        self.0.root()
+    }
+
+    /// The path to the environment's Python executable.
+    pub fn python_executable(&self) -> &Path {
+        self.0.python_executable()
+    }
+
+    /// The packages installed in the environment.
+    pub fn installed_packages(&self) -> Result<Vec<Package>, Error> {
+        let site_packages = SitePackages::from_environment(&self.0)?;
+        let mut packages = site_packages
+            .iter()
+            .map(|dist| Package {
+                name: PackageName(dist.name().clone()),
+                version: Some(Version(dist.version().clone())),
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(packages)
+    }
+}
+
+/// Resolve a set of requirements, as in `uv pip compile`.
+///
+/// The requirements are resolved against the Python interpreter requested in the settings
+/// (`python`), or the active environment otherwise.
+pub async fn resolve(
+    settings: PipCompileSettings,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> Result<Resolution, Error> {
+    resolve_impl(settings, globals, cache).await.map_err(Error)
+}
+
+async fn resolve_impl(
+    settings: PipCompileSettings,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> anyhow::Result<Resolution> {
+    let PipCompileSettings(mut settings) = settings;
+
+    // Write the resolution to a temporary `pylock.toml` file, which is read back once the
+    // resolution completes.
+    let temp_dir = tempfile::tempdir()?;
+    let output = temp_dir.path().join("pylock.toml");
+    settings.format = Some(PipCompileFormat::PylockToml);
+    settings.settings.output_file = Some(output.clone());
+
+    // Suppress the copy of the output that's otherwise written to stdout.
+    let mut globals = globals.0.clone();
+    globals.quiet = 1;
+
+    let client_builder = crate::base_client_builder(&globals)?;
+    let status = Box::pin(crate::pip_compile(
+        settings,
+        &globals,
+        &client_builder,
+        cache.0.clone(),
+        &CWD,
+        Printer::Silent,
+    ))
+    .await?;
+    check_status("pip compile", status)?;
+
+    let pylock = fs_err::read_to_string(&output)?;
+    let packages = toml::from_str::<PylockToml>(&pylock)?
+        .packages
+        .into_iter()
+        .map(|package| Package {
+            name: PackageName(package.name),
+            version: package.version.map(Version),
+        })
+        .collect();
+
+    Ok(Resolution { packages, pylock })
+}
+
+/// Install a set of requirements into an [`Environment`], as in `uv pip install`.
+pub async fn install(
+    settings: PipInstallSettings,
+    environment: &Environment,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> Result<(), Error> {
+    install_impl(settings, environment, globals, cache)
+        .await
+        .map_err(Error)
+}
+
+async fn install_impl(
+    settings: PipInstallSettings,
+    environment: &Environment,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> anyhow::Result<()> {
+    let PipInstallSettings(mut settings) = settings;
+    let GlobalSettings(globals) = globals;
+    settings.settings.python = Some(environment.python_executable().display().to_string());
+    settings.settings.system = false;
+
+    let client_builder = crate::base_client_builder(globals)?;
+    let status = Box::pin(crate::pip_install(
+        settings,
+        globals,
+        &client_builder,
+        cache.0.clone(),
+        &CWD,
+        Printer::Silent,
+    ))
+    .await?;
+    check_status("pip install", status)
+}
+
+/// Lock the project in the given directory, as in `uv lock`, returning the [`Lockfile`].
+pub async fn lock(
+    project_dir: impl AsRef<Path>,
+    settings: LockSettings,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> Result<Lockfile, Error> {
+    lock_impl(project_dir.as_ref(), settings, globals, cache)
+        .await
+        .map_err(Error)
+}
+
+async fn lock_impl(
+    project_dir: &Path,
+    settings: LockSettings,
+    globals: &GlobalSettings,
+    cache: &Cache,
+) -> anyhow::Result<Lockfile> {
+    let LockSettings(settings) = settings;
+    let GlobalSettings(globals) = globals;
+    let project_dir = std::path::absolute(project_dir)?;
+    let client_builder = crate::base_client_builder(globals)?;
+
+    let status = Box::pin(crate::commands::lock(
+        &project_dir,
+        settings.locked,
+        settings.frozen,
+        DryRun::Disabled,
+        settings.integrity,
+        false,
+        settings.profile,
+        settings.python,
+        settings.install_mirrors,
+        settings.settings,
+        client_builder,
+        None,
+        globals.python_preference,
+        globals.python_downloads,
+        globals.concurrency,
+        &globals.plugins,
+        false,
+        &cache.0,
+        Printer::Silent,
+        globals.preview,
+    ))
+    .await?;
+    check_status("lock", status)?;
+
+    let workspace = Workspace::discover(
+        &project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let contents = fs_err::read_to_string(workspace.install_path().join("uv.lock"))?;
+    Ok(Lockfile(toml::from_str::<Lock>(&contents)?))
+}
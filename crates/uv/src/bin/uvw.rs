@@ -101,11 +101,11 @@ fn run() -> std::io::Result<ExitStatus> {
 fn main() -> ExitCode {
     let result = run();
     match result {
-        // Fail with 2 if the status cannot be cast to an exit code
-        Ok(status) => u8::try_from(status.code().unwrap_or(2)).unwrap_or(2).into(),
+        // Fail with 1 if the status cannot be cast to an exit code
+        Ok(status) => u8::try_from(status.code().unwrap_or(1)).unwrap_or(1).into(),
         Err(err) => {
             eprintln!("error: {err}");
-            ExitCode::from(2)
+            ExitCode::from(1)
         }
     }
 }
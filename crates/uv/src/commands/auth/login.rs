@@ -16,7 +16,7 @@ use uv_pep508::VerbatimUrl;
 use uv_preview::Preview;
 
 use crate::commands::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::NetworkSettings;
 
 /// Login to a service.
@@ -49,12 +49,12 @@ pub(crate) async fn login(
         let jwt = PyxJwt::decode(&access_token)?;
 
         if let Some(name) = jwt.name.as_deref() {
-            writeln!(printer.stderr(), "Logged in to {}", name.bold().cyan())?;
+            writeln!(printer.stderr(), "Logged in to {}", name.bold().accent())?;
         } else {
             writeln!(
                 printer.stderr(),
                 "Logged in to {}",
-                pyx_store.api().bold().cyan()
+                pyx_store.api().bold().accent()
             )?;
         }
 
@@ -173,7 +173,7 @@ pub(crate) async fn login(
     writeln!(
         printer.stderr(),
         "Stored credentials for {}",
-        display_url.bold().cyan()
+        display_url.bold().accent()
     )?;
     Ok(ExitStatus::Success)
 }
@@ -193,13 +193,13 @@ pub(crate) async fn pyx_login_with_browser(
     };
     match open::that(url.as_ref()) {
         Ok(()) => {
-            writeln!(printer.stderr(), "Logging in with {}", url.cyan().bold())?;
+            writeln!(printer.stderr(), "Logging in with {}", url.accent().bold())?;
         }
         Err(..) => {
             writeln!(
                 printer.stderr(),
                 "Open the following URL in your browser: {}",
-                url.cyan().bold()
+                url.accent().bold()
             )?;
         }
     }
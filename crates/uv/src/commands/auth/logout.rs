@@ -10,7 +10,10 @@ use uv_pep508::VerbatimUrl;
 use uv_preview::Preview;
 
 use crate::settings::NetworkSettings;
-use crate::{commands::ExitStatus, printer::Printer};
+use crate::{
+    commands::ExitStatus,
+    printer::{Printer, Styled},
+};
 
 /// Logout from a service.
 ///
@@ -84,7 +87,7 @@ pub(crate) async fn logout(
     writeln!(
         printer.stderr(),
         "Removed credentials for {}",
-        display_url.bold().cyan()
+        display_url.bold().accent()
     )?;
 
     Ok(ExitStatus::Success)
@@ -108,7 +111,7 @@ async fn pyx_logout(
         writeln!(
             printer.stderr(),
             "{}",
-            format_args!("No credentials found for {}", store.api().bold().cyan())
+            format_args!("No credentials found for {}", store.api().bold().accent())
         )?;
         return Ok(ExitStatus::Success);
     };
@@ -148,7 +151,7 @@ async fn pyx_logout(
     writeln!(
         printer.stderr(),
         "{}",
-        format_args!("Logged out from {}", store.api().bold().cyan())
+        format_args!("Logged out from {}", store.api().bold().accent())
     )?;
 
     Ok(ExitStatus::Success)
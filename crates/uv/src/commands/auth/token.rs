@@ -1,7 +1,6 @@
 use std::fmt::Write;
 
 use anyhow::{Result, bail};
-use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_auth::{AuthBackend, Service};
@@ -11,7 +10,7 @@ use uv_preview::Preview;
 
 use crate::commands::ExitStatus;
 use crate::commands::auth::login;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::NetworkSettings;
 
 /// Show the token that will be used for a service.
@@ -128,6 +127,6 @@ async fn pyx_refresh(store: &PyxTokenStore, client: &BaseClient, printer: Printe
         }
     };
 
-    writeln!(printer.stdout(), "{}", token.cyan())?;
+    writeln!(printer.stdout(), "{}", token.accent())?;
     Ok(())
 }
@@ -48,7 +48,7 @@ use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverSettings;
 
 #[derive(Debug, Error)]
@@ -199,6 +199,7 @@ async fn build_impl(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        allow_insecure_md5_hashes: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -268,10 +269,10 @@ async fn build_impl(
             let pyproject_toml = package.root().join("pyproject.toml");
             return Err(anyhow::anyhow!(
                 "Package `{}` is missing a `{}`. For example, to build with `{}`, add the following to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
-                name.cyan(),
-                "build-system".green(),
-                "setuptools".cyan(),
-                pyproject_toml.user_display().cyan()
+                name.accent(),
+                "build-system".success(),
+                "setuptools".accent(),
+                pyproject_toml.user_display().accent()
             ));
         }
 
@@ -313,10 +314,10 @@ async fn build_impl(
             let pyproject_toml = member.root().join("pyproject.toml");
             return Err(anyhow::anyhow!(
                 "Workspace does not contain any buildable packages. For example, to build `{}` with `{}`, add a `{}` to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
-                name.cyan(),
-                "setuptools".cyan(),
-                "build-system".green(),
-                pyproject_toml.user_display().cyan()
+                name.accent(),
+                "setuptools".accent(),
+                "build-system".success(),
+                pyproject_toml.user_display().accent()
             ));
         }
 
@@ -378,7 +379,7 @@ async fn build_impl(
             }
             Err(err) => {
                 #[derive(Debug, miette::Diagnostic, thiserror::Error)]
-                #[error("Failed to build `{source}`", source = source.cyan())]
+                #[error("Failed to build `{source}`", source = source.accent())]
                 #[diagnostic()]
                 struct Diagnostic {
                     source: String,
@@ -1114,7 +1115,7 @@ impl AnnotatedSource<'_> {
 
     fn message_prefix(&self) -> Cow<'_, str> {
         if let Some(package) = &self.package {
-            Cow::Owned(format!("[{}] ", package.cyan()))
+            Cow::Owned(format!("[{}] ", package.accent()))
         } else {
             Cow::Borrowed("")
         }
@@ -1227,7 +1228,7 @@ impl BuildMessage {
                 writeln!(
                     printer.stderr(),
                     "Successfully built {}",
-                    output_dir.join(raw_filename).user_display().bold().cyan()
+                    output_dir.join(raw_filename).user_display().bold().accent()
                 )?;
             }
             Self::List {
@@ -1,19 +1,22 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::{Context, Result};
-use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, Removal, measure};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
 use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageReporter};
 use crate::commands::{ExitStatus, human_readable_bytes};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Clear the cache, removing all entries or those linked to specific packages.
+///
+/// With `dry_run`, report the entries that would be removed (and their size) instead.
 pub(crate) fn cache_clean(
     packages: &[PackageName],
+    dry_run: bool,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -21,17 +24,22 @@ pub(crate) fn cache_clean(
         writeln!(
             printer.stderr(),
             "No cache found at: {}",
-            cache.root().user_display().cyan()
+            cache.root().user_display().accent()
         )?;
         return Ok(ExitStatus::Success);
     }
+
+    if dry_run {
+        return cache_clean_dry_run(packages, &cache, printer);
+    }
+
     let cache = cache.with_exclusive_lock()?;
 
     let summary = if packages.is_empty() {
         writeln!(
             printer.stderr(),
             "Clearing cache at: {}",
-            cache.root().user_display().cyan()
+            cache.root().user_display().accent()
         )?;
 
         let num_paths = walkdir::WalkDir::new(cache.root()).into_iter().count();
@@ -55,37 +63,104 @@ pub(crate) fn cache_clean(
         summary
     };
 
-    // Write a summary of the number of files and directories removed.
+    write_summary(&summary, "Removed", printer)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Report the cache entries that would be removed, without removing them.
+fn cache_clean_dry_run(
+    packages: &[PackageName],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let summary = if packages.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Would clear cache at: {}",
+            cache.root().user_display().accent()
+        )?;
+        measure(cache.root())?
+    } else {
+        let mut summary = Removal::default();
+        for package in packages {
+            for entry in cache.removals(package)? {
+                let size = measure(&entry)?;
+                writeln!(
+                    printer.stderr(),
+                    "Would remove: {} ({})",
+                    relative_to_cache(&entry, cache).accent(),
+                    format_bytes(size.total_bytes).success()
+                )?;
+                summary += size;
+            }
+        }
+        summary
+    };
+
+    write_summary(&summary, "Would remove", printer)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a summary of the number of files and directories (and bytes) removed.
+fn write_summary(summary: &Removal, verb: &str, printer: Printer) -> Result<()> {
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
             write!(printer.stderr(), "No cache entries found")?;
         }
         (0, 1) => {
-            write!(printer.stderr(), "Removed 1 directory")?;
+            write!(printer.stderr(), "{verb} 1 directory")?;
         }
         (0, num_dirs_removed) => {
-            write!(printer.stderr(), "Removed {num_dirs_removed} directories")?;
+            write!(printer.stderr(), "{verb} {num_dirs_removed} directories")?;
         }
         (1, _) => {
-            write!(printer.stderr(), "Removed 1 file")?;
+            write!(printer.stderr(), "{verb} 1 file")?;
         }
         (num_files_removed, _) => {
-            write!(printer.stderr(), "Removed {num_files_removed} files")?;
+            write!(printer.stderr(), "{verb} {num_files_removed} files")?;
         }
     }
 
     // If any, write a summary of the total byte count removed.
     if summary.total_bytes > 0 {
-        let bytes = if summary.total_bytes < 1024 {
-            format!("{}B", summary.total_bytes)
-        } else {
-            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
-            format!("{bytes:.1}{unit}")
-        };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+        write!(
+            printer.stderr(),
+            " ({})",
+            format_bytes(summary.total_bytes).success()
+        )?;
     }
 
     writeln!(printer.stderr())?;
 
-    Ok(ExitStatus::Success)
+    Ok(())
+}
+
+/// Format a byte count for display, e.g., `512B` or `1.5MiB`.
+pub(super) fn format_bytes(total_bytes: u64) -> String {
+    if total_bytes < 1024 {
+        format!("{total_bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(total_bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
+/// Display a cache entry relative to the cache root.
+///
+/// Unzipped archives are referenced by their canonical path, so the canonical cache root is
+/// tried as well.
+pub(super) fn relative_to_cache(path: &Path, cache: &Cache) -> String {
+    path.strip_prefix(cache.root())
+        .ok()
+        .map(Path::to_path_buf)
+        .or_else(|| {
+            let root = cache.root().canonicalize().ok()?;
+            path.strip_prefix(root).ok().map(Path::to_path_buf)
+        })
+        .map_or_else(
+            || path.user_display().to_string(),
+            |path| path.portable_display().to_string(),
+        )
 }
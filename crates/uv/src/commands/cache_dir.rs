@@ -1,10 +1,11 @@
 use anstream::println;
-use owo_colors::OwoColorize;
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
 
+use crate::printer::Styled;
+
 /// Show the cache directory.
 pub(crate) fn cache_dir(cache: &Cache) {
-    println!("{}", cache.root().simplified_display().cyan());
+    println!("{}", cache.root().simplified_display().accent());
 }
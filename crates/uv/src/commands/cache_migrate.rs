@@ -0,0 +1,52 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::{Printer, Styled};
+
+/// Migrate cache buckets from previous versions of uv.
+pub(crate) fn cache_migrate(cache: Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+    let cache = cache.with_exclusive_lock()?;
+
+    writeln!(
+        printer.stderr(),
+        "Migrating cache at: {}",
+        cache.root().user_display().accent()
+    )?;
+
+    let migrated = cache.migrate().with_context(|| {
+        format!(
+            "Failed to migrate cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    for bucket in &migrated {
+        writeln!(
+            printer.stderr(),
+            "Migrated {} to {}",
+            bucket.source.user_display(),
+            bucket.target.user_display().accent()
+        )?;
+    }
+
+    match migrated.len() {
+        0 => writeln!(printer.stderr(), "No cache buckets to migrate")?,
+        1 => writeln!(printer.stderr(), "Migrated 1 cache bucket")?,
+        num_migrated => writeln!(printer.stderr(), "Migrated {num_migrated} cache buckets")?,
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,13 +1,12 @@
 use std::fmt::Write;
 
 use anyhow::{Context, Result};
-use owo_colors::OwoColorize;
 
 use uv_cache::{Cache, Removal};
 use uv_fs::Simplified;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Prune all unreachable objects from the cache.
 pub(crate) fn cache_prune(ci: bool, cache: Cache, printer: Printer) -> Result<ExitStatus> {
@@ -15,7 +14,7 @@ pub(crate) fn cache_prune(ci: bool, cache: Cache, printer: Printer) -> Result<Ex
         writeln!(
             printer.stderr(),
             "No cache found at: {}",
-            cache.root().user_display().cyan()
+            cache.root().user_display().accent()
         )?;
         return Ok(ExitStatus::Success);
     }
@@ -24,7 +23,7 @@ pub(crate) fn cache_prune(ci: bool, cache: Cache, printer: Printer) -> Result<Ex
     writeln!(
         printer.stderr(),
         "Pruning cache at: {}",
-        cache.root().user_display().cyan()
+        cache.root().user_display().accent()
     )?;
 
     let mut summary = Removal::default();
@@ -65,7 +64,7 @@ pub(crate) fn cache_prune(ci: bool, cache: Cache, printer: Printer) -> Result<Ex
             let (bytes, unit) = human_readable_bytes(summary.total_bytes);
             format!("{bytes:.1}{unit}")
         };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+        write!(printer.stderr(), " ({})", bytes.success())?;
     }
 
     writeln!(printer.stderr())?;
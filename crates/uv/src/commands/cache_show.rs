@@ -0,0 +1,84 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use uv_cache::{Cache, CacheBucket, Removal, measure};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+
+use crate::commands::ExitStatus;
+use crate::commands::cache_clean::{format_bytes, relative_to_cache};
+use crate::printer::{Printer, Styled};
+
+/// Show the cache entries for a package, grouped by cache bucket.
+pub(crate) fn cache_show(
+    package: &PackageName,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let entries = cache.package_entries(package)?;
+    if entries.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No cache entries found for `{}`",
+            package.accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut summary = Removal::default();
+    let mut current = None;
+    for (bucket, entry) in &entries {
+        if current != Some(*bucket) {
+            if current.is_some() {
+                writeln!(printer.stdout())?;
+            }
+            writeln!(printer.stdout(), "{}:", label(*bucket))?;
+            current = Some(*bucket);
+        }
+
+        let size = measure(entry)?;
+        writeln!(
+            printer.stdout(),
+            "    {} ({})",
+            relative_to_cache(entry, cache),
+            format_bytes(size.total_bytes)
+        )?;
+        summary += size;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Found {} {} for `{}` ({})",
+        entries.len(),
+        if entries.len() == 1 {
+            "cache entry"
+        } else {
+            "cache entries"
+        },
+        package.accent(),
+        format_bytes(summary.total_bytes).success()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// A human-readable description of the contents of a cache bucket.
+fn label(bucket: CacheBucket) -> &'static str {
+    match bucket {
+        CacheBucket::Wheels => "Downloaded wheels",
+        CacheBucket::SourceDistributions => "Source distributions and built wheels",
+        CacheBucket::Simple => "Index metadata",
+        CacheBucket::Archive => "Unzipped wheels",
+        _ => "Other entries",
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt::Write;
+use std::path::{Component, Path};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_fs::Simplified;
+use uv_install_wheel::{hash_file, read_record_file};
+
+use crate::commands::ExitStatus;
+use crate::printer::{Printer, Styled};
+
+/// Re-hash the unzipped wheels in the cache against their `RECORD` files, quarantining any
+/// entries that no longer match.
+pub(crate) fn cache_verify(cache: Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+    let cache = cache.with_exclusive_lock()?;
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().accent()
+    )?;
+
+    let mut entries = match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err.into()),
+    };
+    entries.sort();
+
+    let mut verified = 0usize;
+    let mut corrupted = 0usize;
+    for entry in entries {
+        if !entry.is_dir() {
+            continue;
+        }
+
+        let Some(failure) = verify_archive(&entry)
+            .with_context(|| format!("Failed to verify: `{}`", entry.user_display()))?
+        else {
+            verified += 1;
+            continue;
+        };
+
+        writeln!(
+            printer.stderr(),
+            "{}{} {failure}",
+            "error".error().bold(),
+            ":".bold()
+        )?;
+        cache
+            .quarantine(&entry)
+            .with_context(|| format!("Failed to quarantine: `{}`", entry.user_display()))?;
+        corrupted += 1;
+    }
+
+    if corrupted > 0 {
+        writeln!(
+            printer.stderr(),
+            "Quarantined {corrupted} corrupted cache {} ({verified} verified)",
+            if corrupted == 1 { "entry" } else { "entries" }
+        )?;
+        return Ok(ExitStatus::HashMismatch);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verified {}",
+        format!(
+            "{verified} cache {}",
+            if verified == 1 { "entry" } else { "entries" }
+        )
+        .success()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Verify an unzipped wheel against the hashes in its `RECORD` file.
+///
+/// Returns a description of the first failure, if any. Entries without a `RECORD` file (e.g.,
+/// those that aren't wheels) are considered valid.
+fn verify_archive(archive: &Path) -> Result<Option<String>> {
+    // Find the `.dist-info/RECORD` file.
+    let mut dist_info = None;
+    for entry in fs_err::read_dir(archive)? {
+        let entry = entry?;
+        if entry
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "dist-info")
+            && entry.path().join("RECORD").is_file()
+        {
+            dist_info = Some(entry.path());
+            break;
+        }
+    }
+    let Some(dist_info) = dist_info else {
+        return Ok(None);
+    };
+    let name = dist_info
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let record = read_record_file(&mut fs_err::File::open(dist_info.join("RECORD"))?)?;
+    for entry in record {
+        // Only SHA-256 is used in practice; skip entries without a hash, like the `RECORD` itself.
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .filter(|hash| hash.starts_with("sha256="))
+        else {
+            continue;
+        };
+
+        // Ignore any paths that would escape the archive.
+        let relative = Path::new(&entry.path);
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            continue;
+        }
+
+        let path = archive.join(relative);
+        if !path.is_file() {
+            return Ok(Some(format!("`{}` is missing from `{name}`", entry.path)));
+        }
+
+        let computed = hash_file(&path)?;
+        if computed != expected {
+            return Ok(Some(format!(
+                "Hash mismatch for `{}` in `{name}`\n  Expected: {expected}\n  Computed: {computed}",
+                entry.path
+            )));
+        }
+    }
+
+    Ok(None)
+}
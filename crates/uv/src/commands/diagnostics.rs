@@ -1,7 +1,6 @@
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
-use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use version_ranges::Ranges;
 
@@ -12,7 +11,9 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_resolver::SentinelRange;
 
-use crate::commands::pip;
+use crate::commands::{ExitStatus, pip};
+use crate::hints::Hint;
+use crate::printer::Styled;
 
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
     let suggestions: Vec<(String, String)> =
@@ -70,8 +71,13 @@ impl OperationDiagnostic {
 
     /// Attempt to report an error with rich diagnostic context.
     ///
-    /// Returns `Some` if the error was not handled.
-    pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
+    /// Returns the [`ExitStatus`] for the error if it was handled, or the error itself if it was
+    /// not.
+    pub(crate) fn report(
+        self,
+        err: pip::operations::Error,
+    ) -> Result<ExitStatus, pip::operations::Error> {
+        let status = ExitStatus::from_error(&err).unwrap_or(ExitStatus::Failure);
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
@@ -81,7 +87,7 @@ impl OperationDiagnostic {
                 } else {
                     no_solution(&err);
                 }
-                None
+                Ok(ExitStatus::NoSolution)
             }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Dist(
                 kind,
@@ -90,7 +96,7 @@ impl OperationDiagnostic {
                 err,
             )) => {
                 requested_dist_error(kind, dist, &chain, err, self.hint);
-                None
+                Ok(status)
             }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Dependencies(
                 error,
@@ -99,7 +105,7 @@ impl OperationDiagnostic {
                 chain,
             )) => {
                 dependencies_error(error, &name, &version, &chain, self.hint.clone());
-                None
+                Ok(status)
             }
             pip::operations::Error::Requirements(uv_requirements::Error::Dist(kind, dist, err)) => {
                 dist_error(
@@ -109,7 +115,7 @@ impl OperationDiagnostic {
                     Arc::new(err),
                     self.hint,
                 );
-                None
+                Ok(status)
             }
             pip::operations::Error::Prepare(uv_installer::PrepareError::Dist(
                 kind,
@@ -118,29 +124,29 @@ impl OperationDiagnostic {
                 err,
             )) => {
                 dist_error(kind, dist, &chain, Arc::new(err), self.hint);
-                None
+                Ok(status)
             }
             pip::operations::Error::Requirements(err) => {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
                         .context(format!("Failed to resolve {context} requirement"));
                     anstream::eprint!("{err:?}");
-                    None
+                    Ok(status)
                 } else {
-                    Some(pip::operations::Error::Requirements(err))
+                    Err(pip::operations::Error::Requirements(err))
                 }
             }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Client(err))
                 if !self.native_tls && err.is_ssl() =>
             {
                 native_tls_hint(err);
-                None
+                Ok(status)
             }
             pip::operations::Error::OutdatedEnvironment => {
                 anstream::eprintln!("{}", err);
-                None
+                Ok(status)
             }
-            err => Some(err),
+            err => Err(err),
         }
     }
 }
@@ -171,9 +177,9 @@ pub(crate) fn dist_error(
             .map(|suggestion| {
                 format!(
                     "`{}` is often confused for `{}` Did you mean to install `{}` instead?",
-                    dist.name().cyan(),
-                    suggestion.cyan(),
-                    suggestion.cyan(),
+                    dist.name().accent(),
+                    suggestion.accent(),
+                    suggestion.accent(),
                 )
             })
             .or_else(|| {
@@ -184,6 +190,10 @@ pub(crate) fn dist_error(
                 }
             })
     });
+    let help = match (help, Hint::from_error(&*cause)) {
+        (Some(help), Some(hint)) => Some(format!("{help}\n\n{hint}")),
+        (help, hint) => help.or_else(|| hint.map(|hint| hint.to_string())),
+    };
     let report = miette::Report::new(Diagnostic {
         kind,
         dist,
@@ -219,9 +229,9 @@ pub(crate) fn requested_dist_error(
             .map(|suggestion| {
                 format!(
                     "`{}` is often confused for `{}` Did you mean to install `{}` instead?",
-                    dist.name().cyan(),
-                    suggestion.cyan(),
-                    suggestion.cyan(),
+                    dist.name().accent(),
+                    suggestion.accent(),
+                    suggestion.accent(),
                 )
             })
             .or_else(|| {
@@ -232,6 +242,10 @@ pub(crate) fn requested_dist_error(
                 }
             })
     });
+    let help = match (help, Hint::from_error(&*cause)) {
+        (Some(help), Some(hint)) => Some(format!("{help}\n\n{hint}")),
+        (help, hint) => help.or_else(|| hint.map(|hint| hint.to_string())),
+    };
     let report = miette::Report::new(Diagnostic {
         kind,
         dist,
@@ -250,7 +264,7 @@ pub(crate) fn dependencies_error(
     help: Option<String>,
 ) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
-    #[error("Failed to resolve dependencies for `{}` ({})", name.cyan(), format!("v{version}").cyan())]
+    #[error("Failed to resolve dependencies for `{}` ({})", name.accent(), format!("v{version}").accent())]
     #[diagnostic()]
     struct Diagnostic {
         name: PackageName,
@@ -267,9 +281,9 @@ pub(crate) fn dependencies_error(
             .map(|suggestion| {
                 format!(
                     "`{}` is often confused for `{}` Did you mean to install `{}` instead?",
-                    name.cyan(),
-                    suggestion.cyan(),
-                    suggestion.cyan(),
+                    name.accent(),
+                    suggestion.accent(),
+                    suggestion.accent(),
                 )
             })
             .or_else(|| {
@@ -353,7 +367,7 @@ pub(crate) fn native_tls_hint(err: uv_client::Error) {
         err,
         help: format!(
             "Consider enabling use of system TLS certificates with the `{}` command-line flag",
-            "--native-tls".green()
+            "--native-tls".success()
         ),
     });
     anstream::eprint!("{report:?}");
@@ -371,16 +385,16 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
                     // Ex) `flask[dotenv]>=1.0.0` (v1.2.3)
                     format!(
                         "`{}{}` ({})",
-                        format!("{}[{}]", step.name, extra).cyan(),
-                        range.cyan(),
-                        format!("v{version}").cyan(),
+                        format!("{}[{}]", step.name, extra).accent(),
+                        range.accent(),
+                        format!("v{version}").accent(),
                     )
                 } else {
                     // Ex) `flask[dotenv]>=1.0.0`
                     format!(
                         "`{}{}`",
-                        format!("{}[{}]", step.name, extra).cyan(),
-                        range.cyan(),
+                        format!("{}[{}]", step.name, extra).accent(),
+                        range.accent(),
                     )
                 }
             } else if let Some(group) = &step.group {
@@ -388,16 +402,16 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
                     // Ex) `flask:dev>=1.0.0` (v1.2.3)
                     format!(
                         "`{}{}` ({})",
-                        format!("{}:{}", step.name, group).cyan(),
-                        range.cyan(),
-                        format!("v{version}").cyan(),
+                        format!("{}:{}", step.name, group).accent(),
+                        range.accent(),
+                        format!("v{version}").accent(),
                     )
                 } else {
                     // Ex) `flask:dev>=1.0.0`
                     format!(
                         "`{}{}`",
-                        format!("{}:{}", step.name, group).cyan(),
-                        range.cyan(),
+                        format!("{}:{}", step.name, group).accent(),
+                        range.accent(),
                     )
                 }
             } else {
@@ -405,13 +419,13 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
                     // Ex) `flask>=1.0.0` (v1.2.3)
                     format!(
                         "`{}{}` ({})",
-                        step.name.cyan(),
-                        range.cyan(),
-                        format!("v{version}").cyan(),
+                        step.name.accent(),
+                        range.accent(),
+                        format!("v{version}").accent(),
                     )
                 } else {
                     // Ex) `flask>=1.0.0`
-                    format!("`{}{}`", step.name.cyan(), range.cyan())
+                    format!("`{}{}`", step.name.accent(), range.accent())
                 }
             }
         } else {
@@ -420,32 +434,36 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
                     // Ex) `flask[dotenv]` (v1.2.3)
                     format!(
                         "`{}` ({})",
-                        format!("{}[{}]", step.name, extra).cyan(),
-                        format!("v{version}").cyan(),
+                        format!("{}[{}]", step.name, extra).accent(),
+                        format!("v{version}").accent(),
                     )
                 } else {
                     // Ex) `flask[dotenv]`
-                    format!("`{}`", format!("{}[{}]", step.name, extra).cyan())
+                    format!("`{}`", format!("{}[{}]", step.name, extra).accent())
                 }
             } else if let Some(group) = &step.group {
                 if let Some(version) = step.version.as_ref() {
                     // Ex) `flask:dev` (v1.2.3)
                     format!(
                         "`{}` ({})",
-                        format!("{}:{}", step.name, group).cyan(),
-                        format!("v{version}").cyan(),
+                        format!("{}:{}", step.name, group).accent(),
+                        format!("v{version}").accent(),
                     )
                 } else {
                     // Ex) `flask:dev`
-                    format!("`{}`", format!("{}:{}", step.name, group).cyan())
+                    format!("`{}`", format!("{}:{}", step.name, group).accent())
                 }
             } else {
                 if let Some(version) = step.version.as_ref() {
                     // Ex) `flask` (v1.2.3)
-                    format!("`{}` ({})", step.name.cyan(), format!("v{version}").cyan())
+                    format!(
+                        "`{}` ({})",
+                        step.name.accent(),
+                        format!("v{version}").accent()
+                    )
                 } else {
                     // Ex) `flask`
-                    format!("`{}`", step.name.cyan())
+                    format!("`{}`", step.name.accent())
                 }
             }
         }
@@ -454,11 +472,11 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
     let mut message = if let Some(version) = version {
         format!(
             "`{}` ({}) was included because",
-            name.cyan(),
-            format!("v{version}").cyan()
+            name.accent(),
+            format!("v{version}").accent()
         )
     } else {
-        format!("`{}` was included because", name.cyan())
+        format!("`{}` was included because", name.accent())
     };
     let mut range: Option<Ranges<Version>> = None;
     for (i, step) in chain.iter().enumerate() {
@@ -471,9 +489,9 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
     }
     if let Some(range) = range.filter(|range| *range != Ranges::empty() && *range != Ranges::full())
     {
-        message = format!("{message} `{}{}`", name.cyan(), range.cyan());
+        message = format!("{message} `{}{}`", name.accent(), range.accent());
     } else {
-        message = format!("{message} `{}`", name.cyan());
+        message = format!("{message} `{}`", name.accent());
     }
     message
 }
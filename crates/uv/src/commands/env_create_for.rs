@@ -0,0 +1,151 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_fs::Simplified;
+use uv_preview::Preview;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_virtualenv::{OnExisting, RemovalReason};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::operations::report_interpreter;
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::printer::{Printer, Styled};
+
+/// The version of the installer plugin protocol, included in the JSON output.
+///
+/// Incremented whenever a field is removed or its meaning changes; fields may be added without
+/// incrementing the version.
+const PROTOCOL_VERSION: u8 = 1;
+
+/// The JSON description of an environment, as consumed by installer plugins.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct EnvironmentDescription {
+    protocol: u8,
+    /// The root of the virtual environment.
+    root: PathBuf,
+    /// The Python executable of the virtual environment.
+    python: PathBuf,
+    /// The full version of the Python interpreter, e.g., `3.12.1`.
+    python_version: String,
+    /// The directory containing the environment's scripts (i.e., `bin` or `Scripts`).
+    scripts: PathBuf,
+    /// The `site-packages` directories of the environment.
+    site_packages: Vec<PathBuf>,
+    /// Whether the environment was created, as opposed to reused.
+    created: bool,
+}
+
+/// Create a virtual environment on behalf of an installer plugin (e.g., for tox or nox), and write
+/// its description to `stdout` as JSON.
+pub(crate) async fn env_create_for(
+    path: &Path,
+    python: Option<&str>,
+    system_site_packages: bool,
+    install_mirrors: &PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let path = std::path::absolute(path)?;
+    let python_request = python.map(PythonRequest::parse);
+
+    // Reuse an existing environment if it satisfies the request, such that repeated invocations
+    // (e.g., across tox runs) don't recreate the environment.
+    let existing = match PythonEnvironment::from_root(&path, cache) {
+        Ok(environment) => {
+            let usable = environment.get_pyvenv_version_conflict().is_none()
+                && python_request
+                    .as_ref()
+                    .is_none_or(|request| request.satisfied(environment.interpreter(), cache));
+            if usable {
+                Some(environment)
+            } else {
+                debug!(
+                    "Existing environment at `{}` does not satisfy the request; recreating",
+                    path.user_display()
+                );
+                None
+            }
+        }
+        Err(err) => {
+            debug!("No usable environment at `{}`: {err}", path.user_display());
+            None
+        }
+    };
+
+    let created = existing.is_none();
+    let environment = if let Some(environment) = existing {
+        environment
+    } else {
+        let reporter = PythonDownloadReporter::single(printer);
+        let python = PythonInstallation::find_or_download(
+            python_request.as_ref(),
+            EnvironmentPreference::OnlySystem,
+            python_preference,
+            python_downloads,
+            client_builder,
+            cache,
+            Some(&reporter),
+            install_mirrors.python_install_mirror.as_deref(),
+            install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
+            preview,
+        )
+        .await?;
+        report_interpreter(&python, false, printer)?;
+
+        writeln!(
+            printer.stderr(),
+            "Creating virtual environment at: {}",
+            path.user_display().accent()
+        )?;
+
+        // The caller owns the environment, so it's safe to replace any existing contents.
+        uv_virtualenv::create_venv(
+            &path,
+            python.into_interpreter(),
+            uv_virtualenv::Prompt::None,
+            system_site_packages,
+            OnExisting::Remove(RemovalReason::ManagedEnvironment),
+            false,
+            false,
+            false,
+            preview,
+        )?
+    };
+
+    let interpreter = environment.interpreter();
+    let description = EnvironmentDescription {
+        protocol: PROTOCOL_VERSION,
+        root: environment.root().to_path_buf(),
+        python: interpreter.sys_executable().to_path_buf(),
+        python_version: interpreter.python_full_version().to_string(),
+        scripts: interpreter.scripts().to_path_buf(),
+        site_packages: interpreter
+            .site_packages()
+            .map(std::borrow::Cow::into_owned)
+            .collect(),
+        created,
+    };
+    writeln!(
+        printer.stdout_important(),
+        "{}",
+        serde_json::to_string(&description)?
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,51 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::Preview;
+use uv_python::{EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest};
+
+use crate::commands::ExitStatus;
+use crate::printer::{Printer, Styled};
+
+/// Write a snapshot of the environment markers of a Python interpreter as JSON, for use with
+/// `uv pip compile --marker-snapshot`.
+pub(crate) fn env_snapshot(
+    python: Option<&str>,
+    output_file: Option<&Path>,
+    python_preference: PythonPreference,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let python = PythonInstallation::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::Any,
+        python_preference,
+        cache,
+        preview,
+    )?;
+
+    let snapshot = serde_json::to_string_pretty(python.interpreter().markers())?;
+
+    if let Some(output_file) = output_file {
+        uv_fs::write_atomic_sync(std::path::absolute(output_file)?, format!("{snapshot}\n"))?;
+        writeln!(
+            printer.stderr(),
+            "Wrote marker environment snapshot for {} to: {}",
+            python
+                .interpreter()
+                .sys_executable()
+                .user_display()
+                .accent(),
+            output_file.user_display().accent()
+        )?;
+    } else {
+        writeln!(printer.stdout_important(), "{snapshot}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
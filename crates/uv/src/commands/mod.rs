@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
@@ -16,27 +17,36 @@ pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_migrate::cache_migrate;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_show::cache_show;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use env_create_for::env_create_for;
+pub(crate) use env_snapshot::env_snapshot;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
+pub(crate) use pip::normalize::pip_normalize_requirements;
 pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::bundle::bundle;
+pub(crate) use project::diff::diff;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
-pub(crate) use project::lock::lock;
+pub(crate) use project::lock::{lock, lock_merge};
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
 pub(crate) use project::version::{project_version, self_version};
+pub(crate) use project::watch::watch;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
@@ -48,11 +58,14 @@ pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
+pub(crate) use store_gc::store_gc;
+pub(crate) use tool::check::check as tool_check;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::ToolRunCommand;
 pub(crate) use tool::run::run as tool_run;
+pub(crate) use tool::show::show as tool_show;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
@@ -66,6 +79,9 @@ use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
+pub(crate) use wheelhouse_verify::wheelhouse_verify;
+pub(crate) use workspace_affected::workspace_affected;
+pub(crate) use workspace_graph::workspace_graph;
 
 use crate::printer::Printer;
 
@@ -74,20 +90,30 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_migrate;
 mod cache_prune;
+mod cache_show;
+mod cache_verify;
 mod diagnostics;
+mod env_create_for;
+mod env_snapshot;
 mod help;
 pub(crate) mod pip;
+mod plugins;
 mod project;
 mod publish;
 mod python;
 pub(crate) mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
+mod store_gc;
 mod tool;
 mod venv;
+mod wheelhouse_verify;
+mod workspace_affected;
+mod workspace_graph;
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub(crate) enum ExitStatus {
     /// The command succeeded.
     Success,
@@ -96,18 +122,113 @@ pub(crate) enum ExitStatus {
     Failure,
 
     /// The command failed with an unexpected error.
+    ///
+    /// Uses the same exit code as [`ExitStatus::Failure`].
     Error,
 
+    /// The command failed due to a network error, e.g., a connection failure, a timeout, or a
+    /// server error.
+    NetworkFailure,
+
+    /// The command failed because a downloaded artifact didn't match its expected hash.
+    HashMismatch,
+
+    /// The command failed because no suitable Python interpreter could be found.
+    InterpreterNotFound,
+
+    /// The command failed because the requirements could not be resolved.
+    NoSolution,
+
+    /// The command failed because the device ran out of space.
+    DiskFull,
+
     /// The command's exit status is propagated from an external command.
     External(u8),
 }
 
+impl ExitStatus {
+    /// Determine the exit status for an error based on its cause, if it corresponds to a known
+    /// failure class.
+    pub(crate) fn from_error(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        std::iter::successors(Some(err), |err| err.source()).find_map(|err| {
+            if let Some(err) = err.downcast_ref::<uv_client::WrappedReqwestError>() {
+                return err.is_network_error().then_some(Self::NetworkFailure);
+            }
+            if let Some(err) = err.downcast_ref::<uv_distribution::Error>() {
+                return Self::from_distribution_error(err);
+            }
+            if let Some(err) = err.downcast_ref::<Arc<uv_distribution::Error>>() {
+                return Self::from_distribution_error(err);
+            }
+            if let Some(err) = err.downcast_ref::<uv_python::Error>() {
+                return Self::from_python_error(err);
+            }
+            if let Some(err) = err.downcast_ref::<std::io::Error>() {
+                return Self::from_io_error(err);
+            }
+            if let Some(err) = err.downcast_ref::<pip::operations::Error>() {
+                return Self::from_operation_error(err);
+            }
+            if let Some(project::ProjectError::Python(err)) =
+                err.downcast_ref::<project::ProjectError>()
+            {
+                return Self::from_python_error(err);
+            }
+            if let Some(project::ProjectError::Operation(err)) =
+                err.downcast_ref::<project::ProjectError>()
+            {
+                return Self::from_operation_error(err);
+            }
+            if let Some(uv_tool::Error::EnvironmentError(err)) =
+                err.downcast_ref::<uv_tool::Error>()
+            {
+                return Self::from_python_error(err);
+            }
+            None
+        })
+    }
+
+    fn from_io_error(err: &std::io::Error) -> Option<Self> {
+        (err.kind() == std::io::ErrorKind::StorageFull).then_some(Self::DiskFull)
+    }
+
+    fn from_operation_error(err: &pip::operations::Error) -> Option<Self> {
+        match err {
+            pip::operations::Error::InsufficientDiskSpace { .. } => Some(Self::DiskFull),
+            // The I/O error is transparent, and so isn't included in the chain of sources.
+            pip::operations::Error::Io(err) => Self::from_io_error(err),
+            _ => None,
+        }
+    }
+
+    fn from_distribution_error(err: &uv_distribution::Error) -> Option<Self> {
+        match err {
+            uv_distribution::Error::MismatchedHashes { .. } => Some(Self::HashMismatch),
+            _ => None,
+        }
+    }
+
+    fn from_python_error(err: &uv_python::Error) -> Option<Self> {
+        match err {
+            uv_python::Error::MissingPython(..) => Some(Self::InterpreterNotFound),
+            uv_python::Error::Download(uv_python::downloads::Error::HashMismatch { .. }) => {
+                Some(Self::HashMismatch)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl From<ExitStatus> for ExitCode {
     fn from(status: ExitStatus) -> Self {
         match status {
             ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
+            ExitStatus::Failure | ExitStatus::Error => Self::from(1),
+            ExitStatus::NoSolution => Self::from(2),
+            ExitStatus::NetworkFailure => Self::from(3),
+            ExitStatus::HashMismatch => Self::from(4),
+            ExitStatus::InterpreterNotFound => Self::from(5),
+            ExitStatus::DiskFull => Self::from(6),
             ExitStatus::External(code) => Self::from(code),
         }
     }
@@ -155,15 +276,19 @@ pub(super) struct DryRunEvent<T: Display> {
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
-/// See the `--compile` option on `pip sync` and `pip install`.
+/// See the `--compile` option on `pip sync` and `pip install`. If `exclude_source` is set, the
+/// bytecode is written alongside the sources, and the sources of installed packages are removed
+/// (see the `--exclude-source` option).
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    exclude_source: bool,
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let mut files = 0;
+    let mut sources = 0;
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
         if !site_packages.exists() {
@@ -178,6 +303,7 @@ pub(super) async fn compile_bytecode(
             venv.python_executable(),
             concurrency,
             cache.root(),
+            exclude_source,
         )
         .await
         .with_context(|| {
@@ -186,6 +312,21 @@ pub(super) async fn compile_bytecode(
                 site_packages.user_display()
             )
         })?;
+
+        if exclude_source {
+            for entry in fs_err::read_dir(&site_packages)? {
+                let path = entry?.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "dist-info")
+                {
+                    sources +=
+                        uv_install_wheel::remove_compiled_sources(&path).with_context(|| {
+                            format!("Failed to remove sources of: {}", path.user_display())
+                        })?;
+                }
+            }
+        }
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -198,6 +339,14 @@ pub(super) async fn compile_bytecode(
         )
         .dimmed()
     )?;
+    if exclude_source {
+        let s = if sources == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Removed {}", format!("{sources} source file{s}").bold()).dimmed()
+        )?;
+    }
     Ok(())
 }
 
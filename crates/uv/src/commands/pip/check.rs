@@ -7,7 +7,7 @@ use owo_colors::OwoColorize;
 use uv_cache::Cache;
 use uv_configuration::TargetTriple;
 use uv_distribution_types::{Diagnostic, InstalledDist};
-use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_installer::{SitePackages, SitePackagesDiagnostic, ValidationMode};
 use uv_preview::Preview;
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
@@ -16,7 +16,7 @@ use uv_python::{
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::pip::{resolution_markers, resolution_tags};
 use crate::commands::{ExitStatus, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Check for incompatibilities in installed packages.
 pub(crate) fn pip_check(
@@ -24,6 +24,7 @@ pub(crate) fn pip_check(
     system: bool,
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    strict: bool,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -62,10 +63,12 @@ pub(crate) fn pip_check(
     let tags = resolution_tags(python_version, python_platform, environment.interpreter())?;
 
     // Run the diagnostics.
-    let diagnostics: Vec<SitePackagesDiagnostic> = site_packages
-        .diagnostics(&markers, &tags)?
-        .into_iter()
-        .collect();
+    let diagnostics: Vec<SitePackagesDiagnostic> = uv_installer::validate_environment(
+        &site_packages,
+        &markers,
+        &tags,
+        ValidationMode::from_strict(strict),
+    )?;
 
     if diagnostics.is_empty() {
         writeln!(
@@ -93,6 +96,16 @@ pub(crate) fn pip_check(
 
         for diagnostic in &diagnostics {
             writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+
+            // In strict mode, suggest how to repair the environment.
+            if let Some(hint) = strict.then(|| diagnostic.hint()).flatten() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}{} {hint}",
+                    "hint".bold().accent(),
+                    ":".bold()
+                )?;
+            }
         }
 
         Ok(ExitStatus::Failure)
@@ -1,28 +1,29 @@
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Result, anyhow};
 use itertools::Itertools;
-use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, ExportFormat, ExtrasSpecification,
-    IndexStrategy, NoBinary, NoBuild, Reinstall, SourceStrategy, Upgrade,
+    BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, IndexStrategy,
+    NoBinary, NoBuild, PipCompileFormat, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequiresPython,
-    UnresolvedRequirementSpecification, Verbatim,
+    IndexUrl, NameRequirementSpecification, Origin, PackageConfigSettings, Requirement,
+    RequiresPython, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -34,15 +35,16 @@ use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
     PythonVersion, VersionRequest,
 };
+use uv_redacted::redact_credentials;
 use uv_requirements::upgrade::{LockedRequirements, read_pylock_toml_requirements};
 use uv_requirements::{
     GroupsSpecification, RequirementsSource, RequirementsSpecification, is_pylock_toml,
     upgrade::read_requirements_txt,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    AnnotationStyle, DependencyMode, DisplayResolutionDot, DisplayResolutionGraph, ExcludeNewer,
+    FlatIndex, ForkStrategy, InMemoryIndex, OptionsBuilder, Preference, PrereleaseMode, PylockToml,
+    PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_static::EnvVars;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
@@ -52,9 +54,10 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
-use crate::commands::pip::{operations, resolution_environment};
+use crate::commands::pip::provenance::{Provenance, ProvenanceDifference};
+use crate::commands::pip::{operations, read_marker_snapshot, resolution_environment};
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Resolve a set of requirements into a set of pinned versions.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -65,18 +68,21 @@ pub(crate) async fn pip_compile(
     build_constraints: &[RequirementsSource],
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
+    scoped_overrides_from_workspace: Vec<(PackageName, Requirement)>,
     build_constraints_from_workspace: Vec<Requirement>,
     environments: SupportedEnvironments,
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
-    format: Option<ExportFormat>,
+    format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
+    allow_insecure_md5_hashes: bool,
+    reuse_hashes: bool,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_markers: bool,
@@ -88,6 +94,13 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    include_license: bool,
+    include_homepage: bool,
+    include_latest_version: bool,
+    include_summary: bool,
+    include_fork_annotation: bool,
+    include_provenance: bool,
+    verify: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -102,6 +115,7 @@ pub(crate) async fn pip_compile(
     build_options: BuildOptions,
     mut python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    marker_snapshot: Option<&Path>,
     universal: bool,
     exclude_newer: ExcludeNewer,
     sources: SourceStrategy,
@@ -133,7 +147,7 @@ pub(crate) async fn pip_compile(
     {
         return Err(anyhow!(
             "`pyproject.toml` is not a supported output format for `{}` (only `requirements.txt`-style output is supported)",
-            "uv pip compile".green()
+            "uv pip compile".success()
         ));
     }
 
@@ -141,16 +155,18 @@ pub(crate) async fn pip_compile(
     let format = format.unwrap_or_else(|| {
         let extension = output_file.and_then(Path::extension);
         if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("txt")) {
-            ExportFormat::RequirementsTxt
+            PipCompileFormat::RequirementsTxt
         } else if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("toml")) {
-            ExportFormat::PylockToml
+            PipCompileFormat::PylockToml
+        } else if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("dot")) {
+            PipCompileFormat::Dot
         } else {
-            ExportFormat::RequirementsTxt
+            PipCompileFormat::RequirementsTxt
         }
     });
 
     // If the user is exporting to PEP 751, ensure the filename matches the specification.
-    if matches!(format, ExportFormat::PylockToml) {
+    if matches!(format, PipCompileFormat::PylockToml) {
         if let Some(file_name) = output_file
             .and_then(Path::file_name)
             .and_then(OsStr::to_str)
@@ -186,6 +202,17 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Read the environment markers of the target platform, if provided. Unless a Python version
+    // was requested explicitly, resolve for the Python version of the snapshot.
+    let marker_snapshot = marker_snapshot.map(read_marker_snapshot).transpose()?;
+    if python_version.is_none() {
+        if let Some(marker_snapshot) = marker_snapshot.as_ref() {
+            python_version = Some(PythonVersion::from(
+                marker_snapshot.python_full_version().clone(),
+            ));
+        }
+    }
+
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
     if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
@@ -196,12 +223,21 @@ pub(crate) async fn pip_compile(
 
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
+    // Collect the input files, to record them in the provenance block.
+    let sources = requirements
+        .iter()
+        .chain(constraints)
+        .chain(overrides)
+        .chain(build_constraints)
+        .collect::<Vec<_>>();
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         pylock,
         source_trees,
         groups,
@@ -212,6 +248,7 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
+        comments,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -248,6 +285,11 @@ pub(crate) async fn pip_compile(
         )
         .collect();
 
+    let scoped_overrides: Vec<(PackageName, Requirement)> = scoped_overrides
+        .into_iter()
+        .chain(scoped_overrides_from_workspace)
+        .collect();
+
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
         operations::read_constraints(build_constraints, &client_builder)
@@ -312,7 +354,7 @@ pub(crate) async fn pip_compile(
     debug!(
         "Using Python {} interpreter at {} for builds",
         interpreter.python_version(),
-        interpreter.sys_executable().user_display().cyan()
+        interpreter.sys_executable().user_display().accent()
     );
 
     if let Some(python_version) = python_version.as_ref() {
@@ -370,14 +412,18 @@ pub(crate) async fn pip_compile(
             ResolverEnvironment::universal(environments.into_markers()),
         )
     } else {
-        let (tags, marker_env) =
-            resolution_environment(python_version, python_platform, &interpreter)?;
+        let (tags, marker_env) = resolution_environment(
+            python_version,
+            python_platform,
+            marker_snapshot.as_ref(),
+            &interpreter,
+        )?;
         (Some(tags), ResolverEnvironment::specific(marker_env))
     };
 
     // Generate, but don't enforce hashes for the requirements. PEP 751 _requires_ a hash to be
     // present, but otherwise, we omit them by default.
-    let hasher = if generate_hashes || matches!(format, ExportFormat::PylockToml) {
+    let hasher = if generate_hashes || matches!(format, PipCompileFormat::PylockToml) {
         HashStrategy::Generate(HashGeneration::All)
     } else {
         HashStrategy::None
@@ -399,6 +445,30 @@ pub(crate) async fn pip_compile(
         no_index,
     );
 
+    // If requested, compare the provenance of the existing output file to the current inputs,
+    // rather than performing a resolution.
+    if verify {
+        let provenance = Provenance::new(
+            &sources,
+            python_requirement.target(),
+            &exclude_newer,
+            &index_locations,
+        )?;
+        return verify_provenance(output_file, &provenance, printer).await;
+    }
+
+    // Record the inputs to the resolution, to enable reproducing the output.
+    let provenance = if include_provenance {
+        Some(Provenance::new(
+            &sources,
+            python_requirement.target(),
+            &exclude_newer,
+            &index_locations,
+        )?)
+    } else {
+        None
+    };
+
     // Determine the PyTorch backend.
     let torch_backend = torch_backend
         .map(|mode| {
@@ -434,17 +504,29 @@ pub(crate) async fn pip_compile(
     let LockedRequirements { preferences, git } =
         if let Some(output_file) = output_file.filter(|output_file| output_file.exists()) {
             match format {
-                ExportFormat::RequirementsTxt => LockedRequirements::from_preferences(
+                PipCompileFormat::RequirementsTxt => LockedRequirements::from_preferences(
                     read_requirements_txt(output_file, &upgrade).await?,
                 ),
-                ExportFormat::PylockToml => {
+                PipCompileFormat::PylockToml => {
                     read_pylock_toml_requirements(output_file, &upgrade).await?
                 }
+                // A DOT graph can't be used as a source of preferences.
+                PipCompileFormat::Dot => LockedRequirements::default(),
             }
         } else {
             LockedRequirements::default()
         };
 
+    // Unless requested otherwise, reuse the hashes of any unchanged pins from the output file.
+    let preferences = if reuse_hashes {
+        preferences
+    } else {
+        preferences
+            .into_iter()
+            .map(Preference::without_hashes)
+            .collect()
+    };
+
     // Populate the Git resolver.
     for ResolvedRepositoryReference { reference, sha } in git {
         debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -525,6 +607,7 @@ pub(crate) async fn pip_compile(
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
+        .allow_insecure_md5_hashes(allow_insecure_md5_hashes)
         .build();
 
     // Resolve the requirements.
@@ -532,6 +615,7 @@ pub(crate) async fn pip_compile(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         source_trees,
         project,
         BTreeSet::default(),
@@ -553,6 +637,7 @@ pub(crate) async fn pip_compile(
         &build_dispatch,
         concurrency,
         options,
+        None,
         Box::new(DefaultResolveLogger),
         printer,
     )
@@ -562,10 +647,15 @@ pub(crate) async fn pip_compile(
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
     };
 
+    // Reject any distributions for which the index only provides insecure hashes.
+    if generate_hashes {
+        resolution.validate_hashes()?;
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
@@ -573,7 +663,7 @@ pub(crate) async fn pip_compile(
         writeln!(
             writer,
             "{}",
-            "# This file was autogenerated by uv via the following command:".green()
+            "# This file was autogenerated by uv via the following command:".success()
         )?;
         writeln!(
             writer,
@@ -586,12 +676,18 @@ pub(crate) async fn pip_compile(
                     custom_compile_command
                 )
             )
-            .green()
+            .success()
         )?;
     }
 
+    if let Some(provenance) = provenance {
+        for line in provenance.to_string().lines() {
+            writeln!(writer, "{}", line.success())?;
+        }
+    }
+
     match format {
-        ExportFormat::RequirementsTxt => {
+        PipCompileFormat::RequirementsTxt => {
             if include_marker_expression {
                 if let Some(marker_env) = resolver_env.marker_environment() {
                     let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
@@ -599,9 +695,9 @@ pub(crate) async fn pip_compile(
                         writeln!(
                             writer,
                             "{}",
-                            "# Pinned dependencies known to be valid for:".green()
+                            "# Pinned dependencies known to be valid for:".success()
                         )?;
-                        writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+                        writeln!(writer, "{}", format!("#    {relevant_markers}").success())?;
                     }
                 }
             }
@@ -611,13 +707,17 @@ pub(crate) async fn pip_compile(
             // If necessary, include the `--index-url` and `--extra-index-url` locations.
             if include_index_url {
                 if let Some(index) = index_locations.default_index() {
-                    writeln!(writer, "--index-url {}", index.url().verbatim())?;
+                    writeln!(writer, "--index-url {}", emitted_url(index.url()))?;
                     wrote_preamble = true;
                 }
                 let mut seen = FxHashSet::default();
                 for extra_index in index_locations.implicit_indexes() {
                     if seen.insert(extra_index.url()) {
-                        writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
+                        writeln!(
+                            writer,
+                            "--extra-index-url {}",
+                            emitted_url(extra_index.url())
+                        )?;
                         wrote_preamble = true;
                     }
                 }
@@ -626,7 +726,7 @@ pub(crate) async fn pip_compile(
             // If necessary, include the `--find-links` locations.
             if include_find_links {
                 for flat_index in index_locations.flat_indexes() {
-                    writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
+                    writeln!(writer, "--find-links {}", emitted_url(flat_index.url()))?;
                     wrote_preamble = true;
                 }
             }
@@ -678,11 +778,18 @@ pub(crate) async fn pip_compile(
                     include_markers || universal,
                     include_annotations,
                     include_index_annotation,
+                    include_license,
+                    include_homepage,
+                    include_latest_version,
+                    &top_level_index,
+                    include_summary,
+                    include_fork_annotation,
                     annotation_style,
+                    &comments,
                 )
             )?;
         }
-        ExportFormat::PylockToml => {
+        PipCompileFormat::PylockToml => {
             if include_marker_expression {
                 warn_user!(
                     "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
@@ -708,6 +815,27 @@ pub(crate) async fn pip_compile(
                     "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
                 );
             }
+            if include_license {
+                warn_user!("The `--emit-license` option is not supported for `pylock.toml` output");
+            }
+            if include_homepage {
+                warn_user!(
+                    "The `--emit-homepage` option is not supported for `pylock.toml` output"
+                );
+            }
+            if include_latest_version {
+                warn_user!(
+                    "The `--emit-latest-version` option is not supported for `pylock.toml` output"
+                );
+            }
+            if include_summary {
+                warn_user!("The `--emit-summary` option is not supported for `pylock.toml` output");
+            }
+            if include_fork_annotation {
+                warn_user!(
+                    "The `--emit-fork-annotation` option is not supported for `pylock.toml` output"
+                );
+            }
 
             // Determine the directory relative to which the output file should be written.
             let output_file = output_file.map(std::path::absolute).transpose()?;
@@ -721,6 +849,30 @@ pub(crate) async fn pip_compile(
             let export = PylockToml::from_resolution(&resolution, &no_emit_packages, install_path)?;
             write!(writer, "{}", export.to_toml()?)?;
         }
+        PipCompileFormat::Dot => {
+            for (enabled, option) in [
+                (include_marker_expression, "--emit-marker-expression"),
+                (include_index_url, "--emit-index-url"),
+                (include_find_links, "--emit-find-links"),
+                (include_build_options, "--emit-build-options"),
+                (include_index_annotation, "--emit-index-annotation"),
+                (include_license, "--emit-license"),
+                (include_homepage, "--emit-homepage"),
+                (include_latest_version, "--emit-latest-version"),
+                (include_summary, "--emit-summary"),
+                (include_fork_annotation, "--emit-fork-annotation"),
+            ] {
+                if enabled {
+                    warn_user!("The `{option}` option is not supported for DOT output");
+                }
+            }
+
+            write!(
+                writer,
+                "{}",
+                DisplayResolutionDot::new(&resolution, &no_emit_packages)
+            )?;
+        }
     }
 
     // If any "unsafe" packages were excluded, notify the user.
@@ -733,7 +885,7 @@ pub(crate) async fn pip_compile(
         writeln!(
             writer,
             "{}",
-            "# The following packages were excluded from the output:".green()
+            "# The following packages were excluded from the output:".success()
         )?;
         for package in excluded {
             writeln!(writer, "# {package}")?;
@@ -749,6 +901,76 @@ pub(crate) async fn pip_compile(
     Ok(ExitStatus::Success)
 }
 
+/// Verify that the provenance recorded in an existing output file matches the current inputs.
+async fn verify_provenance(
+    output_file: Option<&Path>,
+    provenance: &Provenance,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some(output_file) = output_file else {
+        return Err(anyhow!(
+            "`--verify` requires an output file (provide one with `--output-file`)"
+        ));
+    };
+
+    let contents = match fs_err::tokio::read_to_string(output_file).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(anyhow!(
+                "Output file `{}` does not exist",
+                output_file.user_display()
+            ));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let Some(recorded) = Provenance::parse(&contents) else {
+        return Err(anyhow!(
+            "Output file `{}` does not contain a provenance block (regenerate it with `{}`)",
+            output_file.user_display(),
+            "--emit-provenance".success()
+        ));
+    };
+
+    let (versions, differences): (Vec<_>, Vec<_>) = recorded
+        .diff(provenance)
+        .into_iter()
+        .partition(ProvenanceDifference::is_version);
+
+    // A different uv version may produce a different resolution, but isn't itself a change to
+    // the inputs.
+    for difference in versions {
+        warn_user!("{difference}");
+    }
+
+    if differences.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Output file `{}` is up-to-date",
+            output_file.user_display().accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Output file `{}` is out-of-date:",
+        output_file.user_display().accent()
+    )?;
+    for difference in differences {
+        writeln!(printer.stderr(), "  - {difference}")?;
+    }
+    Ok(ExitStatus::Failure)
+}
+
+/// Format an index URL for inclusion in the output file, omitting any credentials.
+fn emitted_url(url: &IndexUrl) -> Cow<'_, str> {
+    match url.without_credentials() {
+        Cow::Borrowed(_) => url.verbatim(),
+        Cow::Owned(url) => Cow::Owned(url.to_string()),
+    }
+}
+
 /// Format the uv command used to generate the output file.
 #[allow(clippy::fn_params_excessive_bools)]
 fn cmd(
@@ -853,8 +1075,8 @@ fn cmd(
                 return Some(None);
             }
 
-            // Return the argument.
-            Some(Some(arg))
+            // Return the argument, omitting any credentials (e.g., in a Git requirement).
+            Some(Some(redact_credentials(&arg).into_owned()))
         })
         .flatten()
         .join(" ");
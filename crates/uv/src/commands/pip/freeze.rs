@@ -6,19 +6,22 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 
 use uv_cache::Cache;
-use uv_distribution_types::{Diagnostic, InstalledDistKind, Name};
+use uv_distribution_types::{InstalledDirectUrlDist, InstalledDistKind, Name};
 use uv_installer::SitePackages;
+use uv_normalize::PackageName;
 use uv_preview::Preview;
+use uv_pypi_types::DirectUrl;
 use uv_python::PythonPreference;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
 
 use crate::commands::ExitStatus;
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{report_strict_diagnostics, report_target_environment};
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
+    exclude: &[PackageName],
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -60,6 +63,7 @@ pub(crate) fn pip_freeze(
         .iter()
         .flat_map(uv_installer::SitePackages::iter)
         .filter(|dist| !(exclude_editable && dist.is_editable()))
+        .filter(|dist| !exclude.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .map(|dist| match &dist.kind {
             InstalledDistKind::Registry(dist) => {
@@ -69,7 +73,7 @@ pub(crate) fn pip_freeze(
                 if dist.editable {
                     format!("-e {}", dist.url)
                 } else {
-                    format!("{} @ {}", dist.name().bold(), dist.url)
+                    format!("{} @ {}", dist.name().bold(), direct_reference(dist))
                 }
             }
             InstalledDistKind::EggInfoFile(dist) => {
@@ -91,18 +95,39 @@ pub(crate) fn pip_freeze(
         let markers = environment.interpreter().resolver_marker_environment();
         let tags = environment.interpreter().tags()?;
 
-        for entry in site_packages {
-            for diagnostic in entry.diagnostics(&markers, tags)? {
-                writeln!(
-                    printer.stderr(),
-                    "{}{} {}",
-                    "warning".yellow().bold(),
-                    ":".bold(),
-                    diagnostic.message().bold()
-                )?;
-            }
+        for entry in &site_packages {
+            report_strict_diagnostics(entry, &markers, tags, printer)?;
         }
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Format the URL of a direct URL distribution for use in a PEP 508 direct reference.
+///
+/// As in `pip freeze`, the archive hash (if recorded in `direct_url.json`) is included in the
+/// fragment, such that the output can be used to reinstall the same artifact.
+fn direct_reference(dist: &InstalledDirectUrlDist) -> String {
+    let DirectUrl::ArchiveUrl {
+        archive_info,
+        subdirectory,
+        ..
+    } = &*dist.direct_url
+    else {
+        return dist.url.to_string();
+    };
+    let Some(hash) = archive_info.fragment_hash() else {
+        return dist.url.to_string();
+    };
+
+    let mut url = dist.url.clone();
+    if let Some(subdirectory) = subdirectory {
+        url.set_fragment(Some(&format!(
+            "{hash}&subdirectory={}",
+            subdirectory.display()
+        )));
+    } else {
+        url.set_fragment(Some(&hash));
+    }
+    url.to_string()
+}
@@ -1,9 +1,10 @@
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::Context;
 use itertools::Itertools;
-use owo_colors::OwoColorize;
 use tracing::{Level, debug, enabled, warn};
 
 use uv_cache::Cache;
@@ -20,10 +21,10 @@ use uv_distribution_types::{
     NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
     UnresolvedRequirementSpecification,
 };
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::Conflicts;
 use uv_python::{
@@ -35,6 +36,7 @@ use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
     PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
+use uv_settings::{PluginEvent, Plugins};
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
 use uv_types::HashStrategy;
 use uv_warnings::{warn_user, warn_user_once};
@@ -44,7 +46,10 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    check_platform_override, operations, resolution_markers, resolution_tags,
+};
+use crate::commands::plugins::{self, PluginCandidateVeto, PluginPackage};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 
@@ -57,6 +62,7 @@ pub(crate) async fn pip_install(
     build_constraints: &[RequirementsSource],
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
+    scoped_overrides_from_workspace: Vec<(PackageName, Requirement)>,
     build_constraints_from_workspace: Vec<Requirement>,
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
@@ -75,6 +81,9 @@ pub(crate) async fn pip_install(
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     build_isolation: BuildIsolation,
@@ -84,6 +93,7 @@ pub(crate) async fn pip_install(
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_override: bool,
     strict: bool,
     exclude_newer: ExcludeNewer,
     sources: SourceStrategy,
@@ -96,6 +106,9 @@ pub(crate) async fn pip_install(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    allow_script_conflicts: bool,
+    exclude_source: bool,
+    no_cache_download: bool,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
@@ -118,6 +131,7 @@ pub(crate) async fn pip_install(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         pylock,
         source_trees,
         groups,
@@ -167,6 +181,11 @@ pub(crate) async fn pip_install(
         )
         .collect();
 
+    let scoped_overrides: Vec<(PackageName, Requirement)> = scoped_overrides
+        .into_iter()
+        .chain(scoped_overrides_from_workspace)
+        .collect();
+
     // Read build constraints.
     let build_constraints: Vec<NameRequirementSpecification> =
         operations::read_constraints(build_constraints, &client_builder)
@@ -232,32 +251,34 @@ pub(crate) async fn pip_install(
     };
 
     // If the environment is externally managed, abort.
-    if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan()
-                ))
-            };
-        }
-    }
+    operations::check_externally_managed(&environment, break_system_packages)?;
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match environment.lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
+
+    // If requested, download distributions into a temporary cache on the same filesystem as the
+    // environment, which is removed once the installation completes.
+    let cache = if no_cache_download {
+        let site_packages = environment
+            .site_packages()
+            .next()
+            .map(Cow::into_owned)
+            .unwrap_or_else(|| environment.root().to_path_buf());
+        fs_err::create_dir_all(&site_packages)?;
+        debug!(
+            "Using a temporary cache in: {}",
+            site_packages.user_display()
+        );
+        Cache::temp_in(&site_packages)?.init()?
+    } else {
+        cache
+    };
 
     // Determine the markers and tags to use for the resolution.
     let interpreter = environment.interpreter();
@@ -283,6 +304,7 @@ pub(crate) async fn pip_install(
         && source_trees.is_empty()
         && groups.is_empty()
         && pylock.is_none()
+        && scoped_overrides.is_empty()
         && matches!(modifications, Modifications::Sufficient)
     {
         match site_packages.satisfies_spec(
@@ -290,6 +312,7 @@ pub(crate) async fn pip_install(
             &constraints,
             &overrides,
             InstallationStrategy::Permissive,
+            dependency_mode.is_transitive(),
             &marker_env,
             &tags,
             config_settings,
@@ -438,6 +461,11 @@ pub(crate) async fn pip_install(
     // Initialize any shared state.
     let state = SharedState::default();
 
+    // If the platform is spoofed, verify that the wheels can be installed natively.
+    if platform_override {
+        check_platform_override(&resolution, interpreter)?;
+    }
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -531,6 +559,7 @@ pub(crate) async fn pip_install(
             requirements,
             constraints,
             overrides,
+            scoped_overrides,
             source_trees,
             project,
             BTreeSet::default(),
@@ -552,6 +581,7 @@ pub(crate) async fn pip_install(
             &build_dispatch,
             concurrency,
             options,
+            PluginCandidateVeto::from_plugins(plugins, "pip install"),
             Box::new(DefaultResolveLogger),
             printer,
         )
@@ -563,10 +593,20 @@ pub(crate) async fn pip_install(
                     client_builder.is_native_tls(),
                 )
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
             }
         };
 
+        // Allow any plugins to veto the resolution.
+        plugins::dispatch(
+            plugins,
+            PluginEvent::Resolve,
+            "pip install",
+            None,
+            &PluginPackage::from_resolution(&resolution),
+        )
+        .await?;
+
         (resolution, hasher)
     };
 
@@ -599,6 +639,18 @@ pub(crate) async fn pip_install(
         preview,
     );
 
+    // Allow any plugins to veto the installation.
+    if !dry_run.enabled() {
+        plugins::dispatch(
+            plugins,
+            PluginEvent::Install,
+            "pip install",
+            Some(environment.root()),
+            &PluginPackage::from_resolution(&resolution),
+        )
+        .await?;
+    }
+
     // Sync the environment.
     match operations::install(
         &resolution,
@@ -608,6 +660,7 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
+        None,
         compile,
         &hasher,
         &tags,
@@ -619,6 +672,9 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        allow_script_conflicts,
+        exclude_source,
         dry_run,
         printer,
         preview,
@@ -629,7 +685,7 @@ pub(crate) async fn pip_install(
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
     }
 
@@ -1,5 +1,5 @@
 use std::cmp::max;
-use std::fmt::Write;
+use std::path::Path;
 
 use anstream::println;
 use anyhow::Result;
@@ -10,29 +10,32 @@ use rustc_hash::FxHashMap;
 use serde::Serialize;
 use tokio::sync::Semaphore;
 use unicode_width::UnicodeWidthStr;
+use url::Url;
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
-use uv_cli::ListFormat;
+use uv_cli::{ListFormat, ListSort};
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    Diagnostic, IndexCapabilities, IndexLocations, InstalledDist, Name, RequiresPython,
+    IndexCapabilities, IndexLocations, InstalledDist, Name, RequiresPython,
 };
 use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_preview::Preview;
+use uv_pypi_types::DirectUrl;
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference};
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
 
-use crate::commands::ExitStatus;
 use crate::commands::pip::latest::LatestClient;
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{report_strict_diagnostics, report_target_environment};
 use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
@@ -41,7 +44,10 @@ pub(crate) async fn pip_list(
     editable: Option<bool>,
     exclude: &[PackageName],
     format: &ListFormat,
+    sort: ListSort,
     outdated: bool,
+    include_metadata: bool,
+    include_size: bool,
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -61,6 +67,16 @@ pub(crate) async fn pip_list(
         anyhow::bail!("`--outdated` cannot be used with `--format freeze`");
     }
 
+    // Disallow `--include-metadata` with `--format freeze`.
+    if include_metadata && matches!(format, ListFormat::Freeze) {
+        anyhow::bail!("`--include-metadata` cannot be used with `--format freeze`");
+    }
+
+    // Disallow `--include-size` with `--format freeze`.
+    if include_size && matches!(format, ListFormat::Freeze) {
+        anyhow::bail!("`--include-size` cannot be used with `--format freeze`");
+    }
+
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
         &python.map(PythonRequest::parse).unwrap_or_default(),
@@ -75,14 +91,34 @@ pub(crate) async fn pip_list(
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
 
-    // Filter if `--editable` is specified; always sort by name.
-    let results = site_packages
+    // Filter if `--editable` is specified; sort by name, then by the requested key.
+    let mut results = site_packages
         .iter()
         .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .filter(|dist| !exclude.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
 
+    // Compute the installed size of each package, if needed.
+    let mut sizes = FxHashMap::default();
+    if include_size || matches!(sort, ListSort::Size) {
+        for dist in results.iter().copied() {
+            if let Some(size) = installed_size(dist)? {
+                sizes.insert(dist.install_path(), size);
+            }
+        }
+    }
+
+    match sort {
+        ListSort::Name => {}
+        ListSort::Version => results.sort_by(|a, b| a.version().cmp(b.version())),
+        ListSort::Size => results.sort_by(|a, b| {
+            sizes
+                .get(b.install_path())
+                .cmp(&sizes.get(a.install_path()))
+        }),
+    }
+
     // Determine the latest version for each package.
     let latest = if outdated && !results.is_empty() {
         let capabilities = IndexCapabilities::default();
@@ -158,12 +194,29 @@ pub(crate) async fn pip_list(
         results
     };
 
+    // Read the installer metadata for each package, if requested.
+    let metadata = if include_metadata {
+        results
+            .iter()
+            .map(|dist| {
+                Ok(Some(InstallerMetadata {
+                    installer: dist.read_installer()?,
+                    requested: dist.read_requested(),
+                    direct_url: dist.direct_url(),
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        vec![None; results.len()]
+    };
+
     match format {
         ListFormat::Json => {
             let rows = results
                 .iter()
                 .copied()
-                .map(|dist| Entry {
+                .zip(metadata)
+                .map(|(dist, metadata)| Entry {
                     name: dist.name().clone(),
                     version: dist.version().clone(),
                     latest_version: latest
@@ -178,6 +231,16 @@ pub(crate) async fn pip_list(
                     editable_project_location: dist
                         .as_editable()
                         .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
+                    installed_size: if include_size {
+                        sizes.get(dist.install_path()).copied()
+                    } else {
+                        None
+                    },
+                    installer: metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.installer.clone()),
+                    requested: metadata.as_ref().map(|metadata| metadata.requested),
+                    direct_url: metadata.and_then(|metadata| metadata.direct_url),
                 })
                 .collect_vec();
             let output = serde_json::to_string(&rows)?;
@@ -237,6 +300,25 @@ pub(crate) async fn pip_list(
                 });
             }
 
+            // The installed size is only displayed if requested.
+            if include_size {
+                columns.push(Column {
+                    header: String::from("Size"),
+                    rows: results
+                        .iter()
+                        .map(|dist| {
+                            sizes
+                                .get(dist.install_path())
+                                .map(|size| {
+                                    let (bytes, unit) = human_readable_bytes(*size);
+                                    format!("{bytes:.1}{unit}")
+                                })
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                });
+            }
+
             // Editable column is only displayed if at least one editable package is found.
             if results.iter().copied().any(InstalledDist::is_editable) {
                 columns.push(Column {
@@ -254,6 +336,58 @@ pub(crate) async fn pip_list(
                 });
             }
 
+            // Installer metadata is only displayed if requested.
+            if include_metadata {
+                columns.push(Column {
+                    header: String::from("Installer"),
+                    rows: metadata
+                        .iter()
+                        .map(|metadata| {
+                            metadata
+                                .as_ref()
+                                .and_then(|metadata| metadata.installer.clone())
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                });
+                columns.push(Column {
+                    header: String::from("Requested"),
+                    rows: metadata
+                        .iter()
+                        .map(|metadata| {
+                            if metadata.as_ref().is_some_and(|metadata| metadata.requested) {
+                                String::from("yes")
+                            } else {
+                                String::from("no")
+                            }
+                        })
+                        .collect_vec(),
+                });
+
+                // The direct URL column is only displayed if at least one package has a URL.
+                if metadata
+                    .iter()
+                    .flatten()
+                    .any(|metadata| metadata.direct_url.is_some())
+                {
+                    columns.push(Column {
+                        header: String::from("Direct URL"),
+                        rows: metadata
+                            .iter()
+                            .map(|metadata| {
+                                metadata
+                                    .as_ref()
+                                    .and_then(|metadata| metadata.direct_url)
+                                    .and_then(|direct_url| Url::try_from(direct_url).ok())
+                                    .as_ref()
+                                    .map(ToString::to_string)
+                                    .unwrap_or_default()
+                            })
+                            .collect_vec(),
+                    });
+                }
+            }
+
             for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
                 println!("{}", elems.join(" ").trim_end());
             }
@@ -272,20 +406,41 @@ pub(crate) async fn pip_list(
         let markers = environment.interpreter().resolver_marker_environment();
         let tags = environment.interpreter().tags()?;
 
-        for diagnostic in site_packages.diagnostics(&markers, tags)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
-        }
+        report_strict_diagnostics(&site_packages, &markers, tags, printer)?;
     }
 
     Ok(ExitStatus::Success)
 }
 
+/// Compute the installed size of a distribution, in bytes, from the files listed in its `RECORD`.
+///
+/// Returns `None` if the distribution doesn't have a `RECORD` (e.g., for `.egg-info`
+/// distributions).
+fn installed_size(dist: &InstalledDist) -> Result<Option<u64>> {
+    let record = dist.install_path().join("RECORD");
+    if !record.is_file() {
+        return Ok(None);
+    }
+    let Some(site_packages) = dist.install_path().parent() else {
+        return Ok(None);
+    };
+
+    // Prefer the size recorded in the `RECORD`, falling back to the size on disk for entries
+    // without one (e.g., the `RECORD` itself). Files that no longer exist are ignored.
+    let size = read_record_file(&mut fs_err::File::open(&record)?)?
+        .into_iter()
+        .filter_map(|entry| {
+            entry.size.or_else(|| {
+                fs_err::metadata(site_packages.join(Path::new(&entry.path)))
+                    .ok()
+                    .map(|metadata| metadata.len())
+            })
+        })
+        .sum();
+
+    Ok(Some(size))
+}
+
 #[derive(Debug)]
 enum FileType {
     /// A wheel distribution (i.e., a `.whl` file).
@@ -326,7 +481,7 @@ impl From<&DistFilename> for FileType {
 
 /// An entry in a JSON list of installed packages.
 #[derive(Debug, Serialize)]
-struct Entry {
+struct Entry<'a> {
     name: PackageName,
     version: Version,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -335,15 +490,34 @@ struct Entry {
     latest_filetype: Option<FileType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     editable_project_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direct_url: Option<&'a DirectUrl>,
+}
+
+/// The installer metadata of an installed package, per PEP 376 and PEP 610.
+#[derive(Debug, Clone)]
+struct InstallerMetadata<'a> {
+    /// The contents of the `INSTALLER` file.
+    installer: Option<String>,
+    /// Whether the package has a `REQUESTED` file.
+    requested: bool,
+    /// The contents of the `direct_url.json` file.
+    direct_url: Option<&'a DirectUrl>,
 }
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(crate) struct Column {
     /// The header of the column.
-    header: String,
+    pub(crate) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(crate) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -356,7 +530,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(crate) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -373,7 +547,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(crate) struct MultiZip<T>(pub(crate) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
@@ -1,18 +1,39 @@
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Write;
+use std::time::Instant;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
 use uv_distribution_types::{InstalledMetadata, Name};
+use uv_i18n::message;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
 use crate::commands::pip::operations::Changelog;
 use crate::commands::{ChangeEvent, ChangeEventKind, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
+
+/// Format a count of packages, e.g., `3 packages`.
+fn package_count(count: usize) -> Cow<'static, str> {
+    if count == 1 {
+        message!("package-count-one", "{count} package", count = count)
+    } else {
+        message!("package-count-other", "{count} packages", count = count)
+    }
+}
+
+/// Format the time elapsed since the given instant, e.g., `in 2ms`.
+fn duration(start: Instant) -> Cow<'static, str> {
+    message!(
+        "duration",
+        "in {elapsed}",
+        elapsed = elapsed(start.elapsed())
+    )
+}
 
 /// A trait to handle logging during install operations.
 pub(crate) trait InstallLogger {
@@ -53,17 +74,22 @@ impl InstallLogger for DefaultInstallLogger {
             writeln!(
                 printer.stderr(),
                 "{}",
-                format!("Audited in {}", elapsed(start.elapsed())).dimmed()
+                message!(
+                    "audited-none",
+                    "Audited {duration}",
+                    duration = duration(start),
+                )
+                .dimmed()
             )
         } else {
-            let s = if count == 1 { "" } else { "s" };
             writeln!(
                 printer.stderr(),
                 "{}",
-                format!(
-                    "Audited {} {}",
-                    format!("{count} package{s}").bold(),
-                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                message!(
+                    "audited",
+                    "Audited {packages} {duration}",
+                    packages = package_count(count).bold(),
+                    duration = duration(start).dimmed(),
                 )
                 .dimmed()
             )
@@ -77,19 +103,19 @@ impl InstallLogger for DefaultInstallLogger {
         start: std::time::Instant,
         printer: Printer,
     ) -> fmt::Result {
-        let s = if count == 1 { "" } else { "s" };
+        let packages = if let Some(suffix) = suffix {
+            Cow::Owned(format!("{} {suffix}", package_count(count)))
+        } else {
+            package_count(count)
+        };
         writeln!(
             printer.stderr(),
             "{}",
-            format!(
-                "Prepared {} {}",
-                if let Some(suffix) = suffix {
-                    format!("{count} package{s} {suffix}")
-                } else {
-                    format!("{count} package{s}")
-                }
-                .bold(),
-                format!("in {}", elapsed(start.elapsed())).dimmed()
+            message!(
+                "prepared",
+                "Prepared {packages} {duration}",
+                packages = packages.bold(),
+                duration = duration(start).dimmed(),
             )
             .dimmed()
         )
@@ -101,28 +127,28 @@ impl InstallLogger for DefaultInstallLogger {
         start: std::time::Instant,
         printer: Printer,
     ) -> fmt::Result {
-        let s = if count == 1 { "" } else { "s" };
         writeln!(
             printer.stderr(),
             "{}",
-            format!(
-                "Uninstalled {} {}",
-                format!("{count} package{s}").bold(),
-                format!("in {}", elapsed(start.elapsed())).dimmed()
+            message!(
+                "uninstalled",
+                "Uninstalled {packages} {duration}",
+                packages = package_count(count).bold(),
+                duration = duration(start).dimmed(),
             )
             .dimmed()
         )
     }
 
     fn on_install(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
-        let s = if count == 1 { "" } else { "s" };
         writeln!(
             printer.stderr(),
             "{}",
-            format!(
-                "Installed {} {}",
-                format!("{count} package{s}").bold(),
-                format!("in {}", elapsed(start.elapsed())).dimmed()
+            message!(
+                "installed",
+                "Installed {packages} {duration}",
+                packages = package_count(count).bold(),
+                duration = duration(start).dimmed(),
             )
             .dimmed()
         )
@@ -162,7 +188,7 @@ impl InstallLogger for DefaultInstallLogger {
                     writeln!(
                         printer.stderr(),
                         " {} {}{}",
-                        "+".green(),
+                        "+".success(),
                         event.dist.name().bold(),
                         event.dist.installed_version().dimmed()
                     )?;
@@ -171,7 +197,7 @@ impl InstallLogger for DefaultInstallLogger {
                     writeln!(
                         printer.stderr(),
                         " {} {}{}",
-                        "-".red(),
+                        "-".error(),
                         event.dist.name().bold(),
                         event.dist.installed_version().dimmed()
                     )?;
@@ -180,7 +206,7 @@ impl InstallLogger for DefaultInstallLogger {
                     writeln!(
                         printer.stderr(),
                         " {} {}{}",
-                        "~".yellow(),
+                        "~".warning(),
                         event.dist.name().bold(),
                         event.dist.installed_version().dimmed()
                     )?;
@@ -222,28 +248,28 @@ impl InstallLogger for SummaryInstallLogger {
         start: std::time::Instant,
         printer: Printer,
     ) -> fmt::Result {
-        let s = if count == 1 { "" } else { "s" };
         writeln!(
             printer.stderr(),
             "{}",
-            format!(
-                "Uninstalled {} {}",
-                format!("{count} package{s}").bold(),
-                format!("in {}", elapsed(start.elapsed())).dimmed()
+            message!(
+                "uninstalled",
+                "Uninstalled {packages} {duration}",
+                packages = package_count(count).bold(),
+                duration = duration(start).dimmed(),
             )
             .dimmed()
         )
     }
 
     fn on_install(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
-        let s = if count == 1 { "" } else { "s" };
         writeln!(
             printer.stderr(),
             "{}",
-            format!(
-                "Installed {} {}",
-                format!("{count} package{s}").bold(),
-                format!("in {}", elapsed(start.elapsed())).dimmed()
+            message!(
+                "installed",
+                "Installed {packages} {duration}",
+                packages = package_count(count).bold(),
+                duration = duration(start).dimmed(),
             )
             .dimmed()
         )
@@ -342,7 +368,7 @@ impl InstallLogger for UpgradeInstallLogger {
                     writeln!(
                         printer.stderr(),
                         "{} {} {}",
-                        "Reinstalled".yellow().bold(),
+                        "Reinstalled".warning().bold(),
                         &self.target,
                         reinstalls
                     )?;
@@ -360,7 +386,7 @@ impl InstallLogger for UpgradeInstallLogger {
                     writeln!(
                         printer.stderr(),
                         "{} {} {} -> {}",
-                        "Updated".green().bold(),
+                        "Updated".success().bold(),
                         &self.target,
                         removals,
                         additions
@@ -376,7 +402,7 @@ impl InstallLogger for UpgradeInstallLogger {
                 writeln!(
                     printer.stderr(),
                     "{} {} {}",
-                    "Removed".red().bold(),
+                    "Removed".error().bold(),
                     &self.target,
                     removals
                 )?;
@@ -390,7 +416,7 @@ impl InstallLogger for UpgradeInstallLogger {
                 writeln!(
                     printer.stderr(),
                     "{} {} {}",
-                    "Added".green().bold(),
+                    "Added".success().bold(),
                     &self.target,
                     additions
                 )?;
@@ -435,17 +461,22 @@ impl ResolveLogger for DefaultResolveLogger {
             writeln!(
                 printer.stderr(),
                 "{}",
-                format!("Resolved in {}", elapsed(start.elapsed())).dimmed()
+                message!(
+                    "resolved-none",
+                    "Resolved {duration}",
+                    duration = duration(start),
+                )
+                .dimmed()
             )
         } else {
-            let s = if count == 1 { "" } else { "s" };
             writeln!(
                 printer.stderr(),
                 "{}",
-                format!(
-                    "Resolved {} {}",
-                    format!("{count} package{s}").bold(),
-                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                message!(
+                    "resolved",
+                    "Resolved {packages} {duration}",
+                    packages = package_count(count).bold(),
+                    duration = duration(start).dimmed(),
                 )
                 .dimmed()
             )
@@ -1,10 +1,19 @@
 use std::borrow::Cow;
+use std::path::Path;
+
+use anyhow::{Context, bail};
+use owo_colors::OwoColorize;
 
 use uv_configuration::TargetTriple;
+use uv_distribution_types::{BuiltDist, Dist, Resolution, ResolvedDist};
+use uv_fs::Simplified;
+use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
+use crate::printer::Styled;
+
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
@@ -12,7 +21,9 @@ pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
+pub(crate) mod normalize;
 pub(crate) mod operations;
+pub(crate) mod provenance;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
@@ -71,12 +82,34 @@ pub(crate) fn resolution_tags<'env>(
     })
 }
 
+/// Read a snapshot of the environment markers of a target platform, as generated by
+/// `uv env snapshot`.
+pub(crate) fn read_marker_snapshot(path: &Path) -> anyhow::Result<MarkerEnvironment> {
+    let contents = fs_err::read_to_string(path)?;
+    serde_json::from_str(&contents).with_context(|| {
+        format!(
+            "Failed to parse marker environment snapshot at `{}`",
+            path.user_display()
+        )
+    })
+}
+
 /// Determine the tags, markers, and interpreter to use for resolution.
-pub(crate) fn resolution_environment(
+///
+/// If a `marker_snapshot` of the target platform is provided, its markers are used in lieu of
+/// those approximated from the `python_platform`.
+pub(crate) fn resolution_environment<'env>(
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
-    interpreter: &Interpreter,
-) -> Result<(Cow<'_, Tags>, ResolverMarkerEnvironment), TagsError> {
+    marker_snapshot: Option<&MarkerEnvironment>,
+    interpreter: &'env Interpreter,
+) -> Result<(Cow<'env, Tags>, ResolverMarkerEnvironment), TagsError> {
+    let platform_markers = |python_platform: TargetTriple| {
+        marker_snapshot
+            .cloned()
+            .unwrap_or_else(|| python_platform.markers(interpreter.markers()))
+    };
+
     let tags = match (python_platform, python_version.as_ref()) {
         (Some(python_platform), Some(python_version)) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -108,10 +141,10 @@ pub(crate) fn resolution_environment(
     // Apply the platform tags to the markers.
     let markers = match (python_platform, python_version) {
         (Some(python_platform), Some(python_version)) => ResolverMarkerEnvironment::from(
-            python_version.markers(&python_platform.markers(interpreter.markers())),
+            python_version.markers(&platform_markers(python_platform)),
         ),
         (Some(python_platform), None) => {
-            ResolverMarkerEnvironment::from(python_platform.markers(interpreter.markers()))
+            ResolverMarkerEnvironment::from(platform_markers(python_platform))
         }
         (None, Some(python_version)) => {
             ResolverMarkerEnvironment::from(python_version.markers(interpreter.markers()))
@@ -121,3 +154,46 @@ pub(crate) fn resolution_environment(
 
     Ok((tags, markers))
 }
+
+/// Verify that the wheels in a resolution can run on the current platform.
+///
+/// When spoofing the platform (i.e., with `--platform-tag`), the resolution may include wheels for
+/// the spoofed platform, which can't be installed into an environment for the current platform.
+/// Installing into a `--target` or `--prefix` directory is allowed, e.g., when building an image
+/// for another platform.
+pub(crate) fn check_platform_override(
+    resolution: &Resolution,
+    interpreter: &Interpreter,
+) -> anyhow::Result<()> {
+    if interpreter.target().is_some() || interpreter.prefix().is_some() {
+        return Ok(());
+    }
+
+    let tags = interpreter.tags()?;
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        let Dist::Built(dist) = dist.as_ref() else {
+            continue;
+        };
+        let filename = match dist {
+            BuiltDist::Registry(wheels) => &wheels.best_wheel().filename,
+            BuiltDist::DirectUrl(wheel) => &wheel.filename,
+            BuiltDist::Path(wheel) => &wheel.filename,
+        };
+        if !filename.is_compatible(tags) {
+            bail!(
+                "The wheel `{}` is incompatible with the current platform, and can't be installed into its environment when spoofing the platform with `{}`\n\n{}{} Use `{}` or `{}` to install into a directory for another platform",
+                filename.accent(),
+                "--platform-tag".accent(),
+                "hint".bold().accent(),
+                ":".bold(),
+                "--target".success(),
+                "--prefix".success(),
+            );
+        }
+    }
+
+    Ok(())
+}
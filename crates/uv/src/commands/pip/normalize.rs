@@ -0,0 +1,41 @@
+use std::fmt::Write;
+use std::io::BufRead;
+
+use anyhow::Result;
+
+use uv_pep508::canonicalize_requirement;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the canonical form of each requirement.
+pub(crate) fn pip_normalize_requirements(
+    requirements: Vec<String>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // If no requirements were provided, read them from stdin.
+    let requirements = if requirements.is_empty() {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|line| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with('#')
+            })
+            .collect()
+    } else {
+        requirements
+    };
+
+    for requirement in requirements {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            canonicalize_requirement(requirement.trim())?
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -2,7 +2,7 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, anyhow};
@@ -19,35 +19,42 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    CachedDist, Diagnostic, Dist, InstalledDist, LocalDist, NameRequirementSpecification,
+    RemoteSource, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_install_wheel::LinkMode;
-use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
+use uv_installer::{
+    InstallationStrategy, Plan, Planner, Preparer, ScriptConflict, SitePackages, Store,
+    ValidationMode,
+};
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin};
 use uv_platform_tags::Tags;
 use uv_preview::Preview;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
-use uv_python::{PythonEnvironment, PythonInstallation};
+use uv_python::{ExternallyManaged, PythonEnvironment, PythonInstallation};
 use uv_requirements::{
     GroupsSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
     RequirementsSpecification, SourceTree, SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput,
+    CandidateVeto, DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options,
+    Preference, Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput,
 };
+use uv_static::EnvVars;
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
-use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
-use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode};
-use crate::printer::Printer;
+use crate::commands::reporters::{
+    InstallReporter, PrepareReporter, ResolverReporter, UninstallReporter,
+};
+use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode, human_readable_bytes};
+use crate::printer::{Printer, Styled};
 
 /// Consolidate the requirements for an installation.
 pub(crate) async fn read_requirements(
@@ -103,6 +110,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
     constraints: Vec<NameRequirementSpecification>,
     overrides: Vec<UnresolvedRequirementSpecification>,
+    scoped_overrides: Vec<(PackageName, Requirement)>,
     source_trees: Vec<SourceTree>,
     mut project: Option<PackageName>,
     workspace_members: BTreeSet<PackageName>,
@@ -124,6 +132,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     build_dispatch: &BuildDispatch<'_>,
     concurrency: Concurrency,
     options: Options,
+    candidate_veto: Option<Arc<dyn CandidateVeto>>,
     logger: Box<dyn ResolveLogger>,
     printer: Printer,
 ) -> Result<ResolverOutput, Error> {
@@ -289,7 +298,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             .map(|constraint| constraint.requirement)
             .chain(upgrade.constraints().cloned()),
     );
-    let overrides = Overrides::from_requirements(overrides);
+    let overrides = Overrides::from_requirements(overrides).with_scoped(scoped_overrides);
     let preferences = Preferences::from_iter(preferences, &resolver_env);
 
     // Determine any lookahead requirements.
@@ -351,6 +360,10 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
         )?
         .with_reporter(Arc::new(reporter));
+        let resolver = match candidate_veto {
+            Some(candidate_veto) => resolver.with_candidate_veto(candidate_veto),
+            None => resolver,
+        };
 
         resolver.resolve().await?
     };
@@ -360,7 +373,8 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     Ok(resolution)
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum Modifications {
     /// Use `pip install` semantics, whereby existing installations are left as-is, unless they are
     /// marked for re-installation or upgrade.
@@ -441,6 +455,7 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    store: Option<&Store>,
     compile: bool,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -452,12 +467,20 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    allow_script_conflicts: bool,
+    exclude_source: bool,
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
+    // Sources can only be removed once they've been compiled.
+    if exclude_source && !compile {
+        return Err(anyhow::anyhow!("`--exclude-source` requires `--compile-bytecode`").into());
+    }
+
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
@@ -483,6 +506,8 @@ pub(crate) async fn install(
         return Ok(Changelog::default());
     }
 
+    let download_size = plan.download_size();
+
     let Plan {
         cached,
         remote,
@@ -507,6 +532,15 @@ pub(crate) async fn install(
         return Ok(Changelog::default());
     }
 
+    // Abort if the cache or the environment is unlikely to have enough space for the
+    // distributions to download.
+    check_disk_space(download_size, link_mode, cache, venv)?;
+
+    // On a metered connection, report the size of the distributions to download before fetching.
+    if client.cached_client().uncached().is_metered() {
+        report_download_size(&remote, download_size, printer)?;
+    }
+
     // Partition into two sets: those that require build isolation, and those that disable it. This
     // is effectively a heuristic to make `--no-build-isolation` work "more often" by way of giving
     // `--no-build-isolation` packages "access" to the rest of the environment.
@@ -532,6 +566,7 @@ pub(crate) async fn install(
             resolution,
             build_options,
             link_mode,
+            store,
             hasher,
             tags,
             client,
@@ -542,6 +577,8 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            quarantine,
+            allow_script_conflicts,
             printer,
             preview,
         )
@@ -561,6 +598,7 @@ pub(crate) async fn install(
             resolution,
             build_options,
             link_mode,
+            store,
             hasher,
             tags,
             client,
@@ -571,6 +609,8 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            quarantine,
+            allow_script_conflicts,
             printer,
             preview,
         )
@@ -580,7 +620,7 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, &concurrency, cache, printer).await?;
+        compile_bytecode(venv, exclude_source, &concurrency, cache, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -592,6 +632,102 @@ pub(crate) async fn install(
     Ok(changelog)
 }
 
+/// The assumed ratio between the combined size of a distribution's archive and its unpacked
+/// contents, relative to the size of the archive alone.
+const UNPACKED_SIZE_RATIO: u64 = 3;
+
+/// Abort if the filesystems containing the cache and the environment are unlikely to have enough
+/// free space to download, unpack, and install distributions of the given combined size.
+///
+/// The distributions are unpacked into the cache. When they're copied (rather than linked) into
+/// the environment, the environment requires space for the unpacked contents, too.
+fn check_disk_space(
+    download_size: u64,
+    link_mode: LinkMode,
+    cache: &Cache,
+    venv: &PythonEnvironment,
+) -> Result<(), Error> {
+    if download_size == 0 {
+        return Ok(());
+    }
+    let unpacked_size = download_size.saturating_mul(UNPACKED_SIZE_RATIO - 1);
+    check_available_space(cache.root(), download_size.saturating_add(unpacked_size))?;
+    if matches!(link_mode, LinkMode::Copy) {
+        if let Some(site_packages) = venv.site_packages().next() {
+            check_available_space(&site_packages, unpacked_size)?;
+        }
+    }
+    Ok(())
+}
+
+/// Abort if the filesystem containing the given path has less than `required` bytes available.
+fn check_available_space(path: &Path, required: u64) -> Result<(), Error> {
+    let available = match available_space(path) {
+        Ok(available) => available,
+        Err(err) => {
+            debug!(
+                "Failed to determine the available disk space at `{}`: {err}",
+                path.user_display()
+            );
+            return Ok(());
+        }
+    };
+    debug!(
+        "Estimated {required} bytes required at `{}` ({available} bytes available)",
+        path.user_display()
+    );
+    if available < required {
+        return Err(Error::InsufficientDiskSpace {
+            path: path.to_path_buf(),
+            required,
+            available,
+        });
+    }
+    Ok(())
+}
+
+/// Return the space available on the filesystem containing the given path, in bytes.
+///
+/// In tests, the available space can be overridden with `UV_INTERNAL__TEST_AVAILABLE_SPACE`.
+fn available_space(path: &Path) -> std::io::Result<u64> {
+    if let Some(available) = std::env::var(EnvVars::UV_INTERNAL__TEST_AVAILABLE_SPACE)
+        .ok()
+        .and_then(|available| available.parse().ok())
+    {
+        return Ok(available);
+    }
+    uv_fs::available_space(path)
+}
+
+/// Report the number and combined size of the distributions to download.
+fn report_download_size(
+    remote: &[Arc<Dist>],
+    download_size: u64,
+    printer: Printer,
+) -> std::fmt::Result {
+    if remote.is_empty() {
+        return Ok(());
+    }
+    let s = if remote.len() == 1 { "" } else { "s" };
+    let (size, unit) = human_readable_bytes(download_size);
+    let unknown = remote.iter().filter(|dist| dist.size().is_none()).count();
+    if unknown == 0 {
+        writeln!(
+            printer.stderr(),
+            "Downloading {} ({})",
+            format!("{} package{s}", remote.len()).bold(),
+            format!("{size:.1}{unit}").accent(),
+        )
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Downloading {} ({}, plus {unknown} of unknown size)",
+            format!("{} package{s}", remote.len()).bold(),
+            format!("{size:.1}{unit}").accent(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum InstallPhase {
     /// A dedicated phase for building and installing packages with build-isolation disabled.
@@ -613,6 +749,7 @@ async fn execute_plan(
     resolution: &Resolution,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    store: Option<&Store>,
     hasher: &HashStrategy,
     tags: &Tags,
     client: &RegistryClient,
@@ -623,6 +760,8 @@ async fn execute_plan(
     venv: &PythonEnvironment,
     logger: &dyn InstallLogger,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    allow_script_conflicts: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<(Vec<CachedDist>, Vec<InstalledDist>), Error> {
@@ -659,13 +798,40 @@ async fn execute_plan(
         wheels
     };
 
+    let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+
+    // Detect any scripts that would be overwritten by another package.
+    if !installs.is_empty() {
+        let site_packages = SitePackages::from_environment(venv)?;
+        let conflicts = uv_installer::find_script_conflicts(
+            &site_packages,
+            &installs,
+            extraneous.iter().chain(&reinstalls),
+        )
+        .context("Failed to read entry points")?;
+        if !conflicts.is_empty() {
+            if !allow_script_conflicts {
+                return Err(Error::ScriptConflicts(conflicts));
+            }
+            for conflict in &conflicts {
+                warn_user!("Multiple packages provide the script {conflict}");
+            }
+        }
+    }
+
     // Remove any upgraded or extraneous installations.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
     if !uninstalls.is_empty() {
         let start = std::time::Instant::now();
 
-        for dist_info in &uninstalls {
-            match uv_installer::uninstall(dist_info).await {
+        let results = uv_installer::Uninstaller::default()
+            .with_reporter(Arc::new(
+                UninstallReporter::from(printer).with_length(uninstalls.len() as u64),
+            ))
+            .uninstall(uninstalls.clone())
+            .await?;
+        for (dist_info, result) in uninstalls.iter().zip(results) {
+            match result {
                 Ok(summary) => {
                     debug!(
                         "Uninstalled {} ({} file{}, {} director{})",
@@ -681,7 +847,7 @@ async fn execute_plan(
                 )) => {
                     warn_user!(
                         "Failed to uninstall package at {} due to missing `RECORD` file. Installation may result in an incomplete environment.",
-                        dist_info.install_path().user_display().cyan(),
+                        dist_info.install_path().user_display().accent(),
                     );
                 }
                 Err(uv_installer::UninstallError::Uninstall(
@@ -689,7 +855,7 @@ async fn execute_plan(
                 )) => {
                     warn_user!(
                         "Failed to uninstall package at {} due to missing `top-level.txt` file. Installation may result in an incomplete environment.",
-                        dist_info.install_path().user_display().cyan(),
+                        dist_info.install_path().user_display().accent(),
                     );
                 }
                 Err(err) => return Err(err.into()),
@@ -700,13 +866,15 @@ async fn execute_plan(
     }
 
     // Install the resolved distributions.
-    let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !installs.is_empty() {
         let start = std::time::Instant::now();
         installs = uv_installer::Installer::new(venv, preview)
             .with_link_mode(link_mode)
+            .with_quarantine(quarantine)
             .with_cache(cache)
+            .with_store(store)
             .with_installer_metadata(installer_metadata)
+            .with_requested(resolution.requested())
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
@@ -763,7 +931,7 @@ pub(crate) fn report_interpreter(
                 printer.stderr(),
                 "Using {} {}",
                 implementation.pretty(),
-                interpreter.python_version().cyan()
+                interpreter.python_version().accent()
             )?;
         } else {
             writeln!(
@@ -771,7 +939,7 @@ pub(crate) fn report_interpreter(
                 "Using {} {} interpreter at: {}",
                 implementation.pretty(),
                 interpreter.python_version(),
-                interpreter.sys_executable().user_display().cyan()
+                interpreter.sys_executable().user_display().accent()
             )?;
         }
     }
@@ -923,7 +1091,7 @@ fn report_dry_run(
                 writeln!(
                     printer.stderr(),
                     " {} {}{}",
-                    "+".green(),
+                    "+".success(),
                     event.name.bold(),
                     event.version.dimmed()
                 )?;
@@ -932,7 +1100,7 @@ fn report_dry_run(
                 writeln!(
                     printer.stderr(),
                     " {} {}{}",
-                    "-".red(),
+                    "-".error(),
                     event.name.bold(),
                     event.version.dimmed()
                 )?;
@@ -941,7 +1109,7 @@ fn report_dry_run(
                 writeln!(
                     printer.stderr(),
                     " {} {}{}",
-                    "~".yellow(),
+                    "~".warning(),
                     event.name.bold(),
                     event.version.dimmed()
                 )?;
@@ -966,7 +1134,7 @@ pub(crate) fn diagnose_resolution(
         writeln!(
             printer.stderr(),
             "{}{} {}",
-            "warning".yellow().bold(),
+            "warning".warning().bold(),
             ":".bold(),
             diagnostic.message().bold()
         )?;
@@ -993,7 +1161,7 @@ pub(crate) fn diagnose_environment(
             writeln!(
                 printer.stderr(),
                 "{}{} {}",
-                "warning".yellow().bold(),
+                "warning".warning().bold(),
                 ":".bold(),
                 diagnostic.message().bold()
             )?;
@@ -1002,6 +1170,29 @@ pub(crate) fn diagnose_environment(
     Ok(())
 }
 
+/// Validate the Python environment for a command's `--strict` mode, reporting any diagnostics as
+/// warnings.
+#[allow(clippy::result_large_err)]
+pub(crate) fn report_strict_diagnostics(
+    site_packages: &SitePackages,
+    markers: &ResolverMarkerEnvironment,
+    tags: &Tags,
+    printer: Printer,
+) -> Result<(), Error> {
+    for diagnostic in
+        uv_installer::validate_environment(site_packages, markers, tags, ValidationMode::Strict)?
+    {
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "warning".warning().bold(),
+            ":".bold(),
+            diagnostic.message().bold()
+        )?;
+    }
+    Ok(())
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum Error {
     #[error("Failed to prepare distributions")]
@@ -1028,6 +1219,103 @@ pub(crate) enum Error {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 
-    #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
+    #[error("The environment is outdated; run `{}` to update the environment", "uv sync".accent())]
     OutdatedEnvironment,
+
+    #[error("{}", format_script_conflicts(.0))]
+    ScriptConflicts(Vec<ScriptConflict>),
+
+    #[error("{}", format_externally_managed(.root, .externally_managed))]
+    ExternallyManaged {
+        root: PathBuf,
+        externally_managed: ExternallyManaged,
+    },
+
+    #[error("{}", format_insufficient_disk_space(.path, *.required, *.available))]
+    InsufficientDiskSpace {
+        path: PathBuf,
+        required: u64,
+        available: u64,
+    },
+}
+
+/// Format the error for a filesystem that lacks the space required for an installation.
+fn format_insufficient_disk_space(path: &Path, required: u64, available: u64) -> String {
+    let (required, required_unit) = human_readable_bytes(required);
+    let (available, available_unit) = human_readable_bytes(available);
+    format!(
+        "Installing these packages may require up to {required:.1}{required_unit} of disk space in `{}`, but only {available:.1}{available_unit} is available",
+        path.user_display()
+    )
+}
+
+/// Abort if the environment is externally managed (per PEP 668), unless the user opted in to
+/// modifying it via `--break-system-packages` (or the equivalent setting).
+///
+/// Shared by `uv pip install`, `uv pip sync`, and `uv pip uninstall`, such that the policy is
+/// enforced identically across all commands that mutate an environment.
+pub(crate) fn check_externally_managed(
+    environment: &PythonEnvironment,
+    break_system_packages: bool,
+) -> Result<(), Error> {
+    let Some(externally_managed) = environment.interpreter().is_externally_managed() else {
+        return Ok(());
+    };
+
+    if break_system_packages {
+        debug!(
+            "Ignoring externally managed environment (as marked by `{}`) due to `--break-system-packages`",
+            externally_managed.path().user_display()
+        );
+        return Ok(());
+    }
+
+    Err(Error::ExternallyManaged {
+        root: environment.root().to_path_buf(),
+        externally_managed,
+    })
+}
+
+/// Format the error message for an [`ExternallyManaged`] environment.
+fn format_externally_managed(root: &Path, externally_managed: &ExternallyManaged) -> String {
+    let path = externally_managed.path().user_display().to_string();
+    let hint = format!(
+        "{}{} The policy is defined by `{}`. To modify the environment anyway, pass `{}`, or set `{}` in the `{}` section of a system-level `uv.toml` (e.g., `/etc/uv/uv.toml`)",
+        "hint".bold().accent(),
+        ":".bold(),
+        path.accent(),
+        "--break-system-packages".success(),
+        "break-system-packages = true".success(),
+        "[pip]".success(),
+    );
+    if let Some(error) = externally_managed.error() {
+        format!(
+            "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `{}`.\n\n{hint}",
+            root.user_display().accent(),
+            textwrap::indent(error, "  ").success(),
+            "uv venv".success(),
+        )
+    } else {
+        format!(
+            "The interpreter at {} is externally managed. Instead, create a virtual environment with `{}`.\n\n{hint}",
+            root.user_display().accent(),
+            "uv venv".success(),
+        )
+    }
+}
+
+/// Format the error message for a set of [`ScriptConflict`]s.
+fn format_script_conflicts(conflicts: &[ScriptConflict]) -> String {
+    format!(
+        "Multiple packages provide the same {}: {}\n\n{}{} Use `{}` to install anyway",
+        if conflicts.len() == 1 {
+            "script"
+        } else {
+            "scripts"
+        },
+        conflicts.iter().join(", "),
+        "hint".bold().accent(),
+        ":".bold(),
+        "--allow-script-conflicts".success(),
+    )
 }
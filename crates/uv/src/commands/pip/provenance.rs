@@ -0,0 +1,244 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::Result;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+use sha2::{Digest, Sha256};
+
+use uv_distribution_types::{IndexLocations, RequiresPython};
+use uv_fs::Simplified;
+use uv_requirements::RequirementsSource;
+use uv_resolver::ExcludeNewer;
+
+/// The line that opens the provenance block in the header of a `uv pip compile` output file.
+const PROVENANCE_HEADER: &str = "# Provenance (verify with `uv pip compile --verify`):";
+
+/// The prefix of each entry in the provenance block.
+const PROVENANCE_PREFIX: &str = "#    ";
+
+/// The inputs to a `uv pip compile` invocation, as recorded in the header of the output file.
+///
+/// Each entry is a `key: value` pair; keys may be repeated (e.g., for multiple input files).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Provenance {
+    entries: Vec<(String, String)>,
+}
+
+/// A difference between the provenance recorded in an output file and the current inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProvenanceDifference {
+    key: String,
+    recorded: Vec<String>,
+    current: Vec<String>,
+}
+
+impl ProvenanceDifference {
+    /// Returns `true` if the difference only concerns the uv version, which is not expected to
+    /// invalidate the output on its own.
+    pub(crate) fn is_version(&self) -> bool {
+        self.key == "uv-version"
+    }
+}
+
+impl Provenance {
+    /// Record the provenance of a resolution.
+    pub(crate) fn new(
+        sources: &[&RequirementsSource],
+        python: &RequiresPython,
+        exclude_newer: &ExcludeNewer,
+        index_locations: &IndexLocations,
+    ) -> Result<Self> {
+        let mut entries = vec![
+            ("uv-version".to_string(), uv_version::version().to_string()),
+            ("python".to_string(), python.to_string()),
+        ];
+
+        if !exclude_newer.is_empty() {
+            entries.push(("exclude-newer".to_string(), exclude_newer.to_string()));
+        }
+
+        if let Some(index) = index_locations.default_index() {
+            entries.push((
+                "index-url".to_string(),
+                index.url().without_credentials().to_string(),
+            ));
+        }
+        let mut seen = FxHashSet::default();
+        for index in index_locations.implicit_indexes() {
+            if seen.insert(index.url()) {
+                entries.push((
+                    "extra-index-url".to_string(),
+                    index.url().without_credentials().to_string(),
+                ));
+            }
+        }
+        for index in index_locations.flat_indexes() {
+            entries.push((
+                "find-links".to_string(),
+                index.url().without_credentials().to_string(),
+            ));
+        }
+
+        for source in sources {
+            let path = match source {
+                RequirementsSource::RequirementsTxt(path)
+                | RequirementsSource::PyprojectToml(path)
+                | RequirementsSource::SetupPy(path)
+                | RequirementsSource::SetupCfg(path)
+                | RequirementsSource::EnvironmentYml(path)
+                | RequirementsSource::PylockToml(path)
+                | RequirementsSource::Pep723Script(path) => path,
+                RequirementsSource::Package(_) | RequirementsSource::Editable(_) => continue,
+            };
+
+            // Skip `stdin`, which can't be hashed after the fact.
+            if path == std::path::Path::new("-") {
+                continue;
+            }
+
+            let digest = Sha256::digest(fs_err::read(path)?);
+            entries.push((
+                "input".to_string(),
+                format!("{} (sha256:{digest:x})", path.user_display()),
+            ));
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Parse the provenance block from the contents of an output file, if present.
+    pub(crate) fn parse(contents: &str) -> Option<Self> {
+        let mut lines = contents
+            .lines()
+            .skip_while(|line| line.trim_end() != PROVENANCE_HEADER);
+        lines.next()?;
+
+        let entries = lines
+            .map_while(|line| line.strip_prefix(PROVENANCE_PREFIX))
+            .filter_map(|line| line.split_once(": "))
+            .map(|(key, value)| (key.to_string(), value.trim_end().to_string()))
+            .collect();
+
+        Some(Self { entries })
+    }
+
+    /// Compare the recorded provenance against the current provenance, returning the entries
+    /// that have changed.
+    pub(crate) fn diff(&self, current: &Self) -> Vec<ProvenanceDifference> {
+        self.keys()
+            .chain(current.keys())
+            .unique()
+            .filter_map(|key| {
+                let recorded = self.values(key);
+                let current = current.values(key);
+                (recorded != current).then(|| ProvenanceDifference {
+                    key: key.to_string(),
+                    recorded,
+                    current,
+                })
+            })
+            .collect()
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(key, _)| key.as_str())
+    }
+
+    fn values(&self, key: &str) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|(entry, _)| entry == key)
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+impl Display for Provenance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{PROVENANCE_HEADER}")?;
+        for (key, value) in &self.entries {
+            writeln!(f, "{PROVENANCE_PREFIX}{key}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ProvenanceDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let format = |values: &[String]| {
+            if values.is_empty() {
+                "(none)".to_string()
+            } else {
+                values.iter().map(|value| format!("`{value}`")).join(", ")
+            }
+        };
+        write!(
+            f,
+            "`{}` changed from {} to {}",
+            self.key,
+            format(&self.recorded),
+            format(&self.current)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Provenance;
+
+    #[test]
+    fn round_trip() {
+        let provenance = Provenance {
+            entries: vec![
+                ("uv-version".to_string(), "0.1.0".to_string()),
+                ("python".to_string(), ">=3.12".to_string()),
+                (
+                    "input".to_string(),
+                    "requirements.in (sha256:abc)".to_string(),
+                ),
+            ],
+        };
+        let contents = format!(
+            "# This file was autogenerated by uv via the following command:\n#    uv pip compile requirements.in\n{provenance}iniconfig==2.0.0\n"
+        );
+        assert_eq!(Provenance::parse(&contents), Some(provenance.clone()));
+        assert!(provenance.diff(&provenance).is_empty());
+    }
+
+    #[test]
+    fn diff() {
+        let recorded = Provenance {
+            entries: vec![
+                ("python".to_string(), ">=3.12".to_string()),
+                ("input".to_string(), "a.in (sha256:abc)".to_string()),
+            ],
+        };
+        let current = Provenance {
+            entries: vec![
+                ("python".to_string(), ">=3.12".to_string()),
+                ("input".to_string(), "a.in (sha256:def)".to_string()),
+                (
+                    "exclude-newer".to_string(),
+                    "global: 2024-01-01".to_string(),
+                ),
+            ],
+        };
+        let differences = recorded
+            .diff(&current)
+            .into_iter()
+            .map(|difference| difference.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            differences,
+            vec![
+                "`input` changed from `a.in (sha256:abc)` to `a.in (sha256:def)`",
+                "`exclude-newer` changed from (none) to `global: 2024-01-01`",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing() {
+        assert_eq!(Provenance::parse("iniconfig==2.0.0\n"), None);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
@@ -7,17 +8,17 @@ use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use uv_cache::Cache;
-use uv_distribution_types::{Diagnostic, Name};
+use uv_distribution_types::Name;
 use uv_fs::Simplified;
-use uv_install_wheel::read_record_file;
+use uv_install_wheel::{hash_file, read_installed_scripts, read_record_file};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
 
 use crate::commands::ExitStatus;
-use crate::commands::pip::operations::report_target_environment;
-use crate::printer::Printer;
+use crate::commands::pip::operations::{report_strict_diagnostics, report_target_environment};
+use crate::printer::{Printer, Styled};
 
 /// Show information about one or more installed packages.
 pub(crate) fn pip_show(
@@ -26,6 +27,7 @@ pub(crate) fn pip_show(
     python: Option<&str>,
     system: bool,
     files: bool,
+    scripts: bool,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -36,7 +38,7 @@ pub(crate) fn pip_show(
             writeln!(
                 printer.stderr(),
                 "{}{} Please provide a package name or names.",
-                "warning".yellow().bold(),
+                "warning".warning().bold(),
                 ":".bold(),
             )?;
         }
@@ -57,6 +59,13 @@ pub(crate) fn pip_show(
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // Determine the owner of each script, if requested.
+    let owners = if scripts {
+        script_owners(&site_packages)?
+    } else {
+        FxHashMap::default()
+    };
+
     // Determine the markers and tags to use for resolution.
     let markers = environment.interpreter().resolver_marker_environment();
     let tags = environment.interpreter().tags()?;
@@ -79,7 +88,7 @@ pub(crate) fn pip_show(
         writeln!(
             printer.stderr(),
             "{}{} Package(s) not found for: {}",
-            "warning".yellow().bold(),
+            "warning".warning().bold(),
             ":".bold(),
             missing.iter().join(", ").bold()
         )?;
@@ -202,20 +211,106 @@ pub(crate) fn pip_show(
                 writeln!(printer.stdout(), "  {}", entry.path)?;
             }
         }
+
+        // If requested, show the list of provided scripts.
+        if scripts {
+            let python_minor = environment.interpreter().python_minor();
+            writeln!(printer.stdout(), "Scripts:")?;
+            for script in read_installed_scripts(distribution.install_path(), python_minor)?
+                .into_iter()
+                .sorted_unstable()
+            {
+                match owners.get(&script) {
+                    Some(owner) if owner != distribution.name() => {
+                        writeln!(printer.stdout(), "  {script} (owned by {owner})")?;
+                    }
+                    _ => {
+                        writeln!(printer.stdout(), "  {script}")?;
+                    }
+                }
+            }
+        }
     }
 
     // Validate that the environment is consistent.
     if strict {
-        for diagnostic in site_packages.diagnostics(&markers, tags)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
-        }
+        report_strict_diagnostics(&site_packages, &markers, tags, printer)?;
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Determine the package that owns each script in the environment.
+///
+/// If multiple packages provide the same script, the package whose `RECORD` entry matches the
+/// installed script owns it, since its script overwrote those of the other packages.
+fn script_owners(site_packages: &SitePackages) -> Result<FxHashMap<String, PackageName>> {
+    let interpreter = site_packages.interpreter();
+    let python_minor = interpreter.python_minor();
+
+    // Collect the packages that provide each script.
+    let mut providers: FxHashMap<String, Vec<_>> = FxHashMap::default();
+    for distribution in site_packages.iter() {
+        for script in read_installed_scripts(distribution.install_path(), python_minor)? {
+            providers.entry(script).or_default().push(distribution);
+        }
+    }
+
+    let mut owners = FxHashMap::default();
+    for (script, distributions) in providers {
+        // A script that's provided by a single package is owned by that package.
+        if let [distribution] = distributions.as_slice() {
+            owners.insert(script, distribution.name().clone());
+            continue;
+        }
+
+        for distribution in distributions {
+            let Ok(mut record) = File::open(distribution.install_path().join("RECORD")) else {
+                continue;
+            };
+            let Some(site_packages_dir) = distribution.install_path().parent() else {
+                continue;
+            };
+            let owned = read_record_file(&mut record)?.into_iter().any(|entry| {
+                let Some(expected) = entry
+                    .hash
+                    .as_deref()
+                    .filter(|hash| hash.starts_with("sha256="))
+                else {
+                    return false;
+                };
+                let path = uv_fs::normalize_path_buf(site_packages_dir.join(&entry.path));
+                is_script_path(&path, interpreter.scripts(), &script)
+                    && hash_file(&path).is_ok_and(|computed| computed == expected)
+            });
+            if owned {
+                owners.insert(script, distribution.name().clone());
+                break;
+            }
+        }
+    }
+    Ok(owners)
+}
+
+/// Returns `true` if the path (as listed in a `RECORD` file) is the installed script with the
+/// given name.
+///
+/// On Windows, paths are compared case-insensitively, and scripts are installed as `.exe`
+/// launchers.
+fn is_script_path(path: &Path, scripts: &Path, script: &str) -> bool {
+    let (Some(parent), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return false;
+    };
+    let file_name = file_name.to_string_lossy();
+    if cfg!(windows) {
+        let file_name = file_name.to_ascii_lowercase();
+        let stem = file_name.strip_suffix(".exe").unwrap_or(&file_name);
+        stem == script.to_ascii_lowercase()
+            && parent.to_string_lossy().to_ascii_lowercase()
+                == uv_fs::normalize_path(scripts)
+                    .to_string_lossy()
+                    .to_ascii_lowercase()
+    } else {
+        file_name == script && *parent == *uv_fs::normalize_path(scripts)
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
-use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
@@ -18,7 +18,7 @@ use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Origin,
     PackageConfigSettings, Resolution,
 };
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups};
@@ -42,7 +42,9 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    check_platform_override, operations, resolution_markers, resolution_tags,
+};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 
@@ -66,6 +68,8 @@ pub(crate) async fn pip_sync(
     client_builder: &BaseClientBuilder<'_>,
     allow_empty_requirements: bool,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     build_isolation: BuildIsolation,
@@ -74,6 +78,7 @@ pub(crate) async fn pip_sync(
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_override: bool,
     strict: bool,
     exclude_newer: ExcludeNewer,
     python: Option<String>,
@@ -86,6 +91,8 @@ pub(crate) async fn pip_sync(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    allow_script_conflicts: bool,
+    exclude_source: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -113,6 +120,7 @@ pub(crate) async fn pip_sync(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         pylock,
         source_trees,
         groups,
@@ -206,32 +214,16 @@ pub(crate) async fn pip_sync(
     };
 
     // If the environment is externally managed, abort.
-    if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan()
-                ))
-            };
-        }
-    }
+    operations::check_externally_managed(&environment, break_system_packages)?;
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match environment.lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
 
     let interpreter = environment.interpreter();
 
@@ -461,6 +453,7 @@ pub(crate) async fn pip_sync(
             requirements,
             constraints,
             overrides,
+            scoped_overrides,
             source_trees,
             project,
             BTreeSet::default(),
@@ -482,6 +475,7 @@ pub(crate) async fn pip_sync(
             &build_dispatch,
             concurrency,
             options,
+            None,
             Box::new(DefaultResolveLogger),
             printer,
         )
@@ -493,7 +487,7 @@ pub(crate) async fn pip_sync(
                     client_builder.is_native_tls(),
                 )
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
             }
         };
 
@@ -503,6 +497,11 @@ pub(crate) async fn pip_sync(
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
+    // If the platform is spoofed, verify that the wheels can be installed natively.
+    if platform_override {
+        check_platform_override(&resolution, interpreter)?;
+    }
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -538,6 +537,7 @@ pub(crate) async fn pip_sync(
         &reinstall,
         &build_options,
         link_mode,
+        None,
         compile,
         &hasher,
         &tags,
@@ -549,6 +549,9 @@ pub(crate) async fn pip_sync(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        allow_script_conflicts,
+        exclude_source,
         dry_run,
         printer,
         preview,
@@ -559,7 +562,7 @@ pub(crate) async fn pip_sync(
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
     }
 
@@ -8,13 +8,15 @@ use petgraph::Direction;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use tokio::sync::Semaphore;
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
-use uv_distribution_types::{Diagnostic, IndexCapabilities, IndexLocations, Name, RequiresPython};
+use uv_distribution_types::{IndexCapabilities, IndexLocations, Name, RequiresPython};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -26,14 +28,15 @@ use uv_resolver::{ExcludeNewer, PrereleaseMode};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::latest::LatestClient;
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{report_strict_diagnostics, report_target_environment};
 use crate::commands::reporters::LatestVersionReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Display the installed packages in the current environment as a dependency tree.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_tree(
     show_version_specifiers: bool,
+    format: TreeFormat,
     depth: u8,
     prune: &[PackageName],
     package: &[PackageName],
@@ -148,7 +151,7 @@ pub(crate) async fn pip_tree(
     };
 
     // Render the tree.
-    let rendered_tree = DisplayDependencyGraph::new(
+    let graph = DisplayDependencyGraph::new(
         depth.into(),
         prune,
         package,
@@ -158,32 +161,35 @@ pub(crate) async fn pip_tree(
         &markers,
         &packages,
         &latest,
-    )
-    .render()
-    .join("\n");
+    );
 
-    writeln!(printer.stdout(), "{rendered_tree}")?;
+    match format {
+        TreeFormat::Text => {
+            let rendered_tree = graph.render().join("\n");
 
-    if rendered_tree.contains("(*)") {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
+            writeln!(printer.stdout(), "{rendered_tree}")?;
+
+            if rendered_tree.contains("(*)") {
+                let message = if no_dedupe {
+                    "(*) Package tree is a cycle and cannot be shown".italic()
+                } else {
+                    "(*) Package tree already displayed".italic()
+                };
+                writeln!(printer.stdout(), "{message}")?;
+            }
+        }
+        TreeFormat::Json => {
+            let output = serde_json::to_string(&graph.render_json())?;
+            writeln!(printer.stdout(), "{output}")?;
+        }
+        TreeFormat::Dot => {
+            write!(printer.stdout(), "{}", graph.render_dot())?;
+        }
     }
 
     // Validate that the environment is consistent.
     if strict {
-        for diagnostic in site_packages.diagnostics(&markers, tags)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
-        }
+        report_strict_diagnostics(&site_packages, &markers, tags, printer)?;
     }
 
     Ok(ExitStatus::Success)
@@ -421,7 +427,7 @@ impl<'env> DisplayDependencyGraph<'env> {
             .get(package_name)
             .filter(|&version| *version > metadata.version)
         {
-            format!("{line} {}", format!("(latest: v{version})").bold().cyan())
+            format!("{line} {}", format!("(latest: v{version})").bold().accent())
         } else {
             line
         };
@@ -510,6 +516,158 @@ impl<'env> DisplayDependencyGraph<'env> {
 
         lines
     }
+
+    /// Perform a depth-first traversal of the given distribution and its dependencies, producing
+    /// a [`TreeEntry`].
+    ///
+    /// Mirrors [`DisplayDependencyGraph::visit`], with repeated packages included without their
+    /// dependencies.
+    fn visit_json(
+        &self,
+        cursor: Cursor,
+        visited: &mut FxHashSet<&'env PackageName>,
+        path: &mut Vec<&'env PackageName>,
+    ) -> Option<TreeEntry<'env>> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return None;
+        }
+
+        let metadata = self.graph[cursor.node()];
+        let package_name = &metadata.name;
+
+        let mut entry = TreeEntry {
+            name: package_name,
+            version: &metadata.version,
+            required_version: cursor.edge().map(|edge| {
+                self.graph[edge]
+                    .version_or_url
+                    .as_ref()
+                    .map_or_else(|| String::from("*"), ToString::to_string)
+            }),
+            latest_version: self
+                .latest
+                .get(package_name)
+                .filter(|&version| *version > metadata.version),
+            deduplicated: false,
+            dependencies: Vec::new(),
+        };
+
+        let mut dependencies = self
+            .graph
+            .edges_directed(cursor.node(), Direction::Outgoing)
+            .map(|edge| Cursor::new(edge.target(), edge.id()))
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|node| {
+            let metadata = &self.graph[node.node()];
+            (&metadata.name, &metadata.version)
+        });
+
+        // Skip the traversal if the package is part of a cycle, or has already been visited and
+        // de-duplication is enabled (default).
+        if visited.contains(package_name) && (!self.no_dedupe || path.contains(&package_name)) {
+            entry.deduplicated = !dependencies.is_empty();
+            return Some(entry);
+        }
+
+        visited.insert(package_name);
+        path.push(package_name);
+        entry.dependencies = dependencies
+            .into_iter()
+            .filter_map(|dep| self.visit_json(dep, visited, path))
+            .collect();
+        path.pop();
+
+        Some(entry)
+    }
+
+    /// Depth-first traverse the nodes to render the tree as a list of [`TreeEntry`] values.
+    pub(crate) fn render_json(&self) -> Vec<TreeEntry<'env>> {
+        let mut path = Vec::new();
+        let mut visited = FxHashSet::default();
+
+        self.roots
+            .iter()
+            .filter_map(|node| {
+                path.clear();
+                self.visit_json(Cursor::root(*node), &mut visited, &mut path)
+            })
+            .collect()
+    }
+
+    /// Render the dependency graph in the Graphviz DOT format.
+    ///
+    /// Each edge is labeled with the version constraint that the source imposes on the target
+    /// (or, if inverted, that the target imposes on the source).
+    pub(crate) fn render_dot(&self) -> String {
+        /// Return the DOT identifier for a package.
+        fn id(metadata: &ResolutionMetadata) -> String {
+            format!("\"{}=={}\"", metadata.name, metadata.version)
+        }
+
+        let mut nodes = self.graph.node_indices().collect::<Vec<_>>();
+        nodes.sort_by_key(|index| {
+            let metadata = &self.graph[*index];
+            (&metadata.name, &metadata.version)
+        });
+
+        let mut edges = self
+            .graph
+            .edge_references()
+            .map(|edge| {
+                let label = edge
+                    .weight()
+                    .version_or_url
+                    .as_ref()
+                    .map_or_else(|| String::from("*"), ToString::to_string);
+                (
+                    id(self.graph[edge.source()]),
+                    id(self.graph[edge.target()]),
+                    label.replace('"', "\\\""),
+                )
+            })
+            .collect::<Vec<_>>();
+        edges.sort();
+
+        let mut output = String::from("digraph {\n");
+        for index in nodes {
+            let metadata = self.graph[index];
+            let _ = writeln!(
+                output,
+                "    {} [label=\"{}\\nv{}\"]",
+                id(metadata),
+                metadata.name,
+                metadata.version
+            );
+        }
+        for (source, target, label) in edges {
+            let _ = writeln!(output, "    {source} -> {target} [label=\"{label}\"]");
+        }
+        output.push_str("}\n");
+        output
+    }
+}
+
+/// A package in the JSON representation of the dependency tree.
+#[derive(Debug, Serialize)]
+pub(crate) struct TreeEntry<'env> {
+    /// The name of the package.
+    name: &'env PackageName,
+    /// The installed version of the package.
+    version: &'env Version,
+    /// The version constraint imposed on the package by its parent, or, if inverted, the
+    /// constraint that the package imposes on its parent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_version: Option<String>,
+    /// The latest available version of the package, if newer than the installed version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_version: Option<&'env Version>,
+    /// Whether the dependencies of the package were omitted, as they're displayed elsewhere in
+    /// the tree.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deduplicated: bool,
+    /// The dependencies of the package.
+    dependencies: Vec<TreeEntry<'env>>,
 }
 
 /// A node in the dependency graph along with the edge that led to it, or `None` for root nodes.
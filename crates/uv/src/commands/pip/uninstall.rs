@@ -1,4 +1,6 @@
 use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
@@ -19,9 +21,10 @@ use uv_python::{EnvironmentPreference, PythonPreference};
 use uv_python::{Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{self, report_target_environment};
+use crate::commands::reporters::UninstallReporter;
 use crate::commands::{ExitStatus, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Uninstall packages from the current environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -36,6 +39,7 @@ pub(crate) async fn pip_uninstall(
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     dry_run: DryRun,
+    locked_timeout: Option<Duration>,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -78,32 +82,16 @@ pub(crate) async fn pip_uninstall(
     };
 
     // If the environment is externally managed, abort.
-    if let Some(externally_managed) = environment.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    environment.root().user_display().cyan()
-                ))
-            };
-        }
-    }
+    operations::check_externally_managed(&environment, break_system_packages)?;
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match environment.lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
 
     // Index the current `site-packages` directory.
     let site_packages = uv_installer::SitePackages::from_environment(&environment)?;
@@ -152,7 +140,7 @@ pub(crate) async fn pip_uninstall(
                     writeln!(
                         printer.stderr(),
                         "{}{} Skipping {} as it is not installed",
-                        "warning".yellow().bold(),
+                        "warning".warning().bold(),
                         ":".bold(),
                         package.as_ref().bold()
                     )?;
@@ -170,7 +158,7 @@ pub(crate) async fn pip_uninstall(
                     writeln!(
                         printer.stderr(),
                         "{}{} Skipping {} as it is not installed",
-                        "warning".yellow().bold(),
+                        "warning".warning().bold(),
                         ":".bold(),
                         url.as_ref().bold()
                     )?;
@@ -193,7 +181,7 @@ pub(crate) async fn pip_uninstall(
             writeln!(
                 printer.stderr(),
                 "{}{} No packages to uninstall",
-                "warning".yellow().bold(),
+                "warning".warning().bold(),
                 ":".bold(),
             )?;
         }
@@ -202,8 +190,14 @@ pub(crate) async fn pip_uninstall(
 
     // Uninstall each package.
     if !dry_run.enabled() {
-        for distribution in &distributions {
-            let summary = uv_installer::uninstall(distribution).await?;
+        let results = uv_installer::Uninstaller::default()
+            .with_reporter(Arc::new(
+                UninstallReporter::from(printer).with_length(distributions.len() as u64),
+            ))
+            .uninstall(distributions.iter().copied().cloned().collect())
+            .await?;
+        for (distribution, result) in distributions.iter().zip(results) {
+            let summary = result?;
             debug!(
                 "Uninstalled {} ({} file{}, {} director{})",
                 distribution.name(),
@@ -244,7 +238,7 @@ pub(crate) async fn pip_uninstall(
         writeln!(
             printer.stderr(),
             " {} {}{}",
-            "-".red(),
+            "-".error(),
             distribution.name().as_ref().bold(),
             distribution.installed_version().to_string().dimmed()
         )?;
@@ -0,0 +1,422 @@
+use std::collections::hash_map::Entry;
+use std::path::Path;
+use std::process::{ExitStatus, Stdio};
+use std::sync::Arc;
+
+use anyhow::{Context, Result, anyhow, bail};
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+use uv_distribution_types::{IndexUrl, Name, Resolution};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_resolver::{CandidateVeto, CandidateVetoError, Lock, VetoCandidate};
+use uv_settings::{Plugin, PluginEvent, Plugins};
+
+/// A package included in a plugin event.
+#[derive(Debug, Serialize)]
+pub(crate) struct PluginPackage {
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<Version>,
+    /// The index from which the package was resolved, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+}
+
+impl PluginPackage {
+    /// Collect the packages in a [`Resolution`].
+    pub(crate) fn from_resolution(resolution: &Resolution) -> Vec<Self> {
+        resolution
+            .distributions()
+            .map(|dist| Self {
+                name: dist.name().clone(),
+                version: dist.version().cloned(),
+                index: dist
+                    .index()
+                    .map(|index| index.without_credentials().to_string()),
+            })
+            .collect()
+    }
+
+    /// Collect the packages in a [`Lock`], resolving any relative index paths against the
+    /// workspace root.
+    pub(crate) fn from_lock(lock: &Lock, root: &Path) -> Result<Vec<Self>> {
+        lock.packages()
+            .iter()
+            .map(|package| {
+                Ok(Self {
+                    name: package.name().clone(),
+                    version: package.version().cloned(),
+                    index: package
+                        .index(root)?
+                        .map(|index| index.without_credentials().to_string()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// The JSON payload written to a plugin's standard input.
+#[derive(Debug, Serialize)]
+struct Payload<'a> {
+    /// The event that triggered the plugin.
+    event: PluginEvent,
+    /// The uv command that emitted the event (e.g., `lock`).
+    command: &'a str,
+    /// The root of the environment that's about to be modified, for `install` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<&'a Path>,
+    /// The packages affected by the event.
+    packages: &'a [PluginPackage],
+}
+
+/// Invoke the configured plugins that handle the given event.
+///
+/// Each plugin receives a JSON description of the event on standard input. If a plugin exits
+/// with a non-zero status, the event is vetoed, and an error is returned that includes the
+/// plugin's standard error output.
+pub(crate) async fn dispatch(
+    plugins: &Plugins,
+    event: PluginEvent,
+    command: &str,
+    environment: Option<&Path>,
+    packages: &[PluginPackage],
+) -> Result<()> {
+    if plugins.is_empty() {
+        return Ok(());
+    }
+
+    let payload = serde_json::to_vec(&Payload {
+        event,
+        command,
+        environment,
+        packages,
+    })?;
+
+    for (name, plugin) in plugins.iter().filter(|(_, plugin)| plugin.handles(event)) {
+        invoke(name, plugin, event, &payload).await?;
+    }
+
+    Ok(())
+}
+
+/// The JSON payload written to a plugin's standard input for `candidates` events.
+#[derive(Debug, Serialize)]
+struct CandidatesPayload<'a> {
+    /// The event that triggered the plugin, i.e., `candidates`.
+    event: PluginEvent,
+    /// The uv command that emitted the event (e.g., `lock`).
+    command: &'a str,
+    /// The name of the package.
+    package: &'a PackageName,
+    /// The index from which the candidates were fetched, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+    /// The candidate versions of the package.
+    candidates: Vec<Candidate<'a>>,
+}
+
+/// A candidate version included in a `candidates` event.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Candidate<'a> {
+    version: &'a Version,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upload_time: Option<String>,
+}
+
+/// The JSON response read from a plugin's standard output for `candidates` events.
+#[derive(Debug, Deserialize)]
+struct CandidatesResponse {
+    /// The candidate versions to exclude from the resolution.
+    #[serde(default)]
+    reject: Vec<Version>,
+}
+
+/// A [`CandidateVeto`] that invokes the configured plugins that handle the `candidates` event.
+///
+/// Each plugin is started once per resolution, and receives the candidates for each package as a
+/// line of JSON on standard input. It can reject individual versions by responding with a line of
+/// JSON with a `reject` list on standard output. A plugin that exits before responding fails the
+/// resolution.
+pub(crate) struct PluginCandidateVeto {
+    plugins: Plugins,
+    command: &'static str,
+    /// The running plugin processes, by plugin name, which are started on the first `candidates`
+    /// event and reused for the remainder of the resolution.
+    processes: Mutex<FxHashMap<String, CandidatesProcess>>,
+}
+
+impl PluginCandidateVeto {
+    /// Create a [`PluginCandidateVeto`] for the given plugins, if any handle the `candidates`
+    /// event.
+    pub(crate) fn from_plugins(
+        plugins: &Plugins,
+        command: &'static str,
+    ) -> Option<Arc<dyn CandidateVeto>> {
+        if !plugins
+            .iter()
+            .any(|(_, plugin)| plugin.handles(PluginEvent::Candidates))
+        {
+            return None;
+        }
+        Some(Arc::new(Self {
+            plugins: plugins.clone(),
+            command,
+            processes: Mutex::new(FxHashMap::default()),
+        }))
+    }
+}
+
+impl PluginCandidateVeto {
+    /// Invoke the plugins for the candidate versions of a package, returning the versions that
+    /// any plugin rejected.
+    async fn reject(
+        &self,
+        package: &PackageName,
+        index: Option<&IndexUrl>,
+        candidates: &[VetoCandidate],
+    ) -> Result<Vec<Version>> {
+        let event = PluginEvent::Candidates;
+        let payload = serde_json::to_vec(&CandidatesPayload {
+            event,
+            command: self.command,
+            package,
+            index: index.map(|index| index.without_credentials().to_string()),
+            candidates: candidates
+                .iter()
+                .map(|candidate| Candidate {
+                    version: &candidate.version,
+                    upload_time: candidate.upload_time.as_ref().map(ToString::to_string),
+                })
+                .collect(),
+        })?;
+
+        // Invoke the plugins one event at a time, since each process handles a single request at
+        // a time.
+        let mut processes = self.processes.lock().await;
+
+        let mut rejected = Vec::new();
+        for (name, plugin) in self
+            .plugins
+            .iter()
+            .filter(|(_, plugin)| plugin.handles(event))
+        {
+            let process = match processes.entry(name.to_string()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(CandidatesProcess::spawn(name, plugin)?),
+            };
+            let stdout = match process.request(name, &payload).await {
+                Ok(stdout) => stdout,
+                Err(err) => {
+                    processes.remove(name);
+                    return Err(err);
+                }
+            };
+            if stdout.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let response = serde_json::from_slice::<CandidatesResponse>(&stdout)
+                .with_context(|| format!("Plugin `{name}` returned an invalid response"))?;
+            for version in response.reject {
+                debug!("Plugin `{name}` rejected {package}=={version}");
+                rejected.push(version);
+            }
+        }
+        Ok(rejected)
+    }
+}
+
+impl CandidateVeto for PluginCandidateVeto {
+    fn veto<'a>(
+        &'a self,
+        package: &'a PackageName,
+        index: Option<&'a IndexUrl>,
+        candidates: &'a [VetoCandidate],
+    ) -> LocalBoxFuture<'a, Result<Vec<Version>, CandidateVetoError>> {
+        self.reject(package, index, candidates)
+            .map(|result| result.map_err(CandidateVetoError::from))
+            .boxed_local()
+    }
+}
+
+/// A plugin process that handles `candidates` events for the duration of a resolution.
+///
+/// Each event is written to the plugin's standard input as a single line of JSON, to which the
+/// plugin responds with a single line on standard output. Standard input is closed when the
+/// process is dropped, at which point the plugin is expected to exit.
+struct CandidatesProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    /// The plugin's standard error output, which is collected until the plugin exits.
+    stderr: JoinHandle<Vec<u8>>,
+}
+
+impl CandidatesProcess {
+    /// Start the process for a plugin.
+    fn spawn(name: &str, plugin: &Plugin) -> Result<Self> {
+        debug!("Starting plugin `{name}` for `candidates` events");
+        let mut process = process(name, plugin)?;
+        process
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = process
+            .spawn()
+            .with_context(|| format!("Failed to invoke plugin `{name}`"))?;
+        let (Some(stdin), Some(stdout), Some(mut stderr)) =
+            (child.stdin.take(), child.stdout.take(), child.stderr.take())
+        else {
+            bail!("Failed to invoke plugin `{name}`");
+        };
+
+        // Drain standard error in the background, such that a verbose plugin can't block on a
+        // full pipe.
+        let stderr = tokio::spawn(async move {
+            let mut buffer = Vec::new();
+            let _ = stderr.read_to_end(&mut buffer).await;
+            buffer
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+            stderr,
+        })
+    }
+
+    /// Write a payload to the plugin, returning its response.
+    ///
+    /// If the plugin exits before responding, an error is returned that includes the plugin's
+    /// standard error output.
+    async fn request(&mut self, name: &str, payload: &[u8]) -> Result<Vec<u8>> {
+        let mut response = Vec::new();
+        let result = async {
+            self.stdin.write_all(payload).await?;
+            self.stdin.write_all(b"\n").await?;
+            self.stdin.flush().await?;
+            self.stdout.read_until(b'\n', &mut response).await
+        }
+        .await;
+        match result {
+            Ok(read) if read > 0 => return Ok(response),
+            // The plugin closed its standard output (or input), i.e., it exited.
+            Ok(_) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::BrokenPipe => {}
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to invoke plugin `{name}`"));
+            }
+        }
+
+        let status = self
+            .child
+            .wait()
+            .await
+            .with_context(|| format!("Failed to invoke plugin `{name}`"))?;
+        let stderr = (&mut self.stderr).await.unwrap_or_default();
+        if status.success() {
+            bail!("Plugin `{name}` exited without responding to the `candidates` event");
+        }
+        Err(rejected(name, PluginEvent::Candidates, status, &stderr))
+    }
+}
+
+/// Construct the process for a plugin, i.e., its command, or `wasmtime` for a WebAssembly
+/// module.
+///
+/// uv doesn't embed a WebAssembly runtime: a `wasm` plugin is shorthand for a command that runs
+/// the module with the `wasmtime` CLI.
+fn process(name: &str, plugin: &Plugin) -> Result<Command> {
+    let mut process = match (&plugin.command, &plugin.wasm) {
+        (Some(command), None) => {
+            let Some((program, args)) = command.split_first() else {
+                bail!("Plugin `{name}` has an empty command");
+            };
+            let mut process = Command::new(program);
+            process.args(args);
+            process
+        }
+        (None, Some(wasm)) => {
+            let mut process = Command::new("wasmtime");
+            process.arg("run").arg(wasm);
+            process
+        }
+        (Some(_), Some(_)) => {
+            bail!("Plugin `{name}` must provide either a `command` or a `wasm` module, not both")
+        }
+        (None, None) => bail!("Plugin `{name}` must provide a `command` or a `wasm` module"),
+    };
+    if let Some(working_directory) = &plugin.working_directory {
+        process.current_dir(working_directory);
+    }
+    Ok(process)
+}
+
+/// Invoke a plugin with the given payload on standard input.
+///
+/// If the plugin exits with a non-zero status, an error is returned that includes the plugin's
+/// standard error output.
+async fn invoke(name: &str, plugin: &Plugin, event: PluginEvent, payload: &[u8]) -> Result<()> {
+    debug!("Invoking plugin `{name}` for `{event}` event");
+    let mut process = process(name, plugin)?;
+    process
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = process
+        .spawn()
+        .with_context(|| format!("Failed to invoke plugin `{name}`"))?;
+
+    // Write the payload, then close standard input. Plugins that don't read the payload may exit
+    // before it's written in full.
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(payload).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::BrokenPipe => {}
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to invoke plugin `{name}`"));
+            }
+        }
+    }
+
+    let output = child
+        .wait_with_output()
+        .await
+        .with_context(|| format!("Failed to invoke plugin `{name}`"))?;
+
+    if output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim();
+        if !stderr.is_empty() {
+            debug!("Plugin `{name}` reported: {stderr}");
+        }
+        return Ok(());
+    }
+
+    Err(rejected(name, event, output.status, &output.stderr))
+}
+
+/// Construct the error for a plugin that exited with a non-zero status, including its standard
+/// error output.
+fn rejected(name: &str, event: PluginEvent, status: ExitStatus, stderr: &[u8]) -> anyhow::Error {
+    let stderr = String::from_utf8_lossy(stderr);
+    let stderr = stderr.trim();
+    if stderr.is_empty() {
+        return anyhow!("Plugin `{name}` rejected the `{event}` event ({status})");
+    }
+    anyhow!(stderr.to_string()).context(format!("Plugin `{name}` rejected the `{event}` event"))
+}
@@ -5,6 +5,7 @@ use std::io;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use itertools::Itertools;
@@ -26,7 +27,7 @@ use uv_distribution_types::{
     Index, IndexName, IndexUrl, IndexUrls, NameRequirementSpecification, Requirement,
     RequirementSource, UnresolvedRequirement, VersionId,
 };
-use uv_fs::{LockedFile, Simplified};
+use uv_fs::{LockedFile, Quarantine, Simplified};
 use uv_git::GIT_STORE;
 use uv_git_types::GitReference;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, PackageName};
@@ -38,7 +39,7 @@ use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::FlatIndex;
 use uv_scripts::{Pep723Metadata, Pep723Script};
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{Plugins, PythonInstallMirrors};
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::{DependencyType, Source, SourceError, Sources, ToolUvSources};
@@ -49,6 +50,7 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::edit::write_edited;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -58,7 +60,7 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, project};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverInstallerSettings;
 
 /// Add one or more packages to the project requirements.
@@ -94,6 +96,9 @@ pub(crate) async fn add(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -268,13 +273,13 @@ pub(crate) async fn add(
                 DependencyType::Production => {
                     bail!(
                         "Project is missing a `[project]` table; add a `[project]` table to use production dependencies, or run `{}` instead",
-                        "uv add --dev".green()
+                        "uv add --dev".success()
                     )
                 }
                 DependencyType::Optional(_) => {
                     bail!(
                         "Project is missing a `[project]` table; add a `[project]` table to use optional dependencies, or run `{}` instead",
-                        "uv add --dev".green()
+                        "uv add --dev".success()
                     )
                 }
                 DependencyType::Group(_) => {}
@@ -333,13 +338,14 @@ pub(crate) async fn add(
         }
     };
 
-    let _lock = target
-        .acquire_lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match target.acquire_lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
 
     let client_builder = client_builder
         .clone()
@@ -496,9 +502,9 @@ pub(crate) async fn add(
                     if requirement.name == *project_name {
                         bail!(
                             "Requirement name `{}` matches project name `{}`, but self-dependencies are not permitted without the `--dev` or `--optional` flags. If your project name (`{}`) is shadowing that of a third-party dependency, consider renaming the project.",
-                            requirement.name.cyan(),
-                            project_name.cyan(),
-                            project_name.cyan(),
+                            requirement.name.accent(),
+                            project_name.accent(),
+                            project_name.accent(),
                         );
                     }
                 }
@@ -593,7 +599,7 @@ pub(crate) async fn add(
                 writeln!(
                     printer.stderr(),
                     "Added `{}` to workspace members",
-                    relative_path.user_display().cyan()
+                    relative_path.user_display().accent()
                 )?;
             }
         }
@@ -602,8 +608,9 @@ pub(crate) async fn add(
         // the discovered members, etc.
         target = if modified {
             let workspace_content = toml.to_string();
-            fs_err::write(
-                project.workspace().install_path().join("pyproject.toml"),
+            write_edited(
+                &project.workspace().install_path().join("pyproject.toml"),
+                &project.workspace().pyproject_toml().raw,
                 &workspace_content,
             )?;
 
@@ -642,6 +649,7 @@ pub(crate) async fn add(
         branch.as_deref(),
         &extras_of_dependency,
         index,
+        marker.is_some(),
         &mut toml,
     )?;
 
@@ -692,7 +700,7 @@ pub(crate) async fn add(
             writeln!(
                 printer.stderr(),
                 "Updated `{}`",
-                script.path.user_display().cyan()
+                script.path.user_display().accent()
             )?;
             return Ok(ExitStatus::Success);
         }
@@ -740,6 +748,8 @@ pub(crate) async fn add(
         &settings,
         &client_builder,
         installer_metadata,
+        quarantine,
+        plugins,
         concurrency,
         cache,
         printer,
@@ -753,9 +763,9 @@ pub(crate) async fn add(
                 let _ = snapshot.revert();
             }
             match err {
-                ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls()).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
+                ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls()).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".success()))
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
+                    .map_err(Into::into),
                 err => Err(err.into()),
             }
         }
@@ -773,6 +783,7 @@ fn edits(
     branch: Option<&str>,
     extras: &[ExtraName],
     index: Option<&IndexName>,
+    replace_marker: bool,
     toml: &mut PyProjectTomlMut,
 ) -> Result<Vec<DependencyEdit>> {
     let mut edits = Vec::<DependencyEdit>::with_capacity(requirements.len());
@@ -843,6 +854,7 @@ fn edits(
                 rev,
                 tag,
                 branch,
+                extras,
                 marker,
                 extra,
                 group,
@@ -861,6 +873,7 @@ fn edits(
                     rev,
                     tag,
                     branch,
+                    extras,
                     marker,
                     extra,
                     group,
@@ -907,6 +920,25 @@ fn edits(
             DependencyType::Group(group) => DependencyType::Group(group.clone()),
         };
 
+        // If a marker was provided via `--marker`, replace the marker on an existing entry rather
+        // than adding a second entry alongside it.
+        if replace_marker
+            && !toml
+                .find_dependency(&requirement.name, Some(&requirement.marker))
+                .contains(&dependency_type)
+        {
+            match toml.set_dependency_marker(
+                &requirement.name,
+                &dependency_type,
+                requirement.marker,
+            ) {
+                Ok(_) => {}
+                // If there are multiple entries, fall back to adding a new entry.
+                Err(uv_workspace::pyproject_mut::Error::Ambiguous { .. }) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
         // Update the `pyproject.toml`.
         let edit = match &dependency_type {
             DependencyType::Production => {
@@ -971,6 +1003,8 @@ async fn lock_and_sync(
     settings: &ResolverInstallerSettings,
     client_builder: &BaseClientBuilder<'_>,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    plugins: &Plugins,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -1158,10 +1192,15 @@ async fn lock_and_sync(
         Modifications::Sufficient,
         None,
         settings.into(),
+        None,
         client_builder,
         &sync_state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        false,
+        false,
+        plugins,
         concurrency,
         cache,
         WorkspaceCache::default(),
@@ -1335,10 +1374,13 @@ impl<'lock> From<&'lock AddTarget> for LockTarget<'lock> {
 impl AddTarget {
     /// Acquire a file lock mapped to the underlying interpreter to prevent concurrent
     /// modifications.
-    pub(super) async fn acquire_lock(&self) -> Result<LockedFile, io::Error> {
+    pub(super) async fn acquire_lock(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<LockedFile, io::Error> {
         match self {
-            Self::Script(_, interpreter) => interpreter.lock().await,
-            Self::Project(_, python_target) => python_target.interpreter().lock().await,
+            Self::Script(_, interpreter) => interpreter.lock(timeout).await,
+            Self::Project(_, python_target) => python_target.interpreter().lock(timeout).await,
         }
     }
 
@@ -1353,7 +1395,7 @@ impl AddTarget {
     /// Write the updated content to the target.
     ///
     /// Returns `true` if the content was modified.
-    fn write(&self, content: &str) -> Result<bool, io::Error> {
+    fn write(&self, content: &str) -> Result<bool, ProjectError> {
         match self {
             Self::Script(script, _) => {
                 if content == script.metadata.raw {
@@ -1370,7 +1412,7 @@ impl AddTarget {
                     Ok(false)
                 } else {
                     let pyproject_path = project.root().join("pyproject.toml");
-                    fs_err::write(pyproject_path, content)?;
+                    write_edited(&pyproject_path, &project.pyproject_toml().raw, content)?;
                     Ok(true)
                 }
             }
@@ -0,0 +1,443 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{
+    Concurrency, DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
+};
+use uv_fs::{Quarantine, Simplified};
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_preview::Preview;
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
+};
+use uv_settings::{Plugins, PythonInstallMirrors};
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, UniversalState, WorkspacePython};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, project};
+use crate::printer::{Printer, Styled};
+use crate::settings::ResolverInstallerSettings;
+
+/// The name of the marker file written to the root of each bundle, such that an existing bundle
+/// can be identified (and replaced) by subsequent invocations.
+const BUNDLE_MARKER: &str = ".uv-bundle";
+
+/// Bundle the project into a self-contained, relocatable directory.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn bundle(
+    project_dir: &Path,
+    output_dir: Option<PathBuf>,
+    archive: bool,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    locked: bool,
+    frozen: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    quarantine: Quarantine,
+    plugins: &Plugins,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project = if let Some(package) = package.as_ref() {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?
+    };
+
+    // Ensure that the output directory can be replaced before doing any work.
+    let output_dir = output_dir
+        .map(|output_dir| project_dir.join(output_dir))
+        .unwrap_or_else(|| project.workspace().install_path().join("bundle"));
+    validate_output_dir(&output_dir)?;
+
+    // Unlike `uv sync`, don't include any dependency groups by default.
+    let groups = groups.with_defaults(DefaultGroups::List(vec![]));
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    // Find a managed Python installation to bundle.
+    let WorkspacePython {
+        python_request,
+        requires_python,
+        ..
+    } = WorkspacePython::from_request(
+        python.as_deref().map(PythonRequest::parse),
+        Some(project.workspace()),
+        &groups,
+        project_dir,
+        no_config,
+    )
+    .await?;
+
+    let reporter = PythonDownloadReporter::single(printer);
+    let installation = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        PythonPreference::OnlyManaged,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+        preview,
+    )
+    .await?;
+    let interpreter = installation.into_interpreter();
+
+    if let Some(requires_python) = requires_python.as_ref() {
+        if !requires_python.contains(interpreter.python_version()) {
+            bail!(
+                "The Python interpreter ({}) is incompatible with the project's Python requirement: `{}`",
+                interpreter.python_full_version(),
+                requires_python.specifiers()
+            );
+        }
+    }
+
+    // Determine the lock mode.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreter)
+    } else {
+        LockMode::Write(&interpreter)
+    };
+
+    // Initialize any shared state.
+    let state = UniversalState::default();
+
+    // Lock the project.
+    let lock = match LockOperation::new(
+        mode,
+        &settings.resolver,
+        &client_builder,
+        &state,
+        Box::new(DefaultResolveLogger),
+        concurrency,
+        cache,
+        &workspace_cache,
+        printer,
+        preview,
+    )
+    .execute(LockTarget::from(project.workspace()))
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_err(Into::into);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Build the bundle in a staging directory alongside the output directory, such that a failed
+    // build never leaves a partial bundle (or removes an existing one), and such that the bundle
+    // can be moved into place with a rename.
+    let parent = output_dir
+        .parent()
+        .context("The output directory must have a parent")?;
+    fs_err::create_dir_all(parent)?;
+    let staging = tempfile::tempdir_in(parent)?;
+    let bundle_dir = staging.path().join("bundle");
+    fs_err::create_dir(&bundle_dir)?;
+    fs_err::write(
+        bundle_dir.join(BUNDLE_MARKER),
+        "This directory was created by `uv bundle`, and is replaced by subsequent invocations.\n",
+    )?;
+
+    // Copy the Python installation into the bundle.
+    let (interpreter, executable) =
+        copy_interpreter(&interpreter, &bundle_dir.join("python"), cache)?;
+    let environment = PythonEnvironment::from_interpreter(interpreter);
+
+    // Identify the installation target.
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Install the project and its dependencies into the bundled interpreter. The project itself
+    // must be installed as non-editable, since the bundle can't reference the source tree.
+    let state = state.fork();
+    match project::sync::do_sync(
+        target,
+        &environment,
+        &extras,
+        &groups,
+        Some(EditableMode::NonEditable),
+        InstallOptions::default(),
+        Modifications::Exact,
+        None,
+        (&settings).into(),
+        None,
+        &client_builder,
+        &state,
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        quarantine,
+        false,
+        false,
+        plugins,
+        concurrency,
+        cache,
+        workspace_cache,
+        DryRun::Disabled,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_err(Into::into);
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    // Write a launcher for each installed entry point.
+    let launchers = write_launchers(
+        &environment,
+        &Path::new("python").join(executable),
+        &bundle_dir.join("bin"),
+    )?;
+    if !launchers.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Installed {} {}: {}",
+            launchers.len(),
+            if launchers.len() == 1 {
+                "launcher"
+            } else {
+                "launchers"
+            },
+            launchers.join(", ")
+        )?;
+    }
+
+    // Move the bundle into place, replacing any existing bundle.
+    replace_output_dir(&bundle_dir, &output_dir, staging.path())?;
+
+    writeln!(
+        printer.stderr(),
+        "Created bundle with Python {} at: {}",
+        environment.interpreter().python_full_version().accent(),
+        output_dir.user_display().accent()
+    )?;
+
+    if archive {
+        let archive = write_archive(&output_dir)?;
+        writeln!(
+            printer.stderr(),
+            "Created archive at: {}",
+            archive.user_display().accent()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Ensure that the output directory is absent, empty, or contains a bundle written by a previous
+/// invocation (as identified by its marker file), such that it can be replaced.
+fn validate_output_dir(output_dir: &Path) -> Result<()> {
+    match fs_err::read_dir(output_dir) {
+        Ok(mut entries) => {
+            if entries.next().is_some() && !output_dir.join(BUNDLE_MARKER).is_file() {
+                bail!(
+                    "The output directory `{}` already exists and does not contain a bundle",
+                    output_dir.user_display()
+                );
+            }
+            Ok(())
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotADirectory => bail!(
+            "The output directory `{}` already exists and is not a directory",
+            output_dir.user_display()
+        ),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Move a staged bundle to the output directory, replacing any existing bundle.
+///
+/// The existing bundle is moved into the staging directory before the new bundle is moved into
+/// place, and is removed along with the staging directory.
+fn replace_output_dir(bundle_dir: &Path, output_dir: &Path, staging: &Path) -> Result<()> {
+    // Re-validate, in case the output directory was modified during the build.
+    validate_output_dir(output_dir)?;
+
+    if output_dir.exists() {
+        debug!(
+            "Replacing existing bundle at: {}",
+            output_dir.user_display()
+        );
+        fs_err::rename(output_dir, staging.join("previous"))?;
+    }
+    fs_err::rename(bundle_dir, output_dir)?;
+    Ok(())
+}
+
+/// Copy a managed Python installation into the given directory, returning the copied interpreter
+/// along with the path to its executable, relative to the directory.
+fn copy_interpreter(
+    interpreter: &Interpreter,
+    target: &Path,
+    cache: &Cache,
+) -> Result<(Interpreter, PathBuf)> {
+    let prefix = fs_err::canonicalize(interpreter.sys_base_prefix())?;
+    let executable = fs_err::canonicalize(interpreter.sys_executable())?;
+    let Ok(relative) = executable.strip_prefix(&prefix) else {
+        bail!(
+            "The Python executable at `{}` is outside of its installation at `{}`",
+            executable.user_display(),
+            prefix.user_display()
+        );
+    };
+
+    debug!(
+        "Copying Python installation from {} to {}",
+        prefix.user_display(),
+        target.user_display()
+    );
+    uv_fs::copy_dir_all(&prefix, target)?;
+
+    let interpreter = Interpreter::query(target.join(relative), cache)?;
+    Ok((interpreter, relative.to_path_buf()))
+}
+
+/// Write a launcher to the given directory for each console and GUI script installed in the
+/// given environment, returning the names of the launchers.
+///
+/// Scripts installed by the wheel installer reference the interpreter by absolute path, so the
+/// launchers instead locate the interpreter relative to themselves (via the given path, relative
+/// to the bundle root), such that the bundle can be moved.
+fn write_launchers(
+    environment: &PythonEnvironment,
+    executable: &Path,
+    bin: &Path,
+) -> Result<Vec<String>> {
+    let interpreter = environment.interpreter();
+    fs_err::create_dir_all(bin)?;
+
+    let mut launchers = Vec::new();
+    let site_packages = SitePackages::from_environment(environment)?;
+    for dist in site_packages.iter() {
+        for script in uv_install_wheel::read_installed_entry_points(
+            dist.install_path(),
+            interpreter.python_minor(),
+        )? {
+            write_launcher(bin, executable, &script)?;
+            launchers.push(script.name);
+        }
+    }
+    launchers.sort();
+
+    Ok(launchers)
+}
+
+/// Write a launcher for a single script.
+#[cfg(unix)]
+fn write_launcher(bin: &Path, executable: &Path, script: &uv_install_wheel::Script) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let uv_install_wheel::Script {
+        name,
+        module,
+        function,
+    } = script;
+    let import_name = script.import_name();
+    let executable = executable.portable_display();
+
+    let path = bin.join(name);
+    fs_err::write(
+        &path,
+        format!(
+            r#"#!/bin/sh
+here="$(cd -- "$(dirname -- "$0")" && pwd)"
+exec "$here/../{executable}" -c 'import sys; sys.argv = sys.argv[1:]; from {module} import {import_name}; sys.exit({function}())' "$0" "$@"
+"#
+        ),
+    )?;
+    fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+/// Write a launcher for a single script.
+#[cfg(windows)]
+fn write_launcher(bin: &Path, executable: &Path, script: &uv_install_wheel::Script) -> Result<()> {
+    let uv_install_wheel::Script {
+        name,
+        module,
+        function,
+    } = script;
+    let import_name = script.import_name();
+    let executable = executable.display();
+
+    fs_err::write(
+        bin.join(format!("{name}.cmd")),
+        format!(
+            "@echo off\r\n\"%~dp0..\\{executable}\" -c \"import sys; sys.argv = sys.argv[1:]; from {module} import {import_name}; sys.exit({function}())\" \"%~f0\" %*\r\n"
+        ),
+    )?;
+    Ok(())
+}
+
+/// Write the bundle to a `.tar.gz` archive alongside the output directory.
+fn write_archive(output_dir: &Path) -> Result<PathBuf> {
+    let name = output_dir
+        .file_name()
+        .context("The output directory must have a name")?;
+    let mut filename = name.to_os_string();
+    filename.push(".tar.gz");
+    let path = output_dir.with_file_name(filename);
+
+    let file = fs_err::File::create(&path)?;
+    let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+    builder.append_dir_all(name, output_dir)?;
+    builder.into_inner()?.finish()?;
+
+    Ok(path)
+}
@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::DiffFormat;
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_resolver::Lock;
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::printer::Printer;
+
+/// Compare the packages in two lockfiles or environments.
+pub(crate) async fn diff(
+    old: &Path,
+    new: &Path,
+    output_format: DiffFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let old = Snapshot::read(old, cache).await?;
+    let new = Snapshot::read(new, cache).await?;
+    let changes = PackageChange::from_snapshots(&old, &new);
+
+    match output_format {
+        DiffFormat::Text if changes.is_empty() => {
+            writeln!(printer.stderr(), "No differences found")?;
+        }
+        DiffFormat::Text => {
+            let columns = [
+                Column {
+                    header: String::from("Package"),
+                    rows: changes
+                        .iter()
+                        .map(|change| change.name.to_string())
+                        .collect(),
+                },
+                Column {
+                    header: String::from("Old"),
+                    rows: changes
+                        .iter()
+                        .map(|change| change.old_version.clone().unwrap_or_default())
+                        .collect(),
+                },
+                Column {
+                    header: String::from("New"),
+                    rows: changes
+                        .iter()
+                        .map(|change| change.new_version.clone().unwrap_or_default())
+                        .collect(),
+                },
+                Column {
+                    header: String::from("Change"),
+                    rows: changes
+                        .iter()
+                        .map(|change| change.kind.to_string())
+                        .collect(),
+                },
+                Column {
+                    header: String::from("Required by"),
+                    rows: changes
+                        .iter()
+                        .map(|change| change.required_by.iter().join(", "))
+                        .collect(),
+                },
+            ];
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+        DiffFormat::Json => {
+            let output = serde_json::to_string(&changes)?;
+            writeln!(printer.stdout(), "{output}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The packages in a lockfile or environment.
+#[derive(Debug, Default)]
+struct Snapshot {
+    /// The versions of each package.
+    versions: BTreeMap<PackageName, BTreeSet<Version>>,
+    /// The direct dependencies through which each package is required, if known.
+    required_by: BTreeMap<PackageName, BTreeSet<PackageName>>,
+}
+
+impl Snapshot {
+    /// Read a [`Snapshot`] from a lockfile, a directory containing a lockfile, or a virtual
+    /// environment.
+    async fn read(path: &Path, cache: &Cache) -> Result<Self> {
+        if path.join("pyvenv.cfg").is_file() {
+            let environment = PythonEnvironment::from_root(path, cache)?;
+            return Self::from_environment(&environment);
+        }
+
+        let path = if path.is_dir() {
+            path.join("uv.lock")
+        } else {
+            path.to_path_buf()
+        };
+        let contents = fs_err::tokio::read_to_string(&path).await?;
+        let lock = toml::from_str::<Lock>(&contents)
+            .with_context(|| format!("Failed to parse lockfile: `{}`", path.user_display()))?;
+        Ok(Self::from_lock(&lock))
+    }
+
+    /// Create a [`Snapshot`] from the packages in a [`Lock`].
+    ///
+    /// Packages without a version (e.g., workspace members with a dynamic version) are omitted.
+    fn from_lock(lock: &Lock) -> Self {
+        let mut versions: BTreeMap<PackageName, BTreeSet<Version>> = BTreeMap::new();
+        for package in lock.packages() {
+            if let Some(version) = package.version() {
+                versions
+                    .entry(package.name().clone())
+                    .or_default()
+                    .insert(version.clone());
+            }
+        }
+
+        let required_by = lock
+            .required_by()
+            .into_iter()
+            .map(|(name, required_by)| {
+                (
+                    name.clone(),
+                    required_by.into_iter().cloned().collect::<BTreeSet<_>>(),
+                )
+            })
+            .collect();
+
+        Self {
+            versions,
+            required_by,
+        }
+    }
+
+    /// Create a [`Snapshot`] from the packages installed in a [`PythonEnvironment`].
+    fn from_environment(environment: &PythonEnvironment) -> Result<Self> {
+        let site_packages = SitePackages::from_environment(environment)?;
+
+        let mut versions: BTreeMap<PackageName, BTreeSet<Version>> = BTreeMap::new();
+        for dist in site_packages.iter() {
+            versions
+                .entry(dist.name().clone())
+                .or_default()
+                .insert(dist.version().clone());
+        }
+
+        Ok(Self {
+            versions,
+            required_by: BTreeMap::default(),
+        })
+    }
+}
+
+/// The kind of change to a package between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ChangeKind {
+    /// The package was added.
+    Added,
+    /// The package was removed.
+    Removed,
+    /// The package was upgraded to a newer version.
+    Upgraded,
+    /// The package was downgraded to an older version.
+    Downgraded,
+    /// The set of versions changed, without changing the latest version (e.g., a version was
+    /// added for a specific platform).
+    Changed,
+}
+
+impl std::fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+            Self::Upgraded => write!(f, "upgraded"),
+            Self::Downgraded => write!(f, "downgraded"),
+            Self::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// A change to a package between two snapshots.
+#[derive(Debug, Serialize)]
+struct PackageChange {
+    /// The name of the package.
+    name: PackageName,
+    /// The kind of change.
+    kind: ChangeKind,
+    /// The version (or versions) of the package in the old snapshot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_version: Option<String>,
+    /// The version (or versions) of the package in the new snapshot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_version: Option<String>,
+    /// The direct dependencies through which the package is required.
+    ///
+    /// For removed packages, these are derived from the old snapshot; otherwise, from the new
+    /// snapshot.
+    required_by: Vec<PackageName>,
+}
+
+impl PackageChange {
+    /// Compute the changes between two [`Snapshot`]s, sorted by package name.
+    fn from_snapshots(old: &Snapshot, new: &Snapshot) -> Vec<Self> {
+        let names = old
+            .versions
+            .keys()
+            .chain(new.versions.keys())
+            .collect::<BTreeSet<_>>();
+
+        let mut changes = Vec::new();
+        for name in names {
+            let old_versions = old.versions.get(name);
+            let new_versions = new.versions.get(name);
+            let kind = match (old_versions, new_versions) {
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(_), None) => ChangeKind::Removed,
+                (Some(old_versions), Some(new_versions)) if old_versions != new_versions => {
+                    match new_versions.last().cmp(&old_versions.last()) {
+                        std::cmp::Ordering::Greater => ChangeKind::Upgraded,
+                        std::cmp::Ordering::Less => ChangeKind::Downgraded,
+                        std::cmp::Ordering::Equal => ChangeKind::Changed,
+                    }
+                }
+                _ => continue,
+            };
+
+            let required_by = if kind == ChangeKind::Removed {
+                old.required_by.get(name)
+            } else {
+                new.required_by.get(name)
+            };
+
+            changes.push(Self {
+                name: name.clone(),
+                kind,
+                old_version: old_versions.map(|versions| versions.iter().join(", ")),
+                new_version: new_versions.map(|versions| versions.iter().join(", ")),
+                required_by: required_by
+                    .map(|required_by| required_by.iter().cloned().collect())
+                    .unwrap_or_default(),
+            });
+        }
+        changes
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::Range;
+use std::path::Path;
+
+use console::Term;
+use similar::{Algorithm, DiffOp};
+use tracing::debug;
+
+use uv_cache_key::cache_digest;
+use uv_fs::{LockedFile, Simplified};
+use uv_workspace::pyproject::PyProjectToml;
+
+use crate::commands::project::ProjectError;
+use crate::printer::Styled;
+
+/// Replace the contents of a configuration file (e.g., a `pyproject.toml`) that were read as
+/// `original` with the edited `content`.
+///
+/// The check and write are performed under an exclusive lock, such that concurrent uv processes
+/// are serialized, and the file is replaced atomically, such that editors and other readers never
+/// observe a partial write.
+///
+/// If the file was modified since it was read (e.g., by an editor or another uv process), the
+/// user is prompted to retry the edit on top of the latest contents, which succeeds if the edit
+/// doesn't overlap with the concurrent changes. In non-interactive contexts, the edit is aborted.
+pub(crate) fn write_edited(path: &Path, original: &str, content: &str) -> Result<(), ProjectError> {
+    let path = std::path::absolute(path)?;
+    let _lock = LockedFile::acquire_blocking(
+        std::env::temp_dir().join(format!(
+            "uv-{}.lock",
+            cache_digest(&uv_fs::canonicalize_key(&path))
+        )),
+        path.user_display(),
+    )?;
+
+    let current = match fs_err::read_to_string(&path) {
+        Ok(current) => current,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+
+    let content = if current == original {
+        content.to_string()
+    } else if current == content {
+        debug!(
+            "`{}` already contains the changes; skipping update",
+            path.user_display()
+        );
+        return Ok(());
+    } else {
+        debug!("`{}` was modified since it was read", path.user_display());
+        let term = Term::stderr();
+        if !term.is_term() {
+            return Err(ProjectError::ConcurrentModification(
+                path.user_display().to_string(),
+            ));
+        }
+        let prompt = format!(
+            "`{}` was modified by another process. Retry with the latest contents?",
+            path.user_display().accent()
+        );
+        if !uv_console::confirm(&prompt, &term, false)? {
+            return Err(ProjectError::ConcurrentModification(
+                path.user_display().to_string(),
+            ));
+        }
+        merge_pyproject(original, content, &current)
+            .ok_or_else(|| ProjectError::ConflictingModification(path.user_display().to_string()))?
+    };
+
+    uv_fs::write_atomic_sync(&path, content)?;
+
+    Ok(())
+}
+
+/// A change to a range of lines in the original file.
+#[derive(Debug)]
+struct Hunk<'a> {
+    range: Range<usize>,
+    lines: &'a [&'a str],
+}
+
+/// Compute the changes from the `original` lines to the `modified` lines.
+fn hunks<'a>(original: &[&str], modified: &'a [&'a str]) -> Vec<Hunk<'a>> {
+    similar::capture_diff_slices(Algorithm::Myers, original, modified)
+        .into_iter()
+        .filter_map(|op| match op {
+            DiffOp::Equal { .. } => None,
+            DiffOp::Delete {
+                old_index, old_len, ..
+            } => Some(Hunk {
+                range: old_index..old_index + old_len,
+                lines: &[],
+            }),
+            DiffOp::Insert {
+                old_index,
+                new_index,
+                new_len,
+            } => Some(Hunk {
+                range: old_index..old_index,
+                lines: &modified[new_index..new_index + new_len],
+            }),
+            DiffOp::Replace {
+                old_index,
+                old_len,
+                new_index,
+                new_len,
+            } => Some(Hunk {
+                range: old_index..old_index + old_len,
+                lines: &modified[new_index..new_index + new_len],
+            }),
+        })
+        .collect()
+}
+
+/// Perform a three-way merge of two sets of changes to the same `original` `pyproject.toml`.
+///
+/// Returns `None` if the changes can't be merged, or if the merged content is no longer a valid
+/// `pyproject.toml` (e.g., if both sets of changes add the same key in different places).
+fn merge_pyproject(original: &str, ours: &str, theirs: &str) -> Option<String> {
+    let merged = merge(original, ours, theirs)?;
+    if let Err(err) = merged.parse::<toml_edit::DocumentMut>() {
+        debug!("Merged `pyproject.toml` is not valid TOML: {err}");
+        return None;
+    }
+    if let Err(err) = PyProjectToml::from_string(merged.clone()) {
+        debug!("Merged `pyproject.toml` is invalid: {err}");
+        return None;
+    }
+    Some(merged)
+}
+
+/// Perform a three-way merge of two sets of line-based changes to the same `original` content.
+///
+/// Returns `None` if the changes overlap or are adjacent, in which case they can't be merged
+/// unambiguously.
+fn merge(original: &str, ours: &str, theirs: &str) -> Option<String> {
+    let original = original.split_inclusive('\n').collect::<Vec<_>>();
+    let ours = ours.split_inclusive('\n').collect::<Vec<_>>();
+    let theirs = theirs.split_inclusive('\n').collect::<Vec<_>>();
+
+    let mut changes = hunks(&original, &ours);
+    changes.extend(hunks(&original, &theirs));
+    changes.sort_by_key(|hunk| (hunk.range.start, hunk.range.end));
+    if changes
+        .windows(2)
+        .any(|pair| pair[1].range.start <= pair[0].range.end)
+    {
+        return None;
+    }
+
+    let mut merged = String::new();
+    let mut position = 0;
+    for hunk in &changes {
+        merged.extend(original[position..hunk.range.start].iter().copied());
+        merged.extend(hunk.lines.iter().copied());
+        position = hunk.range.end;
+    }
+    merged.extend(original[position..].iter().copied());
+    Some(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge, merge_pyproject};
+
+    #[test]
+    fn merge_disjoint() {
+        let original =
+            "[project]\nname = \"foo\"\ndependencies = []\n\n[tool.ruff]\nline-length = 88\n";
+        let ours = "[project]\nname = \"foo\"\ndependencies = [\"anyio\"]\n\n[tool.ruff]\nline-length = 88\n";
+        let theirs =
+            "[project]\nname = \"foo\"\ndependencies = []\n\n[tool.ruff]\nline-length = 100\n";
+        assert_eq!(
+            merge(original, ours, theirs).as_deref(),
+            Some(
+                "[project]\nname = \"foo\"\ndependencies = [\"anyio\"]\n\n[tool.ruff]\nline-length = 100\n"
+            )
+        );
+    }
+
+    #[test]
+    fn merge_conflict() {
+        let original = "[project]\nname = \"foo\"\ndependencies = []\n";
+        let ours = "[project]\nname = \"foo\"\ndependencies = [\"anyio\"]\n";
+        let theirs = "[project]\nname = \"foo\"\ndependencies = [\"idna\"]\n";
+        assert_eq!(merge(original, ours, theirs), None);
+
+        // Adjacent changes are treated as conflicting, too.
+        let theirs = "[project]\nname = \"bar\"\ndependencies = []\n";
+        assert_eq!(merge(original, ours, theirs), None);
+    }
+
+    #[test]
+    fn merge_invalid() {
+        // Both sides add the same key in different places, which merges cleanly line-by-line but
+        // results in a duplicate key.
+        let original =
+            "[project]\nname = \"foo\"\ndependencies = []\nrequires-python = \">=3.12\"\n";
+        let ours = "[project]\nname = \"foo\"\nversion = \"0.1.0\"\ndependencies = []\nrequires-python = \">=3.12\"\n";
+        let theirs = "[project]\nname = \"foo\"\ndependencies = []\nrequires-python = \">=3.12\"\nversion = \"0.2.0\"\n";
+        assert!(merge(original, ours, theirs).is_some());
+        assert_eq!(merge_pyproject(original, ours, theirs), None);
+
+        // Valid merges are retained.
+        let theirs = "[project]\nname = \"foo\"\ndependencies = []\nrequires-python = \">=3.11\"\n";
+        assert_eq!(
+            merge_pyproject(original, ours, theirs).as_deref(),
+            Some(
+                "[project]\nname = \"foo\"\nversion = \"0.1.0\"\ndependencies = []\nrequires-python = \">=3.11\"\n"
+            )
+        );
+    }
+}
@@ -15,7 +15,7 @@ use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, TargetTriple};
 use uv_distribution_types::{Name, Resolution};
-use uv_fs::PythonExt;
+use uv_fs::{PythonExt, Quarantine};
 use uv_preview::Preview;
 use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
 
@@ -119,6 +119,7 @@ impl CachedEnvironment {
         resolve: Box<dyn ResolveLogger>,
         install: Box<dyn InstallLogger>,
         installer_metadata: bool,
+        quarantine: Quarantine,
         concurrency: Concurrency,
         cache: &Cache,
         printer: Printer,
@@ -204,6 +205,7 @@ impl CachedEnvironment {
             state,
             install,
             installer_metadata,
+            quarantine,
             concurrency,
             cache,
             printer,
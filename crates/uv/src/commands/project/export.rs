@@ -4,7 +4,6 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, anyhow};
 use itertools::Itertools;
-use owo_colors::OwoColorize;
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
@@ -29,7 +28,7 @@ use crate::commands::project::{
     detect_conflicts,
 };
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverSettings;
 
 #[derive(Debug, Clone)]
@@ -206,7 +205,7 @@ pub(crate) async fn export(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     };
@@ -323,9 +322,9 @@ pub(crate) async fn export(
                 writeln!(
                     writer,
                     "{}",
-                    "# This file was autogenerated by uv via the following command:".green()
+                    "# This file was autogenerated by uv via the following command:".success()
                 )?;
-                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).success())?;
             }
             write!(writer, "{export}")?;
         }
@@ -344,9 +343,9 @@ pub(crate) async fn export(
                 writeln!(
                     writer,
                     "{}",
-                    "# This file was autogenerated by uv via the following command:".green()
+                    "# This file was autogenerated by uv via the following command:".success()
                 )?;
-                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).success())?;
             }
             write!(writer, "{}", export.to_toml()?)?;
         }
@@ -5,7 +5,6 @@ use std::process::{Command, Stdio};
 use std::str::FromStr;
 
 use anyhow::{Context, Result, anyhow};
-use owo_colors::OwoColorize;
 use tracing::{debug, trace, warn};
 
 use uv_cache::Cache;
@@ -25,6 +24,7 @@ use uv_python::{
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
     VersionRequest,
 };
+use uv_redacted::redact_credentials;
 use uv_scripts::{Pep723Script, ScriptTag};
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -33,9 +33,10 @@ use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
+use crate::commands::project::edit::write_edited;
 use crate::commands::project::{find_requires_python, init_script_python_requirement};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Add one or more packages to the project requirements.
 #[allow(clippy::single_match_else, clippy::fn_params_excessive_bools)]
@@ -56,6 +57,7 @@ pub(crate) async fn init(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
+    template: Option<String>,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -92,7 +94,7 @@ pub(crate) async fn init(
             writeln!(
                 printer.stderr(),
                 "Initialized script at `{}`",
-                path.user_display().cyan()
+                path.user_display().accent()
             )?;
         }
         InitKind::Project(project_kind) => {
@@ -108,7 +110,7 @@ pub(crate) async fn init(
                     std::path::absolute(&path).unwrap_or_else(|_| path.simplified().to_path_buf());
                 anyhow::bail!(
                     "Project is already initialized in `{}` (`pyproject.toml` file exists)",
-                    path.display().cyan()
+                    path.display().accent()
                 );
             }
 
@@ -144,6 +146,7 @@ pub(crate) async fn init(
                 python,
                 install_mirrors,
                 no_workspace,
+                template.as_deref(),
                 client_builder,
                 python_preference,
                 python_downloads,
@@ -165,7 +168,7 @@ pub(crate) async fn init(
             match explicit_path {
                 // Initialized a project in the current directory.
                 None => {
-                    writeln!(printer.stderr(), "Initialized project `{}`", name.cyan())?;
+                    writeln!(printer.stderr(), "Initialized project `{}`", name.accent())?;
                 }
                 // Initialized a project in the given directory.
                 Some(path) => {
@@ -174,8 +177,8 @@ pub(crate) async fn init(
                     writeln!(
                         printer.stderr(),
                         "Initialized project `{}` at `{}`",
-                        name.cyan(),
-                        path.display().cyan()
+                        name.accent(),
+                        path.display().accent()
                     )?;
                 }
             }
@@ -225,8 +228,8 @@ async fn init_script(
             if ScriptTag::parse(&metadata)?.is_some() {
                 anyhow::bail!(
                     "`{}` is already a PEP 723 script; use `{}` to execute it",
-                    script_path.simplified_display().cyan(),
-                    "uv run".green()
+                    script_path.simplified_display().accent(),
+                    "uv run".success()
                 );
             }
 
@@ -237,7 +240,7 @@ async fn init_script(
             return Err(err).with_context(|| {
                 format!(
                     "Failed to read script at `{}`",
-                    script_path.simplified_display().cyan()
+                    script_path.simplified_display().accent()
                 )
             });
         }
@@ -285,6 +288,7 @@ async fn init_project(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
+    template: Option<&str>,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -332,7 +336,7 @@ async fn init_project(
                     return Err(err).with_context(|| {
                         format!(
                             "Failed to discover parent workspace; use `{}` to ignore",
-                            "uv init --no-workspace".green()
+                            "uv init --no-workspace".success()
                         )
                     });
                 }
@@ -572,19 +576,23 @@ async fn init_project(
         (requires_python, python_request)
     };
 
-    project_kind.init(
-        name,
-        path,
-        &requires_python,
-        description.as_deref(),
-        no_description,
-        bare,
-        vcs,
-        build_backend,
-        author_from,
-        no_readme,
-        package,
-    )?;
+    if let Some(template) = template {
+        init_template(template, name, path, &requires_python, vcs)?;
+    } else {
+        project_kind.init(
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            no_description,
+            bare,
+            vcs,
+            build_backend,
+            author_from,
+            no_readme,
+            package,
+        )?;
+    }
 
     if let Some(workspace) = workspace {
         if workspace.excludes(path)? {
@@ -592,16 +600,16 @@ async fn init_project(
             writeln!(
                 printer.stderr(),
                 "Project `{}` is excluded by workspace `{}`",
-                name.cyan(),
-                workspace.install_path().simplified_display().cyan()
+                name.accent(),
+                workspace.install_path().simplified_display().accent()
             )?;
         } else if workspace.includes(path)? {
             // If the member is already included in the workspace, skip the `members` addition.
             writeln!(
                 printer.stderr(),
                 "Project `{}` is already a member of workspace `{}`",
-                name.cyan(),
-                workspace.install_path().simplified_display().cyan()
+                name.accent(),
+                workspace.install_path().simplified_display().accent()
             )?;
         } else {
             // Add the package to the workspace.
@@ -612,16 +620,17 @@ async fn init_project(
             pyproject.add_workspace(path.strip_prefix(workspace.install_path())?)?;
 
             // Save the modified `pyproject.toml`.
-            fs_err::write(
-                workspace.install_path().join("pyproject.toml"),
-                pyproject.to_string(),
+            write_edited(
+                &workspace.install_path().join("pyproject.toml"),
+                &workspace.pyproject_toml().raw,
+                &pyproject.to_string(),
             )?;
 
             writeln!(
                 printer.stderr(),
                 "Adding `{}` as member of workspace `{}`",
-                name.cyan(),
-                workspace.install_path().simplified_display().cyan()
+                name.accent(),
+                workspace.install_path().simplified_display().accent()
             )?;
         }
         // Write .python-version if it doesn't exist in the workspace or if the version differs
@@ -664,7 +673,8 @@ async fn init_project(
 }
 
 /// The kind of entity to initialize (either a PEP 723 script or a Python project).
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum InitKind {
     /// Initialize a Python project.
     Project(InitProjectKind),
@@ -679,7 +689,8 @@ impl Default for InitKind {
 }
 
 /// The kind of Python project to initialize (either an application or a library).
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum InitProjectKind {
     /// Initialize a Python application.
     #[default]
@@ -1274,6 +1285,115 @@ fn detect_git_repository(path: &Path) -> GitDiscoveryResult {
     }
 }
 
+/// Initialize a project from a template in a Git repository.
+///
+/// The repository is cloned into a temporary directory, and its files (other than `.git`) are
+/// copied into the project, replacing the `{{ project_name }}`, `{{ module_name }}`, and
+/// `{{ requires_python }}` placeholders in file contents and paths.
+fn init_template(
+    template: &str,
+    name: &PackageName,
+    path: &Path,
+    requires_python: &RequiresPython,
+    vcs: Option<VersionControlSystem>,
+) -> Result<()> {
+    let Ok(git) = GIT.as_ref() else {
+        anyhow::bail!(
+            "`git` is required to initialize a project from a template, but was not found in PATH"
+        )
+    };
+
+    // Split off the branch or tag, if present (e.g., `https://github.com/org/template@v1`).
+    let (url, rev) = match template.rsplit_once('/') {
+        Some((_, tail)) if tail.contains('@') => {
+            let (url, rev) = template.rsplit_once('@').expect("Template contains `@`");
+            (url, Some(rev))
+        }
+        _ => (template, None),
+    };
+    let url = url.strip_prefix("git+").unwrap_or(url);
+
+    let checkout = tempfile::tempdir()?;
+    debug!("Cloning template `{}`", redact_credentials(template));
+    let mut command = Command::new(git);
+    command.args(["clone", "--quiet", "--depth", "1"]);
+    if let Some(rev) = rev {
+        command.arg("--branch").arg(rev);
+    }
+    let output = command
+        .arg(url)
+        .arg(checkout.path())
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run `git clone`")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!(
+            "Failed to clone template `{}`:\n{}",
+            redact_credentials(template).accent(),
+            redact_credentials(stderr.trim())
+        );
+    }
+
+    if !checkout.path().join("pyproject.toml").is_file() {
+        anyhow::bail!(
+            "Template `{}` does not contain a `pyproject.toml`",
+            redact_credentials(template).accent()
+        );
+    }
+
+    let module_name = name.as_dist_info_name();
+    let requires_python = requires_python.specifiers().to_string();
+    let render = |source: &str| {
+        source
+            .replace("{{ project_name }}", name.as_str())
+            .replace("{{ module_name }}", &module_name)
+            .replace("{{ requires_python }}", &requires_python)
+    };
+
+    // Collect the files to copy, and avoid overwriting any existing files in the project.
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(checkout.path())
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+    {
+        let entry = entry?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(checkout.path())?;
+        let target = path.join(render(&relative.to_string_lossy()));
+        if target.try_exists()? {
+            anyhow::bail!(
+                "Failed to initialize project from template: `{}` already exists",
+                target.user_display().accent()
+            );
+        }
+        files.push((entry.into_path(), target));
+    }
+
+    for (source, target) in files {
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        match fs_err::read_to_string(&source) {
+            Ok(contents) => fs_err::write(&target, render(&contents))?,
+            // Copy binary files as-is.
+            Err(err) if err.kind() == std::io::ErrorKind::InvalidData => {
+                fs_err::copy(&source, &target)?;
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    // Initialize the version control system last, so that the template's `.gitignore` (if any)
+    // takes precedence over the default.
+    init_vcs(path, vcs)?;
+
+    Ok(())
+}
+
 /// Initialize the version control system at the given path, if applicable.
 fn init_vcs(path: &Path, vcs: Option<VersionControlSystem>) -> Result<()> {
     // vcs is None for an existing repository because we don't want to initialize again.
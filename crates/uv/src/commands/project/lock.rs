@@ -5,6 +5,7 @@ use std::fmt::Write;
 use std::path::Path;
 use std::sync::Arc;
 
+use anyhow::Context;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
@@ -21,26 +22,31 @@ use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
     Requirement, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    CandidateVeto, ConflictedLock, FlatIndex, InMemoryIndex, Lock, LockMerge, Options,
+    OptionsBuilder, Package, PythonRequirement, ResolverEnvironment, ResolverManifest,
+    SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{PluginEvent, Plugins, PythonInstallMirrors};
+use uv_torch::{TorchSource, TorchStrategy};
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::plugins::{self, PluginCandidateVeto, PluginPackage};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
@@ -48,7 +54,7 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverSettings;
 
 /// The result of running a lock operation.
@@ -84,14 +90,18 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    integrity: bool,
+    resolve_merge_conflicts: bool,
+    profile: Option<String>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
-    settings: ResolverSettings,
+    mut settings: ResolverSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<ScriptPath>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
+    plugins: &Plugins,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
@@ -127,9 +137,20 @@ pub(crate) async fn lock(
     let target = if let Some(script) = script.as_ref() {
         LockTarget::Script(script)
     } else {
-        workspace =
+        let discovered =
             Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
                 .await?;
+
+        // Apply the resolution profile, if any.
+        workspace = if let Some(profile) = profile.as_deref() {
+            settings.index_locations = settings
+                .index_locations
+                .clone()
+                .prepend(discovered.profile_indexes(profile)?);
+            discovered.with_profile(profile)?
+        } else {
+            discovered
+        };
         LockTarget::Workspace(&workspace)
     };
 
@@ -185,11 +206,57 @@ pub(crate) async fn lock(
         }
     };
 
+    // If requested, merge both sides of a conflicted lockfile, allowing any packages that were
+    // changed on both sides to be re-resolved.
+    let merged = if resolve_merge_conflicts {
+        if let Some(conflicted) = read_conflicted_lock(target).await? {
+            match conflicted.merge() {
+                Some(merge) => {
+                    if merge.conflicts().is_empty() {
+                        writeln!(
+                            printer.stderr(),
+                            "Merged conflicting changes to the lockfile"
+                        )?;
+                    } else {
+                        writeln!(
+                            printer.stderr(),
+                            "Resolving conflicting changes to: {}",
+                            merge
+                                .conflicts()
+                                .iter()
+                                .map(|name| name.accent().to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )?;
+                        let packages = merge
+                            .conflicts()
+                            .iter()
+                            .map(|name| (name.clone(), vec![]))
+                            .collect();
+                        settings.upgrade = Upgrade::Packages(packages).combine(settings.upgrade);
+                    }
+                    Some(merge.into_lock())
+                }
+                None => {
+                    // If the two sides can't be merged, re-resolve every package, starting from
+                    // our side of the conflict.
+                    settings.upgrade = Upgrade::All;
+                    Some(conflicted.ours)
+                }
+            }
+        } else {
+            debug!("No merge conflicts found in the lockfile");
+            None
+        }
+    } else {
+        None
+    };
+
     // Initialize any shared state.
     let state = UniversalState::default();
 
     // Perform the lock operation.
-    match LockOperation::new(
+    let operation = LockOperation::new(
         mode,
         &settings,
         &client_builder,
@@ -200,10 +267,15 @@ pub(crate) async fn lock(
         &workspace_cache,
         printer,
         preview,
-    )
-    .execute(target)
-    .await
-    {
+    );
+    let operation = if let Some(merged) = merged {
+        operation.with_existing(merged)
+    } else {
+        operation
+    }
+    .with_integrity(integrity)
+    .with_plugins(plugins);
+    match operation.execute(target).await {
         Ok(lock) => {
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
@@ -238,12 +310,140 @@ pub(crate) async fn lock(
         Err(ProjectError::Operation(err)) => {
             diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_err(Into::into)
         }
         Err(err) => Err(err.into()),
     }
 }
 
+/// Both sides of a lockfile with Git merge conflict markers.
+struct ConflictedLockfile {
+    ours: Lock,
+    base: Option<Lock>,
+    theirs: Lock,
+}
+
+impl ConflictedLockfile {
+    /// Merge both sides of the conflict.
+    ///
+    /// Returns `None` if the sides can't be merged into a valid lockfile, e.g., if a package
+    /// retained from one side depends on a package that was removed on the other.
+    fn merge(&self) -> Option<LockMerge> {
+        match Lock::merge(self.ours.clone(), self.base.as_ref(), &self.theirs) {
+            Ok(merge) => Some(merge),
+            Err(err) => {
+                warn_user!(
+                    "Failed to merge conflicting changes to the lockfile; re-resolving all packages: {err}"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Read a lockfile with Git merge conflict markers.
+///
+/// Returns `None` if the lockfile doesn't exist or doesn't contain any conflict markers.
+async fn read_conflicted_lock(
+    target: LockTarget<'_>,
+) -> Result<Option<ConflictedLockfile>, ProjectError> {
+    let contents = match fs_err::tokio::read_to_string(target.lock_path()).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let Some(conflicted) = ConflictedLock::parse(&contents) else {
+        return Ok(None);
+    };
+
+    let ours = toml::from_str::<Lock>(&conflicted.ours).map_err(ProjectError::UvLockParse)?;
+    let base = conflicted
+        .base
+        .as_deref()
+        .map(toml::from_str::<Lock>)
+        .transpose()
+        .map_err(ProjectError::UvLockParse)?;
+    let theirs = toml::from_str::<Lock>(&conflicted.theirs).map_err(ProjectError::UvLockParse)?;
+
+    Ok(Some(ConflictedLockfile { ours, base, theirs }))
+}
+
+/// Merge two versions of a lockfile, for use as a Git merge driver.
+///
+/// Writes the merged lockfile to `current`. If both versions changed the same package, writes
+/// both versions with conflict markers instead, to be resolved with
+/// `uv lock --resolve-merge-conflicts`.
+pub(crate) async fn lock_merge(
+    current: &Path,
+    base: &Path,
+    other: &Path,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let current_contents = fs_err::tokio::read_to_string(current).await?;
+    let base_contents = fs_err::tokio::read_to_string(base).await?;
+    let other_contents = fs_err::tokio::read_to_string(other).await?;
+
+    let ours = toml::from_str::<Lock>(&current_contents)
+        .with_context(|| format!("Failed to parse: `{}`", current.user_display()))?;
+    // Git provides an empty file if the versions don't share a common ancestor.
+    let ancestor = if base_contents.trim().is_empty() {
+        None
+    } else {
+        Some(
+            toml::from_str::<Lock>(&base_contents)
+                .with_context(|| format!("Failed to parse: `{}`", base.user_display()))?,
+        )
+    };
+    let theirs = toml::from_str::<Lock>(&other_contents)
+        .with_context(|| format!("Failed to parse: `{}`", other.user_display()))?;
+
+    match Lock::merge(ours, ancestor.as_ref(), &theirs) {
+        Ok(merge) if merge.conflicts().is_empty() => {
+            fs_err::tokio::write(current, merge.lock().to_toml()?).await?;
+            return Ok(ExitStatus::Success);
+        }
+        Ok(merge) => {
+            writeln!(
+                printer.stderr(),
+                "Conflicting changes to: {}",
+                merge
+                    .conflicts()
+                    .iter()
+                    .map(|name| name.accent().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Err(err) => {
+            debug!("Failed to merge lockfiles: {err}");
+            writeln!(printer.stderr(), "Conflicting changes to the lockfile")?;
+        }
+    }
+
+    // Write both versions with conflict markers, such that they can be merged with a resolution.
+    let mut conflicted = String::new();
+    conflicted.push_str("<<<<<<< ours\n");
+    conflicted.push_str(&current_contents);
+    if !base_contents.trim().is_empty() {
+        conflicted.push_str("||||||| base\n");
+        conflicted.push_str(&base_contents);
+    }
+    conflicted.push_str("=======\n");
+    conflicted.push_str(&other_contents);
+    conflicted.push_str(">>>>>>> theirs\n");
+    fs_err::tokio::write(current, conflicted).await?;
+
+    writeln!(
+        printer.stderr(),
+        "{}{} Run `{}` after merging to re-resolve the conflicting packages",
+        "hint".bold().accent(),
+        ":".bold(),
+        "uv lock --resolve-merge-conflicts".success()
+    )?;
+
+    Ok(ExitStatus::Failure)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -260,6 +460,9 @@ pub(super) enum LockMode<'env> {
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    existing: Option<Lock>,
+    plugins: Option<&'env Plugins>,
+    integrity: bool,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -288,6 +491,9 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            existing: None,
+            plugins: None,
+            integrity: false,
             settings,
             client_builder,
             state,
@@ -310,8 +516,38 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Use the given lockfile as the existing lockfile, rather than reading it from disk.
+    ///
+    /// The resulting lockfile is written to disk even if it matches the given lockfile.
+    #[must_use]
+    pub(super) fn with_existing(mut self, existing: Lock) -> Self {
+        self.existing = Some(existing);
+        self
+    }
+
+    /// Embed an integrity hash in the lockfile when writing it to disk.
+    ///
+    /// Lockfiles that already include an integrity hash retain it, regardless of this setting.
+    #[must_use]
+    pub(super) fn with_integrity(mut self, integrity: bool) -> Self {
+        self.integrity = integrity;
+        self
+    }
+
+    /// Invoke the given plugins for any new resolution, before the lockfile is written to disk.
+    #[must_use]
+    pub(super) fn with_plugins(mut self, plugins: &'env Plugins) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
+        // Allow any plugins to veto individual candidate versions during resolution.
+        let candidate_veto = self
+            .plugins
+            .and_then(|plugins| PluginCandidateVeto::from_plugins(plugins, "lock"));
+
         match self.mode {
             LockMode::Frozen => {
                 // Read the existing lockfile, but don't attempt to lock the project.
@@ -337,12 +573,13 @@ impl<'env> LockOperation<'env> {
                     self.settings,
                     self.client_builder,
                     self.state,
-                    self.logger,
+                    &*self.logger,
                     self.concurrency,
                     self.cache,
                     self.workspace_cache,
                     self.printer,
                     self.preview,
+                    candidate_veto,
                 )
                 .await?;
 
@@ -357,41 +594,106 @@ impl<'env> LockOperation<'env> {
                 Ok(result)
             }
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
-                // Read the existing lockfile.
-                let existing = match target.read().await {
-                    Ok(Some(existing)) => Some(existing),
-                    Ok(None) => None,
-                    Err(ProjectError::Lock(err)) => {
-                        warn_user!(
-                            "Failed to read existing lockfile; ignoring locked requirements: {err}"
-                        );
-                        None
-                    }
-                    Err(err) => return Err(err),
+                // Read the existing lockfile, unless it was provided.
+                let provided = self.existing.is_some();
+                let existing = match self.existing {
+                    Some(existing) => Some(existing),
+                    None => match target.read().await {
+                        Ok(Some(existing)) => Some(existing),
+                        Ok(None) => None,
+                        Err(ProjectError::Lock(err)) => {
+                            warn_user!(
+                                "Failed to read existing lockfile; ignoring locked requirements: {err}"
+                            );
+                            None
+                        }
+                        Err(err) => return Err(err),
+                    },
                 };
 
-                // Perform the lock operation.
-                let result = do_lock(
+                // Retain the integrity hash if the existing lockfile includes one.
+                let integrity = self.integrity
+                    || existing
+                        .as_ref()
+                        .is_some_and(|existing| existing.integrity().is_some());
+
+                // Perform the lock operation, using the existing lockfile (if any) as the starting
+                // point for the resolution.
+                let result = match do_lock(
                     target,
                     interpreter,
-                    existing,
-                    self.constraints,
+                    existing.clone(),
+                    self.constraints.clone(),
                     self.settings,
                     self.client_builder,
                     self.state,
-                    self.logger,
+                    &*self.logger,
                     self.concurrency,
                     self.cache,
                     self.workspace_cache,
                     self.printer,
                     self.preview,
+                    candidate_veto.clone(),
                 )
-                .await?;
+                .await
+                {
+                    // If the resolution failed while seeded from the forks in the existing lockfile,
+                    // the preserved forks may have been too restrictive. Fall back to a second, full
+                    // resolution that prefers the locked versions but discards the locked forks.
+                    // This is not an incremental repair: none of the work from the failed
+                    // resolution is reused, beyond the cached metadata. Use a forked state, since a
+                    // failed resolution can leave the in-memory index with pending tasks.
+                    Err(ProjectError::Operation(pip::operations::Error::Resolve(
+                        uv_resolver::ResolveError::NoSolution(_),
+                    ))) if existing
+                        .as_ref()
+                        .is_some_and(|lock| !lock.fork_markers().is_empty()) =>
+                    {
+                        debug!(
+                            "Failed to resolve with the forks from the existing lockfile; falling back to a full resolution without them"
+                        );
+                        let state = self.state.fork_universal();
+                        do_lock(
+                            target,
+                            interpreter,
+                            existing.map(Lock::without_fork_markers),
+                            self.constraints,
+                            self.settings,
+                            self.client_builder,
+                            &state,
+                            &*self.logger,
+                            self.concurrency,
+                            self.cache,
+                            self.workspace_cache,
+                            self.printer,
+                            self.preview,
+                            candidate_veto,
+                        )
+                        .await?
+                    }
+                    result => result?,
+                };
+
+                // Allow any plugins to veto the new resolution.
+                if let (Some(plugins), LockResult::Changed(_, lock)) = (self.plugins, &result) {
+                    plugins::dispatch(
+                        plugins,
+                        PluginEvent::Resolve,
+                        "lock",
+                        None,
+                        &PluginPackage::from_lock(lock, target.install_path())?,
+                    )
+                    .await?;
+                }
 
-                // If the lockfile changed, write it to disk.
+                // If the lockfile changed (or differs from the lockfile on disk), write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
-                    if let LockResult::Changed(_, lock) = &result {
-                        target.commit(lock).await?;
+                    match &result {
+                        LockResult::Changed(_, lock) => target.commit(lock, integrity).await?,
+                        LockResult::Unchanged(lock) if provided || self.integrity => {
+                            target.commit(lock, integrity).await?;
+                        }
+                        LockResult::Unchanged(_) => {}
                     }
                 }
 
@@ -410,12 +712,13 @@ async fn do_lock(
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
     state: &UniversalState,
-    logger: Box<dyn ResolveLogger>,
+    logger: &dyn ResolveLogger,
     concurrency: Concurrency,
     cache: &Cache,
     workspace_cache: &WorkspaceCache,
     printer: Printer,
     preview: Preview,
+    candidate_veto: Option<Arc<dyn CandidateVeto>>,
 ) -> Result<LockResult, ProjectError> {
     let start = std::time::Instant::now();
 
@@ -427,6 +730,7 @@ async fn do_lock(
         resolution,
         prerelease,
         fork_strategy,
+        allow_insecure_md5_hashes,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -440,6 +744,15 @@ async fn do_lock(
         sources,
     } = settings;
 
+    // Retain the `--as-of` cutoff recorded in the lockfile, unless a cutoff was provided
+    // explicitly, in which case the lockfile is validated against it below.
+    let exclude_newer = match existing_lock.as_ref() {
+        Some(lock) if exclude_newer.is_empty() && lock.exclude_newer().as_of => {
+            lock.exclude_newer()
+        }
+        _ => exclude_newer.clone(),
+    };
+
     if !preview.is_enabled(PreviewFeatures::EXTRA_BUILD_DEPENDENCIES)
         && !extra_build_dependencies.is_empty()
     {
@@ -462,7 +775,26 @@ async fn do_lock(
 
     // If necessary, lower the overrides and constraints.
     let requirements = target.lower(requirements, index_locations, *sources)?;
-    let overrides = target.lower(overrides, index_locations, *sources)?;
+    let (overrides, scoped): (Vec<_>, Vec<_>) = overrides
+        .into_iter()
+        .partition(|dependency| dependency.when.is_none());
+    let overrides = target.lower(
+        overrides
+            .into_iter()
+            .map(|dependency| dependency.requirement)
+            .collect(),
+        index_locations,
+        *sources,
+    )?;
+    let mut scoped_overrides = Vec::new();
+    for dependency in scoped {
+        let Some(dependent) = dependency.when else {
+            continue;
+        };
+        for requirement in target.lower(vec![dependency.requirement], index_locations, *sources)? {
+            scoped_overrides.push((dependent.clone(), requirement));
+        }
+    }
     let constraints = target.lower(constraints, index_locations, *sources)?;
     let build_constraints = target.lower(build_constraints, index_locations, *sources)?;
     let dependency_groups = dependency_groups
@@ -567,6 +899,29 @@ async fn do_lock(
         None
     };
 
+    // Collect the environment assumptions, which are applied to every environment.
+    let environment_assumptions = target.environment_assumptions();
+    if !environment_assumptions.is_true() {
+        for environment in environments
+            .map(SupportedEnvironments::as_markers)
+            .into_iter()
+            .flatten()
+            .copied()
+        {
+            if environment.is_disjoint(environment_assumptions) {
+                return match (environment.contents(), environment_assumptions.contents()) {
+                    (Some(environment), Some(environment_assumptions)) => {
+                        Err(ProjectError::DisjointEnvironmentAssumptions(
+                            environment,
+                            environment_assumptions,
+                        ))
+                    }
+                    _ => Err(ProjectError::EmptyEnvironment),
+                };
+            }
+        }
+    }
+
     // Determine the supported Python range. If no range is defined, and warn and default to the
     // current minor version.
     let requires_python = target.requires_python()?;
@@ -593,12 +948,13 @@ async fn do_lock(
         default
     };
 
-    // If any of the forks are incompatible with the Python requirement, error.
+    // If any of the forks (or the assumptions) are incompatible with the Python requirement, error.
     for environment in environments
         .map(SupportedEnvironments::as_markers)
         .into_iter()
         .flatten()
         .copied()
+        .chain(std::iter::once(environment_assumptions))
     {
         if requires_python.to_marker_tree().is_disjoint(environment) {
             return if let Some(contents) = environment.contents() {
@@ -629,10 +985,33 @@ async fn do_lock(
         }
     }
 
+    if !preview.is_enabled(PreviewFeatures::TORCH_BACKEND) && target.torch_backend().is_some() {
+        warn_user_once!(
+            "The `torch-backend` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::TORCH_BACKEND
+        );
+    }
+
+    // Determine the PyTorch backend.
+    let torch_backend = target
+        .torch_backend()
+        .map(|mode| {
+            let source = if uv_auth::PyxTokenStore::from_settings()
+                .is_ok_and(|store| store.has_credentials())
+            {
+                TorchSource::Pyx
+            } else {
+                TorchSource::default()
+            };
+            TorchStrategy::from_mode(mode, source, interpreter.platform().os())
+        })
+        .transpose()?;
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(client_builder, cache.clone())
         .index_locations(index_locations.clone())
         .index_strategy(*index_strategy)
+        .torch_backend(torch_backend.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -657,8 +1036,10 @@ async fn do_lock(
         .fork_strategy(*fork_strategy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
+        .torch_backend(torch_backend)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
+        .allow_insecure_md5_hashes(*allow_insecure_md5_hashes)
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -735,10 +1116,12 @@ async fn do_lock(
             &dependency_groups,
             &constraints,
             &overrides,
+            &scoped_overrides,
             &build_constraints,
             &conflicts,
             environments,
             required_environments,
+            environment_assumptions,
             dependency_metadata,
             interpreter,
             &requires_python,
@@ -812,7 +1195,7 @@ async fn do_lock(
             // `preferences-dependent-forking` packse scenario). To avoid this, we store the forks in the
             // lockfile. We read those after all the lockfile filters, to allow the forks to change when
             // the environment changed, e.g. the python bound check above can lead to different forking.
-            let resolver_env = ResolverEnvironment::universal(
+            let resolver_env = ResolverEnvironment::universal(assume_environments(
                 forks_lock
                     .map(|lock| {
                         lock.fork_markers()
@@ -827,7 +1210,8 @@ async fn do_lock(
                             .map(SupportedEnvironments::into_markers)
                             .unwrap_or_default()
                     }),
-            );
+                environment_assumptions,
+            ));
 
             // Resolve the requirements.
             let resolution = pip::operations::resolve(
@@ -857,6 +1241,7 @@ async fn do_lock(
                     .cloned()
                     .map(UnresolvedRequirementSpecification::from)
                     .collect(),
+                scoped_overrides.clone(),
                 source_trees,
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
@@ -879,6 +1264,7 @@ async fn do_lock(
                 &build_dispatch,
                 concurrency,
                 options,
+                candidate_veto,
                 Box::new(SummaryResolveLogger),
                 printer,
             )
@@ -895,6 +1281,7 @@ async fn do_lock(
                 requirements,
                 constraints,
                 overrides,
+                scoped_overrides,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata.values().cloned(),
@@ -916,13 +1303,34 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
-                );
+                )
+                .with_environment_assumptions(environment_assumptions);
 
             Ok(LockResult::Changed(previous, lock))
         }
     }
 }
 
+/// Restrict the initial forks of a universal resolution to the environment assumptions.
+fn assume_environments(
+    forks: Vec<MarkerTree>,
+    environment_assumptions: MarkerTree,
+) -> Vec<MarkerTree> {
+    if environment_assumptions.is_true() {
+        return forks;
+    }
+    if forks.is_empty() {
+        return vec![environment_assumptions];
+    }
+    forks
+        .into_iter()
+        .map(|mut fork| {
+            fork.and(environment_assumptions);
+            fork
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
@@ -949,10 +1357,12 @@ impl ValidatedLock {
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         constraints: &[Requirement],
         overrides: &[Requirement],
+        scoped_overrides: &[(PackageName, Requirement)],
         build_constraints: &[Requirement],
         conflicts: &Conflicts,
         environments: Option<&SupportedEnvironments>,
         required_environments: Option<&SupportedEnvironments>,
+        environment_assumptions: MarkerTree,
         dependency_metadata: &DependencyMetadata,
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
@@ -969,8 +1379,8 @@ impl ValidatedLock {
             let _ = writeln!(
                 printer.stderr(),
                 "Ignoring existing lockfile due to change in resolution mode: `{}` vs. `{}`",
-                lock.resolution_mode().cyan(),
-                options.resolution_mode.cyan()
+                lock.resolution_mode().accent(),
+                options.resolution_mode.accent()
             );
             return Ok(Self::Unusable(lock));
         }
@@ -978,8 +1388,8 @@ impl ValidatedLock {
             let _ = writeln!(
                 printer.stderr(),
                 "Resolving despite existing lockfile due to change in pre-release mode: `{}` vs. `{}`",
-                lock.prerelease_mode().cyan(),
-                options.prerelease_mode.cyan()
+                lock.prerelease_mode().accent(),
+                options.prerelease_mode.accent()
             );
             return Ok(Self::Preferable(lock));
         }
@@ -987,8 +1397,8 @@ impl ValidatedLock {
             let _ = writeln!(
                 printer.stderr(),
                 "Ignoring existing lockfile due to change in fork strategy: `{}` vs. `{}`",
-                lock.fork_strategy().cyan(),
-                options.fork_strategy.cyan()
+                lock.fork_strategy().accent(),
+                options.fork_strategy.accent()
             );
             return Ok(Self::Unusable(lock));
         }
@@ -1005,8 +1415,8 @@ impl ValidatedLock {
                 let _ = writeln!(
                     printer.stderr(),
                     "Ignoring existing lockfile due to change in timestamp cutoff: `{}` vs. `{}`",
-                    lock_exclude_newer.cyan(),
-                    options_exclude_newer.cyan()
+                    lock_exclude_newer.accent(),
+                    options_exclude_newer.accent()
                 );
                 return Ok(Self::Unusable(lock));
             }
@@ -1014,7 +1424,7 @@ impl ValidatedLock {
                 let _ = writeln!(
                     printer.stderr(),
                     "Ignoring existing lockfile due to removal of timestamp cutoff: `{}`",
-                    lock_exclude_newer.cyan(),
+                    lock_exclude_newer.accent(),
                 );
                 return Ok(Self::Unusable(lock));
             }
@@ -1022,7 +1432,7 @@ impl ValidatedLock {
                 let _ = writeln!(
                     printer.stderr(),
                     "Ignoring existing lockfile due to addition of timestamp cutoff: `{}`",
-                    options_exclude_newer.cyan()
+                    options_exclude_newer.accent()
                 );
                 return Ok(Self::Unusable(lock));
             }
@@ -1133,6 +1543,16 @@ impl ValidatedLock {
             return Ok(Self::Versions(lock));
         }
 
+        // If the environment assumptions have changed, we have to perform a clean resolution.
+        if lock.environment_assumptions() != environment_assumptions {
+            debug!(
+                "Resolving despite existing lockfile due to change in environment assumptions: `{:?}` vs. `{:?}`",
+                lock.environment_assumptions(),
+                environment_assumptions
+            );
+            return Ok(Self::Versions(lock));
+        }
+
         // If the conflicting group config has changed, we have to perform a clean resolution.
         if conflicts != lock.conflicts() {
             debug!(
@@ -1150,7 +1570,22 @@ impl ValidatedLock {
         // However, if _no_ indexes were provided, we assume that the user wants to reuse the existing
         // distributions, even though a failure to reuse the lockfile will result in re-resolving
         // against PyPI by default.
-        let indexes = if index_locations.is_none() {
+        //
+        // If a PyTorch backend is set, the lockfile must have been resolved against its indexes, as
+        // the backend may differ from that of the machine on which the lockfile was generated.
+        let torch_index_locations;
+        let indexes = if let Some(torch_backend) = &options.torch_backend {
+            torch_index_locations = index_locations.clone().combine(
+                torch_backend
+                    .index_urls()
+                    .cloned()
+                    .map(Index::from_extra_index_url)
+                    .collect(),
+                Vec::new(),
+                false,
+            );
+            Some(&torch_index_locations)
+        } else if index_locations.is_none() {
             None
         } else {
             Some(index_locations)
@@ -1166,6 +1601,7 @@ impl ValidatedLock {
                 requirements,
                 constraints,
                 overrides,
+                scoped_overrides,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
@@ -1257,6 +1693,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedScopedOverrides(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched scoped overrides:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MismatchedBuildConstraints(expected, actual) => {
                 debug!(
                     "Resolving despite existing lockfile due to mismatched build constraints:\n  Requested: {:?}\n  Existing: {:?}",
@@ -1485,7 +1928,7 @@ impl std::fmt::Display for LockEvent<'_> {
                     } else {
                         "Updated"
                     }
-                    .green()
+                    .success()
                     .bold()
                 )
             }
@@ -1500,7 +1943,7 @@ impl std::fmt::Display for LockEvent<'_> {
                     f,
                     "{} {name} {new_versions}",
                     if dry_run.enabled() { "Add" } else { "Added" }
-                        .green()
+                        .success()
                         .bold()
                 )
             }
@@ -1519,7 +1962,7 @@ impl std::fmt::Display for LockEvent<'_> {
                     } else {
                         "Removed"
                     }
-                    .red()
+                    .error()
                     .bold()
                 )
             }
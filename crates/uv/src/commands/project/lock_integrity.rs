@@ -0,0 +1,82 @@
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use uv_resolver::Lock;
+
+/// Compute the integrity hash of a lockfile.
+///
+/// The hash covers the lockfile as read by uv, rather than its text: the resolution inputs (the
+/// supported Python versions, the resolver options, and the workspace manifest) along with the
+/// resolved packages. Any existing integrity hash is excluded from the hashed contents, such that
+/// the hash can be computed both before it's embedded and when verifying a lockfile on disk.
+pub(crate) fn digest(lock: &Lock) -> Result<String, toml_edit::ser::Error> {
+    let encoded = lock.clone().with_integrity(None).to_toml()?;
+    Ok(format!("sha256:{:x}", Sha256::digest(encoded.as_bytes())))
+}
+
+/// Initialize an HMAC-SHA256 for the given key.
+fn mac(key: &str) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length")
+}
+
+/// Compute the detached signature for an integrity hash.
+///
+/// The signature is an HMAC-SHA256 of the integrity hash, keyed with the given secret, such that
+/// only holders of the key can produce a valid signature for a given lockfile.
+pub(crate) fn sign(integrity: &str, key: &str) -> String {
+    let mut mac = mac(key);
+    mac.update(integrity.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Returns `true` if the given signature is valid for the integrity hash.
+pub(crate) fn verify(integrity: &str, key: &str, signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+    let mut mac = mac(key);
+    mac.update(integrity.as_bytes());
+    // Compare in constant time, to avoid leaking the expected signature.
+    mac.verify_slice(&signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_resolver::Lock;
+
+    use super::*;
+
+    #[test]
+    fn digest_excludes_integrity() {
+        let lock = toml::from_str::<Lock>("version = 1\nrequires-python = \">=3.12\"\n").unwrap();
+        let embedded = toml::from_str::<Lock>(
+            "version = 1\nintegrity = \"sha256:abc\"\nrequires-python = \">=3.12\"\n",
+        )
+        .unwrap();
+        assert_eq!(digest(&lock).unwrap(), digest(&embedded).unwrap());
+
+        // Formatting isn't covered by the hash, but the resolution inputs are.
+        let reformatted =
+            toml::from_str::<Lock>("version = 1\n\nrequires-python = '>=3.12'\n").unwrap();
+        assert_eq!(digest(&lock).unwrap(), digest(&reformatted).unwrap());
+        let modified =
+            toml::from_str::<Lock>("version = 1\nrequires-python = \">=3.11\"\n").unwrap();
+        assert_ne!(digest(&lock).unwrap(), digest(&modified).unwrap());
+    }
+
+    #[test]
+    fn sign_hmac_sha256() {
+        // See: https://datatracker.ietf.org/doc/html/rfc4231#section-4.3
+        assert_eq!(
+            sign("what do ya want for nothing?", "Jefe"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(verify(
+            "what do ya want for nothing?",
+            "Jefe",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843\n"
+        ));
+        assert!(!verify("what do ya want for nothing?", "Jefe", "5bdcc146"));
+        assert!(!verify("what do ya want for nothing?", "Jefe", "not hex"));
+    }
+}
@@ -2,18 +2,23 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use itertools::Either;
+use tracing::debug;
 
 use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
-use uv_pep508::RequirementOrigin;
-use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pep508::{MarkerTree, RequirementOrigin};
+use uv_pypi_types::{Conflicts, OverrideDependency, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
 use uv_scripts::Pep723Script;
+use uv_static::EnvVars;
+use uv_torch::TorchMode;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
 use uv_workspace::{Editability, Workspace, WorkspaceMember};
 
+use crate::commands::project::lock_integrity;
 use crate::commands::project::{ProjectError, find_requires_python};
 
 /// A target that can be resolved into a lockfile.
@@ -46,7 +51,7 @@ impl<'lock> LockTarget<'lock> {
     }
 
     /// Returns the set of overrides for the [`LockTarget`].
-    pub(crate) fn overrides(self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
+    pub(crate) fn overrides(self) -> Vec<OverrideDependency> {
         match self {
             Self::Workspace(workspace) => workspace.overrides(),
             Self::Script(script) => script
@@ -188,6 +193,35 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the environment assumptions for the [`LockTarget`], combined into a single marker.
+    pub(crate) fn environment_assumptions(self) -> MarkerTree {
+        match self {
+            Self::Workspace(workspace) => {
+                let mut environment_assumptions = MarkerTree::TRUE;
+                for marker in workspace
+                    .environment_assumptions()
+                    .into_iter()
+                    .flat_map(SupportedEnvironments::iter)
+                {
+                    environment_assumptions.and(*marker);
+                }
+                environment_assumptions
+            }
+            Self::Script(_) => {
+                // TODO(charlie): Add support for environments in scripts.
+                MarkerTree::TRUE
+            }
+        }
+    }
+
+    /// Returns the PyTorch backend for the [`LockTarget`], if any.
+    pub(crate) fn torch_backend(self) -> Option<TorchMode> {
+        match self {
+            Self::Workspace(workspace) => workspace.torch_backend(),
+            Self::Script(_) => None,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Conflicts {
         match self {
@@ -266,6 +300,13 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Return the path to the detached signature for the lockfile (e.g., `uv.lock.sig`).
+    pub(crate) fn signature_path(self) -> PathBuf {
+        let mut path = self.lock_path().into_os_string();
+        path.push(".sig");
+        PathBuf::from(path)
+    }
+
     /// Read the lockfile from the workspace.
     ///
     /// Returns `Ok(None)` if the lockfile does not exist.
@@ -314,9 +355,90 @@ impl<'lock> LockTarget<'lock> {
     }
 
     /// Write the lockfile to disk.
-    pub(crate) async fn commit(self, lock: &Lock) -> Result<(), ProjectError> {
-        let encoded = lock.to_toml()?;
+    ///
+    /// If `integrity` is set (or the lockfile already includes an integrity hash), the hash is
+    /// recomputed and embedded in the lockfile. If a signing key is configured, a detached
+    /// signature for the hash is written alongside the lockfile.
+    pub(crate) async fn commit(self, lock: &Lock, integrity: bool) -> Result<(), ProjectError> {
+        if !integrity && lock.integrity().is_none() {
+            let encoded = lock.to_toml()?;
+            fs_err::tokio::write(self.lock_path(), encoded).await?;
+            return Ok(());
+        }
+
+        let digest = lock_integrity::digest(lock)?;
+        let encoded = lock
+            .clone()
+            .with_integrity(Some(digest.clone()))
+            .to_toml()?;
         fs_err::tokio::write(self.lock_path(), encoded).await?;
+
+        if let Some(key) = std::env::var(EnvVars::UV_LOCK_SIGNING_KEY)
+            .ok()
+            .filter(|key| !key.is_empty())
+        {
+            let signature = lock_integrity::sign(&digest, &key);
+            fs_err::tokio::write(self.signature_path(), format!("{signature}\n")).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Verify the integrity hash (and, if a signing key is configured, the detached signature) of
+    /// the lockfile on disk.
+    pub(crate) async fn verify_signature(self) -> Result<(), ProjectError> {
+        let encoded = match fs_err::tokio::read_to_string(self.lock_path()).await {
+            Ok(encoded) => encoded,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(ProjectError::MissingLockfile);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let lock = toml::from_str::<Lock>(&encoded).map_err(ProjectError::UvLockParse)?;
+
+        // Verify that the lockfile matches its integrity hash.
+        let Some(expected) = lock.integrity() else {
+            return Err(ProjectError::MissingLockIntegrity);
+        };
+        let actual = lock_integrity::digest(&lock)?;
+        if expected != actual {
+            return Err(ProjectError::LockIntegrityMismatch(
+                expected.to_string(),
+                actual,
+            ));
+        }
+
+        // Verify the detached signature, if a signing key is configured.
+        let Some(key) = std::env::var(EnvVars::UV_LOCK_SIGNING_KEY)
+            .ok()
+            .filter(|key| !key.is_empty())
+        else {
+            debug!(
+                "No `{}` provided; skipping lockfile signature verification",
+                EnvVars::UV_LOCK_SIGNING_KEY
+            );
+            return Ok(());
+        };
+        let signature_path = self.signature_path();
+        let signature = match fs_err::tokio::read_to_string(&signature_path).await {
+            Ok(signature) => signature,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(ProjectError::MissingLockSignature(
+                    signature_path.user_display().to_string(),
+                ));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if !lock_integrity::verify(expected, &key, &signature) {
+            return Err(ProjectError::InvalidLockSignature(
+                signature_path.user_display().to_string(),
+            ));
+        }
+
+        debug!(
+            "Verified lockfile signature at: {}",
+            signature_path.display()
+        );
         Ok(())
     }
 
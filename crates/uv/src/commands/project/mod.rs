@@ -18,10 +18,10 @@ use uv_configuration::{
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
 use uv_distribution_types::{
-    ExtraBuildRequirement, ExtraBuildRequires, Index, Requirement, RequiresPython, Resolution,
-    UnresolvedRequirement, UnresolvedRequirementSpecification,
+    ExtraBuildRequirement, ExtraBuildRequires, Index, IndexName, PackageNamePattern, Requirement,
+    RequiresPython, Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
-use uv_fs::{CWD, LockedFile, Simplified};
+use uv_fs::{CWD, LockedFile, Quarantine, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DEV_DEPENDENCIES, DefaultGroups, ExtraName, GroupName, PackageName};
@@ -30,10 +30,12 @@ use uv_pep508::MarkerTreeContents;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts};
 use uv_python::{
-    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest, satisfies_python_preference,
+    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PyVenvConfiguration,
+    PythonDownloads, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
+    PythonSource, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
+    satisfies_python_preference,
 };
+use uv_redacted::DisplaySafeUrl;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
@@ -56,22 +58,28 @@ use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettings};
 
 pub(crate) mod add;
+pub(crate) mod bundle;
+pub(crate) mod diff;
+pub(crate) mod edit;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod format;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
+mod lock_integrity;
 mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
+pub(crate) mod task_graph;
 pub(crate) mod tree;
 pub(crate) mod version;
+pub(crate) mod watch;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
@@ -85,6 +93,24 @@ pub(crate) enum ProjectError {
     )]
     MissingLockfile,
 
+    #[error(
+        "The lockfile at `uv.lock` does not include an integrity hash. To add one, run `uv lock --integrity`."
+    )]
+    MissingLockIntegrity,
+
+    #[error(
+        "The lockfile at `uv.lock` does not match its integrity hash (expected `{0}`, found `{1}`). The lockfile may have been modified outside of uv."
+    )]
+    LockIntegrityMismatch(String, String),
+
+    #[error(
+        "Unable to find lockfile signature at `{0}`. To sign the lockfile, set `UV_LOCK_SIGNING_KEY` and run `uv lock --integrity`."
+    )]
+    MissingLockSignature(String),
+
+    #[error("The lockfile signature at `{0}` does not match the lockfile")]
+    InvalidLockSignature(String),
+
     #[error(
         "The lockfile at `uv.lock` uses an unsupported schema version (v{1}, but only v{0} is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`."
     )]
@@ -108,6 +134,26 @@ pub(crate) enum ProjectError {
     )]
     LockedPlatformIncompatibility(String),
 
+    #[error(
+        "The current Python implementation ({0}) is not supported by the lockfile, which has no source distribution or compatible wheel for: {1}"
+    )]
+    LockedImplementationIncompatibility(String, String),
+
+    #[error(
+        "Package `{0}` is locked to index `{1}`, but packages matching the index namespace `{2}` must come from index `{3}`"
+    )]
+    LockedIndexNamespaceMismatch(PackageName, DisplaySafeUrl, PackageNamePattern, IndexName),
+
+    #[error(
+        "Package `{0}` is locked to a source other than an index, but packages matching the index namespace `{1}` must come from index `{2}`"
+    )]
+    LockedIndexNamespaceSource(PackageName, PackageNamePattern, IndexName),
+
+    #[error(
+        "Package `{0}` matches the index namespace `{1}`, which is pinned to index `{2}`, but no index named `{2}` is defined"
+    )]
+    MissingIndexNamespaceIndex(PackageName, PackageNamePattern, IndexName),
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -173,7 +219,7 @@ pub(crate) enum ProjectError {
     #[error("PEP 723 scripts do not support optional dependencies, but extra `{0}` was specified")]
     MissingExtraScript(ExtraName),
 
-    #[error("Supported environments must be disjoint, but the following markers overlap: `{0}` and `{1}`.\n\n{hint}{colon} replace `{1}` with `{2}`.", hint = "hint".bold().cyan(), colon = ":".bold())]
+    #[error("Supported environments must be disjoint, but the following markers overlap: `{0}` and `{1}`.\n\n{hint}{colon} replace `{1}` with `{2}`.", hint = "hint".bold().accent(), colon = ":".bold())]
     OverlappingMarkers(String, String, String),
 
     #[error("Environment markers `{0}` don't overlap with Python requirement `{1}`")]
@@ -188,6 +234,9 @@ pub(crate) enum ProjectError {
     #[error("Environment marker is empty")]
     EmptyEnvironment,
 
+    #[error("Environment markers `{0}` don't overlap with the environment assumptions `{1}`")]
+    DisjointEnvironmentAssumptions(MarkerTreeContents, MarkerTreeContents),
+
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
@@ -200,6 +249,12 @@ pub(crate) enum ProjectError {
     #[error("Failed to update `pyproject.toml`")]
     PyprojectTomlUpdate,
 
+    #[error("`{0}` was modified by another process since it was read")]
+    ConcurrentModification(String),
+
+    #[error("Failed to update `{0}`: the changes overlap with concurrent modifications")]
+    ConflictingModification(String),
+
     #[error("Failed to parse PEP 723 script metadata")]
     Pep723ScriptTomlParse(#[source] toml::de::Error),
 
@@ -269,6 +324,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     RetryParsing(#[from] uv_client::RetryParsingError),
 
+    #[error("Failed to detect the PyTorch backend")]
+    Accelerator(#[from] uv_torch::AcceleratorError),
+
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
@@ -394,6 +452,12 @@ impl UniversalState {
     pub(crate) fn fork(&self) -> PlatformState {
         PlatformState(self.0.fork())
     }
+
+    /// Fork the [`UniversalState`] to create a fresh [`UniversalState`] (e.g., to retry a failed
+    /// universal resolution).
+    pub(crate) fn fork_universal(&self) -> Self {
+        Self(self.0.fork())
+    }
 }
 
 /// A [`SharedState`] instance to use for platform-specific resolution.
@@ -592,7 +656,7 @@ impl ScriptInterpreter {
             let entry = match script {
                 // For local scripts, use a hash of the path to the script.
                 Pep723ItemRef::Script(script) => {
-                    let digest = cache_digest(&script.path);
+                    let digest = cache_digest(&uv_fs::canonicalize_key(&script.path));
                     if let Some(file_name) = script
                         .path
                         .file_stem()
@@ -690,7 +754,7 @@ impl ScriptInterpreter {
                     Err(err) if keep_incompatible => {
                         warn_user!(
                             "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
-                            root.user_display().cyan(),
+                            root.user_display().accent(),
                         );
                         return Ok(Self::Environment(venv));
                     }
@@ -755,7 +819,10 @@ impl ScriptInterpreter {
         match script {
             Pep723ItemRef::Script(script) => {
                 LockedFile::acquire(
-                    std::env::temp_dir().join(format!("uv-{}.lock", cache_digest(&script.path))),
+                    std::env::temp_dir().join(format!(
+                        "uv-{}.lock",
+                        cache_digest(&uv_fs::canonicalize_key(&script.path))
+                    )),
                     script.path.simplified_display(),
                 )
                 .await
@@ -912,73 +979,84 @@ impl ProjectInterpreter {
         )
         .await?;
 
-        // Read from the virtual environment first.
+        // Read from the virtual environment first, unless its base interpreter no longer exists.
         let root = workspace.venv(active);
-        match PythonEnvironment::from_root(&root, cache) {
-            Ok(venv) => {
-                match environment_is_usable(
-                    &venv,
-                    EnvironmentKind::Project,
-                    python_request.as_ref(),
-                    python_preference,
-                    requires_python.as_ref(),
-                    cache,
-                ) {
-                    Ok(()) => return Ok(Self::Environment(venv)),
-                    Err(err) if keep_incompatible => {
-                        warn_user!(
-                            "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
-                            root.user_display().cyan(),
-                        );
-                        return Ok(Self::Environment(venv));
-                    }
-                    Err(err) => {
-                        debug!("{err}");
+        if let Some(home) = PyVenvConfiguration::parse(root.join("pyvenv.cfg"))
+            .ok()
+            .and_then(|cfg| cfg.missing_home().map(Path::to_path_buf))
+        {
+            warn_user!(
+                "Ignoring existing virtual environment linked to non-existent Python interpreter: {} -> {}",
+                root.user_display().accent(),
+                home.user_display().accent(),
+            );
+        } else {
+            match PythonEnvironment::from_root(&root, cache) {
+                Ok(venv) => {
+                    match environment_is_usable(
+                        &venv,
+                        EnvironmentKind::Project,
+                        python_request.as_ref(),
+                        python_preference,
+                        requires_python.as_ref(),
+                        cache,
+                    ) {
+                        Ok(()) => return Ok(Self::Environment(venv)),
+                        Err(err) if keep_incompatible => {
+                            warn_user!(
+                                "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
+                                root.user_display().accent(),
+                            );
+                            return Ok(Self::Environment(venv));
+                        }
+                        Err(err) => {
+                            debug!("{err}");
+                        }
                     }
                 }
-            }
-            Err(uv_python::Error::MissingEnvironment(_)) => {}
-            Err(uv_python::Error::InvalidEnvironment(inner)) => {
-                // If there's an invalid environment with existing content, we error instead of
-                // deleting it later on
-                match inner.kind {
-                    InvalidEnvironmentKind::NotDirectory => {
-                        return Err(ProjectError::InvalidProjectEnvironmentDir(
-                            root,
-                            inner.kind.to_string(),
-                        ));
-                    }
-                    InvalidEnvironmentKind::MissingExecutable(_) => {
-                        // If it's not an empty directory
-                        if fs_err::read_dir(&root).is_ok_and(|mut dir| dir.next().is_some()) {
-                            // ... and there's no `pyvenv.cfg`
-                            if !root.join("pyvenv.cfg").try_exists().unwrap_or_default() {
-                                // ... then it's not a valid Python environment
-                                return Err(ProjectError::InvalidProjectEnvironmentDir(
+                Err(uv_python::Error::MissingEnvironment(_)) => {}
+                Err(uv_python::Error::InvalidEnvironment(inner)) => {
+                    // If there's an invalid environment with existing content, we error instead of
+                    // deleting it later on
+                    match inner.kind {
+                        InvalidEnvironmentKind::NotDirectory => {
+                            return Err(ProjectError::InvalidProjectEnvironmentDir(
+                                root,
+                                inner.kind.to_string(),
+                            ));
+                        }
+                        InvalidEnvironmentKind::MissingExecutable(_) => {
+                            // If it's not an empty directory
+                            if fs_err::read_dir(&root).is_ok_and(|mut dir| dir.next().is_some()) {
+                                // ... and there's no `pyvenv.cfg`
+                                if !root.join("pyvenv.cfg").try_exists().unwrap_or_default() {
+                                    // ... then it's not a valid Python environment
+                                    return Err(ProjectError::InvalidProjectEnvironmentDir(
                                     root,
                                     "it is not a valid Python environment (no Python executable was found)"
                                         .to_string(),
                                 ));
+                                }
                             }
+                            // Otherwise, we'll delete it
                         }
-                        // Otherwise, we'll delete it
+                        // If the environment is an empty directory, it's fine to use
+                        InvalidEnvironmentKind::Empty => {}
                     }
-                    // If the environment is an empty directory, it's fine to use
-                    InvalidEnvironmentKind::Empty => {}
                 }
+                Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(_))) => {}
+                Err(uv_python::Error::Query(uv_python::InterpreterError::BrokenSymlink(
+                    broken_symlink,
+                ))) => {
+                    let target_path = fs_err::read_link(&broken_symlink.path)?;
+                    warn_user!(
+                        "Ignoring existing virtual environment linked to non-existent Python interpreter: {} -> {}",
+                        broken_symlink.path.user_display().accent(),
+                        target_path.user_display().accent(),
+                    );
+                }
+                Err(err) => return Err(err.into()),
             }
-            Err(uv_python::Error::Query(uv_python::InterpreterError::NotFound(_))) => {}
-            Err(uv_python::Error::Query(uv_python::InterpreterError::BrokenSymlink(
-                broken_symlink,
-            ))) => {
-                let target_path = fs_err::read_link(&broken_symlink.path)?;
-                warn_user!(
-                    "Ignoring existing virtual environment linked to non-existent Python interpreter: {} -> {}",
-                    broken_symlink.path.user_display().cyan(),
-                    target_path.user_display().cyan(),
-                );
-            }
-            Err(err) => return Err(err.into()),
         }
 
         let reporter = PythonDownloadReporter::single(printer);
@@ -1008,7 +1086,7 @@ impl ProjectInterpreter {
                 printer.stderr(),
                 "Using {} {}",
                 implementation.pretty(),
-                interpreter.python_version().cyan()
+                interpreter.python_version().accent()
             )?;
         } else {
             writeln!(
@@ -1016,7 +1094,7 @@ impl ProjectInterpreter {
                 "Using {} {} interpreter at: {}",
                 implementation.pretty(),
                 interpreter.python_version(),
-                interpreter.sys_executable().user_display().cyan()
+                interpreter.sys_executable().user_display().accent()
             )?;
         }
 
@@ -1046,7 +1124,7 @@ impl ProjectInterpreter {
         LockedFile::acquire(
             std::env::temp_dir().join(format!(
                 "uv-{}.lock",
-                cache_digest(workspace.install_path())
+                cache_digest(&uv_fs::canonicalize_key(workspace.install_path()))
             )),
             workspace.install_path().simplified_display(),
         )
@@ -1390,7 +1468,7 @@ impl ProjectEnvironment {
                             writeln!(
                                 printer.stderr(),
                                 "Removed virtual environment at: {}",
-                                root.user_display().cyan()
+                                root.user_display().accent()
                             )?;
                         }
                         Err(uv_virtualenv::Error::Io(err))
@@ -1402,7 +1480,7 @@ impl ProjectEnvironment {
                 writeln!(
                     printer.stderr(),
                     "Creating virtual environment at: {}",
-                    root.user_display().cyan()
+                    root.user_display().accent()
                 )?;
 
                 let environment = uv_virtualenv::create_venv(
@@ -1584,7 +1662,7 @@ impl ScriptEnvironment {
                     Ok(()) => {
                         debug!(
                             "Removed virtual environment at: {}",
-                            root.user_display().cyan()
+                            root.user_display().accent()
                         );
                         true
                     }
@@ -1598,7 +1676,7 @@ impl ScriptEnvironment {
 
                 debug!(
                     "Creating script environment at: {}",
-                    root.user_display().cyan()
+                    root.user_display().accent()
                 );
 
                 let environment = uv_virtualenv::create_venv(
@@ -1695,6 +1773,7 @@ pub(crate) async fn resolve_names(
     let ResolverInstallerSettings {
         resolver:
             ResolverSettings {
+                allow_insecure_md5_hashes: _,
                 build_options,
                 config_setting,
                 config_settings_package,
@@ -1857,6 +1936,7 @@ pub(crate) async fn resolve_environment(
         resolution,
         prerelease,
         fork_strategy,
+        allow_insecure_md5_hashes: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -1876,6 +1956,7 @@ pub(crate) async fn resolve_environment(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         source_trees,
         ..
     } = spec.requirements;
@@ -1995,6 +2076,7 @@ pub(crate) async fn resolve_environment(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         source_trees,
         project,
         BTreeSet::default(),
@@ -2016,6 +2098,7 @@ pub(crate) async fn resolve_environment(
         &resolve_dispatch,
         concurrency,
         options,
+        None,
         logger,
         printer,
     )
@@ -2033,6 +2116,7 @@ pub(crate) async fn sync_environment(
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -2137,6 +2221,7 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         link_mode,
+        None,
         compile_bytecode,
         &hasher,
         tags,
@@ -2148,6 +2233,9 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        quarantine,
+        false,
+        false,
         dry_run,
         printer,
         preview,
@@ -2190,6 +2278,7 @@ pub(crate) async fn update_environment(
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: &Cache,
     workspace_cache: WorkspaceCache,
@@ -2202,6 +2291,7 @@ pub(crate) async fn update_environment(
     let ResolverInstallerSettings {
         resolver:
             ResolverSettings {
+                allow_insecure_md5_hashes: _,
                 build_options,
                 config_setting,
                 config_settings_package,
@@ -2232,6 +2322,7 @@ pub(crate) async fn update_environment(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         source_trees,
         ..
     } = spec;
@@ -2246,6 +2337,7 @@ pub(crate) async fn update_environment(
     if reinstall.is_none()
         && upgrade.is_none()
         && source_trees.is_empty()
+        && scoped_overrides.is_empty()
         && matches!(modifications, Modifications::Sufficient)
     {
         match site_packages.satisfies_spec(
@@ -2253,6 +2345,7 @@ pub(crate) async fn update_environment(
             &constraints,
             &overrides,
             InstallationStrategy::Permissive,
+            true,
             &marker_env,
             &tags,
             config_setting,
@@ -2364,6 +2457,7 @@ pub(crate) async fn update_environment(
         requirements,
         constraints,
         overrides,
+        scoped_overrides,
         source_trees,
         project,
         BTreeSet::default(),
@@ -2385,6 +2479,7 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         concurrency,
         options,
+        None,
         resolve,
         printer,
     )
@@ -2403,6 +2498,7 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
+        None,
         *compile_bytecode,
         &hasher,
         &tags,
@@ -2414,6 +2510,9 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        quarantine,
+        false,
+        false,
         dry_run,
         printer,
         preview,
@@ -2602,7 +2701,9 @@ pub(crate) fn script_specification(
             .map_ok(LoweredRequirement::into_inner)
         })
         .collect::<Result<Vec<_>, _>>()?;
-    let overrides = script
+    let mut overrides = Vec::new();
+    let mut scoped_overrides = Vec::new();
+    for dependency in script
         .metadata()
         .tool
         .as_ref()
@@ -2610,24 +2711,27 @@ pub(crate) fn script_specification(
         .and_then(|uv| uv.override_dependencies.as_ref())
         .into_iter()
         .flatten()
-        .cloned()
-        .flat_map(|requirement| {
-            LoweredRequirement::from_non_workspace_requirement(
-                requirement,
-                script_dir.as_ref(),
-                script_sources,
-                script_indexes,
-                &settings.index_locations,
-            )
-            .map_ok(LoweredRequirement::into_inner)
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+    {
+        for requirement in LoweredRequirement::from_non_workspace_requirement(
+            dependency.requirement.clone(),
+            script_dir.as_ref(),
+            script_sources,
+            script_indexes,
+            &settings.index_locations,
+        ) {
+            let requirement = requirement?.into_inner();
+            if let Some(dependent) = &dependency.when {
+                scoped_overrides.push((dependent.clone(), requirement));
+            } else {
+                overrides.push(requirement);
+            }
+        }
+    }
 
-    Ok(Some(RequirementsSpecification::from_overrides(
-        requirements,
-        constraints,
-        overrides,
-    )))
+    Ok(Some(RequirementsSpecification {
+        scoped_overrides,
+        ..RequirementsSpecification::from_overrides(requirements, constraints, overrides)
+    }))
 }
 
 /// Determine the extra build requires for a script.
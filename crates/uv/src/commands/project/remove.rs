@@ -1,7 +1,7 @@
 use std::fmt::Write;
-use std::io;
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
@@ -12,13 +12,14 @@ use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions,
 };
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_normalize::PackageName;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups};
+use uv_pep508::MarkerTree;
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_scripts::{Pep723Metadata, Pep723Script};
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{Plugins, PythonInstallMirrors};
 use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::DependencyType;
 use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
@@ -27,6 +28,7 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
+use crate::commands::project::edit::write_edited;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -35,7 +37,7 @@ use crate::commands::project::{
     default_dependency_groups,
 };
 use crate::commands::{ExitStatus, diagnostics, project};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverInstallerSettings;
 
 /// Remove one or more packages from the project requirements.
@@ -48,6 +50,7 @@ pub(crate) async fn remove(
     no_sync: bool,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
+    marker_only: bool,
     package: Option<PackageName>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -57,6 +60,9 @@ pub(crate) async fn remove(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -123,6 +129,11 @@ pub(crate) async fn remove(
     }?;
 
     for package in packages {
+        if marker_only {
+            strip_markers(&mut toml, &package, &dependency_type, printer)?;
+            continue;
+        }
+
         match dependency_type {
             DependencyType::Production => {
                 let deps = toml.remove_dependency(&package)?;
@@ -194,7 +205,7 @@ pub(crate) async fn remove(
             writeln!(
                 printer.stderr(),
                 "Updated `{}`",
-                script.path.user_display().cyan()
+                script.path.user_display().accent()
             )?;
             return Ok(ExitStatus::Success);
         }
@@ -282,13 +293,14 @@ pub(crate) async fn remove(
         }
     };
 
-    let _lock = target
-        .acquire_lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match target.acquire_lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
 
     // Determine the lock mode.
     let mode = if locked {
@@ -320,7 +332,7 @@ pub(crate) async fn remove(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     };
@@ -360,10 +372,15 @@ pub(crate) async fn remove(
         Modifications::Exact,
         None,
         (&settings).into(),
+        None,
         &client_builder,
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        false,
+        false,
+        plugins,
         concurrency,
         cache,
         WorkspaceCache::default(),
@@ -377,7 +394,7 @@ pub(crate) async fn remove(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     }
@@ -399,7 +416,7 @@ impl RemoveTarget {
     /// Write the updated content to the target.
     ///
     /// Returns `true` if the content was modified.
-    fn write(&self, content: &str) -> Result<bool, io::Error> {
+    fn write(&self, content: &str) -> Result<bool, ProjectError> {
         match self {
             Self::Script(script) => {
                 if content == script.metadata.raw {
@@ -416,7 +433,7 @@ impl RemoveTarget {
                     Ok(false)
                 } else {
                     let pyproject_path = project.root().join("pyproject.toml");
-                    fs_err::write(pyproject_path, content)?;
+                    write_edited(&pyproject_path, &project.pyproject_toml().raw, content)?;
                     Ok(true)
                 }
             }
@@ -444,6 +461,56 @@ impl RemoveTarget {
     }
 }
 
+/// Strip the markers from the given dependency, retaining the dependency itself.
+fn strip_markers(
+    toml: &mut PyProjectTomlMut,
+    name: &PackageName,
+    dependency_type: &DependencyType,
+    printer: Printer,
+) -> Result<()> {
+    let (dependency_types, location) = match dependency_type {
+        DependencyType::Production => (
+            vec![DependencyType::Production],
+            "`project.dependencies`".to_string(),
+        ),
+        DependencyType::Dev => (
+            vec![
+                DependencyType::Dev,
+                DependencyType::Group(DEV_DEPENDENCIES.clone()),
+            ],
+            "`tool.uv.dev-dependencies` or `tool.uv.dependency-groups.dev`".to_string(),
+        ),
+        DependencyType::Group(group) if group == &*DEV_DEPENDENCIES => (
+            vec![
+                DependencyType::Dev,
+                DependencyType::Group(DEV_DEPENDENCIES.clone()),
+            ],
+            "`tool.uv.dev-dependencies` or `tool.uv.dependency-groups.dev`".to_string(),
+        ),
+        DependencyType::Optional(extra) => (
+            vec![dependency_type.clone()],
+            format!("`project.optional-dependencies.{extra}`"),
+        ),
+        DependencyType::Group(group) => (
+            vec![dependency_type.clone()],
+            format!("`dependency-groups.{group}`"),
+        ),
+    };
+
+    let mut found = false;
+    for dependency_type in &dependency_types {
+        found |= toml
+            .set_dependency_marker(name, dependency_type, MarkerTree::TRUE)?
+            .is_some();
+    }
+    if !found {
+        show_other_dependency_type_hint(printer, name, toml)?;
+        anyhow::bail!("The dependency `{name}` could not be found in {location}");
+    }
+
+    Ok(())
+}
+
 /// Show a hint if a dependency with the given name is present as any dependency type.
 ///
 /// This is useful when a dependency of the user-specified type was not found, but it may be present
@@ -460,27 +527,27 @@ fn show_other_dependency_type_hint(
             DependencyType::Production => writeln!(
                 printer.stderr(),
                 "{}{} `{name}` is a production dependency",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
             )?,
             DependencyType::Dev => writeln!(
                 printer.stderr(),
                 "{}{} `{name}` is a development dependency (try: `{}`)",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
                 format!("uv remove {name} --dev`").bold()
             )?,
             DependencyType::Optional(group) => writeln!(
                 printer.stderr(),
                 "{}{} `{name}` is an optional dependency (try: `{}`)",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
                 format!("uv remove {name} --optional {group}").bold()
             )?,
             DependencyType::Group(group) => writeln!(
                 printer.stderr(),
                 "{}{} `{name}` is in the `{group}` group (try: `{}`)",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
                 format!("uv remove {name} --group {group}").bold()
             )?,
@@ -4,6 +4,7 @@ use std::ffi::OsString;
 use std::fmt::Write;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
@@ -14,7 +15,7 @@ use tokio::process::Command;
 use tracing::{debug, trace, warn};
 use url::Url;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, EphemeralDir};
 use uv_cli::ExternalCommand;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
@@ -24,7 +25,7 @@ use uv_configuration::{
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::Requirement;
 use uv_fs::which::is_executable;
-use uv_fs::{PythonExt, Simplified, create_symlink};
+use uv_fs::{PythonExt, Quarantine, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
@@ -37,7 +38,7 @@ use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{Installable, Lock, Preference};
 use uv_scripts::Pep723Item;
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{Plugins, PythonInstallMirrors};
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
@@ -63,6 +64,7 @@ use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironm
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::task_graph::{TaskGraph, TaskOutcome};
 use crate::commands::project::{
     EnvironmentSpecification, PreferenceLocation, ProjectEnvironment, ProjectError,
     ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -71,8 +73,8 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics, project};
-use crate::printer::Printer;
-use crate::settings::{ResolverInstallerSettings, ResolverSettings};
+use crate::printer::{Printer, Styled};
+use crate::settings::{ResolverInstallerSettings, ResolverSettings, RunEach};
 
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -87,8 +89,12 @@ pub(crate) async fn run(
     active: Option<bool>,
     no_sync: bool,
     isolated: bool,
+    keep: bool,
     all_packages: bool,
     package: Option<PackageName>,
+    each: Option<RunEach>,
+    profile: Option<String>,
+    env: Option<String>,
     no_project: bool,
     no_config: bool,
     extras: ExtrasSpecification,
@@ -98,11 +104,14 @@ pub(crate) async fn run(
     python: Option<String>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
-    settings: ResolverInstallerSettings,
+    mut settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
     concurrency: Concurrency,
     cache: Cache,
     printer: Printer,
@@ -120,8 +129,8 @@ pub(crate) async fn run(
 `uv run` was recursively invoked {recursion_depth} times which exceeds the limit of {max_recursion_depth}.
 
 hint: If you are running a script with `{}` in the shebang, you may need to include the `{}` flag.",
-            "uv run".green(),
-            "--script".green(),
+            "uv run".success(),
+            "--script".success(),
         );
     }
 
@@ -162,6 +171,41 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     let sync_state = lock_state.fork();
     let workspace_cache = WorkspaceCache::default();
 
+    // With `--each`, determine the workspace members to run the command in.
+    let task_graph = if let Some(each) = each {
+        if script.is_some() {
+            bail!("`--each` is not supported for Python scripts with inline metadata");
+        }
+        if matches!(
+            command,
+            Some(RunCommand::PythonStdin(..) | RunCommand::PythonGuiStdin(..))
+        ) {
+            bail!("`--each` is not supported when reading a script from stdin");
+        }
+
+        let workspace =
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?;
+        let mut task_graph = TaskGraph::from_workspace(&workspace);
+        if !each.packages.is_empty() {
+            task_graph = task_graph.select(&each.packages)?;
+        }
+        if let Some(rev) = each.affected.as_deref() {
+            task_graph = task_graph.affected(workspace.install_path(), rev)?;
+            if task_graph.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No workspace members are affected by changes since `{}`",
+                    rev.accent()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+        }
+        Some(task_graph)
+    } else {
+        None
+    };
+
     // Read from the `.env` file, if necessary.
     for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
         match dotenvy::from_path(env_file_path) {
@@ -252,13 +296,14 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .await?
             .into_environment()?;
 
-            let _lock = environment
-                .lock()
-                .await
-                .inspect_err(|err| {
+            let _lock = match environment.lock(locked_timeout).await {
+                Ok(lock) => Some(lock),
+                Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+                Err(err) => {
                     warn!("Failed to acquire environment lock: {err}");
-                })
-                .ok();
+                    None
+                }
+            };
 
             // Determine the lock mode.
             let mode = if frozen {
@@ -296,7 +341,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     )
                     .with_context("script")
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .map_err(Into::into);
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -319,6 +364,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 modifications,
                 python_platform.as_ref(),
                 (&settings).into(),
+                None,
                 &client_builder,
                 &sync_state,
                 if show_resolution {
@@ -327,6 +373,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
+                quarantine,
+                false,
+                false,
+                plugins,
                 concurrency,
                 &cache,
                 workspace_cache.clone(),
@@ -343,7 +393,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     )
                     .with_context("script")
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .map_err(Into::into);
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -358,13 +408,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             if locked {
                 warn_user!(
                     "No lockfile found for Python script (ignoring `--locked`); run `{}` to generate a lockfile",
-                    "uv lock --script".green(),
+                    "uv lock --script".success(),
                 );
             }
             if frozen {
                 warn_user!(
                     "No lockfile found for Python script (ignoring `--frozen`); run `{}` to generate a lockfile",
-                    "uv lock --script".green(),
+                    "uv lock --script".success(),
                 );
             }
 
@@ -407,13 +457,16 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         )
                     });
 
-                let _lock = environment
-                    .lock()
-                    .await
-                    .inspect_err(|err| {
+                let _lock = match environment.lock(locked_timeout).await {
+                    Ok(lock) => Some(lock),
+                    Err(err) if err.kind() == std::io::ErrorKind::TimedOut => {
+                        return Err(err.into());
+                    }
+                    Err(err) => {
                         warn!("Failed to acquire environment lock: {err}");
-                    })
-                    .ok();
+                        None
+                    }
+                };
 
                 match update_environment(
                     environment,
@@ -436,6 +489,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    quarantine,
                     concurrency,
                     &cache,
                     workspace_cache.clone(),
@@ -452,7 +506,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         )
                         .with_context("script")
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .map_err(Into::into);
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -475,7 +529,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 .await?
                 .into_interpreter();
 
-                temp_dir = cache.venv_dir()?;
+                temp_dir = create_ephemeral_dir(&cache, keep, printer)?;
                 let environment = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
@@ -596,6 +650,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             if no_sync {
                 warn_user!("`--no-sync` has no effect when used alongside `--no-project`");
             }
+            if profile.is_some() {
+                warn_user!("`--profile` has no effect when used alongside `--no-project`");
+            }
+            if env.is_some() {
+                warn_user!("`--env` has no effect when used alongside `--no-project`");
+            }
         } else if project.is_none() {
             // If we can't find a project and the user provided a project-only setting, warn.
             for flag in extras.history().as_flags_pretty() {
@@ -610,8 +670,33 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             if no_sync {
                 warn_user!("`--no-sync` has no effect when used outside of a project");
             }
+            if profile.is_some() {
+                warn_user!("`--profile` has no effect when used outside of a project");
+            }
+            if env.is_some() {
+                warn_user!("`--env` has no effect when used outside of a project");
+            }
         }
 
+        // Apply the resolution profile, if any.
+        let project = match (project, profile.as_deref()) {
+            (Some(project), Some(profile)) => {
+                settings.resolver.index_locations = settings
+                    .resolver
+                    .index_locations
+                    .clone()
+                    .prepend(project.workspace().profile_indexes(profile)?);
+                Some(project.with_profile(profile)?)
+            }
+            (project, _) => project,
+        };
+
+        // Select the named environment, if any.
+        let project = match (project, env.as_deref()) {
+            (Some(project), Some(env)) => Some(project.with_named_environment(env)?),
+            (project, _) => project,
+        };
+
         if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -624,11 +709,31 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     project.workspace().install_path().display()
                 );
             }
+            // Read the configuration of the named environment, if any.
+            let named_environment = env
+                .as_deref()
+                .and_then(|env| project.workspace().named_environment(env))
+                .cloned();
+
             // Determine the groups and extras to include.
-            let default_groups = default_dependency_groups(project.pyproject_toml())?;
-            let default_extras = DefaultExtras::default();
+            let default_groups = if let Some(groups) = named_environment
+                .as_ref()
+                .and_then(|environment| environment.groups.clone())
+            {
+                DefaultGroups::List(groups)
+            } else {
+                default_dependency_groups(project.pyproject_toml())?
+            };
+            let default_extras = named_environment
+                .as_ref()
+                .and_then(|environment| environment.extras.clone())
+                .map(DefaultExtras::List)
+                .unwrap_or_default();
             let groups = groups.with_defaults(default_groups);
             let extras = extras.with_defaults(default_extras);
+            let python = python
+                .clone()
+                .or_else(|| named_environment.and_then(|environment| environment.python));
 
             let venv = if isolated {
                 debug!("Creating isolated virtual environment");
@@ -677,7 +782,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 }
 
                 // Create a virtual environment
-                temp_dir = cache.venv_dir()?;
+                temp_dir = create_ephemeral_dir(&cache, keep, printer)?;
                 uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
@@ -728,13 +833,16 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         .map(|lock| (lock, project.workspace().install_path().to_owned()));
                 }
             } else {
-                let _lock = venv
-                    .lock()
-                    .await
-                    .inspect_err(|err| {
+                let _lock = match venv.lock(locked_timeout).await {
+                    Ok(lock) => Some(lock),
+                    Err(err) if err.kind() == std::io::ErrorKind::TimedOut => {
+                        return Err(err.into());
+                    }
+                    Err(err) => {
                         warn!("Failed to acquire environment lock: {err}");
-                    })
-                    .ok();
+                        None
+                    }
+                };
 
                 // Determine the lock mode.
                 let mode = if frozen {
@@ -772,7 +880,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                             client_builder.is_native_tls(),
                         )
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .map_err(Into::into);
                     }
                     Err(err) => return Err(err.into()),
                 };
@@ -837,6 +945,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     modifications,
                     python_platform.as_ref(),
                     (&settings).into(),
+                    None,
                     &client_builder,
                     &sync_state,
                     if show_resolution {
@@ -845,6 +954,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    quarantine,
+                    false,
+                    false,
+                    plugins,
                     concurrency,
                     &cache,
                     workspace_cache.clone(),
@@ -860,7 +973,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                             client_builder.is_native_tls(),
                         )
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .map_err(Into::into);
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -912,7 +1025,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 debug!("Creating isolated virtual environment");
 
                 // If we're isolating the environment, use an ephemeral virtual environment.
-                temp_dir = cache.venv_dir()?;
+                temp_dir = create_ephemeral_dir(&cache, keep, printer)?;
                 let venv = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
@@ -1001,6 +1114,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
+                quarantine,
                 concurrency,
                 &cache,
                 printer,
@@ -1016,7 +1130,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     )
                     .with_context("`--with`")
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .map_err(Into::into);
                 }
                 Err(err) => return Err(err.into()),
             };
@@ -1030,7 +1144,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     // poison the cache.
     let ephemeral_dir = requirements_env
         .as_ref()
-        .map(|_| cache.venv_dir())
+        .map(|_| create_ephemeral_dir(&cache, keep, printer))
         .transpose()?;
 
     let ephemeral_env = ephemeral_dir
@@ -1264,7 +1378,6 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     };
 
     debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
 
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
@@ -1296,32 +1409,125 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env(EnvVars::PATH, new_path);
 
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
+    let process = || {
+        let mut process = command.as_command(interpreter);
+        process.env(EnvVars::PATH, &new_path);
 
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
-    }
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
+
+        // Ensure `VIRTUAL_ENV` is set.
+        if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+
+        process
+    };
 
     // Unblock cache removal operations.
     drop(cache);
 
+    // Run the command in each of the selected workspace members.
+    if let Some(task_graph) = task_graph {
+        return run_each(&task_graph, &command, process, concurrency.builds, printer).await;
+    }
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
-    let handle = process
+    let handle = process()
         .spawn()
         .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
 
     run_to_completion(handle).await
 }
 
+/// Run the command from the root of each workspace member in the [`TaskGraph`], in dependency
+/// order.
+///
+/// Returns the status of the first command to fail, if any.
+async fn run_each(
+    task_graph: &TaskGraph,
+    command: &RunCommand,
+    process: impl Fn() -> Command,
+    concurrency: usize,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let outcomes = task_graph
+        .run(concurrency, |member| {
+            let mut process = process();
+            process.current_dir(member.root());
+            let name = member.name().clone();
+            async move {
+                writeln!(
+                    printer.stderr(),
+                    "Running `{}` in `{}`",
+                    command.accent(),
+                    name.accent()
+                )?;
+                let handle = process.spawn().with_context(|| {
+                    format!("Failed to spawn: `{}`", command.display_executable())
+                })?;
+                run_to_completion(handle).await
+            }
+        })
+        .await?;
+
+    let mut status = ExitStatus::Success;
+    for (name, outcome) in outcomes {
+        match outcome {
+            TaskOutcome::Completed(ExitStatus::Success) => {}
+            TaskOutcome::Completed(failure) => {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} `{}` failed in `{}`",
+                    "error".error().bold(),
+                    ":".bold(),
+                    command.accent(),
+                    name.accent()
+                )?;
+                if matches!(status, ExitStatus::Success) {
+                    status = failure;
+                }
+            }
+            TaskOutcome::Skipped(dependency) => {
+                writeln!(
+                    printer.stderr(),
+                    "Skipped `{}`, since its dependency `{}` failed",
+                    name.accent(),
+                    dependency.accent()
+                )?;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Create an ephemeral directory for a virtual environment in `uv run`.
+///
+/// With `--keep`, the directory is retained after the command exits, and its path is displayed.
+fn create_ephemeral_dir(
+    cache: &Cache,
+    keep: bool,
+    printer: Printer,
+) -> anyhow::Result<EphemeralDir> {
+    let mut dir = cache.ephemeral_dir()?;
+    if keep {
+        dir.keep();
+        writeln!(
+            printer.stderr(),
+            "Retaining ephemeral environment at: {}",
+            dir.path().user_display().cyan()
+        )?;
+    }
+    Ok(dir)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: &RequirementsSpecification,
@@ -1364,6 +1570,7 @@ fn can_skip_ephemeral(
         &spec.constraints,
         &spec.overrides,
         InstallationStrategy::Permissive,
+        true,
         &markers,
         tags,
         config_setting,
@@ -1767,7 +1974,7 @@ impl RunCommand {
 
                 // Stream the response to the file.
                 let mut writer = file.as_file();
-                let mut reader = response.bytes_stream();
+                let mut reader = client.throttle(response.bytes_stream());
                 while let Some(chunk) = reader.next().await {
                     use std::io::Write;
                     writer.write_all(&chunk?)?;
@@ -1,36 +1,42 @@
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::Serialize;
-use tracing::warn;
+use tracing::{debug, warn};
 use uv_cache::Cache;
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
     ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    TargetTriple, Upgrade,
+    Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, Name, Requirement, Resolution, ResolvedDist, SourceDist,
 };
-use uv_fs::{PortablePathBuf, Simplified};
-use uv_installer::{InstallationStrategy, SitePackages};
+use uv_fs::{PortablePathBuf, Quarantine, Simplified};
+use uv_installer::{InstallationStrategy, SitePackages, Store};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_python::{
+    ImplementationName, Interpreter, PythonDownloads, PythonEnvironment, PythonPreference,
+    PythonRequest,
+};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, Installable, Lock, LockError, PrereleaseMode, ResolutionMode,
+};
 use uv_scripts::Pep723Script;
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{PluginEvent, Plugins, PythonInstallMirrors};
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::pyproject::Source;
@@ -40,6 +46,7 @@ use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger,
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::plugins::{self, PluginPackage};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
@@ -49,7 +56,7 @@ use crate::commands::project::{
     update_environment,
 };
 use crate::commands::{ExitStatus, diagnostics};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettings};
 
 /// Sync the project environment.
@@ -58,10 +65,16 @@ pub(crate) async fn sync(
     project_dir: &Path,
     locked: bool,
     frozen: bool,
+    verify_lock_signature: bool,
+    site_packages_dir: Option<PathBuf>,
     dry_run: DryRun,
+    allow_script_conflicts: bool,
+    exclude_source: bool,
     active: Option<bool>,
     all_packages: bool,
     package: Option<PackageName>,
+    profile: Option<String>,
+    env: Option<String>,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
     editable: Option<EditableMode>,
@@ -72,10 +85,13 @@ pub(crate) async fn sync(
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
-    settings: ResolverInstallerSettings,
+    mut settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<Pep723Script>,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -91,6 +107,18 @@ pub(crate) async fn sync(
         );
     }
 
+    // Initialize the shared store, if requested.
+    let store = if let Some(site_packages_dir) = site_packages_dir {
+        if !settings.resolver.link_mode.is_symlink() {
+            return Err(anyhow::anyhow!(
+                "`--site-packages-dir` requires `--link-mode=symlink`"
+            ));
+        }
+        Some(Store::from_path(site_packages_dir))
+    } else {
+        None
+    };
+
     // Identify the target.
     let workspace_cache = WorkspaceCache::default();
     let target = if let Some(script) = script {
@@ -119,6 +147,25 @@ pub(crate) async fn sync(
                 .await?
         };
 
+        // Apply the resolution profile, if any.
+        let project = if let Some(profile) = profile.as_deref() {
+            settings.resolver.index_locations = settings
+                .resolver
+                .index_locations
+                .clone()
+                .prepend(project.workspace().profile_indexes(profile)?);
+            project.with_profile(profile)?
+        } else {
+            project
+        };
+
+        // Select the named environment, if any.
+        let project = if let Some(env) = env.as_deref() {
+            project.with_named_environment(env)?
+        } else {
+            project
+        };
+
         // TODO(lucab): improve warning content
         // <https://github.com/astral-sh/uv/issues/7428>
         if project.workspace().pyproject_toml().has_scripts()
@@ -132,15 +179,37 @@ pub(crate) async fn sync(
         SyncTarget::Project(project)
     };
 
+    // Read the configuration of the named environment, if any.
+    let named_environment = match (&target, env.as_deref()) {
+        (SyncTarget::Project(project), Some(env)) => {
+            project.workspace().named_environment(env).cloned()
+        }
+        _ => None,
+    };
+
     // Determine the groups and extras to include.
     let default_groups = match &target {
-        SyncTarget::Project(project) => default_dependency_groups(project.pyproject_toml())?,
+        SyncTarget::Project(project) => {
+            if let Some(groups) = named_environment
+                .as_ref()
+                .and_then(|environment| environment.groups.clone())
+            {
+                DefaultGroups::List(groups)
+            } else {
+                default_dependency_groups(project.pyproject_toml())?
+            }
+        }
         SyncTarget::Script(..) => DefaultGroups::default(),
     };
     let default_extras = match &target {
-        SyncTarget::Project(_project) => DefaultExtras::default(),
+        SyncTarget::Project(_project) => named_environment
+            .as_ref()
+            .and_then(|environment| environment.extras.clone())
+            .map(DefaultExtras::List)
+            .unwrap_or_default(),
         SyncTarget::Script(..) => DefaultExtras::default(),
     };
+    let python = python.or_else(|| named_environment.and_then(|environment| environment.python));
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
@@ -185,13 +254,14 @@ pub(crate) async fn sync(
         ),
     };
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
+    let _lock = match environment.lock(locked_timeout).await {
+        Ok(lock) => Some(lock),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => return Err(err.into()),
+        Err(err) => {
             warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+            None
+        }
+    };
 
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
@@ -213,14 +283,14 @@ pub(crate) async fn sync(
             if frozen {
                 return Err(anyhow::anyhow!(
                     "`uv sync --frozen` requires a script lockfile; run `{}` to lock the script",
-                    format!("uv lock --script {}", script.path.user_display()).green(),
+                    format!("uv lock --script {}", script.path.user_display()).success(),
                 ));
             }
 
             if locked {
                 return Err(anyhow::anyhow!(
                     "`uv sync --locked` requires a script lockfile; run `{}` to lock the script",
-                    format!("uv lock --script {}", script.path.user_display()).green(),
+                    format!("uv lock --script {}", script.path.user_display()).success(),
                 ));
             }
 
@@ -260,6 +330,7 @@ pub(crate) async fn sync(
                 Box::new(DefaultResolveLogger),
                 Box::new(DefaultInstallLogger),
                 installer_metadata,
+                quarantine,
                 concurrency,
                 cache,
                 workspace_cache.clone(),
@@ -291,7 +362,7 @@ pub(crate) async fn sync(
                         client_builder.is_native_tls(),
                     )
                     .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .map_err(Into::into);
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -317,6 +388,11 @@ pub(crate) async fn sync(
         SyncTarget::Script(script) => LockTarget::from(script),
     };
 
+    // If requested, verify the lockfile before updating it or installing any packages.
+    if verify_lock_signature {
+        lock_target.verify_signature().await?;
+    }
+
     let outcome = match LockOperation::new(
         mode,
         &settings.resolver,
@@ -329,6 +405,7 @@ pub(crate) async fn sync(
         printer,
         preview,
     )
+    .with_plugins(plugins)
     .execute(lock_target)
     .await
     {
@@ -336,7 +413,7 @@ pub(crate) async fn sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(ProjectError::LockMismatch(prev, cur)) => {
             if dry_run.enabled() {
@@ -391,10 +468,15 @@ pub(crate) async fn sync(
         modifications,
         python_platform.as_ref(),
         (&settings).into(),
+        store.as_ref(),
         &client_builder,
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        allow_script_conflicts,
+        exclude_source,
+        plugins,
         concurrency,
         cache,
         workspace_cache,
@@ -408,7 +490,7 @@ pub(crate) async fn sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     }
@@ -564,10 +646,15 @@ pub(super) async fn do_sync(
     modifications: Modifications,
     python_platform: Option<&TargetTriple>,
     settings: InstallerSettingsRef<'_>,
+    store: Option<&Store>,
     client_builder: &BaseClientBuilder<'_>,
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    allow_script_conflicts: bool,
+    exclude_source: bool,
+    plugins: &Plugins,
     concurrency: Concurrency,
     cache: &Cache,
     workspace_cache: WorkspaceCache,
@@ -618,6 +705,7 @@ pub(super) async fn do_sync(
         InstallTarget::Script { script, .. } => {
             // Try to get extra build dependencies from the script metadata
             let resolver_settings = ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: build_options.clone(),
                 config_setting: config_setting.clone(),
                 config_settings_package: config_settings_package.clone(),
@@ -689,18 +777,72 @@ pub(super) async fn do_sync(
         }
     }
 
+    // Validate that the platform satisfies the environment assumptions of the lockfile.
+    let environment_assumptions = target.lock().environment_assumptions();
+    if !environment_assumptions.evaluate(&marker_env, &[]) {
+        return Err(ProjectError::LockedPlatformIncompatibility(
+            environment_assumptions
+                .contents()
+                .map(|env| format!("`{env}`"))
+                .unwrap_or_default(),
+        ));
+    }
+
+    // Validate that any packages pinned by an index namespace were locked from the pinned index.
+    // Workspace members are exempt, since they're never fetched from an index.
+    for package in target.lock().packages() {
+        if target.lock().members().contains(package.name())
+            || target
+                .lock()
+                .root()
+                .is_some_and(|root| root.name() == package.name())
+        {
+            continue;
+        }
+        let Some(namespace) = index_locations.namespace_for(package.name()) else {
+            continue;
+        };
+        let Some(pinned) = namespace.index else {
+            return Err(ProjectError::MissingIndexNamespaceIndex(
+                package.name().clone(),
+                namespace.pattern.clone(),
+                namespace.name.clone(),
+            ));
+        };
+        let Some(locked) = package.index(target.install_path())? else {
+            return Err(ProjectError::LockedIndexNamespaceSource(
+                package.name().clone(),
+                namespace.pattern.clone(),
+                namespace.name.clone(),
+            ));
+        };
+        if locked.without_credentials() != pinned.url.without_credentials() {
+            return Err(ProjectError::LockedIndexNamespaceMismatch(
+                package.name().clone(),
+                locked.without_credentials().into_owned(),
+                namespace.pattern.clone(),
+                namespace.name.clone(),
+            ));
+        }
+    }
+
     // Determine the tags to use for the resolution.
     let tags = resolution_tags(None, python_platform, venv.interpreter())?;
 
     // Read the lockfile.
-    let resolution = target.to_resolution(
-        &marker_env,
-        &tags,
-        extras,
-        groups,
-        build_options,
-        &install_options,
-    )?;
+    let resolution = target
+        .to_resolution(
+            &marker_env,
+            &tags,
+            extras,
+            groups,
+            build_options,
+            &install_options,
+        )
+        .map_err(|err| {
+            implementation_incompatibility(target.lock(), venv.interpreter(), &err)
+                .unwrap_or_else(|| err.into())
+        })?;
 
     // Always skip virtual projects, which shouldn't be built or installed.
     let resolution = apply_no_virtual_project(resolution);
@@ -708,6 +850,18 @@ pub(super) async fn do_sync(
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // Allow any plugins to veto the installation.
+    if !dry_run.enabled() {
+        plugins::dispatch(
+            plugins,
+            PluginEvent::Install,
+            "sync",
+            Some(venv.root()),
+            &PluginPackage::from_resolution(&resolution),
+        )
+        .await?;
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -776,7 +930,19 @@ pub(super) async fn do_sync(
         preview,
     );
 
-    let site_packages = SitePackages::from_environment(venv)?;
+    let mut site_packages = SitePackages::from_environment(venv)?;
+
+    // If necessary, retain any existing installation of the project.
+    let resolution = if install_options.no_reinstall_project {
+        apply_no_reinstall_project(
+            resolution,
+            &mut site_packages,
+            target.project_name(),
+            reinstall,
+        )
+    } else {
+        resolution
+    };
 
     // Sync the environment.
     operations::install(
@@ -787,6 +953,7 @@ pub(super) async fn do_sync(
         reinstall,
         build_options,
         link_mode,
+        store,
         compile_bytecode,
         &hasher,
         &tags,
@@ -798,6 +965,9 @@ pub(super) async fn do_sync(
         venv,
         logger,
         installer_metadata,
+        quarantine,
+        allow_script_conflicts,
+        exclude_source,
         dry_run,
         printer,
         preview,
@@ -826,6 +996,51 @@ fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     })
 }
 
+/// Retain any existing installation of the project, rather than reinstalling it (e.g., due to a
+/// change in its metadata).
+///
+/// The project is only retained if it's installed from a local source tree with the same
+/// editability as requested, and wasn't explicitly selected via `--reinstall-package`.
+fn apply_no_reinstall_project(
+    resolution: Resolution,
+    site_packages: &mut SitePackages,
+    project_name: Option<&PackageName>,
+    reinstall: &Reinstall,
+) -> Resolution {
+    let Some(project_name) = project_name else {
+        return resolution;
+    };
+
+    if matches!(reinstall, Reinstall::Packages(..)) && reinstall.contains_package(project_name) {
+        return resolution;
+    }
+
+    // Determine whether the project is requested as an editable.
+    let Some(editable) = resolution.distributions().find_map(|dist| {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            return None;
+        };
+        let Dist::Source(SourceDist::Directory(dist)) = dist.as_ref() else {
+            return None;
+        };
+        (dist.name == *project_name).then_some(dist.editable.unwrap_or(false))
+    }) else {
+        return resolution;
+    };
+
+    let installed = site_packages.get_packages(project_name);
+    let [installed] = installed.as_slice() else {
+        return resolution;
+    };
+    if !installed.is_local() || installed.is_editable() != editable {
+        return resolution;
+    }
+
+    debug!("Retaining existing installation of `{project_name}` due to `--no-reinstall-project`");
+    site_packages.remove_packages(project_name);
+    resolution.filter(|dist| dist.name() != project_name)
+}
+
 /// If necessary, convert any editable requirements to non-editable.
 fn apply_editable_mode(resolution: Resolution, editable: Option<EditableMode>) -> Resolution {
     match editable {
@@ -1239,7 +1454,7 @@ impl SyncReport {
 
         let message = format!(
             "{action} {target} environment at: {path}",
-            path = environment.path.user_display().cyan(),
+            path = environment.path.user_display().accent(),
         );
         if *dry_run {
             return Some(message.dimmed().to_string());
@@ -1308,7 +1523,7 @@ impl LockReport {
 
         let message = format!(
             "{action} lockfile at: {path}",
-            path = path.user_display().cyan(),
+            path = path.user_display().accent(),
         );
         if *dry_run {
             return Some(message.dimmed().to_string());
@@ -1326,3 +1541,48 @@ impl Report {
         }
     }
 }
+
+/// If a package can't be installed because the lockfile has no wheel for the current Python
+/// implementation (e.g., PyPy) and no source distribution, return an error that lists every such
+/// package in the lockfile, rather than only the first.
+fn implementation_incompatibility(
+    lock: &Lock,
+    interpreter: &Interpreter,
+    err: &LockError,
+) -> Option<ProjectError> {
+    let name = err.missing_wheel()?;
+    let implementation = interpreter
+        .implementation_name()
+        .parse::<ImplementationName>()
+        .ok()?;
+    let unsupported = lock.unsupported_packages(implementation);
+    if !unsupported
+        .iter()
+        .any(|(package, _)| package.name() == name)
+    {
+        return None;
+    }
+    let packages = unsupported
+        .into_iter()
+        .map(|(package, implementations)| {
+            let supported = implementations
+                .into_iter()
+                .map(ImplementationName::pretty)
+                .join(", ");
+            let package = if let Some(version) = package.version() {
+                format!("`{}=={version}`", package.name())
+            } else {
+                format!("`{}`", package.name())
+            };
+            if supported.is_empty() {
+                package
+            } else {
+                format!("{package} (wheels for {supported})")
+            }
+        })
+        .join(", ");
+    Some(ProjectError::LockedImplementationIncompatibility(
+        implementation.pretty().to_string(),
+        packages,
+    ))
+}
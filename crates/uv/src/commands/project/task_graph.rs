@@ -0,0 +1,546 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use itertools::Itertools;
+use tracing::debug;
+
+use uv_fs::normalize_path;
+use uv_git::GIT;
+use uv_normalize::PackageName;
+use uv_pep508::{Requirement, VerbatimUrl};
+use uv_pypi_types::DependencyGroupSpecifier;
+use uv_workspace::pyproject::{Source, ToolUvSources};
+use uv_workspace::{Workspace, WorkspaceMember};
+
+use crate::commands::ExitStatus;
+
+/// A workspace member in a [`TaskGraph`].
+#[derive(Debug, Clone)]
+pub(crate) struct TaskMember {
+    /// The name of the member.
+    name: PackageName,
+    /// The root directory of the member.
+    root: PathBuf,
+    /// The workspace members that this member depends on.
+    dependencies: BTreeSet<PackageName>,
+    /// The paths of the (non-member) path dependencies that this member depends on.
+    paths: Vec<PathBuf>,
+}
+
+impl TaskMember {
+    /// The name of the member.
+    pub(crate) fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// The root directory of the member.
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+/// The outcome of running a task in a workspace member.
+#[derive(Debug)]
+pub(crate) enum TaskOutcome {
+    /// The task ran to completion with the given status.
+    Completed(ExitStatus),
+    /// The task was skipped, as it depends on a member whose task failed.
+    Skipped(PackageName),
+}
+
+/// A graph of workspace members, used to run a task in each member in dependency order.
+///
+/// A member depends on another member if it lists it in its `project.dependencies`,
+/// `project.optional-dependencies`, or `dependency-groups`. Dependencies with a `path` source in
+/// `tool.uv.sources` (in the member or the workspace root) are tracked by path.
+#[derive(Debug, Clone)]
+pub(crate) struct TaskGraph {
+    members: BTreeMap<PackageName, TaskMember>,
+}
+
+/// Return the names of the direct dependencies of a workspace member, as declared in its
+/// `project.dependencies`, `project.optional-dependencies`, and `dependency-groups`.
+pub(crate) fn direct_dependencies(
+    name: &PackageName,
+    member: &WorkspaceMember,
+) -> BTreeSet<PackageName> {
+    let project = member.project();
+    let groups = member
+        .pyproject_toml()
+        .dependency_groups
+        .iter()
+        .flatten()
+        .flat_map(|(_, specifiers)| specifiers)
+        .filter_map(|specifier| match specifier {
+            DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+            _ => None,
+        });
+    project
+        .dependencies
+        .iter()
+        .flatten()
+        .chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flatten()
+                .flat_map(|(_, deps)| deps),
+        )
+        .chain(groups)
+        .filter_map(|requirement| Requirement::<VerbatimUrl>::from_str(requirement).ok())
+        .map(|requirement| requirement.name)
+        .filter(|dependency| dependency != name)
+        .collect()
+}
+
+/// Return the paths of the `path` sources for a dependency of a workspace member.
+///
+/// Sources in the member take precedence over those in the workspace root, and are relative to
+/// the member, rather than the workspace root.
+pub(crate) fn path_sources(
+    workspace: &Workspace,
+    member: &WorkspaceMember,
+    dependency: &PackageName,
+) -> Vec<PathBuf> {
+    member
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.sources.as_ref())
+        .map(ToolUvSources::inner)
+        .and_then(|sources| sources.get(dependency))
+        .map(|sources| (sources, member.root().as_path()))
+        .or_else(|| {
+            workspace
+                .sources()
+                .get(dependency)
+                .map(|sources| (sources, workspace.install_path().as_path()))
+        })
+        .into_iter()
+        .flat_map(|(sources, base)| {
+            sources.iter().filter_map(move |source| match source {
+                Source::Path { path, .. } => Some(normalize_path(&base.join(path)).into_owned()),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+impl TaskGraph {
+    /// Build the [`TaskGraph`] for all members of a workspace.
+    pub(crate) fn from_workspace(workspace: &Workspace) -> Self {
+        let packages = workspace.packages();
+        let members = packages
+            .iter()
+            .map(|(name, member)| {
+                let dependencies = direct_dependencies(name, member);
+
+                // Resolve the path sources of any non-member dependencies.
+                let paths = dependencies
+                    .iter()
+                    .filter(|dependency| !packages.contains_key(*dependency))
+                    .flat_map(|dependency| path_sources(workspace, member, dependency))
+                    .collect();
+
+                let member = TaskMember {
+                    name: name.clone(),
+                    root: member.root().clone(),
+                    dependencies: dependencies
+                        .into_iter()
+                        .filter(|dependency| packages.contains_key(dependency))
+                        .collect(),
+                    paths,
+                };
+                (name.clone(), member)
+            })
+            .collect();
+        Self { members }
+    }
+
+    /// Restrict the graph to the given members.
+    ///
+    /// Dependencies on members outside of the selection are ignored.
+    pub(crate) fn select(mut self, packages: &[PackageName]) -> Result<Self> {
+        if let Some(package) = packages
+            .iter()
+            .find(|package| !self.members.contains_key(*package))
+        {
+            anyhow::bail!("Package `{package}` not found in workspace");
+        }
+        self.members.retain(|name, _| packages.contains(name));
+        self.prune();
+        Ok(self)
+    }
+
+    /// Restrict the graph to the members affected by changes since the given Git revision.
+    ///
+    /// A member is affected if any file within its root directory (and not within a nested member)
+    /// or within one of its path dependencies has changed since the revision, including uncommitted
+    /// and untracked files, or if it depends on an affected member.
+    pub(crate) fn affected(mut self, workspace_root: &Path, rev: &str) -> Result<Self> {
+        let changed = changed_files(workspace_root, rev)?;
+
+        let mut affected = BTreeSet::new();
+        for path in changed {
+            // Attribute each changed file to the innermost member that contains it.
+            if let Some(member) = self
+                .members
+                .values()
+                .filter(|member| path.starts_with(&member.root))
+                .max_by_key(|member| member.root.components().count())
+            {
+                affected.insert(member.name.clone());
+            }
+
+            // And to any member with a path dependency that contains it.
+            affected.extend(
+                self.members
+                    .values()
+                    .filter(|member| member.paths.iter().any(|dep| path.starts_with(dep)))
+                    .map(|member| member.name.clone()),
+            );
+        }
+
+        // Include any members that depend on an affected member.
+        loop {
+            let dependents = self
+                .members
+                .values()
+                .filter(|member| !affected.contains(&member.name))
+                .filter(|member| member.dependencies.iter().any(|dep| affected.contains(dep)))
+                .map(|member| member.name.clone())
+                .collect::<Vec<_>>();
+            if dependents.is_empty() {
+                break;
+            }
+            affected.extend(dependents);
+        }
+
+        debug!(
+            "Members affected by changes since `{rev}`: {}",
+            affected.iter().join(", ")
+        );
+
+        self.members.retain(|name, _| affected.contains(name));
+        self.prune();
+        Ok(self)
+    }
+
+    /// Remove dependencies on members that are no longer in the graph.
+    fn prune(&mut self) {
+        let names = self.members.keys().cloned().collect::<BTreeSet<_>>();
+        for member in self.members.values_mut() {
+            member.dependencies.retain(|dep| names.contains(dep));
+        }
+    }
+
+    /// Returns `true` if the graph contains no members.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Return the members in dependency order, such that each member follows the members it
+    /// depends on. Members without an ordering between them are sorted by name.
+    pub(crate) fn sorted(&self) -> Result<Vec<&TaskMember>> {
+        let mut pending = self
+            .members
+            .values()
+            .map(|member| (member.name.clone(), member.dependencies.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut sorted = Vec::with_capacity(self.members.len());
+        while let Some(name) = pending
+            .iter()
+            .find(|(_, dependencies)| dependencies.is_empty())
+            .map(|(name, _)| name.clone())
+        {
+            pending.remove(&name);
+            for dependencies in pending.values_mut() {
+                dependencies.remove(&name);
+            }
+            sorted.push(&self.members[&name]);
+        }
+
+        if !pending.is_empty() {
+            return Err(cycle_error(pending.keys()));
+        }
+
+        Ok(sorted)
+    }
+
+    /// Run a task in each member, in dependency order.
+    ///
+    /// A member's task is started once the tasks of all of the members it depends on have
+    /// succeeded, with at most `concurrency` tasks running at once. If a task fails, the tasks of
+    /// any members that depend on it (directly or transitively) are skipped.
+    ///
+    /// Returns the outcome for each member, in the order in which the tasks finished.
+    pub(crate) async fn run<F, Fut>(
+        &self,
+        concurrency: usize,
+        mut task: F,
+    ) -> Result<Vec<(PackageName, TaskOutcome)>>
+    where
+        F: FnMut(&TaskMember) -> Fut,
+        Fut: Future<Output = Result<ExitStatus>>,
+    {
+        let concurrency = concurrency.max(1);
+
+        // The unfinished dependencies of each pending member.
+        let mut pending = self
+            .members
+            .values()
+            .map(|member| (member.name.clone(), member.dependencies.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut outcomes = Vec::with_capacity(self.members.len());
+        let mut running = FuturesUnordered::new();
+        loop {
+            // Start any members whose dependencies have all succeeded.
+            while running.len() < concurrency {
+                let Some(name) = pending
+                    .iter()
+                    .find(|(_, dependencies)| dependencies.is_empty())
+                    .map(|(name, _)| name.clone())
+                else {
+                    break;
+                };
+                pending.remove(&name);
+                let member = &self.members[&name];
+                let future = task(member);
+                running.push(async move { (name, future.await) });
+            }
+
+            let Some((name, status)) = running.next().await else {
+                break;
+            };
+            let status = status?;
+
+            let succeeded = matches!(status, ExitStatus::Success);
+            outcomes.push((name.clone(), TaskOutcome::Completed(status)));
+
+            if succeeded {
+                for dependencies in pending.values_mut() {
+                    dependencies.remove(&name);
+                }
+            } else {
+                // Skip every member that (transitively) depends on the failed member.
+                let mut failed = vec![name];
+                while let Some(dependency) = failed.pop() {
+                    let dependents = pending
+                        .iter()
+                        .filter(|(_, dependencies)| dependencies.contains(&dependency))
+                        .map(|(name, _)| name.clone())
+                        .collect::<Vec<_>>();
+                    for dependent in dependents {
+                        pending.remove(&dependent);
+                        outcomes
+                            .push((dependent.clone(), TaskOutcome::Skipped(dependency.clone())));
+                        failed.push(dependent);
+                    }
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            return Err(cycle_error(pending.keys()));
+        }
+
+        Ok(outcomes)
+    }
+}
+
+/// The error for a set of workspace members that depend on each other.
+fn cycle_error<'a>(members: impl Iterator<Item = &'a PackageName>) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Workspace members have a dependency cycle: {}",
+        members.map(|name| format!("`{name}`")).join(", ")
+    )
+}
+
+/// Return the absolute paths of the files in the Git repository containing the given directory
+/// that have changed since the given Git revision, including uncommitted changes and untracked
+/// files.
+///
+/// Changes anywhere in the repository are included, as path dependencies may live outside of the
+/// workspace.
+fn changed_files(directory: &Path, rev: &str) -> Result<Vec<PathBuf>> {
+    // Determine the root of the repository, relative to the directory.
+    let cdup = git(directory, &["rev-parse", "--show-cdup"], rev)?;
+    let top = normalize_path(&directory.join(cdup.trim())).into_owned();
+
+    let mut files = Vec::new();
+    for args in [
+        &["diff", "--name-only", rev, "--"][..],
+        &["ls-files", "--others", "--exclude-standard"][..],
+    ] {
+        files.extend(
+            git(&top, args, rev)?
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| top.join(line)),
+        );
+    }
+    Ok(files)
+}
+
+/// Run a `git` command in the given directory, returning its output.
+fn git(directory: &Path, args: &[&str], rev: &str) -> Result<String> {
+    let Ok(git) = GIT.as_ref() else {
+        anyhow::bail!(
+            "`git` is required to determine the affected workspace members, but was not found in PATH"
+        )
+    };
+    let output = Command::new(git)
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .context("Failed to run `git`")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to determine the files changed since `{rev}`:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use super::{TaskGraph, TaskMember, TaskOutcome};
+    use crate::commands::ExitStatus;
+
+    fn graph(edges: &[(&str, &[&str])]) -> TaskGraph {
+        let members = edges
+            .iter()
+            .map(|(name, dependencies)| {
+                let name = PackageName::from_str(name).unwrap();
+                let member = TaskMember {
+                    name: name.clone(),
+                    root: PathBuf::from(name.as_str()),
+                    dependencies: dependencies
+                        .iter()
+                        .map(|dep| PackageName::from_str(dep).unwrap())
+                        .collect::<BTreeSet<_>>(),
+                    paths: Vec::new(),
+                };
+                (name, member)
+            })
+            .collect::<BTreeMap<_, _>>();
+        TaskGraph { members }
+    }
+
+    fn summarize(outcomes: Vec<(PackageName, TaskOutcome)>) -> Vec<String> {
+        outcomes
+            .into_iter()
+            .map(|(name, outcome)| match outcome {
+                TaskOutcome::Completed(status) => format!("{name}: {status:?}"),
+                TaskOutcome::Skipped(dependency) => format!("{name}: skipped ({dependency})"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn dependency_order() {
+        let graph = graph(&[
+            ("app", &["core", "utils"]),
+            ("utils", &["core"]),
+            ("core", &[]),
+        ]);
+        let outcomes = graph
+            .run(1, |_| async { Ok(ExitStatus::Success) })
+            .await
+            .unwrap();
+        assert_eq!(
+            summarize(outcomes),
+            ["core: Success", "utils: Success", "app: Success"]
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_dependents() {
+        let graph = graph(&[
+            ("app", &["utils"]),
+            ("utils", &["core"]),
+            ("core", &[]),
+            ("docs", &[]),
+        ]);
+        let outcomes = graph
+            .run(1, |member| {
+                let status = if member.name().as_str() == "core" {
+                    ExitStatus::Failure
+                } else {
+                    ExitStatus::Success
+                };
+                async move { Ok(status) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            summarize(outcomes),
+            [
+                "core: Failure",
+                "utils: skipped (core)",
+                "app: skipped (utils)",
+                "docs: Success",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn select() {
+        let graph = graph(&[("app", &["utils"]), ("utils", &["core"]), ("core", &[])])
+            .select(&[
+                PackageName::from_str("app").unwrap(),
+                PackageName::from_str("core").unwrap(),
+            ])
+            .unwrap();
+        let outcomes = graph
+            .run(1, |_| async { Ok(ExitStatus::Success) })
+            .await
+            .unwrap();
+        assert_eq!(summarize(outcomes), ["app: Success", "core: Success"]);
+    }
+
+    #[test]
+    fn sorted() {
+        let graph = graph(&[
+            ("app", &["core", "utils"]),
+            ("docs", &[]),
+            ("utils", &["core"]),
+            ("core", &[]),
+        ]);
+        let sorted = graph
+            .sorted()
+            .unwrap()
+            .into_iter()
+            .map(|member| member.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, ["core", "docs", "utils", "app"]);
+    }
+
+    #[tokio::test]
+    async fn cycle() {
+        let graph = graph(&[("a", &["b"]), ("b", &["a"]), ("c", &[])]);
+        let err = graph
+            .run(1, |_| async { Ok(ExitStatus::Success) })
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Workspace members have a dependency cycle: `a`, `b`"
+        );
+    }
+}
@@ -157,7 +157,7 @@ pub(crate) async fn tree(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     };
@@ -198,6 +198,7 @@ pub(crate) async fn tree(
                 resolution: _,
                 prerelease: _,
                 fork_strategy: _,
+                allow_insecure_md5_hashes: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
@@ -14,13 +14,13 @@ use uv_configuration::{
     Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
     InstallOptions,
 };
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_normalize::DefaultExtras;
 use uv_normalize::PackageName;
 use uv_pep440::{BumpCommand, PrereleaseKind, Version};
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{Plugins, PythonInstallMirrors};
 use uv_workspace::pyproject_mut::Error;
 use uv_workspace::{
     DiscoveryOptions, WorkspaceCache, WorkspaceError,
@@ -31,13 +31,15 @@ use uv_workspace::{VirtualProject, Workspace};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
+use crate::commands::project::edit::write_edited;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
+use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
 };
 use crate::commands::{ExitStatus, diagnostics, project};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverInstallerSettings;
 
 /// Display version information for uv itself (`uv self version`)
@@ -74,6 +76,8 @@ pub(crate) async fn project_version(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    plugins: &Plugins,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -95,18 +99,11 @@ pub(crate) async fn project_version(
     let is_read_only = value.is_none() && bump.is_empty();
     if frozen && is_read_only {
         return Box::pin(print_frozen_version(
-            project,
+            &project,
             &name,
-            project_dir,
-            active,
-            python,
-            install_mirrors,
             &settings,
             client_builder,
-            python_preference,
-            python_downloads,
             concurrency,
-            no_config,
             cache,
             short,
             output_format,
@@ -308,6 +305,8 @@ pub(crate) async fn project_version(
             python_preference,
             python_downloads,
             installer_metadata,
+            quarantine,
+            plugins,
             concurrency,
             no_config,
             cache,
@@ -335,9 +334,9 @@ fn hint_uv_self_version(err: WorkspaceError, explicit_project: bool) -> anyhow::
         anyhow!(
             "{}\n\n{}{} If you meant to view uv's version, use `{}` instead",
             err,
-            "hint".bold().cyan(),
+            "hint".bold().accent(),
             ":".bold(),
-            "uv self version".green()
+            "uv self version".success()
         )
     } else {
         err.into()
@@ -394,7 +393,7 @@ fn update_project(
     // Save to disk
     toml.set_version(new_version)?;
     let content = toml.to_string();
-    fs_err::write(pyproject_path, &content)?;
+    write_edited(pyproject_path, &project.pyproject_toml().raw, &content)?;
 
     // Update the `pyproject.toml` in-memory.
     let project = project
@@ -404,47 +403,22 @@ fn update_project(
     Ok(project)
 }
 
-/// Do the minimal work to try to find the package in the lockfile and print its version
+/// Do the minimal work to try to find the package in the lockfile and print its version.
+///
+/// Reading the lockfile doesn't require a Python interpreter, so none is discovered.
 async fn print_frozen_version(
-    project: VirtualProject,
+    project: &VirtualProject,
     name: &PackageName,
-    project_dir: &Path,
-    active: Option<bool>,
-    python: Option<String>,
-    install_mirrors: PythonInstallMirrors,
     settings: &ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
-    python_preference: PythonPreference,
-    python_downloads: PythonDownloads,
     concurrency: Concurrency,
-    no_config: bool,
     cache: &Cache,
     short: bool,
     output_format: VersionFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
-    // Discover the interpreter (this is the same interpreter --no-sync uses).
-    let interpreter = ProjectInterpreter::discover(
-        project.workspace(),
-        project_dir,
-        &DependencyGroupsWithDefaults::none(),
-        python.as_deref().map(PythonRequest::parse),
-        &client_builder,
-        python_preference,
-        python_downloads,
-        &install_mirrors,
-        false,
-        no_config,
-        active,
-        cache,
-        printer,
-        preview,
-    )
-    .await?
-    .into_interpreter();
-
-    let target = AddTarget::Project(project, Box::new(PythonTarget::Interpreter(interpreter)));
+    let target = LockTarget::Workspace(project.workspace());
 
     // Initialize any shared state.
     let state = UniversalState::default();
@@ -462,14 +436,14 @@ async fn print_frozen_version(
         printer,
         preview,
     )
-    .execute((&target).into())
+    .execute(target)
     .await
     {
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     };
@@ -513,6 +487,8 @@ async fn lock_and_sync(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
+    plugins: &Plugins,
     concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
@@ -609,7 +585,7 @@ async fn lock_and_sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     };
@@ -651,10 +627,15 @@ async fn lock_and_sync(
         Modifications::Sufficient,
         None,
         settings.into(),
+        None,
         &client_builder,
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        quarantine,
+        false,
+        false,
+        plugins,
         concurrency,
         cache,
         workspace_cache,
@@ -668,7 +649,7 @@ async fn lock_and_sync(
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(err) => return Err(err.into()),
     }
@@ -692,17 +673,17 @@ fn print_version(
             }
             if let Some(new_version) = new_version {
                 if short {
-                    writeln!(printer.stdout(), "{}", new_version.cyan())?;
+                    writeln!(printer.stdout(), "{}", new_version.accent())?;
                 } else {
                     writeln!(
                         printer.stdout(),
                         "{} => {}",
-                        old_version.cyan(),
-                        new_version.cyan()
+                        old_version.accent(),
+                        new_version.accent()
                     )?;
                 }
             } else {
-                writeln!(printer.stdout(), "{}", old_version.cyan())?;
+                writeln!(printer.stdout(), "{}", old_version.accent())?;
             }
         }
         VersionFormat::Json => {
@@ -0,0 +1,483 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use itertools::Itertools;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use owo_colors::OwoColorize;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::SyncFormat;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{
+    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions,
+};
+use uv_fs::{Quarantine, Simplified};
+use uv_preview::Preview;
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_settings::{Plugins, PythonInstallMirrors};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::lock::lock;
+use crate::commands::project::sync::sync;
+use crate::printer::{Printer, Styled};
+use crate::settings::ResolverInstallerSettings;
+
+/// Watch the workspace, re-locking (and, optionally, syncing) the project whenever a
+/// `pyproject.toml` changes.
+pub(crate) async fn watch(
+    project_dir: &Path,
+    sync_environment: bool,
+    debounce: Duration,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    quarantine: Quarantine,
+    locked_timeout: Option<Duration>,
+    plugins: &Plugins,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let mut files = watched_files(project_dir).await?;
+    let mut snapshot = Snapshot::capture(&files);
+    let mut watcher = FileWatcher::new()?;
+    watcher.watch(&files);
+
+    // Register the Ctrl-C handler once, such that an interrupt also cancels an in-flight re-lock.
+    let mut ctrl_c = std::pin::pin!(tokio::signal::ctrl_c());
+
+    let mut changed = Vec::new();
+    loop {
+        if !changed.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Detected changes to: {}",
+                changed
+                    .iter()
+                    .map(|path: &PathBuf| path.user_display().accent().to_string())
+                    .join(", ")
+            )?;
+        }
+
+        let relock = async {
+            if sync_environment {
+                Box::pin(sync(
+                    project_dir,
+                    false,
+                    false,
+                    false,
+                    None,
+                    DryRun::Disabled,
+                    false,
+                    false,
+                    None,
+                    false,
+                    None,
+                    None,
+                    None,
+                    ExtrasSpecification::default(),
+                    DependencyGroups::default(),
+                    None,
+                    InstallOptions::default(),
+                    Modifications::Exact,
+                    python.clone(),
+                    None,
+                    install_mirrors.clone(),
+                    python_preference,
+                    python_downloads,
+                    settings.clone(),
+                    client_builder.clone(),
+                    None,
+                    installer_metadata,
+                    quarantine,
+                    locked_timeout,
+                    plugins,
+                    concurrency,
+                    no_config,
+                    cache,
+                    printer,
+                    preview,
+                    SyncFormat::default(),
+                ))
+                .await
+            } else {
+                Box::pin(lock(
+                    project_dir,
+                    false,
+                    false,
+                    DryRun::Disabled,
+                    false,
+                    false,
+                    None,
+                    python.clone(),
+                    install_mirrors.clone(),
+                    settings.resolver.clone(),
+                    client_builder.clone(),
+                    None,
+                    python_preference,
+                    python_downloads,
+                    concurrency,
+                    plugins,
+                    no_config,
+                    cache,
+                    printer,
+                    preview,
+                ))
+                .await
+            }
+        };
+
+        tokio::select! {
+            result = &mut ctrl_c => {
+                result?;
+                return Ok(ExitStatus::Success);
+            }
+            result = relock => {
+                if let Err(err) = result {
+                    report_error(&err, printer)?;
+                }
+            }
+        }
+
+        // Re-discover the workspace, in case members were added or removed. If the workspace can
+        // no longer be discovered (e.g., due to a malformed `pyproject.toml`), continue watching
+        // the existing set of files.
+        match watched_files(project_dir).await {
+            Ok(discovered) => {
+                snapshot.extend(&discovered);
+                snapshot.retain(&discovered);
+                watcher.watch(&discovered);
+                files = discovered;
+            }
+            Err(err) => debug!("Failed to re-discover workspace: {err}"),
+        }
+
+        writeln!(printer.stderr(), "Waiting for changes...")?;
+
+        tokio::select! {
+            result = &mut ctrl_c => {
+                result?;
+                return Ok(ExitStatus::Success);
+            }
+            result = wait_for_changes(&files, &mut snapshot, &mut watcher, debounce) => {
+                changed = result;
+            }
+        }
+    }
+}
+
+/// Return the files that affect the lockfile: the `pyproject.toml` of each workspace member, along
+/// with the workspace `pyproject.toml` and `uv.toml`.
+async fn watched_files(project_dir: &Path) -> Result<Vec<PathBuf>> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    let mut files = vec![
+        workspace.install_path().join("pyproject.toml"),
+        workspace.install_path().join("uv.toml"),
+    ];
+    files.extend(
+        workspace
+            .packages()
+            .values()
+            .map(|member| member.root().join("pyproject.toml")),
+    );
+    files.sort();
+    files.dedup();
+
+    debug!(
+        "Watching: {}",
+        files.iter().map(|path| path.user_display()).join(", ")
+    );
+
+    Ok(files)
+}
+
+/// A watcher for the directories that contain the watched files.
+///
+/// The parent directories are watched (rather than the files themselves), such that files that are
+/// created, or replaced by an editor's atomic save, are picked up.
+struct FileWatcher {
+    watcher: RecommendedWatcher,
+    /// Receives a notification for each filesystem event in a watched directory.
+    events: mpsc::UnboundedReceiver<()>,
+    /// The directories that are currently watched.
+    directories: BTreeSet<PathBuf>,
+}
+
+impl FileWatcher {
+    fn new() -> notify::Result<Self> {
+        let (sender, events) = mpsc::unbounded_channel();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            match event {
+                // Reads (e.g., by the re-lock itself) can't change the snapshot.
+                Ok(event) if matches!(event.kind, EventKind::Access(_)) => {}
+                Ok(_) => {
+                    let _ = sender.send(());
+                }
+                Err(err) => debug!("Failed to watch for changes: {err}"),
+            }
+        })?;
+        Ok(Self {
+            watcher,
+            events,
+            directories: BTreeSet::new(),
+        })
+    }
+
+    /// Watch the parent directories of the given files, and stop watching any directories that no
+    /// longer contain a watched file.
+    fn watch(&mut self, files: &[PathBuf]) {
+        let directories = files
+            .iter()
+            .filter_map(|path| path.parent())
+            .map(Path::to_path_buf)
+            .collect::<BTreeSet<_>>();
+        for directory in self.directories.difference(&directories) {
+            if let Err(err) = self.watcher.unwatch(directory) {
+                debug!("Failed to unwatch `{}`: {err}", directory.user_display());
+            }
+        }
+        for directory in directories.difference(&self.directories) {
+            if let Err(err) = self.watcher.watch(directory, RecursiveMode::NonRecursive) {
+                debug!("Failed to watch `{}`: {err}", directory.user_display());
+            }
+        }
+        self.directories = directories;
+    }
+
+    /// Wait for the next filesystem event in a watched directory.
+    async fn next(&mut self) {
+        if self.events.recv().await.is_none() {
+            // The sender is owned by the watcher, so the channel can't close while it's alive.
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Wait until one or more of the given files change, and no further events arrive for the debounce
+/// interval. Returns the files that changed.
+async fn wait_for_changes(
+    files: &[PathBuf],
+    snapshot: &mut Snapshot,
+    watcher: &mut FileWatcher,
+    debounce: Duration,
+) -> Vec<PathBuf> {
+    loop {
+        // Wait for the first event that changes the watched files. Events for other files in the
+        // watched directories (e.g., the lockfile itself) leave the snapshot unchanged.
+        loop {
+            watcher.next().await;
+            if Snapshot::capture(files) != *snapshot {
+                break;
+            }
+        }
+
+        // Wait for the events to settle, such that a burst of writes triggers a single re-lock.
+        while tokio::time::timeout(debounce, watcher.next()).await.is_ok() {}
+
+        let current = Snapshot::capture(files);
+        let changed = snapshot.changed(&current);
+        *snapshot = current;
+
+        // If the files were restored to their original state, keep waiting.
+        if !changed.is_empty() {
+            return changed;
+        }
+    }
+}
+
+/// The modification time and size of each watched file, or `None` if the file does not exist.
+#[derive(Debug, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, Option<(SystemTime, u64)>>);
+
+impl Snapshot {
+    /// Capture the current state of the given files.
+    fn capture(files: &[PathBuf]) -> Self {
+        Self(
+            files
+                .iter()
+                .map(|path| (path.clone(), Self::stat(path)))
+                .collect(),
+        )
+    }
+
+    /// Read the modification time and size of a file.
+    fn stat(path: &Path) -> Option<(SystemTime, u64)> {
+        let metadata = fs_err::metadata(path).ok()?;
+        Some((metadata.modified().ok()?, metadata.len()))
+    }
+
+    /// Capture the state of any files that aren't yet part of the snapshot.
+    fn extend(&mut self, files: &[PathBuf]) {
+        for path in files {
+            self.0
+                .entry(path.clone())
+                .or_insert_with(|| Self::stat(path));
+        }
+    }
+
+    /// Remove any files that are no longer watched.
+    fn retain(&mut self, files: &[PathBuf]) {
+        self.0.retain(|path, _| files.contains(path));
+    }
+
+    /// Return the files that differ between this snapshot and another.
+    fn changed(&self, other: &Self) -> Vec<PathBuf> {
+        other
+            .0
+            .iter()
+            .filter(|(path, state)| self.0.get(*path) != Some(*state))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// Report an error from a re-lock, without exiting the watcher.
+fn report_error(err: &anyhow::Error, printer: Printer) -> std::fmt::Result {
+    let mut causes = err.chain();
+    if let Some(err) = causes.next() {
+        writeln!(
+            printer.stderr(),
+            "{}: {}",
+            "error".error().bold(),
+            err.to_string().trim()
+        )?;
+    }
+    for err in causes {
+        writeln!(
+            printer.stderr(),
+            "  {}: {}",
+            "Caused by".error().bold(),
+            err.to_string().trim()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use fs_err as fs;
+
+    use super::{FileWatcher, Snapshot, wait_for_changes};
+
+    #[test]
+    fn snapshot_changed() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let modified = temp_dir.path().join("modified.toml");
+        let removed = temp_dir.path().join("removed.toml");
+        let created = temp_dir.path().join("created.toml");
+        let unchanged = temp_dir.path().join("unchanged.toml");
+        fs::write(&modified, "a").unwrap();
+        fs::write(&removed, "a").unwrap();
+        fs::write(&unchanged, "a").unwrap();
+
+        let files = vec![
+            created.clone(),
+            modified.clone(),
+            removed.clone(),
+            unchanged.clone(),
+        ];
+        let snapshot = Snapshot::capture(&files);
+        assert!(snapshot.changed(&Snapshot::capture(&files)).is_empty());
+
+        fs::write(&modified, "ab").unwrap();
+        fs::remove_file(&removed).unwrap();
+        fs::write(&created, "a").unwrap();
+        assert_eq!(
+            snapshot.changed(&Snapshot::capture(&files)),
+            vec![created, modified, removed]
+        );
+    }
+
+    #[test]
+    fn snapshot_extend_retain() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let old = temp_dir.path().join("old.toml");
+        let new = temp_dir.path().join("new.toml");
+        fs::write(&old, "a").unwrap();
+        fs::write(&new, "a").unwrap();
+
+        let mut snapshot = Snapshot::capture(std::slice::from_ref(&old));
+        let files = vec![new.clone()];
+        snapshot.extend(&files);
+        snapshot.retain(&files);
+
+        // A file that was added to the workspace isn't reported as changed until it's modified.
+        assert_eq!(snapshot, Snapshot::capture(&files));
+        fs::write(&new, "ab").unwrap();
+        assert_eq!(snapshot.changed(&Snapshot::capture(&files)), vec![new]);
+    }
+
+    #[tokio::test]
+    async fn wait_for_changes_debounce() {
+        const WRITES: usize = 5;
+        const WRITE_INTERVAL: Duration = Duration::from_millis(50);
+        const DEBOUNCE: Duration = Duration::from_millis(300);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let pyproject_toml = temp_dir.path().join("pyproject.toml");
+        fs::write(&pyproject_toml, "").unwrap();
+        let files = vec![pyproject_toml.clone()];
+
+        let mut snapshot = Snapshot::capture(&files);
+        let mut watcher = FileWatcher::new().unwrap();
+        watcher.watch(&files);
+
+        // Writes to other files in the watched directory are ignored.
+        fs::write(temp_dir.path().join("uv.lock"), "version = 1").unwrap();
+        assert!(
+            tokio::time::timeout(
+                DEBOUNCE * 2,
+                wait_for_changes(&files, &mut snapshot, &mut watcher, DEBOUNCE)
+            )
+            .await
+            .is_err()
+        );
+
+        // A burst of writes is reported once, after the last write.
+        let start = Instant::now();
+        let writer = std::thread::spawn({
+            let pyproject_toml = pyproject_toml.clone();
+            move || {
+                for i in 1..=WRITES {
+                    fs::write(&pyproject_toml, "#".repeat(i)).unwrap();
+                    std::thread::sleep(WRITE_INTERVAL);
+                }
+            }
+        });
+        let changed = wait_for_changes(&files, &mut snapshot, &mut watcher, DEBOUNCE).await;
+        writer.join().unwrap();
+        assert_eq!(changed, vec![pyproject_toml]);
+        assert!(start.elapsed() >= WRITE_INTERVAL * u32::try_from(WRITES - 1).unwrap() + DEBOUNCE);
+        assert_eq!(snapshot, Snapshot::capture(&files));
+
+        assert!(
+            tokio::time::timeout(
+                DEBOUNCE * 2,
+                wait_for_changes(&files, &mut snapshot, &mut watcher, DEBOUNCE)
+            )
+            .await
+            .is_err()
+        );
+    }
+}
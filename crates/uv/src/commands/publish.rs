@@ -1,28 +1,31 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use console::Term;
-use owo_colors::{AnsiColors, OwoColorize};
+use owo_colors::OwoColorize;
 use tokio::sync::Semaphore;
 use tracing::{debug, info, trace};
 use uv_auth::{Credentials, DEFAULT_TOLERANCE_SECS, PyxTokenStore};
 use uv_cache::Cache;
 use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
+use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
 use uv_pep508::VerbatimUrl;
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
-    files_for_publishing, upload,
+    CheckDiagnostic, CheckUrlClient, FormMetadata, IndexRules, PublishError, Severity,
+    TrustedPublishResult, check_trusted_publishing, files_for_publishing, upload,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::{warn_user_once, write_error_chain};
 
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled, Theme};
 
 pub(crate) async fn publish(
     paths: Vec<String>,
@@ -36,10 +39,11 @@ pub(crate) async fn publish(
     index: Option<String>,
     index_locations: IndexLocations,
     dry_run: bool,
+    check: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
-    if client_builder.is_offline() {
+    if client_builder.is_offline() && !check {
         bail!("Unable to publish files in offline mode");
     }
 
@@ -89,6 +93,20 @@ pub(crate) async fn publish(
     };
 
     let files = files_for_publishing(paths)?;
+    if check {
+        return check_files(
+            files,
+            &publish_url,
+            check_url,
+            keyring_provider,
+            client_builder,
+            index_locations,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
     match files.len() {
         0 => bail!("No files found to publish"),
         1 => {
@@ -184,14 +202,14 @@ pub(crate) async fn publish(
             writeln!(
                 printer.stderr(),
                 "{} {filename} {}",
-                "Checking".bold().cyan(),
+                "Checking".bold().accent(),
                 format!("({bytes:.1}{unit})").dimmed()
             )?;
         } else {
             writeln!(
                 printer.stderr(),
                 "{} {filename} {}",
-                "Uploading".bold().green(),
+                "Uploading".bold().success(),
                 format!("({bytes:.1}{unit})").dimmed()
             )?;
         }
@@ -246,6 +264,162 @@ pub(crate) async fn publish(
     Ok(ExitStatus::Success)
 }
 
+/// Check the files for problems that would fail the upload, without uploading them.
+///
+/// Unlike `--dry-run`, no credentials are required.
+async fn check_files(
+    files: Vec<(PathBuf, String, DistFilename)>,
+    publish_url: &DisplaySafeUrl,
+    check_url: Option<IndexUrl>,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    index_locations: IndexLocations,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let rules = IndexRules::from_publish_url(publish_url);
+
+    match files.len() {
+        0 => bail!("No files found to check"),
+        1 => writeln!(printer.stderr(), "Checking 1 file for {publish_url}")?,
+        n => writeln!(printer.stderr(), "Checking {n} files for {publish_url}")?,
+    }
+
+    // Without a check URL, we can still check the name against PyPI's Simple API.
+    let check_url = check_url.or_else(|| {
+        IndexRules::simple_url(publish_url)
+            .map(|url| IndexUrl::from(VerbatimUrl::from_url(DisplaySafeUrl::parse(url).unwrap())))
+    });
+    let client = client_builder.clone().keyring(keyring_provider).build();
+    let check_url_client = if client_builder.is_offline() {
+        None
+    } else {
+        check_url.map(|index_url| CheckUrlClient {
+            index_url,
+            registry_client_builder: RegistryClientBuilder::new(
+                client_builder.clone(),
+                cache.clone(),
+            )
+            .index_locations(index_locations)
+            .keyring(keyring_provider),
+            client: &client,
+            index_capabilities: IndexCapabilities::default(),
+            cache,
+        })
+    };
+    let download_concurrency = Semaphore::new(1);
+
+    let mut errors = 0usize;
+    let mut warnings = 0usize;
+    let mut names = BTreeSet::new();
+    for (file, _raw_filename, filename) in files {
+        let size = fs_err::metadata(&file)?.len();
+        let (bytes, unit) = human_readable_bytes(size);
+        writeln!(
+            printer.stderr(),
+            "{} {filename} {}",
+            "Checking".bold().accent(),
+            format!("({bytes:.1}{unit})").dimmed()
+        )?;
+
+        let mut diagnostics = match uv_publish::check_file(&file, &filename, rules).await {
+            Ok(diagnostics) => diagnostics,
+            Err(err) => {
+                errors += 1;
+                write_error_chain(
+                    &PublishError::PublishPrepare(file.clone(), Box::new(err)),
+                    printer.stderr(),
+                    "error",
+                    Theme::current().error,
+                )?;
+                continue;
+            }
+        };
+
+        if let Some(check_url_client) = &check_url_client {
+            match uv_publish::check_url(check_url_client, &file, &filename, &download_concurrency)
+                .await
+            {
+                Ok(true) => diagnostics.push(CheckDiagnostic {
+                    severity: Severity::Warning,
+                    message: "File already exists on the index and will be skipped".to_string(),
+                }),
+                Ok(false) => {}
+                Err(err @ PublishError::HashMismatch { .. }) => {
+                    diagnostics.push(CheckDiagnostic {
+                        severity: Severity::Error,
+                        message: err.to_string(),
+                    });
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Severity::Warning => {
+                    warnings += 1;
+                    writeln!(
+                        printer.stderr(),
+                        "  {}{} {diagnostic}",
+                        "warning".bold().warning(),
+                        ":".bold()
+                    )?;
+                }
+                Severity::Error => {
+                    errors += 1;
+                    writeln!(
+                        printer.stderr(),
+                        "  {}{} {diagnostic}",
+                        "error".bold().error(),
+                        ":".bold()
+                    )?;
+                }
+            }
+        }
+
+        names.insert(filename.name().clone());
+    }
+
+    // Check whether the project names are already taken on the index.
+    if let Some(check_url_client) = &check_url_client {
+        for name in &names {
+            if uv_publish::project_exists(check_url_client, name, &download_concurrency).await? {
+                writeln!(
+                    printer.stderr(),
+                    "Project {} exists on {}, ensure you're allowed to upload to it",
+                    name.accent(),
+                    check_url_client.index_url
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "Project name {} is available on {}",
+                    name.accent(),
+                    check_url_client.index_url
+                )?;
+            }
+        }
+    }
+
+    let summary = format!(
+        "{errors} {} and {warnings} {}",
+        if errors == 1 { "error" } else { "errors" },
+        if warnings == 1 { "warning" } else { "warnings" },
+    );
+    if errors > 0 {
+        writeln!(printer.stderr(), "{} {summary}", "Found".bold().error())?;
+        Ok(ExitStatus::Failure)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{} {summary}",
+            "Checks passed with".bold().success()
+        )?;
+        Ok(ExitStatus::Success)
+    }
+}
+
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
@@ -389,7 +563,7 @@ async fn gather_credentials(
                     .as_ref(),
                 printer.stderr(),
                 "error",
-                AnsiColors::Red,
+                Theme::current().error,
             )?;
         }
     }
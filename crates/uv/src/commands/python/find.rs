@@ -1,20 +1,24 @@
 use anyhow::Result;
+use itertools::Either;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonFindFormat;
 use uv_client::BaseClientBuilder;
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_fs::Simplified;
 use uv_preview::Preview;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest, canonicalize_executable,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
 
+use crate::commands::python::list::PrintData;
 use crate::commands::{
     ExitStatus,
     project::{ScriptInterpreter, WorkspacePython, validate_project_requires_python},
@@ -27,6 +31,9 @@ pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
+    base: bool,
+    resolve_symlinks: bool,
+    output_format: PythonFindFormat,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -98,26 +105,25 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            python.interpreter().python_version()
-        )?;
-    } else {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            std::path::absolute(python.interpreter().sys_executable())?.simplified_display()
-        )?;
-    }
+    print_interpreter(
+        python.interpreter(),
+        show_version,
+        base,
+        resolve_symlinks,
+        output_format,
+        printer,
+    )?;
 
     Ok(ExitStatus::Success)
 }
 
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
     show_version: bool,
+    base: bool,
+    resolve_symlinks: bool,
+    output_format: PythonFindFormat,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -144,21 +150,58 @@ pub(crate) async fn find_script(
     {
         Err(error) => {
             writeln!(printer.stderr(), "{error}")?;
-            return Ok(ExitStatus::Failure);
+            return Ok(ExitStatus::from_error(&error).unwrap_or(ExitStatus::Failure));
         }
         Ok(ScriptInterpreter::Interpreter(interpreter)) => interpreter,
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
-    if show_version {
-        writeln!(printer.stdout(), "{}", interpreter.python_version())?;
-    } else {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            std::path::absolute(interpreter.sys_executable())?.simplified_display()
-        )?;
-    }
+    print_interpreter(
+        &interpreter,
+        show_version,
+        base,
+        resolve_symlinks,
+        output_format,
+        printer,
+    )?;
 
     Ok(ExitStatus::Success)
 }
+
+/// Display a discovered interpreter in the requested format.
+#[allow(clippy::fn_params_excessive_bools)]
+fn print_interpreter(
+    interpreter: &Interpreter,
+    show_version: bool,
+    base: bool,
+    resolve_symlinks: bool,
+    output_format: PythonFindFormat,
+    printer: Printer,
+) -> Result<()> {
+    let path = if base {
+        interpreter.to_base_python()?
+    } else {
+        std::path::absolute(interpreter.sys_executable())?
+    };
+    let path = if resolve_symlinks {
+        // On Windows, resolve trampolines (e.g., in virtual environments) to the interpreter they
+        // launch before canonicalizing.
+        dunce::canonicalize(canonicalize_executable(&path)?)?
+    } else {
+        path
+    };
+    match output_format {
+        PythonFindFormat::Json => {
+            let data = PrintData::new(&interpreter.key(), Either::Left(&path))?;
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        PythonFindFormat::Text => {
+            if show_version {
+                writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+            } else {
+                writeln!(printer.stdout(), "{}", path.simplified_display())?;
+            }
+        }
+    }
+    Ok(())
+}
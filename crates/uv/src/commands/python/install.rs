@@ -10,7 +10,7 @@ use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use indexmap::IndexSet;
 use itertools::{Either, Itertools};
-use owo_colors::{AnsiColors, OwoColorize};
+use owo_colors::OwoColorize;
 use reqwest_retry::policies::ExponentialBackoff;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
@@ -22,6 +22,7 @@ use uv_preview::{Preview, PreviewFeatures};
 use uv_python::downloads::{
     self, ArchRequest, DownloadResult, ManagedPythonDownload, PythonDownloadRequest,
 };
+use uv_python::host_libraries;
 use uv_python::managed::{
     ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
     create_link_to_executable, python_executable_dir,
@@ -37,7 +38,7 @@ use uv_warnings::{warn_user, write_error_chain};
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled, Theme};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct InstallRequest {
@@ -148,6 +149,8 @@ enum InstallErrorKind {
     Bin,
     #[cfg_attr(not(windows), allow(dead_code))]
     Registry,
+    Ensurepip,
+    Libraries,
 }
 
 /// Download and install Python versions.
@@ -166,6 +169,8 @@ pub(crate) async fn install(
     python_downloads_json_url: Option<String>,
     client_builder: BaseClientBuilder<'_>,
     default: bool,
+    ensurepip: bool,
+    check_libraries: bool,
     python_downloads: PythonDownloads,
     no_config: bool,
     preview: Preview,
@@ -333,7 +338,7 @@ pub(crate) async fn install(
                             // support for a key we previously supported
                             warn_user!(
                                 "Failed to create reinstall request for existing installation `{}`: {err}",
-                                installation.key().green()
+                                installation.key().success()
                             );
                         }
                     }
@@ -483,6 +488,22 @@ pub(crate) async fn install(
             e.warn_user(installation);
         }
 
+        if ensurepip {
+            if let Err(err) = installation.ensure_pip() {
+                errors.push((
+                    InstallErrorKind::Ensurepip,
+                    installation.key().clone(),
+                    err.into(),
+                ));
+            }
+        }
+
+        if check_libraries {
+            if let Err(err) = check_host_libraries(installation) {
+                errors.push((InstallErrorKind::Libraries, installation.key().clone(), err));
+            }
+        }
+
         let upgradeable = (default || is_default_install)
             || requested_minor_versions.contains(&installation.key().version().python_version());
 
@@ -646,7 +667,7 @@ pub(crate) async fn install(
                     writeln!(
                         printer.stderr(),
                         " {} {}{executables}",
-                        "+".green(),
+                        "+".success(),
                         event.key.bold()
                     )?;
                 }
@@ -654,7 +675,7 @@ pub(crate) async fn install(
                     writeln!(
                         printer.stderr(),
                         " {} {}{executables}",
-                        "-".red(),
+                        "-".error(),
                         event.key.bold()
                     )?;
                 }
@@ -662,7 +683,7 @@ pub(crate) async fn install(
                     writeln!(
                         printer.stderr(),
                         " {} {}{executables}",
-                        "~".yellow(),
+                        "~".warning(),
                         event.key.bold(),
                     )?;
                 }
@@ -680,9 +701,18 @@ pub(crate) async fn install(
         let fatal = !errors.iter().all(|(kind, _, _)| match kind {
             InstallErrorKind::Bin => bin.is_none(),
             InstallErrorKind::Registry => registry.is_none(),
-            InstallErrorKind::DownloadUnpack => false,
+            InstallErrorKind::DownloadUnpack
+            | InstallErrorKind::Ensurepip
+            | InstallErrorKind::Libraries => false,
         });
 
+        // Classify the failure based on the first download error, e.g., a network error.
+        let status = errors
+            .iter()
+            .filter(|(kind, _, _)| matches!(kind, InstallErrorKind::DownloadUnpack))
+            .find_map(|(_, _, err)| ExitStatus::from_error(&**err))
+            .unwrap_or(ExitStatus::Failure);
+
         for (kind, key, err) in errors
             .into_iter()
             .sorted_unstable_by(|(_, key_a, _), (_, key_b, _)| key_a.cmp(key_b))
@@ -693,14 +723,14 @@ pub(crate) async fn install(
                         err.context(format!("Failed to install {key}")).as_ref(),
                         printer.stderr(),
                         "error",
-                        AnsiColors::Red,
+                        Theme::current().error,
                     )?;
                 }
                 InstallErrorKind::Bin => {
                     let (level, color) = match bin {
-                        None => ("warning", AnsiColors::Yellow),
+                        None => ("warning", Theme::current().warning),
                         Some(false) => continue,
-                        Some(true) => ("error", AnsiColors::Red),
+                        Some(true) => ("error", Theme::current().error),
                     };
 
                     write_error_chain(
@@ -713,9 +743,9 @@ pub(crate) async fn install(
                 }
                 InstallErrorKind::Registry => {
                     let (level, color) = match registry {
-                        None => ("warning", AnsiColors::Yellow),
+                        None => ("warning", Theme::current().warning),
                         Some(false) => continue,
-                        Some(true) => ("error", AnsiColors::Red),
+                        Some(true) => ("error", Theme::current().error),
                     };
 
                     trace!("Error trace: {err:?}");
@@ -727,17 +757,59 @@ pub(crate) async fn install(
                         color,
                     )?;
                 }
+                InstallErrorKind::Ensurepip => {
+                    write_error_chain(
+                        err.context(format!("Failed to install pip for {key}"))
+                            .as_ref(),
+                        printer.stderr(),
+                        "error",
+                        Theme::current().error,
+                    )?;
+                }
+                InstallErrorKind::Libraries => {
+                    write_error_chain(
+                        err.context(format!("Failed to verify system libraries for {key}"))
+                            .as_ref(),
+                        printer.stderr(),
+                        "error",
+                        Theme::current().error,
+                    )?;
+                }
             }
         }
 
         if fatal {
-            return Ok(ExitStatus::Failure);
+            return Ok(status);
         }
     }
 
     Ok(ExitStatus::Success)
 }
 
+/// Check that the shared libraries required by a managed Python installation are present on the
+/// host.
+fn check_host_libraries(installation: &ManagedPythonInstallation) -> Result<()> {
+    let missing = host_libraries::find_missing(&installation.executable(false))?;
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "Missing system libraries: {}",
+        missing.iter().map(ToString::to_string).join(", ")
+    );
+    if let Some(command) = host_libraries::install_hint(&missing) {
+        write!(
+            message,
+            "\n\n{}{} Install the missing libraries with: `{}`",
+            "hint".bold().accent(),
+            ":".bold(),
+            command.success()
+        )?;
+    }
+    Err(anyhow::anyhow!(message))
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 ///
 /// This function is fallible, but errors are pushed to `errors` instead of being thrown.
@@ -1013,27 +1085,27 @@ fn warn_if_not_on_path(bin: &Path) {
                 if shell.supports_update() {
                     warn_user!(
                         "`{}` is not on your PATH. To use installed Python executables, run `{}` or `{}`.",
-                        bin.simplified_display().cyan(),
-                        command.green(),
-                        "uv python update-shell".green()
+                        bin.simplified_display().accent(),
+                        command.success(),
+                        "uv python update-shell".success()
                     );
                 } else {
                     warn_user!(
                         "`{}` is not on your PATH. To use installed Python executables, run `{}`.",
-                        bin.simplified_display().cyan(),
-                        command.green()
+                        bin.simplified_display().accent(),
+                        command.success()
                     );
                 }
             } else {
                 warn_user!(
                     "`{}` is not on your PATH. To use installed Python executables, add the directory to your PATH.",
-                    bin.simplified_display().cyan(),
+                    bin.simplified_display().accent(),
                 );
             }
         } else {
             warn_user!(
                 "`{}` is not on your PATH. To use installed Python executables, add the directory to your PATH.",
-                bin.simplified_display().cyan(),
+                bin.simplified_display().accent(),
             );
         }
     }
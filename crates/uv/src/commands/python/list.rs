@@ -1,6 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use uv_cli::PythonListFormat;
 use uv_pep440::Version;
 use uv_preview::Preview;
@@ -13,12 +14,13 @@ use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::{
-    DiscoveryError, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonNotFound,
-    PythonPreference, PythonRequest, PythonSource, find_python_installations,
+    DiscoveryError, EnvironmentPreference, PythonDownloads, PythonInstallation,
+    PythonInstallationKey, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
+    find_python_installations,
 };
 
 use crate::commands::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::PythonListKinds;
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
@@ -35,8 +37,10 @@ struct NamedVersionParts {
     patch: u64,
 }
 
+/// The JSON representation of a Python installation, as shown by `uv python list` and
+/// `uv python find`.
 #[derive(Debug, Serialize)]
-struct PrintData {
+pub(super) struct PrintData {
     key: String,
     version: Version,
     version_parts: NamedVersionParts,
@@ -48,6 +52,60 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    /// The source from which the installation was discovered, as shown with `--all-sources`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+impl PrintData {
+    /// Create the [`PrintData`] for an installation at a local path or a remote URL.
+    pub(super) fn new(key: &PythonInstallationKey, uri: Either<&Path, String>) -> Result<Self> {
+        let mut path_or_none: Option<String> = None;
+        let mut symlink_or_none: Option<String> = None;
+        let mut url_or_none: Option<String> = None;
+        match uri {
+            Either::Left(path) => {
+                path_or_none = Some(path.user_display().to_string());
+
+                let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
+                if is_symlink {
+                    symlink_or_none = Some(path.read_link()?.user_display().to_string());
+                }
+            }
+            Either::Right(url) => {
+                url_or_none = Some(url);
+            }
+        }
+        let version = key.version();
+        let release = version.release();
+
+        Ok(Self {
+            key: key.to_string(),
+            version: version.version().clone(),
+            #[allow(clippy::get_first)]
+            version_parts: NamedVersionParts {
+                major: release.get(0).copied().unwrap_or(0),
+                minor: release.get(1).copied().unwrap_or(0),
+                patch: release.get(2).copied().unwrap_or(0),
+            },
+            path: path_or_none,
+            symlink: symlink_or_none,
+            url: url_or_none,
+            arch: key.arch().to_string(),
+            implementation: key.implementation().to_string(),
+            os: key.os().to_string(),
+            variant: key.variant().to_string(),
+            libc: key.libc().to_string(),
+            source: None,
+        })
+    }
+
+    /// Include the source from which the installation was discovered, if any.
+    #[must_use]
+    fn with_source(mut self, source: Option<PythonSource>) -> Self {
+        self.source = source.map(|source| source.to_string());
+        self
+    }
 }
 
 /// List available Python installations.
@@ -58,6 +116,7 @@ pub(crate) async fn list(
     all_versions: bool,
     all_platforms: bool,
     all_arches: bool,
+    all_sources: bool,
     show_urls: bool,
     output_format: PythonListFormat,
     python_downloads_json_url: Option<String>,
@@ -118,6 +177,7 @@ pub(crate) async fn list(
                 download.key().clone(),
                 Kind::Download,
                 Either::Right(download.url()),
+                None,
             ));
         }
     }
@@ -158,6 +218,7 @@ pub(crate) async fn list(
                 installation.key(),
                 kind,
                 Either::Left(installation.interpreter().real_executable().to_path_buf()),
+                all_sources.then(|| installation.source()),
             ));
         }
     }
@@ -166,10 +227,10 @@ pub(crate) async fn list(
     let mut seen_patch = FxHashSet::default();
     let mut seen_paths = FxHashSet::default();
     let mut include = Vec::new();
-    for (key, kind, uri) in output.iter().rev() {
-        // Do not show the same path more than once
+    for (key, kind, uri, source) in output.iter().rev() {
+        // Do not show the same path more than once (per source, with `--all-sources`)
         if let Either::Left(path) = uri {
-            if !seen_paths.insert(path) {
+            if !seen_paths.insert((path, source)) {
                 continue;
             }
         }
@@ -212,52 +273,19 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, uri, *source));
     }
 
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
-                    let mut path_or_none: Option<String> = None;
-                    let mut symlink_or_none: Option<String> = None;
-                    let mut url_or_none: Option<String> = None;
-                    match uri {
-                        Either::Left(path) => {
-                            path_or_none = Some(path.user_display().to_string());
-
-                            let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
-                            if is_symlink {
-                                symlink_or_none =
-                                    Some(path.read_link()?.user_display().to_string());
-                            }
-                        }
-                        Either::Right(url) => {
-                            url_or_none = Some((*url).to_string());
-                        }
-                    }
-                    let version = key.version();
-                    let release = version.release();
-
-                    Ok(PrintData {
-                        key: key.to_string(),
-                        version: version.version().clone(),
-                        #[allow(clippy::get_first)]
-                        version_parts: NamedVersionParts {
-                            major: release.get(0).copied().unwrap_or(0),
-                            minor: release.get(1).copied().unwrap_or(0),
-                            patch: release.get(2).copied().unwrap_or(0),
-                        },
-                        path: path_or_none,
-                        symlink: symlink_or_none,
-                        url: url_or_none,
-                        arch: key.arch().to_string(),
-                        implementation: key.implementation().to_string(),
-                        os: key.os().to_string(),
-                        variant: key.variant().to_string(),
-                        libc: key.libc().to_string(),
-                    })
+                .map(|(key, uri, source)| {
+                    let uri = match uri {
+                        Either::Left(path) => Either::Left(path.as_path()),
+                        Either::Right(url) => Either::Right((*url).to_string()),
+                    };
+                    Ok(PrintData::new(key, uri)?.with_source(*source))
                 })
                 .collect::<Result<Vec<_>>>()?;
             writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
@@ -266,25 +294,28 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, _, _)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, uri, source) in include {
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
+                        let source = source
+                            .map(|source| format!(" ({source})").dimmed().to_string())
+                            .unwrap_or_default();
                         let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
                         if is_symlink {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {} -> {}",
-                                path.user_display().cyan(),
-                                path.read_link()?.user_display().cyan()
+                                "{key:width$}    {} -> {}{source}",
+                                path.user_display().accent(),
+                                path.read_link()?.user_display().accent()
                             )?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
-                                path.user_display().cyan()
+                                "{key:width$}    {}{source}",
+                                path.user_display().accent()
                             )?;
                         }
                     }
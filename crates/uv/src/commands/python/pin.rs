@@ -3,7 +3,6 @@ use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Result, bail};
-use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -22,7 +21,7 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 use crate::commands::{
     ExitStatus, project::find_requires_python, reporters::PythonDownloadReporter,
 };
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Pin to a specific Python version.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -220,16 +219,16 @@ pub(crate) async fn pin(
         writeln!(
             printer.stdout(),
             "Updated `{}` from `{}` -> `{}`",
-            new.path().user_display().cyan(),
-            existing.to_canonical_string().green(),
-            new.version().unwrap().to_canonical_string().green()
+            new.path().user_display().accent(),
+            existing.to_canonical_string().success(),
+            new.version().unwrap().to_canonical_string().success()
         )?;
     } else {
         writeln!(
             printer.stdout(),
             "Pinned `{}` to `{}`",
-            new.path().user_display().cyan(),
-            new.version().unwrap().to_canonical_string().green()
+            new.path().user_display().accent(),
+            new.version().unwrap().to_canonical_string().success()
         )?;
     }
 
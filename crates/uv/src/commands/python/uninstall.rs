@@ -22,7 +22,7 @@ use uv_python::{PythonInstallationKey, PythonInstallationMinorVersionKey, Python
 use crate::commands::python::install::format_executables;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::{ExitStatus, elapsed};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Uninstall managed Python versions.
 pub(crate) async fn uninstall(
@@ -99,7 +99,7 @@ async fn do_uninstall(
             writeln!(
                 printer.stderr(),
                 "Searching for Python versions matching: {}",
-                request.cyan()
+                request.accent()
             )?;
         }
         let mut found = false;
@@ -127,7 +127,7 @@ async fn do_uninstall(
             writeln!(
                 printer.stderr(),
                 "No existing installations found for: {}",
-                request.cyan()
+                request.accent()
             )?;
         }
     }
@@ -320,7 +320,7 @@ async fn do_uninstall(
                     writeln!(
                         printer.stderr(),
                         " {} {}{}",
-                        "-".red(),
+                        "-".error(),
                         event.key.bold(),
                         executables,
                     )?;
@@ -335,7 +335,7 @@ async fn do_uninstall(
             writeln!(
                 printer.stderr(),
                 "Failed to uninstall {}: {}",
-                key.green(),
+                key.success(),
                 err.to_string().trim()
             )?;
         }
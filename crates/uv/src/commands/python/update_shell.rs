@@ -3,7 +3,6 @@
 use std::fmt::Write;
 
 use anyhow::Result;
-use owo_colors::OwoColorize;
 use tokio::io::AsyncWriteExt;
 use tracing::debug;
 
@@ -12,7 +11,7 @@ use uv_python::managed::python_executable_dir;
 use uv_shell::Shell;
 
 use crate::commands::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Ensure that the executable directory is in PATH.
 pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
@@ -28,14 +27,14 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
             writeln!(
                 printer.stderr(),
                 "Updated PATH to include executable directory {}",
-                executable_directory.simplified_display().cyan()
+                executable_directory.simplified_display().accent()
             )?;
             writeln!(printer.stderr(), "Restart your shell to apply changes")?;
         } else {
             writeln!(
                 printer.stderr(),
                 "Executable directory {} is already in PATH",
-                executable_directory.simplified_display().cyan()
+                executable_directory.simplified_display().accent()
             )?;
         }
 
@@ -46,7 +45,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
         writeln!(
             printer.stderr(),
             "Executable directory {} is already in PATH",
-            executable_directory.simplified_display().cyan()
+            executable_directory.simplified_display().accent()
         )?;
         return Ok(ExitStatus::Success);
     }
@@ -55,7 +54,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     let Some(shell) = Shell::from_env() else {
         return Err(anyhow::anyhow!(
             "The executable directory {} is not in PATH, but the current shell could not be determined",
-            executable_directory.simplified_display().cyan()
+            executable_directory.simplified_display().accent()
         ));
     };
 
@@ -64,7 +63,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     if files.is_empty() {
         return Err(anyhow::anyhow!(
             "The executable directory {} is not in PATH, but updating {shell} is currently unsupported",
-            executable_directory.simplified_display().cyan()
+            executable_directory.simplified_display().accent()
         ));
     }
 
@@ -72,7 +71,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     let Some(command) = shell.prepend_path(&executable_directory) else {
         return Err(anyhow::anyhow!(
             "The executable directory {} is not in PATH, but the necessary command to update {shell} could not be determined",
-            executable_directory.simplified_display().cyan()
+            executable_directory.simplified_display().accent()
         ));
     };
 
@@ -108,7 +107,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
                 writeln!(
                     printer.stderr(),
                     "Updated configuration file: {}",
-                    file.simplified_display().cyan()
+                    file.simplified_display().accent()
                 )?;
                 updated = true;
             }
@@ -131,7 +130,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
                 writeln!(
                     printer.stderr(),
                     "Created configuration file: {}",
-                    file.simplified_display().cyan()
+                    file.simplified_display().accent()
                 )?;
                 updated = true;
             }
@@ -147,7 +146,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     } else {
         Err(anyhow::anyhow!(
             "The executable directory {} is not in PATH, but the {shell} configuration files are already up-to-date",
-            executable_directory.simplified_display().cyan()
+            executable_directory.simplified_display().accent()
         ))
     }
 }
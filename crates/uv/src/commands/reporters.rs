@@ -10,10 +10,11 @@ use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use crate::commands::human_readable_bytes;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled, Theme};
 use uv_cache::Removal;
 use uv_distribution_types::{
-    BuildableSource, CachedDist, DistributionMetadata, Name, SourceDist, VersionOrUrlRef,
+    BuildableSource, CachedDist, DistributionMetadata, InstalledDist, Name, SourceDist,
+    VersionOrUrlRef,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -170,7 +171,7 @@ impl ProgressReporter {
         progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
         let message = format!(
             "   {} {}",
-            "Building".bold().cyan(),
+            "Building".bold().accent(),
             source.to_color_string()
         );
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
@@ -200,7 +201,7 @@ impl ProgressReporter {
 
         let message = format!(
             "      {} {}",
-            "Built".bold().green(),
+            "Built".bold().success(),
             source.to_color_string()
         );
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
@@ -229,9 +230,9 @@ impl ProgressReporter {
         for progress in state.bars.values_mut() {
             // Ignore spinners, such as for builds.
             if let ProgressBarKind::Numeric { progress, .. } = progress {
-                let template = format!(
-                    "{{msg:{max_len}.dim}} {{bar:30.green/dim}} {{binary_bytes:>7}}/{{binary_total_bytes:7}}"
-                );
+                let template = Theme::current().progress_template(&format!(
+                    "{{msg:{max_len}.dim}} {{bar:30.$success/dim}} {{binary_bytes:>7}}/{{binary_total_bytes:7}}"
+                ));
                 progress.set_style(
                     ProgressStyle::with_template(&template)
                         .unwrap()
@@ -251,9 +252,9 @@ impl ProgressReporter {
             // We're using binary bytes to match `human_readable_bytes`.
             progress.set_style(
                 ProgressStyle::with_template(
-                    &format!(
-                        "{{msg:{}.dim}} {{bar:30.green/dim}} {{binary_bytes:>7}}/{{binary_total_bytes:7}}", state.max_len
-                    ),
+                    &Theme::current().progress_template(&format!(
+                        "{{msg:{}.dim}} {{bar:30.$success/dim}} {{binary_bytes:>7}}/{{binary_total_bytes:7}}", state.max_len
+                    )),
                 )
                     .unwrap()
                     .progress_chars("--"),
@@ -271,7 +272,7 @@ impl ProgressReporter {
                         Direction::Extract => "Extracting",
                     }
                     .bold()
-                    .cyan(),
+                    .accent(),
                     name,
                     format!("({bytes:.1}{unit})").dimmed()
                 );
@@ -283,7 +284,7 @@ impl ProgressReporter {
                 let _ = writeln!(
                     self.printer.stderr(),
                     "{} {}",
-                    direction.as_str().bold().cyan(),
+                    direction.as_str().bold().accent(),
                     name
                 );
             }
@@ -324,7 +325,7 @@ impl ProgressReporter {
                 let _ = writeln!(
                     self.printer.stderr(),
                     " {} {}",
-                    direction.as_str().bold().green(),
+                    direction.as_str().bold().success(),
                     progress.message()
                 );
             }
@@ -376,7 +377,12 @@ impl ProgressReporter {
         );
 
         progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
-        let message = format!("   {} {} ({})", "Updating".bold().cyan(), url, rev.dimmed());
+        let message = format!(
+            "   {} {} ({})",
+            "Updating".bold().accent(),
+            url,
+            rev.dimmed()
+        );
         if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
             let _ = writeln!(self.printer.stderr(), "{message}");
         }
@@ -405,7 +411,7 @@ impl ProgressReporter {
 
         let message = format!(
             "    {} {} ({})",
-            "Updated".bold().green(),
+            "Updated".bold().success(),
             url,
             rev.dimmed()
         );
@@ -427,9 +433,11 @@ impl From<Printer> for PrepareReporter {
         let root = multi_progress.add(ProgressBar::with_draw_target(None, printer.target()));
         root.enable_steady_tick(Duration::from_millis(200));
         root.set_style(
-            ProgressStyle::with_template("{spinner:.white} {msg:.dim} ({pos}/{len})")
-                .unwrap()
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+            ProgressStyle::with_template(
+                &Theme::current().progress_template("{spinner:.$accent} {msg:.dim} ({pos}/{len})"),
+            )
+            .unwrap()
+            .tick_strings(Theme::current().spinner()),
         );
         root.set_message("Preparing packages...");
 
@@ -506,9 +514,11 @@ impl From<Printer> for ResolverReporter {
         let root = multi_progress.add(ProgressBar::with_draw_target(None, printer.target()));
         root.enable_steady_tick(Duration::from_millis(200));
         root.set_style(
-            ProgressStyle::with_template("{spinner:.white} {wide_msg:.dim}")
-                .unwrap()
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+            ProgressStyle::with_template(
+                &Theme::current().progress_template("{spinner:.$accent} {wide_msg:.dim}"),
+            )
+            .unwrap()
+            .tick_strings(Theme::current().spinner()),
         );
         root.set_message("Resolving dependencies...");
 
@@ -602,7 +612,12 @@ impl From<Printer> for InstallReporter {
     fn from(printer: Printer) -> Self {
         let progress = ProgressBar::with_draw_target(None, printer.target());
         progress.set_style(
-            ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
+            ProgressStyle::with_template(
+                &Theme::current()
+                    .progress_template("{bar:20.$success} [{pos}/{len}] {wide_msg:.dim}"),
+            )
+            .unwrap()
+            .progress_chars(Theme::current().progress_chars()),
         );
         progress.set_message("Installing wheels...");
         Self { progress }
@@ -629,6 +644,47 @@ impl uv_installer::InstallReporter for InstallReporter {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct UninstallReporter {
+    progress: ProgressBar,
+}
+
+impl From<Printer> for UninstallReporter {
+    fn from(printer: Printer) -> Self {
+        let progress = ProgressBar::with_draw_target(None, printer.target());
+        progress.set_style(
+            ProgressStyle::with_template(
+                &Theme::current()
+                    .progress_template("{bar:20.$success} [{pos}/{len}] {wide_msg:.dim}"),
+            )
+            .unwrap()
+            .progress_chars(Theme::current().progress_chars()),
+        );
+        progress.set_message("Uninstalling packages...");
+        Self { progress }
+    }
+}
+
+impl UninstallReporter {
+    #[must_use]
+    pub(crate) fn with_length(self, length: u64) -> Self {
+        self.progress.set_length(length);
+        self
+    }
+}
+
+impl uv_installer::UninstallReporter for UninstallReporter {
+    fn on_uninstall_progress(&self, dist: &InstalledDist) {
+        self.progress.set_message(format!("{dist}"));
+        self.progress.inc(1);
+    }
+
+    fn on_uninstall_complete(&self) {
+        self.progress.set_message("");
+        self.progress.finish_and_clear();
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PythonDownloadReporter {
     reporter: ProgressReporter,
@@ -722,7 +778,12 @@ impl From<Printer> for LatestVersionReporter {
     fn from(printer: Printer) -> Self {
         let progress = ProgressBar::with_draw_target(None, printer.target());
         progress.set_style(
-            ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
+            ProgressStyle::with_template(
+                &Theme::current()
+                    .progress_template("{bar:20.$success} [{pos}/{len}] {wide_msg:.dim}"),
+            )
+            .unwrap()
+            .progress_chars(Theme::current().progress_chars()),
         );
         progress.set_message("Fetching latest versions...");
         Self { progress }
@@ -761,11 +822,13 @@ impl CleaningDirectoryReporter {
     pub(crate) fn new(printer: Printer, max: usize) -> Self {
         let bar = ProgressBar::with_draw_target(Some(max as u64), printer.target());
         bar.set_style(
-            ProgressStyle::with_template("{prefix} [{bar:20}] {percent}%")
-                .unwrap()
-                .progress_chars("=> "),
+            ProgressStyle::with_template(
+                &Theme::current().progress_template("{prefix} [{bar:20.$accent}] {percent}%"),
+            )
+            .unwrap()
+            .progress_chars("=> "),
         );
-        bar.set_prefix(format!("{}", "Cleaning".bold().cyan()));
+        bar.set_prefix(format!("{}", "Cleaning".bold().accent()));
         Self { bar }
     }
 }
@@ -790,11 +853,13 @@ impl CleaningPackageReporter {
     pub(crate) fn new(printer: Printer, max: usize) -> Self {
         let bar = ProgressBar::with_draw_target(Some(max as u64), printer.target());
         bar.set_style(
-            ProgressStyle::with_template("{prefix} [{bar:20}] {pos}/{len}{msg}")
-                .unwrap()
-                .progress_chars("=> "),
+            ProgressStyle::with_template(
+                &Theme::current().progress_template("{prefix} [{bar:20.$accent}] {pos}/{len}{msg}"),
+            )
+            .unwrap()
+            .progress_chars("=> "),
         );
-        bar.set_prefix(format!("{}", "Cleaning".bold().cyan()));
+        bar.set_prefix(format!("{}", "Cleaning".bold().accent()));
         Self { bar }
     }
 
@@ -9,7 +9,7 @@ use uv_client::{BaseClientBuilder, WrappedReqwestError};
 use uv_fs::Simplified;
 
 use crate::commands::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Attempt to update the uv binary.
 pub(crate) async fn self_update(
@@ -25,7 +25,7 @@ pub(crate) async fn self_update(
             "{}",
             format_args!(
                 "{}{} Self-update is not possible because network connectivity is disabled (i.e., with `--offline`)",
-                "error".red().bold(),
+                "error".error().bold(),
                 ":".bold()
             )
         )?;
@@ -53,7 +53,7 @@ pub(crate) async fn self_update(
                     "\n",
                     "If you installed uv with pip, brew, or another package manager, update uv with `pip install --upgrade`, `brew upgrade`, or similar."
                 ),
-                "error".red().bold(),
+                "error".error().bold(),
                 ":".bold()
             )
         )?;
@@ -85,10 +85,10 @@ pub(crate) async fn self_update(
                     "\n",
                     "The current executable is at `{}` but the standalone installer was used to install uv to `{}`. Are multiple copies of uv installed?"
                 ),
-                "error".red().bold(),
+                "error".error().bold(),
                 ":".bold(),
-                current_exe.simplified_display().bold().cyan(),
-                receipt_prefix.simplified_display().bold().cyan()
+                current_exe.simplified_display().bold().accent(),
+                receipt_prefix.simplified_display().bold().accent()
             )
         )?;
         return Ok(ExitStatus::Error);
@@ -99,7 +99,7 @@ pub(crate) async fn self_update(
         "{}",
         format_args!(
             "{}{} Checking for updates...",
-            "info".cyan().bold(),
+            "info".accent().bold(),
             ":".bold()
         )
     )?;
@@ -160,11 +160,11 @@ pub(crate) async fn self_update(
             let version_information = if let Some(old_version) = result.old_version {
                 format!(
                     "from {} to {}",
-                    format!("v{old_version}").bold().cyan(),
-                    format!("v{}", result.new_version).bold().cyan(),
+                    format!("v{old_version}").bold().accent(),
+                    format!("v{}", result.new_version).bold().accent(),
                 )
             } else {
-                format!("to {}", format!("v{}", result.new_version).bold().cyan())
+                format!("to {}", format!("v{}", result.new_version).bold().accent())
             };
 
             writeln!(
@@ -172,14 +172,14 @@ pub(crate) async fn self_update(
                 "{}",
                 format_args!(
                     "{}{} {direction} uv {}! {}",
-                    "success".green().bold(),
+                    "success".success().bold(),
                     ":".bold(),
                     version_information,
                     format!(
                         "https://github.com/astral-sh/uv/releases/tag/{}",
                         result.new_version_tag
                     )
-                    .cyan()
+                    .accent()
                 )
             )?;
         }
@@ -189,9 +189,9 @@ pub(crate) async fn self_update(
                 "{}",
                 format_args!(
                     "{}{} You're on the latest version of uv ({})",
-                    "success".green().bold(),
+                    "success".success().bold(),
                     ":".bold(),
-                    format!("v{}", env!("CARGO_PKG_VERSION")).bold().cyan()
+                    format!("v{}", env!("CARGO_PKG_VERSION")).bold().accent()
                 )
             )?;
         }
@@ -203,9 +203,9 @@ pub(crate) async fn self_update(
                         "{}",
                         format_args!(
                             "{}{} GitHub API rate limit exceeded. Please provide a GitHub token via the {} option.",
-                            "error".red().bold(),
+                            "error".error().bold(),
                             ":".bold(),
-                            "`--token`".green().bold()
+                            "`--token`".success().bold()
                         )
                     )?;
                     Ok(ExitStatus::Error)
@@ -0,0 +1,56 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use uv_fs::Simplified;
+use uv_installer::Store;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::{Printer, Styled};
+
+/// Remove all packages from the shared store that aren't used by any environment.
+pub(crate) fn store_gc(site_packages_dir: &Path, printer: Printer) -> Result<ExitStatus> {
+    if !site_packages_dir.exists() {
+        writeln!(
+            printer.stderr(),
+            "No store found at: {}",
+            site_packages_dir.user_display().accent()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let store = Store::from_path(site_packages_dir);
+
+    writeln!(
+        printer.stderr(),
+        "Pruning store at: {}",
+        store.root().user_display().accent()
+    )?;
+
+    let summary = store
+        .gc()
+        .with_context(|| format!("Failed to prune store at: {}", store.root().user_display()))?;
+
+    // Write a summary of the number of packages removed.
+    match summary.num_entries {
+        0 => write!(printer.stderr(), "No unused packages found")?,
+        1 => write!(printer.stderr(), "Removed 1 package")?,
+        num_entries => write!(printer.stderr(), "Removed {num_entries} packages")?,
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.success())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,279 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, Constraints};
+use uv_fs::{Quarantine, Simplified};
+use uv_normalize::PackageName;
+use uv_preview::Preview;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation, PythonPreference,
+};
+use uv_requirements::RequirementsSpecification;
+use uv_settings::{ResolverInstallerOptions, ToolOptions};
+use uv_tool::{InstalledTools, Tool};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::loggers::{DefaultInstallLogger, SummaryResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::{PlatformState, resolve_environment, sync_environment};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::tool::common::{finalize_tool_install, remove_entrypoints};
+use crate::printer::{Printer, Styled};
+use crate::settings::ResolverInstallerSettings;
+
+/// Validate the environments and executables of all installed tools, optionally repairing any
+/// broken tools.
+pub(crate) async fn check(
+    repair: bool,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    quarantine: Quarantine,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut tools = installed_tools.tools()?.into_iter().collect::<Vec<_>>();
+    tools.sort_by_key(|(name, _)| name.clone());
+
+    if tools.is_empty() {
+        writeln!(printer.stderr(), "No tools installed")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut broken = 0usize;
+    for (name, tool) in tools {
+        let Ok(tool) = tool else {
+            writeln!(
+                printer.stdout(),
+                "{}: malformed receipt (run `{}` to remove)",
+                name.error(),
+                format!("uv tool uninstall {name}").success()
+            )?;
+            broken += 1;
+            continue;
+        };
+
+        let problems = diagnose(&name, &tool, &installed_tools, cache)?;
+        if problems.is_empty() {
+            writeln!(printer.stdout(), "{}: ok", name.success())?;
+            continue;
+        }
+
+        for problem in &problems {
+            writeln!(printer.stdout(), "{}: {problem}", name.error())?;
+        }
+
+        if repair {
+            match repair_tool(
+                &name,
+                &tool,
+                &installed_tools,
+                &client_builder,
+                python_preference,
+                python_downloads,
+                installer_metadata,
+                quarantine,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await
+            {
+                Ok(_) => {
+                    writeln!(printer.stderr(), "Repaired `{}`", name.accent())?;
+                }
+                Err(err) => {
+                    writeln!(
+                        printer.stderr(),
+                        "Failed to repair `{}`: {err}",
+                        name.accent()
+                    )?;
+                    broken += 1;
+                }
+            }
+        } else {
+            broken += 1;
+        }
+    }
+
+    if broken > 0 {
+        if !repair {
+            writeln!(
+                printer.stderr(),
+                "{}{} Run `{}` to recreate broken tool environments",
+                "hint".bold().accent(),
+                ":".bold(),
+                "uv tool check --repair".success()
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collect the problems with an installed tool, if any.
+fn diagnose(
+    name: &PackageName,
+    tool: &Tool,
+    installed_tools: &InstalledTools,
+    cache: &Cache,
+) -> Result<Vec<String>> {
+    let mut problems = Vec::new();
+
+    // Ensure that the environment exists and its interpreter is intact.
+    match installed_tools.get_environment(name, cache) {
+        Ok(Some(_)) => {}
+        Ok(None) => problems.push("missing Python interpreter".to_string()),
+        Err(err) => problems.push(format!("invalid environment ({err})")),
+    }
+
+    // Ensure that each executable exists (and, if it's a link, that its target exists).
+    for entrypoint in tool.entrypoints() {
+        if !entrypoint.install_path.exists() {
+            problems.push(format!(
+                "missing executable `{}` at {}",
+                entrypoint.name,
+                entrypoint.install_path.user_display()
+            ));
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Recreate the environment for an installed tool from its receipt, and reinstall its
+/// executables.
+pub(crate) async fn repair_tool(
+    name: &PackageName,
+    receipt: &Tool,
+    installed_tools: &InstalledTools,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    quarantine: Quarantine,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<PythonEnvironment> {
+    debug!("Repairing tool: `{name}`");
+
+    let reporter = PythonDownloadReporter::single(printer);
+
+    // Find (or download) an interpreter that satisfies the tool's original request.
+    let interpreter = PythonInstallation::find_or_download(
+        receipt.python().as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        client_builder,
+        cache,
+        Some(&reporter),
+        None,
+        None,
+        None,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Respect the settings with which the tool was originally installed.
+    let options = ResolverInstallerOptions::from(receipt.options().clone());
+    let settings = ResolverInstallerSettings::from(options.clone());
+
+    let build_constraints =
+        Constraints::from_requirements(receipt.build_constraints().iter().cloned());
+
+    let spec = RequirementsSpecification::from_overrides(
+        receipt.requirements().to_vec(),
+        receipt.constraints().to_vec(),
+        receipt.overrides().to_vec(),
+    );
+
+    let state = PlatformState::default();
+
+    let resolution = resolve_environment(
+        spec.into(),
+        &interpreter,
+        None,
+        build_constraints.clone(),
+        &settings.resolver,
+        client_builder,
+        &state,
+        Box::new(SummaryResolveLogger),
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    let environment = installed_tools.create_environment(name, interpreter, preview)?;
+
+    let environment = sync_environment(
+        environment,
+        &resolution.into(),
+        Modifications::Exact,
+        build_constraints,
+        (&settings).into(),
+        client_builder,
+        &state,
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        quarantine,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Reinstall the executables, which may have been linked to the previous environment.
+    remove_entrypoints(receipt);
+
+    let entrypoints: Vec<_> = receipt
+        .entrypoints()
+        .iter()
+        .filter_map(|entry| PackageName::from_str(entry.from.as_ref()?).ok())
+        .collect();
+
+    finalize_tool_install(
+        &environment,
+        name,
+        &entrypoints,
+        installed_tools,
+        &ToolOptions::from(options),
+        true,
+        receipt.python().to_owned(),
+        receipt.requirements().to_vec(),
+        receipt.constraints().to_vec(),
+        receipt.overrides().to_vec(),
+        receipt.build_constraints().to_vec(),
+        printer,
+        preview,
+    )?;
+
+    Ok(environment)
+}
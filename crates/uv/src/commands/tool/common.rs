@@ -18,7 +18,7 @@ use uv_fs::replace_symlink;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeatures};
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, VersionRequest,
@@ -31,7 +31,7 @@ use uv_warnings::warn_user_once;
 use crate::commands::pip;
 use crate::commands::project::ProjectError;
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// Return all packages which contain an executable with the given name.
 pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec<InstalledDist> {
@@ -182,6 +182,7 @@ pub(crate) fn finalize_tool_install(
     overrides: Vec<Requirement>,
     build_constraints: Vec<Requirement>,
     printer: Printer,
+    preview: Preview,
 ) -> anyhow::Result<()> {
     let executable_directory = uv_tool::tool_executable_dir()?;
     fs_err::create_dir_all(&executable_directory)
@@ -235,11 +236,11 @@ pub(crate) fn finalize_tool_install(
                 writeln!(
                     printer.stdout(),
                     "No executables are provided by package `{}`\n{}{} Use `--with {}` to include `{}` as a dependency without installing its executables.",
-                    package.cyan(),
-                    "hint".bold().cyan(),
+                    package.accent(),
+                    "hint".bold().accent(),
                     ":".bold(),
-                    package.cyan(),
-                    package.cyan(),
+                    package.accent(),
+                    package.accent(),
                 )?;
                 continue;
             }
@@ -248,7 +249,7 @@ pub(crate) fn finalize_tool_install(
             writeln!(
                 printer.stdout(),
                 "No executables are provided by package `{}`; removing tool",
-                package.cyan()
+                package.accent()
             )?;
 
             hint_executable_from_dependency(package, &site_packages, printer)?;
@@ -258,7 +259,7 @@ pub(crate) fn finalize_tool_install(
 
             return Err(anyhow::anyhow!(
                 "Failed to install entrypoints for `{}`",
-                package.cyan()
+                package.accent()
             ));
         }
 
@@ -294,12 +295,22 @@ pub(crate) fn finalize_tool_install(
         #[cfg(windows)]
         let itself = std::env::current_exe().ok();
 
+        #[cfg(windows)]
+        if preview.is_enabled(PreviewFeatures::TOOL_SHIMS) {
+            debug!("Tool shims are not supported on Windows; copying executables instead");
+        }
+
         let mut names = BTreeSet::new();
         for (name, src, target) in target_entrypoints {
             debug!("Installing executable: `{name}`");
 
             #[cfg(unix)]
-            replace_symlink(src, &target).context("Failed to install executable")?;
+            if preview.is_enabled(PreviewFeatures::TOOL_SHIMS) {
+                write_shim(&name, package, &src, &target, environment)
+                    .context("Failed to install executable")?;
+            } else {
+                replace_symlink(src, &target).context("Failed to install executable")?;
+            }
 
             #[cfg(windows)]
             if itself.as_ref().is_some_and(|itself| {
@@ -346,6 +357,49 @@ pub(crate) fn finalize_tool_install(
     Ok(())
 }
 
+/// Write a shim that runs an executable from the tool environment.
+///
+/// If the environment's interpreter was removed, the shim instead invokes `uv tool run`, which
+/// recreates the environment from the tool receipt before running the executable.
+#[cfg(unix)]
+fn write_shim(
+    executable: &str,
+    package: &PackageName,
+    source: &Path,
+    target: &Path,
+    environment: &PythonEnvironment,
+) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    use uv_shell::escape_posix_for_single_quotes;
+
+    let quote = |value: &str| format!("'{}'", escape_posix_for_single_quotes(value));
+    let uv = std::env::current_exe().map_or_else(
+        |_| "uv".to_string(),
+        |path| path.to_string_lossy().into_owned(),
+    );
+
+    let contents = format!(
+        "#!/bin/sh\n\
+         if [ -x {python} ]; then\n    exec {source} \"$@\"\nfi\n\
+         UV={uv}\n\
+         [ -x \"$UV\" ] || UV=uv\n\
+         exec \"$UV\" tool run --preview-features tool-shims --from {package} {executable} \"$@\"\n",
+        python = quote(&environment.python_executable().to_string_lossy()),
+        source = quote(&source.to_string_lossy()),
+        uv = quote(&uv),
+        package = quote(package.as_ref()),
+        executable = quote(executable),
+    );
+
+    // Remove any existing executable, which may be a symlink into a previous environment.
+    if target.symlink_metadata().is_ok() {
+        fs_err::remove_file(target)?;
+    }
+    fs_err::write(target, contents)?;
+    fs_err::set_permissions(target, std::fs::Permissions::from_mode(0o755))
+}
+
 fn warn_out_of_path(executable_directory: &Path) {
     // If the executable directory isn't on the user's PATH, warn.
     if !Shell::contains_path(executable_directory) {
@@ -354,27 +408,27 @@ fn warn_out_of_path(executable_directory: &Path) {
                 if shell.supports_update() {
                     warn_user_once!(
                         "`{}` is not on your PATH. To use installed tools, run `{}` or `{}`.",
-                        executable_directory.simplified_display().cyan(),
-                        command.green(),
-                        "uv tool update-shell".green()
+                        executable_directory.simplified_display().accent(),
+                        command.success(),
+                        "uv tool update-shell".success()
                     );
                 } else {
                     warn_user_once!(
                         "`{}` is not on your PATH. To use installed tools, run `{}`.",
-                        executable_directory.simplified_display().cyan(),
-                        command.green()
+                        executable_directory.simplified_display().accent(),
+                        command.success()
                     );
                 }
             } else {
                 warn_user_once!(
                     "`{}` is not on your PATH. To use installed tools, add the directory to your PATH.",
-                    executable_directory.simplified_display().cyan(),
+                    executable_directory.simplified_display().accent(),
                 );
             }
         } else {
             warn_user_once!(
                 "`{}` is not on your PATH. To use installed tools, add the directory to your PATH.",
-                executable_directory.simplified_display().cyan(),
+                executable_directory.simplified_display().accent(),
             );
         }
     }
@@ -394,10 +448,10 @@ fn hint_executable_from_dependency(
             writeln!(
                 printer.stdout(),
                 "{}{} An executable with the name `{}` is available via dependency `{}`.\n      Did you mean `{}`?",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
-                name.cyan(),
-                package.name().cyan(),
+                name.accent(),
+                package.name().accent(),
                 command.bold(),
             )?;
         }
@@ -405,13 +459,13 @@ fn hint_executable_from_dependency(
             writeln!(
                 printer.stdout(),
                 "{}{} An executable with the name `{}` is available via the following dependencies::",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
-                name.cyan(),
+                name.accent(),
             )?;
 
             for package in packages {
-                writeln!(printer.stdout(), "- {}", package.name().cyan())?;
+                writeln!(printer.stdout(), "- {}", package.name().accent())?;
             }
             writeln!(
                 printer.stdout(),
@@ -2,7 +2,6 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Result, bail};
-use owo_colors::OwoColorize;
 use tracing::{debug, trace};
 
 use uv_cache::{Cache, Refresh};
@@ -14,6 +13,7 @@ use uv_distribution_types::{
     ExtraBuildRequires, NameRequirementSpecification, Requirement, RequirementSource,
     UnresolvedRequirementSpecification,
 };
+use uv_fs::Quarantine;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -41,7 +41,7 @@ use crate::commands::tool::common::{
 };
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::{diagnostics, reporters::PythonDownloadReporter};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::{ResolverInstallerSettings, ResolverSettings};
 
 /// Install a tool.
@@ -65,6 +65,7 @@ pub(crate) async fn install(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: Cache,
     printer: Printer,
@@ -127,8 +128,8 @@ pub(crate) async fn install(
                 let Ok(executable) = PackageName::from_str(executable) else {
                     bail!(
                         "Package requirement (`{from}`) provided with `--from` conflicts with install request (`{executable}`)",
-                        from = from.cyan(),
-                        executable = executable.cyan()
+                        from = from.accent(),
+                        executable = executable.accent()
                     )
                 };
                 Some(executable)
@@ -157,8 +158,8 @@ pub(crate) async fn install(
                 if requirement.name != executable {
                     bail!(
                         "Package name (`{}`) provided with `--from` does not match install request (`{}`)",
-                        requirement.name.cyan(),
-                        executable.cyan()
+                        requirement.name.accent(),
+                        executable.accent()
                     );
                 }
             }
@@ -215,8 +216,8 @@ pub(crate) async fn install(
         ToolRequest::Python { .. } => {
             bail!(
                 "Cannot install Python with `{}`. Did you mean to use `{}`?",
-                "uv tool install".cyan(),
-                "uv python install".cyan(),
+                "uv tool install".accent(),
+                "uv python install".accent(),
             );
         }
     };
@@ -332,7 +333,7 @@ pub(crate) async fn install(
                     Ok(()) => {
                         warn_user!(
                             "Removed existing `{}` with invalid receipt",
-                            package_name.cyan()
+                            package_name.accent()
                         );
                     }
                     Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -359,7 +360,7 @@ pub(crate) async fn install(
                     let _ = writeln!(
                         printer.stderr(),
                         "Ignoring existing environment for `{}`: the requested Python interpreter does not match the environment interpreter",
-                        package_name.cyan(),
+                        package_name.accent(),
                     );
                     false
                 }
@@ -406,6 +407,7 @@ pub(crate) async fn install(
                         constraints.iter(),
                         overrides.iter(),
                         InstallationStrategy::Permissive,
+                        true,
                         &markers,
                         &tags,
                         config_setting,
@@ -426,7 +428,7 @@ pub(crate) async fn install(
                     writeln!(
                         printer.stderr(),
                         "`{}` is already installed",
-                        requirement.cyan()
+                        requirement.accent()
                     )?;
 
                     return Ok(ExitStatus::Success);
@@ -473,6 +475,7 @@ pub(crate) async fn install(
             Box::new(DefaultResolveLogger),
             Box::new(DefaultInstallLogger),
             installer_metadata,
+            quarantine,
             concurrency,
             &cache,
             workspace_cache,
@@ -488,7 +491,7 @@ pub(crate) async fn install(
                     client_builder.is_native_tls(),
                 )
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
             }
             Err(err) => return Err(err.into()),
         };
@@ -551,7 +554,7 @@ pub(crate) async fn install(
                             client_builder.is_native_tls(),
                         )
                         .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .map_err(Into::into);
                     };
 
                     debug!(
@@ -582,7 +585,7 @@ pub(crate) async fn install(
                                 client_builder.is_native_tls(),
                             )
                             .report(err)
-                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                            .map_err(Into::into);
                         }
                         Err(err) => return Err(err.into()),
                     }
@@ -610,6 +613,7 @@ pub(crate) async fn install(
             &state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
+            quarantine,
             concurrency,
             &cache,
             printer,
@@ -627,7 +631,7 @@ pub(crate) async fn install(
                     client_builder.is_native_tls(),
                 )
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
             }
             Err(err) => return Err(err.into()),
         }
@@ -646,6 +650,7 @@ pub(crate) async fn install(
         overrides,
         build_constraints,
         printer,
+        preview,
     )?;
 
     Ok(ExitStatus::Success)
@@ -10,7 +10,7 @@ use uv_tool::InstalledTools;
 use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 /// List installed tools.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -45,7 +45,7 @@ pub(crate) async fn list(
         let Ok(tool) = tool else {
             warn_user!(
                 "Ignoring malformed tool `{name}` (run `{}` to remove)",
-                format!("uv tool uninstall {name}").green()
+                format!("uv tool uninstall {name}").success()
             );
             continue;
         };
@@ -57,7 +57,7 @@ pub(crate) async fn list(
                 if let uv_tool::Error::EnvironmentError(e) = e {
                     warn_user!(
                         "{e} (run `{}` to reinstall)",
-                        format!("uv tool install {name} --reinstall").green()
+                        format!("uv tool install {name} --reinstall").success()
                     );
                 } else {
                     writeln!(printer.stderr(), "{e}")?;
@@ -121,7 +121,10 @@ pub(crate) async fn list(
                     "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}"
                 )
                 .bold(),
-                installed_tools.tool_dir(&name).simplified_display().cyan(),
+                installed_tools
+                    .tool_dir(&name)
+                    .simplified_display()
+                    .accent(),
             )?;
         } else {
             writeln!(
@@ -137,7 +140,7 @@ pub(crate) async fn list(
         // Output tool entrypoints
         for entrypoint in tool.entrypoints() {
             if show_paths {
-                writeln!(printer.stdout(), "- {}", entrypoint.to_string().cyan())?;
+                writeln!(printer.stdout(), "- {}", entrypoint.to_string().accent())?;
             } else {
                 writeln!(printer.stdout(), "- {}", entrypoint.name)?;
             }
@@ -6,11 +6,13 @@ use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
 use uv_python::PythonRequest;
 
+pub(crate) mod check;
 mod common;
 pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod run;
+pub(crate) mod show;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 pub(crate) mod upgrade;
@@ -25,7 +25,7 @@ use uv_distribution_types::{
     IndexUrl, Name, NameRequirementSpecification, Requirement, RequirementSource,
     UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
-use uv_fs::Simplified;
+use uv_fs::{Quarantine, Simplified};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -55,10 +55,11 @@ use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_names,
 };
 use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::tool::check::repair_tool;
 use crate::commands::tool::common::{matching_packages, refine_interpreter};
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::{diagnostics, project::environment::CachedEnvironment};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 use crate::settings::ResolverInstallerSettings;
 use crate::settings::ResolverSettings;
 
@@ -101,6 +102,7 @@ pub(crate) async fn run(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: Cache,
     printer: Printer,
@@ -175,16 +177,16 @@ pub(crate) async fn run(
             let package_name = PackageName::from_str(from)?;
             return Err(anyhow::anyhow!(
                 "It looks like you provided a Python script to `--from`, which is not supported\n\n{}{} If you meant to run a command from the `{}` package, use the normalized package name instead to disambiguate, e.g., `{}`",
-                "hint".bold().cyan(),
+                "hint".bold().accent(),
                 ":".bold(),
-                package_name.cyan(),
+                package_name.accent(),
                 format!(
                     "{} --from {} {}",
                     invocation_source,
-                    package_name.cyan(),
+                    package_name.accent(),
                     target
                 )
-                .green(),
+                .success(),
             ));
         }
     } else {
@@ -197,19 +199,19 @@ pub(crate) async fn run(
                     "It looks like you tried to run a Python script at `{}`, which is not supported by `{}`\n\n{}{} Use `{}` instead",
                     target_path.user_display(),
                     invocation_source,
-                    "hint".bold().cyan(),
+                    "hint".bold().accent(),
                     ":".bold(),
-                    format!("uv run {}", target_path.user_display().cyan()),
+                    format!("uv run {}", target_path.user_display().accent()),
                 ))
             } else {
                 let package_name = PackageName::from_str(target)?;
                 Err(anyhow::anyhow!(
                     "It looks like you provided a Python script to run, which is not supported supported by `{}`\n\n{}{} We did not find a script at the requested path. If you meant to run a command from the `{}` package, pass the normalized package name to `--from` to disambiguate, e.g., `{}`",
                     invocation_source,
-                    "hint".bold().cyan(),
+                    "hint".bold().accent(),
                     ":".bold(),
-                    package_name.cyan(),
-                    format!("{invocation_source} --from {package_name} {target}").green(),
+                    package_name.accent(),
+                    format!("{invocation_source} --from {package_name} {target}").success(),
                 ))
             };
         }
@@ -232,9 +234,9 @@ pub(crate) async fn run(
             let rest = args.iter().map(|s| s.to_string_lossy()).join(" ");
             let prompt = format!(
                 "`{}` invokes the `{}` package. Did you mean `{}`?",
-                format!("uvx run {rest}").green(),
-                "run".cyan(),
-                format!("uvx {rest}").green()
+                format!("uvx run {rest}").success(),
+                "run".accent(),
+                format!("uvx {rest}").success()
             );
             let confirmation = uv_console::confirm(&prompt, &term, true)?;
             if confirmation {
@@ -279,6 +281,7 @@ pub(crate) async fn run(
         python_preference,
         python_downloads,
         installer_metadata,
+        quarantine,
         concurrency,
         &cache,
         printer,
@@ -298,19 +301,19 @@ pub(crate) async fn run(
                 )
                 .with_hint(format!(
                     "`{}` invokes the `{}` package. Did you mean `{}`?",
-                    format!("uvx run {rest}").green(),
-                    "run".cyan(),
-                    format!("uvx {rest}").green()
+                    format!("uvx run {rest}").success(),
+                    "run".accent(),
+                    format!("uvx {rest}").success()
                 ))
                 .with_context("tool")
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
             }
 
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .with_context("tool")
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .map_err(Into::into);
         }
         Err(ProjectError::Requirements(err)) => {
             let err = miette::Report::msg(format!("{err}"))
@@ -561,19 +564,19 @@ impl std::fmt::Display for ExecutableProviderHints<'_> {
                     write!(
                         f,
                         "Package `{}` does not provide any executables.",
-                        from.name.red()
+                        from.name.error()
                     )?;
                     return Ok(());
                 }
                 writeln!(
                     f,
                     "An executable named `{}` is not provided by package `{}`.",
-                    executable.cyan(),
-                    from.name.cyan(),
+                    executable.accent(),
+                    from.name.accent(),
                 )?;
                 writeln!(f, "The following executables are available:")?;
                 for (name, _) in &entrypoints {
-                    writeln!(f, "- {}", name.cyan())?;
+                    writeln!(f, "- {}", name.accent())?;
                 }
                 let name = match entrypoints.as_slice() {
                     [entrypoint] => entrypoint.0.as_str(),
@@ -583,15 +586,15 @@ impl std::fmt::Display for ExecutableProviderHints<'_> {
                 if *executable == from.name.as_str() {
                     let suggested_command =
                         format!("{} --from {} {name}", invocation_source, from.name);
-                    writeln!(f, "\nUse `{}` instead.", suggested_command.green().bold())?;
+                    writeln!(f, "\nUse `{}` instead.", suggested_command.success().bold())?;
                 }
             }
             [package] if package.name() == &from.name => {
                 write!(
                     f,
                     "An executable named `{}` is provided by package `{}`",
-                    executable.cyan(),
-                    from.name.cyan(),
+                    executable.accent(),
+                    from.name.accent(),
                 )?;
             }
             [package] => {
@@ -603,34 +606,34 @@ impl std::fmt::Display for ExecutableProviderHints<'_> {
                 write!(
                     f,
                     "An executable named `{}` is not provided by package `{}` but is available via the dependency `{}`. Consider using `{}` instead.",
-                    executable.cyan(),
-                    from.name.cyan(),
-                    package.name().cyan(),
-                    suggested_command.green()
+                    executable.accent(),
+                    from.name.accent(),
+                    package.name().accent(),
+                    suggested_command.success()
                 )?;
             }
             packages => {
                 let provided_by = packages
                     .iter()
                     .map(uv_distribution_types::Name::name)
-                    .map(|name| format!("- {}", name.cyan()))
+                    .map(|name| format!("- {}", name.accent()))
                     .join("\n");
                 if self.not_from_expected() {
                     let suggested_command = format!("{invocation_source} --from PKG {executable}");
                     write!(
                         f,
                         "An executable named `{}` is not provided by package `{}` but is available via the following dependencies:\n- {}\nConsider using `{}` instead.",
-                        executable.cyan(),
-                        from.name.cyan(),
+                        executable.accent(),
+                        from.name.accent(),
                         provided_by,
-                        suggested_command.green(),
+                        suggested_command.success(),
                     )?;
                 } else {
                     write!(
                         f,
                         "An executable named `{}` is provided by package `{}` but is also available via the following dependencies:\n- {}\nUnexpected behavior may occur.",
-                        executable.cyan(),
-                        from.name.cyan(),
+                        executable.accent(),
+                        from.name.accent(),
                         provided_by,
                     )?;
                 }
@@ -695,6 +698,7 @@ async fn get_or_create_environment(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -729,8 +733,8 @@ async fn get_or_create_environment(
                     if tool_python_request != &PythonRequest::Default {
                         return Err(anyhow::anyhow!(
                             "Received multiple Python version requests: `{}` and `{}`",
-                            python_flag.to_string().cyan(),
-                            tool_python_request.to_canonical_string().cyan()
+                            python_flag.to_string().accent(),
+                            tool_python_request.to_canonical_string().accent()
                         )
                         .into());
                     }
@@ -930,13 +934,65 @@ async fn get_or_create_environment(
         let _lock = installed_tools.lock().await?;
 
         if let ToolRequirement::Package { requirement, .. } = &from {
-            let existing_environment = installed_tools
-                .get_environment(&requirement.name, cache)?
-                .filter(|environment| {
-                    python_request.as_ref().is_none_or(|python_request| {
-                        python_request.satisfied(environment.interpreter(), cache)
-                    })
-                });
+            let (mut existing_environment, invalid) =
+                match installed_tools.get_environment(&requirement.name, cache) {
+                    Ok(environment) => (environment, None),
+                    Err(err) => (None, Some(err)),
+                };
+
+            // If the tool is installed, but its environment is broken (e.g., its interpreter was
+            // removed), recreate the environment from the tool receipt.
+            if existing_environment.is_none() {
+                if let Some(receipt) = installed_tools
+                    .get_tool_receipt(&requirement.name)
+                    .ok()
+                    .flatten()
+                {
+                    if let Some(err) = invalid {
+                        warn_user!(
+                            "The environment for `{}` is broken ({err}); recreating it",
+                            requirement.name
+                        );
+                    } else {
+                        warn_user!(
+                            "The environment for `{}` is broken; recreating it",
+                            requirement.name
+                        );
+                    }
+                    match Box::pin(repair_tool(
+                        &requirement.name,
+                        &receipt,
+                        &installed_tools,
+                        client_builder,
+                        python_preference,
+                        python_downloads,
+                        installer_metadata,
+                        quarantine,
+                        concurrency,
+                        cache,
+                        printer,
+                        preview,
+                    ))
+                    .await
+                    {
+                        Ok(environment) => existing_environment = Some(environment),
+                        Err(err) => {
+                            warn_user!("Failed to repair `{}`: {err}", requirement.name);
+                        }
+                    }
+                } else if let Some(err) = invalid {
+                    warn_user!(
+                        "Ignoring invalid environment for tool `{}`: {err}",
+                        requirement.name
+                    );
+                }
+            }
+
+            let existing_environment = existing_environment.filter(|environment| {
+                python_request.as_ref().is_none_or(|python_request| {
+                    python_request.satisfied(environment.interpreter(), cache)
+                })
+            });
 
             // Check if the installed packages meet the requirements.
             if let Some(environment) = existing_environment {
@@ -977,6 +1033,7 @@ async fn get_or_create_environment(
                             constraints.iter(),
                             overrides.iter(),
                             InstallationStrategy::Permissive,
+                            true,
                             &markers,
                             &tags,
                             config_setting,
@@ -1041,6 +1098,7 @@ async fn get_or_create_environment(
             Box::new(SummaryInstallLogger)
         },
         installer_metadata,
+        quarantine,
         concurrency,
         cache,
         printer,
@@ -1101,6 +1159,7 @@ async fn get_or_create_environment(
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    quarantine,
                     concurrency,
                     cache,
                     printer,
@@ -0,0 +1,70 @@
+use std::fmt::Write;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::{Printer, Styled};
+
+/// Show the receipt and installed packages for a tool.
+pub(crate) async fn show(name: PackageName, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("`{name}` is not installed");
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Validate the receipt, then display it as-is.
+    if installed_tools.get_tool_receipt(&name)?.is_none() {
+        bail!("`{name}` is not installed");
+    }
+    let path = installed_tools.receipt_path(&name);
+    let receipt = fs_err::read_to_string(&path)?;
+
+    writeln!(
+        printer.stdout(),
+        "{} ({})",
+        name.bold(),
+        path.simplified_display().accent()
+    )?;
+    writeln!(printer.stdout())?;
+    write!(printer.stdout(), "{}", receipt.trim_end())?;
+    writeln!(printer.stdout())?;
+
+    // Display the packages installed in the tool environment.
+    let Some(environment) = installed_tools.get_environment(&name, cache)? else {
+        warn_user!(
+            "The environment for `{name}` is broken (run `{}` to repair)",
+            "uv tool check --repair".success()
+        );
+        return Ok(ExitStatus::Success);
+    };
+
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let mut packages = site_packages.iter().collect::<Vec<_>>();
+    packages.sort_by(|a, b| a.name().cmp(b.name()));
+
+    writeln!(printer.stdout())?;
+    writeln!(printer.stdout(), "{}", "Installed packages:".bold())?;
+    for package in packages {
+        writeln!(
+            printer.stdout(),
+            "- {}=={}",
+            package.name(),
+            package.version()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,6 +1,5 @@
 use anyhow::Result;
 use itertools::Itertools;
-use owo_colors::{AnsiColors, OwoColorize};
 use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::str::FromStr;
@@ -10,12 +9,12 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, TargetTriple};
 use uv_distribution_types::{ExtraBuildRequires, Requirement};
-use uv_fs::CWD;
+use uv_fs::{CWD, Quarantine};
 use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -23,17 +22,19 @@ use uv_tool::InstalledTools;
 use uv_warnings::write_error_chain;
 use uv_workspace::WorkspaceCache;
 
+use crate::commands::pip;
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
 };
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::{
-    EnvironmentUpdate, PlatformState, resolve_environment, sync_environment, update_environment,
+    EnvironmentUpdate, PlatformState, ProjectError, resolve_environment, sync_environment,
+    update_environment,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::remove_entrypoints;
 use crate::commands::{ExitStatus, conjunction, tool::common::finalize_tool_install};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled, Theme};
 use crate::settings::ResolverInstallerSettings;
 
 /// Upgrade a tool.
@@ -41,6 +42,7 @@ pub(crate) async fn upgrade(
     names: Vec<String>,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
+    relax_with: bool,
     install_mirrors: PythonInstallMirrors,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
@@ -48,6 +50,7 @@ pub(crate) async fn upgrade(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -122,6 +125,7 @@ pub(crate) async fn upgrade(
             constraints,
             interpreter.as_ref(),
             python_platform.as_ref(),
+            relax_with,
             printer,
             &installed_tools,
             &args,
@@ -129,6 +133,7 @@ pub(crate) async fn upgrade(
             cache,
             &filesystem,
             installer_metadata,
+            quarantine,
             concurrency,
             preview,
         )
@@ -156,13 +161,23 @@ pub(crate) async fn upgrade(
             .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
         {
             trace!("Error trace: {err:?}");
+            let relax = err.downcast_ref::<WithConflictError>().is_some();
             write_error_chain(
-                err.context(format!("Failed to upgrade {}", name.green()))
+                err.context(format!("Failed to upgrade {}", name.success()))
                     .as_ref(),
                 printer.stderr(),
                 "error",
-                AnsiColors::Red,
+                Theme::current().error,
             )?;
+            if relax {
+                writeln!(
+                    printer.stderr(),
+                    "\n{}{} Use `{}` to remove the conflicting requirements from the tool",
+                    "hint".bold().accent(),
+                    ":".bold(),
+                    format!("uv tool upgrade {name} --relax-with").success(),
+                )?;
+            }
         }
         return Ok(ExitStatus::Failure);
     }
@@ -175,14 +190,14 @@ pub(crate) async fn upgrade(
         if !did_upgrade_environment.is_empty() {
             let tools = did_upgrade_environment
                 .iter()
-                .map(|name| format!("`{}`", name.cyan()))
+                .map(|name| format!("`{}`", name.accent()))
                 .collect::<Vec<_>>();
             let s = if tools.len() > 1 { "s" } else { "" };
             writeln!(
                 printer.stderr(),
                 "Upgraded tool environment{s} for {} to {}",
                 conjunction(tools),
-                python_request.cyan(),
+                python_request.accent(),
             )?;
         }
     }
@@ -208,6 +223,7 @@ async fn upgrade_tool(
     constraints: &[Requirement],
     interpreter: Option<&Interpreter>,
     python_platform: Option<&TargetTriple>,
+    relax_with: bool,
     printer: Printer,
     installed_tools: &InstalledTools,
     args: &ResolverInstallerOptions,
@@ -215,6 +231,7 @@ async fn upgrade_tool(
     cache: &Cache,
     filesystem: &ResolverInstallerOptions,
     installer_metadata: bool,
+    quarantine: Quarantine,
     concurrency: Concurrency,
     preview: Preview,
 ) -> Result<UpgradeOutcome> {
@@ -225,16 +242,16 @@ async fn upgrade_tool(
             let install_command = format!("uv tool install {name}");
             return Err(anyhow::anyhow!(
                 "`{}` is not installed; run `{}` to install",
-                name.cyan(),
-                install_command.green()
+                name.accent(),
+                install_command.success()
             ));
         }
         Err(_) => {
             let install_command = format!("uv tool install --force {name}");
             return Err(anyhow::anyhow!(
                 "`{}` is missing a valid receipt; run `{}` to reinstall",
-                name.cyan(),
-                install_command.green()
+                name.accent(),
+                install_command.success()
             ));
         }
     };
@@ -245,16 +262,16 @@ async fn upgrade_tool(
             let install_command = format!("uv tool install {name}");
             return Err(anyhow::anyhow!(
                 "`{}` is not installed; run `{}` to install",
-                name.cyan(),
-                install_command.green()
+                name.accent(),
+                install_command.success()
             ));
         }
         Err(_) => {
             let install_command = format!("uv tool install --force {name}");
             return Err(anyhow::anyhow!(
                 "`{}` is missing a valid environment; run `{}` to reinstall",
-                name.cyan(),
-                install_command.green()
+                name.accent(),
+                install_command.success()
             ));
         }
     };
@@ -269,28 +286,191 @@ async fn upgrade_tool(
     let build_constraints =
         Constraints::from_requirements(existing_tool_receipt.build_constraints().iter().cloned());
 
-    // Resolve the requirements.
-    let spec = RequirementsSpecification::from_overrides(
-        existing_tool_receipt.requirements().to_vec(),
-        existing_tool_receipt
-            .constraints()
-            .iter()
-            .chain(constraints)
-            .cloned()
-            .collect(),
-        existing_tool_receipt.overrides().to_vec(),
-    );
-
     // Initialize any shared state.
     let state = PlatformState::default();
-    let workspace_cache = WorkspaceCache::default();
-
-    // Check if we need to create a new environment — if so, resolve it first, then
-    // install the requested tool
-    let (environment, outcome) = if let Some(interpreter) =
-        interpreter.filter(|interpreter| !environment.uses(interpreter))
-    {
-        // If we're using a new interpreter, re-create the environment for each tool.
+
+    // If we're using a new interpreter, re-create the environment for the tool.
+    let interpreter = interpreter.filter(|interpreter| !environment.uses(interpreter));
+
+    // Resolve the requirements, alongside any constraints from the receipt.
+    let constraints: Vec<_> = existing_tool_receipt
+        .constraints()
+        .iter()
+        .chain(constraints)
+        .cloned()
+        .collect();
+
+    let mut requirements = existing_tool_receipt.requirements().to_vec();
+    let result = install_requirements(
+        name,
+        requirements.clone(),
+        constraints.clone(),
+        existing_tool_receipt.overrides().to_vec(),
+        environment.clone(),
+        interpreter,
+        python_platform,
+        build_constraints.clone(),
+        installed_tools,
+        &settings,
+        client_builder,
+        &state,
+        installer_metadata,
+        quarantine,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await;
+
+    let (environment, outcome) = match result {
+        Ok(result) => result,
+        Err(err) if is_no_solution(&err) => {
+            // If the failure is caused by the additional (e.g., `--with`) requirements, identify
+            // the minimal set of conflicting requirements.
+            let conflicting = find_conflicting_requirements(
+                name,
+                &requirements,
+                constraints.clone(),
+                existing_tool_receipt.overrides().to_vec(),
+                interpreter.unwrap_or_else(|| environment.interpreter()),
+                python_platform,
+                &build_constraints,
+                &settings,
+                client_builder,
+                &state,
+                concurrency,
+                cache,
+                preview,
+            )
+            .await?;
+            if conflicting.is_empty() {
+                return Err(err.into());
+            }
+            if !relax_with {
+                return Err(WithConflictError {
+                    name: name.clone(),
+                    conflicting,
+                    err,
+                }
+                .into());
+            }
+
+            writeln!(
+                printer.stderr(),
+                "{}{} Removing {} from `{}`, as {} with the latest compatible version",
+                "warning".bold().warning(),
+                ":".bold(),
+                conjunction(
+                    conflicting
+                        .iter()
+                        .map(|requirement| format!("`{}`", requirement.accent()))
+                        .collect()
+                ),
+                name.accent(),
+                if conflicting.len() == 1 {
+                    "it conflicts"
+                } else {
+                    "they conflict"
+                },
+            )?;
+            requirements.retain(|requirement| !conflicting.contains(requirement));
+
+            let (environment, outcome) = install_requirements(
+                name,
+                requirements.clone(),
+                constraints,
+                existing_tool_receipt.overrides().to_vec(),
+                environment,
+                interpreter,
+                python_platform,
+                build_constraints,
+                installed_tools,
+                &settings,
+                client_builder,
+                &state,
+                installer_metadata,
+                quarantine,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await?;
+
+            // The receipt must be updated to drop the conflicting requirements, even if the tool
+            // itself is unchanged.
+            let outcome = match outcome {
+                UpgradeOutcome::NoOp | UpgradeOutcome::UpgradeDependencies => {
+                    UpgradeOutcome::UpgradeTool
+                }
+                outcome => outcome,
+            };
+            (environment, outcome)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    if matches!(
+        outcome,
+        UpgradeOutcome::UpgradeEnvironment | UpgradeOutcome::UpgradeTool
+    ) {
+        // At this point, we updated the existing environment, so we should remove any of its
+        // existing executables.
+        remove_entrypoints(&existing_tool_receipt);
+
+        let entrypoints: Vec<_> = existing_tool_receipt
+            .entrypoints()
+            .iter()
+            .filter_map(|entry| PackageName::from_str(entry.from.as_ref()?).ok())
+            .collect();
+
+        // If we modified the target tool, reinstall the entrypoints.
+        finalize_tool_install(
+            &environment,
+            name,
+            &entrypoints,
+            installed_tools,
+            &ToolOptions::from(options),
+            true,
+            existing_tool_receipt.python().to_owned(),
+            requirements,
+            existing_tool_receipt.constraints().to_vec(),
+            existing_tool_receipt.overrides().to_vec(),
+            existing_tool_receipt.build_constraints().to_vec(),
+            printer,
+            preview,
+        )?;
+    }
+
+    Ok(outcome)
+}
+
+/// Resolve and install the tool's requirements, either into its existing environment or (if an
+/// interpreter is provided) into a new environment.
+async fn install_requirements(
+    name: &PackageName,
+    requirements: Vec<Requirement>,
+    constraints: Vec<Requirement>,
+    overrides: Vec<Requirement>,
+    environment: PythonEnvironment,
+    interpreter: Option<&Interpreter>,
+    python_platform: Option<&TargetTriple>,
+    build_constraints: Constraints,
+    installed_tools: &InstalledTools,
+    settings: &ResolverInstallerSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    state: &PlatformState,
+    installer_metadata: bool,
+    quarantine: Quarantine,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<(PythonEnvironment, UpgradeOutcome), ProjectError> {
+    let spec = RequirementsSpecification::from_overrides(requirements, constraints, overrides);
+
+    if let Some(interpreter) = interpreter {
         let resolution = resolve_environment(
             spec.into(),
             interpreter,
@@ -298,7 +478,7 @@ async fn upgrade_tool(
             build_constraints.clone(),
             &settings.resolver,
             client_builder,
-            &state,
+            state,
             Box::new(SummaryResolveLogger),
             concurrency,
             cache,
@@ -314,11 +494,12 @@ async fn upgrade_tool(
             &resolution.into(),
             Modifications::Exact,
             build_constraints,
-            (&settings).into(),
+            settings.into(),
             client_builder,
-            &state,
+            state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
+            quarantine,
             concurrency,
             cache,
             printer,
@@ -326,7 +507,7 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        Ok((environment, UpgradeOutcome::UpgradeEnvironment))
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -341,15 +522,16 @@ async fn upgrade_tool(
             python_platform,
             build_constraints,
             ExtraBuildRequires::default(),
-            &settings,
+            settings,
             client_builder,
-            &state,
+            state,
             Box::new(SummaryResolveLogger),
             Box::new(UpgradeInstallLogger::new(name.clone())),
             installer_metadata,
+            quarantine,
             concurrency,
             cache,
-            workspace_cache,
+            WorkspaceCache::default(),
             DryRun::Disabled,
             printer,
             preview,
@@ -364,39 +546,133 @@ async fn upgrade_tool(
             UpgradeOutcome::UpgradeDependencies
         };
 
-        (environment, outcome)
+        Ok((environment, outcome))
+    }
+}
+
+/// Returns `true` if the error is a resolution failure due to conflicting requirements.
+fn is_no_solution(err: &ProjectError) -> bool {
+    matches!(
+        err,
+        ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(_)
+        ))
+    )
+}
+
+/// Identify a minimal set of the tool's additional requirements (i.e., those other than the tool
+/// itself) that conflict with the tool, such that removing any one of them from the set resolves
+/// the conflict.
+///
+/// Returns an empty set if the tool can't be resolved even without its additional requirements.
+async fn find_conflicting_requirements(
+    name: &PackageName,
+    requirements: &[Requirement],
+    constraints: Vec<Requirement>,
+    overrides: Vec<Requirement>,
+    interpreter: &Interpreter,
+    python_platform: Option<&TargetTriple>,
+    build_constraints: &Constraints,
+    settings: &ResolverInstallerSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    state: &PlatformState,
+    concurrency: Concurrency,
+    cache: &Cache,
+    preview: Preview,
+) -> Result<Vec<Requirement>, ProjectError> {
+    let (tool, additional): (Vec<_>, Vec<_>) = requirements
+        .iter()
+        .cloned()
+        .partition(|requirement| requirement.name == *name);
+    if additional.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Determine whether the tool resolves alongside the given additional requirements.
+    let resolves = async |additional: &[Requirement]| -> Result<bool, ProjectError> {
+        let spec = RequirementsSpecification::from_overrides(
+            tool.iter().chain(additional).cloned().collect(),
+            constraints.clone(),
+            overrides.clone(),
+        );
+        match resolve_environment(
+            spec.into(),
+            interpreter,
+            python_platform,
+            build_constraints.clone(),
+            &settings.resolver,
+            client_builder,
+            state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            Printer::Silent,
+            preview,
+        )
+        .await
+        {
+            Ok(_) => Ok(true),
+            Err(err) if is_no_solution(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
     };
 
-    if matches!(
-        outcome,
-        UpgradeOutcome::UpgradeEnvironment | UpgradeOutcome::UpgradeTool
-    ) {
-        // At this point, we updated the existing environment, so we should remove any of its
-        // existing executables.
-        remove_entrypoints(&existing_tool_receipt);
+    if !resolves(&[]).await? {
+        debug!("`{name}` can't be resolved without its additional requirements");
+        return Ok(Vec::new());
+    }
 
-        let entrypoints: Vec<_> = existing_tool_receipt
-            .entrypoints()
-            .iter()
-            .filter_map(|entry| PackageName::from_str(entry.from.as_ref()?).ok())
-            .collect();
+    // Remove each requirement in turn, retaining it only if the conflict disappears without it.
+    let mut conflicting = additional;
+    let mut index = 0;
+    while index < conflicting.len() {
+        let mut candidate = conflicting.clone();
+        candidate.remove(index);
+        if resolves(&candidate).await? {
+            index += 1;
+        } else {
+            debug!(
+                "Requirement `{}` is not required to reproduce the conflict",
+                conflicting[index]
+            );
+            conflicting = candidate;
+        }
+    }
 
-        // If we modified the target tool, reinstall the entrypoints.
-        finalize_tool_install(
-            &environment,
-            name,
-            &entrypoints,
-            installed_tools,
-            &ToolOptions::from(options),
-            true,
-            existing_tool_receipt.python().to_owned(),
-            existing_tool_receipt.requirements().to_vec(),
-            existing_tool_receipt.constraints().to_vec(),
-            existing_tool_receipt.overrides().to_vec(),
-            existing_tool_receipt.build_constraints().to_vec(),
-            printer,
-        )?;
+    Ok(conflicting)
+}
+
+/// An error raised when a tool's additional requirements conflict with the upgraded tool.
+#[derive(Debug)]
+pub(crate) struct WithConflictError {
+    name: PackageName,
+    conflicting: Vec<Requirement>,
+    err: ProjectError,
+}
+
+impl std::fmt::Display for WithConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (noun, verb) = if self.conflicting.len() == 1 {
+            ("requirement", "conflicts")
+        } else {
+            ("requirements", "conflict")
+        };
+        write!(
+            f,
+            "The additional {noun} {} {verb} with the latest compatible version of `{}`",
+            conjunction(
+                self.conflicting
+                    .iter()
+                    .map(|requirement| format!("`{}`", requirement.accent()))
+                    .collect()
+            ),
+            self.name.accent()
+        )
     }
+}
 
-    Ok(outcome)
+impl std::error::Error for WithConflictError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
 }
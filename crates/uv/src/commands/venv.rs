@@ -4,7 +4,6 @@ use std::str::FromStr;
 use std::vec;
 
 use anyhow::Result;
-use owo_colors::OwoColorize;
 use thiserror::Error;
 
 use uv_cache::Cache;
@@ -38,7 +37,7 @@ use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
 use crate::commands::pip::operations::{Changelog, report_interpreter};
 use crate::commands::project::{WorkspacePython, validate_project_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled};
 
 use super::project::default_dependency_groups;
 
@@ -99,7 +98,7 @@ pub(crate) async fn venv(
             Err(WorkspaceError::Toml(path, err)) => {
                 warn_user!(
                     "Failed to parse `{}` during environment creation:\n{}",
-                    path.user_display().cyan(),
+                    path.user_display().accent(),
                     textwrap::indent(&err.to_string(), "  ")
                 );
                 None
@@ -187,7 +186,7 @@ pub(crate) async fn venv(
         printer.stderr(),
         "Creating virtual environment {}at: {}",
         if seed { "with seed packages " } else { "" },
-        path.user_display().cyan()
+        path.user_display().accent()
     )?;
 
     let upgradeable = preview.is_enabled(PreviewFeatures::PYTHON_UPGRADE)
@@ -339,7 +338,7 @@ pub(crate) async fn venv(
         Some(Shell::Cmd) => Some(shlex_windows(venv.scripts().join("activate"), Shell::Cmd)),
     };
     if let Some(act) = activation {
-        writeln!(printer.stderr(), "Activate with: {}", act.green())?;
+        writeln!(printer.stderr(), "Activate with: {}", act.success())?;
     }
 
     Ok(ExitStatus::Success)
@@ -3,7 +3,6 @@ use std::str::FromStr;
 use std::{cmp::Ordering, path::Path};
 
 use anyhow::{Result, anyhow};
-use owo_colors::OwoColorize;
 
 use uv_cli::version::VersionInfo;
 use uv_cli::{VersionBump, VersionFormat};
@@ -16,7 +15,10 @@ use uv_workspace::{
     pyproject_mut::{DependencyTarget, PyProjectTomlMut},
 };
 
-use crate::{commands::ExitStatus, printer::Printer};
+use crate::{
+    commands::{ExitStatus, project::edit::write_edited},
+    printer::{Printer, Styled},
+};
 
 /// Display version information for uv itself (`uv self version`)
 pub(crate) fn self_version(
@@ -59,7 +61,7 @@ pub(crate) async fn project_version(
             // Otherwise, warn and provide fallback
             warn_user!(
                 "Failed to read project metadata ({err}). Running `{}` for compatibility. This fallback will be removed in the future; pass `--preview` to force an error.",
-                "uv self version".green()
+                "uv self version".success()
             );
             return self_version(short, output_format, printer);
         }
@@ -120,7 +122,11 @@ pub(crate) async fn project_version(
     if let Some(new_version) = &new_version {
         if !dry_run {
             pyproject.set_version(new_version)?;
-            fs_err::write(pyproject_path, pyproject.to_string())?;
+            write_edited(
+                &pyproject_path,
+                &workspace.current_project().pyproject_toml().raw,
+                &pyproject.to_string(),
+            )?;
         }
     }
 
@@ -148,17 +154,17 @@ fn print_version(
             }
             if let Some(new_version) = new_version {
                 if short {
-                    writeln!(printer.stdout(), "{}", new_version.cyan())?;
+                    writeln!(printer.stdout(), "{}", new_version.accent())?;
                 } else {
                     writeln!(
                         printer.stdout(),
                         "{} => {}",
-                        old_version.cyan(),
-                        new_version.cyan()
+                        old_version.accent(),
+                        new_version.accent()
                     )?;
                 }
             } else {
-                writeln!(printer.stdout(), "{}", old_version.cyan())?;
+                writeln!(printer.stdout(), "{}", old_version.accent())?;
             }
         }
         VersionFormat::Json => {
@@ -0,0 +1,137 @@
+use std::fmt::Write;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_client::{WHEELHOUSE_MANIFEST, WheelhouseManifest};
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::commands::ExitStatus;
+use crate::printer::{Printer, Styled};
+
+/// Verify every distribution in a wheelhouse directory against its integrity manifest.
+pub(crate) fn wheelhouse_verify(
+    directory: &Path,
+    manifest: Option<&Path>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let manifest_path =
+        manifest.map_or_else(|| directory.join(WHEELHOUSE_MANIFEST), Path::to_path_buf);
+    let manifest = WheelhouseManifest::from_path(&manifest_path)?;
+
+    writeln!(
+        printer.stderr(),
+        "Verifying wheelhouse at: {}",
+        directory.user_display().accent()
+    )?;
+
+    let mut verified = 0usize;
+    let mut failed = 0usize;
+    let mut mismatched = false;
+
+    // Verify each of the files listed in the manifest.
+    for (filename, expected) in manifest.iter() {
+        let path = directory.join(filename);
+        if !path.is_file() {
+            writeln!(
+                printer.stderr(),
+                "{}{} `{filename}` is listed in the manifest, but does not exist",
+                "error".error().bold(),
+                ":".bold()
+            )?;
+            failed += 1;
+            continue;
+        }
+
+        let computed = hash_file(&path, expected.algorithm)
+            .with_context(|| format!("Failed to hash: `{}`", path.user_display()))?;
+        if computed == *expected {
+            verified += 1;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "{}{} Hash mismatch for `{filename}`\n  Expected: {}\n  Computed: {}",
+                "error".error().bold(),
+                ":".bold(),
+                expected,
+                computed
+            )?;
+            failed += 1;
+            mismatched = true;
+        }
+    }
+
+    // Flag any distributions that aren't covered by the manifest.
+    let mut unlisted = Vec::new();
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        if DistFilename::try_from_normalized_filename(&filename).is_none() {
+            continue;
+        }
+        if manifest.get(&filename).is_none() {
+            unlisted.push(filename);
+        }
+    }
+    unlisted.sort_unstable();
+    for filename in &unlisted {
+        writeln!(
+            printer.stderr(),
+            "{}{} `{filename}` is not listed in the manifest",
+            "error".error().bold(),
+            ":".bold()
+        )?;
+        failed += 1;
+    }
+
+    if failed > 0 {
+        writeln!(
+            printer.stderr(),
+            "Verification failed for {failed} {} ({verified} verified)",
+            if failed == 1 { "file" } else { "files" }
+        )?;
+        return Ok(if mismatched {
+            ExitStatus::HashMismatch
+        } else {
+            ExitStatus::Failure
+        });
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verified {} against {}",
+        format!(
+            "{verified} {}",
+            if verified == 1 { "file" } else { "files" }
+        )
+        .success(),
+        manifest_path.user_display().accent()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Compute the digest of a file with the given algorithm.
+fn hash_file(path: &Path, algorithm: HashAlgorithm) -> std::io::Result<HashDigest> {
+    let mut file = fs_err::File::open(path)?;
+    let mut hasher = Hasher::from(algorithm);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+    Ok(HashDigest::from(hasher))
+}
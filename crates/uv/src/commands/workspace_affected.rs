@@ -0,0 +1,78 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use uv_cli::AffectedFormat;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::task_graph::TaskGraph;
+use crate::printer::{Printer, Styled};
+
+/// The JSON description of an affected workspace member.
+#[derive(Debug, Serialize)]
+struct AffectedMember<'a> {
+    name: &'a PackageName,
+    /// The root of the member, relative to the workspace root.
+    path: String,
+}
+
+/// List the workspace members affected by changes since the given Git revision.
+pub(crate) async fn workspace_affected(
+    project_dir: &Path,
+    since: &str,
+    output_format: AffectedFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    let task_graph =
+        TaskGraph::from_workspace(&workspace).affected(workspace.install_path(), since)?;
+    let members = task_graph.sorted()?;
+
+    match output_format {
+        AffectedFormat::Text => {
+            if members.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No workspace members are affected by changes since `{}`",
+                    since.accent()
+                )?;
+            }
+            for member in members {
+                writeln!(printer.stdout(), "{}", member.name())?;
+            }
+        }
+        AffectedFormat::Json => {
+            let members = members
+                .into_iter()
+                .map(|member| {
+                    let path = member
+                        .root()
+                        .strip_prefix(workspace.install_path())
+                        .unwrap_or(member.root());
+                    AffectedMember {
+                        name: member.name(),
+                        path: if path.as_os_str().is_empty() {
+                            ".".to_string()
+                        } else {
+                            path.portable_display().to_string()
+                        },
+                    }
+                })
+                .collect::<Vec<_>>();
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&members)?)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
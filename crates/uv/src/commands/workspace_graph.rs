@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use uv_cli::GraphFormat;
+use uv_fs::{Simplified, relative_to};
+use uv_normalize::PackageName;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::task_graph::{direct_dependencies, path_sources};
+use crate::printer::Printer;
+
+/// The kind of a dependency in the workspace graph.
+///
+/// Variants are ordered by precedence: if a package is a workspace member, it's rendered as a
+/// member, even if another member declares a path source for it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum DependencyKind {
+    /// Another member of the workspace.
+    Workspace,
+    /// A package with a `path` source in `tool.uv.sources`, outside of the workspace.
+    Path,
+    /// Any other dependency, e.g., from a registry.
+    External,
+}
+
+/// The JSON description of a workspace member in the graph.
+#[derive(Debug, Serialize)]
+struct GraphMember {
+    name: PackageName,
+    /// The root of the member, relative to the workspace root.
+    path: String,
+    dependencies: Vec<GraphDependency>,
+}
+
+/// The JSON description of a direct dependency of a workspace member.
+#[derive(Debug, Serialize)]
+struct GraphDependency {
+    name: PackageName,
+    kind: DependencyKind,
+    /// For path dependencies, the path to the dependency, relative to the workspace root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkspaceGraph {
+    members: Vec<GraphMember>,
+}
+
+impl WorkspaceGraph {
+    /// Build the graph for all members of a workspace.
+    fn from_workspace(workspace: &Workspace) -> Self {
+        let packages = workspace.packages();
+        let members = packages
+            .iter()
+            .map(|(name, member)| {
+                let dependencies = direct_dependencies(name, member)
+                    .into_iter()
+                    .map(|dependency| {
+                        if packages.contains_key(&dependency) {
+                            return GraphDependency {
+                                name: dependency,
+                                kind: DependencyKind::Workspace,
+                                path: None,
+                            };
+                        }
+                        match path_sources(workspace, member, &dependency).first() {
+                            Some(path) => GraphDependency {
+                                path: Some(relative_display(path, workspace.install_path())),
+                                name: dependency,
+                                kind: DependencyKind::Path,
+                            },
+                            None => GraphDependency {
+                                name: dependency,
+                                kind: DependencyKind::External,
+                                path: None,
+                            },
+                        }
+                    })
+                    .collect();
+                GraphMember {
+                    name: name.clone(),
+                    path: relative_display(member.root(), workspace.install_path()),
+                    dependencies,
+                }
+            })
+            .collect();
+        Self { members }
+    }
+
+    /// Return every node in the graph, along with its kind.
+    fn nodes(&self) -> BTreeMap<&PackageName, DependencyKind> {
+        let mut nodes = BTreeMap::new();
+        for member in &self.members {
+            nodes.insert(&member.name, DependencyKind::Workspace);
+        }
+        for dependency in self.members.iter().flat_map(|member| &member.dependencies) {
+            nodes
+                .entry(&dependency.name)
+                .and_modify(|kind: &mut DependencyKind| *kind = (*kind).min(dependency.kind))
+                .or_insert(dependency.kind);
+        }
+        nodes
+    }
+
+    /// Render the graph in the Graphviz DOT language.
+    fn to_dot(&self) -> String {
+        let mut output = String::from("digraph workspace {\n");
+        for (name, kind) in self.nodes() {
+            let attributes = match kind {
+                DependencyKind::Workspace => "shape=box",
+                DependencyKind::Path => "shape=box, style=dashed",
+                DependencyKind::External => "shape=ellipse",
+            };
+            writeln!(output, "    \"{name}\" [{attributes}];").unwrap();
+        }
+        for member in &self.members {
+            for dependency in &member.dependencies {
+                writeln!(
+                    output,
+                    "    \"{}\" -> \"{}\";",
+                    member.name, dependency.name
+                )
+                .unwrap();
+            }
+        }
+        output.push('}');
+        output
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    fn to_mermaid(&self) -> String {
+        // Package names can include characters (like `.` and `-`) that aren't valid in Mermaid
+        // node identifiers, so each node is assigned a numeric identifier.
+        let nodes = self.nodes();
+        let ids = nodes
+            .keys()
+            .enumerate()
+            .map(|(index, name)| (*name, format!("n{index}")))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut output = String::from("graph TD\n");
+        for (name, kind) in &nodes {
+            let id = &ids[name];
+            match kind {
+                DependencyKind::Workspace => writeln!(output, "    {id}[\"{name}\"]"),
+                DependencyKind::Path => writeln!(output, "    {id}([\"{name}\"])"),
+                DependencyKind::External => writeln!(output, "    {id}(\"{name}\")"),
+            }
+            .unwrap();
+        }
+        for member in &self.members {
+            for dependency in &member.dependencies {
+                writeln!(
+                    output,
+                    "    {} --> {}",
+                    ids[&member.name], ids[&dependency.name]
+                )
+                .unwrap();
+            }
+        }
+        output.pop();
+        output
+    }
+}
+
+/// Display a path relative to the workspace root, using `.` for the root itself.
+fn relative_display(path: &Path, root: &Path) -> String {
+    let path = relative_to(path, root).unwrap_or_else(|_| path.to_path_buf());
+    if path.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        path.portable_display().to_string()
+    }
+}
+
+/// Display the dependency graph of the workspace members.
+pub(crate) async fn workspace_graph(
+    project_dir: &Path,
+    format: GraphFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    let graph = WorkspaceGraph::from_workspace(&workspace);
+    let output = match format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Json => serde_json::to_string_pretty(&graph)?,
+        GraphFormat::Mermaid => graph.to_mermaid(),
+    };
+    writeln!(printer.stdout(), "{output}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,251 @@
+//! Actionable suggestions for common failures, like a missing C compiler when building a source
+//! distribution, or a TLS certificate that can't be verified.
+//!
+//! Hints are detected by matching the messages of an error chain (and, for build failures, the
+//! trailing lines of the build backend's output) against known failure modes, and include
+//! remediation steps for the current operating system.
+
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::printer::Styled;
+
+/// e.g. `error: command 'gcc' failed: No such file or directory` or
+/// `unable to execute 'x86_64-linux-gnu-gcc': No such file or directory`
+static MISSING_COMPILER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:command|execute) '(?:\S*[/\\-])?(?:cc|gcc|clang|c\+\+|g\+\+|clang\+\+|cl\.exe)'(?: failed)?: No such file or directory",
+    )
+    .unwrap()
+});
+
+/// e.g. `src/main.c:1:10: fatal error: Python.h: No such file or directory` (GCC),
+/// `src/main.c:1:10: fatal error: 'Python.h' file not found` (Clang), or
+/// `src/main.c(1): fatal error C1083: Cannot open include file: 'Python.h': No such file or directory` (MSVC)
+static MISSING_PYTHON_HEADERS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"'?Python\.h'?(?:: No such file or directory| file not found)").unwrap()
+});
+
+/// A known failure mode, with remediation steps for the current platform.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Hint {
+    /// A C compiler is required to build a source distribution, but none was found.
+    MissingCompiler,
+    /// The Python development headers are required to build a source distribution, but the
+    /// interpreter doesn't provide them.
+    MissingPythonHeaders,
+    /// A Rust toolchain is required to build a source distribution (e.g., with `maturin` or
+    /// `setuptools-rust`), but none was found.
+    MissingRustToolchain,
+    /// A TLS certificate couldn't be verified against the trusted root certificates.
+    CertificateVerification,
+}
+
+impl Hint {
+    /// Detect a known failure mode from an error and its causes.
+    pub(crate) fn from_error(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        std::iter::successors(Some(err), |err| err.source()).find_map(|err| {
+            // The build backend output isn't part of the error message, unless the build was run
+            // with verbose output.
+            let output = if let Some(err) =
+                err.downcast_ref::<uv_build_frontend::BuildBackendError>()
+            {
+                err.output_tail()
+            } else if let Some(err) = err.downcast_ref::<uv_build_frontend::MissingHeaderError>() {
+                err.output_tail()
+            } else {
+                &[]
+            };
+            output
+                .iter()
+                .rev()
+                .find_map(|line| Self::from_line(line))
+                .or_else(|| err.to_string().lines().find_map(Self::from_line))
+        })
+    }
+
+    /// Detect a known failure mode from a single line of an error message or build output.
+    fn from_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if MISSING_PYTHON_HEADERS_RE.is_match(line) {
+            Some(Self::MissingPythonHeaders)
+        } else if MISSING_COMPILER_RE.is_match(line)
+            || line.contains("Microsoft Visual C++ 14.0 or greater is required")
+            || line.contains("xcrun: error: invalid active developer path")
+            || line.contains("error: linker `cc` not found")
+            || line.contains("No CMAKE_C_COMPILER could be found")
+        {
+            Some(Self::MissingCompiler)
+        } else if line.contains("Cargo, the Rust package manager, is not installed")
+            || line.contains("can't find Rust compiler")
+        {
+            Some(Self::MissingRustToolchain)
+        } else if line.contains("invalid peer certificate")
+            || line.contains("certificate verify failed")
+            || line.contains("unable to get local issuer certificate")
+        {
+            Some(Self::CertificateVerification)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCompiler => {
+                write!(
+                    f,
+                    "A C compiler is required to build this package, but none was found. "
+                )?;
+                if cfg!(target_os = "macos") {
+                    write!(
+                        f,
+                        "Install the Xcode Command Line Tools with `{}`",
+                        "xcode-select --install".success()
+                    )?;
+                } else if cfg!(windows) {
+                    write!(
+                        f,
+                        "Install the Microsoft C++ Build Tools from {}, including the \"Desktop development with C++\" workload",
+                        "https://visualstudio.microsoft.com/visual-cpp-build-tools/".accent()
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "Install one with your system package manager (e.g., `{}` on Debian or Ubuntu, or `{}` on Fedora)",
+                        "sudo apt install build-essential".success(),
+                        "sudo dnf install gcc".success()
+                    )?;
+                }
+                write!(
+                    f,
+                    ", or use a version of the package that provides a pre-built wheel for your platform"
+                )
+            }
+            Self::MissingPythonHeaders => {
+                write!(
+                    f,
+                    "The Python development headers are required to build this package, but aren't included in the Python installation. "
+                )?;
+                if cfg!(any(target_os = "macos", windows)) {
+                    write!(
+                        f,
+                        "Use a Python installation that includes the headers, like those installed with `{}`",
+                        "uv python install".success()
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Install them with your system package manager (e.g., `{}` on Debian or Ubuntu, or `{}` on Fedora), or use a Python installation that includes the headers, like those installed with `{}`",
+                        "sudo apt install python3-dev".success(),
+                        "sudo dnf install python3-devel".success(),
+                        "uv python install".success()
+                    )
+                }
+            }
+            Self::MissingRustToolchain => {
+                write!(
+                    f,
+                    "A Rust toolchain is required to build this package, but none was found. "
+                )?;
+                if cfg!(windows) {
+                    write!(
+                        f,
+                        "Install one with the installer from {}",
+                        "https://rustup.rs".accent()
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "Install one with `{}`",
+                        "curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh".success()
+                    )?;
+                }
+                write!(
+                    f,
+                    " and ensure `{}` is on your `{}`, or use a version of the package that provides a pre-built wheel for your platform",
+                    "cargo".accent(),
+                    "PATH".accent()
+                )
+            }
+            Self::CertificateVerification => {
+                write!(
+                    f,
+                    "A TLS certificate couldn't be verified. If you're behind a proxy or use a custom certificate authority, re-run with `{}` to use the system's certificate store",
+                    "--native-tls".success()
+                )?;
+                if cfg!(target_os = "macos") {
+                    write!(f, " (after adding the certificate to the System keychain)")?;
+                } else if cfg!(windows) {
+                    write!(
+                        f,
+                        " (after importing the certificate into the \"Trusted Root Certification Authorities\" store)"
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        " (after installing the certificate with `{}`)",
+                        "update-ca-certificates".success()
+                    )?;
+                }
+                write!(
+                    f,
+                    ", or set `{}` to the path of a PEM bundle that includes the certificate",
+                    "SSL_CERT_FILE".accent()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hint;
+
+    #[test]
+    fn from_line() {
+        assert_eq!(
+            Hint::from_line("      error: command 'gcc' failed: No such file or directory"),
+            Some(Hint::MissingCompiler)
+        );
+        assert_eq!(
+            Hint::from_line("unable to execute 'x86_64-linux-gnu-gcc': No such file or directory"),
+            Some(Hint::MissingCompiler)
+        );
+        assert_eq!(
+            Hint::from_line(
+                "error: Microsoft Visual C++ 14.0 or greater is required. Get it with \"Microsoft C++ Build Tools\""
+            ),
+            Some(Hint::MissingCompiler)
+        );
+        assert_eq!(
+            Hint::from_line("src/main.c:1:10: fatal error: Python.h: No such file or directory"),
+            Some(Hint::MissingPythonHeaders)
+        );
+        assert_eq!(
+            Hint::from_line("src/main.c:1:10: fatal error: 'Python.h' file not found"),
+            Some(Hint::MissingPythonHeaders)
+        );
+        assert_eq!(
+            Hint::from_line("Cargo, the Rust package manager, is not installed or is not on PATH."),
+            Some(Hint::MissingRustToolchain)
+        );
+        assert_eq!(
+            Hint::from_line("error: can't find Rust compiler"),
+            Some(Hint::MissingRustToolchain)
+        );
+        assert_eq!(
+            Hint::from_line("invalid peer certificate: UnknownIssuer"),
+            Some(Hint::CertificateVerification)
+        );
+        assert_eq!(
+            Hint::from_line("fatal error: graphviz/cgraph.h: No such file or directory"),
+            None
+        );
+        assert_eq!(Hint::from_line("error: command 'gcc' failed"), None);
+    }
+}
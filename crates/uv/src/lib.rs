@@ -7,6 +7,7 @@ use std::ops::Bound;
 use std::path::Path;
 use std::process::ExitCode;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
 use anstream::eprintln;
@@ -25,17 +26,20 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand,
-    SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs, compat::CompatArgs,
+    EnvCommand, EnvNamespace, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
+    PythonNamespace, SchemaKind, SelfCommand, SelfNamespace, StoreCommand, StoreNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, WheelhouseCommand, WheelhouseNamespace,
+    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
-use uv_client::BaseClientBuilder;
-use uv_configuration::min_stack_size;
+use uv_client::{BaseClientBuilder, MirrorManifest};
+use uv_configuration::{DryRun, min_stack_size};
 use uv_fs::{CWD, Simplified};
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
 use uv_pep508::VersionOrUrl;
 use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl};
 use uv_python::PythonRequest;
+use uv_redacted::redact_credentials;
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
@@ -45,18 +49,21 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
-use crate::printer::Printer;
+use crate::printer::{Printer, Styled, Theme};
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
     PublishSettings,
 };
 
+pub mod api;
 pub(crate) mod child;
 pub(crate) mod commands;
+pub(crate) mod hints;
 pub(crate) mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
+mod show_settings;
 #[cfg(windows)]
 mod windows_exception;
 
@@ -130,6 +137,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // 3. The nearest configuration file (`uv.toml` or `pyproject.toml`) in the directory tree,
     //    starting from the current directory.
     let workspace_cache = WorkspaceCache::default();
+    let skip_workspace_discovery = match &*cli.command {
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Dir,
+        }) => true,
+        Commands::Project(command) => matches!(
+            &**command,
+            ProjectCommand::Version(args) if args.value.is_none() && args.bump.is_empty()
+        ),
+        _ => false,
+    };
     let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
         if config_file
             .file_name()
@@ -145,6 +162,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
         // For commands that operate at the user-level, ignore local configuration.
         FilesystemOptions::user()?.combine(FilesystemOptions::system()?)
+    } else if matches!(
+        &*cli.command,
+        Commands::Help(_)
+            | Commands::GenerateShellCompletion(_)
+            | Commands::GenerateSchema(_)
+            | Commands::BuildBackend { .. }
+    ) {
+        // For commands that never consult the project, skip workspace discovery entirely, since
+        // it's the dominant cost of startup in large workspaces.
+        FilesystemOptions::user()?.combine(FilesystemOptions::system()?)
+    } else if skip_workspace_discovery {
+        // For commands that only read configuration (like `uv cache dir`, or `uv version` without
+        // an update), skip workspace discovery, but respect the nearest configuration file.
+        let project = FilesystemOptions::find(&project_dir)?;
+        let system = FilesystemOptions::system()?;
+        let user = FilesystemOptions::user()?;
+        project.combine(user).combine(system)
     } else if let Ok(workspace) =
         Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache).await
     {
@@ -170,12 +204,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }) = &mut **command
         {
             let settings = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
+            let mirror_manifest = settings
+                .network_settings
+                .mirror_manifest
+                .as_deref()
+                .map(MirrorManifest::from_path)
+                .transpose()?
+                .map(Arc::new);
             let client_builder = BaseClientBuilder::new(
                 settings.network_settings.connectivity,
                 settings.network_settings.native_tls,
                 settings.network_settings.allow_insecure_host,
                 settings.preview,
             )
+            .rate_limit(settings.network_settings.limit_rate)
+            .metered(settings.network_settings.metered)
+            .mirror_manifest(mirror_manifest)
+            .quarantine(settings.quarantine)
             .retries_from_env()?;
             Some(
                 RunCommand::from_args(command, client_builder, *module, *script, *gui_script)
@@ -250,15 +295,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 Ok(None) => {
                     bail!(
                         "`{}` does not contain a PEP 723 metadata tag; run `{}` to initialize the script",
-                        script.user_display().cyan(),
-                        format!("uv init --script {}", script.user_display()).green()
+                        script.user_display().accent(),
+                        format!("uv init --script {}", script.user_display()).success()
                     )
                 }
                 Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
                     bail!(
                         "Failed to read `{}` (not found); run `{}` to create a PEP 723 script",
-                        script.user_display().cyan(),
-                        format!("uv init --script {}", script.user_display()).green()
+                        script.user_display().accent(),
+                        format!("uv init --script {}", script.user_display()).success()
                     )
                 }
                 Err(err) => return Err(err.into()),
@@ -278,15 +323,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             Ok(None) => {
                 bail!(
                     "`{}` does not contain a PEP 723 metadata tag; run `{}` to initialize the script",
-                    script.user_display().cyan(),
-                    format!("uv init --script {}", script.user_display()).green()
+                    script.user_display().accent(),
+                    format!("uv init --script {}", script.user_display()).success()
                 )
             }
             Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
                 bail!(
                     "Failed to read `{}` (not found); run `{}` to create a PEP 723 script",
-                    script.user_display().cyan(),
-                    format!("uv init --script {}", script.user_display()).green()
+                    script.user_display().accent(),
+                    format!("uv init --script {}", script.user_display()).success()
                 )
             }
             Err(err) => return Err(err.into()),
@@ -328,7 +373,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     // E.g., `==1.0.0`
                     format!(
                         ". Update `uv` by running `{}`.",
-                        format!("uv self update {singleton}").green()
+                        format!("uv self update {singleton}").success()
                     )
                 } else if ranges
                     .bounding_range()
@@ -340,7 +385,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     })
                 {
                     // E.g., `>=1.0.0`
-                    format!(". Update `uv` by running `{}`.", "uv self update".cyan())
+                    format!(". Update `uv` by running `{}`.", "uv self update".accent())
                 } else {
                     String::new()
                 }
@@ -391,6 +436,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     anstream::ColorChoice::write_global(globals.color.into());
 
+    // Configure the style table for user-facing output, restricting it to ASCII on dumb terminals.
+    if std::env::var_os(EnvVars::TERM).is_some_and(|term| term == "dumb") {
+        Theme {
+            ascii_only: true,
+            ..globals.theme
+        }
+        .init();
+    } else {
+        globals.theme.init();
+    }
+
     miette::set_hook(Box::new(|_| {
         Box::new(
             miette::MietteHandlerOpts::new()
@@ -415,13 +471,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     macro_rules! show_settings {
         ($arg:expr) => {
             if globals.show_settings {
-                writeln!(printer.stdout(), "{:#?}", $arg)?;
+                show_settings::write_settings(printer, globals.show_settings_format, &$arg)?;
                 return Ok(ExitStatus::Success);
             }
         };
         ($arg:expr, false) => {
             if globals.show_settings {
-                writeln!(printer.stdout(), "{:#?}", $arg)?;
+                show_settings::write_settings(printer, globals.show_settings_format, &$arg)?;
             }
         };
     }
@@ -432,16 +488,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_index_policy(cache_settings.index_policy);
 
     // Configure the global network settings.
-    let client_builder = BaseClientBuilder::new(
-        globals.network_settings.connectivity,
-        globals.network_settings.native_tls,
-        globals.network_settings.allow_insecure_host.clone(),
-        globals.preview,
-    )
-    .retries_from_env()?;
+    let client_builder = base_client_builder(&globals)?;
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -526,96 +577,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = PipCompileSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
-
-            let requirements = args
-                .src_file
-                .into_iter()
-                .map(RequirementsSource::from_requirements_file)
-                .collect::<Result<Vec<_>, _>>()?;
-            let constraints = args
-                .constraints
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let overrides = args
-                .overrides
-                .into_iter()
-                .map(RequirementsSource::from_overrides_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let build_constraints = args
-                .build_constraints
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let groups = GroupsSpecification {
-                root: project_dir.to_path_buf(),
-                groups: args.settings.groups,
-            };
-
-            commands::pip_compile(
-                &requirements,
-                &constraints,
-                &overrides,
-                &build_constraints,
-                args.constraints_from_workspace,
-                args.overrides_from_workspace,
-                args.build_constraints_from_workspace,
-                args.environments,
-                args.settings.extras,
-                groups,
-                args.settings.output_file.as_deref(),
-                args.format,
-                args.settings.resolution,
-                args.settings.prerelease,
-                args.settings.fork_strategy,
-                args.settings.dependency_mode,
-                args.settings.upgrade,
-                args.settings.generate_hashes,
-                args.settings.no_emit_package,
-                args.settings.no_strip_extras,
-                args.settings.no_strip_markers,
-                !args.settings.no_annotate,
-                !args.settings.no_header,
-                args.settings.custom_compile_command,
-                args.settings.emit_index_url,
-                args.settings.emit_find_links,
-                args.settings.emit_build_options,
-                args.settings.emit_marker_expression,
-                args.settings.emit_index_annotation,
-                args.settings.index_locations,
-                args.settings.index_strategy,
-                args.settings.torch_backend,
-                args.settings.dependency_metadata,
-                args.settings.keyring_provider,
+            Box::pin(pip_compile(
+                args,
+                &globals,
                 &client_builder,
-                args.settings.config_setting,
-                args.settings.config_settings_package,
-                args.settings.build_isolation.clone(),
-                &args.settings.extra_build_dependencies,
-                &args.settings.extra_build_variables,
-                args.settings.build_options,
-                args.settings.python_version,
-                args.settings.python_platform,
-                args.settings.universal,
-                args.settings.exclude_newer,
-                args.settings.sources,
-                args.settings.annotation_style,
-                args.settings.link_mode,
-                args.settings.python,
-                args.settings.system,
-                globals.python_preference,
-                globals.concurrency,
-                globals.quiet > 0,
                 cache,
+                &project_dir,
                 printer,
-                globals.preview,
-            )
+            ))
             .await
         }
         Commands::Pip(PipNamespace {
@@ -672,6 +641,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &client_builder,
                 args.settings.allow_empty_requirements,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
                 args.settings.build_isolation.clone(),
@@ -680,6 +651,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.platform_override,
                 args.settings.strict,
                 args.settings.exclude_newer,
                 args.settings.python,
@@ -692,6 +664,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.allow_script_conflicts,
+                args.exclude_source,
                 printer,
                 globals.preview,
             )
@@ -703,142 +677,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.compat_args.validate()?;
 
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let mut args = PipInstallSettings::resolve(args, filesystem);
+            let args = PipInstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            let mut requirements = Vec::with_capacity(
-                args.package.len() + args.editables.len() + args.requirements.len(),
-            );
-            for package in args.package {
-                requirements.push(RequirementsSource::from_package_argument(&package)?);
-            }
-            for package in args.editables {
-                requirements.push(RequirementsSource::from_editable(&package)?);
-            }
-            requirements.extend(
-                args.requirements
-                    .into_iter()
-                    .map(RequirementsSource::from_requirements_file)
-                    .collect::<Result<Vec<_>, _>>()?,
-            );
-            let constraints = args
-                .constraints
-                .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let overrides = args
-                .overrides
-                .into_iter()
-                .map(RequirementsSource::from_overrides_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let build_constraints = args
-                .build_constraints
-                .into_iter()
-                .map(RequirementsSource::from_overrides_txt)
-                .collect::<Result<Vec<_>, _>>()?;
-            let groups = GroupsSpecification {
-                root: project_dir.to_path_buf(),
-                groups: args.settings.groups,
-            };
-
-            // Special-case: any source trees specified on the command-line are automatically
-            // reinstalled. This matches user expectations: `uv pip install .` should always
-            // re-build and re-install the package in the current working directory.
-            for requirement in &requirements {
-                let requirement = match requirement {
-                    RequirementsSource::Package(requirement) => requirement,
-                    RequirementsSource::Editable(requirement) => requirement,
-                    _ => continue,
-                };
-                match requirement {
-                    RequirementsTxtRequirement::Named(requirement) => {
-                        if let Some(VersionOrUrl::Url(url)) = requirement.version_or_url.as_ref() {
-                            if let ParsedUrl::Directory(ParsedDirectoryUrl {
-                                install_path, ..
-                            }) = &url.parsed_url
-                            {
-                                debug!(
-                                    "Marking explicit source tree for reinstall: `{}`",
-                                    install_path.display()
-                                );
-                                args.settings.reinstall = args
-                                    .settings
-                                    .reinstall
-                                    .with_package(requirement.name.clone());
-                            }
-                        }
-                    }
-                    RequirementsTxtRequirement::Unnamed(requirement) => {
-                        if let ParsedUrl::Directory(ParsedDirectoryUrl { install_path, .. }) =
-                            &requirement.url.parsed_url
-                        {
-                            debug!(
-                                "Marking explicit source tree for reinstall: `{}`",
-                                install_path.display()
-                            );
-                            args.settings.reinstall =
-                                args.settings.reinstall.with_path(install_path.clone());
-                        }
-                    }
-                }
-            }
-
-            // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
-                args.refresh
-                    .combine(Refresh::from(args.settings.reinstall.clone()))
-                    .combine(Refresh::from(args.settings.upgrade.clone())),
-            );
-
-            commands::pip_install(
-                &requirements,
-                &constraints,
-                &overrides,
-                &build_constraints,
-                args.constraints_from_workspace,
-                args.overrides_from_workspace,
-                args.build_constraints_from_workspace,
-                &args.settings.extras,
-                &groups,
-                args.settings.resolution,
-                args.settings.prerelease,
-                args.settings.dependency_mode,
-                args.settings.upgrade,
-                args.settings.index_locations,
-                args.settings.index_strategy,
-                args.settings.torch_backend,
-                args.settings.dependency_metadata,
-                args.settings.keyring_provider,
+            Box::pin(pip_install(
+                args,
+                &globals,
                 &client_builder,
-                args.settings.reinstall,
-                args.settings.link_mode,
-                args.settings.compile_bytecode,
-                args.settings.hash_checking,
-                globals.installer_metadata,
-                &args.settings.config_setting,
-                &args.settings.config_settings_package,
-                args.settings.build_isolation.clone(),
-                &args.settings.extra_build_dependencies,
-                &args.settings.extra_build_variables,
-                args.settings.build_options,
-                args.modifications,
-                args.settings.python_version,
-                args.settings.python_platform,
-                args.settings.strict,
-                args.settings.exclude_newer,
-                args.settings.sources,
-                args.settings.python,
-                args.settings.system,
-                args.settings.break_system_packages,
-                args.settings.target,
-                args.settings.prefix,
-                globals.python_preference,
-                globals.concurrency,
                 cache,
-                args.dry_run,
+                &project_dir,
                 printer,
-                globals.preview,
-            )
+            ))
             .await
         }
         Commands::Pip(PipNamespace {
@@ -872,6 +721,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &client_builder,
                 args.dry_run,
+                globals.locked_timeout,
                 printer,
                 globals.preview,
             )
@@ -889,6 +739,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_freeze(
                 args.exclude_editable,
+                &args.exclude,
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
@@ -914,7 +765,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.editable,
                 &args.exclude,
                 &args.format,
+                args.sort,
                 args.outdated,
+                args.include_metadata,
+                args.include_size,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -947,6 +801,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.files,
+                args.scripts,
                 &cache,
                 printer,
                 globals.preview,
@@ -963,6 +818,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_tree(
                 args.show_version_specifiers,
+                args.format,
                 args.depth,
                 &args.prune,
                 &args.package,
@@ -1000,17 +856,30 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.system,
                 args.settings.python_version.as_ref(),
                 args.settings.python_platform.as_ref(),
+                args.settings.strict,
                 &cache,
                 printer,
                 globals.preview,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::NormalizeRequirements(args),
+        }) => {
+            show_settings!(args);
+            commands::pip_normalize_requirements(args.requirements, printer)
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, cache, printer)
+            commands::cache_clean(&args.package, args.dry_run, cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Show(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_show(&args.package, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -1018,12 +887,84 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
             commands::cache_prune(args.ci, cache, printer)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Migrate,
+        }) => commands::cache_migrate(cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify,
+        }) => commands::cache_verify(cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Env(EnvNamespace {
+            command: EnvCommand::CreateFor(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::EnvCreateForSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::env_create_for(
+                &args.path,
+                args.python.as_deref(),
+                args.system_site_packages,
+                &args.install_mirrors,
+                globals.python_preference,
+                globals.python_downloads,
+                &client_builder,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Env(EnvNamespace {
+            command: EnvCommand::Snapshot(args),
+        }) => {
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::env_snapshot(
+                args.python.as_deref(),
+                args.output_file.as_deref(),
+                globals.python_preference,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Store(StoreNamespace {
+            command: StoreCommand::Gc(args),
+        }) => {
+            show_settings!(args);
+            commands::store_gc(&args.site_packages_dir, printer)
+        }
+        Commands::Wheelhouse(WheelhouseNamespace {
+            command: WheelhouseCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::wheelhouse_verify(&args.path, args.manifest.as_deref(), printer)
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Affected(args),
+        }) => {
+            show_settings!(args);
+            commands::workspace_affected(&project_dir, &args.since, args.output_format, printer)
+                .await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Graph(args),
+        }) => {
+            show_settings!(args);
+            commands::workspace_graph(&project_dir, args.format, printer).await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -1187,6 +1128,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             args.shell.generate(&mut Cli::command(), &mut stdout());
             Ok(ExitStatus::Success)
         }
+        Commands::GenerateSchema(args) => {
+            let schema = match args.kind {
+                SchemaKind::Config => uv_settings::json_schema(),
+                SchemaKind::ShowSettings => show_settings::json_schema(),
+            };
+            writeln!(printer.stdout_important(), "{schema}")?;
+            Ok(ExitStatus::Success)
+        }
         Commands::Tool(ToolNamespace {
             command: run_variant @ (ToolCommand::Uvx(_) | ToolCommand::Run(_)),
         }) => {
@@ -1286,6 +1235,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
                 globals.concurrency,
                 cache,
                 printer,
@@ -1332,7 +1282,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 else {
                     bail!(
                         "Expected a named package for `--with-executables-from`, but got: {}",
-                        source.to_string().cyan()
+                        source.to_string().accent()
                     )
                 };
                 entrypoints.push(requirement.name.clone());
@@ -1374,6 +1324,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
                 globals.concurrency,
                 cache,
                 printer,
@@ -1401,6 +1352,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Show(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolShowSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tool_show(args.name, &cache, printer).await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Upgrade(args),
         }) => {
@@ -1415,6 +1378,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.names,
                 args.python,
                 args.python_platform,
+                args.relax_with,
                 args.install_mirrors,
                 args.args,
                 args.filesystem,
@@ -1422,6 +1386,31 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Check(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolCheckSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::tool_check(
+                args.repair,
+                client_builder,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.quarantine,
                 globals.concurrency,
                 &cache,
                 printer,
@@ -1470,6 +1459,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.all_versions,
                 args.all_platforms,
                 args.all_arches,
+                args.all_sources,
                 args.show_urls,
                 args.output_format,
                 args.python_downloads_json_url,
@@ -1504,6 +1494,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_downloads_json_url,
                 client_builder,
                 args.default,
+                args.ensurepip,
+                args.check_libraries,
                 globals.python_downloads,
                 cli.top_level.no_config,
                 globals.preview,
@@ -1533,6 +1525,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_downloads_json_url,
                 client_builder,
                 args.default,
+                args.ensurepip,
+                args.check_libraries,
                 globals.python_downloads,
                 cli.top_level.no_config,
                 globals.preview,
@@ -1569,6 +1563,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::python_find_script(
                     (&script).into(),
                     args.show_version,
+                    args.base,
+                    args.resolve_symlinks,
+                    args.output_format,
                     &client_builder,
                     globals.python_preference,
                     globals.python_downloads,
@@ -1583,6 +1580,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.request,
                     args.show_version,
+                    args.base,
+                    args.resolve_symlinks,
+                    args.output_format,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -1655,6 +1655,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 username,
                 password,
                 dry_run,
+                check,
                 publish_url,
                 trusted_publishing,
                 keyring_provider,
@@ -1675,6 +1676,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 index,
                 index_locations,
                 dry_run,
+                check,
                 &cache,
                 printer,
             )
@@ -1719,6 +1721,294 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     }
 }
 
+/// Configure a [`BaseClientBuilder`] from the global network settings.
+pub(crate) fn base_client_builder(globals: &GlobalSettings) -> Result<BaseClientBuilder<'static>> {
+    // Load the mirror manifest, if any.
+    let mirror_manifest = globals
+        .network_settings
+        .mirror_manifest
+        .as_deref()
+        .map(MirrorManifest::from_path)
+        .transpose()?
+        .map(Arc::new);
+
+    Ok(BaseClientBuilder::new(
+        globals.network_settings.connectivity,
+        globals.network_settings.native_tls,
+        globals.network_settings.allow_insecure_host.clone(),
+        globals.preview,
+    )
+    .rate_limit(globals.network_settings.limit_rate)
+    .metered(globals.network_settings.metered)
+    .mirror_manifest(mirror_manifest)
+    .quarantine(globals.quarantine)
+    .retries_from_env()?)
+}
+
+/// Run `uv pip compile` with the resolved settings.
+pub(crate) async fn pip_compile(
+    args: PipCompileSettings,
+    globals: &GlobalSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Cache,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Initialize the cache.
+    let cache = cache.init()?.with_refresh(
+        args.refresh
+            .combine(Refresh::from(args.settings.reinstall.clone()))
+            .combine(Refresh::from(args.settings.upgrade.clone())),
+    );
+
+    let requirements = args
+        .src_file
+        .into_iter()
+        .map(RequirementsSource::from_requirements_file)
+        .collect::<Result<Vec<_>, _>>()?;
+    let constraints = args
+        .constraints
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let overrides = args
+        .overrides
+        .into_iter()
+        .map(RequirementsSource::from_overrides_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let build_constraints = args
+        .build_constraints
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let groups = GroupsSpecification {
+        root: project_dir.to_path_buf(),
+        groups: args.settings.groups,
+    };
+
+    commands::pip_compile(
+        &requirements,
+        &constraints,
+        &overrides,
+        &build_constraints,
+        args.constraints_from_workspace,
+        args.overrides_from_workspace,
+        args.scoped_overrides_from_workspace,
+        args.build_constraints_from_workspace,
+        args.environments,
+        args.settings.extras,
+        groups,
+        args.settings.output_file.as_deref(),
+        args.format,
+        args.settings.resolution,
+        args.settings.prerelease,
+        args.settings.fork_strategy,
+        args.settings.dependency_mode,
+        args.settings.upgrade,
+        args.settings.generate_hashes,
+        args.settings.allow_insecure_md5_hashes,
+        args.reuse_hashes,
+        args.settings.no_emit_package,
+        args.settings.no_strip_extras,
+        args.settings.no_strip_markers,
+        !args.settings.no_annotate,
+        !args.settings.no_header,
+        args.settings.custom_compile_command,
+        args.settings.emit_index_url,
+        args.settings.emit_find_links,
+        args.settings.emit_build_options,
+        args.settings.emit_marker_expression,
+        args.settings.emit_index_annotation,
+        args.settings.emit_license,
+        args.settings.emit_homepage,
+        args.settings.emit_latest_version,
+        args.settings.emit_summary,
+        args.settings.emit_fork_annotation,
+        args.settings.emit_provenance,
+        args.verify,
+        args.settings.index_locations,
+        args.settings.index_strategy,
+        args.settings.torch_backend,
+        args.settings.dependency_metadata,
+        args.settings.keyring_provider,
+        client_builder,
+        args.settings.config_setting,
+        args.settings.config_settings_package,
+        args.settings.build_isolation.clone(),
+        &args.settings.extra_build_dependencies,
+        &args.settings.extra_build_variables,
+        args.settings.build_options,
+        args.settings.python_version,
+        args.settings.python_platform,
+        args.marker_snapshot.as_deref(),
+        args.settings.universal,
+        args.settings.exclude_newer,
+        args.settings.sources,
+        args.settings.annotation_style,
+        args.settings.link_mode,
+        args.settings.python,
+        args.settings.system,
+        globals.python_preference,
+        globals.concurrency,
+        globals.quiet > 0,
+        cache,
+        printer,
+        globals.preview,
+    )
+    .await
+}
+
+/// Run `uv pip install` with the resolved settings.
+pub(crate) async fn pip_install(
+    mut args: PipInstallSettings,
+    globals: &GlobalSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Cache,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut requirements =
+        Vec::with_capacity(args.package.len() + args.editables.len() + args.requirements.len());
+    for package in args.package {
+        requirements.push(RequirementsSource::from_package_argument(&package)?);
+    }
+    for package in args.editables {
+        requirements.push(RequirementsSource::from_editable(&package)?);
+    }
+    requirements.extend(
+        args.requirements
+            .into_iter()
+            .map(RequirementsSource::from_requirements_file)
+            .collect::<Result<Vec<_>, _>>()?,
+    );
+    let constraints = args
+        .constraints
+        .into_iter()
+        .map(RequirementsSource::from_constraints_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let overrides = args
+        .overrides
+        .into_iter()
+        .map(RequirementsSource::from_overrides_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let build_constraints = args
+        .build_constraints
+        .into_iter()
+        .map(RequirementsSource::from_overrides_txt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let groups = GroupsSpecification {
+        root: project_dir.to_path_buf(),
+        groups: args.settings.groups,
+    };
+
+    // Special-case: any source trees specified on the command-line are automatically
+    // reinstalled. This matches user expectations: `uv pip install .` should always
+    // re-build and re-install the package in the current working directory.
+    for requirement in &requirements {
+        let requirement = match requirement {
+            RequirementsSource::Package(requirement) => requirement,
+            RequirementsSource::Editable(requirement) => requirement,
+            _ => continue,
+        };
+        match requirement {
+            RequirementsTxtRequirement::Named(requirement) => {
+                if let Some(VersionOrUrl::Url(url)) = requirement.version_or_url.as_ref() {
+                    if let ParsedUrl::Directory(ParsedDirectoryUrl { install_path, .. }) =
+                        &url.parsed_url
+                    {
+                        debug!(
+                            "Marking explicit source tree for reinstall: `{}`",
+                            install_path.display()
+                        );
+                        args.settings.reinstall = args
+                            .settings
+                            .reinstall
+                            .with_package(requirement.name.clone());
+                    }
+                }
+            }
+            RequirementsTxtRequirement::Unnamed(requirement) => {
+                if let ParsedUrl::Directory(ParsedDirectoryUrl { install_path, .. }) =
+                    &requirement.url.parsed_url
+                {
+                    debug!(
+                        "Marking explicit source tree for reinstall: `{}`",
+                        install_path.display()
+                    );
+                    args.settings.reinstall =
+                        args.settings.reinstall.with_path(install_path.clone());
+                }
+            }
+        }
+    }
+
+    // Initialize the cache.
+    let cache = cache.init()?.with_refresh(
+        args.refresh
+            .combine(Refresh::from(args.settings.reinstall.clone()))
+            .combine(Refresh::from(args.settings.upgrade.clone())),
+    );
+
+    commands::pip_install(
+        &requirements,
+        &constraints,
+        &overrides,
+        &build_constraints,
+        args.constraints_from_workspace,
+        args.overrides_from_workspace,
+        args.scoped_overrides_from_workspace,
+        args.build_constraints_from_workspace,
+        &args.settings.extras,
+        &groups,
+        args.settings.resolution,
+        args.settings.prerelease,
+        args.settings.dependency_mode,
+        args.settings.upgrade,
+        args.settings.index_locations,
+        args.settings.index_strategy,
+        args.settings.torch_backend,
+        args.settings.dependency_metadata,
+        args.settings.keyring_provider,
+        client_builder,
+        args.settings.reinstall,
+        args.settings.link_mode,
+        args.settings.compile_bytecode,
+        args.settings.hash_checking,
+        globals.installer_metadata,
+        globals.quarantine,
+        globals.locked_timeout,
+        &globals.plugins,
+        &args.settings.config_setting,
+        &args.settings.config_settings_package,
+        args.settings.build_isolation.clone(),
+        &args.settings.extra_build_dependencies,
+        &args.settings.extra_build_variables,
+        args.settings.build_options,
+        args.modifications,
+        args.settings.python_version,
+        args.settings.python_platform,
+        args.platform_override,
+        args.settings.strict,
+        args.settings.exclude_newer,
+        args.settings.sources,
+        args.settings.python,
+        args.settings.system,
+        args.settings.break_system_packages,
+        args.settings.target,
+        args.settings.prefix,
+        globals.python_preference,
+        globals.concurrency,
+        cache,
+        args.dry_run,
+        args.allow_script_conflicts,
+        args.exclude_source,
+        args.no_cache_download,
+        printer,
+        globals.preview,
+    )
+    .await
+}
+
 /// Run a [`ProjectCommand`].
 async fn run_project(
     project_command: Box<ProjectCommand>,
@@ -1738,7 +2028,7 @@ async fn run_project(
     macro_rules! show_settings {
         ($arg:expr) => {
             if globals.show_settings {
-                writeln!(printer.stdout(), "{:#?}", $arg)?;
+                show_settings::write_settings(printer, globals.show_settings_format, &$arg)?;
                 return Ok(ExitStatus::Success);
             }
         };
@@ -1753,7 +2043,13 @@ async fn run_project(
             // Initialize the cache.
             let cache = cache.init()?;
 
-            commands::init(
+            // Determine the directory of the new project, to lock it once it's been created.
+            let lock_dir = match args.path.as_deref() {
+                Some(path) => std::path::absolute(path)?,
+                None => project_dir.to_path_buf(),
+            };
+
+            let status = commands::init(
                 project_dir,
                 args.path,
                 args.name,
@@ -1767,9 +2063,10 @@ async fn run_project(
                 args.no_readme,
                 args.author_from,
                 args.pin_python,
-                args.python,
-                args.install_mirrors,
+                args.python.clone(),
+                args.install_mirrors.clone(),
                 args.no_workspace,
+                args.template,
                 &client_builder,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1778,6 +2075,35 @@ async fn run_project(
                 printer,
                 globals.preview,
             )
+            .await?;
+
+            if !args.lock {
+                return Ok(status);
+            }
+
+            // Lock the project that was created from the template.
+            Box::pin(commands::lock(
+                &lock_dir,
+                false,
+                false,
+                DryRun::Disabled,
+                false,
+                false,
+                None,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder,
+                None,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                &globals.plugins,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
             .await
         }
         ProjectCommand::Run(args) => {
@@ -1819,8 +2145,12 @@ async fn run_project(
                 args.active,
                 args.no_sync,
                 args.isolated,
+                args.keep,
                 args.all_packages,
                 args.package,
+                args.each,
+                args.profile,
+                args.env,
                 args.no_project,
                 no_config,
                 args.extras,
@@ -1835,6 +2165,9 @@ async fn run_project(
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
+                &globals.plugins,
                 globals.concurrency,
                 cache,
                 printer,
@@ -1867,10 +2200,16 @@ async fn run_project(
                 project_dir,
                 args.locked,
                 args.frozen,
+                args.verify_lock_signature,
+                args.site_packages_dir,
                 args.dry_run,
+                args.allow_script_conflicts,
+                args.exclude_source,
                 args.active,
                 args.all_packages,
                 args.package,
+                args.profile,
+                args.env,
                 args.extras,
                 args.groups,
                 args.editable,
@@ -1885,6 +2224,9 @@ async fn run_project(
                 client_builder,
                 script,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
+                &globals.plugins,
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -1899,6 +2241,11 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // If invoked as a merge driver, merge the lockfiles without performing a resolution.
+            if let Some([current, base, other]) = &args.merge {
+                return commands::lock_merge(current, base, other, printer).await;
+            }
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -1923,6 +2270,9 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.integrity,
+                args.resolve_merge_conflicts,
+                args.profile,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -1931,6 +2281,41 @@ async fn run_project(
                 globals.python_preference,
                 globals.python_downloads,
                 globals.concurrency,
+                &globals.plugins,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Watch(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WatchSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::watch(
+                project_dir,
+                args.sync,
+                args.debounce,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
+                &globals.plugins,
+                globals.concurrency,
                 no_config,
                 &cache,
                 printer,
@@ -2053,6 +2438,9 @@ async fn run_project(
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
+                &globals.plugins,
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -2088,6 +2476,7 @@ async fn run_project(
                 args.no_sync,
                 args.packages,
                 args.dependency_type,
+                args.marker_only,
                 args.package,
                 args.python,
                 args.install_mirrors,
@@ -2097,6 +2486,9 @@ async fn run_project(
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
+                globals.locked_timeout,
+                &globals.plugins,
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -2137,6 +2529,8 @@ async fn run_project(
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
+                globals.quarantine,
+                &globals.plugins,
                 globals.concurrency,
                 no_config,
                 &cache,
@@ -2190,6 +2584,16 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Diff(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::DiffSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::diff(&args.old, &args.new, args.output_format, &cache, printer).await
+        }
         ProjectCommand::Export(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::ExportSettings::resolve(args, filesystem);
@@ -2238,6 +2642,43 @@ async fn run_project(
             .boxed_local()
             .await
         }
+        ProjectCommand::Bundle(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::bundle(
+                project_dir,
+                args.output_dir,
+                args.archive,
+                args.package,
+                args.extras,
+                args.groups,
+                args.locked,
+                args.frozen,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.quarantine,
+                &globals.plugins,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Format(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::FormatSettings::resolve(args, filesystem);
@@ -2302,6 +2743,11 @@ where
     let cli = match Cli::try_parse_from(args) {
         Ok(cli) => cli,
         Err(mut err) => {
+            // Avoid leaking credentials from, e.g., index URLs provided via environment variables.
+            if let Some(ContextValue::String(value)) = err.get(ContextKind::InvalidValue) {
+                let value = redact_credentials(value).into_owned();
+                err.insert(ContextKind::InvalidValue, ContextValue::String(value));
+            }
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
             {
                 match subcommand.as_str() {
@@ -2344,7 +2790,15 @@ where
                     _ => {}
                 }
             }
-            err.exit()
+            // Print the error (or the help and version output) as clap would, but exit with the
+            // generic failure code for invalid arguments, since `2` is reserved for resolution
+            // failures.
+            let _ = err.print();
+            return if err.use_stderr() {
+                ExitStatus::Failure.into()
+            } else {
+                ExitStatus::Success.into()
+            };
         }
     };
 
@@ -2381,13 +2835,28 @@ where
             let mut causes = err.chain();
             eprintln!(
                 "{}: {}",
-                "error".red().bold(),
+                "error".error().bold(),
                 causes.next().unwrap().to_string().trim()
             );
             for err in causes {
-                eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
+                eprintln!(
+                    "  {}: {}",
+                    "Caused by".error().bold(),
+                    err.to_string().trim()
+                );
+            }
+            let status = ExitStatus::from_error(&*err).unwrap_or(ExitStatus::Error);
+            if matches!(status, ExitStatus::DiskFull) {
+                eprintln!(
+                    "\n{}{} Free up disk space, or run `{}` to remove unused entries from the cache",
+                    "hint".bold().accent(),
+                    ":".bold(),
+                    "uv cache prune".success()
+                );
+            } else if let Some(hint) = hints::Hint::from_error(&*err) {
+                eprintln!("\n{}{} {hint}", "hint".bold().accent(), ":".bold());
             }
-            ExitStatus::Error.into()
+            status.into()
         }
     }
 }
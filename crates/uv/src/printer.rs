@@ -1,5 +1,192 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
+use owo_colors::{AnsiColors, FgDynColorDisplay, OwoColorize};
+
+use uv_settings::{TerminalColor, TerminalOptions};
+
+/// The [`Theme`] for the current process.
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// The [`Theme`] to use if none was configured.
+static DEFAULT_THEME: Theme = Theme {
+    accent: AnsiColors::Cyan,
+    success: AnsiColors::Green,
+    warning: AnsiColors::Yellow,
+    error: AnsiColors::Red,
+    ascii_only: false,
+};
+
+/// The central style table for user-facing output, as configured via `[tool.uv.terminal]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Theme {
+    /// The color used to highlight package names, paths, versions, and other notable values.
+    pub(crate) accent: AnsiColors,
+    /// The color used for suggested commands, added packages, and other successful outcomes.
+    pub(crate) success: AnsiColors,
+    /// The color used for warnings.
+    pub(crate) warning: AnsiColors,
+    /// The color used for errors and removed packages.
+    pub(crate) error: AnsiColors,
+    /// Whether to restrict output to ASCII characters.
+    pub(crate) ascii_only: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        DEFAULT_THEME
+    }
+}
+
+impl Theme {
+    /// Resolve the [`Theme`] from the `[tool.uv.terminal]` settings.
+    pub(crate) fn from_options(options: TerminalOptions) -> Self {
+        let TerminalOptions {
+            accent_color,
+            success_color,
+            warning_color,
+            error_color,
+            ascii_only,
+        } = options;
+        let default = Self::default();
+        Self {
+            accent: accent_color.map_or(default.accent, ansi_color),
+            success: success_color.map_or(default.success, ansi_color),
+            warning: warning_color.map_or(default.warning, ansi_color),
+            error: error_color.map_or(default.error, ansi_color),
+            ascii_only: ascii_only.unwrap_or(default.ascii_only),
+        }
+    }
+
+    /// Set the [`Theme`] for the current process.
+    ///
+    /// Has no effect if a [`Theme`] was already set.
+    pub(crate) fn init(self) {
+        let _ = THEME.set(self);
+    }
+
+    /// Return the [`Theme`] for the current process.
+    pub(crate) fn current() -> &'static Self {
+        THEME.get().unwrap_or(&DEFAULT_THEME)
+    }
+
+    /// Return the tick strings to use for progress spinners.
+    pub(crate) fn spinner(&self) -> &'static [&'static str] {
+        if self.ascii_only {
+            &["|", "/", "-", "\\"]
+        } else {
+            &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+        }
+    }
+
+    /// Return the characters to use for progress bars.
+    pub(crate) fn progress_chars(&self) -> &'static str {
+        if self.ascii_only { "#-" } else { "█░" }
+    }
+
+    /// Apply the theme's colors to an `indicatif` progress template, replacing each `$accent` and
+    /// `$success` placeholder with the corresponding style (e.g., `{spinner:.$accent}`).
+    pub(crate) fn progress_template(&self, template: &str) -> String {
+        template
+            .replace("$accent", progress_color(self.accent))
+            .replace("$success", progress_color(self.success))
+    }
+
+    /// Replace any non-ASCII symbols in the given output, if the theme is restricted to ASCII.
+    fn render<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if !self.ascii_only || s.is_ascii() {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(
+            s.chars()
+                .map(|c| match c {
+                    '├' | '└' | '│' => '|',
+                    '─' => '-',
+                    c => c,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Return the `indicatif` style for an [`AnsiColors`].
+///
+/// `indicatif` ignores unknown style names, so the terminal's default color is rendered unstyled.
+fn progress_color(color: AnsiColors) -> &'static str {
+    match color {
+        AnsiColors::Default => "default",
+        AnsiColors::Black => "black",
+        AnsiColors::Red => "red",
+        AnsiColors::Green => "green",
+        AnsiColors::Yellow => "yellow",
+        AnsiColors::Blue => "blue",
+        AnsiColors::Magenta => "magenta",
+        AnsiColors::Cyan => "cyan",
+        AnsiColors::White => "white",
+        AnsiColors::BrightBlack => "black.bright",
+        AnsiColors::BrightRed => "red.bright",
+        AnsiColors::BrightGreen => "green.bright",
+        AnsiColors::BrightYellow => "yellow.bright",
+        AnsiColors::BrightBlue => "blue.bright",
+        AnsiColors::BrightMagenta => "magenta.bright",
+        AnsiColors::BrightCyan => "cyan.bright",
+        AnsiColors::BrightWhite => "white.bright",
+    }
+}
+
+/// Convert a [`TerminalColor`] to the equivalent [`AnsiColors`].
+fn ansi_color(color: TerminalColor) -> AnsiColors {
+    match color {
+        TerminalColor::Default => AnsiColors::Default,
+        TerminalColor::Black => AnsiColors::Black,
+        TerminalColor::Red => AnsiColors::Red,
+        TerminalColor::Green => AnsiColors::Green,
+        TerminalColor::Yellow => AnsiColors::Yellow,
+        TerminalColor::Blue => AnsiColors::Blue,
+        TerminalColor::Magenta => AnsiColors::Magenta,
+        TerminalColor::Cyan => AnsiColors::Cyan,
+        TerminalColor::White => AnsiColors::White,
+        TerminalColor::BrightBlack => AnsiColors::BrightBlack,
+        TerminalColor::BrightRed => AnsiColors::BrightRed,
+        TerminalColor::BrightGreen => AnsiColors::BrightGreen,
+        TerminalColor::BrightYellow => AnsiColors::BrightYellow,
+        TerminalColor::BrightBlue => AnsiColors::BrightBlue,
+        TerminalColor::BrightMagenta => AnsiColors::BrightMagenta,
+        TerminalColor::BrightCyan => AnsiColors::BrightCyan,
+        TerminalColor::BrightWhite => AnsiColors::BrightWhite,
+    }
+}
+
+/// Apply the semantic styles from the current [`Theme`] to a value.
+///
+/// Colored output should go through these methods (rather than, e.g., [`OwoColorize::cyan`]), and
+/// progress bars through [`Theme::progress_template`], such that it respects the user's
+/// `[tool.uv.terminal]` settings.
+pub(crate) trait Styled: OwoColorize {
+    /// Style a package name, path, version, or other notable value.
+    fn accent(&self) -> FgDynColorDisplay<'_, AnsiColors, Self> {
+        self.color(Theme::current().accent)
+    }
+
+    /// Style a suggested command, added package, or other successful outcome.
+    fn success(&self) -> FgDynColorDisplay<'_, AnsiColors, Self> {
+        self.color(Theme::current().success)
+    }
+
+    /// Style a warning.
+    fn warning(&self) -> FgDynColorDisplay<'_, AnsiColors, Self> {
+        self.color(Theme::current().warning)
+    }
+
+    /// Style an error or removed package.
+    fn error(&self) -> FgDynColorDisplay<'_, AnsiColors, Self> {
+        self.color(Theme::current().error)
+    }
+}
+
+impl<T: OwoColorize> Styled for T {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Printer {
@@ -76,7 +263,7 @@ impl std::fmt::Write for Stdout {
             Self::Enabled => {
                 #[allow(clippy::print_stdout, clippy::ignored_unit_patterns)]
                 {
-                    print!("{s}");
+                    print!("{}", Theme::current().render(s));
                 }
             }
             Self::Disabled => {}
@@ -98,7 +285,7 @@ impl std::fmt::Write for Stderr {
             Self::Enabled => {
                 #[allow(clippy::print_stderr, clippy::ignored_unit_patterns)]
                 {
-                    eprint!("{s}");
+                    eprint!("{}", Theme::current().render(s));
                 }
             }
             Self::Disabled => {}
@@ -107,3 +294,65 @@ impl std::fmt::Write for Stderr {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use owo_colors::AnsiColors;
+
+    use uv_settings::{TerminalColor, TerminalOptions};
+
+    use super::Theme;
+
+    #[test]
+    fn theme_from_options() {
+        assert_eq!(
+            Theme::from_options(TerminalOptions::default()),
+            Theme::default()
+        );
+
+        let theme = Theme::from_options(TerminalOptions {
+            accent_color: Some(TerminalColor::BrightMagenta),
+            error_color: Some(TerminalColor::Default),
+            ascii_only: Some(true),
+            ..TerminalOptions::default()
+        });
+        assert_eq!(theme.accent, AnsiColors::BrightMagenta);
+        assert_eq!(theme.success, AnsiColors::Green);
+        assert_eq!(theme.warning, AnsiColors::Yellow);
+        assert_eq!(theme.error, AnsiColors::Default);
+        assert!(theme.ascii_only);
+    }
+
+    #[test]
+    fn progress_template() {
+        let theme = Theme {
+            accent: AnsiColors::BrightCyan,
+            success: AnsiColors::Default,
+            ..Theme::default()
+        };
+        assert_eq!(
+            theme.progress_template("{spinner:.$accent} {bar:20.$success/dim}"),
+            "{spinner:.cyan.bright} {bar:20.default/dim}"
+        );
+    }
+
+    #[test]
+    fn render_ascii_only() {
+        let theme = Theme::default();
+        assert_eq!(theme.render("├── foo ─ bar"), "├── foo ─ bar");
+        assert!(!theme.progress_chars().is_ascii());
+
+        let theme = Theme {
+            ascii_only: true,
+            ..Theme::default()
+        };
+        assert_eq!(
+            theme.render("project\n├── foo\n│   └── bar\n└── baz (*)"),
+            "project\n|-- foo\n|   |-- bar\n|-- baz (*)"
+        );
+        // Non-ASCII output other than the tree symbols is left as-is.
+        assert_eq!(theme.render("café"), "café");
+        assert!(theme.spinner().iter().all(|tick| tick.is_ascii()));
+        assert!(theme.progress_chars().is_ascii());
+    }
+}
@@ -3,35 +3,44 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
+
+use itertools::{Either, Itertools};
+use schemars::JsonSchema;
+use serde::Serialize;
 
 use uv_auth::Service;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, IndexCachePolicy, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs,
-    PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
-    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
-    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
-    VersionBump, VersionFormat,
+    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, EnvCreateForArgs,
+    ExternalCommand, GlobalArgs, InitArgs, InitTemplate, ListFormat, ListSort, LockArgs, Maybe,
+    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonFindFormat,
+    PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, RemoveArgs, RunArgs, ShowSettingsFormat, SyncArgs, SyncFormat,
+    ToolCheckArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolShowArgs,
+    ToolUninstallArgs, TreeArgs, TreeFormat, VenvArgs, VersionArgs, VersionBump, VersionFormat,
+    WatchArgs,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, BundleArgs, DiffArgs, DiffFormat, ExportArgs, FormatArgs, PublishArgs,
+    PythonDirArgs, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{flag, resolver_installer_options, resolver_options},
 };
 use uv_client::Connectivity;
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
     ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, ProjectBuildBackend, Reinstall, RequiredVersion,
-    SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    KeyringProviderType, NoBinary, NoBuild, PipCompileFormat, ProjectBuildBackend, RateLimit,
+    Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing,
+    Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
     PackageConfigSettings, Requirement,
 };
+use uv_fs::Quarantine;
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, RequirementOrigin};
@@ -44,8 +53,9 @@ use uv_resolver::{
     PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
-    Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
-    PythonInstallMirrors, ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
+    CacheOptions, Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, Plugins,
+    PublishOptions, PythonInstallMirrors, ResolverInstallerOptions, ResolverInstallerSchema,
+    ResolverOptions,
 };
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -55,13 +65,17 @@ use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::ToolRunCommand;
 use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
+use crate::printer::Theme;
+use crate::show_settings;
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
 
 /// The resolved global settings to use for any invocation of the CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct GlobalSettings {
+    #[serde(serialize_with = "show_settings::serialize_display_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) required_version: Option<RequiredVersion>,
     pub(crate) quiet: u8,
     pub(crate) verbose: u8,
@@ -69,11 +83,24 @@ pub(crate) struct GlobalSettings {
     pub(crate) network_settings: NetworkSettings,
     pub(crate) concurrency: Concurrency,
     pub(crate) show_settings: bool,
+    pub(crate) show_settings_format: ShowSettingsFormat,
+    #[serde(serialize_with = "show_settings::serialize_display")]
+    #[schemars(with = "String")]
     pub(crate) preview: Preview,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) quarantine: Quarantine,
+    #[serde(serialize_with = "show_settings::serialize_debug_option")]
+    #[schemars(with = "Option<String>")]
+    pub(crate) locked_timeout: Option<Duration>,
+    pub(crate) plugins: Plugins,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) theme: Theme,
 }
 
 impl GlobalSettings {
@@ -121,8 +148,19 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                compiles: env(env::CONCURRENT_COMPILES)
+                    .combine(workspace.and_then(|workspace| workspace.globals.concurrent_compiles))
+                    .map(NonZeroUsize::get)
+                    .unwrap_or_else(Concurrency::threads),
+                prefetches: env(env::CONCURRENT_PREFETCHES)
+                    .combine(
+                        workspace.and_then(|workspace| workspace.globals.concurrent_prefetches),
+                    )
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_PREFETCHES),
             },
             show_settings: args.show_settings,
+            show_settings_format: args.show_settings_format,
             preview: Preview::from_args(
                 flag(args.preview, args.no_preview, "preview")
                     .combine(workspace.and_then(|workspace| workspace.globals.preview))
@@ -139,15 +177,138 @@ impl GlobalSettings {
             .map(PythonDownloads::from)
             .combine(env(env::UV_PYTHON_DOWNLOADS))
             .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
-            .unwrap_or_default(),
+            .unwrap_or(if network_settings.metered {
+                // On a metered connection, defer Python downloads to an explicit
+                // `uv python install`.
+                PythonDownloads::Manual
+            } else {
+                PythonDownloads::default()
+            }),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            quarantine: args.quarantine.unwrap_or_default(),
+            locked_timeout: args.locked_timeout.map(Duration::from_secs),
+            plugins: workspace
+                .and_then(|workspace| workspace.plugins.clone())
+                .unwrap_or_default(),
+            theme: Theme::from_options(
+                workspace
+                    .and_then(|workspace| workspace.terminal.clone())
+                    .unwrap_or_default(),
+            ),
         }
     }
 }
 
+impl GlobalSettings {
+    /// Create a [`GlobalSettingsBuilder`], to construct [`GlobalSettings`] programmatically rather
+    /// than from command-line arguments.
+    pub(crate) fn builder() -> GlobalSettingsBuilder {
+        GlobalSettingsBuilder::default()
+    }
+}
+
+/// A builder for [`GlobalSettings`].
+///
+/// Any setting that isn't provided explicitly is read from the persistent configuration passed to
+/// [`GlobalSettingsBuilder::build`], then from the environment, as for a command-line invocation.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GlobalSettingsBuilder {
+    offline: Option<bool>,
+    native_tls: Option<bool>,
+    preview: Option<bool>,
+    python_preference: Option<PythonPreference>,
+    python_downloads: Option<PythonDownloads>,
+    no_progress: bool,
+}
+
+impl GlobalSettingsBuilder {
+    /// Disable network access.
+    #[must_use]
+    pub(crate) fn offline(mut self, offline: bool) -> Self {
+        self.offline = Some(offline);
+        self
+    }
+
+    /// Load TLS certificates from the platform's native certificate store.
+    #[must_use]
+    pub(crate) fn native_tls(mut self, native_tls: bool) -> Self {
+        self.native_tls = Some(native_tls);
+        self
+    }
+
+    /// Enable preview features.
+    #[must_use]
+    pub(crate) fn preview(mut self, preview: bool) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+
+    /// Set the preference for managed and system Python installations.
+    #[must_use]
+    pub(crate) fn python_preference(mut self, python_preference: PythonPreference) -> Self {
+        self.python_preference = Some(python_preference);
+        self
+    }
+
+    /// Set whether Python installations can be downloaded automatically.
+    #[must_use]
+    pub(crate) fn python_downloads(mut self, python_downloads: PythonDownloads) -> Self {
+        self.python_downloads = Some(python_downloads);
+        self
+    }
+
+    /// Hide all progress outputs.
+    #[must_use]
+    pub(crate) fn no_progress(mut self, no_progress: bool) -> Self {
+        self.no_progress = no_progress;
+        self
+    }
+
+    /// Build the [`GlobalSettings`], falling back to the given persistent configuration, if any.
+    pub(crate) fn build(self, filesystem: Option<&FilesystemOptions>) -> GlobalSettings {
+        let args = GlobalArgs {
+            python_preference: self.python_preference,
+            managed_python: false,
+            no_managed_python: false,
+            allow_python_downloads: false,
+            no_python_downloads: false,
+            python_fetch: None,
+            quiet: 0,
+            verbose: 0,
+            no_color: false,
+            color: None,
+            native_tls: self.native_tls == Some(true),
+            no_native_tls: self.native_tls == Some(false),
+            offline: self.offline == Some(true),
+            no_offline: self.offline == Some(false),
+            allow_insecure_host: None,
+            limit_rate: None,
+            metered: false,
+            no_metered: false,
+            preview: self.preview == Some(true),
+            no_preview: self.preview == Some(false),
+            preview_features: Vec::new(),
+            isolated: false,
+            show_settings: false,
+            show_settings_format: ShowSettingsFormat::default(),
+            no_progress: self.no_progress,
+            no_installer_metadata: false,
+            quarantine: None,
+            locked_timeout: None,
+            directory: None,
+            project: None,
+        };
+        let mut settings = GlobalSettings::resolve(&args, filesystem);
+        if let Some(python_downloads) = self.python_downloads {
+            settings.python_downloads = python_downloads;
+        }
+        settings
+    }
+}
+
 fn resolve_python_preference(
     args: &GlobalArgs,
     workspace: Option<&FilesystemOptions>,
@@ -164,11 +325,16 @@ fn resolve_python_preference(
 }
 
 /// The resolved network settings to use for any invocation of the CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct NetworkSettings {
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) limit_rate: Option<RateLimit>,
+    pub(crate) metered: bool,
+    pub(crate) mirror_manifest: Option<PathBuf>,
 }
 
 impl NetworkSettings {
@@ -201,24 +367,42 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let metered = flag(args.metered, args.no_metered, "metered").unwrap_or(false);
+        let mirror_manifest = std::env::var_os(EnvVars::UV_MIRROR_MANIFEST)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| workspace.and_then(|workspace| workspace.globals.mirror_manifest.clone()));
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
+            limit_rate: args.limit_rate,
+            metered,
+            mirror_manifest,
         }
     }
 }
 
 /// The resolved cache settings to use for any invocation of the CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) index_policy: IndexCachePolicy,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+        let CacheOptions {
+            index_ttl,
+            stale_while_revalidate,
+        } = workspace
+            .and_then(|workspace| workspace.cache.clone())
+            .unwrap_or_default();
+
         Self {
             no_cache: args.no_cache
                 || workspace
@@ -227,12 +411,16 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            index_policy: IndexCachePolicy {
+                ttl: index_ttl.map(Duration::from_secs),
+                stale_while_revalidate: stale_while_revalidate.unwrap_or(false),
+            },
         }
     }
 }
 
 /// The resolved settings to use for a `init` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct InitSettings {
     pub(crate) path: Option<PathBuf>,
     pub(crate) name: Option<PackageName>,
@@ -249,6 +437,9 @@ pub(crate) struct InitSettings {
     pub(crate) no_workspace: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) template: Option<String>,
+    pub(crate) lock: bool,
+    pub(crate) settings: ResolverSettings,
 }
 
 impl InitSettings {
@@ -275,6 +466,8 @@ impl InitSettings {
             pin_python,
             no_workspace,
             python,
+            template,
+            no_lock,
             ..
         } = args;
 
@@ -286,6 +479,36 @@ impl InitSettings {
             (_, _, _) => unreachable!("`app`, `lib`, and `script` are mutually exclusive"),
         };
 
+        // Expand the built-in templates into the equivalent project kind and build backend.
+        let lock = template.is_some() && !no_lock;
+        let (kind, package, build_backend) = match &template {
+            Some(InitTemplate::Library) => (
+                InitKind::Project(InitProjectKind::Library),
+                package,
+                build_backend,
+            ),
+            Some(InitTemplate::Application) => (
+                InitKind::Project(InitProjectKind::Application),
+                package,
+                build_backend,
+            ),
+            Some(InitTemplate::Cli) => (
+                InitKind::Project(InitProjectKind::Application),
+                true,
+                build_backend,
+            ),
+            Some(InitTemplate::Maturin) => (
+                InitKind::Project(InitProjectKind::Library),
+                package,
+                Some(ProjectBuildBackend::Maturin),
+            ),
+            Some(InitTemplate::Git(_)) | None => (kind, package, build_backend),
+        };
+        let template = match template {
+            Some(InitTemplate::Git(url)) => Some(url),
+            _ => None,
+        };
+
         let package = flag(
             package || build_backend.is_some(),
             no_package || r#virtual,
@@ -294,6 +517,7 @@ impl InitSettings {
         .unwrap_or(kind.packaged_by_default());
 
         let install_mirrors = filesystem
+            .as_ref()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
@@ -315,16 +539,23 @@ impl InitSettings {
             no_workspace,
             python: python.and_then(Maybe::into_option),
             install_mirrors,
+            template,
+            lock,
+            settings: ResolverSettings::combine(ResolverOptions::default(), filesystem),
         }
     }
 }
 
 /// The resolved settings to use for a `run` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct RunSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) extras: ExtrasSpecification,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: Option<EditableMode>,
     pub(crate) modifications: Modifications,
@@ -332,21 +563,36 @@ pub(crate) struct RunSettings {
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) isolated: bool,
+    pub(crate) keep: bool,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) each: Option<RunEach>,
+    pub(crate) profile: Option<String>,
+    pub(crate) env: Option<String>,
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: EnvFile,
     pub(crate) max_recursion_depth: u32,
 }
 
+/// The workspace members to run a command in, with `uv run --each`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct RunEach {
+    /// The members to run the command in, or all members if empty.
+    pub(crate) packages: Vec<PackageName>,
+    /// Only run the command in the members affected by changes since this Git revision.
+    pub(crate) affected: Option<String>,
+}
+
 impl RunSettings {
     // Default value for UV_RUN_MAX_RECURSION_DEPTH if unset. This is large
     // enough that it's unlikely a user actually needs this recursion depth,
@@ -382,16 +628,21 @@ impl RunSettings {
             with_editable,
             with_requirements,
             isolated,
+            keep,
             active,
             no_active,
             no_sync,
             locked,
             frozen,
+            profile,
+            env,
             installer,
             build,
             refresh,
             all_packages,
-            package,
+            mut package,
+            each,
+            affected,
             no_project,
             python,
             python_platform,
@@ -401,6 +652,23 @@ impl RunSettings {
             max_recursion_depth,
         } = args;
 
+        // With `--each`, `--package` selects the members to run the command in.
+        let (all_packages, package, each) = if each {
+            let each = RunEach {
+                packages: package,
+                affected,
+            };
+            (true, None, Some(each))
+        } else {
+            if package.len() > 1 {
+                warn_user_once!(
+                    "`--package` can only be provided multiple times alongside `--each`; using `{}`",
+                    package.last().unwrap()
+                );
+            }
+            (all_packages, package.pop(), None)
+        };
+
         let install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -447,9 +715,13 @@ impl RunSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             isolated,
+            keep,
             show_resolution,
             all_packages,
             package,
+            each,
+            profile,
+            env,
             no_project,
             no_sync,
             active: flag(active, no_active, "active"),
@@ -468,8 +740,10 @@ impl RunSettings {
 }
 
 /// The resolved settings to use for a `tool run` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolRunSettings {
+    #[serde(serialize_with = "show_settings::serialize_debug_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) command: Option<ExternalCommand>,
     pub(crate) from: Option<String>,
     pub(crate) with: Vec<String>,
@@ -483,7 +757,11 @@ pub(crate) struct ToolRunSettings {
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
@@ -603,7 +881,7 @@ impl ToolRunSettings {
 }
 
 /// The resolved settings to use for a `tool install` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolInstallSettings {
     pub(crate) package: String,
     pub(crate) from: Option<String>,
@@ -616,7 +894,11 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
@@ -707,13 +989,18 @@ impl ToolInstallSettings {
 }
 
 /// The resolved settings to use for a `tool upgrade` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolUpgradeSettings {
     pub(crate) names: Vec<String>,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) relax_with: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) args: ResolverInstallerOptions,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) filesystem: ResolverInstallerOptions,
 }
 impl ToolUpgradeSettings {
@@ -724,6 +1011,7 @@ impl ToolUpgradeSettings {
             name,
             python,
             python_platform,
+            relax_with,
             upgrade,
             upgrade_package,
             index_args,
@@ -773,6 +1061,7 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            allow_insecure_md5_hashes: false,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -802,6 +1091,7 @@ impl ToolUpgradeSettings {
             names: if all { vec![] } else { name },
             python: python.and_then(Maybe::into_option),
             python_platform,
+            relax_with,
             args,
             filesystem: top_level,
             install_mirrors,
@@ -810,7 +1100,7 @@ impl ToolUpgradeSettings {
 }
 
 /// The resolved settings to use for a `tool list` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolListSettings {
     pub(crate) show_paths: bool,
     pub(crate) show_version_specifiers: bool,
@@ -840,8 +1130,44 @@ impl ToolListSettings {
     }
 }
 
+/// The resolved settings to use for a `tool show` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct ToolShowSettings {
+    pub(crate) name: PackageName,
+}
+
+impl ToolShowSettings {
+    /// Resolve the [`ToolShowSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolShowArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolShowArgs {
+            name,
+            python_preference: _,
+            no_python_downloads: _,
+        } = args;
+
+        Self { name }
+    }
+}
+
+/// The resolved settings to use for a `tool check` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct ToolCheckSettings {
+    pub(crate) repair: bool,
+}
+
+impl ToolCheckSettings {
+    /// Resolve the [`ToolCheckSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolCheckArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolCheckArgs { repair } = args;
+
+        Self { repair }
+    }
+}
+
 /// The resolved settings to use for a `tool uninstall` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolUninstallSettings {
     pub(crate) name: Vec<PackageName>,
 }
@@ -859,7 +1185,7 @@ impl ToolUninstallSettings {
 }
 
 /// The resolved settings to use for a `tool dir` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ToolDirSettings {
     pub(crate) bin: bool,
 }
@@ -874,7 +1200,8 @@ impl ToolDirSettings {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum PythonListKinds {
     #[default]
     Default,
@@ -885,13 +1212,14 @@ pub(crate) enum PythonListKinds {
 }
 
 /// The resolved settings to use for a `tool run` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonListSettings {
     pub(crate) request: Option<String>,
     pub(crate) kinds: PythonListKinds,
     pub(crate) all_platforms: bool,
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
+    pub(crate) all_sources: bool,
     pub(crate) show_urls: bool,
     pub(crate) output_format: PythonListFormat,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -906,6 +1234,7 @@ impl PythonListSettings {
             all_versions,
             all_platforms,
             all_arches,
+            all_sources,
             only_installed,
             only_downloads,
             show_urls,
@@ -936,6 +1265,7 @@ impl PythonListSettings {
             all_platforms,
             all_arches,
             all_versions,
+            all_sources,
             show_urls,
             output_format,
             python_downloads_json_url,
@@ -944,7 +1274,7 @@ impl PythonListSettings {
 }
 
 /// The resolved settings to use for a `python dir` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonDirSettings {
     pub(crate) bin: bool,
 }
@@ -960,7 +1290,7 @@ impl PythonDirSettings {
 }
 
 /// The resolved settings to use for a `python install` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonInstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
@@ -972,6 +1302,8 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
+    pub(crate) ensurepip: bool,
+    pub(crate) check_libraries: bool,
 }
 
 impl PythonInstallSettings {
@@ -1009,6 +1341,8 @@ impl PythonInstallSettings {
             pypy_mirror: _,
             python_downloads_json_url: _,
             default,
+            ensurepip,
+            check_libraries,
         } = args;
 
         Self {
@@ -1023,13 +1357,15 @@ impl PythonInstallSettings {
             pypy_install_mirror: pypy_mirror,
             python_downloads_json_url,
             default,
+            ensurepip,
+            check_libraries,
         }
     }
 }
 
 /// The resolved settings to use for a `python upgrade` invocation.
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonUpgradeSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
@@ -1041,6 +1377,8 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) ensurepip: bool,
+    pub(crate) check_libraries: bool,
 }
 
 impl PythonUpgradeSettings {
@@ -1064,6 +1402,8 @@ impl PythonUpgradeSettings {
         let default = false;
         let bin = None;
         let registry = None;
+        let ensurepip = false;
+        let check_libraries = false;
 
         let PythonUpgradeArgs {
             install_dir,
@@ -1085,12 +1425,14 @@ impl PythonUpgradeSettings {
             python_downloads_json_url,
             default,
             bin,
+            ensurepip,
+            check_libraries,
         }
     }
 }
 
 /// The resolved settings to use for a `python uninstall` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
@@ -1119,10 +1461,13 @@ impl PythonUninstallSettings {
 }
 
 /// The resolved settings to use for a `python find` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
+    pub(crate) base: bool,
+    pub(crate) resolve_symlinks: bool,
+    pub(crate) output_format: PythonFindFormat,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
 }
@@ -1134,15 +1479,21 @@ impl PythonFindSettings {
         let PythonFindArgs {
             request,
             show_version,
+            base,
+            resolve_symlinks,
             no_project,
             system,
             no_system,
             script: _,
+            output_format,
         } = args;
 
         Self {
             request,
             show_version,
+            base,
+            resolve_symlinks,
+            output_format,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
         }
@@ -1150,7 +1501,7 @@ impl PythonFindSettings {
 }
 
 /// The resolved settings to use for a `python pin` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PythonPinSettings {
     pub(crate) request: Option<String>,
     pub(crate) resolved: bool,
@@ -1188,25 +1539,69 @@ impl PythonPinSettings {
     }
 }
 
+/// The resolved settings to use for an `env create-for` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct EnvCreateForSettings {
+    pub(crate) path: PathBuf,
+    pub(crate) python: Option<String>,
+    pub(crate) system_site_packages: bool,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl EnvCreateForSettings {
+    /// Resolve the [`EnvCreateForSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: EnvCreateForArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let EnvCreateForArgs {
+            path,
+            python,
+            system_site_packages,
+        } = args;
+
+        let install_mirrors = filesystem
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            python,
+            system_site_packages,
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `sync` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct SyncSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) verify_lock_signature: bool,
+    pub(crate) site_packages_dir: Option<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) allow_script_conflicts: bool,
+    pub(crate) exclude_source: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) extras: ExtrasSpecification,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: Option<EditableMode>,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) profile: Option<String>,
+    pub(crate) env: Option<String>,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) output_format: SyncFormat,
@@ -1237,17 +1632,25 @@ impl SyncSettings {
             no_install_workspace,
             no_install_local,
             no_install_package,
+            no_reinstall_project,
             locked,
             frozen,
+            verify_lock_signature,
+            site_packages_dir,
             active,
             no_active,
             dry_run,
+            allow_script_conflicts,
+            exclude_source,
             installer,
             build,
             refresh,
             all_packages,
             package,
             script,
+            profile,
+            as_of,
+            env,
             python,
             python_platform,
             check,
@@ -1259,11 +1662,17 @@ impl SyncSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
-        let settings = ResolverInstallerSettings::combine(
+        let mut settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
             filesystem,
         );
 
+        // Resolve as of the given date, overriding any configured global timestamp.
+        if let Some(as_of) = as_of {
+            settings.resolver.exclude_newer.global = Some(as_of);
+            settings.resolver.exclude_newer.as_of = true;
+        }
+
         let check = flag(check, no_check, "check").unwrap_or_default();
         let dry_run = if check {
             DryRun::Check
@@ -1273,9 +1682,15 @@ impl SyncSettings {
 
         Self {
             output_format,
-            locked,
+            // Verifying the lockfile implies `--locked`, such that the verified lockfile is used
+            // as-is (and never rewritten, or re-signed).
+            locked: locked || (verify_lock_signature && !frozen),
             frozen,
+            verify_lock_signature,
+            site_packages_dir,
             dry_run,
+            allow_script_conflicts,
+            exclude_source,
             script,
             active: flag(active, no_active, "active"),
             extras: ExtrasSpecification::from_args(
@@ -1303,7 +1718,8 @@ impl SyncSettings {
                 no_install_workspace,
                 no_install_local,
                 no_install_package,
-            ),
+            )
+            .with_no_reinstall_project(no_reinstall_project),
             modifications: if flag(exact, inexact, "inexact").unwrap_or(true) {
                 Modifications::Exact
             } else {
@@ -1311,6 +1727,8 @@ impl SyncSettings {
             },
             all_packages,
             package,
+            profile,
+            env,
             python: python.and_then(Maybe::into_option),
             python_platform,
             refresh: Refresh::from(refresh),
@@ -1322,14 +1740,20 @@ impl SyncSettings {
 
 /// The resolved settings to use for a `lock` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) integrity: bool,
+    pub(crate) resolve_merge_conflicts: bool,
+    pub(crate) merge: Option<[PathBuf; 3]>,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) profile: Option<String>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
 }
@@ -1342,7 +1766,12 @@ impl LockSettings {
             check,
             check_exists,
             dry_run,
+            resolve_merge_conflicts,
+            merge,
             script,
+            profile,
+            as_of,
+            integrity,
             resolver,
             build,
             refresh,
@@ -1354,22 +1783,155 @@ impl LockSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
+        let mut settings = ResolverSettings::combine(resolver_options(resolver, build), filesystem);
+
+        // Resolve as of the given date, overriding any configured global timestamp.
+        if let Some(as_of) = as_of {
+            settings.exclude_newer.global = Some(as_of);
+            settings.exclude_newer.as_of = true;
+        }
+
         Self {
             locked: check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
+            integrity,
+            resolve_merge_conflicts,
+            merge: merge.and_then(|paths| <[PathBuf; 3]>::try_from(paths).ok()),
             script,
+            profile,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            settings,
             install_mirrors,
         }
     }
 }
 
+impl LockSettings {
+    /// Create a [`LockSettingsBuilder`], to construct [`LockSettings`] programmatically rather
+    /// than from command-line arguments.
+    pub(crate) fn builder() -> LockSettingsBuilder {
+        LockSettingsBuilder::default()
+    }
+}
+
+/// A builder for [`LockSettings`].
+///
+/// Resolver options (like the index URL or the resolution strategy) are read from the
+/// persistent configuration passed to [`LockSettingsBuilder::build`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LockSettingsBuilder {
+    locked: bool,
+    frozen: bool,
+    profile: Option<String>,
+    python: Option<String>,
+}
+
+impl LockSettingsBuilder {
+    /// Assert that the lockfile will remain unchanged, as in `uv lock --check`.
+    #[must_use]
+    pub(crate) fn locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
+    /// Assert that a lockfile exists, without checking that it's up-to-date, as in
+    /// `uv lock --check-exists`.
+    #[must_use]
+    pub(crate) fn frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Resolve with the given resolution profile.
+    #[must_use]
+    pub(crate) fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Set the Python interpreter to use during resolution, as a request (e.g., `3.12`).
+    #[must_use]
+    pub(crate) fn python(mut self, python: impl Into<String>) -> Self {
+        self.python = Some(python.into());
+        self
+    }
+
+    /// Build the [`LockSettings`], falling back to the given persistent configuration, if any.
+    pub(crate) fn build(self, filesystem: Option<FilesystemOptions>) -> LockSettings {
+        let install_mirrors = filesystem
+            .as_ref()
+            .map(|configuration| configuration.install_mirrors.clone())
+            .unwrap_or_default();
+
+        LockSettings {
+            locked: self.locked,
+            frozen: self.frozen,
+            dry_run: DryRun::Disabled,
+            integrity: false,
+            resolve_merge_conflicts: false,
+            merge: None,
+            script: None,
+            profile: self.profile,
+            python: self.python,
+            install_mirrors,
+            refresh: Refresh::from_args(None, Vec::new(), Vec::new()),
+            settings: ResolverSettings::combine(ResolverOptions::default(), filesystem),
+        }
+    }
+}
+
+/// The resolved settings to use for a `watch` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct WatchSettings {
+    pub(crate) sync: bool,
+    pub(crate) debounce: Duration,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl WatchSettings {
+    /// Resolve the [`WatchSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: WatchArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let WatchArgs {
+            sync,
+            debounce,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        let settings = ResolverInstallerSettings::combine(
+            resolver_installer_options(installer, build),
+            filesystem,
+        );
+
+        Self {
+            sync,
+            debounce: Duration::from_millis(debounce),
+            python: python.and_then(Maybe::into_option),
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings,
+        }
+    }
+}
+
 /// The resolved settings to use for a `add` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct AddSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
@@ -1378,7 +1940,11 @@ pub(crate) struct AddSettings {
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
+    #[serde(serialize_with = "show_settings::serialize_debug_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) marker: Option<MarkerTree>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) dependency_type: DependencyType,
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
@@ -1395,6 +1961,8 @@ pub(crate) struct AddSettings {
     pub(crate) no_install_workspace: bool,
     pub(crate) no_install_local: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) indexes: Vec<Index>,
     pub(crate) settings: ResolverInstallerSettings,
@@ -1555,18 +2123,23 @@ impl AddSettings {
 
 /// The resolved settings to use for a `remove` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct RemoveSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) dependency_type: DependencyType,
+    pub(crate) marker_only: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -1580,6 +2153,7 @@ impl RemoveSettings {
             optional,
             packages,
             group,
+            marker_only,
             no_sync,
             locked,
             frozen,
@@ -1620,6 +2194,7 @@ impl RemoveSettings {
             no_sync,
             packages,
             dependency_type,
+            marker_only,
             package,
             script,
             python: python.and_then(Maybe::into_option),
@@ -1635,7 +2210,7 @@ impl RemoveSettings {
 
 /// The resolved settings to use for a `version` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct VersionSettings {
     pub(crate) value: Option<String>,
     pub(crate) bump: Vec<VersionBump>,
@@ -1649,6 +2224,8 @@ pub(crate) struct VersionSettings {
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -1703,8 +2280,10 @@ impl VersionSettings {
 }
 
 /// The resolved settings to use for a `tree` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct TreeSettings {
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) groups: DependencyGroups,
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
@@ -1718,6 +2297,8 @@ pub(crate) struct TreeSettings {
     pub(crate) show_sizes: bool,
     #[allow(dead_code)]
     pub(crate) script: Option<PathBuf>,
+    #[serde(serialize_with = "show_settings::serialize_display_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) python: Option<String>,
@@ -1786,13 +2367,17 @@ impl TreeSettings {
 
 /// The resolved settings to use for an `export` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct ExportSettings {
     pub(crate) format: Option<ExportFormat>,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) prune: Vec<PackageName>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) extras: ExtrasSpecification,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: Option<EditableMode>,
     pub(crate) hashes: bool,
@@ -1805,6 +2390,8 @@ pub(crate) struct ExportSettings {
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
 }
@@ -1902,8 +2489,112 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `bundle` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct BundleSettings {
+    pub(crate) output_dir: Option<PathBuf>,
+    pub(crate) archive: bool,
+    pub(crate) package: Option<PackageName>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) extras: ExtrasSpecification,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) groups: DependencyGroups,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl BundleSettings {
+    /// Resolve the [`BundleSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: BundleArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleArgs {
+            output_dir,
+            archive,
+            package,
+            extra,
+            all_extras,
+            group,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            output_dir,
+            archive,
+            package,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                vec![],
+                false,
+                vec![],
+                all_extras,
+            ),
+            groups: DependencyGroups::from_args(
+                false,
+                false,
+                false,
+                group,
+                vec![],
+                false,
+                vec![],
+                false,
+            ),
+            locked,
+            frozen,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `diff` invocation.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub(crate) struct DiffSettings {
+    pub(crate) old: PathBuf,
+    pub(crate) new: PathBuf,
+    pub(crate) output_format: DiffFormat,
+}
+
+impl DiffSettings {
+    /// Resolve the [`DiffSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: DiffArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let DiffArgs {
+            old,
+            new,
+            output_format,
+        } = args;
+
+        Self {
+            old,
+            new,
+            output_format,
+        }
+    }
+}
+
 /// The resolved settings to use for a `format` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct FormatSettings {
     pub(crate) check: bool,
     pub(crate) diff: bool,
@@ -1933,18 +2624,91 @@ impl FormatSettings {
     }
 }
 
+/// The constraints, overrides, and build constraints declared in the persistent configuration.
+#[derive(Debug, Clone, Default)]
+struct WorkspaceRequirements {
+    constraints: Vec<Requirement>,
+    overrides: Vec<Requirement>,
+    scoped_overrides: Vec<(PackageName, Requirement)>,
+    build_constraints: Vec<Requirement>,
+}
+
+impl WorkspaceRequirements {
+    /// Read the [`WorkspaceRequirements`] from the filesystem configuration, if any.
+    fn from_filesystem(filesystem: Option<&FilesystemOptions>) -> Self {
+        let Some(configuration) = filesystem else {
+            return Self::default();
+        };
+
+        let constraints = configuration
+            .constraint_dependencies
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|requirement| {
+                Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+            })
+            .collect();
+
+        let (overrides, scoped_overrides) = configuration
+            .override_dependencies
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .partition_map(|dependency| {
+                let requirement = Requirement::from(
+                    dependency
+                        .requirement
+                        .with_origin(RequirementOrigin::Workspace),
+                );
+                match dependency.when {
+                    None => Either::Left(requirement),
+                    Some(dependent) => Either::Right((dependent, requirement)),
+                }
+            });
+
+        let build_constraints = configuration
+            .build_constraint_dependencies
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|requirement| {
+                Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+            })
+            .collect();
+
+        Self {
+            constraints,
+            overrides,
+            scoped_overrides,
+            build_constraints,
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipCompileSettings {
-    pub(crate) format: Option<ExportFormat>,
+    pub(crate) format: Option<PipCompileFormat>,
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) constraints_from_workspace: Vec<Requirement>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) overrides_from_workspace: Vec<Requirement>,
+    #[schemars(with = "serde_json::Value")]
+    pub(crate) scoped_overrides_from_workspace: Vec<(PackageName, Requirement)>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) verify: bool,
+    pub(crate) reuse_hashes: bool,
+    pub(crate) marker_snapshot: Option<PathBuf>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1988,6 +2752,7 @@ impl PipCompileSettings {
             only_binary,
             python_version,
             python_platform,
+            marker_snapshot,
             universal,
             no_universal,
             no_emit_package,
@@ -2001,57 +2766,116 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_license,
+            no_emit_license,
+            emit_homepage,
+            no_emit_homepage,
+            emit_latest_version,
+            no_emit_latest_version,
+            emit_summary,
+            no_emit_summary,
+            emit_fork_annotation,
+            no_emit_fork_annotation,
+            emit_provenance,
+            no_emit_provenance,
+            verify,
+            as_of,
             torch_backend,
-            compat_args: _,
+            compat_args,
         } = args;
 
-        let constraints_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .constraint_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
+        let WorkspaceRequirements {
+            constraints: constraints_from_workspace,
+            overrides: overrides_from_workspace,
+            scoped_overrides: scoped_overrides_from_workspace,
+            build_constraints: build_constraints_from_workspace,
+        } = WorkspaceRequirements::from_filesystem(filesystem.as_ref());
 
-        let overrides_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .override_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
+        let environments = if let Some(configuration) = &filesystem {
+            configuration.environments.clone().unwrap_or_default()
         } else {
-            Vec::new()
+            SupportedEnvironments::default()
         };
 
-        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .build_constraint_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
+        // Omit any packages marked as "unsafe" via the `pip-compile` compatibility options.
+        let unsafe_packages = compat_args.unsafe_packages();
+        let no_emit_package = if unsafe_packages.is_empty() {
+            no_emit_package
         } else {
-            Vec::new()
+            Some(
+                no_emit_package
+                    .into_iter()
+                    .flatten()
+                    .chain(unsafe_packages)
+                    .collect(),
+            )
         };
 
-        let environments = if let Some(configuration) = &filesystem {
-            configuration.environments.clone().unwrap_or_default()
-        } else {
-            SupportedEnvironments::default()
-        };
+        let mut settings = PipSettings::combine(
+            PipOptions {
+                python: python.and_then(Maybe::into_option),
+                system: flag(system, no_system, "system"),
+                no_build: flag(no_build, build, "build"),
+                no_binary,
+                only_binary,
+                extra,
+                all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                no_deps: flag(no_deps, deps, "deps"),
+                group: Some(group),
+                output_file,
+                no_strip_extras: flag(no_strip_extras, strip_extras, "strip-extras"),
+                no_strip_markers: flag(no_strip_markers, strip_markers, "strip-markers"),
+                no_annotate: flag(no_annotate, annotate, "annotate"),
+                no_header: flag(no_header, header, "header"),
+                custom_compile_command,
+                generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
+                python_version,
+                python_platform,
+                universal: flag(universal, no_universal, "universal"),
+                no_emit_package,
+                emit_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url"),
+                emit_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links"),
+                emit_build_options: flag(
+                    emit_build_options,
+                    no_emit_build_options,
+                    "emit-build-options",
+                ),
+                emit_marker_expression: flag(
+                    emit_marker_expression,
+                    no_emit_marker_expression,
+                    "emit-marker-expression",
+                ),
+                emit_index_annotation: flag(
+                    emit_index_annotation,
+                    no_emit_index_annotation,
+                    "emit-index-annotation",
+                ),
+                emit_license: flag(emit_license, no_emit_license, "emit-license"),
+                emit_homepage: flag(emit_homepage, no_emit_homepage, "emit-homepage"),
+                emit_latest_version: flag(
+                    emit_latest_version,
+                    no_emit_latest_version,
+                    "emit-latest-version",
+                ),
+                emit_summary: flag(emit_summary, no_emit_summary, "emit-summary"),
+                emit_fork_annotation: flag(
+                    emit_fork_annotation,
+                    no_emit_fork_annotation,
+                    "emit-fork-annotation",
+                ),
+                emit_provenance: flag(emit_provenance, no_emit_provenance, "emit-provenance"),
+                annotation_style,
+                torch_backend,
+                ..PipOptions::from(resolver)
+            },
+            filesystem,
+        );
+
+        // Resolve as of the given date, overriding any configured global timestamp.
+        if let Some(as_of) = as_of {
+            settings.exclude_newer.global = Some(as_of);
+            settings.exclude_newer.as_of = true;
+        }
 
         Self {
             format,
@@ -2070,65 +2894,122 @@ impl PipCompileSettings {
                 .collect(),
             constraints_from_workspace,
             overrides_from_workspace,
+            scoped_overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            verify,
+            reuse_hashes: compat_args.reuse_hashes(),
+            marker_snapshot,
             refresh: Refresh::from(refresh),
-            settings: PipSettings::combine(
-                PipOptions {
-                    python: python.and_then(Maybe::into_option),
-                    system: flag(system, no_system, "system"),
-                    no_build: flag(no_build, build, "build"),
-                    no_binary,
-                    only_binary,
-                    extra,
-                    all_extras: flag(all_extras, no_all_extras, "all-extras"),
-                    no_deps: flag(no_deps, deps, "deps"),
-                    group: Some(group),
-                    output_file,
-                    no_strip_extras: flag(no_strip_extras, strip_extras, "strip-extras"),
-                    no_strip_markers: flag(no_strip_markers, strip_markers, "strip-markers"),
-                    no_annotate: flag(no_annotate, annotate, "annotate"),
-                    no_header: flag(no_header, header, "header"),
-                    custom_compile_command,
-                    generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
-                    python_version,
-                    python_platform,
-                    universal: flag(universal, no_universal, "universal"),
-                    no_emit_package,
-                    emit_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url"),
-                    emit_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links"),
-                    emit_build_options: flag(
-                        emit_build_options,
-                        no_emit_build_options,
-                        "emit-build-options",
-                    ),
-                    emit_marker_expression: flag(
-                        emit_marker_expression,
-                        no_emit_marker_expression,
-                        "emit-marker-expression",
-                    ),
-                    emit_index_annotation: flag(
-                        emit_index_annotation,
-                        no_emit_index_annotation,
-                        "emit-index-annotation",
-                    ),
-                    annotation_style,
-                    torch_backend,
-                    ..PipOptions::from(resolver)
-                },
-                filesystem,
-            ),
+            settings,
+        }
+    }
+}
+
+impl PipCompileSettings {
+    /// Create a [`PipCompileSettingsBuilder`], to construct [`PipCompileSettings`]
+    /// programmatically rather than from command-line arguments.
+    pub(crate) fn builder() -> PipCompileSettingsBuilder {
+        PipCompileSettingsBuilder::default()
+    }
+}
+
+/// A builder for [`PipCompileSettings`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PipCompileSettingsBuilder {
+    src_file: Vec<PathBuf>,
+    constraints: Vec<PathBuf>,
+    overrides: Vec<PathBuf>,
+    build_constraints: Vec<PathBuf>,
+    options: PipOptions,
+}
+
+impl PipCompileSettingsBuilder {
+    /// Add files from which to read the requirements to resolve, like `requirements.in` or
+    /// `pyproject.toml`.
+    #[must_use]
+    pub(crate) fn requirements(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.src_file.extend(paths);
+        self
+    }
+
+    /// Add files from which to read constraints.
+    #[must_use]
+    pub(crate) fn constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.constraints.extend(paths);
+        self
+    }
+
+    /// Add files from which to read overrides.
+    #[must_use]
+    pub(crate) fn overrides(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.overrides.extend(paths);
+        self
+    }
+
+    /// Add files from which to read build constraints.
+    #[must_use]
+    pub(crate) fn build_constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.build_constraints.extend(paths);
+        self
+    }
+
+    /// Set the `pip` options, as in the `[tool.uv.pip]` section of a configuration file.
+    ///
+    /// These take precedence over the persistent configuration passed to
+    /// [`PipCompileSettingsBuilder::build`].
+    #[must_use]
+    pub(crate) fn options(mut self, options: PipOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Build the [`PipCompileSettings`], falling back to the given persistent configuration, if
+    /// any.
+    pub(crate) fn build(self, filesystem: Option<FilesystemOptions>) -> PipCompileSettings {
+        let WorkspaceRequirements {
+            constraints: constraints_from_workspace,
+            overrides: overrides_from_workspace,
+            scoped_overrides: scoped_overrides_from_workspace,
+            build_constraints: build_constraints_from_workspace,
+        } = WorkspaceRequirements::from_filesystem(filesystem.as_ref());
+        let environments = filesystem
+            .as_ref()
+            .and_then(|configuration| configuration.environments.clone())
+            .unwrap_or_default();
+
+        PipCompileSettings {
+            format: None,
+            src_file: self.src_file,
+            constraints: self.constraints,
+            overrides: self.overrides,
+            build_constraints: self.build_constraints,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            scoped_overrides_from_workspace,
+            build_constraints_from_workspace,
+            environments,
+            verify: false,
+            reuse_hashes: false,
+            marker_snapshot: None,
+            refresh: Refresh::from_args(None, Vec::new(), Vec::new()),
+            settings: PipSettings::combine(self.options, filesystem),
         }
     }
 }
 
 /// The resolved settings to use for a `pip sync` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) allow_script_conflicts: bool,
+    pub(crate) exclude_source: bool,
+    pub(crate) platform_override: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2165,13 +3046,20 @@ impl PipSyncSettings {
             only_binary,
             python_version,
             python_platform,
+            platform_tag,
             strict,
             no_strict,
             dry_run,
+            allow_script_conflicts,
+            exclude_source,
             torch_backend,
             compat_args: _,
         } = *args;
 
+        // Spoof the platform if requested, unless it's provided explicitly.
+        let platform_override = python_platform.is_none() && platform_tag.is_some();
+        let python_platform = python_platform.or(platform_tag);
+
         Self {
             src_file,
             constraints: constraints
@@ -2183,6 +3071,9 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            allow_script_conflicts,
+            exclude_source,
+            platform_override,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2221,7 +3112,7 @@ impl PipSyncSettings {
 }
 
 /// The resolved settings to use for a `pip install` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
@@ -2230,10 +3121,21 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) allow_script_conflicts: bool,
+    pub(crate) exclude_source: bool,
+    pub(crate) no_cache_download: bool,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) constraints_from_workspace: Vec<Requirement>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) overrides_from_workspace: Vec<Requirement>,
+    #[schemars(with = "serde_json::Value")]
+    pub(crate) scoped_overrides_from_workspace: Vec<(PackageName, Requirement)>,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) platform_override: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2273,56 +3175,29 @@ impl PipInstallSettings {
             only_binary,
             python_version,
             python_platform,
+            platform_tag,
             inexact,
             exact,
             strict,
             no_strict,
             dry_run,
+            allow_script_conflicts,
+            exclude_source,
+            no_cache_download,
             torch_backend,
             compat_args: _,
         } = args;
 
-        let constraints_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .constraint_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
+        let WorkspaceRequirements {
+            constraints: constraints_from_workspace,
+            overrides: overrides_from_workspace,
+            scoped_overrides: scoped_overrides_from_workspace,
+            build_constraints: build_constraints_from_workspace,
+        } = WorkspaceRequirements::from_filesystem(filesystem.as_ref());
 
-        let overrides_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .override_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
-
-        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
-            configuration
-                .build_constraint_dependencies
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|requirement| {
-                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
-                })
-                .collect()
-        } else {
-            Vec::new()
-        };
+        // Spoof the platform if requested, unless it's provided explicitly.
+        let platform_override = python_platform.is_none() && platform_tag.is_some();
+        let python_platform = python_platform.or(platform_tag);
 
         Self {
             package,
@@ -2341,14 +3216,19 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            allow_script_conflicts,
+            exclude_source,
+            no_cache_download,
             constraints_from_workspace,
             overrides_from_workspace,
+            scoped_overrides_from_workspace,
             build_constraints_from_workspace,
             modifications: if flag(exact, inexact, "inexact").unwrap_or(false) {
                 Modifications::Exact
             } else {
                 Modifications::Sufficient
             },
+            platform_override,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2382,8 +3262,123 @@ impl PipInstallSettings {
     }
 }
 
+impl PipInstallSettings {
+    /// Create a [`PipInstallSettingsBuilder`], to construct [`PipInstallSettings`]
+    /// programmatically rather than from command-line arguments.
+    pub(crate) fn builder() -> PipInstallSettingsBuilder {
+        PipInstallSettingsBuilder::default()
+    }
+}
+
+/// A builder for [`PipInstallSettings`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PipInstallSettingsBuilder {
+    package: Vec<String>,
+    requirements: Vec<PathBuf>,
+    editables: Vec<String>,
+    constraints: Vec<PathBuf>,
+    overrides: Vec<PathBuf>,
+    build_constraints: Vec<PathBuf>,
+    dry_run: bool,
+    exact: bool,
+}
+
+impl PipInstallSettingsBuilder {
+    /// Add packages to install, as PEP 508 requirements (e.g., `flask>=2`).
+    #[must_use]
+    pub(crate) fn packages(mut self, packages: impl IntoIterator<Item = String>) -> Self {
+        self.package.extend(packages);
+        self
+    }
+
+    /// Add files from which to read the requirements to install, like `requirements.txt`.
+    #[must_use]
+    pub(crate) fn requirements(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.requirements.extend(paths);
+        self
+    }
+
+    /// Add local directories to install in editable mode.
+    #[must_use]
+    pub(crate) fn editables(mut self, editables: impl IntoIterator<Item = String>) -> Self {
+        self.editables.extend(editables);
+        self
+    }
+
+    /// Add files from which to read constraints.
+    #[must_use]
+    pub(crate) fn constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.constraints.extend(paths);
+        self
+    }
+
+    /// Add files from which to read overrides.
+    #[must_use]
+    pub(crate) fn overrides(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.overrides.extend(paths);
+        self
+    }
+
+    /// Add files from which to read build constraints.
+    #[must_use]
+    pub(crate) fn build_constraints(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.build_constraints.extend(paths);
+        self
+    }
+
+    /// Perform a dry run, i.e., don't actually install anything.
+    #[must_use]
+    pub(crate) fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Remove any extraneous packages from the environment, as in `uv pip install --exact`.
+    #[must_use]
+    pub(crate) fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Build the [`PipInstallSettings`], falling back to the given persistent configuration, if
+    /// any.
+    pub(crate) fn build(self, filesystem: Option<FilesystemOptions>) -> PipInstallSettings {
+        let WorkspaceRequirements {
+            constraints: constraints_from_workspace,
+            overrides: overrides_from_workspace,
+            scoped_overrides: scoped_overrides_from_workspace,
+            build_constraints: build_constraints_from_workspace,
+        } = WorkspaceRequirements::from_filesystem(filesystem.as_ref());
+
+        PipInstallSettings {
+            package: self.package,
+            requirements: self.requirements,
+            editables: self.editables,
+            constraints: self.constraints,
+            overrides: self.overrides,
+            build_constraints: self.build_constraints,
+            dry_run: DryRun::from_args(self.dry_run),
+            allow_script_conflicts: false,
+            exclude_source: false,
+            no_cache_download: false,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            scoped_overrides_from_workspace,
+            build_constraints_from_workspace,
+            modifications: if self.exact {
+                Modifications::Exact
+            } else {
+                Modifications::Sufficient
+            },
+            platform_override: false,
+            refresh: Refresh::from_args(None, Vec::new(), Vec::new()),
+            settings: PipSettings::combine(PipOptions::default(), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
@@ -2434,9 +3429,10 @@ impl PipUninstallSettings {
 }
 
 /// The resolved settings to use for a `pip freeze` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
+    pub(crate) exclude: Vec<PackageName>,
     pub(crate) paths: Option<Vec<PathBuf>>,
     pub(crate) settings: PipSettings,
 }
@@ -2446,6 +3442,7 @@ impl PipFreezeSettings {
     pub(crate) fn resolve(args: PipFreezeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            exclude,
             strict,
             no_strict,
             python,
@@ -2457,6 +3454,7 @@ impl PipFreezeSettings {
 
         Self {
             exclude_editable,
+            exclude,
             paths,
             settings: PipSettings::combine(
                 PipOptions {
@@ -2472,12 +3470,15 @@ impl PipFreezeSettings {
 }
 
 /// The resolved settings to use for a `pip list` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipListSettings {
     pub(crate) editable: Option<bool>,
     pub(crate) exclude: Vec<PackageName>,
     pub(crate) format: ListFormat,
+    pub(crate) sort: ListSort,
     pub(crate) outdated: bool,
+    pub(crate) include_metadata: bool,
+    pub(crate) include_size: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2489,8 +3490,11 @@ impl PipListSettings {
             exclude_editable,
             exclude,
             format,
+            sort,
             outdated,
             no_outdated,
+            include_metadata,
+            include_size,
             strict,
             no_strict,
             fetch,
@@ -2504,7 +3508,10 @@ impl PipListSettings {
             editable: flag(editable, exclude_editable, "exclude-editable"),
             exclude,
             format,
+            sort,
             outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
+            include_metadata,
+            include_size,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2519,10 +3526,11 @@ impl PipListSettings {
 }
 
 /// The resolved settings to use for a `pip show` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) scripts: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2534,6 +3542,7 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            scripts,
             python,
             system,
             no_system,
@@ -2543,6 +3552,7 @@ impl PipShowSettings {
         Self {
             package,
             files,
+            scripts,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2557,9 +3567,10 @@ impl PipShowSettings {
 }
 
 /// The resolved settings to use for a `pip tree` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipTreeSettings {
     pub(crate) show_version_specifiers: bool,
+    pub(crate) format: TreeFormat,
     pub(crate) depth: u8,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
@@ -2574,6 +3585,7 @@ impl PipTreeSettings {
     pub(crate) fn resolve(args: PipTreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipTreeArgs {
             show_version_specifiers,
+            format,
             tree,
             strict,
             no_strict,
@@ -2586,6 +3598,7 @@ impl PipTreeSettings {
 
         Self {
             show_version_specifiers,
+            format,
             depth: tree.depth,
             prune: tree.prune,
             no_dedupe: tree.no_dedupe,
@@ -2606,7 +3619,7 @@ impl PipTreeSettings {
 }
 
 /// The resolved settings to use for a `pip check` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipCheckSettings {
     pub(crate) settings: PipSettings,
 }
@@ -2620,6 +3633,8 @@ impl PipCheckSettings {
             no_system,
             python_version,
             python_platform,
+            strict,
+            no_strict,
         } = args;
 
         Self {
@@ -2629,6 +3644,7 @@ impl PipCheckSettings {
                     system: flag(system, no_system, "system"),
                     python_version,
                     python_platform,
+                    strict: flag(strict, no_strict, "strict"),
                     ..PipOptions::default()
                 },
                 filesystem,
@@ -2638,7 +3654,7 @@ impl PipCheckSettings {
 }
 
 /// The resolved settings to use for a `build` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct BuildSettings {
     pub(crate) src: Option<PathBuf>,
     pub(crate) package: Option<PackageName>,
@@ -2653,6 +3669,8 @@ pub(crate) struct BuildSettings {
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
 }
@@ -2714,7 +3732,7 @@ impl BuildSettings {
 }
 
 /// The resolved settings to use for a `venv` invocation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
     pub(crate) allow_existing: bool,
@@ -2725,6 +3743,8 @@ pub(crate) struct VenvSettings {
     pub(crate) system_site_packages: bool,
     pub(crate) relocatable: bool,
     pub(crate) no_project: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2811,14 +3831,19 @@ pub(crate) struct InstallerSettingsRef<'a> {
 ///
 /// Combines the `[tool.uv]` persistent configuration with the command-line arguments
 /// ([`ResolverArgs`], represented as [`ResolverOptions`]).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
 pub(crate) struct ResolverSettings {
+    pub(crate) allow_insecure_md5_hashes: bool,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) build_options: BuildOptions,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
     pub(crate) fork_strategy: ForkStrategy,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -2829,6 +3854,8 @@ pub(crate) struct ResolverSettings {
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: SourceStrategy,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) upgrade: Upgrade,
 }
 
@@ -2865,12 +3892,14 @@ impl From<ResolverOptions> for ResolverSettings {
                 .map(Index::from)
                 .collect(),
             value.no_index.unwrap_or_default(),
-        );
+        )
+        .with_namespaces(value.index_namespaces.unwrap_or_default());
         Self {
             index_locations,
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            allow_insecure_md5_hashes: value.allow_insecure_md5_hashes.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2898,10 +3927,11 @@ impl From<ResolverOptions> for ResolverSettings {
 ///
 /// Represents the shared settings that are used across all uv commands outside the `pip` API.
 /// Analogous to the settings contained in the `[tool.uv]` table, combined with [`ResolverInstallerArgs`].
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
 pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolver: ResolverSettings,
     pub(crate) compile_bytecode: bool,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) reinstall: Reinstall,
 }
 
@@ -2939,9 +3969,11 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 .map(Index::from)
                 .collect(),
             value.no_index.unwrap_or_default(),
-        );
+        )
+        .with_namespaces(value.index_namespaces.unwrap_or_default());
         Self {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: value.allow_insecure_md5_hashes.unwrap_or_default(),
                 build_options: BuildOptions::new(
                     NoBinary::from_args(
                         value.no_binary,
@@ -2962,6 +3994,10 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .into_iter()
                         .map(Into::into)
                         .collect(),
+                )
+                .with_days(
+                    value.exclude_newer_than_days,
+                    value.exclude_newer_than_days_package.unwrap_or_default(),
                 ),
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
                 index_locations,
@@ -2986,16 +4022,23 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
 ///
 /// Represents the shared settings that are used across all `pip` commands. Analogous to the
 /// settings contained in the `[tool.uv.pip]` table.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PipSettings {
+    #[schemars(with = "serde_json::Value")]
     pub(crate) index_locations: IndexLocations,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) system: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: Vec<PipGroupName>,
     pub(crate) break_system_packages: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) target: Option<Target>,
+    #[serde(serialize_with = "show_settings::serialize_debug_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) prefix: Option<Prefix>,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -3003,6 +4046,7 @@ pub(crate) struct PipSettings {
     pub(crate) build_isolation: BuildIsolation,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) build_options: BuildOptions,
     pub(crate) allow_empty_requirements: bool,
     pub(crate) strict: bool,
@@ -3010,6 +4054,9 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) allow_insecure_md5_hashes: bool,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -3020,6 +4067,8 @@ pub(crate) struct PipSettings {
     pub(crate) generate_hashes: bool,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
+    #[serde(serialize_with = "show_settings::serialize_display_option")]
+    #[schemars(with = "Option<String>")]
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) universal: bool,
@@ -3030,12 +4079,21 @@ pub(crate) struct PipSettings {
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) emit_license: bool,
+    pub(crate) emit_homepage: bool,
+    pub(crate) emit_latest_version: bool,
+    pub(crate) emit_summary: bool,
+    pub(crate) emit_fork_annotation: bool,
+    pub(crate) emit_provenance: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
+    #[serde(serialize_with = "show_settings::serialize_debug")]
+    #[schemars(with = "String")]
     pub(crate) upgrade: Upgrade,
+    #[schemars(with = "serde_json::Value")]
     pub(crate) reinstall: Reinstall,
 }
 
@@ -3082,6 +4140,7 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            allow_insecure_md5_hashes,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -3102,6 +4161,12 @@ impl PipSettings {
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            emit_license,
+            emit_homepage,
+            emit_latest_version,
+            emit_summary,
+            emit_fork_annotation,
+            emit_provenance,
             annotation_style,
             link_mode,
             compile_bytecode,
@@ -3121,11 +4186,13 @@ impl PipSettings {
             extra_index_url: top_level_extra_index_url,
             no_index: top_level_no_index,
             find_links: top_level_find_links,
+            index_namespaces: top_level_index_namespaces,
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            allow_insecure_md5_hashes: top_level_allow_insecure_md5_hashes,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
@@ -3146,6 +4213,8 @@ impl PipSettings {
             no_binary: top_level_no_binary,
             no_binary_package: top_level_no_binary_package,
             exclude_newer_package: top_level_exclude_newer_package,
+            exclude_newer_than_days: top_level_exclude_newer_than_days,
+            exclude_newer_than_days_package: top_level_exclude_newer_than_days_package,
         } = top_level;
 
         // Merge the top-level options (`tool.uv`) with the pip-specific options (`tool.uv.pip`),
@@ -3162,6 +4231,8 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let allow_insecure_md5_hashes =
+            allow_insecure_md5_hashes.combine(top_level_allow_insecure_md5_hashes);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -3207,7 +4278,8 @@ impl PipSettings {
                     .map(Index::from)
                     .collect(),
                 args.no_index.combine(no_index).unwrap_or_default(),
-            ),
+            )
+            .with_namespaces(top_level_index_namespaces.unwrap_or_default()),
             extras: ExtrasSpecification::from_args(
                 args.extra.combine(extra).unwrap_or_default(),
                 args.no_extra.combine(no_extra).unwrap_or_default(),
@@ -3230,6 +4302,10 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            allow_insecure_md5_hashes: args
+                .allow_insecure_md5_hashes
+                .combine(allow_insecure_md5_hashes)
+                .unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -3299,6 +4375,10 @@ impl PipSettings {
             exclude_newer: ExcludeNewer::from_args(
                 exclude_newer,
                 exclude_newer_package.into_iter().map(Into::into).collect(),
+            )
+            .with_days(
+                top_level_exclude_newer_than_days,
+                top_level_exclude_newer_than_days_package.unwrap_or_default(),
             ),
             no_emit_package: args
                 .no_emit_package
@@ -3324,6 +4404,24 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
+            emit_license: args.emit_license.combine(emit_license).unwrap_or_default(),
+            emit_homepage: args
+                .emit_homepage
+                .combine(emit_homepage)
+                .unwrap_or_default(),
+            emit_latest_version: args
+                .emit_latest_version
+                .combine(emit_latest_version)
+                .unwrap_or_default(),
+            emit_summary: args.emit_summary.combine(emit_summary).unwrap_or_default(),
+            emit_fork_annotation: args
+                .emit_fork_annotation
+                .combine(emit_fork_annotation)
+                .unwrap_or_default(),
+            emit_provenance: args
+                .emit_provenance
+                .combine(emit_provenance)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
@@ -3414,7 +4512,7 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
 }
 
 /// The resolved settings to use for an invocation of the `uv publish` CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct PublishSettings {
     // CLI only, see [`PublishArgs`] for docs.
     pub(crate) files: Vec<String>,
@@ -3422,6 +4520,7 @@ pub(crate) struct PublishSettings {
     pub(crate) password: Option<String>,
     pub(crate) index: Option<String>,
     pub(crate) dry_run: bool,
+    pub(crate) check: bool,
 
     // Both CLI and configuration.
     pub(crate) publish_url: DisplaySafeUrl,
@@ -3430,6 +4529,7 @@ pub(crate) struct PublishSettings {
     pub(crate) check_url: Option<IndexUrl>,
 
     // Configuration only
+    #[schemars(with = "serde_json::Value")]
     pub(crate) index_locations: IndexLocations,
 }
 
@@ -3467,6 +4567,7 @@ impl PublishSettings {
             username,
             password,
             dry_run: args.dry_run,
+            check: args.check,
             publish_url: args
                 .publish_url
                 .combine(publish_url)
@@ -3495,8 +4596,10 @@ impl PublishSettings {
 }
 
 /// The resolved settings to use for an invocation of the `uv auth logout` CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct AuthLogoutSettings {
+    #[serde(serialize_with = "show_settings::serialize_display")]
+    #[schemars(with = "String")]
     pub(crate) service: Service,
     pub(crate) username: Option<String>,
 
@@ -3520,8 +4623,10 @@ impl AuthLogoutSettings {
 }
 
 /// The resolved settings to use for an invocation of the `uv auth token` CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct AuthTokenSettings {
+    #[serde(serialize_with = "show_settings::serialize_display")]
+    #[schemars(with = "String")]
     pub(crate) service: Service,
     pub(crate) username: Option<String>,
 
@@ -3545,8 +4650,10 @@ impl AuthTokenSettings {
 }
 
 /// The resolved settings to use for an invocation of the `uv auth set` CLI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub(crate) struct AuthLoginSettings {
+    #[serde(serialize_with = "show_settings::serialize_display")]
+    #[schemars(with = "String")]
     pub(crate) service: Service,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
@@ -3586,6 +4693,12 @@ mod env {
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_COMPILES: (&str, &str) =
+        (EnvVars::UV_CONCURRENT_COMPILES, "a non-zero integer");
+
+    pub(super) const CONCURRENT_PREFETCHES: (&str, &str) =
+        (EnvVars::UV_CONCURRENT_PREFETCHES, "a non-zero integer");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
@@ -3617,3 +4730,88 @@ fn parse_failure(name: &str, expected: &str) -> ! {
     eprintln!("error: invalid value for {name}, expected {expected}");
     process::exit(1)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use uv_client::Connectivity;
+    use uv_resolver::ResolutionMode;
+    use uv_settings::{FilesystemOptions, PipOptions};
+
+    use super::{GlobalSettings, LockSettings, PipCompileSettings, PipInstallSettings};
+
+    fn filesystem(content: &str) -> FilesystemOptions {
+        FilesystemOptions::from_toml_str(content, Path::new("/project")).unwrap()
+    }
+
+    #[test]
+    fn global_settings_builder() {
+        let settings = GlobalSettings::builder().offline(true).build(None);
+        assert_eq!(
+            settings.network_settings.connectivity,
+            Connectivity::Offline
+        );
+
+        // Explicit settings take precedence over the persistent configuration.
+        let configuration = filesystem("offline = true\nnative-tls = true\n");
+        let settings = GlobalSettings::builder()
+            .offline(false)
+            .build(Some(&configuration));
+        assert_eq!(settings.network_settings.connectivity, Connectivity::Online);
+        assert!(settings.network_settings.native_tls);
+    }
+
+    #[test]
+    fn pip_compile_settings_builder() {
+        let configuration = filesystem(
+            r#"
+            constraint-dependencies = ["idna<3"]
+
+            [pip]
+            generate-hashes = false
+            universal = true
+            "#,
+        );
+        let settings = PipCompileSettings::builder()
+            .requirements([PathBuf::from("requirements.in")])
+            .options(PipOptions {
+                generate_hashes: Some(true),
+                ..PipOptions::default()
+            })
+            .build(Some(configuration));
+
+        assert_eq!(settings.src_file, [PathBuf::from("requirements.in")]);
+        assert_eq!(settings.constraints_from_workspace.len(), 1);
+        assert!(settings.settings.generate_hashes);
+        assert!(settings.settings.universal);
+    }
+
+    #[test]
+    fn pip_install_settings_builder() {
+        let settings = PipInstallSettings::builder()
+            .packages(["flask>=2".to_string()])
+            .dry_run(true)
+            .build(None);
+
+        assert_eq!(settings.package, ["flask>=2"]);
+        assert!(settings.dry_run.enabled());
+        assert!(settings.requirements.is_empty());
+    }
+
+    #[test]
+    fn lock_settings_builder() {
+        let configuration = filesystem("resolution = \"lowest\"\n");
+        let settings = LockSettings::builder()
+            .locked(true)
+            .python("3.12")
+            .build(Some(configuration));
+
+        assert!(settings.locked);
+        assert_eq!(settings.python.as_deref(), Some("3.12"));
+        assert!(matches!(
+            settings.settings.resolution,
+            ResolutionMode::Lowest
+        ));
+    }
+}
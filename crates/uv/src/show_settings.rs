@@ -0,0 +1,165 @@
+//! Rendering of the resolved settings for `--show-settings`.
+//!
+//! In the JSON format, each setting is written as a single line containing an object keyed by the
+//! name of the settings type, e.g., `{"GlobalSettings": {...}}`. The schema for these lines is
+//! generated by [`json_schema`] and checked in as `show-settings.schema.json`.
+
+use std::fmt::{Debug, Display, Write};
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use uv_cli::{
+    CacheShowArgs, CleanArgs, EnvSnapshotArgs, PipNormalizeRequirementsArgs, PruneArgs,
+    PublishArgs, ShowSettingsFormat, StoreGcArgs, WheelhouseVerifyArgs, WorkspaceAffectedArgs,
+    WorkspaceGraphArgs,
+};
+
+use crate::printer::Printer;
+use crate::settings::{
+    AddSettings, AuthLoginSettings, AuthLogoutSettings, AuthTokenSettings, BuildSettings,
+    BundleSettings, CacheSettings, DiffSettings, EnvCreateForSettings, ExportSettings,
+    FormatSettings, GlobalSettings, InitSettings, LockSettings, PipCheckSettings,
+    PipCompileSettings, PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings,
+    PipSyncSettings, PipTreeSettings, PipUninstallSettings, PythonDirSettings,
+    PythonInstallSettings, PythonListSettings, PythonUninstallSettings, PythonUpgradeSettings,
+    RemoveSettings, RunSettings, SyncSettings, ToolCheckSettings, ToolDirSettings,
+    ToolInstallSettings, ToolListSettings, ToolRunSettings, ToolShowSettings,
+    ToolUninstallSettings, ToolUpgradeSettings, TreeSettings, VenvSettings, VersionSettings,
+    WatchSettings,
+};
+
+/// Write the resolved settings to `stdout` in the given format.
+pub(crate) fn write_settings<T: Debug + Serialize + JsonSchema>(
+    printer: Printer,
+    format: ShowSettingsFormat,
+    settings: &T,
+) -> Result<()> {
+    match format {
+        ShowSettingsFormat::Text => writeln!(printer.stdout(), "{settings:#?}")?,
+        ShowSettingsFormat::Json => {
+            let mut line = Map::new();
+            line.insert(
+                T::schema_name().into_owned(),
+                serde_json::to_value(settings)?,
+            );
+            writeln!(printer.stdout(), "{}", Value::Object(line))?;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize a setting whose type doesn't implement [`Serialize`] as its [`Debug`] representation.
+pub(crate) fn serialize_debug<T: Debug, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{value:?}"))
+}
+
+/// Serialize an optional setting whose type doesn't implement [`Serialize`] as its [`Debug`]
+/// representation.
+#[allow(clippy::ref_option)] // Required by `#[serde(serialize_with = "...")]`.
+pub(crate) fn serialize_debug_option<T: Debug, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.collect_str(&format_args!("{value:?}")),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a setting whose type doesn't implement [`Serialize`] as its [`Display`]
+/// representation.
+pub(crate) fn serialize_display<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Serialize an optional setting whose type doesn't implement [`Serialize`] as its [`Display`]
+/// representation.
+#[allow(clippy::ref_option)] // Required by `#[serde(serialize_with = "...")]`.
+pub(crate) fn serialize_display_option<T: Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.collect_str(value),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+// The variants must be named after the settings types, since each line of the output is keyed by
+// the settings type's schema name, see [`write_settings`].
+/// A line of the output of `uv --show-settings --show-settings-format json`.
+enum ShowSettings {
+    GlobalSettings(GlobalSettings),
+    CacheSettings(CacheSettings),
+    AuthLoginSettings(AuthLoginSettings),
+    AuthLogoutSettings(AuthLogoutSettings),
+    AuthTokenSettings(AuthTokenSettings),
+    PipCompileSettings(PipCompileSettings),
+    PipSyncSettings(PipSyncSettings),
+    PipInstallSettings(PipInstallSettings),
+    PipUninstallSettings(PipUninstallSettings),
+    PipFreezeSettings(PipFreezeSettings),
+    PipListSettings(PipListSettings),
+    PipShowSettings(PipShowSettings),
+    PipTreeSettings(PipTreeSettings),
+    PipCheckSettings(PipCheckSettings),
+    PipNormalizeRequirementsArgs(PipNormalizeRequirementsArgs),
+    CleanArgs(CleanArgs),
+    CacheShowArgs(CacheShowArgs),
+    PruneArgs(PruneArgs),
+    EnvCreateForSettings(EnvCreateForSettings),
+    EnvSnapshotArgs(EnvSnapshotArgs),
+    StoreGcArgs(StoreGcArgs),
+    WheelhouseVerifyArgs(WheelhouseVerifyArgs),
+    WorkspaceAffectedArgs(WorkspaceAffectedArgs),
+    WorkspaceGraphArgs(WorkspaceGraphArgs),
+    BuildSettings(BuildSettings),
+    VenvSettings(VenvSettings),
+    ToolRunSettings(ToolRunSettings),
+    ToolInstallSettings(ToolInstallSettings),
+    ToolListSettings(ToolListSettings),
+    ToolShowSettings(ToolShowSettings),
+    ToolUpgradeSettings(ToolUpgradeSettings),
+    ToolCheckSettings(ToolCheckSettings),
+    ToolUninstallSettings(ToolUninstallSettings),
+    ToolDirSettings(ToolDirSettings),
+    PythonListSettings(PythonListSettings),
+    PythonInstallSettings(PythonInstallSettings),
+    PythonUpgradeSettings(PythonUpgradeSettings),
+    PythonUninstallSettings(PythonUninstallSettings),
+    PythonDirSettings(PythonDirSettings),
+    PublishArgs(PublishArgs),
+    InitSettings(InitSettings),
+    RunSettings(RunSettings),
+    SyncSettings(SyncSettings),
+    LockSettings(LockSettings),
+    WatchSettings(WatchSettings),
+    AddSettings(AddSettings),
+    RemoveSettings(RemoveSettings),
+    VersionSettings(VersionSettings),
+    TreeSettings(TreeSettings),
+    DiffSettings(DiffSettings),
+    ExportSettings(ExportSettings),
+    BundleSettings(BundleSettings),
+    FormatSettings(FormatSettings),
+}
+
+/// Generate the JSON Schema for the lines written by `--show-settings --show-settings-format json`,
+/// as a pretty-printed string.
+pub(crate) fn json_schema() -> String {
+    let settings = schemars::generate::SchemaSettings::draft07();
+    let generator = schemars::SchemaGenerator::new(settings);
+    let schema = generator.into_root_schema_for::<ShowSettings>();
+    serde_json::to_string_pretty(&schema).unwrap()
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv::api::cache::Cache;
+use uv::api::settings::{
+    GlobalSettings, LockSettings, PipCompileSettings, PipInstallSettings, PythonDownloads,
+};
+use uv::api::{Environment, Package};
+
+use crate::common::TestContext;
+
+/// Render the name and version of each package, for comparison.
+fn pins(packages: &[Package]) -> Vec<String> {
+    packages
+        .iter()
+        .map(|package| match package.version() {
+            Some(version) => format!("{}=={version}", package.name()),
+            None => package.name().to_string(),
+        })
+        .collect()
+}
+
+/// Resolve, install, and lock in-process through the public API.
+#[tokio::test]
+async fn api_resolve_install_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let cache = Cache::from_path(context.cache_dir.path())?;
+    let globals = GlobalSettings::builder()
+        .python_downloads(PythonDownloads::Never)
+        .no_progress(true)
+        .build(None);
+    let python = context.interpreter().display().to_string();
+
+    // Resolve a set of requirements.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+    let settings = PipCompileSettings::builder()
+        .requirements([requirements_in.to_path_buf()])
+        .python(python.clone())
+        .build(None);
+    let resolution = uv::api::resolve(settings, &globals, &cache).await?;
+    assert_eq!(pins(resolution.packages()), ["iniconfig==2.0.0"]);
+    assert!(resolution.to_pylock_toml().contains("name = \"iniconfig\""));
+
+    // Install the same requirements into the test environment.
+    let environment = Environment::from_root(context.venv.path(), &cache)?;
+    assert!(environment.installed_packages()?.is_empty());
+    let settings = PipInstallSettings::builder()
+        .packages(["iniconfig==2.0.0".to_string()])
+        .build(None);
+    uv::api::install(settings, &environment, &globals, &cache).await?;
+    assert_eq!(
+        pins(&environment.installed_packages()?),
+        ["iniconfig==2.0.0"]
+    );
+
+    // Lock a project with the same dependency.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+    let settings = LockSettings::builder().python(python.clone()).build(None);
+    let lockfile = uv::api::lock(project.path(), settings, &globals, &cache).await?;
+    assert_eq!(
+        pins(&lockfile.packages()),
+        ["iniconfig==2.0.0", "project==0.1.0"]
+    );
+    project.child("uv.lock").assert(predicates::path::is_file());
+
+    // A failed operation is reported as an error.
+    let settings = LockSettings::builder()
+        .locked(true)
+        .python(python)
+        .build(None);
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+        "#
+    })?;
+    assert!(
+        uv::api::lock(project.path(), settings, &globals, &cache)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -34,7 +34,7 @@ fn add_package_native_auth_realm() -> Result<()> {
     uv_snapshot!(context.add().arg("anyio").arg("--default-index").arg("https://public@pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -100,7 +100,7 @@ fn add_package_native_auth_realm() -> Result<()> {
     uv_snapshot!(context.add().arg("iniconfig").arg("--default-index").arg("https://public@pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -144,7 +144,7 @@ fn add_package_native_auth() -> Result<()> {
     uv_snapshot!(context.add().arg("anyio").arg("--default-index").arg("https://public@pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -210,7 +210,7 @@ fn add_package_native_auth() -> Result<()> {
     uv_snapshot!(context.add().arg("iniconfig").arg("--default-index").arg("https://public@pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -244,7 +244,7 @@ fn token_native_auth() -> Result<()> {
         .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -258,7 +258,7 @@ fn token_native_auth() -> Result<()> {
         .arg("public")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -302,7 +302,7 @@ fn token_native_auth() -> Result<()> {
         .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -317,7 +317,7 @@ fn token_native_auth() -> Result<()> {
         .arg("private")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -377,7 +377,7 @@ fn token_native_auth() -> Result<()> {
         .arg("different")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -427,7 +427,7 @@ fn token_native_auth_realm() -> Result<()> {
         .arg("public")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -511,7 +511,7 @@ fn token_native_auth_realm() -> Result<()> {
         .arg("private")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -525,7 +525,7 @@ fn token_native_auth_realm() -> Result<()> {
         .arg("public")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -598,7 +598,7 @@ fn login_native_auth() -> Result<()> {
     // Without a service name
     uv_snapshot!(context.auth_login(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -615,7 +615,7 @@ fn login_native_auth() -> Result<()> {
         .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -629,7 +629,7 @@ fn login_native_auth() -> Result<()> {
         .arg("public")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -705,7 +705,7 @@ fn logout_native_auth() -> Result<()> {
     // Without a service name
     uv_snapshot!(context.auth_logout(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -736,7 +736,7 @@ fn logout_native_auth() -> Result<()> {
         .arg("public")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -767,7 +767,7 @@ fn logout_native_auth() -> Result<()> {
         .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -820,7 +820,7 @@ fn logout_native_auth() -> Result<()> {
         .arg("foo")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -834,7 +834,7 @@ fn logout_native_auth() -> Result<()> {
         .arg("foo")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -916,7 +916,7 @@ fn login_native_auth_url() {
         .arg("test")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -981,7 +981,7 @@ fn login_native_auth_url() {
         .arg("test")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1025,7 +1025,7 @@ fn login_native_auth_url() {
         .arg("password")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1039,7 +1039,7 @@ fn login_native_auth_url() {
         .arg("different")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1053,7 +1053,7 @@ fn login_native_auth_url() {
         .arg("some-token")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1103,7 +1103,7 @@ fn login_text_store() {
         .arg("--password")
         .arg("testpass"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1119,7 +1119,7 @@ fn login_text_store() {
         .arg("--password")
         .arg(""), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1135,7 +1135,7 @@ fn login_text_store() {
         .arg("--password")
         .arg("testpass"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1152,7 +1152,7 @@ fn login_text_store() {
         .arg("--password")
         .arg("testpass"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1330,7 +1330,7 @@ fn token_text_store() {
         .arg("--username")
         .arg(""), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1394,7 +1394,7 @@ fn logout_text_store() {
         .arg("--username")
         .arg(""), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1666,7 +1666,7 @@ fn token_text_store_username() {
     uv_snapshot!(context.auth_token()
         .arg("https://example.com/simple"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1680,7 +1680,7 @@ fn token_text_store_username() {
         .arg("--username")
         .arg("wronguser"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1724,7 +1724,7 @@ fn token_text_store_username() {
     uv_snapshot!(context.auth_token()
         .arg("https://userexample.com/simple"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1778,7 +1778,7 @@ fn logout_text_store_multiple_usernames() {
         .arg("--username")
         .arg("user1"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1803,7 +1803,7 @@ fn logout_text_store_multiple_usernames() {
     uv_snapshot!(context.auth_logout()
         .arg("https://example.com/simple"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1915,7 +1915,7 @@ fn native_auth_host_fallback() -> Result<()> {
         .arg("testuser")
         .env(EnvVars::UV_PREVIEW_FEATURES, "native-auth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
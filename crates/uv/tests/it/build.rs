@@ -94,7 +94,7 @@ fn build_basic() -> Result<()> {
     // Error if there's nothing to build.
     uv_snapshot!(&filters, context.build(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -354,7 +354,7 @@ fn build_wheel_from_sdist() -> Result<()> {
     // Error if `--wheel` is not specified.
     uv_snapshot!(&filters, context.build().arg("./dist/project-0.1.0.tar.gz").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -365,7 +365,7 @@ fn build_wheel_from_sdist() -> Result<()> {
     // Error if `--sdist` is specified.
     uv_snapshot!(&filters, context.build().arg("./dist/project-0.1.0.tar.gz").arg("--sdist").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -396,7 +396,7 @@ fn build_wheel_from_sdist() -> Result<()> {
     // Passing a wheel is an error.
     uv_snapshot!(&filters, context.build().arg("./dist/project-0.1.0-py3-none-any.whl").arg("--wheel").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -456,7 +456,7 @@ fn build_fail() -> Result<()> {
     // Build the specified path.
     uv_snapshot!(&filters, context.build().arg("project"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -655,7 +655,7 @@ fn build_workspace() -> Result<()> {
     // Fail when `--package` is provided without a workspace.
     uv_snapshot!(&filters, context.build().arg("--package").arg("member"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -666,7 +666,7 @@ fn build_workspace() -> Result<()> {
     // Fail when `--all` is provided without a workspace.
     uv_snapshot!(&filters, context.build().arg("--all"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -677,7 +677,7 @@ fn build_workspace() -> Result<()> {
     // Fail when `--package` is a non-existent member without a workspace.
     uv_snapshot!(&filters, context.build().arg("--package").arg("fail").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -792,7 +792,7 @@ fn build_all_with_failure() -> Result<()> {
     // Build all the packages
     uv_snapshot!(&filters, context.build().arg("--all").arg("--no-build-logs").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -871,7 +871,7 @@ fn build_constraints() -> Result<()> {
 
     uv_snapshot!(&filters, context.build().arg("--build-constraint").arg("constraints.txt").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -957,7 +957,7 @@ fn build_sha() -> Result<()> {
 
     uv_snapshot!(&filters, context.build().arg("--build-constraint").arg("constraints.txt").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -989,7 +989,7 @@ fn build_sha() -> Result<()> {
     // Reject a missing hash with `--requires-hashes`.
     uv_snapshot!(&filters, context.build().arg("--build-constraint").arg("constraints.txt").arg("--require-hashes").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1024,7 +1024,7 @@ fn build_sha() -> Result<()> {
 
     uv_snapshot!(&filters, context.build().arg("--build-constraint").arg("constraints.txt").arg("--require-hashes").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1380,7 +1380,7 @@ fn build_non_package() -> Result<()> {
     // Build the member.
     uv_snapshot!(&filters, context.build().arg("--package").arg("member").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1404,7 +1404,7 @@ fn build_non_package() -> Result<()> {
     // Build all packages.
     uv_snapshot!(&filters, context.build().arg("--all").arg("--no-build-logs").current_dir(&project), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1671,7 +1671,7 @@ fn build_list_files_errors() -> Result<()> {
         .arg("--list")
         .arg("--force-pep517"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1693,7 +1693,7 @@ fn build_list_files_errors() -> Result<()> {
         .arg(context.temp_dir.join("output2"))
         .arg("--list"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1726,7 +1726,7 @@ fn build_version_mismatch() -> Result<()> {
         .arg("--out-dir")
         .arg(context.temp_dir.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2000,7 +2000,7 @@ fn force_pep517() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build().env(EnvVars::RUST_BACKTRACE, "0"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2011,7 +2011,7 @@ fn force_pep517() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build().arg("--force-pep517").env(EnvVars::RUST_BACKTRACE, "0"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2070,14 +2070,14 @@ fn venv_included_in_sdist() -> Result<()> {
     // context.filters()
     uv_snapshot!(context.filters(), context.build(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     Building source distribution...
       × Failed to build `[TEMP_DIR]/`
       ├─▶ Invalid tar file
-      ├─▶ failed to unpack `[CACHE_DIR]/sdists-v9/[TMP]/python`
+      ├─▶ failed to unpack `[CACHE_DIR]/sdists-v10/[TMP]/python`
       ╰─▶ symlink destination for [PYTHON-3.12] is outside of the target directory
       help: This file seems to be part of a virtual environment. Virtual environments must be excluded from source distributions.
     ");
@@ -449,7 +449,7 @@ fn build_module_name_normalization() -> Result<()> {
         .arg("build-wheel")
         .arg(&wheel_dir), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -463,7 +463,7 @@ fn build_module_name_normalization() -> Result<()> {
         .arg("build-wheel")
         .arg(&wheel_dir), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -599,7 +599,7 @@ fn sdist_error_without_module() -> Result<()> {
         .arg("build-sdist")
         .arg(temp_dir.path()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -613,7 +613,7 @@ fn sdist_error_without_module() -> Result<()> {
         .arg("build-sdist")
         .arg(temp_dir.path()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -910,7 +910,7 @@ fn error_on_relative_module_root_outside_project_root() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -921,7 +921,7 @@ fn error_on_relative_module_root_outside_project_root() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build().arg("--wheel"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -965,7 +965,7 @@ fn error_on_relative_data_dir_outside_project_root() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build().arg("project"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -976,7 +976,7 @@ fn error_on_relative_data_dir_outside_project_root() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.build().arg("project").arg("--wheel"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1009,7 +1009,7 @@ fn venv_in_source_tree() {
 
     uv_snapshot!(context.filters(), context.build(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1020,7 +1020,7 @@ fn venv_in_source_tree() {
 
     uv_snapshot!(context.filters(), context.build().arg("--wheel"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
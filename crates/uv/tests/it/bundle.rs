@@ -0,0 +1,178 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Bundle a project with a console script, and run the launcher after moving the bundle.
+#[cfg(unix)]
+#[test]
+fn bundle_relocatable() -> Result<()> {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    context.python_install().arg("3.12").assert().success();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.scripts]
+        hello = "example:main"
+
+        [dependency-groups]
+        dev = ["anyio"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    context
+        .temp_dir
+        .child("src")
+        .child("example")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+            def main():
+                import iniconfig
+                print("Hello from the bundle!")
+        "#})?;
+
+    context.bundle().arg("--archive").assert().success();
+
+    // The launcher and archive should be created.
+    context
+        .temp_dir
+        .child("bundle")
+        .child("bin")
+        .child("hello")
+        .assert(predicates::path::exists());
+    context
+        .temp_dir
+        .child("bundle.tar.gz")
+        .assert(predicates::path::exists());
+
+    // Move the bundle, and remove the managed Python installation.
+    fs_err::rename(
+        context.temp_dir.child("bundle"),
+        context.temp_dir.child("moved"),
+    )?;
+    fs_err::remove_dir_all(context.temp_dir.child("managed"))?;
+
+    uv_snapshot!(context.filters(), std::process::Command::new(context.temp_dir.child("moved").child("bin").child("hello").as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from the bundle!
+
+    ----- stderr -----
+    ");
+
+    // The development dependencies should be excluded.
+    uv_snapshot!(context.filters(), std::process::Command::new(context.temp_dir.child("moved").child("python").child("bin").child("python3").as_os_str())
+        .arg("-c")
+        .arg("import importlib.util; print(importlib.util.find_spec('anyio'))"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn bundle_existing_directory() -> Result<()> {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    context.python_install().arg("3.12").assert().success();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // Refuse to replace a directory that isn't a bundle.
+    context.temp_dir.child("out").child("file.txt").touch()?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--output-dir").arg("out"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output directory `out` already exists and does not contain a bundle
+    ");
+
+    // Refuse to replace a directory that looks like a bundle, but wasn't written by `uv bundle`.
+    context
+        .temp_dir
+        .child("python-dir")
+        .child("python")
+        .child("file.txt")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.bundle().arg("--output-dir").arg("python-dir"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output directory `python-dir` already exists and does not contain a bundle
+    ");
+
+    context
+        .temp_dir
+        .child("python-dir")
+        .child("python")
+        .child("file.txt")
+        .assert(predicates::path::exists());
+
+    // Replace a bundle written by a previous invocation.
+    context.bundle().assert().success();
+    context
+        .temp_dir
+        .child("bundle")
+        .child("stale.txt")
+        .touch()?;
+    context.bundle().assert().success();
+
+    context
+        .temp_dir
+        .child("bundle")
+        .child(".uv-bundle")
+        .assert(predicates::path::exists());
+    context
+        .temp_dir
+        .child("bundle")
+        .child("stale.txt")
+        .assert(predicates::path::missing());
+
+    // The staging directory should be removed.
+    let entries = fs_err::read_dir(&context.temp_dir)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .filter(|name: &Result<String>| name.as_ref().is_ok_and(|name| name.starts_with(".tmp")))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(entries.is_empty(), "{entries:?}");
+
+    Ok(())
+}
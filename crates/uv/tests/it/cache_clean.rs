@@ -35,6 +35,43 @@ fn clean_all() -> Result<()> {
     Ok(())
 }
 
+/// `cache clean --dry-run` should report the size of the cache without removing anything.
+#[test]
+fn clean_all_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("typing-extensions\niniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([("Would remove \\d+ files?", "Would remove [N] files")])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would clear cache at: [CACHE_DIR]/
+    Would remove [N] files ([SIZE])
+    ");
+
+    // The cache should be untouched.
+    assert!(context.cache_dir.child("wheels-v6").exists());
+
+    Ok(())
+}
+
 /// `cache clean iniconfig` should remove a single package (`iniconfig`).
 #[test]
 fn clean_package_pypi() -> Result<()> {
@@ -53,7 +90,7 @@ fn clean_package_pypi() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v18")
+        .child("simple-v20")
         .child("pypi")
         .child("iniconfig.rkyv");
     assert!(
@@ -111,6 +148,59 @@ fn clean_package_pypi() -> Result<()> {
     Ok(())
 }
 
+/// `cache clean --dry-run iniconfig` should list the entries for a single package (`iniconfig`),
+/// including the unzipped wheel that would no longer be referenced, without removing them.
+#[test]
+fn clean_package_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The archive does not have a stable key, so we filter it out.
+            (r"archive-v0(\\|\/)[\w-]+", "archive-v0/[ENTRY]"),
+            // The file count varies by operating system, so we filter it out.
+            ("Would remove \\d+ files?", "Would remove [N] files"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--dry-run").arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would remove: wheels-v6/pypi/iniconfig ([SIZE])
+    Would remove: simple-v20/pypi/iniconfig.rkyv ([SIZE])
+    Would remove: archive-v0/[ENTRY] ([SIZE])
+    Would remove [N] files ([SIZE])
+    ");
+
+    // Assert that the `.rkyv` file still exists for `iniconfig`.
+    assert!(
+        context
+            .cache_dir
+            .child("simple-v20")
+            .child("pypi")
+            .child("iniconfig.rkyv")
+            .exists(),
+        "Expected the `.rkyv` file to be retained for `iniconfig`"
+    );
+
+    Ok(())
+}
+
 /// `cache clean iniconfig` should remove a single package (`iniconfig`).
 #[test]
 fn clean_package_index() -> Result<()> {
@@ -131,7 +221,7 @@ fn clean_package_index() -> Result<()> {
     // Assert that the `.rkyv` file is created for `iniconfig`.
     let rkyv = context
         .cache_dir
-        .child("simple-v18")
+        .child("simple-v20")
         .child("index")
         .child("e8208120cae3ba69")
         .child("iniconfig.rkyv");
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+use crate::common::uv_snapshot;
+
+/// `cache migrate` should leave previous versions of a bucket in place if no migration is
+/// available, such that `uv cache prune` can remove them.
+#[test]
+fn migrate_unavailable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Add a stale bucket to the cache.
+    let simple = context.cache_dir.child("simple-v4");
+    simple.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.cache_migrate(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrating cache at: [CACHE_DIR]/
+    No cache buckets to migrate
+    ");
+
+    simple.assert(predicates::path::is_dir());
+
+    Ok(())
+}
+
+/// `cache migrate` should carry forward the entries for remote distributions when migrating the
+/// wheels bucket, and remove the entries for local distributions, whose cache keys changed.
+#[test]
+fn migrate_wheels() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheels = context.cache_dir.child("wheels-v5");
+    wheels.child("pypi").child("iniconfig").create_dir_all()?;
+    wheels
+        .child("path")
+        .child("0123456789abcdef")
+        .create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.cache_migrate(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrating cache at: [CACHE_DIR]/
+    Migrated [CACHE_DIR]/wheels-v5 to [CACHE_DIR]/wheels-v6
+    Migrated 1 cache bucket
+    ");
+
+    // The previous version is left in place for `uv cache prune`.
+    wheels
+        .child("path")
+        .child("0123456789abcdef")
+        .assert(predicates::path::is_dir());
+    context
+        .cache_dir
+        .child("wheels-v6")
+        .child("pypi")
+        .child("iniconfig")
+        .assert(predicates::path::is_dir());
+    context
+        .cache_dir
+        .child("wheels-v6")
+        .child("path")
+        .assert(predicates::path::missing());
+
+    // Once migrated, there's nothing left to do.
+    uv_snapshot!(context.filters(), context.cache_migrate(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrating cache at: [CACHE_DIR]/
+    No cache buckets to migrate
+    ");
+
+    Ok(())
+}
@@ -87,6 +87,45 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune` should remove any ephemeral environments left behind by a terminated process.
+#[test]
+fn prune_stale_ephemeral_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Add an ephemeral environment whose lock isn't held by any process.
+    let builds = context.cache_dir.child("builds-v0");
+    builds
+        .child("ephemeral-stale")
+        .child("pyvenv.cfg")
+        .touch()?;
+    builds.child("ephemeral-stale.lock").touch()?;
+
+    uv_snapshot!(context.filters(), context.prune().arg("--verbose"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    DEBUG Acquired lock for `[CACHE_DIR]/`
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `[CACHE_DIR]/builds-v0/ephemeral-stale.lock`
+    DEBUG Removing stale ephemeral environment: [CACHE_DIR]/builds-v0/ephemeral-stale
+    DEBUG Released lock at `[CACHE_DIR]/builds-v0/ephemeral-stale.lock`
+    Removed 2 files
+    DEBUG Released lock at `[CACHE_DIR]/.lock`
+    ");
+
+    builds
+        .child("ephemeral-stale")
+        .assert(predicates::path::missing());
+    builds
+        .child("ephemeral-stale.lock")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
 /// `cache prune` should remove all cached environments from the cache.
 #[test]
 fn prune_cached_env() {
@@ -164,7 +203,7 @@ fn prune_stale_symlink() -> Result<()> {
         .success();
 
     // Remove the wheels directory, causing the symlink to become stale.
-    let wheels = context.cache_dir.child("wheels-v5");
+    let wheels = context.cache_dir.child("wheels-v6");
     fs_err::remove_dir_all(wheels)?;
 
     let filters: Vec<_> = context
@@ -260,7 +299,7 @@ fn prune_unzipped() -> Result<()> {
     " })?;
     uv_snapshot!(&filters, context.pip_install().arg("-r").arg("requirements.txt").arg("--offline"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -357,7 +396,7 @@ fn prune_stale_revision() -> Result<()> {
     DEBUG uv [VERSION] ([COMMIT] DATE)
     DEBUG Acquired lock for `[CACHE_DIR]/`
     Pruning cache at: [CACHE_DIR]/
-    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v9/[ENTRY]
+    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v10/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
     DEBUG Released lock at `[CACHE_DIR]/.lock`
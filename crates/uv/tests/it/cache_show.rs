@@ -0,0 +1,60 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache show iniconfig` should list the entries for a single package (`iniconfig`) across all
+/// buckets.
+#[test]
+fn show_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The archive does not have a stable key, so we filter it out.
+            (r"archive-v0(\\|\/)[\w-]+", "archive-v0/[ENTRY]"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_show().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Downloaded wheels:
+        wheels-v6/pypi/iniconfig ([SIZE])
+
+    Index metadata:
+        simple-v20/pypi/iniconfig.rkyv ([SIZE])
+
+    Unzipped wheels:
+        archive-v0/[ENTRY] ([SIZE])
+
+    ----- stderr -----
+    Found 3 cache entries for `iniconfig` ([SIZE])
+    ");
+
+    // A package that isn't in the cache has no entries.
+    uv_snapshot!(&filters, context.cache_show().arg("flask"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found for `flask`
+    ");
+
+    Ok(())
+}
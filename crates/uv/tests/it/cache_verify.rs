@@ -0,0 +1,70 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+use crate::common::uv_snapshot;
+
+/// `cache verify` should quarantine unzipped wheels whose contents no longer match their `RECORD`.
+#[test]
+fn verify_quarantines_corrupted_entry() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("iniconfig==2.0.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 cache entry
+    ");
+
+    // Remove a file from the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0"))?
+        .next()
+        .unwrap()?
+        .path();
+    fs_err::remove_file(archive.join("iniconfig").join("__init__.py"))?;
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    error: `iniconfig/__init__.py` is missing from `iniconfig-2.0.0`
+    Quarantined 1 corrupted cache entry (0 verified)
+    ");
+
+    // The entry is moved out of the archive bucket, such that it's re-downloaded when next needed.
+    assert!(!archive.exists());
+    context
+        .cache_dir
+        .child("quarantine-v0")
+        .assert(predicates::path::is_dir());
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 0 cache entries
+    ");
+
+    Ok(())
+}
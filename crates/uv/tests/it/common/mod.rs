@@ -737,6 +737,10 @@ impl TestContext {
         // Filter non-deterministic temporary directory names
         // Note we apply this _after_ all the full paths to avoid breaking their matching
         filters.push((r"(\\|\/)\.tmp.*(\\|\/)".to_string(), "/[TMP]/".to_string()));
+        filters.push((
+            r"(\\|\/)ephemeral-.*(\\|\/)".to_string(),
+            "/[TMP]/".to_string(),
+        ));
 
         // Account for platform prefix differences `file://` (Unix) vs `file:///` (Windows)
         filters.push((r"file:///".to_string(), "file://".to_string()));
@@ -877,6 +881,9 @@ impl TestContext {
             // non-deterministic, so can't capture them in test output.
             .env(EnvVars::UV_TEST_NO_CLI_PROGRESS, "1")
             .env_remove(EnvVars::UV_CACHE_DIR)
+            .env_remove(EnvVars::UV_LANG)
+            .env_remove(EnvVars::UV_LOCKED_TIMEOUT)
+            .env_remove(EnvVars::UV_PROFILE)
             .env_remove(EnvVars::UV_TOOL_BIN_DIR)
             .env_remove(EnvVars::XDG_CONFIG_HOME)
             // I believe the intent of all tests is that they are run outside the
@@ -979,6 +986,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip normalize-requirements` command for testing.
+    pub fn pip_normalize_requirements(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("pip").arg("normalize-requirements");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `pip tree` command for testing.
     pub fn pip_tree(&self) -> Command {
         let mut command = Self::new_command();
@@ -1180,6 +1195,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool show` command with options shared across scenarios.
+    pub fn tool_show(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("tool").arg("show");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool check` command with options shared across scenarios.
+    pub fn tool_check(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("tool").arg("check");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool dir` command with options shared across scenarios.
     pub fn tool_dir(&self) -> Command {
         let mut command = Self::new_command();
@@ -1220,6 +1251,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv bundle` command with options shared across scenarios.
+    pub fn bundle(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("bundle");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv diff` command with options shared across scenarios.
+    pub fn diff(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("diff");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = Self::new_command();
@@ -1228,6 +1275,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache show` command.
+    pub fn cache_show(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("show");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache migrate` command.
+    pub fn cache_migrate(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("migrate");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache prune` command.
     pub fn prune(&self) -> Command {
         let mut command = Self::new_command();
@@ -1236,6 +1307,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv store gc` command.
+    pub fn store_gc(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("store").arg("gc");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv wheelhouse verify` command.
+    pub fn wheelhouse_verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("wheelhouse").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace affected` command.
+    pub fn workspace_affected(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("workspace").arg("affected");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace graph` command.
+    pub fn workspace_graph(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("workspace").arg("graph");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
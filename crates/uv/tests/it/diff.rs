@@ -0,0 +1,107 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+#[test]
+fn diff_lockfiles() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("old.lock"),
+    )?;
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0", "iniconfig"]
+        "#,
+    )?;
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.diff().arg("old.lock").arg("uv.lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package   Old   New   Change   Required by
+    --------- ----- ----- -------- -----------
+    anyio     3.7.0 4.3.0 upgraded anyio
+    iniconfig       2.0.0 added    iniconfig
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.diff().arg("uv.lock").arg("old.lock").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"anyio","kind":"downgraded","old_version":"4.3.0","new_version":"3.7.0","required_by":["anyio"]},{"name":"iniconfig","kind":"removed","old_version":"2.0.0","required_by":["iniconfig"]}]
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn diff_lockfile_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+    context.sync().assert().success();
+
+    // The environment should match the lockfile.
+    uv_snapshot!(context.filters(), context.diff().arg("uv.lock").arg(".venv"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No differences found
+    ");
+
+    // Install an additional package into the environment.
+    context
+        .pip_install()
+        .arg("typing-extensions")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.diff().arg(".").arg(".venv"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package           Old New    Change Required by
+    ----------------- --- ------ ------ -----------
+    typing-extensions     4.10.0 added
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
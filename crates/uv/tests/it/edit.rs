@@ -613,7 +613,7 @@ fn add_git_error() -> Result<()> {
     // Provide a tag without a Git source.
     uv_snapshot!(context.filters(), context.add().arg("flask").arg("--tag").arg("0.0.1"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -623,7 +623,7 @@ fn add_git_error() -> Result<()> {
     // Provide a tag with a non-Git source.
     uv_snapshot!(context.filters(), context.add().arg("flask @ https://files.pythonhosted.org/packages/61/80/ffe1da13ad9300f87c93af113edd0638c75138c42a0994becfacac078c06/flask-3.0.3-py3-none-any.whl").arg("--branch").arg("0.0.1"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -883,7 +883,7 @@ fn add_raw_error() -> Result<()> {
     // Provide a tag without a Git source.
     uv_snapshot!(context.filters(), context.add().arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage").arg("--tag").arg("0.0.1").arg("--raw-sources"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -967,7 +967,7 @@ fn add_editable_error() -> Result<()> {
     // Provide `--editable` with a non-source tree.
     uv_snapshot!(context.filters(), context.add().arg("flask @ https://files.pythonhosted.org/packages/61/80/ffe1da13ad9300f87c93af113edd0638c75138c42a0994becfacac078c06/flask-3.0.3-py3-none-any.whl").arg("--editable"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1197,7 +1197,7 @@ fn add_remove_dev() -> Result<()> {
     // This should fail without --dev.
     uv_snapshot!(context.filters(), context.remove().arg("anyio"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1407,7 +1407,7 @@ fn add_remove_optional() -> Result<()> {
     // This should fail without --optional.
     uv_snapshot!(context.filters(), context.remove().arg("anyio"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1626,7 +1626,7 @@ fn add_remove_workspace() -> Result<()> {
 
     uv_snapshot!(context.filters(), add_cmd, @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2125,7 +2125,7 @@ fn disallow_group_script_add() -> Result<()> {
         .arg("--script")
         .arg("main.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3347,6 +3347,91 @@ fn add_update_marker() -> Result<()> {
     Ok(())
 }
 
+/// Replace the marker on an existing dependency with `--marker`, and strip it with
+/// `uv remove --marker-only`.
+#[test]
+fn add_replace_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2", "anyio"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--marker").arg("sys_platform == 'win32' or sys_platform == 'win32'").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    // The existing entry should be updated in-place, with a simplified marker.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2 ; sys_platform == 'win32'",
+            "anyio",
+        ]
+        "#
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--marker-only").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    // The marker should be removed, but the dependency retained.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2",
+            "anyio",
+        ]
+        "#
+        );
+    });
+
+    // Stripping markers from a missing dependency is an error.
+    uv_snapshot!(context.filters(), context.remove().arg("typing-extensions").arg("--marker-only").arg("--frozen"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The dependency `typing-extensions` could not be found in `project.dependencies`
+    ");
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "git")]
 fn update_source_replace_url() -> Result<()> {
@@ -4163,7 +4248,7 @@ fn add_reject_multiple_git_ref_flags() {
         .arg("--branch")
         .arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4184,7 +4269,7 @@ fn add_reject_multiple_git_ref_flags() {
         .arg("--rev")
         .arg("326b943"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4205,7 +4290,7 @@ fn add_reject_multiple_git_ref_flags() {
         .arg("--tag")
         .arg("0.0.2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4234,7 +4319,7 @@ fn add_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("xyz"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4282,7 +4367,7 @@ fn add_environment_yml_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("-r").arg("environment.yml"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4711,7 +4796,7 @@ fn add_non_project() -> Result<()> {
     // dependencies.
     uv_snapshot!(context.filters(), context.add().arg("iniconfig"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4722,7 +4807,7 @@ fn add_non_project() -> Result<()> {
     // optional dependencies.
     uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--optional").arg("async"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4810,7 +4895,7 @@ fn add_virtual_empty() -> Result<()> {
     uv_snapshot!(context.filters(), context.add()
         .arg("sortedcontainers"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4972,7 +5057,7 @@ fn remove_virtual_empty() -> Result<()> {
     uv_snapshot!(context.filters(), context.remove()
         .arg("sortedcontainers"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4997,7 +5082,7 @@ fn remove_virtual_empty() -> Result<()> {
         .arg("sortedcontainers")
         .arg("--group").arg("dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5070,7 +5155,7 @@ fn remove_virtual_dependency_group() -> Result<()> {
         .arg("sortedcontainers")
         .arg("--group").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5248,7 +5333,7 @@ fn add_requirements_file() -> Result<()> {
     // Passing a `setup.py` should fail.
     uv_snapshot!(context.filters(), context.add().arg("-r").arg("setup.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5258,7 +5343,7 @@ fn add_requirements_file() -> Result<()> {
     // Passing nothing should fail.
     uv_snapshot!(context.filters(), context.add(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6151,7 +6236,7 @@ fn remove_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--group").arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6179,7 +6264,7 @@ fn remove_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--group").arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6197,7 +6282,7 @@ fn remove_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.remove().arg("anyio").arg("--group").arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8881,7 +8966,7 @@ fn add_shadowed_name() -> Result<()> {
     // Pinned constrained, check for a direct dependency loop.
     uv_snapshot!(context.filters(), context.add().arg("dagster-webserver==1.6.13"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -8895,7 +8980,7 @@ fn add_shadowed_name() -> Result<()> {
     // Constraint with several available versions, check for an indirect dependency loop.
     uv_snapshot!(context.filters(), context.add().arg("dagster-webserver>=1.6.11,<1.7.0"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -8999,7 +9084,7 @@ fn add_warn_index_url() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--extra-index-url").arg("https://test.pypi.org/simple"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -10295,7 +10380,7 @@ fn add_index_with_non_existent_relative_path() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--index").arg("./test-index"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10325,7 +10410,7 @@ fn add_index_with_non_existent_relative_path_with_same_name_as_index() -> Result
 
     uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--index").arg("./test-index"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10389,7 +10474,7 @@ fn add_index_with_ambiguous_relative_path() -> Result<()> {
     #[cfg(unix)]
     uv_snapshot!(filters, context.add().arg("iniconfig").arg("--index").arg("test-index"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10400,7 +10485,7 @@ fn add_index_with_ambiguous_relative_path() -> Result<()> {
     #[cfg(windows)]
     uv_snapshot!(filters, context.add().arg("iniconfig").arg("--index").arg("test-index"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10663,7 +10748,7 @@ fn add_self() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12257,7 +12342,7 @@ fn add_unsupported_git_scheme() {
 
     uv_snapshot!(context.filters(), context.add().arg("git+fantasy://ferris/dreams/of/urls@7701ffcbae245819b828dc5f885a5201158897ef"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12366,7 +12451,7 @@ fn add_full_url_in_keyring() -> Result<()> {
         .env(EnvVars::KEYRING_TEST_CREDENTIALS, r#"{"https://pypi-proxy.fly.dev/basic-auth/simple/anyio": {"public": "heron"}}"#)
         .env(EnvVars::PATH, venv_bin_path(&keyring_context.venv)), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -12403,7 +12488,7 @@ fn add_stop_index_search_early_on_auth_failure() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -12484,7 +12569,7 @@ fn add_empty_ignore_error_codes() -> Result<()> {
     // by the empty ignore-error-codes list.
     uv_snapshot!(context.add().arg("flask"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -12523,7 +12608,7 @@ fn add_missing_package_on_pytorch() -> Result<()> {
 
     uv_snapshot!(context.add().arg("fakepkg"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -12561,7 +12646,7 @@ async fn add_unexpected_error_code() -> Result<()> {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -12595,7 +12680,7 @@ fn add_invalid_ignore_error_code() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12636,7 +12721,7 @@ fn add_invalid_requires_python() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12719,7 +12804,7 @@ fn add_auth_policy_always_without_credentials() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12730,7 +12815,7 @@ fn add_auth_policy_always_without_credentials() -> Result<()> {
 
     uv_snapshot!(context.pip_install().arg("black"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12765,7 +12850,7 @@ fn add_auth_policy_always_with_username_no_password() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12800,7 +12885,7 @@ fn add_auth_policy_never_with_url_credentials() -> Result<()> {
 
     uv_snapshot!(context.add().arg("anyio"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12838,7 +12923,7 @@ fn add_auth_policy_never_with_env_var_credentials() -> Result<()> {
         .env(EnvVars::UV_INDEX_MY_INDEX_USERNAME, "public")
         .env(EnvVars::UV_INDEX_MY_INDEX_PASSWORD, "heron"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -12932,7 +13017,7 @@ async fn add_redirect_cross_origin() -> Result<()> {
 
     uv_snapshot!(filters, context.add().arg("--default-index").arg(redirect_url.as_str()).arg("anyio"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -13059,7 +13144,7 @@ async fn add_redirect_with_keyring_cross_origin() -> Result<()> {
         .env(EnvVars::KEYRING_TEST_CREDENTIALS, r#"{"pypi-proxy.fly.dev": {"public": "heron"}}"#)
         .env(EnvVars::PATH, venv_bin_path(&keyring_context.venv)), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -13167,7 +13252,7 @@ fn add_ambiguous() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("anyio"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -13178,7 +13263,7 @@ fn add_ambiguous() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.add().arg("--group").arg("bar").arg("anyio"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
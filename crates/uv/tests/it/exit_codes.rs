@@ -0,0 +1,100 @@
+//! Tests for the exit codes that indicate the class of a failure (see `docs/reference/exit-codes.md`).
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use uv_static::EnvVars;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::TestContext;
+
+/// Invalid command-line arguments are a generic failure.
+#[test]
+fn exit_code_invalid_arguments() {
+    let context = TestContext::new("3.12");
+
+    context.pip_compile().arg("--not-a-flag").assert().code(1);
+}
+
+/// Unsatisfiable requirements exit with `2`.
+#[test]
+fn exit_code_no_solution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0\nwerkzeug<3")?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .assert()
+        .code(2);
+
+    Ok(())
+}
+
+/// A server error exits with `3`.
+#[tokio::test]
+async fn exit_code_network_failure() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(server.uri())
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .assert()
+        .code(3);
+}
+
+/// A distribution that doesn't match its expected hash exits with `4`.
+#[test]
+fn exit_code_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "iniconfig==2.0.0 --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc",
+    )?;
+
+    context
+        .pip_install()
+        .arg("--no-deps")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .assert()
+        .code(4);
+
+    Ok(())
+}
+
+/// A Python request that can't be satisfied exits with `5`.
+#[test]
+fn exit_code_interpreter_not_found() {
+    let context = TestContext::new("3.12");
+
+    context.tool_run().arg("python@3.12.99").assert().code(5);
+}
+
+/// An installation that the disk lacks the space for exits with `6`.
+#[test]
+fn exit_code_disk_full() {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .env(EnvVars::UV_INTERNAL__TEST_AVAILABLE_SPACE, "0")
+        .assert()
+        .code(6);
+}
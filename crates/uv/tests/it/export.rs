@@ -2507,7 +2507,7 @@ fn requirements_txt_export_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.export().arg("--all-groups").arg("--no-group").arg("baz"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2872,7 +2872,7 @@ fn requirements_txt_conflicts() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.export().arg("--extra").arg("extra1").arg("--extra").arg("extra2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4447,7 +4447,7 @@ fn pep_751_filename() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.export().arg("--format").arg("pylock.toml").arg("-o").arg("test.toml"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4591,7 +4591,7 @@ fn export_only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --extra together should error.
     uv_snapshot!(context.filters(), context.export().arg("--only-group").arg("dev").arg("--extra").arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4605,7 +4605,7 @@ fn export_only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --all-extras together should also error.
     uv_snapshot!(context.filters(), context.export().arg("--only-group").arg("dev").arg("--all-extras"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -280,7 +280,7 @@ fn format_fails_malformed_pyproject() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.format(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1,3 +1,7 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
 use uv_static::EnvVars;
 
 use crate::common::{TestContext, uv_snapshot};
@@ -24,8 +28,11 @@ fn help() {
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
+      watch                      Watch the project and re-lock it whenever its dependencies change
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a self-contained, relocatable directory
       tree                       Display the project's dependency tree
+      diff                       Compare the packages in two lockfiles or environments
       format                     Format Python code in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -34,6 +41,9 @@ fn help() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      store                      Manage the shared store of installed packages
+      wheelhouse                 Manage directories of pre-downloaded distributions
+      workspace                  Inspect the members of a workspace
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -63,8 +73,14 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -98,26 +114,32 @@ fn help_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      auth     Manage authentication
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      version  Read or update the project's version
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      format   Format Python code in the project
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      help     Display documentation for a command
+      auth        Manage authentication
+      run         Run a command or script
+      init        Create a new project
+      add         Add dependencies to the project
+      remove      Remove dependencies from the project
+      version     Read or update the project's version
+      sync        Update the project's environment
+      lock        Update the project's lockfile
+      watch       Watch the project and re-lock it whenever its dependencies change
+      export      Export the project's lockfile to an alternate format
+      bundle      Bundle the project into a self-contained, relocatable directory
+      tree        Display the project's dependency tree
+      diff        Compare the packages in two lockfiles or environments
+      format      Format Python code in the project
+      tool        Run and install commands provided by Python packages
+      python      Manage Python versions and installations
+      pip         Manage Python packages with a pip-compatible interface
+      venv        Create a virtual environment
+      build       Build Python packages into source distributions and wheels
+      publish     Upload distributions to an index
+      cache       Manage uv's cache
+      store       Manage the shared store of installed packages
+      wheelhouse  Manage directories of pre-downloaded distributions
+      workspace   Inspect the members of a workspace
+      self        Manage the uv executable
+      help        Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -144,8 +166,14 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -178,26 +206,32 @@ fn help_short_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      auth     Manage authentication
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      version  Read or update the project's version
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      format   Format Python code in the project
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      help     Display documentation for a command
+      auth        Manage authentication
+      run         Run a command or script
+      init        Create a new project
+      add         Add dependencies to the project
+      remove      Remove dependencies from the project
+      version     Read or update the project's version
+      sync        Update the project's environment
+      lock        Update the project's lockfile
+      watch       Watch the project and re-lock it whenever its dependencies change
+      export      Export the project's lockfile to an alternate format
+      bundle      Bundle the project into a self-contained, relocatable directory
+      tree        Display the project's dependency tree
+      diff        Compare the packages in two lockfiles or environments
+      format      Format Python code in the project
+      tool        Run and install commands provided by Python packages
+      python      Manage Python versions and installations
+      pip         Manage Python packages with a pip-compatible interface
+      venv        Create a virtual environment
+      build       Build Python packages into source distributions and wheels
+      publish     Upload distributions to an index
+      cache       Manage uv's cache
+      store       Manage the shared store of installed packages
+      wheelhouse  Manage directories of pre-downloaded distributions
+      workspace   Inspect the members of a workspace
+      self        Manage the uv executable
+      help        Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -224,8 +258,14 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -400,6 +440,23 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads.
+              
+              Accepts a number of bytes per second, with an optional unit and `/s` suffix (e.g.,
+              `5MB/s`, `500K`, or `1MiB/s`). The limit is shared across all concurrent downloads.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --metered
+              Treat the network connection as metered.
+              
+              On a metered connection, uv avoids large optional downloads, such as automatic downloads
+              of managed Python installations, and reports the size of any distributions before
+              downloading them.
+              
+              [env: UV_METERED=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -407,6 +464,15 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds.
+              
+              Commands that modify an environment, such as `uv sync` and `uv pip install`, hold a
+              lock on it to prevent concurrent modifications. By default, uv waits indefinitely for
+              another process to release the lock.
+              
+              [env: UV_LOCKED_TIMEOUT=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -572,6 +638,20 @@ fn help_subsubcommand() {
               
               If multiple Python versions are requested, uv will exit with an error.
 
+          --ensurepip
+              Install pip into the Python installation with `ensurepip`.
+              
+              Some distributions (e.g., PyPy) do not include pip by default.
+
+          --check-libraries
+              Check that the system libraries required by the Python installation are present.
+              
+              Some standard library modules (e.g., `tkinter`) depend on shared libraries provided by
+              the host system. If any are missing, uv will report them, along with a command to
+              install them with the system package manager, if known, and exit with an error.
+              
+              Only supported on Linux.
+
     Cache options:
       -n, --no-cache
               Avoid reading from or writing to the cache, instead using a temporary directory for the
@@ -667,6 +747,23 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads.
+              
+              Accepts a number of bytes per second, with an optional unit and `/s` suffix (e.g.,
+              `5MB/s`, `500K`, or `1MiB/s`). The limit is shared across all concurrent downloads.
+              
+              [env: UV_LIMIT_RATE=]
+
+          --metered
+              Treat the network connection as metered.
+              
+              On a metered connection, uv avoids large optional downloads, such as automatic downloads
+              of managed Python installations, and reports the size of any distributions before
+              downloading them.
+              
+              [env: UV_METERED=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -674,6 +771,15 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds.
+              
+              Commands that modify an environment, such as `uv sync` and `uv pip install`, hold a
+              lock on it to prevent concurrent modifications. By default, uv waits indefinitely for
+              another process to release the lock.
+              
+              [env: UV_LOCKED_TIMEOUT=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -768,8 +874,14 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -823,6 +935,10 @@ fn help_flag_subsubcommand() {
               Replace existing Python executables during installation
           --default
               Use as the default Python version
+          --ensurepip
+              Install pip into the Python installation with `ensurepip`
+          --check-libraries
+              Check that the system libraries required by the Python installation are present
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -849,8 +965,14 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -872,7 +994,7 @@ fn help_unknown_subcommand() {
 
     uv_snapshot!(context.filters(), context.help().arg("foobar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -901,7 +1023,7 @@ fn help_unknown_subcommand() {
 
     uv_snapshot!(context.filters(), context.help().arg("foo").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -935,7 +1057,7 @@ fn help_unknown_subsubcommand() {
 
     uv_snapshot!(context.filters(), context.help().arg("python").arg("foobar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -972,8 +1094,11 @@ fn help_with_global_option() {
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
+      watch                      Watch the project and re-lock it whenever its dependencies change
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a self-contained, relocatable directory
       tree                       Display the project's dependency tree
+      diff                       Compare the packages in two lockfiles or environments
       format                     Format Python code in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -982,6 +1107,9 @@ fn help_with_global_option() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      store                      Manage the shared store of installed packages
+      wheelhouse                 Manage directories of pre-downloaded distributions
+      workspace                  Inspect the members of a workspace
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1011,8 +1139,14 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1058,7 +1192,7 @@ fn help_with_version() {
 
     uv_snapshot!(context.filters(), context.help().arg("--version"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1095,8 +1229,11 @@ fn help_with_no_pager() {
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
+      watch                      Watch the project and re-lock it whenever its dependencies change
       export                     Export the project's lockfile to an alternate format
+      bundle                     Bundle the project into a self-contained, relocatable directory
       tree                       Display the project's dependency tree
+      diff                       Compare the packages in two lockfiles or environments
       format                     Format Python code in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
@@ -1105,6 +1242,9 @@ fn help_with_no_pager() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      store                      Manage the shared store of installed packages
+      wheelhouse                 Manage directories of pre-downloaded distributions
+      workspace                  Inspect the members of a workspace
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1134,8 +1274,14 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --limit-rate <RATE>
+              Limit the network bandwidth used for downloads [env: UV_LIMIT_RATE=]
+          --metered
+              Treat the network connection as metered [env: UV_METERED=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --locked-timeout <SECONDS>
+              The time to wait for an environment lock, in seconds [env: UV_LOCKED_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1155,3 +1301,79 @@ fn help_with_no_pager() {
     ----- stderr -----
     "#);
 }
+
+/// Commands that never consult the project should skip workspace discovery, and so shouldn't fail
+/// on invalid project configuration.
+#[test]
+fn help_ignores_project_configuration() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str("[pip")?;
+
+    context
+        .help()
+        .arg("--no-pager")
+        .current_dir(context.temp_dir.path())
+        .assert()
+        .success();
+
+    context
+        .command()
+        .arg("generate-shell-completion")
+        .arg("bash")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Commands that only read configuration should skip workspace discovery, but still respect the
+/// nearest configuration file.
+#[test]
+fn read_only_commands_skip_workspace_discovery() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+    "#})?;
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("cache-keys = [{ file = \"pyproject.toml\" }]")?;
+
+    let output = context
+        .command()
+        .arg("cache")
+        .arg("dir")
+        .arg("--verbose")
+        .current_dir(context.temp_dir.path())
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!stderr.contains("Found workspace root"), "{stderr}");
+    assert!(
+        stderr.contains("Found workspace configuration at"),
+        "{stderr}"
+    );
+
+    // `uv version` discovers the project itself, but not again when loading the configuration.
+    let output = context.version().arg("--verbose").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(
+        stderr.matches("Found workspace root").count(),
+        1,
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Found workspace configuration at"),
+        "{stderr}"
+    );
+
+    Ok(())
+}
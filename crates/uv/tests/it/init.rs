@@ -6,6 +6,7 @@ use assert_fs::prelude::*;
 use indoc::indoc;
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
+use url::Url;
 
 use uv_static::EnvVars;
 
@@ -807,7 +808,7 @@ fn init_script_file_conflicts() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--script").arg("name_conflict.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -988,7 +989,7 @@ fn init_library_no_package() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--lib").arg("--no-package"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2655,7 +2656,7 @@ fn init_hidden() {
 
     uv_snapshot!(context.filters(), context.init().arg(".foo"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2674,7 +2675,7 @@ fn init_failure() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.init().arg("foo"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2716,7 +2717,7 @@ fn init_failure_with_invalid_option_named_backend() {
     let context = TestContext::new("3.12");
     uv_snapshot!(context.filters(), context.init().arg("foo").arg("--backend"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2730,7 +2731,7 @@ fn init_failure_with_invalid_option_named_backend() {
     "###);
     uv_snapshot!(context.filters(), context.init().arg("foo").arg("--backend").arg("maturin"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2862,7 +2863,7 @@ fn init_git_not_installed() {
     // Set `PATH` to child to make `git` command cannot be found.
     uv_snapshot!(context.filters(), context.init().env(EnvVars::PATH, &*child).arg(child.as_ref()).arg("--vcs").arg("git"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4015,7 +4016,7 @@ fn git_states() {
         .arg("--vcs")
         .arg("git"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4025,3 +4026,214 @@ fn git_states() {
     ");
     assert!(!context.temp_dir.child("broken-git/.git").is_dir());
 }
+
+/// Initialize a command-line application from the built-in `cli` template.
+#[test]
+fn init_template_cli() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("cli").arg("--no-lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    "###);
+
+    let pyproject = context.read("foo/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        foo = "foo:main"
+
+        [build-system]
+        requires = ["uv_build>=[CURRENT_VERSION],<[NEXT_BREAKING]"]
+        build-backend = "uv_build"
+        "#
+        );
+    });
+
+    context
+        .temp_dir
+        .child("foo/src/foo/__init__.py")
+        .assert(predicate::path::is_file());
+    context
+        .temp_dir
+        .child("foo/uv.lock")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Projects created from a template are locked, unless `--no-lock` is provided.
+#[test]
+fn init_template_lock() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("lib"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    Resolved 1 package in [TIME]
+    "###);
+
+    context
+        .temp_dir
+        .child("foo/src/foo/py.typed")
+        .assert(predicate::path::is_file());
+    context
+        .temp_dir
+        .child("foo/uv.lock")
+        .assert(predicate::path::is_file());
+
+    // `--no-lock` requires `--template`.
+    uv_snapshot!(context.filters(), context.init().arg("bar").arg("--no-lock"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --template <TEMPLATE>
+
+    Usage: uv init --no-lock --template <TEMPLATE> [PATH]
+
+    For more information, try '--help'.
+    "###);
+}
+
+#[test]
+fn init_template_invalid() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("django"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'django' for '--template <TEMPLATE>': `django` is not a built-in template (`lib`, `app`, `cli`, or `maturin`) or a Git URL
+
+    For more information, try '--help'.
+    "###);
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("lib").arg("--app"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--template <TEMPLATE>' cannot be used with '--app'
+
+    Usage: uv init --template <TEMPLATE> [PATH]
+
+    For more information, try '--help'.
+    "###);
+}
+
+/// Initialize a project from a template in a Git repository.
+#[test]
+#[cfg(feature = "git")]
+fn init_template_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a template repository.
+    let template = context.temp_dir.child("template");
+    template.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "{{ project_name }}"
+        version = "0.1.0"
+        requires-python = "{{ requires_python }}"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10"]
+        build-backend = "uv_build"
+    "#})?;
+    template
+        .child("src/{{ module_name }}/__init__.py")
+        .write_str("NAME = \"{{ project_name }}\"\n")?;
+    template.child(".gitignore").write_str("*.log\n")?;
+    Command::new("git")
+        .arg("init")
+        .current_dir(&template)
+        .assert()
+        .success();
+    Command::new("git")
+        .args(["add", "."])
+        .current_dir(&template)
+        .assert()
+        .success();
+    Command::new("git")
+        .args([
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "Initial commit",
+        ])
+        .current_dir(&template)
+        .assert()
+        .success();
+
+    let url = Url::from_directory_path(template.path()).unwrap();
+    uv_snapshot!(context.filters(), context.init().arg("my-app").arg("--template").arg(url.as_str()).arg("--no-lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `my-app` at `[TEMP_DIR]/my-app`
+    "###);
+
+    let pyproject = context.read("my-app/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "my-app"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10"]
+        build-backend = "uv_build"
+        "#
+        );
+    });
+
+    let init = context.read("my-app/src/my_app/__init__.py");
+    assert_snapshot!(init, @r#"NAME = "my-app""#);
+
+    // The template's `.gitignore` takes precedence over the default.
+    let gitignore = context.read("my-app/.gitignore");
+    assert_snapshot!(gitignore, @"*.log");
+
+    context
+        .temp_dir
+        .child("my-app/.git")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
@@ -3,8 +3,11 @@ use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
+use serde_json::json;
 use std::io::BufReader;
 use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[cfg(feature = "git")]
 use crate::common::{READ_ONLY_GITHUB_TOKEN, decode_token};
@@ -1066,7 +1069,7 @@ fn lock_wheel_url() -> Result<()> {
     // Re-run with `--offline`. This should fail: we need network access to resolve mutable metadata.
     uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1716,6 +1719,164 @@ fn lock_project_with_overrides() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with a scoped override in `uv.tool.override-dependencies`.
+#[test]
+fn lock_project_with_scoped_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["flask==3.0.0"]
+
+        [tool.uv]
+        override-dependencies = ["werkzeug==2.3.8 ; when = 'flask'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    ");
+
+    // The scoped override is recorded in the lockfile manifest.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(indoc! {r#"
+        [manifest.scoped-overrides]
+        flask = [{ name = "werkzeug", specifier = "==2.3.8" }]
+    "#}));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    ");
+
+    // Changing the scope of the override invalidates the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["flask==3.0.0"]
+
+        [tool.uv]
+        override-dependencies = ["werkzeug==2.3.8 ; when = 'jinja2'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // Since `jinja2` doesn't depend on `werkzeug`, the override no longer applies.
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    Updated werkzeug v2.3.8 -> v3.0.1
+    ");
+
+    Ok(())
+}
+
+/// Lock and sync a project with a resolution profile from `tool.uv.profiles`.
+#[test]
+fn lock_project_with_profile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.profiles.legacy]
+        override-dependencies = ["iniconfig==1.1.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The lockfile doesn't reflect the profile.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile").arg("legacy").arg("--locked"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // Sync with the profile, which should apply its overrides.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROFILE, "legacy"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    ");
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile").arg("legacy").arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Request a profile that doesn't exist.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile").arg("missing"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Profile `missing` is not defined in `tool.uv.profiles`
+    ");
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.override-dependencies` that reference `tool.uv.sources`.
 #[test]
 fn lock_project_with_override_sources() -> Result<()> {
@@ -2463,7 +2624,7 @@ fn lock_conditional_dependency_extra() -> Result<()> {
     // // Re-run with `--locked`.
     // uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     // success: false
-    // exit_code: 2
+    // exit_code: 1
     // ----- stdout -----
     //
     // ----- stderr -----
@@ -2734,7 +2895,7 @@ fn lock_conflicting_project_basic1() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2868,7 +3029,7 @@ fn lock_conflicting_project_basic1() -> Result<()> {
     // should fail because it conflicts with the project.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3056,7 +3217,7 @@ fn lock_conflicting_workspace_members() -> Result<()> {
     // Attempt to install them together, i.e., with `--all-packages`
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--all-packages"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3126,7 +3287,7 @@ fn lock_conflicting_workspace_members_depends_direct() -> Result<()> {
     // This should fail to resolve, because these conflict
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3302,7 +3463,7 @@ fn lock_conflicting_workspace_members_depends_direct_extra() -> Result<()> {
     // Attempt to install with the extra selected
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3423,7 +3584,7 @@ fn lock_conflicting_workspace_members_depends_transitive() -> Result<()> {
     // This should fail to resolve, because these conflict
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3621,7 +3782,7 @@ fn lock_conflicting_workspace_members_depends_transitive_extra() -> Result<()> {
     // Install from the lockfile
     uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3815,7 +3976,7 @@ fn lock_conflicting_project_basic2() -> Result<()> {
     // should fail because it conflicts with the project.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3870,7 +4031,7 @@ fn lock_conflicting_mixed() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4029,7 +4190,7 @@ fn lock_conflicting_mixed() -> Result<()> {
     // And finally, installing both the group and the extra should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=project1").arg("--extra=project2"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5006,7 +5167,7 @@ fn lock_requires_python() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -5659,7 +5820,7 @@ fn lock_requires_python() -> Result<()> {
     // Note we need to disable Python fetches or we'll just download 3.12
     uv_snapshot!(context_unsupported.filters(), context_unsupported.sync().arg("--frozen").arg("--no-python-downloads"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -6667,7 +6828,7 @@ fn lock_requires_python_disjoint() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7459,30 +7620,65 @@ fn lock_relative_and_absolute_paths() -> Result<()> {
     Ok(())
 }
 
-/// Lock a project that includes cyclic dependencies.
+/// Lock path sources that reference variables, which should be preserved in the lockfile.
 #[test]
-fn lock_cycles() -> Result<()> {
+fn lock_path_source_env_vars() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
+    pyproject_toml.write_str(indoc! {r#"
         [project]
-        name = "project"
+        name = "a"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["testtools==2.3.0", "fixtures==3.0.0"]
-        "#,
-    )?;
+        requires-python = ">=3.11,<3.13"
+        dependencies = ["b", "c"]
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+        [tool.uv.sources]
+        b = { path = "${WORKSPACE_ROOT}/b" }
+        c = { path = "${SHARED_DIR}/c" }
+        "#
+    })?;
+    context
+        .temp_dir
+        .child("b/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "b"
+        version = "0.1.0"
+        dependencies = []
+        requires-python = ">=3.11,<3.13"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context.temp_dir.child("b/b/__init__.py").touch()?;
+    context
+        .temp_dir
+        .child("shared/c/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "c"
+        version = "0.1.0"
+        dependencies = []
+        requires-python = ">=3.11,<3.13"
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+    context.temp_dir.child("shared/c/c/__init__.py").touch()?;
+
+    let shared_dir = context.temp_dir.child("shared");
+
+    uv_snapshot!(context.filters(), context.lock().env("SHARED_DIR", shared_dir.as_os_str()), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 11 packages in [TIME]
-    ");
+    Resolved 3 packages in [TIME]
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -7493,52 +7689,145 @@ fn lock_cycles() -> Result<()> {
             lock, @r#"
         version = 1
         revision = 3
-        requires-python = ">=3.12"
+        requires-python = ">=3.11, <3.13"
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
-        name = "argparse"
-        version = "1.4.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/18/dd/e617cfc3f6210ae183374cd9f6a26b20514bbb5a792af97949c5aacddf0f/argparse-1.4.0.tar.gz", hash = "sha256:62b089a55be1d8949cd2bc7e0df0bddb9e028faefc8c32038cc84862aefdd6e4", size = 70508, upload-time = "2015-09-12T20:22:16.217Z" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/f2/94/3af39d34be01a24a6e65433d19e107099374224905f1e0cc6bbe1fd22a2f/argparse-1.4.0-py2.py3-none-any.whl", hash = "sha256:c31647edb69fd3d465a847ea3157d37bed1f95f19760b11a47aa91c04b666314", size = 23000, upload-time = "2015-09-14T16:03:16.137Z" },
+        name = "a"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "b" },
+            { name = "c" },
         ]
 
-        [[package]]
-        name = "extras"
-        version = "1.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/be/18/0b7283f0ebf6ad4bb6b9937538495eadf05ef097b102946b9445c4242636/extras-1.0.0.tar.gz", hash = "sha256:132e36de10b9c91d5d4cc620160a476e0468a88f16c9431817a6729611a81b4e", size = 6759, upload-time = "2016-05-18T09:18:31.4Z" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/03/e9/e915af1f97914cd0bc021e125fd1bfd4106de614a275e4b6866dd9a209ac/extras-1.0.0-py2.py3-none-any.whl", hash = "sha256:f689f08df47e2decf76aa6208c081306e7bd472630eb1ec8a875c67de2366e87", size = 7279, upload-time = "2016-05-18T09:18:16.376Z" },
+        [package.metadata]
+        requires-dist = [
+            { name = "b", directory = "${WORKSPACE_ROOT}/b" },
+            { name = "c", directory = "${SHARED_DIR}/c" },
         ]
 
         [[package]]
-        name = "fixtures"
-        version = "3.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        dependencies = [
-            { name = "pbr" },
-            { name = "six" },
-            { name = "testtools" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/84/be/94ecbc3f2487bd14aa8b44852f498086219b7cc0c8250ee65a03e2c63119/fixtures-3.0.0.tar.gz", hash = "sha256:fcf0d60234f1544da717a9738325812de1f42c2fa085e2d9252d8fff5712b2ef", size = 56629, upload-time = "2016-05-19T23:03:34.618Z" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/a8/28/7eed6bf76792f418029a18d5b2ace87ce7562927cdd00f1cefe481cd148f/fixtures-3.0.0-py2.py3-none-any.whl", hash = "sha256:2a551b0421101de112d9497fb5f6fd25e5019391c0fbec9bad591ecae981420d", size = 67478, upload-time = "2016-05-19T23:03:17.072Z" },
-        ]
+        name = "b"
+        version = "0.1.0"
+        source = { directory = "${WORKSPACE_ROOT}/b" }
 
         [[package]]
-        name = "linecache2"
-        version = "1.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/44/b0/963c352372c242f9e40db02bbc6a39ae51bde15dddee8523fe4aca94a97e/linecache2-1.0.0.tar.gz", hash = "sha256:4b26ff4e7110db76eeb6f5a7b64a82623839d595c2038eeda662f2a2db78e97c", size = 11013, upload-time = "2015-03-06T01:33:30.161Z" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/c7/a3/c5da2a44c85bfbb6eebcfc1dde24933f8704441b98fdde6528f4831757a6/linecache2-1.0.0-py2.py3-none-any.whl", hash = "sha256:e78be9c0a0dfcbac712fe04fbf92b96cddae80b1b842f24248214c8496f006ef", size = 12967, upload-time = "2015-03-06T01:33:36.657Z" },
-        ]
-
+        name = "c"
+        version = "0.1.0"
+        source = { directory = "${SHARED_DIR}/c" }
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").env("SHARED_DIR", shared_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Install from the lockfile, expanding the variables.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").env("SHARED_DIR", shared_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + b==0.1.0 (from file://[TEMP_DIR]/b)
+     + c==0.1.0 (from file://[TEMP_DIR]/shared/c)
+    "###);
+
+    Ok(())
+}
+
+/// Lock a project that includes cyclic dependencies.
+#[test]
+fn lock_cycles() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["testtools==2.3.0", "fixtures==3.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "argparse"
+        version = "1.4.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/18/dd/e617cfc3f6210ae183374cd9f6a26b20514bbb5a792af97949c5aacddf0f/argparse-1.4.0.tar.gz", hash = "sha256:62b089a55be1d8949cd2bc7e0df0bddb9e028faefc8c32038cc84862aefdd6e4", size = 70508, upload-time = "2015-09-12T20:22:16.217Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/f2/94/3af39d34be01a24a6e65433d19e107099374224905f1e0cc6bbe1fd22a2f/argparse-1.4.0-py2.py3-none-any.whl", hash = "sha256:c31647edb69fd3d465a847ea3157d37bed1f95f19760b11a47aa91c04b666314", size = 23000, upload-time = "2015-09-14T16:03:16.137Z" },
+        ]
+
+        [[package]]
+        name = "extras"
+        version = "1.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/be/18/0b7283f0ebf6ad4bb6b9937538495eadf05ef097b102946b9445c4242636/extras-1.0.0.tar.gz", hash = "sha256:132e36de10b9c91d5d4cc620160a476e0468a88f16c9431817a6729611a81b4e", size = 6759, upload-time = "2016-05-18T09:18:31.4Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/03/e9/e915af1f97914cd0bc021e125fd1bfd4106de614a275e4b6866dd9a209ac/extras-1.0.0-py2.py3-none-any.whl", hash = "sha256:f689f08df47e2decf76aa6208c081306e7bd472630eb1ec8a875c67de2366e87", size = 7279, upload-time = "2016-05-18T09:18:16.376Z" },
+        ]
+
+        [[package]]
+        name = "fixtures"
+        version = "3.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "pbr" },
+            { name = "six" },
+            { name = "testtools" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/84/be/94ecbc3f2487bd14aa8b44852f498086219b7cc0c8250ee65a03e2c63119/fixtures-3.0.0.tar.gz", hash = "sha256:fcf0d60234f1544da717a9738325812de1f42c2fa085e2d9252d8fff5712b2ef", size = 56629, upload-time = "2016-05-19T23:03:34.618Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/a8/28/7eed6bf76792f418029a18d5b2ace87ce7562927cdd00f1cefe481cd148f/fixtures-3.0.0-py2.py3-none-any.whl", hash = "sha256:2a551b0421101de112d9497fb5f6fd25e5019391c0fbec9bad591ecae981420d", size = 67478, upload-time = "2016-05-19T23:03:17.072Z" },
+        ]
+
+        [[package]]
+        name = "linecache2"
+        version = "1.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/44/b0/963c352372c242f9e40db02bbc6a39ae51bde15dddee8523fe4aca94a97e/linecache2-1.0.0.tar.gz", hash = "sha256:4b26ff4e7110db76eeb6f5a7b64a82623839d595c2038eeda662f2a2db78e97c", size = 11013, upload-time = "2015-03-06T01:33:30.161Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c7/a3/c5da2a44c85bfbb6eebcfc1dde24933f8704441b98fdde6528f4831757a6/linecache2-1.0.0-py2.py3-none-any.whl", hash = "sha256:e78be9c0a0dfcbac712fe04fbf92b96cddae80b1b842f24248214c8496f006ef", size = 12967, upload-time = "2015-03-06T01:33:36.657Z" },
+        ]
+
         [[package]]
         name = "pbr"
         version = "6.0.0"
@@ -8016,7 +8305,7 @@ fn lock_invalid_hash() -> Result<()> {
     // Install from the lockfile.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -8233,7 +8522,7 @@ fn lock_requires_python_no_wheels() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -8561,7 +8850,7 @@ fn lock_exclusion() -> Result<()> {
     // Re-run with `--locked`.
     uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8660,7 +8949,7 @@ fn lock_relative_lock_deserialization() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().current_dir(&child), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8873,7 +9162,7 @@ fn lock_peer_member() -> Result<()> {
     // Re-run with `--locked`.
     uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8934,7 +9223,7 @@ fn lock_index_workspace_member() -> Result<()> {
     // Locking without the necessary credentials should fail.
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -9190,7 +9479,7 @@ fn lock_dev_transitive() -> Result<()> {
     // Re-run with `--locked`.
     uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -9851,7 +10140,7 @@ fn lock_env_credentials() -> Result<()> {
     // Without credentials, the resolution should fail.
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -13098,7 +13387,7 @@ fn unconditional_overlapping_marker_disjoint_version_constraints() -> Result<()>
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -13128,7 +13417,7 @@ fn check_no_lock() -> Result<()> {
     uv_snapshot!(context.filters(), context.lock()
         .arg("--check"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -13811,7 +14100,7 @@ fn lock_add_member_with_build_system() -> Result<()> {
     // Re-run with `--offline`. This should also fail, during the resolve phase.
     uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -14019,7 +14308,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
     // Re-run with `--offline`. This should also fail, during the resolve phase.
     uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -16224,7 +16513,7 @@ fn lock_overlapping_environment() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -16236,6 +16525,115 @@ fn lock_overlapping_environment() -> Result<()> {
     Ok(())
 }
 
+/// Lock with user-provided assumptions about the supported environments, which are omitted from
+/// the markers in the lockfile.
+#[test]
+fn lock_environment_assumptions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; sys_platform == 'linux'", "colorama ; sys_platform == 'win32'"]
+
+        [tool.uv]
+        environment-assumptions = ["sys_platform == 'linux'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    // Because we're assuming Linux, `colorama` should not be included, and `iniconfig` should be
+    // included without a marker.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        assumed-markers = "sys_platform == 'linux'"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "iniconfig", marker = "sys_platform == 'linux'" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Supported environments that contradict the assumptions are rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig ; sys_platform == 'linux'", "colorama ; sys_platform == 'win32'"]
+
+        [tool.uv]
+        environments = ["sys_platform == 'win32'"]
+        environment-assumptions = ["sys_platform == 'linux'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Environment markers `sys_platform == 'win32'` don't overlap with the environment assumptions `sys_platform == 'linux'`
+    ");
+
+    Ok(())
+}
+
 /// Lock a (legacy) non-project workspace root with forked dev dependencies.
 #[test]
 fn lock_non_project_fork() -> Result<()> {
@@ -17482,7 +17880,7 @@ fn lock_invalid_index() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -17699,7 +18097,7 @@ fn lock_explicit_default_index() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--verbose"), @r#"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -17933,7 +18331,7 @@ fn lock_default_index() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -18097,7 +18495,7 @@ fn lock_repeat_named_index() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -19179,7 +19577,7 @@ fn lock_conflicting_environment() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20246,7 +20644,7 @@ fn lock_request_requires_python() -> Result<()> {
     // Request a version that conflicts with `--requires-python`.
     uv_snapshot!(context.filters(), context.lock().arg("--python").arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20260,7 +20658,7 @@ fn lock_request_requires_python() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20292,7 +20690,7 @@ fn lock_duplicate_sources() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20327,7 +20725,7 @@ fn lock_duplicate_sources() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20404,7 +20802,7 @@ fn lock_missing_name() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20435,7 +20833,7 @@ fn lock_missing_version() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20498,7 +20896,7 @@ fn lock_unsupported_version() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--frozen"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20529,7 +20927,7 @@ fn lock_unsupported_version() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--frozen"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -20918,7 +21316,7 @@ fn lock_keyring_explicit_always() -> Result<()> {
         .env(EnvVars::KEYRING_TEST_CREDENTIALS, r#"{"pypi-proxy.fly.dev": {"public": "frog"}}"#)
         .env(EnvVars::PATH, venv_bin_path(&keyring_context.venv)), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -21096,7 +21494,7 @@ fn lock_keyring_credentials_always_authenticate_unsupported_mode() -> Result<()>
         .env(EnvVars::KEYRING_TEST_CREDENTIALS, r#"{"pypi-proxy.fly.dev": {"public": "heron"}}"#)
         .env(EnvVars::PATH, venv_bin_path(&keyring_context.venv)), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -21230,7 +21628,7 @@ fn lock_multiple_sources_conflict() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -21270,7 +21668,7 @@ fn lock_multiple_sources_no_marker() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -22899,7 +23297,7 @@ fn lock_group_requires_undefined_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -22933,7 +23331,7 @@ fn lock_group_requires_dev_dep() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23089,7 +23487,7 @@ fn lock_group_include_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23123,7 +23521,7 @@ fn lock_group_include_dev() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23155,7 +23553,7 @@ fn lock_group_include_missing() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23186,7 +23584,7 @@ fn lock_group_invalid_entry_package() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23199,7 +23597,7 @@ fn lock_group_invalid_entry_package() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("foo"), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23233,7 +23631,7 @@ fn lock_group_invalid_entry_group_name() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23270,7 +23668,7 @@ fn lock_group_invalid_duplicate_group_name() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23305,7 +23703,7 @@ fn lock_group_invalid_entry_table() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23336,7 +23734,7 @@ fn lock_group_invalid_entry_type() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -23372,7 +23770,7 @@ fn lock_group_empty_entry_table() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -26350,7 +26748,7 @@ fn lock_self_incompatible() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -26487,7 +26885,7 @@ fn lock_self_extra_to_same_extra_incompatible() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -26521,7 +26919,7 @@ fn lock_self_extra_to_other_extra_incompatible() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -26658,7 +27056,7 @@ fn lock_self_extra_incompatible() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -26788,7 +27186,7 @@ fn lock_self_marker_incompatible() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -27408,7 +27806,7 @@ fn lock_script_initialize() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -29849,6 +30247,117 @@ fn lock_invalid_fork_markers() -> Result<()> {
     Ok(())
 }
 
+/// If the forks in an existing lockfile make the resolution unsatisfiable, retry without them.
+#[test]
+fn lock_unsatisfiable_fork_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+    "#})?;
+
+    // The dependency only supports Python 3.12, so it's unavailable in the `>= '3.13'` fork.
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12,<3.13"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "python_full_version >= '3.13'",
+            "python_full_version < '3.13'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+    "#})?;
+
+    let output = context.lock().arg("--verbose").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains(
+        "Failed to resolve with the forks from the existing lockfile; falling back to a full resolution without them"
+    ));
+
+    // The new lockfile shouldn't preserve the forks.
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("resolution-markers"));
+    assert!(lock.contains(r#"name = "child""#));
+
+    // The lockfile should be up-to-date.
+    context.lock().arg("--locked").assert().success();
+
+    Ok(())
+}
+
+/// The `torch-backend` setting is experimental.
+#[test]
+fn lock_torch_backend_preview() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        torch-backend = "cpu"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `torch-backend` option is experimental and may change without warning. Pass `--preview-features torch-backend` to disable this warning.
+    Resolved 2 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("torch-backend"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_omit_wheels_exclude_newer() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-08-01T00:00:00Z");
@@ -29960,7 +30469,7 @@ fn lock_conflict_for_disjoint_python_version() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -30214,7 +30723,7 @@ fn lock_conflict_for_disjoint_platform() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -30830,7 +31339,7 @@ fn lock_prefix_match() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -31330,6 +31839,116 @@ fn lock_path_dependency_no_index() -> Result<()> {
     Ok(())
 }
 
+/// Enable extras on a path dependency via its source, rather than the dependency specifier.
+#[test]
+fn lock_path_source_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child", extras = ["cli"], editable = true }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { editable = "child" }
+
+        [package.optional-dependencies]
+        cli = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", marker = "extra == 'cli'" }]
+        provides-extras = ["cli"]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child", extra = ["cli"] },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", extras = ["cli"], editable = "child" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Test that a nested path dependency with an explicit index validates correctly.
 #[test]
 fn lock_nested_path_dependency_explicit_index() -> Result<()> {
@@ -31700,3 +32319,927 @@ fn lock_required_intersection() -> Result<()> {
 
     Ok(())
 }
+
+/// Merge two lockfiles with `--merge`, then resolve the conflicting packages with
+/// `--resolve-merge-conflicts`.
+#[test]
+fn lock_merge_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_dependencies = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {
+            r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+            "#,
+        })
+    };
+
+    // Lock the common ancestor, and both sides of the merge.
+    write_dependencies(r#""iniconfig""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("base.lock"),
+    )?;
+
+    write_dependencies(r#""iniconfig", "typing-extensions""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("current.lock"),
+    )?;
+
+    write_dependencies(r#""iniconfig", "sniffio""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("other.lock"),
+    )?;
+
+    // Both sides changed the dependencies of the project itself, so the merge should fail.
+    uv_snapshot!(context.filters(), context.lock().arg("--merge").arg("current.lock").arg("base.lock").arg("other.lock"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Conflicting changes to: project
+    hint: Run `uv lock --resolve-merge-conflicts` after merging to re-resolve the conflicting packages
+    ");
+
+    let merged = context.read("current.lock");
+    assert!(merged.starts_with("<<<<<<< ours\n"));
+    assert!(merged.ends_with(">>>>>>> theirs\n"));
+
+    // Resolve the conflicts against the merged project.
+    write_dependencies(r#""iniconfig", "sniffio", "typing-extensions""#)?;
+    fs_err::copy(
+        context.temp_dir.join("current.lock"),
+        context.temp_dir.join("uv.lock"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolve-merge-conflicts"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving conflicting changes to: project
+    Resolved 4 packages in [TIME]
+    ");
+
+    // The lockfile should be up-to-date, and retain the versions from both sides.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("<<<<<<<"));
+    assert!(lock.contains(r#"name = "sniffio""#));
+    assert!(lock.contains(r#"name = "typing-extensions""#));
+
+    Ok(())
+}
+
+/// If the two sides of a conflicted lockfile can't be merged, `--resolve-merge-conflicts`
+/// re-resolves every package, rather than failing.
+#[test]
+fn lock_merge_conflicts_unmergeable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_dependencies = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {
+            r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+            "#,
+        })
+    };
+
+    write_dependencies(r#""iniconfig""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("base.lock"),
+    )?;
+
+    write_dependencies(r#""iniconfig", "typing-extensions""#)?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("current.lock"),
+    )?;
+
+    // Their side removes `iniconfig`, which our side of the project still depends on.
+    write_dependencies("")?;
+    context.lock().assert().success();
+    fs_err::copy(
+        context.temp_dir.join("uv.lock"),
+        context.temp_dir.join("other.lock"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--merge").arg("current.lock").arg("base.lock").arg("other.lock"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Conflicting changes to the lockfile
+    hint: Run `uv lock --resolve-merge-conflicts` after merging to re-resolve the conflicting packages
+    ");
+
+    write_dependencies(r#""iniconfig", "typing-extensions""#)?;
+    fs_err::copy(
+        context.temp_dir.join("current.lock"),
+        context.temp_dir.join("uv.lock"),
+    )?;
+
+    let output = context.lock().arg("--resolve-merge-conflicts").output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains(
+        "warning: Failed to merge conflicting changes to the lockfile; re-resolving all packages"
+    ));
+
+    // The lockfile should be up-to-date.
+    context.lock().arg("--locked").assert().success();
+
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("<<<<<<<"));
+    assert!(lock.contains(r#"name = "iniconfig""#));
+    assert!(lock.contains(r#"name = "typing-extensions""#));
+
+    Ok(())
+}
+
+/// A plugin can veto a resolution before the lockfile is written.
+#[test]
+#[cfg(unix)]
+fn lock_plugin_veto() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.plugins]
+        policy = { command = ["sh", "-c", "grep -q '\"name\":\"iniconfig\"' && echo 'iniconfig is not allowed' >&2 && exit 1; exit 0"], events = ["resolve"] }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: Plugin `policy` rejected the `resolve` event
+      Caused by: iniconfig is not allowed
+    ");
+
+    // The lockfile should not be written.
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::missing());
+
+    // Plugins that only handle other events are not invoked.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.plugins]
+        policy = { command = ["sh", "-c", "exit 1"], events = ["install"] }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// A plugin can reject individual candidate versions during resolution.
+#[test]
+#[cfg(unix)]
+fn lock_plugin_candidates() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The plugin responds to each package's candidates with a line of JSON, and records each time
+    // it's started.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0", "iniconfig"]
+
+        [tool.uv.plugins]
+        policy = { command = ["sh", "-c", "echo started >> starts.log; while read -r line; do case \"$line\" in *'\"package\":\"iniconfig\"'*) echo '{\"reject\": [\"2.0.0\"]}' ;; *) echo '{}' ;; esac; done"], events = ["candidates"] }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // The rejected version should be skipped in favor of the previous release.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"version = "1.1.1""#));
+    assert!(!lock.contains(r#"version = "2.0.0""#));
+
+    // The plugin should be started once for the resolution, rather than once per package.
+    assert_eq!(context.read("starts.log"), "started\n");
+
+    // A plugin that fails aborts the resolution.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.plugins]
+        policy = { command = ["sh", "-c", "echo 'policy server is unavailable' >&2; exit 1"], events = ["candidates"] }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to filter the candidate versions of `iniconfig`
+      Caused by: Plugin `policy` rejected the `candidates` event
+      Caused by: policy server is unavailable
+    ");
+
+    Ok(())
+}
+
+/// Lock with a minimum release age, overridden for a specific package.
+#[test]
+fn lock_exclude_newer_than_days() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        exclude-newer-than-days = 36500
+        "#,
+    )?;
+
+    // No versions of `iniconfig` were published more than a century ago.
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    // Exempt `iniconfig` from the minimum release age; the global `exclude-newer` still applies.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        exclude-newer-than-days = 36500
+        exclude-newer-than-days-package = { iniconfig = 0 }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("exclude-newer-than-days = 36500\n"));
+    assert!(lock.contains("[options.exclude-newer-than-days-package]\niniconfig = 0\n"));
+    assert!(lock.contains("version = \"2.0.0\""));
+
+    // The minimum release age is recorded in the lockfile, so the lockfile remains valid.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Pin packages matching a pattern to a named index, and verify the pin when syncing.
+#[test]
+fn lock_index_namespaces() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        index-namespaces = { "ini*" = "internal" }
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Point the pinned index elsewhere; the locked `iniconfig` should be rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        index-namespaces = { "ini*" = "internal" }
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://test.pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` is locked to index `https://pypi.org/simple`, but packages matching the index namespace `ini*` must come from index `internal`
+    ");
+
+    // Pin `iniconfig` to an index that isn't defined.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        index-namespaces = { "iniconfig" = "missing" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` matches the index namespace `iniconfig`, which is pinned to index `missing`, but no index named `missing` is defined
+    ");
+
+    Ok(())
+}
+
+/// Packages matching an index namespace can't come from a direct URL or a `--find-links` entry,
+/// neither when resolving nor when syncing.
+#[test]
+fn lock_index_namespaces_sources() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"]
+
+        [tool.uv]
+        index-namespaces = { "ini*" = "internal" }
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` matches the index namespace `ini*`, which is pinned to index `internal`, but was requested from: https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl
+    ");
+
+    // Lock without the namespace, then add it; the locked direct URL should be rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"]
+
+        [tool.uv]
+        index-namespaces = { "ini*" = "internal" }
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` is locked to a source other than an index, but packages matching the index namespace `ini*` must come from index `internal`
+    ");
+
+    // `tqdm==1000.0.0` is only available from the `--find-links` entries, which are ignored for
+    // packages matching a namespace.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm==1000.0.0"]
+
+        [tool.uv]
+        index-namespaces = { "tqdm" = "internal" }
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links")), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of tqdm==1000.0.0 and your project depends on tqdm==1000.0.0, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// Resolve as of a historical date, recording the cutoff in the lockfile.
+#[test]
+fn lock_as_of() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--as-of").arg("2022-01-01T00:00:00Z"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // `iniconfig==2.0.0` was released in 2023.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("as-of = \"2022-01-01T00:00:00Z\"\n"));
+    assert!(!lock.contains("exclude-newer = "));
+    assert!(lock.contains("version = \"1.1.1\""));
+
+    // The lockfile remains valid when resolving as of the same date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--as-of").arg("2022-01-01T00:00:00Z"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The recorded date is retained when no cutoff is provided, so a plain `uv sync` doesn't
+    // re-resolve.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("as-of = \"2022-01-01T00:00:00Z\"\n"));
+    assert!(lock.contains("version = \"1.1.1\""));
+
+    // An explicit, different cutoff invalidates the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--exclude-newer").arg("2024-01-01T00:00:00Z"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in timestamp cutoff: `as of: 2022-01-01T00:00:00Z` vs. `global: 2024-01-01T00:00:00Z`
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // `uv sync` records the date when it creates the lockfile.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    context
+        .sync()
+        .arg("--as-of")
+        .arg("2022-01-01T00:00:00Z")
+        .assert()
+        .success();
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("as-of = \"2022-01-01T00:00:00Z\"\n"));
+    assert!(lock.contains("version = \"1.1.1\""));
+
+    Ok(())
+}
+
+/// Start an index serving `iniconfig==1.1.1` (uploaded in 2020) and `iniconfig==2.0.0` (uploaded
+/// in 2023), in which `iniconfig==1.1.1` is yanked at the given time, if any.
+async fn yanked_iniconfig_index(yanked_time: Option<&str>) -> MockServer {
+    let server = MockServer::start().await;
+
+    let mut yanked = json!({
+        "filename": "iniconfig-1.1.1-py2.py3-none-any.whl",
+        "hashes": {
+            "sha256": "011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3"
+        },
+        "upload-time": "2020-10-16T17:37:23.000000Z",
+        "url": "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl",
+        "yanked": "Broken release",
+    });
+    if let Some(yanked_time) = yanked_time {
+        yanked["yanked-time"] = json!(yanked_time);
+    }
+    let simple_index = json!({
+        "files": [
+            yanked,
+            {
+                "filename": "iniconfig-2.0.0-py3-none-any.whl",
+                "hashes": {
+                    "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                },
+                "upload-time": "2023-01-07T11:08:09.864000Z",
+                "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+            }
+        ]
+    });
+    Mock::given(method("GET"))
+        .and(path("/simple/iniconfig/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            simple_index.to_string().into_bytes(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// Resolve as of a date, respecting yanks that predate the cutoff, along with yanks for which the
+/// index doesn't report a time.
+#[tokio::test]
+async fn lock_as_of_yanked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // `iniconfig==1.1.1` was yanked after the cutoff, so it was available at the time.
+    let server = yanked_iniconfig_index(Some("2023-06-01T00:00:00Z")).await;
+    context
+        .lock()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--as-of")
+        .arg("2022-01-01T00:00:00Z")
+        .assert()
+        .success();
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("as-of = \"2022-01-01T00:00:00Z\"\n"));
+    assert!(lock.contains("version = \"1.1.1\""));
+
+    // `iniconfig==1.1.1` was yanked before the cutoff, so it's excluded, like `iniconfig==2.0.0`.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    let server = yanked_iniconfig_index(Some("2021-06-01T00:00:00Z")).await;
+    let output = context
+        .lock()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--as-of")
+        .arg("2022-01-01T00:00:00Z")
+        .output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("yanked"));
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    // The index doesn't report when `iniconfig==1.1.1` was yanked, so it remains yanked.
+    let server = yanked_iniconfig_index(None).await;
+    let output = context
+        .lock()
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--as-of")
+        .arg("2022-01-01T00:00:00Z")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains(
+        "iniconfig-1.1.1-py2.py3-none-any.whl is yanked, but the index doesn't report when it was yanked"
+    ));
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
+
+/// Embed an integrity hash in the lockfile, and retain it when the lockfile is updated.
+#[test]
+fn lock_integrity() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--integrity"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("\nintegrity = \"sha256:"));
+    assert!(!context.temp_dir.child("uv.lock.sig").exists());
+
+    // Updating the lockfile retains (and recomputes) the integrity hash.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Added sniffio v1.3.1
+    ");
+
+    let updated = context.read("uv.lock");
+    assert!(updated.contains("\nintegrity = \"sha256:"));
+    assert_ne!(
+        lock.lines().find(|line| line.starts_with("integrity = ")),
+        updated
+            .lines()
+            .find(|line| line.starts_with("integrity = "))
+    );
+
+    // If a signing key is provided, write a detached signature.
+    uv_snapshot!(context.filters(), context.lock().arg("--integrity").env(EnvVars::UV_LOCK_SIGNING_KEY, "secret"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    assert!(context.temp_dir.child("uv.lock.sig").exists());
+
+    Ok(())
+}
+
+/// Start an index that only provides an MD5 hash for `iniconfig==2.0.0`.
+async fn md5_iniconfig_index() -> MockServer {
+    let server = MockServer::start().await;
+
+    let iniconfig_page = r#"
+    <!DOCTYPE html>
+    <html>
+        <body>
+        <h1>Links for iniconfig</h1>
+        <a href="https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#md5=a6d1b4d2d8a6fc1fb1e5b0a8e3d8b5c9">iniconfig-2.0.0-py3-none-any.whl</a><br/>
+    </body>
+    </html>
+    "#;
+    Mock::given(method("GET"))
+        .and(path("/simple/iniconfig/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(iniconfig_page, "text/html"))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// Refuse to lock a distribution for which the index only provides an MD5 hash, unless MD5 hashes
+/// are explicitly allowed.
+#[tokio::test]
+async fn lock_insecure_md5_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let server = md5_iniconfig_index().await;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri())), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The index only provides an MD5 hash for `iniconfig==2.0.0`, which is not secure
+
+    hint: Pass `--allow-insecure-md5-hashes` (or set `UV_INSECURE_MD5_HASHES=1`) to allow MD5 hashes
+    ");
+
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--allow-insecure-md5-hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("hash = \"md5:a6d1b4d2d8a6fc1fb1e5b0a8e3d8b5c9\""));
+
+    // The setting can also be provided via the environment.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_INSECURE_MD5_HASHES, "1")
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri()))
+        .assert()
+        .success();
+
+    Ok(())
+}
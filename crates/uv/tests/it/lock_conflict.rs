@@ -40,7 +40,7 @@ fn extra_basic() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -188,7 +188,7 @@ fn extra_basic() -> Result<()> {
     // And finally, installing both extras should error.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--all-extras"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -197,7 +197,7 @@ fn extra_basic() -> Result<()> {
     // As should exporting them.
     uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--all-extras"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -232,7 +232,7 @@ fn extra_basic_three_extras() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -411,7 +411,7 @@ fn extra_multiple_not_conflicting1() -> Result<()> {
         context.sync().arg("--frozen").arg("--extra=extra1").arg("--extra=extra2"),
         @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -423,7 +423,7 @@ fn extra_multiple_not_conflicting1() -> Result<()> {
         context.sync().arg("--frozen").arg("--extra=project3").arg("--extra=project4"),
         @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -508,7 +508,7 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
     // Fails, as expected.
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -548,7 +548,7 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
     )?;
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -669,7 +669,7 @@ fn extra_multiple_independent() -> Result<()> {
     )?;
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -705,7 +705,7 @@ fn extra_multiple_independent() -> Result<()> {
     )?;
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -989,7 +989,7 @@ fn extra_config_change_ignore_lockfile() -> Result<()> {
     // the conflicting group config removal.
     uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1061,7 +1061,7 @@ fn extra_unconditional() -> Result<()> {
     // This should error since we're enabling two conflicting extras.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1336,7 +1336,7 @@ fn extra_unconditional_in_optional() -> Result<()> {
         context.sync().arg("--frozen").arg("--extra=x1").arg("--extra=x2"),
         @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1440,7 +1440,7 @@ fn extra_unconditional_non_local_conflict() -> Result<()> {
     // of two different versions of the same package.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1553,7 +1553,7 @@ fn extra_nested_across_workspace() -> Result<()> {
     // up with a resolution failure.
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1692,7 +1692,7 @@ fn extra_depends_on_conflicting_extra() -> Result<()> {
     // `example[foo]` is unusable.
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1873,7 +1873,7 @@ fn extra_depends_on_conflicting_extra_transitive() -> Result<()> {
     // Install with `foo`
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--extra").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1920,7 +1920,7 @@ fn group_basic() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2068,7 +2068,7 @@ fn group_basic() -> Result<()> {
     // And finally, installing both groups should error.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=group1").arg("--group=group2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2213,7 +2213,7 @@ fn group_default() -> Result<()> {
     // enabled by default.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=group2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2224,7 +2224,7 @@ fn group_default() -> Result<()> {
     // "enabled by default".
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=group1").arg("--group=group2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2235,7 +2235,7 @@ fn group_default() -> Result<()> {
     // default".
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--all-groups"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2285,7 +2285,7 @@ fn mixed() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2438,7 +2438,7 @@ fn mixed() -> Result<()> {
     // And finally, installing both the group and the extra should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group=group1").arg("--extra=extra1"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4699,7 +4699,7 @@ conflicts = [
     // Error out, as x2 extra is only on the child.
     uv_snapshot!(context.filters(), context.sync().arg("--extra=x2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -773,7 +773,7 @@ fn conflict_in_fork() -> Result<()> {
     cmd.arg("--index-url").arg(packse_index_url());
     uv_snapshot!(filters, cmd, @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -843,7 +843,7 @@ fn fork_conflict_unsatisfiable() -> Result<()> {
     cmd.arg("--index-url").arg(packse_index_url());
     uv_snapshot!(filters, cmd, @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1514,7 +1514,7 @@ fn fork_marker_disjoint() -> Result<()> {
     cmd.arg("--index-url").arg(packse_index_url());
     uv_snapshot!(filters, cmd, @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3143,7 +3143,7 @@ fn fork_non_local_fork_marker_direct() -> Result<()> {
     cmd.arg("--index-url").arg(packse_index_url());
     uv_snapshot!(filters, cmd, @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3215,7 +3215,7 @@ fn fork_non_local_fork_marker_transitive() -> Result<()> {
     cmd.arg("--index-url").arg(packse_index_url());
     uv_snapshot!(filters, cmd, @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
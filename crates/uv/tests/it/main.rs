@@ -3,6 +3,9 @@
 
 pub(crate) mod common;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod api;
+
 mod auth;
 
 mod branching_urls;
@@ -13,18 +16,36 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(all(feature = "python-managed", feature = "pypi"))]
+mod bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_migrate;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_show;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod diff;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod edit;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod exit_codes;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod export;
 
@@ -64,6 +85,8 @@ mod pip_install_scenarios;
 
 mod pip_list;
 
+mod pip_normalize_requirements;
+
 mod pip_show;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -95,6 +118,12 @@ mod python_pin;
 #[cfg(feature = "python-managed")]
 mod python_upgrade;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod reproducible;
+
+#[cfg(feature = "python")]
+mod resolver_testkit;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
@@ -107,6 +136,9 @@ mod show_settings;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_check;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
@@ -119,6 +151,9 @@ mod tool_list;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_show;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_uninstall;
 
@@ -133,8 +168,18 @@ mod venv;
 
 mod version;
 
+mod wheelhouse;
+
+#[cfg(all(windows, feature = "python"))]
+mod windows_paths;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod workflow;
 
 mod extract;
 mod workspace;
+
+#[cfg(feature = "git")]
+mod workspace_affected;
+
+mod workspace_graph;
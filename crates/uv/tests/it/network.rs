@@ -53,7 +53,7 @@ async fn simple_http_500() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -79,7 +79,7 @@ async fn simple_io_err() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -108,7 +108,7 @@ async fn find_links_http_500() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -136,7 +136,7 @@ async fn find_links_io_error() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -167,7 +167,7 @@ async fn direct_url_http_500() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -195,7 +195,7 @@ async fn direct_url_io_error() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -256,7 +256,7 @@ async fn python_install_http_500() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -288,7 +288,7 @@ async fn python_install_io_error() {
         .env_remove(EnvVars::UV_HTTP_RETRIES)
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -319,7 +319,7 @@ async fn install_http_retries() {
         .arg(server.uri())
         .env(EnvVars::UV_HTTP_RETRIES, "foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -334,7 +334,7 @@ async fn install_http_retries() {
         .arg(server.uri())
         .env(EnvVars::UV_HTTP_RETRIES, "999999999999"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -350,7 +350,7 @@ async fn install_http_retries() {
         .env(EnvVars::UV_HTTP_RETRIES, "5")
         .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -220,3 +221,75 @@ fn check_python_version() {
     "
     );
 }
+
+/// In strict mode, packages with files that were removed after installation are reported,
+/// alongside a suggestion for repairing the environment.
+#[test]
+fn check_strict_broken_record() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("idna==3.6"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    fs_err::remove_file(context.site_packages().join("idna").join("core.py"))?;
+
+    // Without `--strict`, only compatibility is validated.
+    uv_snapshot!(context.pip_check(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    uv_snapshot!(context.pip_check().arg("--strict"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    Found 1 incompatibility
+    The package `idna` is missing 1 file listed in its `RECORD`:
+      - idna/core.py
+      hint: Reinstall the package with `uv pip install --reinstall-package idna idna`
+    "###
+    );
+
+    // Reinstalling the package repairs the environment.
+    context
+        .pip_install()
+        .arg("--reinstall-package")
+        .arg("idna")
+        .arg("idna==3.6")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_check().arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    Ok(())
+}
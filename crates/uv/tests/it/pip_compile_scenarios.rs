@@ -69,7 +69,7 @@ fn compatible_python_incompatible_override() -> Result<()> {
         .arg("--python-version=3.9")
         , @r"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
@@ -379,7 +379,7 @@ fn python_patch_override_no_patch() -> Result<()> {
         .arg("--python-version=3.9")
         , @r"
                  success: false
-                 exit_code: 1
+                 exit_code: 2
                  ----- stdout -----
 
                  ----- stderr -----
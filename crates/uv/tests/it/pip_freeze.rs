@@ -122,6 +122,81 @@ fn freeze_url() -> Result<()> {
     Ok(())
 }
 
+/// List a direct URL package installed with a hash, which should be included in the URL fragment.
+#[test]
+fn freeze_url_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    // Run `pip sync`.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.pip_freeze(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// Exclude packages from the output with `--exclude`.
+#[test]
+fn freeze_exclude() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    // Run `pip sync`.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.pip_freeze()
+        .arg("--exclude")
+        .arg("markupsafe"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    tomli==2.0.1
+
+    ----- stderr -----
+    "
+    );
+
+    // Package names are normalized.
+    uv_snapshot!(context.pip_freeze()
+        .arg("--exclude")
+        .arg("MarkupSafe")
+        .arg("--exclude")
+        .arg("tomli"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn freeze_with_editable() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -182,7 +182,7 @@ fn list_outdated_freeze() {
 
     uv_snapshot!(context.pip_list().arg("--outdated").arg("--format").arg("freeze"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -386,7 +386,7 @@ fn list_editable_only() {
         .arg("--editable")
         .arg("--exclude-editable"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -481,6 +481,118 @@ fn list_exclude() {
     );
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn list_sort() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_list()
+        .arg("--sort")
+        .arg("version")
+        .arg("--format")
+        .arg("freeze"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    sniffio==1.3.1
+    idna==3.6
+    anyio==4.3.0
+
+    ----- stderr -----
+    "
+    );
+}
+
+#[test]
+#[cfg(feature = "pypi")]
+fn list_include_size() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(vec![
+            (r"\-\-\-\-\-\-+.*", "[UNDERLINE]"),
+            (r"\d+\.\d(B|KiB|MiB)", "[SIZE]"),
+            (r#""installed_size":\d+"#, r#""installed_size":[SIZE]"#),
+            ("  +", " "),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--include-size"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Size
+    [UNDERLINE]
+    anyio 4.3.0 [SIZE]
+    idna 3.6 [SIZE]
+    sniffio 1.3.1 [SIZE]
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--include-size")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"anyio","version":"4.3.0","installed_size":[SIZE]},{"name":"idna","version":"3.6","installed_size":[SIZE]},{"name":"sniffio","version":"1.3.1","installed_size":[SIZE]}]
+
+    ----- stderr -----
+    "#
+    );
+
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--include-size")
+        .arg("--format")
+        .arg("freeze"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--include-size` cannot be used with `--format freeze`
+    "
+    );
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 #[cfg(not(windows))]
@@ -548,6 +660,79 @@ fn list_format_json() {
     );
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+#[cfg(not(windows))]
+fn list_include_metadata() {
+    let context = TestContext::new("3.12");
+
+    // Install the editable package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/poetry_editable")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + poetry-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/poetry_editable)
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Only the editable package was requested; its dependencies were not.
+    uv_snapshot!(context.filters(), context.pip_list()
+    .arg("--format=json")
+    .arg("--include-metadata"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"anyio","version":"4.3.0","installer":"uv","requested":false},{"name":"idna","version":"3.6","installer":"uv","requested":false},{"name":"poetry-editable","version":"0.1.0","editable_project_location":"[WORKSPACE]/scripts/packages/poetry_editable","installer":"uv","requested":true,"direct_url":{"url":"file://[WORKSPACE]/scripts/packages/poetry_editable","dir_info":{"editable":true}}},{"name":"sniffio","version":"1.3.1","installer":"uv","requested":false}]
+
+    ----- stderr -----
+    "###
+    );
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(vec![(r"\-\-\-\-\-\-+.*", "[UNDERLINE]"), ("  +", " ")])
+        .collect();
+
+    uv_snapshot!(filters, context.pip_list()
+    .arg("--include-metadata"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Editable project location Installer Requested Direct URL
+    [UNDERLINE]
+    anyio 4.3.0 uv no
+    idna 3.6 uv no
+    poetry-editable 0.1.0 [WORKSPACE]/scripts/packages/poetry_editable uv yes file://[WORKSPACE]/scripts/packages/poetry_editable
+    sniffio 1.3.1 uv no
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_list()
+    .arg("--format=freeze")
+    .arg("--include-metadata"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--include-metadata` cannot be used with `--format freeze`
+    "###
+    );
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn list_format_freeze() {
@@ -702,7 +887,7 @@ Version: 0.1-bulbasaur
     uv_snapshot!(filters, context.pip_list()
         .arg("--editable"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Normalize requirements provided on the command line.
+#[test]
+fn normalize_requirements() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.pip_normalize_requirements()
+        .arg("Foo_Bar [b, a, b] >= 1.0, >=1.2, < 2, != 3.0")
+        .arg("requests [socks] >= 2.0")
+        .arg("Flask==3.0.*,~=3.0.1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-bar[a,b]>=1.2,<2
+    requests[socks]>=2.0
+    flask~=3.0.1
+
+    ----- stderr -----
+    ");
+}
+
+/// Normalize requirements read from stdin, skipping empty lines and comments.
+#[test]
+fn normalize_requirements_stdin() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # A comment.
+        Foo_Bar [b, a] >= 1.0, >=1.2
+
+        iniconfig >= 2.0 , >= 1.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_normalize_requirements()
+        .stdin(std::fs::File::open(requirements_txt)?), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo-bar[a,b]>=1.2
+    iniconfig>=2.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -23,7 +23,7 @@ fn missing_requirements_txt() {
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -45,7 +45,7 @@ fn missing_venv() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.pip_sync().arg("requirements.txt"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -58,7 +58,7 @@ fn missing_venv() -> Result<()> {
     // If not "active", we hint to create one
     uv_snapshot!(context.filters(), context.pip_sync().arg("requirements.txt").env_remove(EnvVars::VIRTUAL_ENV), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -78,7 +78,7 @@ fn missing_system() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.pip_sync().arg("requirements.txt").arg("--system"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -269,7 +269,7 @@ fn install_symlink_no_cache() -> Result<()> {
         .arg("--no-cache")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -953,7 +953,7 @@ fn install_no_index() -> Result<()> {
         .arg("--no-index")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1002,7 +1002,7 @@ fn install_no_index_cached() -> Result<()> {
         .arg("--no-index")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1235,7 +1235,7 @@ fn mismatched_version() -> Result<()> {
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1271,7 +1271,7 @@ fn mismatched_name() -> Result<()> {
         .arg("requirements.txt")
         .arg("--strict"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1934,7 +1934,7 @@ fn duplicate_package_overlap() -> Result<()> {
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2615,7 +2615,7 @@ fn incompatible_wheel() -> Result<()> {
         .arg("requirements.txt")
         .arg("--strict"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2751,6 +2751,87 @@ fn find_links_offline_match() -> Result<()> {
     Ok(())
 }
 
+/// Sync using `--find-links` with `--offline` and `--require-hashes`, using the hashes from the
+/// wheelhouse manifest to select the distribution.
+#[test]
+fn find_links_offline_require_hashes_manifest() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .write_file(
+            &context
+                .workspace_root
+                .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        )?;
+    wheelhouse.child("SHA256SUMS").write_str(
+        "a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13  tqdm-1000.0.0-py3-none-any.whl\n",
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "tqdm==1000.0.0 --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--offline")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("wheelhouse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    ");
+
+    Ok(())
+}
+
+/// The wheelhouse manifest only selects distributions: archives are still hashed at install time,
+/// such that a distribution that doesn't match its manifest entry is rejected.
+#[test]
+fn find_links_offline_require_hashes_manifest_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Replace the archive with a different wheel, leaving the manifest unchanged.
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .write_file(&context.workspace_root.join(
+            "scripts/links/tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl",
+        ))?;
+    wheelhouse.child("SHA256SUMS").write_str(
+        "a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13  tqdm-1000.0.0-py3-none-any.whl\n",
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "tqdm==1000.0.0 --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13",
+    )?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--offline")
+        .arg("--require-hashes")
+        .arg("--find-links")
+        .arg("wheelhouse")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Hash mismatch for `tqdm==1000.0.0`",
+        ));
+
+    Ok(())
+}
+
 /// Sync using `--find-links` with `--offline`, which should fail to find `numpy`.
 #[test]
 fn find_links_offline_no_match() -> Result<()> {
@@ -2768,7 +2849,7 @@ fn find_links_offline_no_match() -> Result<()> {
         .arg("--find-links")
         .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2893,7 +2974,7 @@ fn offline() -> Result<()> {
         .arg("requirements.in")
         .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2984,12 +3065,14 @@ fn incompatible_constraint() -> Result<()> {
         .arg("--constraint")
         .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
       × No solution found when resolving dependencies:
       ╰─▶ Because you require anyio==3.7.0 and anyio==3.6.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: `anyio` was declared via `-c constraints.txt:1` and `-r requirements.txt:1`
     "###
     );
 
@@ -3081,7 +3164,7 @@ fn repeat_requirement_incompatible() -> Result<()> {
     uv_snapshot!(context.pip_sync()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3301,6 +3384,71 @@ fn compile() -> Result<()> {
     Ok(())
 }
 
+/// Install with bytecode compilation, removing the compiled sources.
+#[test]
+fn compile_exclude_source() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    // `--exclude-source` requires `--compile-bytecode`.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--exclude-source"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `--exclude-source` requires `--compile-bytecode`
+    "###
+    );
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--exclude-source"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+    Removed 2 source files
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let package = context.site_packages().join("markupsafe");
+    assert!(package.join("__init__.pyc").exists());
+    assert!(!package.join("__init__.py").exists());
+
+    context.assert_command("import markupsafe").success();
+
+    // The `RECORD` reflects the removed sources.
+    uv_snapshot!(context.pip_check().arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    // Uninstalling removes the bytecode.
+    context.pip_uninstall().arg("markupsafe").assert().success();
+    assert!(!package.exists());
+
+    Ok(())
+}
+
 /// Re-install with bytecode compilation.
 #[test]
 fn recompile() -> Result<()> {
@@ -3379,7 +3527,7 @@ requires-python = ">=3.13"
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3419,7 +3567,7 @@ requires-python = ">=3.13"
     uv_snapshot!(context.filters(), context.pip_sync()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -3446,7 +3594,7 @@ fn require_hashes_unknown_algorithm() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3485,7 +3633,7 @@ fn require_hashes_missing_hash() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3526,7 +3674,7 @@ fn require_hashes_missing_version() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3567,7 +3715,7 @@ fn require_hashes_invalid_operator() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3593,7 +3741,7 @@ fn require_hashes_wheel_no_binary() -> Result<()> {
         .arg(":all:")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3685,7 +3833,7 @@ fn require_hashes_source_only_binary() -> Result<()> {
         .arg(":all:")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3717,7 +3865,7 @@ fn require_hashes_wrong_digest() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3749,7 +3897,7 @@ fn require_hashes_wrong_algorithm() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3820,7 +3968,7 @@ fn require_hashes_source_url() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3851,7 +3999,7 @@ fn require_hashes_source_url_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3921,7 +4069,7 @@ fn require_hashes_wheel_url() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -3975,7 +4123,7 @@ fn require_hashes_wheel_url_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4083,7 +4231,7 @@ fn require_hashes_re_download() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4174,7 +4322,7 @@ fn require_hashes_wheel_path_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4243,7 +4391,7 @@ fn require_hashes_source_path_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4307,7 +4455,7 @@ fn require_hashes_editable() -> Result<()> {
         .arg(requirements_txt.path())
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4331,7 +4479,7 @@ fn require_hashes_repeated_dependency() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4348,7 +4496,7 @@ fn require_hashes_repeated_dependency() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4450,7 +4598,7 @@ fn require_hashes_repeated_hash() -> Result<()> {
         .arg("--require-hashes")
         .arg("--reinstall"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4581,7 +4729,7 @@ fn require_hashes_find_links_no_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4610,7 +4758,7 @@ fn require_hashes_find_links_no_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4700,7 +4848,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4728,7 +4876,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4808,7 +4956,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4874,7 +5022,7 @@ fn require_hashes_registry_valid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://astral-test.github.io/astral-test-hash/valid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -4903,7 +5051,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4932,7 +5080,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -5015,7 +5163,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -5075,7 +5223,7 @@ fn require_hashes_url_other_fragment() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5099,7 +5247,7 @@ fn require_hashes_url_invalid() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -5131,7 +5279,7 @@ fn require_hashes_url_ignore() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -5400,6 +5548,39 @@ fn target_no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Sync a package that requires building from source with `--no-build-isolation-package`, such
+/// that it's built against the build dependencies in the environment.
+#[test]
+fn no_build_isolation_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("flit_core\nwheel")?;
+
+    // `flit_core` should be installed prior to building `wheel` without build isolation.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg("wheel")
+        .arg("--no-build-isolation-package")
+        .arg("wheel"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Prepared 1 package without build isolation in [TIME]
+    Installed 1 package in [TIME]
+     + flit-core==3.9.0
+     + wheel==0.43.0
+    ");
+
+    Ok(())
+}
+
 /// Sync to a `--target` directory without a virtual environment.
 #[test]
 fn target_system() -> Result<()> {
@@ -5726,7 +5907,7 @@ fn semicolon_no_space() -> Result<()> {
     uv_snapshot!(context.pip_sync()
         .arg("requirements.txt"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5893,7 +6074,7 @@ fn pep_751_wheel_only() -> Result<()> {
         .arg("--python-version")
         .arg("3.8"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5972,7 +6153,7 @@ fn pep_751_build_options() -> Result<()> {
         .arg("--no-binary")
         .arg("odrive"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6004,7 +6185,7 @@ fn pep_751_build_options() -> Result<()> {
         .arg("--only-binary")
         .arg("source-distribution"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6064,7 +6245,7 @@ fn pep_751_direct_url_tags() -> Result<()> {
         .arg("--python-platform")
         .arg("linux"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6105,7 +6286,7 @@ fn incompatible_python_version_direct_url() -> Result<()> {
         .arg("--python-platform")
         .arg("windows"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6132,7 +6313,7 @@ fn incompatible_platform_direct_url() -> Result<()> {
         .arg("--python-platform")
         .arg("linux"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
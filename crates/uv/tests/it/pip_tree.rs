@@ -967,6 +967,106 @@ fn show_version_specifiers_simple() {
     );
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn format_json() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_tree().arg("--format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"requests","version":"2.31.0","dependencies":[{"name":"certifi","version":"2024.2.2","required_version":">=2017.4.17","dependencies":[]},{"name":"charset-normalizer","version":"3.3.2","required_version":">=2, <4","dependencies":[]},{"name":"idna","version":"3.6","required_version":">=2.5, <4","dependencies":[]},{"name":"urllib3","version":"2.2.1","required_version":">=1.21.1, <3","dependencies":[]}]}]
+
+    ----- stderr -----
+    "#
+    );
+
+    uv_snapshot!(context.filters(), context.pip_tree().arg("--format").arg("json").arg("--invert").arg("--package").arg("idna"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"idna","version":"3.6","dependencies":[{"name":"requests","version":"2.31.0","required_version":">=2.5, <4","dependencies":[]}]}]
+
+    ----- stderr -----
+    "#
+    );
+}
+
+#[test]
+#[cfg(feature = "pypi")]
+fn format_dot() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_tree().arg("--format").arg("dot"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        "certifi==2024.2.2" [label="certifi\nv2024.2.2"]
+        "charset-normalizer==3.3.2" [label="charset-normalizer\nv3.3.2"]
+        "idna==3.6" [label="idna\nv3.6"]
+        "requests==2.31.0" [label="requests\nv2.31.0"]
+        "urllib3==2.2.1" [label="urllib3\nv2.2.1"]
+        "requests==2.31.0" -> "certifi==2024.2.2" [label=">=2017.4.17"]
+        "requests==2.31.0" -> "charset-normalizer==3.3.2" [label=">=2, <4"]
+        "requests==2.31.0" -> "idna==3.6" [label=">=2.5, <4"]
+        "requests==2.31.0" -> "urllib3==2.2.1" [label=">=1.21.1, <3"]
+    }
+
+    ----- stderr -----
+    "#
+    );
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn show_version_specifiers_with_invert() {
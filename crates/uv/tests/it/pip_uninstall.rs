@@ -14,7 +14,7 @@ fn no_arguments() {
         .arg("uninstall")
         .env_clear(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -38,7 +38,7 @@ fn invalid_requirement() -> Result<()> {
         .arg("flask==1.0.x")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -62,7 +62,7 @@ fn missing_requirements_txt() -> Result<()> {
         .arg("requirements.txt")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -86,7 +86,7 @@ fn invalid_requirements_txt_requirement() -> Result<()> {
         .arg("requirements.txt")
         .current_dir(&temp_dir), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -155,7 +155,7 @@ fn missing_record() -> Result<()> {
     uv_snapshot!(context.filters(), context.pip_uninstall()
         .arg("MarkupSafe"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
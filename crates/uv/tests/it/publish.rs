@@ -25,7 +25,7 @@ fn username_password_no_longer_supported() {
         .arg("https://test.pypi.org/legacy/")
         .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -50,7 +50,7 @@ fn invalid_token() {
         .arg("https://test.pypi.org/legacy/")
         .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -80,7 +80,7 @@ fn mixed_credentials() {
         // Emulate CI
         .env(EnvVars::GITHUB_ACTIONS, "true"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -104,7 +104,7 @@ fn missing_trusted_publishing_permission() {
         // Emulate CI
         .env(EnvVars::GITHUB_ACTIONS, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -130,7 +130,7 @@ fn no_credentials() {
         // Emulate CI
         .env(EnvVars::GITHUB_ACTIONS, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -158,7 +158,7 @@ fn skip_existing_redirect() {
         .arg("--publish-url")
         .arg("https://test.pypi.org/legacy/"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -188,7 +188,7 @@ fn dubious_filenames() {
         .arg("https://test.pypi.org/legacy/")
         .arg(context.temp_dir.join("*")), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -200,6 +200,29 @@ fn dubious_filenames() {
     );
 }
 
+/// Check a file for problems without credentials and without uploading it.
+#[test]
+fn check() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--check")
+        .arg("--publish-url")
+        .arg("https://example.com/upload")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checking 1 file for https://example.com/upload
+    Checking ok-1.0.0-py3-none-any.whl ([SIZE])
+      warning: No long description, the project page will be empty (set `readme` in `pyproject.toml`)
+    Checks passed with 0 errors and 1 warning
+    "
+    );
+}
+
 /// Check that we (don't) use the keyring and warn for missing keyring behaviors correctly.
 #[test]
 fn check_keyring_behaviours() {
@@ -233,7 +256,7 @@ fn check_keyring_behaviours() {
         .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
         .env(EnvVars::PATH, venv_bin_path(&context.venv)), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -257,7 +280,7 @@ fn check_keyring_behaviours() {
         .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
         .env(EnvVars::PATH, venv_bin_path(&context.venv)),  @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -283,7 +306,7 @@ fn check_keyring_behaviours() {
         .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
         .env(EnvVars::PATH, venv_bin_path(&context.venv)), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -312,7 +335,7 @@ fn check_keyring_behaviours() {
         .env(EnvVars::KEYRING_TEST_CREDENTIALS, r#"{"https://test.pypi.org/legacy/?ok": {"dummy": "dummy"}}"#)
         .env(EnvVars::PATH, venv_bin_path(&context.venv)), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -364,7 +387,7 @@ fn invalid_index() {
         .arg(&ok_wheel)
         .current_dir(context.temp_dir.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -383,7 +406,7 @@ fn invalid_index() {
         .arg(&ok_wheel)
         .current_dir(context.temp_dir.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -466,7 +489,7 @@ async fn read_index_credential_env_vars_for_check_url() {
         .arg("never"),
         @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use assert_fs::prelude::{FileTouch, PathChild};
 use assert_fs::{fixture::FileWriteStr, prelude::PathCreateDir};
 use indoc::indoc;
@@ -15,7 +17,7 @@ fn python_find() {
     // No interpreters on the path
     uv_snapshot!(context.filters(), context.python_find().env(EnvVars::UV_TEST_PYTHON_PATH, ""), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -119,7 +121,7 @@ fn python_find() {
     // Request PyPy (which should be missing)
     uv_snapshot!(context.filters(), context.python_find().arg("pypy"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -233,7 +235,7 @@ fn python_find_pin_arbitrary_name() {
     // Try to pin to an arbitrary name
     uv_snapshot!(context.filters(), context.python_pin().arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -656,7 +658,7 @@ fn python_find_venv() {
     // TODO(zanieb): Report this as a bug upstream — this should be allowed.
     uv_snapshot!(context.filters(), context.python_find().arg("--no-system").env(EnvVars::UV_SYSTEM_PYTHON, "1"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -791,7 +793,7 @@ fn python_find_unsupported_version() {
     // Request a low version
     uv_snapshot!(context.filters(), context.python_find().arg("3.6"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -801,7 +803,7 @@ fn python_find_unsupported_version() {
     // Request a low version with a patch
     uv_snapshot!(context.filters(), context.python_find().arg("3.6.9"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -811,7 +813,7 @@ fn python_find_unsupported_version() {
     // Request a really low version
     uv_snapshot!(context.filters(), context.python_find().arg("2.6"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -821,7 +823,7 @@ fn python_find_unsupported_version() {
     // Request a really low version with a patch
     uv_snapshot!(context.filters(), context.python_find().arg("2.6.8"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -831,7 +833,7 @@ fn python_find_unsupported_version() {
     // Request a future version
     uv_snapshot!(context.filters(), context.python_find().arg("4.2"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -841,7 +843,7 @@ fn python_find_unsupported_version() {
     // Request a low version with a range
     uv_snapshot!(context.filters(), context.python_find().arg("<3.0"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -851,7 +853,7 @@ fn python_find_unsupported_version() {
     // Request free-threaded Python on unsupported version
     uv_snapshot!(context.filters(), context.python_find().arg("3.12t"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -881,7 +883,7 @@ fn python_find_venv_invalid() {
 
     uv_snapshot!(context.filters(), context.python_find().env(EnvVars::VIRTUAL_ENV, context.venv.as_os_str()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -931,7 +933,7 @@ fn python_find_managed() {
     // Request an interpreter that cannot be satisfied
     uv_snapshot!(context.filters(), context.python_find().arg("--managed-python").arg("3.11"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -955,7 +957,7 @@ fn python_find_managed() {
     // Request an interpreter that cannot be satisfied
     uv_snapshot!(context.filters(), context.python_find().arg("--no-managed-python").arg("3.11"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1026,7 +1028,7 @@ fn python_required_python_major_minor() {
     // Fail to find any matching Python interpreter.
     uv_snapshot!(context.filters(), context.python_find().arg(">3.11.255, <3.12").env(EnvVars::UV_TEST_PYTHON_PATH, context.temp_dir.child("child").path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1114,7 +1116,7 @@ fn python_find_script_python_not_found() {
 
     uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("foo.py"), @r"
     success: false
-    exit_code: 1
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1163,7 +1165,7 @@ fn python_find_script_no_such_version() {
 
     uv_snapshot!(context.filters(), context.python_find().arg("--script").arg("foo.py"), @r"
     success: false
-    exit_code: 1
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1179,7 +1181,7 @@ fn python_find_show_version() {
     // No interpreters found
     uv_snapshot!(context.filters(), context.python_find().env(EnvVars::UV_TEST_PYTHON_PATH, "").arg("--show-version"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1217,9 +1219,34 @@ fn python_find_show_version() {
     ");
 }
 
+#[test]
+fn python_find_output_format_json() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_find()
+        .arg("--output-format")
+        .arg("json")
+        .arg("3.12")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(data["implementation"], "cpython");
+    assert_eq!(data["version_parts"]["major"], 3);
+    assert_eq!(data["version_parts"]["minor"], 12);
+    assert!(data["key"].as_str().unwrap().starts_with("cpython-3.12."));
+    assert!(data["path"].is_string());
+    assert!(data["url"].is_null());
+}
+
 #[test]
 fn python_find_path() {
-    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_not_executable();
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_not_executable()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_python_names();
 
     context.temp_dir.child("foo").create_dir_all().unwrap();
     context.temp_dir.child("bar").touch().unwrap();
@@ -1227,17 +1254,17 @@ fn python_find_path() {
     // No interpreter in a directory
     uv_snapshot!(context.filters(), context.python_find().arg(context.temp_dir.child("foo").as_os_str()), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
-    error: No interpreter found in directory `foo`
+    error: No interpreter found in directory `foo` (expected an executable at `foo/[BIN]/[PYTHON]`)
     ");
 
     // No interpreter at a file
     uv_snapshot!(context.filters(), context.python_find().arg(context.temp_dir.child("bar").as_os_str()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1249,10 +1276,140 @@ fn python_find_path() {
     // No interpreter at a file that does not exist
     uv_snapshot!(context.filters(), context.python_find().arg(context.temp_dir.child("foobar").as_os_str()), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
     error: No interpreter found at path `foobar`
     ");
 }
+
+/// A leading `~` in a path request is expanded to the home directory, even if the shell doesn't
+/// expand it.
+#[test]
+#[cfg(unix)]
+fn python_find_path_tilde() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"])
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin();
+
+    // Create a virtual environment in the home directory
+    uv_snapshot!(context.filters(), context.venv().arg(context.home_dir.child(".venv").as_os_str()).arg("--python").arg("3.12").arg("-q"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().arg("~/.venv"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [HOME]/.venv/[BIN]/[PYTHON]
+
+    ----- stderr -----
+    ");
+
+    // The error for a directory without an interpreter includes the expected executable
+    context.home_dir.child("empty").create_dir_all().unwrap();
+    uv_snapshot!(context.filters(), context.python_find().arg("~/empty"), @r"
+    success: false
+    exit_code: 5
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found in directory `[HOME]/empty` (expected an executable at `[HOME]/empty/[BIN]/[PYTHON]`)
+    ");
+
+    // A missing path is reported after expansion
+    uv_snapshot!(context.filters(), context.python_find().arg("~/missing"), @r"
+    success: false
+    exit_code: 5
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found at path `[HOME]/missing`
+    ");
+}
+
+#[test]
+#[cfg(unix)]
+fn python_find_base_resolve_symlinks() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    let output = context.venv().arg("--python").arg("3.12").output().unwrap();
+    assert!(output.status.success());
+
+    let find = |args: &[&str]| {
+        let output = context.python_find().args(args).output().unwrap();
+        assert!(output.status.success());
+        PathBuf::from(String::from_utf8(output.stdout).unwrap().trim())
+    };
+
+    // By default, the virtual environment interpreter is shown as-is, i.e., as a symbolic link.
+    let venv_python = find(&[]);
+    assert!(venv_python.starts_with(&context.venv));
+    assert!(fs_err::symlink_metadata(&venv_python).unwrap().is_symlink());
+
+    // With `--resolve-symlinks`, the link is followed to the underlying executable.
+    let resolved = find(&["--resolve-symlinks"]);
+    assert_eq!(resolved, fs_err::canonicalize(&venv_python).unwrap());
+
+    // With `--base`, the interpreter that the environment was created from is shown instead.
+    let base = find(&["--base"]);
+    assert!(!base.starts_with(&context.venv));
+
+    // The base interpreter matches the system interpreter, once symbolic links are resolved.
+    assert_eq!(
+        find(&["--base", "--resolve-symlinks"]),
+        find(&["--system", "--resolve-symlinks", "3.12"])
+    );
+}
+
+/// Python installations are discovered in the pyenv installation directory, even if they're missing
+/// from the search path.
+#[cfg(unix)]
+#[test]
+fn python_find_pyenv() -> anyhow::Result<()> {
+    let context: TestContext =
+        TestContext::new_with_versions(&["3.11", "3.12"]).with_filtered_python_sources();
+
+    let pyenv_root = context.temp_dir.child("pyenv");
+    let pyenv_bin = pyenv_root.child("versions").child("3.11.9").child("bin");
+    pyenv_bin.create_dir_all()?;
+    fs_err::os::unix::fs::symlink(&context.python_versions[0].1, pyenv_bin.join("python3"))?;
+
+    // Only Python 3.12 is on the search path.
+    let search_path = context.python_dir.join("3.12");
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.11")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &search_path)
+        .env(EnvVars::UV_INTERNAL__TEST_VERSION_MANAGERS, "1")
+        .env(EnvVars::PYENV_ROOT, pyenv_root.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/pyenv/versions/3.11.9/bin/python3
+
+    ----- stderr -----
+    ");
+
+    // The pyenv installation isn't considered if only managed interpreters are allowed.
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg("3.11")
+        .arg("--managed-python")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &search_path)
+        .env(EnvVars::UV_INTERNAL__TEST_VERSION_MANAGERS, "1")
+        .env(EnvVars::PYENV_ROOT, pyenv_root.path()), @r"
+    success: false
+    exit_code: 5
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.11 in virtual environments or managed installations
+    ");
+
+    Ok(())
+}
@@ -112,7 +112,7 @@ fn python_install() {
     // Uninstallation requires an argument
     uv_snapshot!(context.filters(), context.python_uninstall(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -243,7 +243,7 @@ fn python_install_automatic() {
         .arg("--no-python-downloads")
         .arg("python").arg("-c").arg("import sys; print(sys.version_info[:2])"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -296,7 +296,7 @@ fn python_install_automatic() {
        .arg("-p").arg("foobar")
        .arg("python").arg("-c").arg("import sys; print(sys.version_info[:2])"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -743,7 +743,7 @@ fn python_install_preview() {
     // Uninstallation requires an argument
     uv_snapshot!(context.filters(), context.python_uninstall(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -878,7 +878,7 @@ fn python_install_preview_no_bin() {
 
     uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("--no-bin").arg("--default"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1192,7 +1192,7 @@ fn python_install_freethreaded() {
     // Should not work with older Python versions
     uv_snapshot!(context.filters(), context.python_install().arg("3.12t"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1395,7 +1395,7 @@ fn python_install_debug_freethreaded() {
     // We should not find it without opt-in
     uv_snapshot!(context.filters(), context.python_find().arg("3.13"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -1501,7 +1501,7 @@ fn python_install_invalid_request() {
     // Request something that is not a Python version
     uv_snapshot!(context.filters(), context.python_install().arg("foobar"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1511,7 +1511,7 @@ fn python_install_invalid_request() {
     // Request a version we don't have a download for
     uv_snapshot!(context.filters(), context.python_install().arg("3.8.0"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1521,7 +1521,7 @@ fn python_install_invalid_request() {
     // Request a version we don't have a download for mixed with one we do
     uv_snapshot!(context.filters(), context.python_install().arg("3.8.0").arg("3.12"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1687,7 +1687,7 @@ fn python_install_default() {
     // Install multiple versions, with the `--default` flag
     uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("3.13").arg("--default"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1942,7 +1942,7 @@ fn python_install_default_preview() {
     // Install multiple versions, with the `--default` flag
     uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.12").arg("3.13").arg("--default"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2164,7 +2164,7 @@ fn python_install_unknown() {
     // An unknown request
     uv_snapshot!(context.filters(), context.python_install().arg("foobar"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2176,7 +2176,7 @@ fn python_install_unknown() {
     // A directory
     uv_snapshot!(context.filters(), context.python_install().arg("./foo"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2256,7 +2256,7 @@ fn python_install_default_from_env() {
     // We should ignore `UV_PYTHON` here and complain there is not a target
     uv_snapshot!(context.filters(), context.python_uninstall().env(EnvVars::UV_PYTHON, "3.12"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2284,7 +2284,7 @@ fn python_install_default_from_env() {
     // Uninstall with no targets should error
     uv_snapshot!(context.filters(), context.python_uninstall(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2299,7 +2299,7 @@ fn python_install_default_from_env() {
     // Uninstall with conflicting options should error
     uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("3.12"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2617,7 +2617,7 @@ fn python_install_no_cache() {
     // Uninstallation requires an argument
     uv_snapshot!(context.filters(), context.python_uninstall(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3271,7 +3271,7 @@ fn uninstall_last_patch() {
     #[cfg(unix)]
     uv_snapshot!(filters, context.run().arg("python").arg("--version"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3416,7 +3416,7 @@ fn python_install_pyodide() {
     // Find without a request should fail
     uv_snapshot!(context.filters(), context.python_find(), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -3425,7 +3425,7 @@ fn python_install_pyodide() {
     // Find with "cpython" should also fail
     uv_snapshot!(context.filters(), context.python_find().arg("cpython"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -3516,7 +3516,7 @@ fn python_install_build_version() {
         .arg("3.12")
         .env(EnvVars::UV_PYTHON_CPYTHON_BUILD, "99999999"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -3528,7 +3528,7 @@ fn python_install_build_version() {
         .arg("3.12")
         .env(EnvVars::UV_PYTHON_CPYTHON_BUILD, "99999999"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3540,7 +3540,7 @@ fn python_install_build_version() {
         .arg("3.12.10")
         .env(EnvVars::UV_PYTHON_CPYTHON_BUILD, "20250814"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3599,7 +3599,7 @@ fn python_install_build_version_pypy() {
         .arg("pypy3.10")
         .env(EnvVars::UV_PYTHON_PYPY_BUILD, "99.99.99"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -3611,7 +3611,7 @@ fn python_install_build_version_pypy() {
         .arg("pypy3.10")
         .env(EnvVars::UV_PYTHON_PYPY_BUILD, "99.99.99"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -218,7 +218,7 @@ fn python_list_unsupported_version() {
     // Request a low version
     uv_snapshot!(context.filters(), context.python_list().arg("3.6"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -228,7 +228,7 @@ fn python_list_unsupported_version() {
     // Request a low version with a patch
     uv_snapshot!(context.filters(), context.python_list().arg("3.6.9"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -238,7 +238,7 @@ fn python_list_unsupported_version() {
     // Request a really low version
     uv_snapshot!(context.filters(), context.python_list().arg("2.6"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -248,7 +248,7 @@ fn python_list_unsupported_version() {
     // Request a really low version with a patch
     uv_snapshot!(context.filters(), context.python_list().arg("2.6.8"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -276,7 +276,7 @@ fn python_list_unsupported_version() {
     // Request free-threaded Python on unsupported version
     uv_snapshot!(context.filters(), context.python_list().arg("3.12t"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -478,3 +478,114 @@ fn python_list_downloads_installed() {
     ----- stderr -----
     ");
 }
+
+/// Installations in the pyenv and asdf installation directories are discovered, unless the Python
+/// preference excludes system interpreters. With `--all-sources`, an installation is listed once
+/// per source.
+#[test]
+#[cfg(unix)]
+fn python_list_version_managers() -> anyhow::Result<()> {
+    use assert_fs::prelude::*;
+
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+    let executable = |version: &str| {
+        context
+            .python_versions
+            .iter()
+            .find(|(python_version, _)| python_version.to_string() == version)
+            .map(|(_, executable)| executable.clone())
+            .unwrap()
+    };
+
+    // Install Python 3.11 with pyenv, and Python 3.12 with asdf.
+    let pyenv_root = context.temp_dir.child("pyenv");
+    let pyenv_bin = pyenv_root.child("versions").child("3.11.9").child("bin");
+    pyenv_bin.create_dir_all()?;
+    fs_err::os::unix::fs::symlink(executable("3.11"), pyenv_bin.join("python3"))?;
+
+    let asdf_root = context.temp_dir.child("asdf");
+    let asdf_bin = asdf_root
+        .child("installs")
+        .child("python")
+        .child("3.12.1")
+        .child("bin");
+    asdf_bin.create_dir_all()?;
+    fs_err::os::unix::fs::symlink(executable("3.12"), asdf_bin.join("python3"))?;
+
+    // Include the pyenv installation on the search path, too, along with the test Python 3.12.
+    let search_path =
+        std::env::join_paths([pyenv_bin.to_path_buf(), context.python_dir.join("3.12")])?;
+
+    let list = |preference: &str, all_sources: bool| -> anyhow::Result<Vec<serde_json::Value>> {
+        let mut command = context.python_list();
+        command
+            .arg("--only-installed")
+            .arg("--output-format")
+            .arg("json")
+            .arg("--python-preference")
+            .arg(preference)
+            .env(EnvVars::UV_TEST_PYTHON_PATH, &search_path)
+            .env(EnvVars::UV_INTERNAL__TEST_VERSION_MANAGERS, "1")
+            .env(EnvVars::PYENV_ROOT, pyenv_root.path())
+            .env(EnvVars::ASDF_DATA_DIR, asdf_root.path());
+        if all_sources {
+            command.arg("--all-sources");
+        }
+        let output = command.output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(serde_json::from_slice(&output.stdout)?)
+    };
+    let sources = |installations: &[serde_json::Value]| {
+        let mut sources = installations
+            .iter()
+            .map(|installation| installation["source"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        sources.sort();
+        sources
+    };
+
+    // The pyenv installation is only shown once, even though it's also on the search path.
+    let installations = list("managed", false)?;
+    assert_eq!(installations.len(), 3, "{installations:?}");
+    assert!(
+        installations
+            .iter()
+            .all(|installation| installation.get("source").is_none())
+    );
+
+    // With `--all-sources`, it's shown once per source.
+    let installations = list("managed", true)?;
+    assert_eq!(
+        sources(&installations),
+        [
+            "asdf installations",
+            "first executable in the search path",
+            "pyenv installations",
+            "search path",
+        ]
+    );
+    let pyenv = installations
+        .iter()
+        .filter(|installation| {
+            installation["path"]
+                .as_str()
+                .unwrap()
+                .ends_with("3.11.9/bin/python3")
+        })
+        .count();
+    assert_eq!(pyenv, 2);
+
+    // Version managers are consulted for system interpreters.
+    let installations = list("only-system", true)?;
+    assert_eq!(installations.len(), 4, "{installations:?}");
+
+    // But not if only managed interpreters are allowed.
+    let installations = list("only-managed", true)?;
+    assert!(installations.is_empty(), "{installations:?}");
+
+    Ok(())
+}
@@ -16,7 +16,7 @@ fn python_pin() {
     // Without arguments, we attempt to read the current pin (which does not exist yet)
     uv_snapshot!(context.filters(), context.python_pin(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -210,7 +210,7 @@ fn python_pin_global_if_no_local() -> Result<()> {
     // Without arguments, we attempt to read the current pin (which does not exist yet)
     uv_snapshot!(context.filters(), context.python_pin(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -388,7 +388,7 @@ fn python_pin_compatible_with_requires_python() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.python_pin().arg("3.10"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -398,7 +398,7 @@ fn python_pin_compatible_with_requires_python() -> Result<()> {
     // Request a implementation version that is incompatible
     uv_snapshot!(context.filters(), context.python_pin().arg("cpython@3.10"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -589,7 +589,7 @@ fn python_pin_resolve_no_python() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_sources();
     uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -732,7 +732,7 @@ fn python_pin_resolve() {
     #[cfg(unix)]
     uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("pypy"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -753,7 +753,7 @@ fn python_pin_resolve() {
     #[cfg(unix)]
     uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("3.7"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -839,7 +839,7 @@ fn python_pin_rm() {
 
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -859,7 +859,7 @@ fn python_pin_rm() {
 
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("--global"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -870,7 +870,7 @@ fn python_pin_rm() {
     context.python_pin().arg("3.12").assert().success();
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("--global"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -914,7 +914,7 @@ fn python_pin_rm() {
     // The global pin should not be removed without `--global`
     uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -0,0 +1,128 @@
+//! Tests that `uv pip compile` and `uv lock` produce byte-identical output across repeated runs.
+//!
+//! Each run happens in a separate process, such that any dependence on hash map iteration order,
+//! the order in which the resolver visits packages, or the line endings of the inputs shows up as
+//! a difference between the outputs.
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use uv_static::EnvVars;
+
+use crate::common::TestContext;
+
+/// The concurrency settings to vary between runs, which affect the order in which the resolver
+/// receives metadata.
+const CONCURRENT_DOWNLOADS: &[&str] = &["1", "4", "50"];
+
+/// Run `uv pip compile` repeatedly, with varying concurrency and line endings, and diff the outputs.
+#[test]
+fn pip_compile_reproducible() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements = [
+        "anyio==3.7.0  # pinned for CI",
+        "black[d]",
+        "pydantic",
+        "colorama ; sys_platform == 'win32'",
+        "uvloop ; sys_platform != 'win32'",
+    ];
+
+    let compile = |line_ending: &str, concurrency: &str| -> Result<String> {
+        let requirements_in = context.temp_dir.child("requirements.in");
+        requirements_in.write_str(&(requirements.join(line_ending) + line_ending))?;
+
+        let requirements_txt = context.temp_dir.child("requirements.txt");
+        if requirements_txt.exists() {
+            fs_err::remove_file(&requirements_txt)?;
+        }
+
+        context
+            .pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--generate-hashes")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .env(EnvVars::UV_CONCURRENT_DOWNLOADS, concurrency)
+            .assert()
+            .success();
+
+        Ok(fs_err::read_to_string(&requirements_txt)?)
+    };
+
+    let expected = compile("\n", CONCURRENT_DOWNLOADS[0])?;
+    assert!(!expected.contains('\r'));
+    for line_ending in ["\n", "\r\n"] {
+        for concurrency in CONCURRENT_DOWNLOADS {
+            let actual = compile(line_ending, concurrency)?;
+            assert_eq!(
+                expected, actual,
+                "line ending: {line_ending:?}, concurrency: {concurrency}"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Run `uv lock` repeatedly from scratch, with varying concurrency and line endings, and diff the
+/// lockfiles.
+#[test]
+fn lock_reproducible() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+            "black[d]",
+            "colorama ; sys_platform == 'win32'",
+            "uvloop ; sys_platform != 'win32'",
+        ]
+
+        [project.optional-dependencies]
+        types = ["pydantic"]
+
+        [dependency-groups]
+        dev = ["iniconfig"]
+    "#};
+
+    let lock = |line_ending: &str, concurrency: &str| -> Result<String> {
+        context
+            .temp_dir
+            .child("pyproject.toml")
+            .write_str(&pyproject_toml.replace('\n', line_ending))?;
+
+        let uv_lock = context.temp_dir.child("uv.lock");
+        if uv_lock.exists() {
+            fs_err::remove_file(&uv_lock)?;
+        }
+
+        context
+            .lock()
+            .env(EnvVars::UV_CONCURRENT_DOWNLOADS, concurrency)
+            .assert()
+            .success();
+
+        Ok(fs_err::read_to_string(&uv_lock)?)
+    };
+
+    let expected = lock("\n", CONCURRENT_DOWNLOADS[0])?;
+    assert!(!expected.contains('\r'));
+    for line_ending in ["\n", "\r\n"] {
+        for concurrency in CONCURRENT_DOWNLOADS {
+            let actual = lock(line_ending, concurrency)?;
+            assert_eq!(
+                expected, actual,
+                "line ending: {line_ending:?}, concurrency: {concurrency}"
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use itertools::Itertools;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerEnvironment;
+use uv_resolver::Lock;
+use uv_resolver_testkit::{Universe, UniverseOptions, check_install, check_lock};
+
+use crate::common::TestContext;
+
+/// Print the marker environment of the running interpreter as JSON.
+const MARKER_ENVIRONMENT: &str = r#"
+import json, os, platform, sys
+
+def format_full_version(info):
+    version = "{0.major}.{0.minor}.{0.micro}".format(info)
+    if info.releaselevel != "final":
+        version += info.releaselevel[0] + str(info.serial)
+    return version
+
+print(json.dumps({
+    "implementation_name": sys.implementation.name,
+    "implementation_version": format_full_version(sys.implementation.version),
+    "os_name": os.name,
+    "platform_machine": platform.machine(),
+    "platform_python_implementation": platform.python_implementation(),
+    "platform_release": platform.release(),
+    "platform_system": platform.system(),
+    "platform_version": platform.version(),
+    "python_full_version": platform.python_version(),
+    "python_version": ".".join(platform.python_version_tuple()[:2]),
+    "sys_platform": sys.platform,
+}))
+"#;
+
+/// The number of universes to generate for each set of options.
+const SEEDS: u64 = 8;
+
+/// Lock and sync a series of synthetic universes, checking that each lockfile is a valid
+/// resolution of its universe, and that each environment matches its lockfile.
+#[test]
+fn resolver_testkit_universes() -> Result<()> {
+    check_universes(&UniverseOptions::default())
+}
+
+/// Lock and sync a series of synthetic universes without conditional dependencies, for which
+/// the universal resolution should succeed if and only if the universe is satisfiable.
+#[test]
+fn resolver_testkit_universes_unconditional() -> Result<()> {
+    check_universes(&UniverseOptions {
+        marker_percentage: 0,
+        ..UniverseOptions::default()
+    })
+}
+
+fn check_universes(options: &UniverseOptions) -> Result<()> {
+    let mut locked = 0;
+    for seed in 0..SEEDS {
+        let context = TestContext::new("3.12");
+        let universe = Universe::generate(seed, options);
+        universe.write_wheels(context.temp_dir.child("wheels").path())?;
+
+        let dependencies = universe
+            .requirements()
+            .iter()
+            .map(|requirement| format!("{:?}", requirement.to_string()))
+            .join(", ");
+        context
+            .temp_dir
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+                [project]
+                name = "project"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = [{dependencies}]
+
+                [tool.uv]
+                no-index = true
+                find-links = ["./wheels"]
+            "#})?;
+
+        let output = context
+            .python_command()
+            .arg("-c")
+            .arg(MARKER_ENVIRONMENT)
+            .output()?;
+        let marker_env = serde_json::from_slice::<MarkerEnvironment>(&output.stdout)?;
+        let satisfiable = universe.is_satisfiable(&marker_env);
+
+        // Not every universe is satisfiable, but a universe that's unsatisfiable in the current
+        // environment must fail to resolve (rather than, e.g., produce an invalid lockfile).
+        let output = context.lock().output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(
+                stderr.contains("No solution found"),
+                "seed {seed}: unexpected failure:\n{stderr}"
+            );
+            assert!(
+                !satisfiable || options.marker_percentage > 0,
+                "seed {seed}: failed to lock a satisfiable universe:\n{stderr}"
+            );
+            continue;
+        }
+        assert!(satisfiable, "seed {seed}: locked an unsatisfiable universe");
+        locked += 1;
+
+        let lock = toml::from_str::<Lock>(&context.read("uv.lock"))?;
+        check_lock(&universe, &lock)?;
+
+        context.sync().arg("--frozen").assert().success();
+
+        let output = context.pip_freeze().output()?;
+        let installed = String::from_utf8(output.stdout)?
+            .lines()
+            .filter_map(|line| line.split_once("=="))
+            .map(|(name, version)| Ok((PackageName::from_str(name)?, Version::from_str(version)?)))
+            .collect::<Result<Vec<_>>>()?;
+        check_install(&lock, &marker_env, installed)?;
+    }
+
+    // Bound the number of skipped universes, such that a regression that causes every resolution
+    // to fail doesn't go unnoticed.
+    assert!(
+        locked >= SEEDS / 2,
+        "only {locked} of {SEEDS} universes could be locked"
+    );
+
+    Ok(())
+}
@@ -128,7 +128,7 @@ fn run_with_python_version() -> Result<()> {
 
     uv_snapshot!(context.filters(), command_with_args, @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -219,7 +219,7 @@ fn run_no_args() -> Result<()> {
     #[cfg(not(windows))]
     uv_snapshot!(context.filters(), context.run(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
     Provide a command or script to invoke with `uv run <command>` or `uv run <script>.py`.
 
@@ -241,7 +241,7 @@ fn run_no_args() -> Result<()> {
     #[cfg(windows)]
     uv_snapshot!(context.filters(), context.run(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
     Provide a command or script to invoke with `uv run <command>` or `uv run <script>.py`.
 
@@ -416,7 +416,7 @@ fn run_pep723_script() -> Result<()> {
     // Running a script with `--group` should warn.
     uv_snapshot!(context.filters(), context.run().arg("--group").arg("foo").arg("main.py"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -438,7 +438,7 @@ fn run_pep723_script() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.run().arg("--no-project").arg("main.py"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -463,7 +463,7 @@ fn run_pep723_script() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.run().arg("--no-project").arg("main.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -951,7 +951,7 @@ fn run_pep723_script_lock() -> Result<()> {
     // Re-running the script with `--locked` should error.
     uv_snapshot!(context.filters(), context.run().arg("--locked").arg("main.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1258,7 +1258,7 @@ fn run_with() -> Result<()> {
     // If the dependencies can't be resolved, we should reference `--with`.
     uv_snapshot!(context.filters(), context.run().arg("--with").arg("add").arg("main.py"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2172,7 +2172,7 @@ fn run_locked() -> Result<()> {
     // Running with `--locked` should error, if no lockfile is present.
     uv_snapshot!(context.filters(), context.run().arg("--locked").arg("--").arg("python").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2259,7 +2259,7 @@ fn run_locked() -> Result<()> {
     // Running with `--locked` should error.
     uv_snapshot!(context.filters(), context.run().arg("--locked").arg("--").arg("python").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2336,7 +2336,7 @@ fn run_frozen() -> Result<()> {
     // Running with `--frozen` should error, if no lockfile is present.
     uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2620,7 +2620,7 @@ fn run_requirements_txt() -> Result<()> {
         .arg("-")
         .stdin(std::fs::File::open(&requirements_txt)?), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2634,7 +2634,7 @@ fn run_requirements_txt() -> Result<()> {
         .arg("-")
         .stdin(std::fs::File::open(&requirements_txt)?), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2870,7 +2870,7 @@ fn run_from_directory() -> Result<()> {
     fs_err::remove_dir_all(context.temp_dir.join("project").join(".venv"))?;
     uv_snapshot!(filters.clone(), context.run().arg("--directory").arg("project").arg("./project/main.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3305,7 +3305,7 @@ fn run_module_stdin() {
 
     uv_snapshot!(context.filters(), context.run().arg("-m").arg("-"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3390,7 +3390,7 @@ fn run_isolated_incompatible_python() -> Result<()> {
     // We should reject Python 3.9...
     uv_snapshot!(context.filters(), context.run().arg("main.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3402,7 +3402,7 @@ fn run_isolated_incompatible_python() -> Result<()> {
     // ...even if `--isolated` is provided.
     uv_snapshot!(context.filters(), context.run().arg("--isolated").arg("main.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3584,6 +3584,50 @@ fn run_isolated_with_frozen() -> Result<()> {
     Ok(())
 }
 
+/// `--keep` should retain the ephemeral environment after the command exits.
+#[test]
+fn run_isolated_keep() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"ephemeral-[a-zA-Z0-9]+", "ephemeral-[RANDOM]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.run()
+        .arg("--no-project")
+        .arg("--isolated")
+        .arg("--keep")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print(sys.executable)"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/builds-v0/[TMP]/python
+
+    ----- stderr -----
+    Retaining ephemeral environment at: [CACHE_DIR]/builds-v0/ephemeral-[RANDOM]
+    ");
+
+    // The environment should be retained, without a lock file that would mark it as stale.
+    let builds = context.cache_dir.child("builds-v0");
+    let mut entries = Vec::new();
+    for entry in fs_err::read_dir(&builds)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with("ephemeral-") {
+            entries.push(name);
+        }
+    }
+    let [entry] = entries.as_slice() else {
+        panic!("Expected a single ephemeral entry in the builds bucket: {entries:?}");
+    };
+    assert!(builds.child(entry).child("pyvenv.cfg").exists());
+
+    Ok(())
+}
+
 #[test]
 fn run_compiled_python_file() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3727,7 +3771,7 @@ fn run_invalid_project_table() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.run().arg("main.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3771,7 +3815,7 @@ fn run_script_without_build_system() -> Result<()> {
     // <https://github.com/astral-sh/uv/issues/7428>
     uv_snapshot!(context.filters(), context.run().arg("entry"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3957,7 +4001,7 @@ fn run_script_explicit_directory() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.run().arg("--script").arg("script"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4681,7 +4725,7 @@ fn run_with_not_existing_env_file() -> Result<()> {
 
     uv_snapshot!(filters, context.run().arg("--env-file").arg(".env.development").arg("test.py"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5151,7 +5195,7 @@ fn run_groups_requires_python() -> Result<()> {
         .arg("-p").arg("3.11")
         .arg("python").arg("-c").arg("import typing_extensions"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5164,7 +5208,7 @@ fn run_groups_requires_python() -> Result<()> {
         .arg("--group").arg("foo")
         .arg("python").arg("-c").arg("import typing_extensions"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -5247,7 +5291,7 @@ fn run_groups_include_requires_python() -> Result<()> {
         .arg("--group").arg("bar")
         .arg("python").arg("-c").arg("import typing_extensions"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5262,7 +5306,7 @@ fn run_groups_include_requires_python() -> Result<()> {
         .arg("-p").arg("3.13")
         .arg("python").arg("-c").arg("import typing_extensions"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5454,7 +5498,7 @@ fn detect_infinite_recursion() -> Result<()> {
 
     uv_snapshot!(context.filters(), cmd, @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5525,7 +5569,7 @@ fn run_windows_legacy_scripts() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.run(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
     Provide a command or script to invoke with `uv run <command>` or `uv run <script>.py`.
 
@@ -6067,7 +6111,7 @@ fn run_only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --extra together should error.
     uv_snapshot!(context.filters(), context.run().arg("--only-group").arg("dev").arg("--extra").arg("test").arg("python").arg("-c").arg("print('hello')"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6081,7 +6125,7 @@ fn run_only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --all-extras together should also error.
     uv_snapshot!(context.filters(), context.run().arg("--only-group").arg("dev").arg("--all-extras").arg("python").arg("-c").arg("print('hello')"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6094,3 +6138,206 @@ fn run_only_group_and_extra_conflict() -> Result<()> {
 
     Ok(())
 }
+
+/// Run a command in each workspace member, in dependency order.
+#[test]
+fn run_each() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+        })?;
+    context
+        .temp_dir
+        .child("packages/bird-feeder/pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "bird-feeder"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = ["seeds"]
+
+        [tool.uv.sources]
+        seeds = { workspace = true }
+        "#
+        })?;
+    context
+        .temp_dir
+        .child("packages/seeds/pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "seeds"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+
+    let print_name =
+        "import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])";
+
+    // Dependencies run before their dependents, from the root of each member.
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_CONCURRENT_BUILDS, "1").arg("--each").arg("python").arg("-c").arg(print_name), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds
+    bird-feeder
+    albatross
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Running `python -c import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])` in `seeds`
+    Running `python -c import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])` in `bird-feeder`
+    Running `python -c import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])` in `albatross`
+    "###);
+
+    // Select a subset of the members.
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_CONCURRENT_BUILDS, "1").arg("--each").arg("--package").arg("seeds").arg("--package").arg("bird-feeder").arg("python").arg("-c").arg(print_name), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds
+    bird-feeder
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Running `python -c import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])` in `seeds`
+    Running `python -c import tomllib; print(tomllib.load(open('pyproject.toml', 'rb'))['project']['name'])` in `bird-feeder`
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--each").arg("--package").arg("penguin").arg("python").arg("-c").arg("pass"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `penguin` not found in workspace
+    "###);
+
+    // If a member fails, its dependents are skipped.
+    context.temp_dir.child("packages/seeds/fail").touch()?;
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_CONCURRENT_BUILDS, "1").arg("--each").arg("python").arg("-c").arg("import os, sys; sys.exit(os.path.exists('fail'))"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Running `python -c import os, sys; sys.exit(os.path.exists('fail'))` in `seeds`
+    error: `python -c import os, sys; sys.exit(os.path.exists('fail'))` failed in `seeds`
+    Skipped `bird-feeder`, since its dependency `seeds` failed
+    Skipped `albatross`, since its dependency `bird-feeder` failed
+    "###);
+
+    Ok(())
+}
+
+/// Run a command in the workspace members affected by changes since a Git revision.
+#[test]
+#[cfg(feature = "git")]
+fn run_each_affected() -> Result<()> {
+    use indoc::formatdoc;
+
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["seeds"]
+
+        [tool.uv.sources]
+        seeds = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+        })?;
+    for name in ["bird-feeder", "seeds"] {
+        context
+            .temp_dir
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+            })?;
+    }
+
+    for args in [
+        &["init", "--quiet"][..],
+        &["add", "."][..],
+        &[
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "Initial commit",
+        ][..],
+    ] {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&context.temp_dir)
+            .assert()
+            .success();
+    }
+
+    uv_snapshot!(context.filters(), context.run().arg("--each").arg("--affected").arg("HEAD").arg("python").arg("-c").arg("pass"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No workspace members are affected by changes since `HEAD`
+    "###);
+
+    // Changing `seeds` affects `seeds` and its dependent, `albatross`, but not `bird-feeder`.
+    context
+        .temp_dir
+        .child("packages/seeds/seeds.py")
+        .write_str("")?;
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_CONCURRENT_BUILDS, "1").arg("--each").arg("--affected").arg("HEAD").arg("python").arg("-c").arg("pass"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Running `python -c pass` in `seeds`
+    Running `python -c pass` in `albatross`
+    "###);
+
+    Ok(())
+}
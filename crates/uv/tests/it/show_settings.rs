@@ -17,6 +17,7 @@ fn add_shared_args(mut command: Command, cwd: &Path) -> Command {
         .env(EnvVars::UV_CONCURRENT_DOWNLOADS, "50")
         .env(EnvVars::UV_CONCURRENT_BUILDS, "16")
         .env(EnvVars::UV_CONCURRENT_INSTALLS, "8")
+        .env(EnvVars::UV_CONCURRENT_COMPILES, "8")
         // Set an explicit `XDG_CONFIG_DIRS` to avoid loading system configuration.
         .env(EnvVars::XDG_CONFIG_DIRS, cwd)
         // Set an explicit `XDG_CONFIG_HOME` to avoid loading user configuration.
@@ -66,13 +67,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -82,12 +89,28 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -99,10 +122,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -150,6 +177,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -201,6 +231,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -225,6 +256,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -232,6 +266,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -265,13 +305,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -281,12 +327,28 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -298,10 +360,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -349,6 +415,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -400,6 +469,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -424,6 +494,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -431,6 +504,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -465,13 +544,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -481,12 +566,28 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -498,10 +599,14 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -549,6 +654,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -600,6 +708,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -624,6 +733,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -631,6 +743,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -697,13 +815,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -713,12 +837,28 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -730,10 +870,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -781,6 +925,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -832,6 +979,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -856,6 +1004,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -863,6 +1014,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -898,13 +1055,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -914,12 +1077,28 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -931,10 +1110,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -948,6 +1131,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -999,6 +1185,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1023,6 +1210,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -1030,6 +1220,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1075,13 +1271,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -1091,12 +1293,28 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -1108,10 +1326,14 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1159,6 +1381,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1210,6 +1435,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1236,6 +1462,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -1243,6 +1472,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1301,13 +1536,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -1317,12 +1558,28 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -1334,10 +1591,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1418,6 +1679,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1469,6 +1733,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1493,6 +1758,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -1500,6 +1768,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1535,13 +1809,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -1551,12 +1831,28 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -1568,10 +1864,14 @@ fn resolve_index_url() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1687,6 +1987,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1738,6 +2041,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1762,6 +2066,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -1769,6 +2076,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1827,13 +2140,19 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -1843,12 +2162,28 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -1860,10 +2195,14 @@ fn resolve_find_links() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1911,6 +2250,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
                     },
                 ],
                 no_index: true,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -1962,6 +2304,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1986,6 +2329,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -1993,6 +2339,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2050,13 +2402,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -2066,12 +2424,28 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -2083,10 +2457,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2100,6 +2478,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2151,6 +2532,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2175,6 +2557,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -2182,6 +2567,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2232,13 +2623,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -2248,12 +2645,28 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -2265,10 +2678,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2349,6 +2766,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2400,6 +2820,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2424,6 +2845,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -2431,6 +2855,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2464,13 +2894,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -2480,12 +2916,28 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -2497,10 +2949,14 @@ fn resolve_top_level() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2581,6 +3037,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2632,6 +3091,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2656,6 +3116,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -2663,6 +3126,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2719,13 +3188,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -2735,12 +3210,28 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -2752,10 +3243,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2769,6 +3264,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2820,6 +3318,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2844,6 +3343,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -2851,6 +3353,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2891,13 +3399,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -2907,12 +3421,28 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -2924,10 +3454,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2941,6 +3475,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -2992,6 +3529,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3016,6 +3554,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -3023,6 +3564,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3063,13 +3610,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -3079,12 +3632,28 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -3096,10 +3665,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3113,6 +3686,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3164,6 +3740,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3188,6 +3765,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -3195,6 +3775,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3237,13 +3823,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -3253,12 +3845,28 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -3270,10 +3878,14 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3287,6 +3899,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3338,6 +3953,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3362,6 +3978,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -3369,6 +3988,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3430,13 +4055,19 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -3446,12 +4077,28 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3479,6 +4126,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             extra_index_url: None,
             no_index: None,
             find_links: None,
+            index_namespaces: None,
             index_strategy: None,
             keyring_provider: None,
             resolution: Some(
@@ -3486,6 +4134,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             ),
             prerelease: None,
             fork_strategy: None,
+            allow_insecure_md5_hashes: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
@@ -3494,6 +4143,8 @@ fn resolve_tool() -> anyhow::Result<()> {
             extra_build_variables: None,
             exclude_newer: None,
             exclude_newer_package: None,
+            exclude_newer_than_days: None,
+            exclude_newer_than_days_package: None,
             link_mode: Some(
                 Clone,
             ),
@@ -3508,6 +4159,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -3526,12 +4178,18 @@ fn resolve_tool() -> anyhow::Result<()> {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -3615,13 +4273,19 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -3631,12 +4295,28 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -3648,10 +4328,14 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3665,6 +4349,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3716,6 +4403,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3740,6 +4428,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -3747,6 +4438,12 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3821,13 +4518,19 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -3837,12 +4540,28 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -3854,10 +4573,14 @@ fn resolve_both() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3905,6 +4628,9 @@ fn resolve_both() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -3956,6 +4682,7 @@ fn resolve_both() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3980,6 +4707,9 @@ fn resolve_both() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -3987,6 +4717,12 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4066,13 +4802,19 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -4082,12 +4824,28 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -4099,10 +4857,14 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4150,6 +4912,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4201,6 +4966,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4225,6 +4991,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -4232,6 +5001,12 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4274,7 +5049,7 @@ fn invalid_conflicts() -> anyhow::Result<()> {
     // The file should be rejected for violating the schema.
     uv_snapshot!(context.filters(), add_shared_args(context.lock(), context.temp_dir.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4301,7 +5076,7 @@ fn invalid_conflicts() -> anyhow::Result<()> {
     // The file should be rejected for violating the schema.
     uv_snapshot!(context.filters(), add_shared_args(context.lock(), context.temp_dir.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4390,13 +5165,19 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -4406,12 +5187,28 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -4423,10 +5220,14 @@ fn resolve_config_file() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4474,6 +5275,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4525,6 +5329,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4549,6 +5354,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -4556,6 +5364,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4591,7 +5405,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         .arg(config.path())
         .arg("requirements.in"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4600,7 +5414,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `concurrent-compiles`, `concurrent-prefetches`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4625,7 +5439,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         .arg(config.path())
         .arg("requirements.in"), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4689,13 +5503,19 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -4705,12 +5525,28 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -4722,10 +5558,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4739,6 +5579,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4790,6 +5633,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4814,6 +5658,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -4821,6 +5668,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4864,13 +5717,19 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -4880,12 +5739,28 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -4897,10 +5772,14 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4914,6 +5793,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -4965,6 +5847,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4989,6 +5872,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -4996,6 +5882,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5058,13 +5950,19 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -5074,12 +5972,28 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -5091,10 +6005,14 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5108,6 +6026,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5159,6 +6080,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5183,6 +6105,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -5190,6 +6115,12 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5244,13 +6175,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -5260,12 +6197,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -5277,10 +6230,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5363,6 +6320,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5414,6 +6374,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5438,6 +6399,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -5445,6 +6409,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5478,13 +6448,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -5494,12 +6470,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -5511,10 +6503,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5597,6 +6593,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5648,6 +6647,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5672,6 +6672,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -5679,6 +6682,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5718,13 +6727,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -5734,12 +6749,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -5751,10 +6782,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5837,6 +6872,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -5888,6 +6926,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5912,6 +6951,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -5919,6 +6961,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5953,13 +7001,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -5969,12 +7023,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -5986,10 +7056,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6072,6 +7146,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6123,6 +7200,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6147,6 +7225,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -6154,6 +7235,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6195,13 +7282,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -6211,12 +7304,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -6228,10 +7337,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6314,6 +7427,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6365,6 +7481,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6389,6 +7506,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -6396,6 +7516,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6430,13 +7556,19 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -6446,12 +7578,28 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -6463,10 +7611,14 @@ fn index_priority() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6549,6 +7701,9 @@ fn index_priority() -> anyhow::Result<()> {
                 ],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6600,6 +7755,7 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6624,6 +7780,9 @@ fn index_priority() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -6631,6 +7790,12 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6678,13 +7843,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -6694,12 +7865,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -6711,10 +7898,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6728,6 +7920,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6779,6 +7974,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6803,6 +7999,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -6810,6 +8009,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6843,13 +8048,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -6859,12 +8070,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -6876,10 +8103,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6893,6 +8125,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -6944,6 +8179,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6968,6 +8204,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -6975,6 +8214,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7006,13 +8251,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -7022,12 +8273,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -7039,10 +8306,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7056,6 +8328,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7107,6 +8382,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7131,6 +8407,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -7138,6 +8417,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7171,13 +8456,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -7187,12 +8478,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -7204,10 +8511,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7221,6 +8533,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7272,6 +8587,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7296,6 +8612,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -7303,6 +8622,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7334,13 +8659,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -7350,12 +8681,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -7367,10 +8714,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7384,6 +8736,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7435,6 +8790,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7459,6 +8815,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -7466,6 +8825,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7498,13 +8863,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -7514,12 +8885,28 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -7531,10 +8918,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        allow_script_conflicts: false,
+        exclude_source: false,
+        no_cache_download: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_override: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7548,6 +8940,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -7599,6 +8994,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7623,6 +9019,9 @@ fn verify_hashes() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -7630,6 +9029,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7677,28 +9082,50 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | TOOL_SHIMS | TORCH_BACKEND,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -7727,6 +9154,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -7745,12 +9173,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -7789,13 +9223,19 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -7805,12 +9245,28 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -7839,6 +9295,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -7857,12 +9314,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -7901,28 +9364,50 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | TOOL_SHIMS | TORCH_BACKEND,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -7951,6 +9436,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -7969,12 +9455,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8013,13 +9505,19 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE,
@@ -8029,12 +9527,28 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -8063,6 +9577,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8081,12 +9596,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8125,13 +9646,19 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE,
@@ -8141,12 +9668,28 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -8175,6 +9718,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8193,12 +9737,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8239,13 +9789,19 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -8255,12 +9811,28 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     VersionSettings {
         value: None,
@@ -8289,6 +9861,7 @@ fn preview_features() {
         ),
         settings: ResolverInstallerSettings {
             resolver: ResolverSettings {
+                allow_insecure_md5_hashes: false,
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
@@ -8307,12 +9880,18 @@ fn preview_features() {
                     package: ExcludeNewerPackage(
                         {},
                     ),
+                    global_days: None,
+                    package_days: {},
+                    as_of: false,
                 },
                 fork_strategy: RequiresPython,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
                     no_index: false,
+                    namespaces: IndexNamespaces(
+                        {},
+                    ),
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
@@ -8372,13 +9951,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -8388,12 +9973,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -8405,10 +10006,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8422,6 +10027,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -8473,6 +10081,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -8497,6 +10106,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -8504,6 +10116,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -8545,13 +10163,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -8561,12 +10185,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -8578,10 +10218,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8595,6 +10239,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -8646,6 +10293,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -8670,6 +10318,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -8677,6 +10328,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -8741,13 +10398,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -8757,12 +10420,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -8774,10 +10453,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8791,6 +10474,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -8842,6 +10528,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -8866,6 +10553,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -8873,6 +10563,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -8912,13 +10608,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -8928,12 +10630,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -8945,10 +10663,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8962,6 +10684,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9013,6 +10738,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9037,6 +10763,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -9044,6 +10773,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9077,13 +10812,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9093,12 +10834,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -9110,10 +10867,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9127,6 +10888,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9178,6 +10942,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9202,6 +10967,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -9209,6 +10977,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9243,13 +11017,19 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9259,12 +11039,28 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -9276,10 +11072,14 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9293,6 +11093,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -9344,6 +11147,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9368,6 +11172,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -9375,6 +11182,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9474,13 +11287,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9490,18 +11309,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -9517,6 +11356,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9535,12 +11375,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -9591,13 +11437,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9607,18 +11459,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -9634,6 +11506,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9652,12 +11525,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -9731,13 +11610,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9747,18 +11632,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -9774,6 +11679,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9792,12 +11698,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -9846,13 +11758,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9862,18 +11780,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -9889,6 +11827,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -9907,12 +11846,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -9951,13 +11896,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -9967,18 +11918,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -9994,6 +11965,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10012,12 +11984,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10057,13 +12035,19 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -10073,18 +12057,38 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     LockSettings {
         locked: false,
         frozen: false,
         dry_run: Disabled,
+        integrity: false,
+        resolve_merge_conflicts: false,
+        merge: None,
         script: None,
+        profile: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -10100,6 +12104,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             ),
         ),
         settings: ResolverSettings {
+            allow_insecure_md5_hashes: false,
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -10118,12 +12123,18 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             fork_strategy: RequiresPython,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
@@ -10227,13 +12238,19 @@ fn build_isolation_override() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -10243,12 +12260,28 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -10260,10 +12293,14 @@ fn build_isolation_override() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10277,6 +12314,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -10328,6 +12368,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -10352,6 +12393,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -10359,6 +12403,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10395,13 +12445,19 @@ fn build_isolation_override() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            limit_rate: None,
+            metered: false,
+            mirror_manifest: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
+            prefetches: 50,
         },
         show_settings: true,
+        show_settings_format: Text,
         preview: Preview {
             flags: PreviewFeatures(
                 0x0,
@@ -10411,12 +12467,28 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        quarantine: Preserve,
+        locked_timeout: None,
+        plugins: Plugins(
+            {},
+        ),
+        theme: Theme {
+            accent: Cyan,
+            success: Green,
+            warning: Yellow,
+            error: Red,
+            ascii_only: false,
+        },
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        index_policy: IndexCachePolicy {
+            ttl: None,
+            stale_while_revalidate: false,
+        },
     }
     PipCompileSettings {
         format: None,
@@ -10428,10 +12500,14 @@ fn build_isolation_override() -> anyhow::Result<()> {
         build_constraints: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
+        scoped_overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        verify: false,
+        reuse_hashes: true,
+        marker_snapshot: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10445,6 +12521,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 indexes: [],
                 flat_index: [],
                 no_index: false,
+                namespaces: IndexNamespaces(
+                    {},
+                ),
             },
             python: None,
             install_mirrors: PythonInstallMirrors {
@@ -10502,6 +12581,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            allow_insecure_md5_hashes: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -10526,6 +12606,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 package: ExcludeNewerPackage(
                     {},
                 ),
+                global_days: None,
+                package_days: {},
+                as_of: false,
             },
             no_emit_package: [],
             emit_index_url: false,
@@ -10533,6 +12616,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_license: false,
+            emit_homepage: false,
+            emit_latest_version: false,
+            emit_summary: false,
+            emit_fork_annotation: false,
+            emit_provenance: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10550,3 +12639,99 @@ fn build_isolation_override() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Show the resolved settings as JSON, one line per settings type.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn show_settings_json() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        [pip]
+        resolution = "lowest-direct"
+        generate-hashes = true
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let output = add_shared_args(context.pip_compile(), context.temp_dir.path())
+        .arg("--show-settings")
+        .arg("--show-settings-format")
+        .arg("json")
+        .arg("requirements.in")
+        .output()?;
+    assert!(output.status.success());
+
+    let settings = String::from_utf8(output.stdout)?
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(settings.len(), 3);
+
+    let globals = &settings[0]["GlobalSettings"];
+    assert_eq!(globals["quiet"], 0);
+    assert_eq!(globals["show_settings"], true);
+    assert_eq!(globals["network_settings"]["connectivity"], "Online");
+
+    let cache = &settings[1]["CacheSettings"];
+    assert_eq!(cache["no_cache"], false);
+
+    let compile = &settings[2]["PipCompileSettings"];
+    assert_eq!(compile["settings"]["resolution"], "lowest-direct");
+    assert_eq!(compile["settings"]["generate_hashes"], true);
+    assert_eq!(
+        compile["settings"]["python_version"],
+        serde_json::Value::Null
+    );
+
+    Ok(())
+}
+
+/// The generated JSON Schema should match the checked-in `uv.schema.json`.
+#[test]
+fn generate_schema() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context.command().arg("generate-schema").output()?;
+    assert!(output.status.success());
+
+    let expected = fs_err::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("..")
+            .join("uv.schema.json"),
+    )?;
+    assert_eq!(String::from_utf8(output.stdout)?.trim_end(), expected);
+
+    Ok(())
+}
+
+/// The generated JSON Schema for `--show-settings` should match the checked-in
+/// `show-settings.schema.json`.
+#[test]
+fn generate_show_settings_schema() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .command()
+        .arg("generate-schema")
+        .arg("--kind")
+        .arg("show-settings")
+        .output()?;
+    assert!(output.status.success());
+
+    let expected = fs_err::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("..")
+            .join("show-settings.schema.json"),
+    )?;
+    assert_eq!(String::from_utf8(output.stdout)?.trim_end(), expected);
+
+    Ok(())
+}
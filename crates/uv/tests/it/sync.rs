@@ -44,6 +44,196 @@ fn sync() -> Result<()> {
     Ok(())
 }
 
+/// Verify the integrity hash and signature of the lockfile before syncing.
+#[test]
+fn sync_verify_lock_signature() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"sha256:[0-9a-f]{64}", "sha256:[HASH]")])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Without an integrity hash, verification should fail.
+    context.lock().assert().success();
+
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--verify-lock-signature"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile at `uv.lock` does not include an integrity hash. To add one, run `uv lock --integrity`.
+    ");
+
+    // Sign the lockfile.
+    context
+        .lock()
+        .arg("--integrity")
+        .env(EnvVars::UV_LOCK_SIGNING_KEY, "secret")
+        .assert()
+        .success();
+
+    // Verification should fail with a different key.
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--verify-lock-signature").env(EnvVars::UV_LOCK_SIGNING_KEY, "other"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile signature at `uv.lock.sig` does not match the lockfile
+    ");
+
+    // Verification should fail if the lockfile is modified.
+    let lock = context.read("uv.lock");
+    context.temp_dir.child("uv.lock").write_str(&lock.replace(
+        "requires-python = \">=3.12\"",
+        "requires-python = \">=3.11\"",
+    ))?;
+
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--verify-lock-signature").env(EnvVars::UV_LOCK_SIGNING_KEY, "secret"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile at `uv.lock` does not match its integrity hash (expected `sha256:[HASH]`, found `sha256:[HASH]`). The lockfile may have been modified outside of uv.
+    ");
+
+    // Verification should succeed with the original lockfile and key.
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--verify-lock-signature").env(EnvVars::UV_LOCK_SIGNING_KEY, "secret"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Verification implies `--locked`, so an outdated lockfile is never rewritten (or re-signed).
+    let signature = context.read("uv.lock.sig");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.sync().arg("--verify-lock-signature").env(EnvVars::UV_LOCK_SIGNING_KEY, "secret"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    assert_eq!(context.read("uv.lock"), lock);
+    assert_eq!(context.read("uv.lock.sig"), signature);
+
+    Ok(())
+}
+
+/// Install packages into a shared store, and remove them once they're no longer referenced.
+#[test]
+#[cfg(unix)]
+fn sync_site_packages_dir() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let store = context.temp_dir.child("store");
+
+    // The store requires symlink-based installation.
+    uv_snapshot!(context.filters(), context.sync().arg("--site-packages-dir").arg(store.path()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--site-packages-dir` requires `--link-mode=symlink`
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--link-mode").arg("symlink").arg("--site-packages-dir").arg(store.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The package should be linked from the store.
+    let init = context
+        .site_packages()
+        .join("iniconfig")
+        .join("__init__.py");
+    assert!(fs_err::read_link(&init)?.starts_with(store.path().join("packages")));
+
+    // The package is still in use, so it should be retained.
+    uv_snapshot!(context.filters(), context.store_gc().arg("--site-packages-dir").arg(store.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning store at: store
+    No unused packages found
+    ");
+
+    // Once the environment is removed, the package should be removed from the store. Entries
+    // that are still being copied into the store should be retained.
+    fs_err::remove_dir_all(&context.venv)?;
+    let temp_entry = store.child("packages").child(".tmpABC123");
+    temp_entry.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.store_gc().arg("--site-packages-dir").arg(store.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning store at: store
+    Removed 1 package ([SIZE])
+    ");
+
+    assert!(temp_entry.exists());
+
+    Ok(())
+}
+
 #[test]
 fn locked() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -62,7 +252,7 @@ fn locked() -> Result<()> {
     // Running with `--locked` should error, if no lockfile is present.
     uv_snapshot!(context.filters(), context.sync().arg("--locked"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -122,7 +312,7 @@ fn frozen() -> Result<()> {
     // Running with `--frozen` should error, if no lockfile is present.
     uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -614,7 +804,7 @@ fn mixed_requires_python() -> Result<()> {
     // Running `uv sync` again should fail.
     uv_snapshot!(context.filters(), context.sync().arg("-p").arg("3.9"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -660,7 +850,7 @@ fn group_requires_python_useful_defaults() -> Result<()> {
     uv_snapshot!(context.filters(), context.sync()
         .arg("--no-dev"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -677,7 +867,7 @@ fn group_requires_python_useful_defaults() -> Result<()> {
     // Running `uv sync` should always fail, as now sphinx is involved
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -804,7 +994,7 @@ fn group_requires_python_useful_non_defaults() -> Result<()> {
     // even though it's not enabled, or even a default!
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -822,7 +1012,7 @@ fn group_requires_python_useful_non_defaults() -> Result<()> {
     uv_snapshot!(context.filters(), context.sync()
         .arg("--group").arg("mygroup"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1234,7 +1424,7 @@ fn sync_legacy_non_project_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("bop"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2864,7 +3054,7 @@ fn sync_environment() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3481,7 +3671,7 @@ fn sync_non_existent_group() -> Result<()> {
     // Requesting a non-existent group should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3491,7 +3681,7 @@ fn sync_non_existent_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-group").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3554,7 +3744,7 @@ fn sync_non_existent_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--group").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3594,7 +3784,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--no-dev")
         .arg("--only-dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3610,7 +3800,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--dev")
         .arg("--only-group").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3627,7 +3817,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--dev")
         .arg("--only-group").arg("dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3644,7 +3834,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--only-dev")
         .arg("--group").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3661,7 +3851,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--only-dev")
         .arg("--group").arg("dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3677,7 +3867,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--all-groups")
         .arg("--only-dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3693,7 +3883,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--all-groups")
         .arg("--only-group").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3709,7 +3899,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--group").arg("foo")
         .arg("--only-group").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3726,7 +3916,7 @@ fn sync_corner_groups() -> Result<()> {
         .arg("--group").arg("foo")
         .arg("--only-group").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3806,7 +3996,7 @@ fn sync_non_existent_default_group() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4223,7 +4413,7 @@ fn sync_default_groups_gibberish() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r#"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4596,7 +4786,7 @@ fn sync_non_existent_extra() -> Result<()> {
     // Requesting a non-existent extra should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4607,7 +4797,7 @@ fn sync_non_existent_extra() -> Result<()> {
     // Excluding a non-existing extra when requesting all extras should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--all-extras").arg("--no-extra").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4637,7 +4827,7 @@ fn sync_non_existent_extra_no_optional_dependencies() -> Result<()> {
     // Requesting a non-existent extra should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4648,7 +4838,7 @@ fn sync_non_existent_extra_no_optional_dependencies() -> Result<()> {
     // Excluding a non-existing extra when requesting all extras should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--all-extras").arg("--no-extra").arg("baz"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4859,7 +5049,7 @@ fn sync_non_existent_extra_workspace_member() -> Result<()> {
     // Requesting an extra that only exists in the child should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--extra").arg("async"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -4959,7 +5149,7 @@ fn sync_non_existent_extra_non_project_workspace() -> Result<()> {
     // Syncing from an unrelated child should fail.
     uv_snapshot!(context.filters(), context.sync().arg("--package").arg("other").arg("--extra").arg("async"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5052,7 +5242,7 @@ fn no_install_project() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-install-project"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5167,7 +5357,7 @@ fn no_install_workspace() -> Result<()> {
     // Unless the package doesn't exist.
     uv_snapshot!(context.filters(), context.sync().arg("--package").arg("fake").arg("--no-install-workspace").arg("--frozen"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5192,7 +5382,7 @@ fn no_install_workspace() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-install-workspace").arg("--frozen"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5369,7 +5559,7 @@ fn no_install_project_no_build() -> Result<()> {
     // `--no-build` should raise an error, since we try to install the project.
     uv_snapshot!(context.filters(), context.sync().arg("--no-build"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -5782,7 +5972,7 @@ fn virtual_no_build_dynamic_no_cache() -> Result<()> {
     // `--no-build` should raise an error, since we need to build the project.
     uv_snapshot!(context.filters(), context.sync().arg("--no-build"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -6193,43 +6383,205 @@ fn sync_custom_environment_path() -> Result<()> {
     fs_err::write(context.temp_dir.join("foo").join("file"), b"")?;
     uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROJECT_ENVIRONMENT, "foo"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project virtual environment directory `[TEMP_DIR]/foo` cannot be used because it is not a valid Python environment (no Python executable was found)
+    "###);
+
+    // But if it's just an incompatible virtual environment...
+    fs_err::remove_dir_all(context.temp_dir.join("foo"))?;
+    uv_snapshot!(context.filters(), context.venv().arg("foo").arg("--python").arg("3.11"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    warning: The requested interpreter resolved to Python 3.11.[X], which is incompatible with the project's Python requirement: `>=3.12` (from `project.requires-python`)
+    Creating virtual environment at: foo
+    Activate with: source foo/[BIN]/activate
+    ");
+
+    // Even with some extraneous content...
+    fs_err::write(context.temp_dir.join("foo").join("file"), b"")?;
+
+    // We can delete and use it
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROJECT_ENVIRONMENT, "foo"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: foo
+    Creating virtual environment at: foo
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn sync_environment_path_setting() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"])
+        .with_filtered_virtualenv_bin()
+        .with_filtered_python_names();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        environment-path = ".venv-custom"
+        "#,
+    )?;
+
+    // Running `uv sync` should create the environment at the configured path.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv-custom
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    context
+        .temp_dir
+        .child(".venv-custom")
+        .assert(predicate::path::is_dir());
+
+    // `UV_PROJECT_ENVIRONMENT` takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROJECT_ENVIRONMENT, "foo"), @r"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
-    ----- stderr -----
-    error: Project virtual environment directory `[TEMP_DIR]/foo` cannot be used because it is not a valid Python environment (no Python executable was found)
-    "###);
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: foo
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The `{platform}` placeholder is replaced with the current operating system.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        environment-path = ".venv-{platform}"
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    context
+        .temp_dir
+        .child(format!(".venv-{}", std::env::consts::OS))
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+/// Sync a named environment from `tool.uv.named-environments`.
+#[test]
+fn sync_named_environment() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"])
+        .with_filtered_virtualenv_bin()
+        .with_filtered_python_names();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\.venvs[\\/]", ".venvs/")])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["typing-extensions"]
 
-    // But if it's just an incompatible virtual environment...
-    fs_err::remove_dir_all(context.temp_dir.join("foo"))?;
-    uv_snapshot!(context.filters(), context.venv().arg("foo").arg("--python").arg("3.11"), @r"
+        [tool.uv.named-environments.py311]
+        python = "3.11"
+        extras = ["types"]
+        "#,
+    )?;
+
+    // Sync the named environment, which should use its Python version and extras.
+    uv_snapshot!(filters, context.sync().arg("--env").arg("py311"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
-    warning: The requested interpreter resolved to Python 3.11.[X], which is incompatible with the project's Python requirement: `>=3.12` (from `project.requires-python`)
-    Creating virtual environment at: foo
-    Activate with: source foo/[BIN]/activate
+    Creating virtual environment at: .venvs/py311
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + typing-extensions==4.10.0
     ");
 
-    // Even with some extraneous content...
-    fs_err::write(context.temp_dir.join("foo").join("file"), b"")?;
+    context
+        .temp_dir
+        .child(".venvs")
+        .child("py311")
+        .assert(predicate::path::is_dir());
 
-    // We can delete and use it
-    uv_snapshot!(context.filters(), context.sync().env(EnvVars::UV_PROJECT_ENVIRONMENT, "foo"), @r"
+    // The project environment is unaffected.
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    // Run a command in the named environment.
+    uv_snapshot!(filters, context.run().arg("--env").arg("py311").arg("python").arg("-c").arg("import sys, typing_extensions; print(sys.version_info[:2])"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
+    (3, 11)
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Removed virtual environment at: foo
-    Creating virtual environment at: foo
-    Resolved 2 packages in [TIME]
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0
+    Resolved 3 packages in [TIME]
+    Audited 2 packages in [TIME]
+    ");
+
+    // Request an environment that doesn't exist.
+    uv_snapshot!(filters, context.sync().arg("--env").arg("missing"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Environment `missing` is not defined in `tool.uv.named-environments`
     ");
 
     Ok(())
@@ -7103,7 +7455,7 @@ fn no_binary() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--reinstall").env(EnvVars::UV_NO_BINARY, "iniconfig"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7134,7 +7486,7 @@ fn no_binary_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-binary-package").arg("odrive"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7213,7 +7565,7 @@ fn no_build_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-build-package").arg("django-allauth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7223,7 +7575,7 @@ fn no_build_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-build"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7233,7 +7585,7 @@ fn no_build_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--reinstall").env(EnvVars::UV_NO_BUILD, "1"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7243,7 +7595,7 @@ fn no_build_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--reinstall").env(EnvVars::UV_NO_BUILD_PACKAGE, "django-allauth"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7253,7 +7605,7 @@ fn no_build_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--reinstall").env(EnvVars::UV_NO_BUILD, "django-allauth"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7295,7 +7647,7 @@ fn sync_wheel_url_source_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -7346,7 +7698,7 @@ fn sync_wheel_path_source_error() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8173,7 +8525,7 @@ fn sync_invalid_environment() -> Result<()> {
     fs_err::write(context.temp_dir.join(".venv").join("file"), b"")?;
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8236,6 +8588,38 @@ fn sync_invalid_environment() -> Result<()> {
         ");
     }
 
+    // If the base interpreter recorded in the `pyvenv.cfg` no longer exists, we should warn
+    let pyvenv_cfg = context.temp_dir.join(".venv").join("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?
+        .lines()
+        .map(|line| {
+            if line.starts_with("home ") {
+                format!(
+                    "home = {}",
+                    context.temp_dir.join("does-not-exist").display()
+                )
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs_err::write(&pyvenv_cfg, contents)?;
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring existing virtual environment linked to non-existent Python interpreter: .venv -> does-not-exist
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
     // If the Python executable is missing entirely, we'll delete and use it
     fs_err::remove_dir_all(&bin)?;
     uv_snapshot!(context.filters(), context.sync(), @r"
@@ -8275,7 +8659,7 @@ fn sync_invalid_environment() -> Result<()> {
     // We should never delete it
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8287,7 +8671,7 @@ fn sync_invalid_environment() -> Result<()> {
     fs_err::remove_dir_all(&bin)?;
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8338,7 +8722,7 @@ fn sync_partial_environment_delete() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("-p").arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8348,7 +8732,7 @@ fn sync_partial_environment_delete() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("-p").arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8455,7 +8839,7 @@ fn sync_python_version() -> Result<()> {
     // Unless explicitly requested...
     uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.10"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8502,7 +8886,7 @@ fn sync_python_version() -> Result<()> {
     // We should warn on subsequent uses, but respect the pinned version?
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8801,7 +9185,7 @@ fn sync_all_extras() -> Result<()> {
     // Sync an extra that doesn't exist.
     uv_snapshot!(context.filters(), context.sync().arg("--all-packages").arg("--extra").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8812,7 +9196,7 @@ fn sync_all_extras() -> Result<()> {
     // Sync all extras excluding an extra that doesn't exist.
     uv_snapshot!(context.filters(), context.sync().arg("--all-packages").arg("--all-extras").arg("--no-extra").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -8926,7 +9310,7 @@ fn sync_all_extras_dynamic() -> Result<()> {
     // Sync a dynamic extra that doesn't exist in the child.
     uv_snapshot!(context.filters(), context.sync().arg("--all-packages").arg("--extra").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -9033,7 +9417,7 @@ fn sync_all_groups() -> Result<()> {
     // Sync a group that doesn't exist.
     uv_snapshot!(context.filters(), context.sync().arg("--all-packages").arg("--group").arg("foo"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10069,7 +10453,7 @@ fn url_hash_mismatch() -> Result<()> {
     // Running `uv sync` should fail.
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -10142,7 +10526,7 @@ fn path_hash_mismatch() -> Result<()> {
     // Running `uv sync` should fail.
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 1
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -10569,7 +10953,7 @@ fn sync_script() -> Result<()> {
     // `--locked` and `--frozen` should fail with helpful error messages.
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py").arg("--locked"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -10579,7 +10963,7 @@ fn sync_script() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py").arg("--frozen"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11033,7 +11417,7 @@ fn multiple_group_conflicts() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("foo").arg("--group").arg("bar"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11111,7 +11495,7 @@ fn transitive_group_conflicts_shallow() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("test").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11121,7 +11505,7 @@ fn transitive_group_conflicts_shallow() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11208,7 +11592,7 @@ fn transitive_group_conflicts_deep() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11218,7 +11602,7 @@ fn transitive_group_conflicts_deep() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--no-dev").arg("--group").arg("intermediate").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11302,7 +11686,7 @@ fn transitive_group_conflicts_siblings() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev2"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11312,7 +11696,7 @@ fn transitive_group_conflicts_siblings() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev").arg("--group").arg("dev2"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11359,7 +11743,7 @@ fn transitive_group_conflicts_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11369,7 +11753,7 @@ fn transitive_group_conflicts_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11379,7 +11763,7 @@ fn transitive_group_conflicts_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev").arg("--group").arg("test"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11389,7 +11773,7 @@ fn transitive_group_conflicts_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("test").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11399,7 +11783,7 @@ fn transitive_group_conflicts_cycle() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync().arg("--group").arg("dev").arg("--group").arg("magic"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11533,7 +11917,7 @@ fn locked_version_coherence() -> Result<()> {
     // An inconsistent lockfile should fail with `--locked`
     uv_snapshot!(context.filters(), context.sync().arg("--locked"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -11544,7 +11928,7 @@ fn locked_version_coherence() -> Result<()> {
     // Without `--locked`, we could fail or recreate the lockfile, currently, we fail.
     uv_snapshot!(context.filters(), context.lock(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12062,7 +12446,7 @@ fn sync_required_environment_hint() -> Result<()> {
 
     uv_snapshot!(filters, context.sync().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -12749,7 +13133,7 @@ fn sync_python_preference() -> Result<()> {
     // If a interpreter cannot be found, we'll fail
     uv_snapshot!(context.filters(), context.sync().arg("--managed-python").arg("-p").arg("3.11"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -12994,7 +13378,7 @@ fn sync_does_not_remove_empty_virtual_environment_directory() -> Result<()> {
     // project directory
     uv_snapshot!(context.filters(), context.sync().current_dir(&project_dir), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -13272,7 +13656,7 @@ fn sync_build_dependencies_respect_locked_versions() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -14179,7 +14563,7 @@ fn workspace_editable_conflict() -> Result<()> {
     // If the `editable` declarations are conflicting, raise an error.
     uv_snapshot!(context.filters(), context.sync(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -14213,7 +14597,7 @@ fn only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --extra together should error.
     uv_snapshot!(context.filters(), context.sync().arg("--only-group").arg("dev").arg("--extra").arg("test"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -14227,7 +14611,7 @@ fn only_group_and_extra_conflict() -> Result<()> {
     // Using --only-group and --all-extras together should also error.
     uv_snapshot!(context.filters(), context.sync().arg("--only-group").arg("dev").arg("--all-extras"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -14340,3 +14724,116 @@ fn sync_no_sources_editable_to_package_switch() -> Result<()> {
 
     Ok(())
 }
+
+/// Retain the existing installation of the project with `--no-reinstall-project`, even if its
+/// metadata changed.
+#[test]
+fn sync_no_reinstall_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // Add a dependency, which changes the project's metadata.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    // The dependency should be installed, but the project should be retained.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-reinstall-project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // `--reinstall-package` takes precedence for the project itself.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-reinstall-project").arg("--reinstall-package").arg("project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // Without `--no-reinstall-project`, the project is reinstalled after a metadata change.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        description = "A project."
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    Ok(())
+}
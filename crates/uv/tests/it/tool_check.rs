@@ -0,0 +1,250 @@
+use crate::common::{self, TestContext, uv_snapshot};
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use fs_err as fs;
+use uv_static::EnvVars;
+
+#[test]
+fn tool_check_empty() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No tools installed
+    ");
+}
+
+#[test]
+fn tool_check_repair() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black: ok
+
+    ----- stderr -----
+    ");
+
+    // Remove the Python interpreter for `black`.
+    fs::remove_dir_all(common::venv_bin_path(tool_dir.path().join("black")))?;
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    black: invalid environment (missing Python executable at `tools/black/[BIN]/[PYTHON]`)
+
+    ----- stderr -----
+    hint: Run `uv tool check --repair` to recreate broken tool environments
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .arg("--repair")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black: invalid environment (missing Python executable at `tools/black/[BIN]/[PYTHON]`)
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    Repaired `black`
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black: ok
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn tool_run_repair() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Remove the Python interpreter for `black`.
+    fs::remove_dir_all(common::venv_bin_path(tool_dir.path().join("black")))?;
+
+    // Running the tool should recreate its environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+    .arg("black")
+    .arg("--version")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.2.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: The environment for `black` is broken (missing Python executable at `tools/black/[BIN]/[PYTHON]`); recreating it
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black: ok
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// With `tool-shims`, an installed executable recreates its tool's environment if it's broken.
+#[test]
+#[cfg(unix)]
+fn tool_shim_repair() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shims")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The executable is a shim, rather than a link into the tool environment.
+    let black = bin_dir.child("black");
+    assert!(!black.path().is_symlink());
+
+    let run_black = || {
+        let mut command = std::process::Command::new(black.path());
+        context.add_shared_env(&mut command, false);
+        command
+            .arg("--version")
+            .env(EnvVars::UV_CACHE_DIR, context.cache_dir.path())
+            .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+            .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str());
+        command
+    };
+
+    uv_snapshot!(context.filters(), run_black(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.2.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    ");
+
+    // Remove the Python interpreter for `black`.
+    fs::remove_dir_all(common::venv_bin_path(tool_dir.path().join("black")))?;
+
+    // Running the executable should recreate the environment, and reinstall the shims.
+    uv_snapshot!(context.filters(), run_black(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.2.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    warning: The environment for `black` is broken (missing Python executable at `tools/black/[BIN]/[PYTHON]`); recreating it
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    ");
+
+    assert!(!black.path().is_symlink());
+
+    uv_snapshot!(context.filters(), context.tool_check()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black: ok
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
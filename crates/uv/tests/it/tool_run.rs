@@ -94,7 +94,7 @@ fn tool_run_at_version() {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -321,7 +321,7 @@ fn tool_run_warn_executable_not_in_from() {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1031,7 +1031,7 @@ fn tool_run_list_installed() {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
     Provide a command to run with `uv tool run <command>`.
 
@@ -1054,7 +1054,7 @@ fn tool_run_list_installed() {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
     Provide a command to run with `uv tool run <command>`.
 
@@ -1665,7 +1665,7 @@ fn tool_run_resolution_error() {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1983,7 +1983,7 @@ fn tool_run_python_at_version() {
         .arg("cp311")
         .arg("--version"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1996,7 +1996,7 @@ fn tool_run_python_at_version() {
         .arg("311")
         .arg("--version"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -2041,7 +2041,7 @@ fn tool_run_python_at_version() {
         .arg("python")
         .arg("--version"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2055,7 +2055,7 @@ fn tool_run_python_at_version() {
         .arg("python@3.11")
         .arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2066,7 +2066,7 @@ fn tool_run_python_at_version() {
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("python@3.12.99"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2077,7 +2077,7 @@ fn tool_run_python_at_version() {
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("python@3.300"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2089,7 +2089,7 @@ fn tool_run_python_at_version() {
         .arg("python@latest")
         .arg("--version"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2107,7 +2107,7 @@ fn tool_run_hint_version_not_available() {
         .arg("python@3.12")
         .env(EnvVars::UV_PYTHON_DOWNLOADS, "never"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2121,7 +2121,7 @@ fn tool_run_hint_version_not_available() {
         .env(EnvVars::UV_PYTHON_DOWNLOADS, "auto")
         .env(EnvVars::UV_OFFLINE, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2135,7 +2135,7 @@ fn tool_run_hint_version_not_available() {
         .env(EnvVars::UV_PYTHON_DOWNLOADS, "auto")
         .env(EnvVars::UV_NO_MANAGED_PYTHON, "true"), @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2462,7 +2462,7 @@ fn tool_run_with_existing_py_script() -> anyhow::Result<()> {
 
     uv_snapshot!(context.filters(), context.tool_run().arg("script.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2482,7 +2482,7 @@ fn tool_run_with_existing_pyw_script() -> anyhow::Result<()> {
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("script.pyw"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2501,7 +2501,7 @@ fn tool_run_with_nonexistent_py_script() {
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("script.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2519,7 +2519,7 @@ fn tool_run_with_nonexistent_pyw_script() {
     uv_snapshot!(context.filters(), context.tool_run()
         .arg("script.pyw"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2539,7 +2539,7 @@ fn tool_run_with_from_script() {
         .arg("script.py")
         .arg("ruff"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2559,7 +2559,7 @@ fn tool_run_with_script_and_from_script() {
         .arg("script.py")
         .arg("other-script.py"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2693,7 +2693,7 @@ fn tool_run_with_dependencies_from_script() -> Result<()> {
         .arg("not_pep723_script.py")
         .arg("black"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2706,7 +2706,7 @@ fn tool_run_with_dependencies_from_script() -> Result<()> {
         .arg("missing_file.py")
         .arg("black"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -3094,7 +3094,7 @@ fn tool_run_reresolve_python() -> anyhow::Result<()> {
         .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
         .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -0,0 +1,77 @@
+use crate::common::{TestContext, uv_snapshot};
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use uv_static::EnvVars;
+
+#[test]
+fn tool_show() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--with")
+        .arg("anyio")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_show()
+    .arg("black")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black ([TEMP_DIR]/tools/black/uv-receipt.toml)
+
+    [tool]
+    requirements = [
+        { name = "black", specifier = "==24.2.0" },
+        { name = "anyio" },
+    ]
+    entrypoints = [
+        { name = "black", install-path = "[TEMP_DIR]/bin/black", from = "black" },
+        { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd", from = "black" },
+    ]
+
+    [tool.options]
+    exclude-newer = "2024-03-25T00:00:00Z"
+
+    Installed packages:
+    - anyio==4.3.0
+    - black==24.2.0
+    - click==8.1.7
+    - idna==3.6
+    - mypy-extensions==1.0.0
+    - packaging==24.0
+    - pathspec==0.12.1
+    - platformdirs==4.2.0
+    - sniffio==1.3.1
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn tool_show_missing() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_show()
+    .arg("black")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `black` is not installed
+    ");
+}
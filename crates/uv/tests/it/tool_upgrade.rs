@@ -686,6 +686,100 @@ fn tool_upgrade_with() {
     "###);
 }
 
+/// Upgrade a tool whose `--with` requirements conflict with the upgraded version.
+#[test]
+fn tool_upgrade_with_conflict() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` alongside an outdated `packaging`, which limits `black` to `<23.1`.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--with")
+        .arg("packaging<22")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==22.12.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==21.3
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+     + pyparsing==3.1.2
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Upgrading `black` beyond `23.1` should fail, and identify the conflicting requirement.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("black>=23.1")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to upgrade black
+      Caused by: The additional requirement `packaging<22` conflicts with the latest compatible version of `black`
+      Caused by: Because only black<=24.3.0 is available and black>=23.1.0,<=24.3.0 depends on packaging>=22.0, we can conclude that black>=23.1 depends on packaging>=22.0.
+    And because you require black>=23.1 and packaging<22, we can conclude that your requirements are unsatisfiable.
+
+    hint: Use `uv tool upgrade black --relax-with` to remove the conflicting requirements from the tool
+    "###);
+
+    // With `--relax-with`, the conflicting requirement should be dropped.
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("black>=23.1")
+        .arg("--relax-with")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Removing `packaging<22` from `black`, as it conflicts with the latest compatible version
+    Updated black v22.12.0 -> v24.3.0
+     - black==22.12.0
+     + black==24.3.0
+     - packaging==21.3
+     + packaging==24.0
+     - pyparsing==3.1.2
+    Installed 2 executables: black, blackd
+    "###);
+
+    // The conflicting requirement should be removed from the receipt.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black", from = "black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd", from = "black" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+}
+
 #[test]
 fn tool_upgrade_python() {
     let context = TestContext::new_with_versions(&["3.11", "3.12"])
@@ -732,7 +732,7 @@ fn create_venv_respects_group_requires_python() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.venv().arg("--clear").arg("--python").arg("3.11"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -856,6 +856,37 @@ fn seed() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+/// An invalid mirror manifest should be rejected before any downloads are attempted.
+#[test]
+fn mirror_manifest_invalid() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    let manifest = context.temp_dir.child("mirrors.toml");
+    manifest.write_str(indoc! {r#"
+        [[mirror]]
+        url = "https://example.com/pip-24.0-py3-none-any.whl"
+        prefix = "https://example.com/"
+        mirror = "https://mirror.example.com/pip-24.0-py3-none-any.whl"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed")
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::UV_MIRROR_MANIFEST, manifest.path()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Mirror manifest entry for `https://mirror.example.com/pip-24.0-py3-none-any.whl` must specify exactly one of `url` or `prefix`
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn seed_older_python_version() {
@@ -897,7 +928,7 @@ fn create_venv_unknown_python_minor() {
 
     uv_snapshot!(context.filters(), &mut command, @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -923,7 +954,7 @@ fn create_venv_unknown_python_patch() {
 
     uv_snapshot!(context.filters(), &mut command, @r"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -969,7 +1000,7 @@ fn file_exists() -> Result<()> {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1023,7 +1054,7 @@ fn non_empty_dir_exists() -> Result<()> {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1068,7 +1099,7 @@ fn non_empty_dir_exists_allow_existing() -> Result<()> {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1337,7 +1368,7 @@ fn path_with_trailing_space_gives_proper_error() {
         .arg("venv")
         .env(EnvVars::UV_CACHE_DIR, path_with_trailing_slash), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1718,7 +1749,7 @@ fn create_venv_current_working_directory() {
         .arg("3.12")
         .current_dir(&context.venv), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1757,7 +1788,7 @@ fn no_clear_with_existing_directory() {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1811,7 +1842,7 @@ fn no_clear_overrides_clear() {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1837,7 +1868,7 @@ fn no_clear_conflicts_with_allow_existing() {
         .arg("--python")
         .arg("3.12"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1849,3 +1880,68 @@ fn no_clear_conflicts_with_allow_existing() {
     "
     );
 }
+
+/// Create an environment on behalf of an installer plugin with `uv env create-for`.
+#[test]
+fn env_create_for() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let output = context
+        .command()
+        .arg("env")
+        .arg("create-for")
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .output()?;
+    assert!(output.status.success());
+
+    // The environment is described on `stdout`.
+    let description: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(description["protocol"], 1);
+    assert_eq!(description["created"], true);
+    assert!(
+        description["python-version"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3.12."))
+    );
+    assert!(
+        description["python"]
+            .as_str()
+            .is_some_and(|python| std::path::Path::new(python).is_file())
+    );
+    context.venv.assert(predicates::path::is_dir());
+
+    // A compatible environment is reused.
+    let output = context
+        .command()
+        .arg("env")
+        .arg("create-for")
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .output()?;
+    assert!(output.status.success());
+    let description: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(description["created"], false);
+
+    // An incompatible environment is recreated.
+    let output = context
+        .command()
+        .arg("env")
+        .arg("create-for")
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.11")
+        .output()?;
+    assert!(output.status.success());
+    let description: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(description["created"], true);
+    assert!(
+        description["python-version"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3.11."))
+    );
+
+    Ok(())
+}
@@ -597,7 +597,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("post")
         .arg("--bump").arg("post"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -915,7 +915,7 @@ requires-python = ">=3.12"
     uv_snapshot!(context.filters(), context.version()
         .arg("--bump").arg("stable"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -942,7 +942,7 @@ requires-python = ">=3.12"
     uv_snapshot!(context.filters(), context.version()
         .arg("--bump").arg("alpha"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -969,7 +969,7 @@ requires-python = ">=3.12"
     uv_snapshot!(context.filters(), context.version()
         .arg("--bump").arg("alpha"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -997,7 +997,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("major")
         .arg("--bump").arg("major"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1025,7 +1025,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("alpha")
         .arg("--bump").arg("alpha"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1053,7 +1053,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("stable")
         .arg("--bump").arg("major"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1141,7 +1141,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("alpha")
         .arg("--bump").arg("dev"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1199,7 +1199,7 @@ requires-python = ">=3.12"
         .arg("--bump").arg("major")
         .arg("--bump").arg("post"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1304,7 +1304,7 @@ requires-python = ">=3.12"
     uv_snapshot!(context.filters(), context.version()
         .arg("abcd"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1342,7 +1342,7 @@ requires-python = ">=3.12"
     uv_snapshot!(context.filters(), context.version()
         .arg("minor"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1379,7 +1379,7 @@ fn version_get_dynamic() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.version(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1417,7 +1417,7 @@ fn version_set_dynamic() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("0.1.2"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1457,7 +1457,7 @@ fn version_get_fallback_unmanaged() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.version(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1507,7 +1507,7 @@ fn version_get_fallback_unmanaged_short() -> Result<()> {
     uv_snapshot!(filters, context.version()
         .arg("--short"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1566,7 +1566,7 @@ fn version_get_fallback_unmanaged_strict() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("--project").arg("."), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1597,7 +1597,7 @@ fn version_get_fallback_missing_strict() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("--project").arg("."), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1614,7 +1614,7 @@ fn version_get_missing_with_hint() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.version(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1863,7 +1863,7 @@ fn version_get_workspace() -> Result<()> {
     // only for `uv version`)
     uv_snapshot!(context.filters(), context.version().arg("--project").arg(context.temp_dir.as_ref()), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2477,7 +2477,7 @@ fn virtual_empty() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("sortedcontainers"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2501,7 +2501,7 @@ fn virtual_empty() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("1.0.0"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2542,7 +2542,7 @@ fn add_virtual_dependency_group() -> Result<()> {
     // Get the version (doesn't make sense)
     uv_snapshot!(context.filters(), context.version(), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2568,7 +2568,7 @@ fn add_virtual_dependency_group() -> Result<()> {
     uv_snapshot!(context.filters(), context.version()
         .arg("1.0.0"), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -2647,3 +2647,35 @@ conflicts = [[{"extra" = "foo"}, {"extra" = "bar"}]]
 
     Ok(())
 }
+
+// Reading the version from the lockfile with `--frozen` shouldn't require a Python interpreter
+#[test]
+fn version_get_frozen_no_interpreter() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        version = "1.10.31"
+        requires-python = ">=3.12"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Request an interpreter that doesn't exist.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--frozen")
+        .arg("--python").arg("3.99"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    myproject 1.10.31
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Verify a wheelhouse in which every distribution matches the manifest.
+#[test]
+fn wheelhouse_verify() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .write_file(
+            &context
+                .workspace_root
+                .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        )?;
+    wheelhouse.child("SHA256SUMS").write_str(
+        "a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13  tqdm-1000.0.0-py3-none-any.whl\n",
+    )?;
+
+    uv_snapshot!(context.filters(), context.wheelhouse_verify().arg("wheelhouse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying wheelhouse at: wheelhouse
+    Verified 1 file against wheelhouse/SHA256SUMS
+    ");
+
+    Ok(())
+}
+
+/// Verify a wheelhouse with a missing file, a mismatched hash, and an unlisted distribution.
+#[test]
+fn wheelhouse_verify_failure() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .write_file(
+            &context
+                .workspace_root
+                .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        )?;
+    wheelhouse
+        .child("basic_package-0.1.0-py3-none-any.whl")
+        .write_file(
+            &context
+                .workspace_root
+                .join("scripts/links/basic_package-0.1.0-py3-none-any.whl"),
+        )?;
+    wheelhouse.child("SHA256SUMS").write_str(indoc::indoc! {"
+        0000000000000000000000000000000000000000000000000000000000000000  tqdm-1000.0.0-py3-none-any.whl
+        7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82  idna-3.6-py3-none-any.whl
+    "})?;
+
+    uv_snapshot!(context.filters(), context.wheelhouse_verify().arg("wheelhouse"), @r"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying wheelhouse at: wheelhouse
+    error: `idna-3.6-py3-none-any.whl` is listed in the manifest, but does not exist
+    error: Hash mismatch for `tqdm-1000.0.0-py3-none-any.whl`
+      Expected: sha256:0000000000000000000000000000000000000000000000000000000000000000
+      Computed: sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+    error: `basic_package-0.1.0-py3-none-any.whl` is not listed in the manifest
+    Verification failed for 3 files (0 verified)
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! Tests for long paths, extended-length (`\\?\`) paths, and case-insensitive paths on Windows.
+
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_fs::verbatim_path;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// A directory component that, repeated, pushes a module path beyond `MAX_PATH`.
+const SEGMENT: &str = "a_deeply_nested_directory_name";
+
+/// The relative path to a module within the wheel that exceeds `MAX_PATH` when installed.
+fn deep_module() -> String {
+    let mut module = String::from("deep_package");
+    for _ in 0..10 {
+        module.push('/');
+        module.push_str(SEGMENT);
+    }
+    module.push_str("/module.py");
+    module
+}
+
+/// Write a wheel for `deep_package` that contains a deeply nested module.
+fn write_deep_wheel(path: &Path, module: &str) -> Result<()> {
+    let file = fs_err::File::create(path)?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default();
+
+    let files = [
+        ("deep_package/__init__.py", ""),
+        (module, "VALUE = 1\n"),
+        (
+            "deep_package-0.1.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: deep-package\nVersion: 0.1.0\n",
+        ),
+        (
+            "deep_package-0.1.0.dist-info/WHEEL",
+            "Wheel-Version: 1.0\nGenerator: test\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+        ),
+    ];
+
+    let mut record = String::new();
+    for (name, contents) in files {
+        zip.start_file(name, options)?;
+        zip.write_all(contents.as_bytes())?;
+        record.push_str(name);
+        record.push_str(",,\n");
+    }
+    record.push_str("deep_package-0.1.0.dist-info/RECORD,,\n");
+    zip.start_file("deep_package-0.1.0.dist-info/RECORD", options)?;
+    zip.write_all(record.as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Install and uninstall a wheel containing a module whose installed path exceeds `MAX_PATH`.
+#[test]
+fn install_long_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let module = deep_module();
+    let wheel = context
+        .temp_dir
+        .child("deep_package-0.1.0-py3-none-any.whl");
+    write_deep_wheel(wheel.path(), &module)?;
+
+    let installed = context.site_packages().join(module.replace('/', "\\"));
+    assert!(installed.as_os_str().len() > 260);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(wheel.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + deep-package==0.1.0 (from file://[TEMP_DIR]/deep_package-0.1.0-py3-none-any.whl)
+    "
+    );
+
+    assert!(verbatim_path(&installed).is_file());
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("deep-package"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - deep-package==0.1.0 (from file://[TEMP_DIR]/deep_package-0.1.0-py3-none-any.whl)
+    "
+    );
+
+    assert!(!verbatim_path(&context.site_packages().join("deep_package")).exists());
+
+    Ok(())
+}
+
+/// Install into a virtual environment addressed by an extended-length (`\\?\`) path.
+#[test]
+fn install_verbatim_venv_path() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let module = deep_module();
+    let wheel = context
+        .temp_dir
+        .child("deep_package-0.1.0-py3-none-any.whl");
+    write_deep_wheel(wheel.path(), &module)?;
+
+    let venv = verbatim_path(context.venv.path()).into_owned();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--python")
+        .arg(&venv)
+        .arg(wheel.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: .venv
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + deep-package==0.1.0 (from file://[TEMP_DIR]/deep_package-0.1.0-py3-none-any.whl)
+    "
+    );
+
+    Ok(())
+}
@@ -216,7 +216,7 @@ fn test_albatross_project_in_excluded() {
         .join("seeds");
     uv_snapshot!(context.filters(), context.sync().current_dir(&current_dir), @r"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -883,7 +883,7 @@ fn workspace_empty_member() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
@@ -1133,7 +1133,7 @@ fn workspace_inherit_sources() -> Result<()> {
     // As-is, resolving should fail.
     uv_snapshot!(context.filters(), context.lock().arg("--offline").current_dir(&workspace), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1353,7 +1353,7 @@ fn workspace_unsatisfiable_member_dependencies() -> Result<()> {
     // Resolving should fail.
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1421,7 +1421,7 @@ fn workspace_unsatisfiable_member_dependencies_conflicting() -> Result<()> {
     // Resolving should fail.
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1504,7 +1504,7 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_threeway() -> Result<
     // Resolving should fail.
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1574,7 +1574,7 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_extra() -> Result<()>
     // Resolving should fail.
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -1644,7 +1644,7 @@ fn workspace_unsatisfiable_member_dependencies_conflicting_dev() -> Result<()> {
     // Resolving should fail.
     uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
@@ -0,0 +1,182 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Run a `git` command in the given directory.
+fn git(dir: &Path, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .assert()
+        .success();
+}
+
+/// Commit all changes in the given directory.
+fn commit(dir: &Path) {
+    git(dir, &["add", "."]);
+    git(
+        dir,
+        &[
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "Update",
+        ],
+    );
+}
+
+/// List the members affected by changes to a member and to a path dependency outside of the
+/// workspace.
+#[test]
+fn workspace_affected() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    // A path dependency that lives alongside (rather than within) the workspace.
+    context
+        .temp_dir
+        .child("shared/pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "shared"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "shared"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+        seeds = { workspace = true }
+        shared = { path = "../shared" }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+    })?;
+    for (name, dependencies) in [("bird-feeder", r#"["seeds"]"#), ("seeds", "[]")] {
+        project
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+            "#
+            })?;
+    }
+
+    git(&context.temp_dir, &["init", "--quiet"]);
+    commit(&context.temp_dir);
+
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("HEAD").current_dir(&project), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No workspace members are affected by changes since `HEAD`
+    ");
+
+    // Changing `seeds` affects its dependents, transitively, in dependency order.
+    project.child("packages/seeds/seeds.py").write_str("")?;
+
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("HEAD").current_dir(&project), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds
+    bird-feeder
+    albatross
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("HEAD").arg("--output-format").arg("json").current_dir(&project), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"seeds","path":"packages/seeds"},{"name":"bird-feeder","path":"packages/bird-feeder"},{"name":"albatross","path":"."}]
+
+    ----- stderr -----
+    "#);
+
+    commit(&context.temp_dir);
+
+    // Changing the path dependency only affects the member that depends on it.
+    context.temp_dir.child("shared/shared.py").write_str("")?;
+
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("HEAD").current_dir(&project), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+
+    ----- stderr -----
+    ");
+
+    // Comparing against an earlier revision includes committed changes.
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("HEAD~1").current_dir(&project), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds
+    bird-feeder
+    albatross
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// An unknown revision is reported as an error.
+#[test]
+fn workspace_affected_unknown_revision() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+
+    git(&context.temp_dir, &["init", "--quiet"]);
+    commit(&context.temp_dir);
+
+    uv_snapshot!(context.filters(), context.workspace_affected().arg("--since").arg("does-not-exist"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to determine the files changed since `does-not-exist`:
+    fatal: bad revision 'does-not-exist'
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Render the graph of a workspace with member, path, and external dependencies.
+#[test]
+fn workspace_graph() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    // A path dependency that lives alongside (rather than within) the workspace.
+    context
+        .temp_dir
+        .child("shared/pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "shared"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "albatross"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bird-feeder", "shared", "anyio>=4"]
+
+        [dependency-groups]
+        dev = ["pytest"]
+
+        [tool.uv.sources]
+        bird-feeder = { workspace = true }
+        seeds = { workspace = true }
+        shared = { path = "../shared" }
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+    })?;
+    for (name, dependencies) in [
+        ("bird-feeder", r#"["seeds", "iniconfig"]"#),
+        ("seeds", "[]"),
+    ] {
+        project
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+            "#
+            })?;
+    }
+
+    uv_snapshot!(context.filters(), context.workspace_graph().current_dir(&project), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph workspace {
+        "albatross" [shape=box];
+        "anyio" [shape=ellipse];
+        "bird-feeder" [shape=box];
+        "iniconfig" [shape=ellipse];
+        "pytest" [shape=ellipse];
+        "seeds" [shape=box];
+        "shared" [shape=box, style=dashed];
+        "albatross" -> "anyio";
+        "albatross" -> "bird-feeder";
+        "albatross" -> "pytest";
+        "albatross" -> "shared";
+        "bird-feeder" -> "iniconfig";
+        "bird-feeder" -> "seeds";
+    }
+
+    ----- stderr -----
+    "#);
+
+    uv_snapshot!(context.filters(), context.workspace_graph().arg("--format").arg("mermaid").current_dir(&project), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    graph TD
+        n0["albatross"]
+        n1("anyio")
+        n2["bird-feeder"]
+        n3("iniconfig")
+        n4("pytest")
+        n5["seeds"]
+        n6(["shared"])
+        n0 --> n1
+        n0 --> n2
+        n0 --> n4
+        n0 --> n6
+        n2 --> n3
+        n2 --> n5
+
+    ----- stderr -----
+    "#);
+
+    uv_snapshot!(context.filters(), context.workspace_graph().arg("--format").arg("json").current_dir(&project), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "members": [
+        {
+          "name": "albatross",
+          "path": ".",
+          "dependencies": [
+            {
+              "name": "anyio",
+              "kind": "external"
+            },
+            {
+              "name": "bird-feeder",
+              "kind": "workspace"
+            },
+            {
+              "name": "pytest",
+              "kind": "external"
+            },
+            {
+              "name": "shared",
+              "kind": "path",
+              "path": "../shared"
+            }
+          ]
+        },
+        {
+          "name": "bird-feeder",
+          "path": "packages/bird-feeder",
+          "dependencies": [
+            {
+              "name": "iniconfig",
+              "kind": "external"
+            },
+            {
+              "name": "seeds",
+              "kind": "workspace"
+            }
+          ]
+        },
+        {
+          "name": "seeds",
+          "path": "packages/seeds",
+          "dependencies": []
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}